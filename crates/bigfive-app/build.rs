@@ -17,6 +17,18 @@ fn main() {
 
     println!("cargo:rustc-env=GIT_HASH={}", git_hash);
     println!("cargo:rustc-env=BUILD_TIME={}", build_time);
+    // Prefix of browser storage keys, so instances on one origin keep separate data
+    println!("cargo::rerun-if-env-changed=STORAGE_NAMESPACE");
+    let namespace = std::env::var("STORAGE_NAMESPACE").unwrap_or_else(|_| "bigfive".to_string());
+    if namespace.is_empty()
+        || !namespace
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+    {
+        panic!("STORAGE_NAMESPACE must be non-empty and contain only letters, digits, '-' and '_'");
+    }
+    println!("cargo:rustc-env=STORAGE_NAMESPACE={}", namespace);
+
     println!("cargo:rerun-if-changed=../../.git/HEAD");
     println!("cargo:rerun-if-changed=../../.git/refs/heads/");
 }
@@ -0,0 +1,71 @@
+// Service worker for offline viewing of shared results.
+//
+// Shared result pages and the app bundle (/pkg/) are fetched from the network first and
+// cached, so a link opened before still loads without a connection. The result data itself
+// comes from the page's IndexedDB copy (see src/offline.rs), since server functions are POSTs.
+
+const CACHE = 'bigfive-offline-v1';
+
+// Result pages kept; the oldest are dropped first
+const MAX_RESULT_PAGES = 20;
+
+const RESULT_PAGE = /^(\/[a-z]{2})?\/results\/[^/]+\/?$/;
+
+self.addEventListener('install', () => {
+  self.skipWaiting();
+});
+
+self.addEventListener('activate', (event) => {
+  event.waitUntil(
+    caches
+      .keys()
+      .then((keys) => Promise.all(keys.filter((key) => key !== CACHE).map((key) => caches.delete(key))))
+      .then(() => self.clients.claim()),
+  );
+});
+
+self.addEventListener('fetch', (event) => {
+  const request = event.request;
+  if (request.method !== 'GET') {
+    return;
+  }
+  const url = new URL(request.url);
+  if (url.origin !== self.location.origin) {
+    return;
+  }
+
+  const resultPage = request.mode === 'navigate' && RESULT_PAGE.test(url.pathname);
+  if (resultPage || url.pathname.startsWith('/pkg/')) {
+    event.respondWith(networkFirst(event, request, resultPage));
+  }
+});
+
+// Network response when there is one (cached for later), the cached copy otherwise
+async function networkFirst(event, request, resultPage) {
+  const cache = await caches.open(CACHE);
+  try {
+    const response = await fetch(request);
+    if (response.ok) {
+      event.waitUntil(store(cache, request, response.clone(), resultPage));
+    }
+    return response;
+  } catch (error) {
+    const cached = await cache.match(request);
+    if (cached) {
+      return cached;
+    }
+    throw error;
+  }
+}
+
+async function store(cache, request, response, resultPage) {
+  if (resultPage) {
+    // Re-inserted entries move to the end, so the key order is least recently visited first
+    await cache.delete(request);
+  }
+  await cache.put(request, response);
+  if (resultPage) {
+    const pages = (await cache.keys()).filter((key) => RESULT_PAGE.test(new URL(key.url).pathname));
+    await Promise.all(pages.slice(0, -MAX_RESULT_PAGES).map((key) => cache.delete(key)));
+  }
+}
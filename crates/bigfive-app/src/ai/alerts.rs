@@ -0,0 +1,155 @@
+//! Webhook alerts for model presets whose analyses keep failing.
+//!
+//! Every analysis outcome is kept per preset for `provider_alerts.window_secs`. Once a preset
+//! has enough analyses in the window and too many of them failed at the provider (errors
+//! from the API, unreadable or empty responses, a missing API key), the configured webhook
+//! is called, at most once per `cooldown_secs`. Rejections of the user's input don't count.
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+use tracing::{error, info, warn};
+
+use super::error::AnalysisError;
+use crate::config::{ProviderAlertsConfig, get_config};
+
+/// Longest provider error quoted in an alert.
+const MAX_ERROR_CHARS: usize = 500;
+
+/// Timeout for delivering an alert.
+const WEBHOOK_TIMEOUT: Duration = Duration::from_secs(10);
+
+#[derive(Default)]
+struct PresetOutcomes {
+    /// When each analysis finished and whether it failed, oldest first.
+    outcomes: VecDeque<(Instant, bool)>,
+    last_alert: Option<Instant>,
+}
+
+static OUTCOMES: LazyLock<Mutex<HashMap<String, PresetOutcomes>>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Body of the webhook request.
+#[derive(Debug, Serialize)]
+struct Alert {
+    /// One-line summary for chat webhooks.
+    text: String,
+    preset: String,
+    failed: usize,
+    calls: usize,
+    window_secs: u64,
+    last_error: String,
+}
+
+/// Whether an error points at the provider or its configuration rather than the request.
+fn is_provider_failure(error: &AnalysisError) -> bool {
+    matches!(
+        error,
+        AnalysisError::Config(_)
+            | AnalysisError::Request(_)
+            | AnalysisError::ApiError { .. }
+            | AnalysisError::ParseResponse(_)
+            | AnalysisError::EmptyResponse
+    )
+}
+
+/// Count the outcome of an analysis with preset `preset_id`, alerting if the preset's error
+/// rate crossed the configured threshold.
+pub fn record<T>(preset_id: &str, result: &Result<T, AnalysisError>) {
+    let Some(config) = get_config().ok().and_then(|c| c.provider_alerts.as_ref()) else {
+        return;
+    };
+    let error = match result {
+        Err(e) if is_provider_failure(e) => Some(e),
+        Err(_) => return,
+        Ok(_) => None,
+    };
+
+    let now = Instant::now();
+    let alert = {
+        let mut outcomes = OUTCOMES.lock().unwrap();
+        let preset = outcomes.entry(preset_id.to_string()).or_default();
+        preset.outcomes.push_back((now, error.is_some()));
+        let window = Duration::from_secs(config.window_secs);
+        while preset
+            .outcomes
+            .front()
+            .is_some_and(|(at, _)| now.duration_since(*at) > window)
+        {
+            preset.outcomes.pop_front();
+        }
+        check(config, preset_id, preset, now, error)
+    };
+
+    if let Some(alert) = alert {
+        warn!(preset = %alert.preset, failed = alert.failed, calls = alert.calls, "Provider error rate over threshold, sending alert");
+        let url = config.webhook_url.clone();
+        tokio::spawn(send(url, alert));
+    }
+}
+
+/// The alert to send for a preset's recent outcomes, if its error rate is over the threshold
+/// and it isn't cooling down from an earlier alert.
+fn check(
+    config: &ProviderAlertsConfig,
+    preset_id: &str,
+    preset: &mut PresetOutcomes,
+    now: Instant,
+    error: Option<&AnalysisError>,
+) -> Option<Alert> {
+    let error = error?;
+    let calls = preset.outcomes.len();
+    let failed = preset.outcomes.iter().filter(|(_, failed)| *failed).count();
+    if calls < config.min_calls || (failed as f64) < config.error_rate * calls as f64 {
+        return None;
+    }
+    let cooldown = Duration::from_secs(config.cooldown_secs);
+    if preset
+        .last_alert
+        .is_some_and(|at| now.duration_since(at) < cooldown)
+    {
+        return None;
+    }
+    preset.last_alert = Some(now);
+
+    let minutes = config.window_secs.div_ceil(60);
+    Some(Alert {
+        text: format!(
+            "Model preset '{preset_id}': {failed} of {calls} analyses failed in the last {minutes} min. Last error: {}",
+            truncate(&error.to_string())
+        ),
+        preset: preset_id.to_string(),
+        failed,
+        calls,
+        window_secs: config.window_secs,
+        last_error: truncate(&error.to_string()),
+    })
+}
+
+fn truncate(text: &str) -> String {
+    match text.char_indices().nth(MAX_ERROR_CHARS) {
+        Some((i, _)) => format!("{}…", &text[..i]),
+        None => text.to_string(),
+    }
+}
+
+async fn send(url: String, alert: Alert) {
+    let client = match reqwest::Client::builder().timeout(WEBHOOK_TIMEOUT).build() {
+        Ok(client) => client,
+        Err(e) => {
+            error!("Failed to build webhook client: {e}");
+            return;
+        }
+    };
+    match client.post(&url).json(&alert).send().await {
+        Ok(response) if response.status().is_success() => {
+            info!(preset = %alert.preset, "Provider alert delivered");
+        }
+        Ok(response) => {
+            error!(preset = %alert.preset, status = %response.status(), "Provider alert webhook rejected the alert");
+        }
+        Err(e) => error!(preset = %alert.preset, "Failed to deliver provider alert: {e}"),
+    }
+}
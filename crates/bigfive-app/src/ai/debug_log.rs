@@ -0,0 +1,131 @@
+//! Redacted record of the provider calls made for one analysis job.
+//!
+//! With `debug_provider_logs` enabled, the analysis task runs inside [`capture`] and every
+//! model call made meanwhile is recorded. Nothing secret is kept: the API key is masked
+//! wherever it shows up, the user's context is cut down to a short excerpt and long prompts
+//! and responses are truncated. Failed jobs store their record for the admin page.
+
+use std::cell::RefCell;
+use std::future::Future;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+
+use crate::config::ApiConfig;
+
+use super::error::AnalysisError;
+
+/// Longest prompt, response or error text kept, in characters.
+const MAX_TEXT_CHARS: usize = 2000;
+
+/// Characters of the user's context kept at the start of each occurrence.
+const CONTEXT_EXCERPT_CHARS: usize = 80;
+
+const API_KEY_MASK: &str = "[api key]";
+
+/// One model call made while capturing.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProviderCall {
+    pub provider: String,
+    pub model: String,
+    pub max_tokens: u32,
+    pub system: Option<String>,
+    pub user: String,
+    /// Response text, for successful calls.
+    pub response: Option<String>,
+    /// Error, for failed calls.
+    pub error: Option<String>,
+    pub elapsed_ms: u64,
+}
+
+#[derive(Default)]
+struct Recorder {
+    calls: Vec<ProviderCall>,
+    /// User contexts to shorten wherever they appear in a prompt.
+    contexts: Vec<String>,
+}
+
+tokio::task_local! {
+    static RECORDER: RefCell<Recorder>;
+}
+
+/// Run `future`, recording the provider calls it makes when `enabled`.
+pub async fn capture<F: Future>(enabled: bool, future: F) -> (F::Output, Vec<ProviderCall>) {
+    if !enabled {
+        return (future.await, Vec::new());
+    }
+    RECORDER
+        .scope(RefCell::new(Recorder::default()), async move {
+            let output = future.await;
+            let calls = RECORDER.with(|recorder| std::mem::take(&mut recorder.borrow_mut().calls));
+            (output, calls)
+        })
+        .await
+}
+
+/// Mark `context` as the user's text, so recorded prompts keep only an excerpt of it.
+///
+/// Does nothing outside [`capture`].
+pub fn redact_context(context: &str) {
+    // Prompts embed the trimmed text
+    let context = context.trim();
+    if context.chars().count() <= CONTEXT_EXCERPT_CHARS {
+        return;
+    }
+    let _ = RECORDER.try_with(|recorder| recorder.borrow_mut().contexts.push(context.to_string()));
+}
+
+/// Record a finished model call. Does nothing outside [`capture`].
+pub(super) fn record(
+    api: &ApiConfig,
+    model: &str,
+    system: Option<&str>,
+    user: &str,
+    max_tokens: u32,
+    result: Result<&str, &AnalysisError>,
+    elapsed: Duration,
+) {
+    let _ = RECORDER.try_with(|recorder| {
+        let mut recorder = recorder.borrow_mut();
+        let api_key = api.api_key().ok().filter(|key| !key.is_empty());
+        let clean = |text: &str| {
+            let mut text = text.to_string();
+            for context in &recorder.contexts {
+                text = text.replace(context, &context_excerpt(context));
+            }
+            if let Some(key) = &api_key {
+                text = text.replace(key.as_str(), API_KEY_MASK);
+            }
+            truncate(&text)
+        };
+
+        let call = ProviderCall {
+            provider: format!("{:?}", api.provider),
+            model: model.to_string(),
+            max_tokens,
+            system: system.map(&clean),
+            user: clean(user),
+            response: result.ok().map(&clean),
+            error: result.err().map(|e| clean(&e.to_string())),
+            elapsed_ms: elapsed.as_millis() as u64,
+        };
+        recorder.calls.push(call);
+    });
+}
+
+fn context_excerpt(context: &str) -> String {
+    let excerpt: String = context.chars().take(CONTEXT_EXCERPT_CHARS).collect();
+    format!(
+        "{excerpt}… [user context, {} characters]",
+        context.chars().count()
+    )
+}
+
+fn truncate(text: &str) -> String {
+    let len = text.chars().count();
+    if len <= MAX_TEXT_CHARS {
+        return text.to_string();
+    }
+    let kept: String = text.chars().take(MAX_TEXT_CHARS).collect();
+    format!("{kept}… [{} more characters]", len - MAX_TEXT_CHARS)
+}
@@ -31,6 +31,22 @@ pub enum AnalysisError {
     )]
     UnsafeInput,
 
+    /// User context exceeds the configured maximum length
+    #[error("Your context is too long ({len} characters, maximum is {max}).")]
+    ContextTooLong { len: usize, max: usize },
+
+    /// Full analysis prompt (profile + context) exceeds the configured maximum length
+    #[error("The analysis request is too large ({len} characters, maximum is {max}).")]
+    PromptTooLong { len: usize, max: usize },
+
+    /// AI features are turned off for this deployment
+    #[error("AI analysis is not available on this site.")]
+    AiDisabled,
+
+    /// Facet explanations are not configured or disabled
+    #[error("Facet explanations are not available.")]
+    ExplanationsDisabled,
+
     /// Invalid model ID
     #[error("Invalid model: {0}")]
     InvalidModel(String),
@@ -0,0 +1,39 @@
+//! Short explanations of single facet scores.
+//!
+//! A cheap alternative to the full analysis for people who want to learn about one trait.
+//! The text only depends on the facet, level and language, so callers cache it.
+
+use bigfive::{Facet, ScoreLevel};
+use tracing::{info, instrument};
+
+use crate::config::get_config;
+
+use super::error::AnalysisError;
+use super::prompts;
+use super::provider::call_model;
+
+/// Generate an explanation of a facet score with the configured explanation model.
+#[instrument(skip_all, fields(facet = ?facet, level = ?level, lang = %lang))]
+pub async fn generate_facet_explanation(
+    facet: Facet,
+    level: ScoreLevel,
+    lang: &str,
+) -> Result<String, AnalysisError> {
+    let config = get_config()?;
+    let explanations = match &config.facet_explanations {
+        Some(e) if config.facet_explanations_enabled() => e,
+        _ => return Err(AnalysisError::ExplanationsDisabled),
+    };
+
+    info!(model = %explanations.model, "Generating facet explanation");
+    let prompt = prompts::facet_explanation_prompt(facet, level, lang);
+    let text = call_model(
+        &explanations.api,
+        &explanations.model,
+        &prompt,
+        explanations.max_tokens,
+        None,
+    )
+    .await?;
+    Ok(text.trim().to_string())
+}
@@ -1,12 +1,20 @@
 //! AI analysis module.
 //!
 //! Provides personality analysis using configurable AI models with optional
-//! safeguard (prompt injection detection) and translation pipeline.
+//! safeguard (prompt injection detection) and translation pipeline, plus short
+//! explanations of single facet scores.
 
+pub mod alerts;
+pub mod debug_log;
 pub mod error;
+pub mod explain;
 pub mod pipeline;
 pub mod prompts;
 pub mod provider;
 
 pub use error::AnalysisError;
-pub use pipeline::generate_analysis;
+pub use explain::generate_facet_explanation;
+pub use pipeline::{
+    check_context_length, check_prompt_length, generate_analysis, limit_analysis_length,
+};
+pub use prompts::prompt_version;
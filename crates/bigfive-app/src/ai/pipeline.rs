@@ -9,7 +9,7 @@ use crate::config::{AiConfig, ModelPreset, get_config};
 
 use super::error::AnalysisError;
 use super::prompts;
-use super::provider::{call_model, call_model_with_system};
+use super::provider::{call_model_continued, call_model_with_system};
 
 /// Generate personality analysis using a specific model preset.
 ///
@@ -20,9 +20,10 @@ use super::provider::{call_model, call_model_with_system};
 /// * `interface_language` - The user's interface language ("en", "ru", or "zh")
 ///
 /// # Pipeline
-/// 1. If safeguard is enabled, check user_context for prompt injection
-/// 2. Generate analysis in model's source_lang
-/// 3. If source_lang != interface_language, translate to interface_language
+/// 1. If PII scrubbing is enabled, mask personal data in user_context
+/// 2. If safeguard is enabled, check user_context for prompt injection
+/// 3. Generate analysis in model's source_lang
+/// 4. If source_lang != interface_language, translate to interface_language
 #[instrument(skip_all, fields(model_id = %model_id, lang = %interface_language, has_context = user_context.is_some()))]
 pub async fn generate_analysis(
     model_id: &str,
@@ -32,6 +33,9 @@ pub async fn generate_analysis(
 ) -> Result<String, AnalysisError> {
     info!("Starting personality analysis pipeline");
     let config = get_config()?;
+    if !config.ai_enabled {
+        return Err(AnalysisError::AiDisabled);
+    }
 
     // Find the model preset
     let preset = config
@@ -44,6 +48,22 @@ pub async fn generate_analysis(
         "Using model preset"
     );
 
+    // Reject oversized context before it reaches any model (including the safeguard)
+    if let Some(context) = user_context {
+        check_context_length(config, context)?;
+    }
+
+    // Mask personal data before the context reaches any provider
+    let user_context =
+        user_context.map(|context| crate::pii::scrub(&config.pii_scrubbing, context));
+    let user_context = user_context.as_deref();
+    if let Some(context) = user_context {
+        super::debug_log::redact_context(context);
+    }
+
+    // Catch oversized prompts here rather than as a provider error after a long wait
+    check_prompt_length(config, preset, profile, user_context)?;
+
     // Step 0: Safeguard check (if enabled and context provided)
     if let Some(context) = user_context
         && !context.trim().is_empty()
@@ -54,7 +74,46 @@ pub async fn generate_analysis(
     }
 
     // Generate analysis with the preset
-    generate_with_preset(preset, profile, user_context, interface_language).await
+    let result =
+        generate_with_preset(config, preset, profile, user_context, interface_language).await;
+    super::alerts::record(&preset.id, &result);
+    result
+}
+
+/// Check user context against the configured maximum length.
+pub fn check_context_length(config: &AiConfig, user_context: &str) -> Result<(), AnalysisError> {
+    let len = user_context.chars().count();
+    if len > config.max_context_chars {
+        warn!(len, max = config.max_context_chars, "User context too long");
+        return Err(AnalysisError::ContextTooLong {
+            len,
+            max: config.max_context_chars,
+        });
+    }
+    Ok(())
+}
+
+/// Check the full analysis prompt for a preset against the configured maximum length.
+pub fn check_prompt_length(
+    config: &AiConfig,
+    preset: &ModelPreset,
+    profile: &PersonalityProfile,
+    user_context: Option<&str>,
+) -> Result<(), AnalysisError> {
+    let prompt = prompts::analysis_prompt(preset.source_lang, profile, user_context);
+    let len = prompt.chars().count();
+    if len > config.max_prompt_chars {
+        warn!(
+            len,
+            max = config.max_prompt_chars,
+            "Analysis prompt too long"
+        );
+        return Err(AnalysisError::PromptTooLong {
+            len,
+            max: config.max_prompt_chars,
+        });
+    }
+    Ok(())
 }
 
 /// Check user context for prompt injection using safeguard model.
@@ -96,6 +155,7 @@ async fn check_safeguard(config: &AiConfig, user_context: &str) -> Result<(), An
 /// Generate analysis using a model preset.
 #[instrument(skip_all, fields(model = %preset.model, source_lang = ?preset.source_lang))]
 async fn generate_with_preset(
+    config: &AiConfig,
     preset: &ModelPreset,
     profile: &PersonalityProfile,
     user_context: Option<&str>,
@@ -110,12 +170,13 @@ async fn generate_with_preset(
 
     let prompt = prompts::analysis_prompt(preset.source_lang, profile, user_context);
 
-    let analysis = call_model(
+    let analysis = call_model_continued(
         &preset.api,
         &preset.model,
         &prompt,
         preset.max_tokens,
         preset.thinking.as_ref(),
+        config.max_continuations,
     )
     .await?;
 
@@ -124,7 +185,11 @@ async fn generate_with_preset(
     // Step 2: Translate if source != target
     if preset.source_lang.code() == interface_language {
         info!("Source matches interface language, skipping translation");
-        return Ok(analysis);
+        return Ok(limit_analysis_length(
+            &analysis,
+            config.max_analysis_chars,
+            interface_language,
+        ));
     }
 
     // Check if translation is configured
@@ -132,7 +197,11 @@ async fn generate_with_preset(
         Some(t) => t,
         None => {
             info!("No translation configured, returning analysis in source language");
-            return Ok(analysis);
+            return Ok(limit_analysis_length(
+                &analysis,
+                config.max_analysis_chars,
+                preset.source_lang.code(),
+            ));
         }
     };
 
@@ -146,15 +215,55 @@ async fn generate_with_preset(
     let translation_prompt =
         prompts::translation_prompt(&analysis, preset.source_lang, interface_language);
 
-    let translated = call_model(
+    let translated = call_model_continued(
         &translation.api,
         &translation.model,
         &translation_prompt,
         translation.max_tokens,
         None, // No thinking for translation
+        config.max_continuations,
     )
     .await?;
 
     info!(translated_len = translated.len(), "Translation complete");
-    Ok(translated)
+    Ok(limit_analysis_length(
+        &translated,
+        config.max_analysis_chars,
+        interface_language,
+    ))
+}
+
+/// Cut an analysis longer than `max_chars` at the last paragraph (or line, or word) break
+/// that fits and end it with a notice in `lang`. Shorter analyses are returned unchanged.
+///
+/// The result, notice included, fits in `max_chars`, so cutting again changes nothing.
+pub fn limit_analysis_length(analysis: &str, max_chars: usize, lang: &str) -> String {
+    if analysis.chars().count() <= max_chars {
+        return analysis.to_string();
+    }
+    let notice = match lang {
+        "ru" => "*Анализ сокращён: он превысил максимальную длину.*",
+        "zh" => "*分析内容超出最大长度，已被截断。*",
+        _ => "*The analysis was shortened because it exceeded the maximum length.*",
+    };
+    let suffix = format!("\n\n---\n\n{notice}");
+
+    let budget = max_chars.saturating_sub(suffix.chars().count());
+    let limit = analysis
+        .char_indices()
+        .nth(budget)
+        .map_or(analysis.len(), |(i, _)| i);
+    let head = &analysis[..limit];
+    let cut = head
+        .rfind("\n\n")
+        .or_else(|| head.rfind('\n'))
+        .or_else(|| head.rfind(char::is_whitespace))
+        .filter(|&i| i > 0)
+        .unwrap_or(limit);
+    warn!(
+        len = analysis.chars().count(),
+        max_chars, "Analysis too long, truncating"
+    );
+
+    format!("{}{suffix}", head[..cut].trim_end())
 }
@@ -0,0 +1,95 @@
+//! Mock provider for developing and demoing the UI without API keys.
+//!
+//! Returns canned analyses in the prompt's language after a configurable delay.
+//! Safeguard checks always pass, translations return the canned text in the
+//! target language and facet explanations get a canned sentence.
+
+use std::time::Duration;
+
+use tracing::info;
+
+use crate::config::ApiConfig;
+
+/// Answer a model call with canned text.
+pub async fn complete(api: &ApiConfig, system: Option<&str>, user: &str) -> String {
+    info!(delay_ms = api.mock_delay_ms, "Mock provider call");
+    tokio::time::sleep(Duration::from_millis(api.mock_delay_ms)).await;
+
+    // Only safeguard checks use a system prompt
+    if system.is_some() {
+        return "SAFE".to_string();
+    }
+
+    let first_line = user.lines().next().unwrap_or_default();
+    if first_line.starts_with("In the IPIP-NEO-120") {
+        return if user.contains("Answer in Russian") {
+            "Это демонстрационное пояснение от mock-провайдера: настоящая модель не вызывалась."
+        } else {
+            "This is a demo explanation from the mock provider: no real model was called."
+        }
+        .to_string();
+    }
+    let lang = match first_line.strip_prefix("Translate this personality analysis") {
+        Some(rest) if rest.contains(" to Russian") => "ru",
+        Some(_) => "en",
+        None if first_line.chars().any(is_cjk) => "zh",
+        None if first_line.chars().any(is_cyrillic) => "ru",
+        None => "en",
+    };
+    canned_analysis(lang).to_string()
+}
+
+fn is_cjk(c: char) -> bool {
+    ('\u{4e00}'..='\u{9fff}').contains(&c)
+}
+
+fn is_cyrillic(c: char) -> bool {
+    ('\u{0400}'..='\u{04ff}').contains(&c)
+}
+
+fn canned_analysis(lang: &str) -> &'static str {
+    match lang {
+        "ru" => {
+            r#"## Обзор
+Это **демонстрационный анализ** от mock-провайдера. Настоящая модель не вызывалась.
+
+## Сильные стороны
+- Вы дочитали тест до конца
+- Интерфейс работает: задания, опрос статуса и сохранение результата
+
+## Рекомендации
+- Укажите реальный провайдер в `ai_config.toml`, чтобы получить настоящий анализ
+
+## Заключение
+Mock-провайдер нужен для разработки и демонстраций без API-ключей."#
+        }
+        "zh" => {
+            r#"## 概述
+这是来自 mock 提供方的**演示分析**，没有调用真实模型。
+
+## 优势
+- 您完成了整个测试
+- 界面流程正常：任务、状态轮询和结果保存
+
+## 建议
+- 在 `ai_config.toml` 中配置真实的提供方以获得真正的分析
+
+## 结论
+Mock 提供方用于在没有 API 密钥的情况下进行开发和演示。"#
+        }
+        _ => {
+            r#"## Overview
+This is a **demo analysis** from the mock provider. No real model was called.
+
+## Strengths
+- You made it through the whole test
+- The UI flow works: jobs, status polling and saving results
+
+## Recommendations
+- Configure a real provider in `ai_config.toml` to get an actual analysis
+
+## Conclusion
+The mock provider exists for development and demos without API keys."#
+        }
+    }
+}
@@ -1,19 +1,33 @@
 //! API provider implementations.
 //!
-//! Uses llm-relay for all LLM calls.
+//! Uses llm-relay for all real LLM calls; the `mock` provider answers locally.
+
+mod mock;
 
 use std::time::{Duration, Instant};
 
-use llm_relay::{ChatOptions, ClientConfig, LlmClient};
+use llm_relay::{ChatOptions, ClientConfig, LlmClient, Message, StopReason};
 use tracing::{debug, info, instrument, warn};
 
 use crate::config::{ApiConfig, Provider, ThinkingConfig};
 
+use super::debug_log;
 use super::error::AnalysisError;
 
 /// Default timeout for API calls (3 minutes to allow for slow Claude Opus responses).
 pub const API_TIMEOUT: Duration = Duration::from_secs(180);
 
+/// Sent after a response that was cut off by `max_tokens`, with the partial answer before it.
+const CONTINUE_PROMPT: &str =
+    "Your answer was cut off. Continue exactly where it stopped, without repeating anything.";
+
+/// Text of one model response.
+struct Completion {
+    text: String,
+    /// The response stopped at `max_tokens` rather than finishing.
+    cut_off: bool,
+}
+
 /// Call an AI model with the given prompt.
 #[instrument(skip(api, prompt), fields(model = %model, max_tokens = %max_tokens, provider = ?api.provider))]
 pub async fn call_model(
@@ -24,18 +38,40 @@ pub async fn call_model(
     thinking: Option<&ThinkingConfig>,
 ) -> Result<String, AnalysisError> {
     debug!(prompt_len = prompt.len(), "Calling model");
-    let start = Instant::now();
-    let result = do_call(api, model, None, prompt, max_tokens, thinking).await;
-    let elapsed = start.elapsed();
-    match &result {
-        Ok(response) => info!(
-            response_len = response.len(),
-            elapsed_ms = elapsed.as_millis(),
-            "Model call succeeded"
-        ),
-        Err(e) => warn!(error = %e, elapsed_ms = elapsed.as_millis(), "Model call failed"),
+    let completion = timed_call(api, model, None, prompt, None, max_tokens, thinking).await?;
+    Ok(completion.text)
+}
+
+/// Call an AI model with the given prompt, asking it to continue while its response is cut
+/// off by `max_tokens`, at most `max_continuations` times. Returns the joined text.
+#[instrument(skip(api, prompt), fields(model = %model, max_tokens = %max_tokens, provider = ?api.provider))]
+pub async fn call_model_continued(
+    api: &ApiConfig,
+    model: &str,
+    prompt: &str,
+    max_tokens: u32,
+    thinking: Option<&ThinkingConfig>,
+    max_continuations: u32,
+) -> Result<String, AnalysisError> {
+    debug!(prompt_len = prompt.len(), "Calling model");
+    let mut completion = timed_call(api, model, None, prompt, None, max_tokens, thinking).await?;
+    let mut text = completion.text;
+    let mut continuations = 0;
+    while completion.cut_off {
+        if continuations == max_continuations {
+            warn!(continuations, "Response still cut off by max_tokens");
+            break;
+        }
+        continuations += 1;
+        info!(
+            continuations,
+            "Response cut off by max_tokens, requesting continuation"
+        );
+        completion =
+            timed_call(api, model, None, prompt, Some(&text), max_tokens, thinking).await?;
+        text.push_str(&completion.text);
     }
-    result
+    Ok(text)
 }
 
 /// Call an AI model with system and user messages.
@@ -53,30 +89,64 @@ pub async fn call_model_with_system(
         user_len = user.len(),
         "Calling model with system prompt"
     );
+    let completion = timed_call(api, model, Some(system), user, None, max_tokens, thinking).await?;
+    Ok(completion.text)
+}
+
+/// [`do_call`] with timing, logging and debug recording.
+async fn timed_call(
+    api: &ApiConfig,
+    model: &str,
+    system: Option<&str>,
+    user: &str,
+    partial: Option<&str>,
+    max_tokens: u32,
+    thinking: Option<&ThinkingConfig>,
+) -> Result<Completion, AnalysisError> {
     let start = Instant::now();
-    let result = do_call(api, model, Some(system), user, max_tokens, thinking).await;
+    let result = do_call(api, model, system, user, partial, max_tokens, thinking).await;
     let elapsed = start.elapsed();
     match &result {
-        Ok(response) => info!(
-            response_len = response.len(),
+        Ok(completion) => info!(
+            response_len = completion.text.len(),
+            cut_off = completion.cut_off,
             elapsed_ms = elapsed.as_millis(),
             "Model call succeeded"
         ),
         Err(e) => warn!(error = %e, elapsed_ms = elapsed.as_millis(), "Model call failed"),
     }
+    debug_log::record(
+        api,
+        model,
+        system,
+        user,
+        max_tokens,
+        result.as_ref().map(|c| c.text.as_str()),
+        elapsed,
+    );
     result
 }
 
+/// One request to the provider. With `partial`, the model is shown its earlier, cut-off
+/// answer and asked to continue it.
 async fn do_call(
     api: &ApiConfig,
     model: &str,
     system: Option<&str>,
     user: &str,
+    partial: Option<&str>,
     max_tokens: u32,
     thinking: Option<&ThinkingConfig>,
-) -> Result<String, AnalysisError> {
+) -> Result<Completion, AnalysisError> {
+    if api.provider == Provider::Mock {
+        return Ok(Completion {
+            text: mock::complete(api, system, user).await,
+            cut_off: false,
+        });
+    }
     let api_key = api.api_key()?;
     let config = match api.provider {
+        Provider::Mock => unreachable!("handled above"),
         Provider::Anthropic => ClientConfig::anthropic(&api_key, model),
         Provider::OpenAiCompatible => {
             let api_url = api.api_url.as_ref().ok_or_else(|| {
@@ -94,11 +164,19 @@ async fn do_call(
         thinking,
         ..Default::default()
     };
-    let resp = client.complete(user, options).await.map_err(|e| match e {
+    let mut messages = vec![Message::user_text(user)];
+    if let Some(partial) = partial {
+        messages.push(Message::assistant_text(partial));
+        messages.push(Message::user_text(CONTINUE_PROMPT));
+    }
+    let resp = client.chat(&messages, options).await.map_err(|e| match e {
         llm_relay::LlmError::ApiError { status, body } => AnalysisError::ApiError { status, body },
         llm_relay::LlmError::EmptyResponse => AnalysisError::EmptyResponse,
         llm_relay::LlmError::ParseResponse(msg) => AnalysisError::ParseResponse(msg),
         other => AnalysisError::Request(other.to_string()),
     })?;
-    Ok(resp.text())
+    Ok(Completion {
+        text: resp.text(),
+        cut_off: resp.stop_reason == StopReason::MaxTokens,
+    })
 }
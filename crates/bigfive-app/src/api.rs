@@ -0,0 +1,327 @@
+//! Public JSON API for external quiz frontends and dashboards.
+
+use axum::Json;
+use axum::extract::{Extension, Path, Query};
+use axum::http::header::{
+    ACCESS_CONTROL_ALLOW_HEADERS, ACCESS_CONTROL_ALLOW_METHODS, ACCESS_CONTROL_ALLOW_ORIGIN,
+    ACCESS_CONTROL_MAX_AGE, ORIGIN, VARY,
+};
+use axum::http::{HeaderMap, HeaderValue, StatusCode};
+use axum::response::{IntoResponse, Response};
+use bigfive::{Domain, ScoreLevel};
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+
+use crate::auth::tokens_match;
+use crate::components::MIN_NORM_SAMPLE;
+use crate::config::{SummaryApiConfig, TenantConfig, get_config};
+use crate::db;
+use crate::tenancy::CurrentTenant;
+
+/// Largest page size for [`inventory_handler`].
+const MAX_PAGE_SIZE: usize = 120;
+
+/// Query parameters of `GET /api/v1/inventory/:lang`.
+#[derive(Debug, Deserialize)]
+pub struct InventoryQuery {
+    /// Domain code to filter by (`N`, `E`, `O`, `A` or `C`).
+    domain: Option<String>,
+    /// Facet number (1-6) to filter by, within `domain` if given.
+    facet: Option<u8>,
+    #[serde(default)]
+    offset: usize,
+    limit: Option<usize>,
+}
+
+/// One inventory item.
+#[derive(Debug, Serialize)]
+struct InventoryItem<'a> {
+    id: &'a str,
+    /// Position in the full inventory (0-119), stable across requests and filters.
+    position: usize,
+    text: &'a str,
+    domain: &'static str,
+    facet: u8,
+    facet_name: &'static str,
+    /// Whether agreeing lowers the score (`minus`) rather than raising it (`plus`).
+    keyed: &'static str,
+}
+
+/// A page of inventory items after filtering.
+#[derive(Debug, Serialize)]
+struct InventoryPage<'a> {
+    lang: &'a str,
+    /// Labels of answer values 1 to 5.
+    scale: &'a [String; 5],
+    /// Number of items matching the filters, across all pages.
+    total: usize,
+    offset: usize,
+    limit: usize,
+    /// Ordering of `items`; always `position`.
+    order: &'static str,
+    items: Vec<InventoryItem<'a>>,
+}
+
+/// Header carrying a key for [`summary_handler`].
+const API_KEY_HEADER: &str = "x-api-key";
+
+/// Header carrying the access or owner token of a protected result.
+const RESULT_KEY_HEADER: &str = "x-result-key";
+
+/// Scores of a shared result for `GET /api/v1/results/:id/summary`.
+#[derive(Debug, Serialize)]
+struct ResultSummary<'a> {
+    id: &'a str,
+    lang: &'a str,
+    /// Unix seconds.
+    created_at: i64,
+    /// Unix seconds after which the result is gone, if it expires.
+    expires_at: Option<i64>,
+    /// Five-letter archetype code, e.g. `SCOAI`.
+    archetype: Option<String>,
+    /// Number of saved results the percentiles are relative to, if there are enough.
+    norm_sample_size: Option<usize>,
+    domains: Vec<DomainSummary>,
+}
+
+/// One domain of a [`ResultSummary`].
+#[derive(Debug, Serialize)]
+struct DomainSummary {
+    code: &'static str,
+    name: &'static str,
+    raw: u8,
+    /// Position in the score range (0-100).
+    percentage: f32,
+    /// `low`, `neutral` or `high`.
+    level: &'static str,
+    /// Share of saved results scoring lower (0-100), if there are enough.
+    percentile: Option<f32>,
+}
+
+fn error_response(status: StatusCode, message: String) -> Response {
+    (status, Json(json!({ "error": message }))).into_response()
+}
+
+/// `GET /api/v1/inventory/:lang?domain=N&facet=1&offset=0&limit=24`
+///
+/// Items are always in inventory order; `position` lets clients merge pages or
+/// filtered subsets back into the canonical order.
+pub async fn inventory_handler(
+    Path(lang): Path<String>,
+    Query(query): Query<InventoryQuery>,
+) -> Response {
+    let Some(inventory) = crate::inventory::inventory(&lang) else {
+        return error_response(
+            StatusCode::NOT_FOUND,
+            format!("Unsupported language: {lang}"),
+        );
+    };
+
+    let domain = match query.domain.as_deref().map(Domain::from_code) {
+        Some(None) => {
+            return error_response(
+                StatusCode::BAD_REQUEST,
+                "domain must be one of N, E, O, A, C".to_string(),
+            );
+        }
+        Some(domain) => domain,
+        None => None,
+    };
+    if query.facet.is_some_and(|f| !(1..=6).contains(&f)) {
+        return error_response(
+            StatusCode::BAD_REQUEST,
+            "facet must be between 1 and 6".to_string(),
+        );
+    }
+    let limit = query.limit.unwrap_or(MAX_PAGE_SIZE);
+    if limit == 0 || limit > MAX_PAGE_SIZE {
+        return error_response(
+            StatusCode::BAD_REQUEST,
+            format!("limit must be between 1 and {MAX_PAGE_SIZE}"),
+        );
+    }
+
+    let matching: Vec<_> = inventory
+        .questions()
+        .iter()
+        .enumerate()
+        .filter(|(_, q)| domain.is_none_or(|d| q.domain == d))
+        .filter(|(_, q)| query.facet.is_none_or(|f| q.facet_index == f))
+        .collect();
+
+    let items = matching
+        .iter()
+        .skip(query.offset)
+        .take(limit)
+        .map(|(position, q)| InventoryItem {
+            id: &q.id,
+            position: *position,
+            text: &q.text,
+            domain: q.domain.code(),
+            facet: q.facet_index,
+            facet_name: q.domain.facets()[usize::from(q.facet_index) - 1].name(),
+            keyed: if q.reversed { "minus" } else { "plus" },
+        })
+        .collect();
+
+    Json(InventoryPage {
+        lang: inventory.lang(),
+        scale: inventory.scale_labels(),
+        total: matching.len(),
+        offset: query.offset,
+        limit,
+        order: "position",
+        items,
+    })
+    .into_response()
+}
+
+/// `GET /api/v1/results/:id/summary`
+///
+/// Domain scores of a shared result, without notes, analysis or demographics. Protected
+/// results are not found unless their access or owner token is sent in `X-Result-Key`,
+/// and nothing is found unless `summary_api` is enabled.
+pub async fn summary_handler(
+    Path(id): Path<String>,
+    Extension(CurrentTenant(tenant)): Extension<CurrentTenant>,
+    headers: HeaderMap,
+) -> Response {
+    let Some(config) = summary_config() else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+    let mut response = summary_response(&id, tenant, &headers, config).await;
+    add_cors_headers(&mut response, &headers, config);
+    response
+}
+
+/// `OPTIONS /api/v1/results/:id/summary`, the CORS preflight of [`summary_handler`].
+pub async fn summary_preflight_handler(headers: HeaderMap) -> Response {
+    let Some(config) = summary_config() else {
+        return StatusCode::NOT_FOUND.into_response();
+    };
+    let mut response = StatusCode::NO_CONTENT.into_response();
+    add_cors_headers(&mut response, &headers, config);
+    if response.headers().contains_key(ACCESS_CONTROL_ALLOW_ORIGIN) {
+        let response_headers = response.headers_mut();
+        response_headers.insert(
+            ACCESS_CONTROL_ALLOW_METHODS,
+            HeaderValue::from_static("GET"),
+        );
+        response_headers.insert(
+            ACCESS_CONTROL_ALLOW_HEADERS,
+            HeaderValue::from_static("x-api-key, x-result-key"),
+        );
+        response_headers.insert(ACCESS_CONTROL_MAX_AGE, HeaderValue::from_static("86400"));
+    }
+    response
+}
+
+/// The summary API's configuration, if it is enabled.
+fn summary_config() -> Option<&'static SummaryApiConfig> {
+    get_config()
+        .ok()
+        .map(|config| &config.summary_api)
+        .filter(|summary| summary.enabled)
+}
+
+async fn summary_response(
+    id: &str,
+    tenant: Option<&TenantConfig>,
+    headers: &HeaderMap,
+    config: &SummaryApiConfig,
+) -> Response {
+    if let Some(var) = &config.api_keys_env {
+        let key = headers.get(API_KEY_HEADER).and_then(|h| h.to_str().ok());
+        if !key.is_some_and(|key| is_valid_api_key(var, key)) {
+            return error_response(
+                StatusCode::UNAUTHORIZED,
+                "missing or invalid API key".to_string(),
+            );
+        }
+    }
+
+    let namespace = tenant.map_or("", |t| t.id.as_str());
+    let result = match db::get_result(id, namespace).await {
+        Ok(Some(result))
+            if result
+                .is_unlocked_by(headers.get(RESULT_KEY_HEADER).and_then(|h| h.to_str().ok())) =>
+        {
+            result
+        }
+        // Locked results look missing, so their ids can't be probed
+        Ok(_) => return error_response(StatusCode::NOT_FOUND, "result not found".to_string()),
+        Err(e) => {
+            tracing::error!(result_id = %id, "Failed to load result for summary: {e}");
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+    // Percentiles are only a nicety: a failed aggregate leaves them out
+    let all_results = db::NormFilter {
+        tenant: namespace.to_string(),
+        ..db::NormFilter::default()
+    };
+    let norms = match db::score_distribution(&all_results).await {
+        Ok(distribution) => distribution.filter(|d| d.sample_size >= MIN_NORM_SAMPLE),
+        Err(e) => {
+            tracing::warn!("Failed to load norms for summary: {e}");
+            None
+        }
+    };
+
+    let domains = result
+        .profile
+        .domains
+        .iter()
+        .map(|score| DomainSummary {
+            code: score.domain.code(),
+            name: score.domain.name(),
+            raw: score.raw,
+            percentage: score.percentage(),
+            level: match score.level {
+                ScoreLevel::Low => "low",
+                ScoreLevel::Neutral => "neutral",
+                ScoreLevel::High => "high",
+            },
+            percentile: norms
+                .as_ref()
+                .and_then(|n| n.percentile(score.domain, score.percentage())),
+        })
+        .collect();
+    Json(ResultSummary {
+        id: &result.id,
+        lang: &result.lang,
+        created_at: result.created_at,
+        expires_at: result.expires_at,
+        archetype: result.profile.archetype().map(|a| a.code()),
+        norm_sample_size: norms.as_ref().map(|n| n.sample_size),
+        domains,
+    })
+    .into_response()
+}
+
+/// Whether `key` is one of the comma-separated keys in the environment variable `var`.
+///
+/// With the variable unset or empty no key is valid, so a misconfigured deployment
+/// doesn't end up open.
+fn is_valid_api_key(var: &str, key: &str) -> bool {
+    let Ok(keys) = std::env::var(var) else {
+        return false;
+    };
+    // Every key is compared, so the time taken doesn't depend on which one matched
+    keys.split(',')
+        .map(str::trim)
+        .filter(|k| !k.is_empty())
+        .fold(false, |found, expected| found | tokens_match(expected, key))
+}
+
+/// Allow the request's origin to read `response` if it is in `allowed_origins`.
+fn add_cors_headers(response: &mut Response, headers: &HeaderMap, config: &SummaryApiConfig) {
+    let response_headers = response.headers_mut();
+    response_headers.insert(VARY, HeaderValue::from_static("Origin"));
+    let Some(origin) = headers.get(ORIGIN) else {
+        return;
+    };
+    if origin.to_str().is_ok_and(|o| config.allows_origin(o)) {
+        response_headers.insert(ACCESS_CONTROL_ALLOW_ORIGIN, origin.clone());
+    }
+}
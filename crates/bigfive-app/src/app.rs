@@ -3,13 +3,42 @@
 use leptos::prelude::*;
 use leptos_meta::{Link, Meta, MetaTags, Stylesheet, Title, provide_meta_context};
 use leptos_router::{
+    SsrMode,
     components::{Outlet, ParentRoute, Route, Router, Routes},
+    hooks::use_location,
     path,
 };
 
-use crate::components::{HomePage, ResultsPage, TestPage};
+use crate::branding::branding;
+use crate::components::{
+    AboutPage, AdminPage, AppFooter, AppHeader, ErrorPage, HomePage, ImportPage, NotFoundPage,
+    ResultsPage, StatsPage, TestPage, TraitPage,
+};
 use crate::i18n::I18nProvider;
 
+/// Site description, used on every page except shared results.
+const DEFAULT_DESCRIPTION: &str = "Take the Big Five personality test (IPIP-NEO-120) and get AI-powered insights about your personality.";
+
+/// Header carrying the per-request id assigned by the server middleware.
+pub const REQUEST_ID_HEADER: &str = "x-request-id";
+
+/// Request id of the request being rendered (SSR only).
+fn current_request_id() -> Option<String> {
+    #[cfg(feature = "ssr")]
+    {
+        use_context::<axum::http::request::Parts>()?
+            .headers
+            .get(REQUEST_ID_HEADER)?
+            .to_str()
+            .ok()
+            .map(str::to_string)
+    }
+    #[cfg(not(feature = "ssr"))]
+    {
+        None
+    }
+}
+
 /// Shell function for SSR.
 pub fn shell(options: LeptosOptions) -> impl IntoView {
     // Inline script to apply theme before render (prevents flash)
@@ -24,12 +53,41 @@ pub fn shell(options: LeptosOptions) -> impl IntoView {
         })();
     "#;
 
+    // Exposed to the client so error screens can show a reference for bug reports
+    let request_id = current_request_id().map(|id| view! { <meta name="request-id" content=id /> });
+
+    // Read in the browser by `branding::branding()`, `inventory::inventory()` and
+    // `translations::override_text()`; the accent
+    // recolors every indigo class
+    #[cfg(feature = "ssr")]
+    let branding = {
+        use crate::branding::{BRANDING_ELEMENT_ID, accent_color, accent_css, branding_json};
+        use crate::inventory::{OVERRIDES_ELEMENT_ID, overrides_json};
+        use crate::translations::{TRANSLATIONS_ELEMENT_ID, translations_json};
+        view! {
+            <script id=BRANDING_ELEMENT_ID type="application/json" inner_html=branding_json()></script>
+            {accent_color().map(|accent| view! { <style inner_html=accent_css(accent)></style> })}
+            {overrides_json()
+                .map(|json| {
+                    view! { <script id=OVERRIDES_ELEMENT_ID type="application/json" inner_html=json></script> }
+                })}
+            {translations_json()
+                .map(|json| {
+                    view! { <script id=TRANSLATIONS_ELEMENT_ID type="application/json" inner_html=json></script> }
+                })}
+        }
+    };
+    #[cfg(not(feature = "ssr"))]
+    let branding = ();
+
     view! {
         <!DOCTYPE html>
         <html lang="en">
             <head>
                 <meta charset="utf-8" />
                 <meta name="viewport" content="width=device-width, initial-scale=1" />
+                {request_id}
+                {branding}
                 <script inner_html=theme_script></script>
                 <AutoReload options=options.clone() />
                 <HydrationScripts options />
@@ -42,18 +100,49 @@ pub fn shell(options: LeptosOptions) -> impl IntoView {
     }
 }
 
-/// Layout wrapper that provides i18n context.
+/// Page frame that provides i18n context, the page background and the shared header/footer.
 #[component]
-fn LocaleLayout() -> impl IntoView {
+fn PageFrame(children: Children) -> impl IntoView {
     view! {
         <I18nProvider>
-            <main class="min-h-screen bg-gray-50 dark:bg-gray-900 transition-colors duration-300">
-                <Outlet />
-            </main>
+            <div class="min-h-screen flex flex-col bg-gray-50 dark:bg-gray-900 transition-colors duration-300">
+                <AppHeader />
+                <main class="flex-1">{children()}</main>
+                <AppFooter />
+            </div>
         </I18nProvider>
     }
 }
 
+/// Layout wrapper for locale routes, with an error boundary around the page.
+#[component]
+fn LocaleLayout() -> impl IntoView {
+    // Shared results set their own description from the stored profile
+    let location = use_location();
+    let shared_result = move || location.pathname.with(|path| path.contains("/results/"));
+
+    view! {
+        <Show when=move || !shared_result()>
+            <Meta name="description" content=DEFAULT_DESCRIPTION />
+        </Show>
+        <PageFrame>
+            <ErrorBoundary fallback=|errors| view! { <ErrorPage errors /> }>
+                <Outlet />
+            </ErrorBoundary>
+        </PageFrame>
+    }
+}
+
+/// Router fallback: localized 404 page.
+#[component]
+fn NotFound() -> impl IntoView {
+    view! {
+        <PageFrame>
+            <NotFoundPage />
+        </PageFrame>
+    }
+}
+
 /// Main application component.
 #[component]
 pub fn App() -> impl IntoView {
@@ -62,21 +151,21 @@ pub fn App() -> impl IntoView {
     view! {
         <Stylesheet id="leptos" href="/pkg/bigfive-app.css" />
         <Link rel="icon" type_="image/x-icon" href="/favicon.ico" />
-        <Meta
-            name="description"
-            content="Take the Big Five personality test (IPIP-NEO-120) and get AI-powered insights about your personality."
-        />
-
-        <Title text="Big Five Personality Test" />
+        <Title text=branding().site_name.clone().unwrap_or_else(|| "Big Five Personality Test".to_string()) />
 
         <Router>
-            <Routes fallback=|| "Page not found.".into_view()>
+            <Routes fallback=NotFound>
                 // English routes
                 <ParentRoute path=path!("/en") view=LocaleLayout>
                     <Route path=path!("") view=HomePage />
                     <Route path=path!("test") view=TestPage />
                     <Route path=path!("results") view=ResultsPage />
-                    <Route path=path!("results/:id") view=ResultsPage />
+                    <Route path=path!("results/:id") view=ResultsPage ssr=SsrMode::Async />
+                    <Route path=path!("about") view=AboutPage />
+                    <Route path=path!("import") view=ImportPage />
+                    <Route path=path!("stats") view=StatsPage />
+                    <Route path=path!("traits/:domain") view=TraitPage />
+                    <Route path=path!("admin") view=AdminPage />
                 </ParentRoute>
 
                 // Russian routes
@@ -84,7 +173,12 @@ pub fn App() -> impl IntoView {
                     <Route path=path!("") view=HomePage />
                     <Route path=path!("test") view=TestPage />
                     <Route path=path!("results") view=ResultsPage />
-                    <Route path=path!("results/:id") view=ResultsPage />
+                    <Route path=path!("results/:id") view=ResultsPage ssr=SsrMode::Async />
+                    <Route path=path!("about") view=AboutPage />
+                    <Route path=path!("import") view=ImportPage />
+                    <Route path=path!("stats") view=StatsPage />
+                    <Route path=path!("traits/:domain") view=TraitPage />
+                    <Route path=path!("admin") view=AdminPage />
                 </ParentRoute>
 
                 // Root redirect to /en
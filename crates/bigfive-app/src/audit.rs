@@ -0,0 +1,108 @@
+//! Append-only log of who accessed or changed a saved result.
+//!
+//! With `[audit_log]` enabled, saving, opening, deleting and restoring a shared result (and
+//! moderating it on the admin page) adds a row with the action, the tenant and a hash of the
+//! client's IP. Addresses are hashed with the `AUDIT_IP_SALT` secret, so requests from one
+//! address can be matched up without storing the address itself. Rows are never updated;
+//! the cleanup job drops them after `retention_days`.
+
+use std::sync::LazyLock;
+
+use leptos::prelude::use_context;
+use real::RealIp;
+use sha2::{Digest, Sha256};
+use tracing::warn;
+
+use crate::config::get_config;
+use crate::db;
+
+/// Bytes of the salted SHA-256 kept as the IP hash.
+const IP_HASH_BYTES: usize = 16;
+
+/// Secret mixed into IP hashes. Without `AUDIT_IP_SALT` a random one is used, so hashes
+/// only match within one server run.
+static IP_SALT: LazyLock<String> = LazyLock::new(|| {
+    std::env::var("AUDIT_IP_SALT")
+        .ok()
+        .filter(|s| !s.is_empty())
+        .unwrap_or_else(|| {
+            warn!("AUDIT_IP_SALT is not set, audit log IP hashes won't match across restarts");
+            uuid::Uuid::new_v4().to_string()
+        })
+});
+
+/// What happened to a saved result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AuditAction {
+    /// Shared by its owner.
+    Save,
+    /// Saved together with an AI analysis.
+    AnalysisAdded,
+    /// Opened through its link.
+    View,
+    /// Deleted by its owner.
+    Delete,
+    /// Deletion undone by its owner.
+    Restore,
+    /// Hidden by a moderator.
+    Unpublish,
+    /// Published again by a moderator.
+    Publish,
+    /// Permanently deleted by a moderator.
+    Purge,
+}
+
+impl AuditAction {
+    /// Name stored in the `action` column.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Save => "save",
+            Self::AnalysisAdded => "analysis_added",
+            Self::View => "view",
+            Self::Delete => "delete",
+            Self::Restore => "restore",
+            Self::Unpublish => "unpublish",
+            Self::Publish => "publish",
+            Self::Purge => "purge",
+        }
+    }
+}
+
+/// Whether accesses are being recorded.
+pub fn enabled() -> bool {
+    get_config().is_ok_and(|config| config.audit_log.enabled)
+}
+
+/// Log `action` on `result_id` for the request being handled.
+///
+/// A failed write is only logged: the audit trail never blocks the action itself.
+pub async fn record(action: AuditAction, result_id: &str) {
+    if !enabled() {
+        return;
+    }
+    let ip_hash = client_ip_hash();
+    let tenant = crate::tenancy::current_namespace();
+    if let Err(e) =
+        db::append_audit_entry(result_id, action.as_str(), ip_hash.as_deref(), tenant).await
+    {
+        warn!(error = %e, result_id, action = action.as_str(), "Failed to write audit log entry");
+    }
+}
+
+/// Salted hash of the request's client address, if it is known.
+fn client_ip_hash() -> Option<String> {
+    let ip = use_context::<axum::http::request::Parts>()?
+        .extensions
+        .get::<RealIp>()?
+        .0;
+    let hash = Sha256::new()
+        .chain_update(IP_SALT.as_bytes())
+        .chain_update(ip.to_string().as_bytes())
+        .finalize();
+    Some(
+        hash[..IP_HASH_BYTES]
+            .iter()
+            .map(|b| format!("{b:02x}"))
+            .collect(),
+    )
+}
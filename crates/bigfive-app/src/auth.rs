@@ -0,0 +1,30 @@
+//! Comparison of secrets: result keys, owner tokens, the admin token and API keys.
+
+use sha2::{Digest, Sha256};
+
+/// Whether `key` equals the secret `expected`, in constant time.
+///
+/// Both sides are hashed first, so the digests compared always have the same length and
+/// neither the secret's bytes nor its length can be learned from how long the check takes.
+pub fn tokens_match(expected: &str, key: &str) -> bool {
+    Sha256::digest(expected.as_bytes())
+        .iter()
+        .zip(&Sha256::digest(key.as_bytes()))
+        .fold(0u8, |acc, (a, b)| acc | (a ^ b))
+        == 0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_only_the_same_secret() {
+        assert!(tokens_match("secret", "secret"));
+        assert!(!tokens_match("secret", "Secret"));
+        assert!(!tokens_match("secret", "secre"));
+        assert!(!tokens_match("secret", "secrets"));
+        assert!(!tokens_match("secret", ""));
+        assert!(tokens_match("", ""));
+    }
+}
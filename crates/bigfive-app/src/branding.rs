@@ -0,0 +1,134 @@
+//! Site branding from the `[branding]` config section, or the tenant's own in multi-tenant
+//! mode.
+//!
+//! The SSR shell embeds the branding as JSON and overrides Tailwind's indigo palette (which
+//! every accent class uses) with the configured accent color. [`branding`] reads the config
+//! on the server and the embedded JSON in the browser, so both render the same markup.
+
+use serde::{Deserialize, Serialize};
+
+/// Id of the shell `<script>` element holding the branding JSON.
+pub const BRANDING_ELEMENT_ID: &str = "site-branding";
+
+/// Branding visible in components; unset fields keep the stock look.
+#[derive(Clone, Debug, Default, PartialEq, Serialize, Deserialize)]
+pub struct Branding {
+    pub site_name: Option<String>,
+    pub logo_url: Option<String>,
+    /// Footer links as (label, URL).
+    pub footer_links: Vec<(String, String)>,
+}
+
+/// Branding of the site being rendered.
+#[cfg(feature = "ssr")]
+pub fn branding() -> &'static Branding {
+    use std::collections::HashMap;
+
+    static DEFAULT: Branding = Branding {
+        site_name: None,
+        logo_url: None,
+        footer_links: Vec::new(),
+    };
+    // Keyed by tenant id, "" for the default site
+    static BRANDINGS: std::sync::OnceLock<HashMap<String, Branding>> = std::sync::OnceLock::new();
+
+    let brandings = BRANDINGS.get_or_init(|| {
+        let Ok(config) = crate::config::get_config() else {
+            return HashMap::new();
+        };
+        std::iter::once((String::new(), &config.branding))
+            .chain(
+                config
+                    .tenants
+                    .iter()
+                    .map(|t| (t.id.clone(), config.branding_for(Some(t)))),
+            )
+            .map(|(id, branding)| {
+                let branding = Branding {
+                    site_name: branding.site_name.clone(),
+                    logo_url: branding.logo_url.clone(),
+                    footer_links: branding
+                        .footer_links
+                        .iter()
+                        .map(|link| (link.label.clone(), link.url.clone()))
+                        .collect(),
+                };
+                (id, branding)
+            })
+            .collect()
+    });
+    brandings
+        .get(crate::tenancy::current_namespace())
+        .unwrap_or(&DEFAULT)
+}
+
+/// Branding of this site, as embedded in the page by the server.
+#[cfg(not(feature = "ssr"))]
+pub fn branding() -> &'static Branding {
+    static BRANDING: std::sync::OnceLock<Branding> = std::sync::OnceLock::new();
+    BRANDING.get_or_init(load_branding)
+}
+
+#[cfg(not(feature = "ssr"))]
+fn load_branding() -> Branding {
+    #[cfg(target_arch = "wasm32")]
+    {
+        leptos::prelude::document()
+            .get_element_by_id(BRANDING_ELEMENT_ID)
+            .and_then(|e| e.text_content())
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default()
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        Branding::default()
+    }
+}
+
+/// Branding JSON for the shell, safe to place inside a `<script>` element.
+#[cfg(feature = "ssr")]
+pub fn branding_json() -> String {
+    serde_json::to_string(branding())
+        .unwrap_or_default()
+        .replace('<', "\\u003c")
+}
+
+/// Configured accent color, if any.
+#[cfg(feature = "ssr")]
+pub fn accent_color() -> Option<&'static str> {
+    crate::config::get_config()
+        .ok()?
+        .branding_for(crate::tenancy::current_tenant())
+        .accent_color
+        .as_deref()
+}
+
+/// CSS overriding the indigo palette with `accent` (`#rrggbb`), lighter and darker
+/// shades mixed towards white and black.
+#[cfg(feature = "ssr")]
+pub fn accent_css(accent: &str) -> String {
+    const SHADES: &[(u16, &str, u8)] = &[
+        (50, "white", 90),
+        (100, "white", 80),
+        (200, "white", 60),
+        (300, "white", 40),
+        (400, "white", 20),
+        (500, "white", 10),
+        (600, "black", 0),
+        (700, "black", 15),
+        (800, "black", 30),
+        (900, "black", 45),
+    ];
+
+    let vars: String = SHADES
+        .iter()
+        .map(|&(shade, towards, amount)| {
+            if amount == 0 {
+                format!("--color-indigo-{shade}:{accent};")
+            } else {
+                format!("--color-indigo-{shade}:color-mix(in oklab,{accent},{towards} {amount}%);")
+            }
+        })
+        .collect();
+    format!(":root{{{vars}}}")
+}
@@ -0,0 +1,211 @@
+//! Calendar (.ics) reminders to retake the test.
+//!
+//! `GET /api/v1/results/{id}/reminder.ics?months=6` answers with a one-event calendar file
+//! for the day `months` after the shared result was saved, linking back to the test and to
+//! the result for comparison. Nothing is stored: the file is built from the saved result on
+//! each request, and the user's calendar app does the reminding.
+
+use axum::extract::{Extension, Path, Query};
+use axum::http::header::{CONTENT_DISPOSITION, CONTENT_TYPE, HOST};
+use axum::http::{HeaderMap, StatusCode};
+use axum::response::{IntoResponse, Response};
+use serde::Deserialize;
+
+use crate::components::REMINDER_MONTHS;
+use crate::db;
+use crate::i18n::{self, Locale};
+use crate::tenancy::CurrentTenant;
+
+/// Query parameters of `GET /api/v1/results/:id/reminder.ics`.
+#[derive(Debug, Deserialize)]
+pub struct ReminderQuery {
+    months: u32,
+}
+
+/// `GET /api/v1/results/:id/reminder.ics?months=6`
+pub async fn reminder_handler(
+    Path(id): Path<String>,
+    Query(query): Query<ReminderQuery>,
+    Extension(CurrentTenant(tenant)): Extension<CurrentTenant>,
+    headers: HeaderMap,
+) -> Response {
+    if !REMINDER_MONTHS.contains(&query.months) {
+        return (StatusCode::BAD_REQUEST, "months must be 6, 9 or 12").into_response();
+    }
+    let namespace = tenant.map_or("", |t| t.id.as_str());
+    let result = match db::get_result(&id, namespace).await {
+        Ok(Some(result)) => result,
+        Ok(None) => return StatusCode::NOT_FOUND.into_response(),
+        Err(e) => {
+            tracing::error!(result_id = %id, "Failed to load result for reminder: {e}");
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+    let Some(host) = headers.get(HOST).and_then(|h| h.to_str().ok()) else {
+        return StatusCode::BAD_REQUEST.into_response();
+    };
+    let scheme = match headers
+        .get("x-forwarded-proto")
+        .and_then(|h| h.to_str().ok())
+    {
+        Some("http") => "http",
+        _ => "https",
+    };
+    let locale = Locale::ALL
+        .iter()
+        .copied()
+        .find(|l| l.code() == result.lang)
+        .unwrap_or_default();
+
+    let ics = reminder_ics(&ReminderEvent {
+        result_id: &result.id,
+        saved_at: result.created_at,
+        months: query.months,
+        base_url: &format!("{scheme}://{host}{}", locale.path_prefix()),
+        locale,
+        now: unix_now(),
+    });
+    (
+        [
+            (CONTENT_TYPE, "text/calendar; charset=utf-8"),
+            (
+                CONTENT_DISPOSITION,
+                "attachment; filename=\"bigfive-retest.ics\"",
+            ),
+        ],
+        ics,
+    )
+        .into_response()
+}
+
+/// What goes into a reminder file.
+struct ReminderEvent<'a> {
+    result_id: &'a str,
+    /// When the result was saved (Unix seconds).
+    saved_at: i64,
+    months: u32,
+    /// Site URL including the locale prefix, without a trailing slash.
+    base_url: &'a str,
+    locale: Locale,
+    /// Unix seconds, for the `DTSTAMP`.
+    now: i64,
+}
+
+/// iCalendar text with one all-day event and a morning alert.
+fn reminder_ics(event: &ReminderEvent) -> String {
+    let (year, month, day) = civil_from_days(event.saved_at.div_euclid(86_400));
+    let (year, month, day) = add_months((year, month, day), event.months);
+    let start = days_from_civil(year, month, day);
+    let date = |days: i64| {
+        let (y, m, d) = civil_from_days(days);
+        format!("{y:04}{m:02}{d:02}")
+    };
+    let (y, m, d) = civil_from_days(event.now.div_euclid(86_400));
+    let secs = event.now.rem_euclid(86_400);
+    let stamp = format!(
+        "{y:04}{m:02}{d:02}T{:02}{:02}{:02}Z",
+        secs / 3600,
+        secs / 60 % 60,
+        secs % 60
+    );
+
+    let summary = i18n::t(event.locale, "reminder_event_summary");
+    let description = format!(
+        "{}\n\n{}: {}/test\n{}: {}/results/{}",
+        i18n::t(event.locale, "reminder_event_description"),
+        i18n::t(event.locale, "reminder_event_test_link"),
+        event.base_url,
+        i18n::t(event.locale, "reminder_event_result_link"),
+        event.base_url,
+        event.result_id,
+    );
+
+    let lines = [
+        "BEGIN:VCALENDAR".to_string(),
+        "VERSION:2.0".to_string(),
+        "PRODID:-//Big Five Tester//Retest reminder//EN".to_string(),
+        "CALSCALE:GREGORIAN".to_string(),
+        "METHOD:PUBLISH".to_string(),
+        "BEGIN:VEVENT".to_string(),
+        format!("UID:retest-{}-{}m@bigfive", event.result_id, event.months),
+        format!("DTSTAMP:{stamp}"),
+        format!("DTSTART;VALUE=DATE:{}", date(start)),
+        format!("DTEND;VALUE=DATE:{}", date(start + 1)),
+        format!("SUMMARY:{}", escape_text(summary)),
+        format!("DESCRIPTION:{}", escape_text(&description)),
+        format!("URL:{}/test", event.base_url),
+        "TRANSP:TRANSPARENT".to_string(),
+        "BEGIN:VALARM".to_string(),
+        "ACTION:DISPLAY".to_string(),
+        // 09:00 on the day of the all-day event
+        "TRIGGER:PT9H".to_string(),
+        format!("DESCRIPTION:{}", escape_text(summary)),
+        "END:VALARM".to_string(),
+        "END:VEVENT".to_string(),
+        "END:VCALENDAR".to_string(),
+    ];
+    lines.iter().map(|line| fold_line(line)).collect()
+}
+
+/// Escape text for an iCalendar property value.
+fn escape_text(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+/// Fold a content line to 75 octets per line, as iCalendar requires, ending it with CRLF.
+fn fold_line(line: &str) -> String {
+    let mut folded = String::with_capacity(line.len() + 8);
+    let mut width = 0;
+    for c in line.chars() {
+        if width + c.len_utf8() > 75 {
+            folded.push_str("\r\n ");
+            width = 1;
+        }
+        folded.push(c);
+        width += c.len_utf8();
+    }
+    folded.push_str("\r\n");
+    folded
+}
+
+/// The date `months` later, moved back to the month's last day if it has fewer days.
+fn add_months((year, month, day): (i64, i64, i64), months: u32) -> (i64, i64, i64) {
+    let index = year * 12 + (month - 1) + i64::from(months);
+    let (year, month) = (index.div_euclid(12), index.rem_euclid(12) + 1);
+    let days_in_month =
+        days_from_civil(year + month / 12, month % 12 + 1, 1) - days_from_civil(year, month, 1);
+    (year, month, day.min(days_in_month))
+}
+
+/// Days since 1970-01-01 of a civil date (Howard Hinnant's algorithm).
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let y = if month <= 2 { year - 1 } else { year };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let doy = (153 * (if month > 2 { month - 3 } else { month + 9 }) + 2) / 5 + day - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// Civil date of a day count since 1970-01-01, the inverse of [`days_from_civil`].
+pub(crate) fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z - era * 146_097;
+    let yoe = (doe - doe / 1460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+fn unix_now() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_or(0, |d| d.as_secs() as i64)
+}
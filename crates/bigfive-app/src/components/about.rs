@@ -0,0 +1,62 @@
+//! About page with background on the test and a short FAQ.
+
+use leptos::prelude::*;
+use leptos_router::components::A;
+
+use crate::i18n::use_i18n;
+
+/// FAQ entries as (question key, answer key) pairs.
+const FAQ: &[(&str, &str)] = &[
+    ("faq_accuracy_q", "faq_accuracy_a"),
+    ("faq_time_q", "faq_time_a"),
+    ("faq_data_q", "faq_data_a"),
+    ("faq_ai_q", "faq_ai_a"),
+    ("faq_share_q", "faq_share_a"),
+];
+
+/// About page: what the test is, how it's scored, and frequently asked questions.
+#[component]
+pub fn AboutPage() -> impl IntoView {
+    let i18n = use_i18n();
+
+    view! {
+        <div class="max-w-4xl mx-auto px-4 py-8">
+            <div class="bg-white dark:bg-gray-800 rounded-lg shadow-md p-8 transition-colors duration-300">
+                <h1 class="text-3xl font-bold text-gray-900 dark:text-white mb-6">{move || i18n.t("about_title")}</h1>
+                <div class="space-y-4 text-gray-600 dark:text-gray-300 leading-relaxed mb-10">
+                    <p>{move || i18n.t("about_intro")}</p>
+                    <p>{move || i18n.t("about_scoring")}</p>
+                    <p>{move || i18n.t("about_ai")}</p>
+                </div>
+
+                <h2 id="faq" class="text-2xl font-semibold text-gray-800 dark:text-gray-100 mb-4 scroll-mt-4">
+                    {move || i18n.t("nav_faq")}
+                </h2>
+                <div class="divide-y divide-gray-200 dark:divide-gray-700 mb-8">
+                    {FAQ
+                        .iter()
+                        .map(|&(question, answer)| {
+                            view! {
+                                <details class="py-4 group">
+                                    <summary class="cursor-pointer font-medium text-gray-800 dark:text-gray-100">
+                                        {move || i18n.t(question)}
+                                    </summary>
+                                    <p class="mt-2 text-gray-600 dark:text-gray-300 leading-relaxed">
+                                        {move || i18n.t(answer)}
+                                    </p>
+                                </details>
+                            }
+                        })
+                        .collect_view()}
+                </div>
+
+                <A
+                    href=move || format!("{}/test", i18n.get_locale().path_prefix())
+                    attr:class="inline-block w-full sm:w-auto text-center px-8 py-3 bg-indigo-600 dark:bg-indigo-500 text-white font-semibold rounded-lg hover:bg-indigo-700 dark:hover:bg-indigo-600 transition-colors"
+                >
+                    {move || i18n.t("home_start_button")}
+                </A>
+            </div>
+        </div>
+    }
+}
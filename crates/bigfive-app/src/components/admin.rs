@@ -0,0 +1,1184 @@
+//! Moderation page for saved results.
+//!
+//! Every server function takes the admin token, checked against the `ADMIN_TOKEN`
+//! environment variable; with the variable unset the page is disabled. The token is kept
+//! in sessionStorage, so it is forgotten when the tab closes.
+
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use serde::{Deserialize, Serialize};
+
+use crate::components::dates::{Timestamp, format_date_time};
+use crate::i18n::use_i18n;
+
+#[cfg(target_arch = "wasm32")]
+const STORAGE_KEY_ADMIN_TOKEN: &str = crate::storage::storage_key!("admin_token");
+
+/// Search criteria; empty fields match everything.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct AdminFilter {
+    /// Full id or its beginning.
+    pub id: String,
+    pub lang: String,
+    /// First day to include, `YYYY-MM-DD` (UTC).
+    pub from: String,
+    /// Last day to include, `YYYY-MM-DD` (UTC).
+    pub to: String,
+}
+
+/// A saved result as seen by a moderator.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(not(any(target_arch = "wasm32", feature = "ssr")), allow(dead_code))]
+pub struct AdminResult {
+    pub id: String,
+    pub lang: String,
+    /// Unix seconds.
+    pub created_at: i64,
+    /// Unix seconds.
+    pub expires_at: Option<i64>,
+    pub nickname: Option<String>,
+    pub user_context: Option<String>,
+    pub ai_analysis: Option<String>,
+    pub prompt_version: Option<String>,
+    /// Shared with an access key.
+    pub protected: bool,
+    pub unpublished: bool,
+    /// When the owner deleted it (Unix seconds), pending purge.
+    pub deleted_at: Option<i64>,
+}
+
+/// One finished analysis job.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(not(any(target_arch = "wasm32", feature = "ssr")), allow(dead_code))]
+pub struct AdminAnalysisEntry {
+    pub model_id: String,
+    pub lang: String,
+    pub success: bool,
+    /// Unix seconds.
+    pub created_at: i64,
+}
+
+/// Redacted provider calls of a failed analysis job.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(not(any(target_arch = "wasm32", feature = "ssr")), allow(dead_code))]
+pub struct AdminProviderLog {
+    pub job_id: String,
+    pub model_id: String,
+    /// Error the job failed with.
+    pub error: String,
+    pub calls: Vec<AdminProviderCall>,
+    /// Unix seconds.
+    pub created_at: i64,
+}
+
+/// One model call of a failed job, with prompts and response already redacted.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(not(any(target_arch = "wasm32", feature = "ssr")), allow(dead_code))]
+pub struct AdminProviderCall {
+    pub provider: String,
+    pub model: String,
+    pub max_tokens: u32,
+    pub system: Option<String>,
+    pub user: String,
+    pub response: Option<String>,
+    pub error: Option<String>,
+    pub elapsed_ms: u64,
+}
+
+/// What the cleanup job removed since the server started.
+#[derive(Clone, Copy, Debug, Default, Serialize, Deserialize)]
+#[cfg_attr(not(any(target_arch = "wasm32", feature = "ssr")), allow(dead_code))]
+pub struct AdminCleanupTotals {
+    pub runs: u64,
+    /// Unix seconds.
+    pub last_run_at: Option<i64>,
+    pub deleted_results: u64,
+    pub provider_logs: u64,
+    pub sessions: u64,
+    pub jobs: u64,
+    pub feedback: u64,
+    pub audit_entries: u64,
+    pub quota_counters: u64,
+}
+
+/// One access or change of a result from the audit log.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(not(any(target_arch = "wasm32", feature = "ssr")), allow(dead_code))]
+pub struct AdminAuditEntry {
+    pub action: String,
+    /// Salted hash of the client address; equal hashes mean the same address.
+    pub ip_hash: Option<String>,
+    /// Unix seconds.
+    pub created_at: i64,
+}
+
+/// Question timing totals of one interface language.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(not(any(target_arch = "wasm32", feature = "ssr")), allow(dead_code))]
+pub struct AdminQuestionReport {
+    pub lang: String,
+    /// Most reports any question got, roughly the number of tests that shared timings.
+    pub tests: i64,
+    /// Average seconds per question over all questions.
+    pub avg_secs: f64,
+    /// Share of reports in which the respondent went back, over all questions.
+    pub revisit_rate: f64,
+    /// Questions ordered from the most to the least unusual.
+    pub questions: Vec<AdminQuestionStat>,
+}
+
+/// Timing of one question compared with the rest of its language.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(not(any(target_arch = "wasm32", feature = "ssr")), allow(dead_code))]
+pub struct AdminQuestionStat {
+    pub question_id: String,
+    /// Question text as currently shown, overrides included.
+    pub text: String,
+    pub responses: i64,
+    pub avg_secs: f64,
+    pub revisit_rate: f64,
+    /// Well above the language's average time or revisit rate.
+    pub flagged: bool,
+}
+
+#[cfg(feature = "ssr")]
+impl From<crate::db::ProviderLog> for AdminProviderLog {
+    fn from(log: crate::db::ProviderLog) -> Self {
+        Self {
+            job_id: log.job_id,
+            model_id: log.model_id,
+            error: log.error,
+            calls: log
+                .calls
+                .into_iter()
+                .map(|c| AdminProviderCall {
+                    provider: c.provider,
+                    model: c.model,
+                    max_tokens: c.max_tokens,
+                    system: c.system,
+                    user: c.user,
+                    response: c.response,
+                    error: c.error,
+                    elapsed_ms: c.elapsed_ms,
+                })
+                .collect(),
+            created_at: log.created_at,
+        }
+    }
+}
+
+/// Reject the request unless `token` matches `ADMIN_TOKEN`.
+#[cfg(feature = "ssr")]
+fn check_admin_token(token: &str) -> Result<(), ServerFnError> {
+    let expected = std::env::var("ADMIN_TOKEN")
+        .ok()
+        .filter(|t| !t.is_empty())
+        .ok_or_else(|| ServerFnError::new("Admin access is disabled"))?;
+
+    if !crate::auth::tokens_match(&expected, token) {
+        tracing::warn!("Rejected admin request with an invalid token");
+        return Err(ServerFnError::new("Invalid admin token"));
+    }
+    Ok(())
+}
+
+/// Unix seconds at the start of a `YYYY-MM-DD` day (UTC).
+#[cfg(feature = "ssr")]
+fn parse_day(date: &str) -> Result<i64, ServerFnError> {
+    let invalid = || ServerFnError::new(format!("Invalid date: {date}"));
+    let mut parts = date.splitn(3, '-').map(|p| p.parse::<i64>().ok());
+    let (Some(Some(y)), Some(Some(m)), Some(Some(d))) = (parts.next(), parts.next(), parts.next())
+    else {
+        return Err(invalid());
+    };
+    if !(1..=12).contains(&m) || !(1..=31).contains(&d) {
+        return Err(invalid());
+    }
+
+    // Days from civil date (Howard Hinnant's algorithm)
+    let y = if m <= 2 { y - 1 } else { y };
+    let era = y.div_euclid(400);
+    let yoe = y - era * 400;
+    let doy = (153 * (if m > 2 { m - 3 } else { m + 9 }) + 2) / 5 + d - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    Ok((era * 146_097 + doe - 719_468) * 86_400)
+}
+
+/// Search saved results, newest first. Includes expired, unpublished and deleted ones.
+#[server]
+pub async fn admin_search_results(
+    token: String,
+    filter: AdminFilter,
+) -> Result<Vec<AdminResult>, ServerFnError> {
+    use crate::db;
+
+    /// Maximum number of results returned by one search.
+    const SEARCH_LIMIT: u32 = 50;
+
+    check_admin_token(&token)?;
+
+    let non_empty = |s: &str| Some(s.trim()).filter(|s| !s.is_empty()).map(str::to_string);
+    let from = non_empty(&filter.from).map(|d| parse_day(&d)).transpose()?;
+    let to = non_empty(&filter.to)
+        .map(|d| parse_day(&d).map(|start| start + 86_400))
+        .transpose()?;
+    let id = non_empty(&filter.id);
+    let lang = non_empty(&filter.lang);
+
+    let results = db::search_results(
+        &db::ResultFilter {
+            id_prefix: id.as_deref(),
+            lang: lang.as_deref(),
+            created_from: from,
+            created_to: to,
+        },
+        SEARCH_LIMIT,
+    )
+    .await
+    .map_err(|e| ServerFnError::new(e.to_string()))?;
+
+    Ok(results
+        .into_iter()
+        .map(|r| AdminResult {
+            protected: r.access_token.is_some(),
+            id: r.id,
+            lang: r.lang,
+            created_at: r.created_at,
+            expires_at: r.expires_at,
+            nickname: r.nickname,
+            user_context: r.user_context,
+            ai_analysis: r.ai_analysis,
+            prompt_version: r.prompt_version,
+            unpublished: r.unpublished,
+            deleted_at: r.deleted_at,
+        })
+        .collect())
+}
+
+/// Hide a result from its shared link, or publish it again.
+#[server]
+pub async fn admin_set_unpublished(
+    token: String,
+    id: String,
+    unpublished: bool,
+) -> Result<bool, ServerFnError> {
+    use crate::audit::{self, AuditAction};
+    use crate::db;
+
+    check_admin_token(&token)?;
+    let updated = db::set_result_unpublished(&id, unpublished)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    if updated {
+        tracing::info!(result_id = %id, unpublished, "Changed result visibility");
+        let action = if unpublished {
+            AuditAction::Unpublish
+        } else {
+            AuditAction::Publish
+        };
+        audit::record(action, &id).await;
+    }
+    Ok(updated)
+}
+
+/// Permanently delete a result.
+#[server]
+pub async fn admin_purge_result(token: String, id: String) -> Result<bool, ServerFnError> {
+    use crate::audit::{self, AuditAction};
+    use crate::db;
+
+    check_admin_token(&token)?;
+    let deleted = db::purge_result(&id)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    if deleted {
+        tracing::info!(result_id = %id, "Purged results snapshot");
+        audit::record(AuditAction::Purge, &id).await;
+    }
+    Ok(deleted)
+}
+
+/// Most recent AI analyses, newest first.
+#[server]
+pub async fn admin_recent_analyses(
+    token: String,
+) -> Result<Vec<AdminAnalysisEntry>, ServerFnError> {
+    use crate::db;
+
+    /// Number of log entries shown under "recent analyses".
+    const RECENT_ANALYSES: u32 = 30;
+
+    check_admin_token(&token)?;
+    let entries = db::recent_analyses(RECENT_ANALYSES)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    Ok(entries
+        .into_iter()
+        .map(|e| AdminAnalysisEntry {
+            model_id: e.model_id,
+            lang: e.lang,
+            success: e.success,
+            created_at: e.created_at,
+        })
+        .collect())
+}
+
+/// Most recent provider logs of failed analyses, newest first.
+///
+/// Logs are only kept while `debug_provider_logs` is enabled.
+#[server]
+pub async fn admin_recent_provider_logs(
+    token: String,
+) -> Result<Vec<AdminProviderLog>, ServerFnError> {
+    use crate::db;
+
+    /// Number of logs shown under "provider logs".
+    const RECENT_PROVIDER_LOGS: u32 = 10;
+
+    check_admin_token(&token)?;
+    let logs = db::recent_provider_logs(RECENT_PROVIDER_LOGS)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    Ok(logs.into_iter().map(AdminProviderLog::from).collect())
+}
+
+/// Provider log of a failed analysis, by job id.
+#[server]
+pub async fn admin_find_provider_log(
+    token: String,
+    job_id: String,
+) -> Result<Option<AdminProviderLog>, ServerFnError> {
+    use crate::db;
+
+    check_admin_token(&token)?;
+    let log = db::get_provider_log(job_id.trim())
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    Ok(log.map(AdminProviderLog::from))
+}
+
+/// Counts of stale data removed by the cleanup job since the server started.
+#[server]
+pub async fn admin_cleanup_stats(token: String) -> Result<AdminCleanupTotals, ServerFnError> {
+    check_admin_token(&token)?;
+    let totals = crate::purge::cleanup_totals();
+    Ok(AdminCleanupTotals {
+        runs: totals.runs,
+        last_run_at: totals.last_run_at,
+        deleted_results: totals.deleted_results,
+        provider_logs: totals.provider_logs,
+        sessions: totals.sessions,
+        jobs: totals.jobs,
+        feedback: totals.feedback,
+        audit_entries: totals.audit_entries,
+        quota_counters: totals.quota_counters,
+    })
+}
+
+/// Audit log of a result, newest first.
+#[server]
+pub async fn admin_audit_log(
+    token: String,
+    id: String,
+) -> Result<Vec<AdminAuditEntry>, ServerFnError> {
+    use crate::db;
+
+    /// Entries shown per result.
+    const LISTED_ENTRIES: u32 = 50;
+
+    check_admin_token(&token)?;
+    let entries = db::audit_entries(&id, LISTED_ENTRIES)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    Ok(entries
+        .into_iter()
+        .map(|e| AdminAuditEntry {
+            action: e.action,
+            ip_hash: e.ip_hash,
+            created_at: e.created_at,
+        })
+        .collect())
+}
+
+/// Question timings per language, unusual questions first.
+#[server]
+pub async fn admin_question_timings(
+    token: String,
+) -> Result<Vec<AdminQuestionReport>, ServerFnError> {
+    use crate::db;
+
+    /// Questions listed per language.
+    const LISTED_QUESTIONS: usize = 15;
+    /// Reports a question needs before it can be flagged.
+    const MIN_RESPONSES: i64 = 10;
+    /// How far above the language's average a question has to be to get flagged.
+    const FLAG_RATIO: f64 = 1.5;
+
+    check_admin_token(&token)?;
+    let stats = db::question_stats()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+
+    let mut reports = Vec::new();
+    for lang in crate::i18n::Locale::ALL.iter().map(|l| l.code()) {
+        let rows: Vec<_> = stats
+            .iter()
+            .filter(|s| s.lang == lang && s.responses > 0)
+            .collect();
+        let Some(inventory) = crate::inventory::inventory(lang).filter(|_| !rows.is_empty()) else {
+            continue;
+        };
+
+        let responses: i64 = rows.iter().map(|s| s.responses).sum();
+        let avg_secs =
+            rows.iter().map(|s| s.total_ms).sum::<i64>() as f64 / 1000.0 / responses as f64;
+        let revisit_rate = rows.iter().map(|s| s.revisits).sum::<i64>() as f64 / responses as f64;
+
+        // Relative to the language average, so slower readers of one translation don't
+        // flag all of its items
+        let mut questions: Vec<(f64, AdminQuestionStat)> = rows
+            .iter()
+            .map(|s| {
+                let secs = s.total_ms as f64 / 1000.0 / s.responses as f64;
+                let revisits = s.revisits as f64 / s.responses as f64;
+                let time_ratio = if avg_secs > 0.0 { secs / avg_secs } else { 0.0 };
+                let revisit_ratio = if revisit_rate > 0.0 {
+                    revisits / revisit_rate
+                } else {
+                    0.0
+                };
+                let text = inventory
+                    .questions()
+                    .iter()
+                    .find(|q| q.id == s.question_id)
+                    .map(|q| q.text.clone())
+                    .unwrap_or_default();
+                let stat = AdminQuestionStat {
+                    question_id: s.question_id.clone(),
+                    text,
+                    responses: s.responses,
+                    avg_secs: secs,
+                    revisit_rate: revisits,
+                    flagged: s.responses >= MIN_RESPONSES
+                        && (time_ratio >= FLAG_RATIO || revisit_ratio >= FLAG_RATIO),
+                };
+                (time_ratio.max(revisit_ratio), stat)
+            })
+            .collect();
+        questions.sort_by(|a, b| b.0.total_cmp(&a.0));
+
+        reports.push(AdminQuestionReport {
+            lang: lang.to_string(),
+            tests: rows.iter().map(|s| s.responses).max().unwrap_or(0),
+            avg_secs,
+            revisit_rate,
+            questions: questions
+                .into_iter()
+                .take(LISTED_QUESTIONS)
+                .map(|(_, stat)| stat)
+                .collect(),
+        });
+    }
+    Ok(reports)
+}
+
+/// Research donations as CSV: language, age band, gender and one column per question id
+/// (answer values 1-5, in inventory order). Rows are shuffled.
+#[server]
+pub async fn admin_export_research(token: String) -> Result<String, ServerFnError> {
+    use crate::db;
+
+    check_admin_token(&token)?;
+    let responses = db::research_responses()
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+
+    // Every field is a code or number checked on input, so nothing needs quoting
+    let mut csv = String::from("lang,age_band,gender");
+    for question in crate::inventory::reference().questions() {
+        csv.push(',');
+        csv.push_str(&question.id);
+    }
+    csv.push('\n');
+    for response in &responses {
+        csv.push_str(&response.lang);
+        csv.push(',');
+        csv.push_str(response.age_band.as_deref().unwrap_or_default());
+        csv.push(',');
+        csv.push_str(response.gender.as_deref().unwrap_or_default());
+        for value in &response.answers {
+            csv.push(',');
+            csv.push_str(&value.to_string());
+        }
+        csv.push('\n');
+    }
+
+    tracing::info!(rows = responses.len(), "Exported research dataset");
+    Ok(csv)
+}
+
+fn load_token() -> String {
+    #[cfg(target_arch = "wasm32")]
+    {
+        web_sys::window()
+            .and_then(|w| w.session_storage().ok().flatten())
+            .and_then(|s| s.get_item(STORAGE_KEY_ADMIN_TOKEN).ok().flatten())
+            .unwrap_or_default()
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        String::new()
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), allow(unused_variables))]
+fn store_token(token: &str) {
+    #[cfg(target_arch = "wasm32")]
+    {
+        if let Some(storage) = web_sys::window().and_then(|w| w.session_storage().ok().flatten()) {
+            let _ = storage.set_item(STORAGE_KEY_ADMIN_TOKEN, token);
+        }
+    }
+}
+
+/// `data:` URL for downloading a CSV file (client only).
+#[cfg_attr(not(target_arch = "wasm32"), allow(unused_variables))]
+fn csv_data_url(csv: &str) -> String {
+    #[cfg(target_arch = "wasm32")]
+    {
+        format!(
+            "data:text/csv;charset=utf-8,{}",
+            js_sys::encode_uri_component(csv)
+        )
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        String::new()
+    }
+}
+
+const INPUT_CLASS: &str = "px-3 py-2 border border-gray-300 dark:border-gray-600 rounded-md bg-white dark:bg-gray-700 text-gray-800 dark:text-gray-100 text-sm";
+const BUTTON_CLASS: &str = "px-3 py-1.5 text-sm rounded-md border border-gray-300 dark:border-gray-600 text-gray-700 dark:text-gray-200 hover:bg-gray-100 dark:hover:bg-gray-700 transition-colors disabled:opacity-50";
+
+/// Admin page: search, inspect, unpublish and purge saved results.
+#[component]
+pub fn AdminPage() -> impl IntoView {
+    let i18n = use_i18n();
+
+    let (token, set_token) = signal(String::new());
+    let (filter, set_filter) = signal(AdminFilter::default());
+    let (results, set_results) = signal(Vec::<AdminResult>::new());
+    let (analyses, set_analyses) = signal(Vec::<AdminAnalysisEntry>::new());
+    let (provider_logs, set_provider_logs) = signal(Vec::<AdminProviderLog>::new());
+    let (cleanup, set_cleanup) = signal(AdminCleanupTotals::default());
+    let (question_reports, set_question_reports) = signal(Vec::<AdminQuestionReport>::new());
+    let (loading, set_loading) = signal(false);
+    let (error, set_error) = signal(Option::<String>::None);
+    let (searched, set_searched) = signal(false);
+
+    // Restored on the client only (avoids hydration mismatch)
+    Effect::new(move |_| set_token.set(load_token()));
+
+    let search = move || {
+        let token = token.get_untracked();
+        let filter = filter.get_untracked();
+        store_token(&token);
+        set_loading.set(true);
+        set_error.set(None);
+        spawn_local(async move {
+            match admin_search_results(token.clone(), filter).await {
+                Ok(list) => {
+                    set_results.set(list);
+                    set_searched.set(true);
+                }
+                Err(e) => set_error.set(Some(e.to_string())),
+            }
+            if let Ok(list) = admin_recent_analyses(token.clone()).await {
+                set_analyses.set(list);
+            }
+            if let Ok(list) = admin_recent_provider_logs(token.clone()).await {
+                set_provider_logs.set(list);
+            }
+            if let Ok(totals) = admin_cleanup_stats(token.clone()).await {
+                set_cleanup.set(totals);
+            }
+            if let Ok(reports) = admin_question_timings(token).await {
+                set_question_reports.set(reports);
+            }
+            set_loading.set(false);
+        });
+    };
+
+    let set_unpublished = move |id: String, unpublished: bool| {
+        let token = token.get_untracked();
+        spawn_local(async move {
+            match admin_set_unpublished(token, id.clone(), unpublished).await {
+                Ok(_) => set_results.update(|list| {
+                    if let Some(r) = list.iter_mut().find(|r| r.id == id) {
+                        r.unpublished = unpublished;
+                    }
+                }),
+                Err(e) => set_error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let purge = move |id: String| {
+        let token = token.get_untracked();
+        spawn_local(async move {
+            match admin_purge_result(token, id.clone()).await {
+                Ok(_) => set_results.update(|list| list.retain(|r| r.id != id)),
+                Err(e) => set_error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    // Download link for the research CSV, once prepared
+    let (export_url, set_export_url) = signal(Option::<String>::None);
+    let export_research = move |_| {
+        let token = token.get_untracked();
+        spawn_local(async move {
+            match admin_export_research(token).await {
+                Ok(csv) => set_export_url.set(Some(csv_data_url(&csv))),
+                Err(e) => set_error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    // Provider log looked up by job id: `None` before the first lookup, `Some(None)` if missing
+    let (log_job_id, set_log_job_id) = signal(String::new());
+    let (found_log, set_found_log) = signal(Option::<Option<AdminProviderLog>>::None);
+    let find_provider_log = move || {
+        let token = token.get_untracked();
+        let job_id = log_job_id.get_untracked();
+        spawn_local(async move {
+            match admin_find_provider_log(token, job_id).await {
+                Ok(log) => set_found_log.set(Some(log)),
+                Err(e) => set_error.set(Some(e.to_string())),
+            }
+        });
+    };
+
+    let filter_input = move |label: &'static str,
+                             kind: &'static str,
+                             field: fn(&mut AdminFilter) -> &mut String| {
+        view! {
+            <label class="flex flex-col gap-1 text-sm text-gray-600 dark:text-gray-300">
+                {move || i18n.t(label)}
+                <input
+                    type=kind
+                    class=INPUT_CLASS
+                    on:input=move |ev| set_filter.update(|f| *field(f) = event_target_value(&ev))
+                />
+            </label>
+        }
+    };
+
+    view! {
+        <div class="max-w-5xl mx-auto px-4 py-8 space-y-6">
+            <h1 class="text-2xl font-bold text-gray-800 dark:text-gray-100">{move || i18n.t("admin_title")}</h1>
+
+            <form
+                class="bg-white dark:bg-gray-800 rounded-lg shadow-md p-6 space-y-4 transition-colors duration-300"
+                on:submit=move |ev| {
+                    ev.prevent_default();
+                    search();
+                }
+            >
+                <label class="flex flex-col gap-1 text-sm text-gray-600 dark:text-gray-300">
+                    {move || i18n.t("admin_token")}
+                    <input
+                        type="password"
+                        autocomplete="off"
+                        class=INPUT_CLASS
+                        prop:value=move || token.get()
+                        on:input=move |ev| set_token.set(event_target_value(&ev))
+                    />
+                </label>
+                <div class="grid grid-cols-1 sm:grid-cols-4 gap-4">
+                    {filter_input("admin_filter_id", "text", |f| &mut f.id)}
+                    {filter_input("admin_filter_lang", "text", |f| &mut f.lang)}
+                    {filter_input("admin_filter_from", "date", |f| &mut f.from)}
+                    {filter_input("admin_filter_to", "date", |f| &mut f.to)}
+                </div>
+                <button
+                    type="submit"
+                    class="px-4 py-2 bg-indigo-600 hover:bg-indigo-700 text-white rounded-md transition-colors disabled:opacity-50"
+                    disabled=move || loading.get() || token.get().is_empty()
+                >
+                    {move || i18n.t("admin_search")}
+                </button>
+                {move || error.get().map(|e| view! { <p class="text-sm text-red-600 dark:text-red-400">{e}</p> })}
+            </form>
+
+            <Show when=move || searched.get()>
+                <div class="bg-white dark:bg-gray-800 rounded-lg shadow-md p-6 transition-colors duration-300">
+                    <h2 class="text-lg font-semibold text-gray-800 dark:text-gray-100 mb-4">
+                        {move || i18n.t("admin_results")} " (" {move || results.get().len()} ")"
+                    </h2>
+                    <ul class="divide-y divide-gray-200 dark:divide-gray-700">
+                        <For each=move || results.get() key=|r| (r.id.clone(), r.unpublished) let:result>
+                            <AdminResultRow result token set_unpublished purge />
+                        </For>
+                    </ul>
+                </div>
+
+                <div class="bg-white dark:bg-gray-800 rounded-lg shadow-md p-6 transition-colors duration-300">
+                    <h2 class="text-lg font-semibold text-gray-800 dark:text-gray-100 mb-4">
+                        {move || i18n.t("admin_recent_analyses")}
+                    </h2>
+                    <table class="w-full text-sm text-left text-gray-700 dark:text-gray-200">
+                        <tbody>
+                            {move || {
+                                analyses
+                                    .get()
+                                    .into_iter()
+                                    .map(|entry| {
+                                        let status = if entry.success { "admin_success" } else { "admin_failed" };
+                                        view! {
+                                            <tr class="border-t border-gray-200 dark:border-gray-700">
+                                                <td class="py-1.5 pr-4">
+                                                    <Timestamp ms=entry.created_at as f64 * 1000.0 with_time=true />
+                                                </td>
+                                                <td class="py-1.5 pr-4 font-mono">{entry.model_id}</td>
+                                                <td class="py-1.5 pr-4">{entry.lang}</td>
+                                                <td class="py-1.5">{i18n.t(status)}</td>
+                                            </tr>
+                                        }
+                                    })
+                                    .collect_view()
+                            }}
+                        </tbody>
+                    </table>
+                </div>
+
+                <div class="bg-white dark:bg-gray-800 rounded-lg shadow-md p-6 transition-colors duration-300">
+                    <h2 class="text-lg font-semibold text-gray-800 dark:text-gray-100 mb-2">
+                        {move || i18n.t("admin_provider_logs_title")}
+                    </h2>
+                    <p class="text-sm text-gray-600 dark:text-gray-300 mb-4">
+                        {move || i18n.t("admin_provider_logs_description")}
+                    </p>
+                    <form
+                        class="flex flex-wrap items-end gap-2 mb-4"
+                        on:submit=move |ev| {
+                            ev.prevent_default();
+                            find_provider_log();
+                        }
+                    >
+                        <label class="flex flex-col gap-1 text-sm text-gray-600 dark:text-gray-300">
+                            {move || i18n.t("admin_provider_logs_job_id")}
+                            <input
+                                type="text"
+                                class=INPUT_CLASS
+                                on:input=move |ev| set_log_job_id.set(event_target_value(&ev))
+                            />
+                        </label>
+                        <button type="submit" class=BUTTON_CLASS disabled=move || log_job_id.get().trim().is_empty()>
+                            {move || i18n.t("admin_provider_logs_find")}
+                        </button>
+                    </form>
+                    {move || {
+                        found_log
+                            .get()
+                            .map(|log| match log {
+                                Some(log) => view! { <ProviderLogView log open=true /> }.into_any(),
+                                None => {
+                                    view! {
+                                        <p class="text-sm text-gray-500 dark:text-gray-400 mb-4">
+                                            {move || i18n.t("admin_provider_logs_not_found")}
+                                        </p>
+                                    }
+                                        .into_any()
+                                }
+                            })
+                    }}
+                    <For each=move || provider_logs.get() key=|log| log.job_id.clone() let:log>
+                        <ProviderLogView log open=false />
+                    </For>
+                </div>
+
+                <div class="bg-white dark:bg-gray-800 rounded-lg shadow-md p-6 transition-colors duration-300">
+                    <h2 class="text-lg font-semibold text-gray-800 dark:text-gray-100 mb-2">
+                        {move || i18n.t("admin_cleanup_title")}
+                    </h2>
+                    <p class="text-sm text-gray-600 dark:text-gray-300 mb-4">
+                        {move || i18n.t("admin_cleanup_description")}
+                    </p>
+                    <table class="text-sm text-left text-gray-700 dark:text-gray-200">
+                        <tbody>
+                            {move || {
+                                let totals = cleanup.get();
+                                let last_run = totals
+                                    .last_run_at
+                                    .map(|at| format_date_time(at as f64 * 1000.0, i18n.get_locale().code()))
+                                    .unwrap_or_else(|| "—".to_string());
+                                [
+                                    ("admin_cleanup_runs", totals.runs.to_string()),
+                                    ("admin_cleanup_last_run", last_run),
+                                    ("admin_cleanup_results", totals.deleted_results.to_string()),
+                                    ("admin_cleanup_sessions", totals.sessions.to_string()),
+                                    ("admin_cleanup_jobs", totals.jobs.to_string()),
+                                    ("admin_cleanup_feedback", totals.feedback.to_string()),
+                                    ("admin_cleanup_provider_logs", totals.provider_logs.to_string()),
+                                    ("admin_cleanup_audit_entries", totals.audit_entries.to_string()),
+                                    ("admin_cleanup_quota_counters", totals.quota_counters.to_string()),
+                                ]
+                                    .into_iter()
+                                    .map(|(label, value)| {
+                                        view! {
+                                            <tr>
+                                                <td class="py-0.5 pr-6">{i18n.t(label)}</td>
+                                                <td class="py-0.5 font-mono">{value}</td>
+                                            </tr>
+                                        }
+                                    })
+                                    .collect_view()
+                            }}
+                        </tbody>
+                    </table>
+                </div>
+
+                <div class="bg-white dark:bg-gray-800 rounded-lg shadow-md p-6 transition-colors duration-300">
+                    <h2 class="text-lg font-semibold text-gray-800 dark:text-gray-100 mb-2">
+                        {move || i18n.t("admin_questions_title")}
+                    </h2>
+                    <p class="text-sm text-gray-600 dark:text-gray-300 mb-4">
+                        {move || i18n.t("admin_questions_description")}
+                    </p>
+                    <Show
+                        when=move || !question_reports.get().is_empty()
+                        fallback=move || {
+                            view! {
+                                <p class="text-sm text-gray-500 dark:text-gray-400">
+                                    {move || i18n.t("admin_questions_empty")}
+                                </p>
+                            }
+                        }
+                    >
+                        <For each=move || question_reports.get() key=|r| (r.lang.clone(), r.tests) let:report>
+                            <QuestionReportView report />
+                        </For>
+                    </Show>
+                </div>
+
+                <div class="bg-white dark:bg-gray-800 rounded-lg shadow-md p-6 transition-colors duration-300">
+                    <h2 class="text-lg font-semibold text-gray-800 dark:text-gray-100 mb-2">
+                        {move || i18n.t("admin_research_title")}
+                    </h2>
+                    <p class="text-sm text-gray-600 dark:text-gray-300 mb-4">
+                        {move || i18n.t("admin_research_description")}
+                    </p>
+                    <Show
+                        when=move || export_url.get().is_some()
+                        fallback=move || {
+                            view! {
+                                <button type="button" class=BUTTON_CLASS on:click=export_research>
+                                    {move || i18n.t("admin_research_prepare")}
+                                </button>
+                            }
+                        }
+                    >
+                        <a
+                            href=move || export_url.get().unwrap_or_default()
+                            download="bigfive-research.csv"
+                            class="text-indigo-600 dark:text-indigo-400 hover:underline"
+                        >
+                            {move || i18n.t("admin_research_download")}
+                        </a>
+                    </Show>
+                </div>
+            </Show>
+        </div>
+    }
+}
+
+/// Question timing table of one language.
+#[component]
+fn QuestionReportView(report: AdminQuestionReport) -> impl IntoView {
+    let i18n = use_i18n();
+    let (tests, avg_secs, revisit_rate) = (report.tests, report.avg_secs, report.revisit_rate);
+
+    view! {
+        <div class="mb-6 last:mb-0">
+            <h3 class="font-medium text-gray-800 dark:text-gray-100">{report.lang.clone()}</h3>
+            <p class="text-sm text-gray-500 dark:text-gray-400 mb-2">
+                {move || {
+                    format!(
+                        "{}: {tests} · {}: {avg_secs:.1} s · {}: {:.0}%",
+                        i18n.t("admin_questions_responses"),
+                        i18n.t("admin_questions_time"),
+                        i18n.t("admin_questions_revisits"),
+                        revisit_rate * 100.0,
+                    )
+                }}
+            </p>
+            <table class="w-full text-sm text-left text-gray-700 dark:text-gray-200">
+                <thead class="text-gray-500 dark:text-gray-400">
+                    <tr>
+                        <th class="py-1.5 pr-4 font-normal">{move || i18n.t("admin_questions_question")}</th>
+                        <th class="py-1.5 pr-4 font-normal text-right">{move || i18n.t("admin_questions_responses")}</th>
+                        <th class="py-1.5 pr-4 font-normal text-right">{move || i18n.t("admin_questions_time")}</th>
+                        <th class="py-1.5 font-normal text-right">{move || i18n.t("admin_questions_revisits")}</th>
+                    </tr>
+                </thead>
+                <tbody>
+                    {report
+                        .questions
+                        .into_iter()
+                        .map(|q| {
+                            let row_class = if q.flagged {
+                                "border-t border-gray-200 dark:border-gray-700 bg-amber-50 dark:bg-amber-900/20"
+                            } else {
+                                "border-t border-gray-200 dark:border-gray-700"
+                            };
+                            view! {
+                                <tr class=row_class title=q.question_id>
+                                    <td class="py-1.5 pr-4">{q.text}</td>
+                                    <td class="py-1.5 pr-4 text-right font-mono">{q.responses}</td>
+                                    <td class="py-1.5 pr-4 text-right font-mono">{format!("{:.1} s", q.avg_secs)}</td>
+                                    <td class="py-1.5 text-right font-mono">{format!("{:.0}%", q.revisit_rate * 100.0)}</td>
+                                </tr>
+                            }
+                        })
+                        .collect_view()}
+                </tbody>
+            </table>
+        </div>
+    }
+}
+
+/// One search hit with its stored context, analysis and moderation actions.
+#[component]
+fn AdminResultRow(
+    result: AdminResult,
+    token: ReadSignal<String>,
+    set_unpublished: impl Fn(String, bool) + Copy + Send + Sync + 'static,
+    purge: impl Fn(String) + Copy + Send + Sync + 'static,
+) -> impl IntoView {
+    let i18n = use_i18n();
+    let (expanded, set_expanded) = signal(false);
+    let (confirm_purge, set_confirm_purge) = signal(false);
+    // Fetched the first time the details are opened
+    let (audit, set_audit) = signal(Option::<Result<Vec<AdminAuditEntry>, String>>::None);
+
+    let href = format!("/{}/results/{}", result.lang, result.id);
+    let id = StoredValue::new(result.id.clone());
+    let unpublished = result.unpublished;
+    let created_at = result.created_at;
+    let expires_at = result.expires_at;
+    let deleted = result.deleted_at.is_some();
+    let details = StoredValue::new(result.clone());
+
+    view! {
+        <li class="py-3 space-y-2">
+            <div class="flex flex-wrap items-center gap-x-4 gap-y-1 text-sm">
+                <a href=href class="font-mono text-indigo-600 dark:text-indigo-400 hover:underline">
+                    {result.id.clone()}
+                </a>
+                <span class="text-gray-500 dark:text-gray-400">
+                    <Timestamp ms=created_at as f64 * 1000.0 with_time=true />
+                </span>
+                <span class="text-gray-500 dark:text-gray-400">{result.lang.clone()}</span>
+                {result.nickname.clone().map(|n| view! { <span class="text-gray-700 dark:text-gray-200">{n}</span> })}
+                {expires_at
+                    .map(|at| {
+                        view! {
+                            <span class="text-gray-500 dark:text-gray-400">
+                                {move || i18n.t("admin_expires")} " "
+                                <Timestamp ms=at as f64 * 1000.0 />
+                            </span>
+                        }
+                    })}
+                {result
+                    .protected
+                    .then(|| {
+                        view! {
+                            <span class="px-2 py-0.5 rounded bg-gray-100 dark:bg-gray-700 text-gray-600 dark:text-gray-300">
+                                {move || i18n.t("admin_protected")}
+                            </span>
+                        }
+                    })}
+                {deleted
+                    .then(|| {
+                        view! {
+                            <span class="px-2 py-0.5 rounded bg-red-100 dark:bg-red-900 text-red-800 dark:text-red-200">
+                                {move || i18n.t("admin_deleted")}
+                            </span>
+                        }
+                    })}
+                {unpublished
+                    .then(|| {
+                        view! {
+                            <span class="px-2 py-0.5 rounded bg-amber-100 dark:bg-amber-900 text-amber-800 dark:text-amber-200">
+                                {move || i18n.t("admin_unpublished")}
+                            </span>
+                        }
+                    })}
+                <span class="text-gray-500 dark:text-gray-400">
+                    {move || {
+                        details
+                            .with_value(|r| match (&r.ai_analysis, &r.prompt_version) {
+                                (Some(_), Some(version)) => format!("{} ({version})", i18n.t("admin_has_analysis")),
+                                (Some(_), None) => i18n.t("admin_has_analysis").to_string(),
+                                (None, _) => i18n.t("admin_no_analysis").to_string(),
+                            })
+                    }}
+                </span>
+            </div>
+            <div class="flex flex-wrap gap-2">
+                <button
+                    type="button"
+                    class=BUTTON_CLASS
+                    on:click=move |_| {
+                        set_expanded.update(|e| *e = !*e);
+                        if expanded.get_untracked() && audit.get_untracked().is_none() {
+                            let token = token.get_untracked();
+                            spawn_local(async move {
+                                let entries = admin_audit_log(token, id.get_value())
+                                    .await
+                                    .map_err(|e| e.to_string());
+                                set_audit.set(Some(entries));
+                            });
+                        }
+                    }
+                >
+                    {move || i18n.t(if expanded.get() { "admin_hide_details" } else { "admin_show_details" })}
+                </button>
+                <button
+                    type="button"
+                    class=BUTTON_CLASS
+                    on:click=move |_| set_unpublished(id.get_value(), !unpublished)
+                >
+                    {move || i18n.t(if unpublished { "admin_publish" } else { "admin_unpublish" })}
+                </button>
+                <Show
+                    when=move || confirm_purge.get()
+                    fallback=move || {
+                        view! {
+                            <button type="button" class=BUTTON_CLASS on:click=move |_| set_confirm_purge.set(true)>
+                                {move || i18n.t("admin_purge")}
+                            </button>
+                        }
+                    }
+                >
+                    <button
+                        type="button"
+                        class="px-3 py-1.5 text-sm rounded-md bg-red-600 hover:bg-red-700 text-white transition-colors"
+                        on:click=move |_| purge(id.get_value())
+                    >
+                        {move || i18n.t("admin_purge_confirm")}
+                    </button>
+                    <button type="button" class=BUTTON_CLASS on:click=move |_| set_confirm_purge.set(false)>
+                        {move || i18n.t("admin_cancel")}
+                    </button>
+                </Show>
+            </div>
+            <Show when=move || expanded.get()>
+                <div class="grid grid-cols-1 md:grid-cols-2 gap-4 text-sm">
+                    <div>
+                        <h3 class="font-medium text-gray-700 dark:text-gray-200 mb-1">
+                            {move || i18n.t("admin_context")}
+                        </h3>
+                        <pre class="whitespace-pre-wrap p-3 rounded bg-gray-50 dark:bg-gray-900 text-gray-700 dark:text-gray-300">
+                            {details.with_value(|r| r.user_context.clone()).unwrap_or_default()}
+                        </pre>
+                    </div>
+                    <div>
+                        <h3 class="font-medium text-gray-700 dark:text-gray-200 mb-1">
+                            {move || i18n.t("admin_analysis")}
+                        </h3>
+                        <pre class="whitespace-pre-wrap p-3 rounded bg-gray-50 dark:bg-gray-900 text-gray-700 dark:text-gray-300">
+                            {details.with_value(|r| r.ai_analysis.clone()).unwrap_or_default()}
+                        </pre>
+                    </div>
+                </div>
+                <div class="text-sm">
+                    <h3 class="font-medium text-gray-700 dark:text-gray-200 mb-1">
+                        {move || i18n.t("admin_audit_title")}
+                    </h3>
+                    {move || match audit.get() {
+                        None => ().into_any(),
+                        Some(Err(e)) => view! { <p class="text-red-600 dark:text-red-400">{e}</p> }.into_any(),
+                        Some(Ok(entries)) if entries.is_empty() => {
+                            view! {
+                                <p class="text-gray-500 dark:text-gray-400">{move || i18n.t("admin_audit_empty")}</p>
+                            }
+                                .into_any()
+                        }
+                        Some(Ok(entries)) => {
+                            view! {
+                                <ul class="space-y-0.5 text-gray-600 dark:text-gray-300">
+                                    {entries
+                                        .into_iter()
+                                        .map(|entry| {
+                                            let created_at = entry.created_at;
+                                            view! {
+                                                <li class="flex flex-wrap gap-x-3">
+                                                    <Timestamp ms=created_at as f64 * 1000.0 with_time=true />
+                                                    <span class="font-medium">{entry.action}</span>
+                                                    <span class="font-mono text-gray-500 dark:text-gray-400">
+                                                        {entry.ip_hash.unwrap_or_else(|| "-".to_string())}
+                                                    </span>
+                                                </li>
+                                            }
+                                        })
+                                        .collect_view()}
+                                </ul>
+                            }
+                                .into_any()
+                        }
+                    }}
+                </div>
+            </Show>
+        </li>
+    }
+}
+
+/// A failed job's error and its provider calls, collapsed into a summary line.
+#[component]
+fn ProviderLogView(log: AdminProviderLog, open: bool) -> impl IntoView {
+    let created_at = log.created_at;
+
+    let calls = log
+        .calls
+        .into_iter()
+        .map(|call| {
+            let outcome = call.response.or(call.error).unwrap_or_default();
+            view! {
+                <div class="space-y-1">
+                    <p class="font-mono text-gray-500 dark:text-gray-400">
+                        {format!("{} / {} · max_tokens {} · {} ms", call.provider, call.model, call.max_tokens, call.elapsed_ms)}
+                    </p>
+                    {call
+                        .system
+                        .map(|system| {
+                            view! {
+                                <pre class="whitespace-pre-wrap p-3 rounded bg-gray-50 dark:bg-gray-900 text-gray-700 dark:text-gray-300">
+                                    {system}
+                                </pre>
+                            }
+                        })}
+                    <pre class="whitespace-pre-wrap p-3 rounded bg-gray-50 dark:bg-gray-900 text-gray-700 dark:text-gray-300">
+                        {call.user}
+                    </pre>
+                    <pre class="whitespace-pre-wrap p-3 rounded bg-indigo-50 dark:bg-indigo-900/30 text-gray-700 dark:text-gray-300">
+                        {outcome}
+                    </pre>
+                </div>
+            }
+        })
+        .collect_view();
+
+    view! {
+        <details open=open class="border-t border-gray-200 dark:border-gray-700 py-2 text-sm">
+            <summary class="cursor-pointer flex flex-wrap gap-x-4 text-gray-700 dark:text-gray-200">
+                <Timestamp ms=created_at as f64 * 1000.0 with_time=true />
+                <span class="font-mono">{log.job_id}</span>
+                <span class="font-mono">{log.model_id}</span>
+                <span class="text-red-600 dark:text-red-400">{log.error}</span>
+            </summary>
+            <div class="mt-2 space-y-4">{calls}</div>
+        </details>
+    }
+}
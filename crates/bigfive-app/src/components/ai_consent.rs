@@ -0,0 +1,125 @@
+//! Confirmation before anything is sent to an AI model provider.
+//!
+//! The first analysis request of a browser session opens [`AiConsentDialog`]; once confirmed,
+//! the consent is kept in sessionStorage until the tab closes. The server refuses analysis
+//! requests without it and stores when it was given next to the analysis.
+
+use leptos::prelude::*;
+
+use crate::i18n::use_i18n;
+
+#[cfg(target_arch = "wasm32")]
+const STORAGE_KEY_AI_CONSENT: &str = crate::storage::storage_key!("ai_consent");
+
+/// Whether the user already agreed to send data to the model provider in this session.
+pub fn has_ai_consent() -> bool {
+    #[cfg(target_arch = "wasm32")]
+    {
+        web_sys::window()
+            .and_then(|w| w.session_storage().ok().flatten())
+            .and_then(|s| s.get_item(STORAGE_KEY_AI_CONSENT).ok().flatten())
+            .is_some()
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        false
+    }
+}
+
+fn store_ai_consent() {
+    #[cfg(target_arch = "wasm32")]
+    {
+        if let Some(storage) = web_sys::window().and_then(|w| w.session_storage().ok().flatten()) {
+            let _ = storage.set_item(STORAGE_KEY_AI_CONSENT, "1");
+        }
+    }
+}
+
+/// Modal explaining what an analysis sends to the selected model's provider.
+///
+/// `on_confirm` runs after the consent is stored; cancelling just closes the dialog.
+#[component]
+pub fn AiConsentDialog(
+    open: RwSignal<bool>,
+    /// Display name of the selected model.
+    #[prop(into)]
+    model_name: Signal<Option<String>>,
+    /// Whether the user wrote notes that go along with the scores.
+    #[prop(into)]
+    has_context: Signal<bool>,
+    #[prop(into)] on_confirm: Callback<()>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+
+    let confirm = move |_| {
+        store_ai_consent();
+        open.set(false);
+        on_confirm.run(());
+    };
+
+    view! {
+        <Show when=move || open.get()>
+            <div
+                class="no-print fixed inset-0 z-50 flex items-center justify-center bg-black/50 px-4"
+                on:click=move |_| open.set(false)
+                on:keydown=move |ev| {
+                    if ev.key() == "Escape" {
+                        open.set(false);
+                    }
+                }
+            >
+                <div
+                    role="dialog"
+                    aria-modal="true"
+                    aria-labelledby="ai-consent-title"
+                    class="w-full max-w-md bg-white dark:bg-gray-800 rounded-lg shadow-xl p-6"
+                    on:click=|ev| ev.stop_propagation()
+                >
+                    <h2 id="ai-consent-title" class="text-lg font-semibold text-gray-900 dark:text-white mb-3">
+                        {move || i18n.t("ai_consent_title")}
+                    </h2>
+                    <p class="text-sm text-gray-700 dark:text-gray-300 mb-3">
+                        {move || {
+                            if has_context.get() {
+                                i18n.t("ai_consent_text_context")
+                            } else {
+                                i18n.t("ai_consent_text")
+                            }
+                        }}
+                    </p>
+                    {move || {
+                        model_name
+                            .get()
+                            .map(|name| {
+                                view! {
+                                    <p class="text-sm text-gray-700 dark:text-gray-300 mb-3">
+                                        {i18n.t("ai_consent_model")} " "
+                                        <span class="font-medium">{name}</span>
+                                    </p>
+                                }
+                            })
+                    }}
+                    <p class="text-xs text-gray-500 dark:text-gray-400 mb-5">
+                        {move || i18n.t("ai_consent_note")}
+                    </p>
+                    <div class="flex justify-end gap-3">
+                        <button
+                            type="button"
+                            on:click=move |_| open.set(false)
+                            class="px-4 py-2 border border-gray-300 dark:border-gray-600 text-gray-700 dark:text-gray-300 rounded-lg hover:bg-gray-50 dark:hover:bg-gray-700 transition-colors"
+                        >
+                            {move || i18n.t("ai_consent_cancel")}
+                        </button>
+                        <button
+                            type="button"
+                            on:click=confirm
+                            class="px-4 py-2 bg-indigo-600 dark:bg-indigo-500 text-white rounded-lg hover:bg-indigo-700 dark:hover:bg-indigo-600 transition-colors"
+                        >
+                            {move || i18n.t("ai_consent_confirm")}
+                        </button>
+                    </div>
+                </div>
+            </div>
+        </Show>
+    }
+}
@@ -0,0 +1,172 @@
+//! Questions bookmarked during the test for reflecting on afterwards.
+//!
+//! Bookmarks of the test in progress live next to the answers. When the test is finished
+//! they are kept as the last test's reflection items, which the results page lists with
+//! the facet each question measures and can add to the context of the AI analysis.
+
+use std::collections::HashSet;
+
+use leptos::prelude::*;
+
+use crate::components::history::{domain_key, facet_key};
+use crate::i18n::use_i18n;
+
+#[cfg(target_arch = "wasm32")]
+pub(super) const STORAGE_KEY_BOOKMARKS: &str = crate::storage::storage_key!("bookmarks");
+#[cfg(target_arch = "wasm32")]
+const STORAGE_KEY_REFLECTION: &str = crate::storage::storage_key!("reflection_items");
+
+/// Ids of the questions bookmarked in the test in progress.
+pub(super) fn load_bookmarks() -> HashSet<String> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        load_ids(STORAGE_KEY_BOOKMARKS)
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        HashSet::new()
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), allow(unused_variables))]
+pub(super) fn save_bookmarks(bookmarks: &HashSet<String>) {
+    #[cfg(target_arch = "wasm32")]
+    {
+        if let Some(storage) = web_sys::window().and_then(|w| w.local_storage().ok().flatten())
+            && let Ok(json) = serde_json::to_string(bookmarks)
+        {
+            let _ = storage.set_item(STORAGE_KEY_BOOKMARKS, &json);
+        }
+    }
+}
+
+/// Turn the finished test's bookmarks into its reflection items, replacing the previous
+/// test's, and clear them for the next test.
+pub(super) fn keep_bookmarks_for_reflection() {
+    #[cfg(target_arch = "wasm32")]
+    {
+        let Some(storage) = web_sys::window().and_then(|w| w.local_storage().ok().flatten()) else {
+            return;
+        };
+        match storage.get_item(STORAGE_KEY_BOOKMARKS).ok().flatten() {
+            Some(json) => {
+                let _ = storage.set_item(STORAGE_KEY_REFLECTION, &json);
+                let _ = storage.remove_item(STORAGE_KEY_BOOKMARKS);
+            }
+            None => {
+                let _ = storage.remove_item(STORAGE_KEY_REFLECTION);
+            }
+        }
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn load_ids(key: &str) -> HashSet<String> {
+    web_sys::window()
+        .and_then(|w| w.local_storage().ok().flatten())
+        .and_then(|s| s.get_item(key).ok().flatten())
+        .and_then(|json| serde_json::from_str(&json).ok())
+        .unwrap_or_default()
+}
+
+/// Ids of the questions bookmarked in the last finished test.
+fn load_reflection_items() -> HashSet<String> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        load_ids(STORAGE_KEY_REFLECTION)
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        HashSet::new()
+    }
+}
+
+/// Bookmarked questions of the last test with their facets, for the owner's results page.
+///
+/// `on_discuss` gets a short text listing the questions to append to the AI context; the
+/// button is only offered while `can_discuss` is true.
+#[component]
+pub(super) fn ReflectionItems(
+    #[prop(into)] can_discuss: Signal<bool>,
+    on_discuss: Callback<String>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+
+    // Loaded on the client only (avoids hydration mismatch)
+    let (ids, set_ids) = signal(HashSet::<String>::new());
+    Effect::new(move |_| set_ids.set(load_reflection_items()));
+    let (added, set_added) = signal(false);
+
+    // (question text, facet) in test order
+    let items = Memo::new(move |_| {
+        let ids = ids.get();
+        if ids.is_empty() {
+            return Vec::new();
+        }
+        let Some(inventory) = crate::inventory::inventory(i18n.get_locale().code()) else {
+            return Vec::new();
+        };
+        inventory
+            .questions()
+            .iter()
+            .filter(|q| ids.contains(&q.id))
+            .filter_map(|q| Some((q.text.clone(), q.facet()?)))
+            .collect::<Vec<_>>()
+    });
+
+    let discuss = move |_| {
+        let mut text = i18n.t("reflection_context_intro").to_string();
+        for (question, facet) in items.get_untracked() {
+            text.push_str(&format!(
+                "\n- \"{question}\" ({})",
+                i18n.t(facet_key(facet))
+            ));
+        }
+        on_discuss.run(text);
+        set_added.set(true);
+    };
+
+    view! {
+        <Show when=move || !items.get().is_empty()>
+            <div class="no-print bg-white dark:bg-gray-800 rounded-lg shadow-md p-6 mb-8 transition-colors duration-300">
+                <h2 class="text-xl font-semibold text-gray-800 dark:text-gray-100 mb-2">
+                    {move || i18n.t("reflection_title")}
+                </h2>
+                <p class="text-sm text-gray-600 dark:text-gray-300 mb-4">
+                    {move || i18n.t("reflection_description")}
+                </p>
+                <ul class="space-y-3 mb-4">
+                    {move || {
+                        items
+                            .get()
+                            .into_iter()
+                            .map(|(question, facet)| {
+                                view! {
+                                    <li>
+                                        <p class="text-gray-800 dark:text-gray-100">{question}</p>
+                                        <p class="text-sm text-gray-500 dark:text-gray-400">
+                                            {move || i18n.t(facet_key(facet))} " · "
+                                            {move || i18n.t(domain_key(facet.domain()))}
+                                        </p>
+                                    </li>
+                                }
+                            })
+                            .collect_view()
+                    }}
+                </ul>
+                <Show when=move || can_discuss.get()>
+                    <button
+                        type="button"
+                        on:click=discuss
+                        disabled=move || added.get()
+                        class="px-4 py-2 text-sm rounded-lg border border-indigo-600 dark:border-indigo-400 text-indigo-600 dark:text-indigo-400 hover:bg-indigo-50 dark:hover:bg-indigo-900/30 transition-colors disabled:opacity-50"
+                    >
+                        {move || {
+                            i18n.t(if added.get() { "reflection_added" } else { "reflection_add_to_context" })
+                        }}
+                    </button>
+                </Show>
+            </div>
+        </Show>
+    }
+}
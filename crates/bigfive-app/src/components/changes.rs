@@ -0,0 +1,218 @@
+//! "What changed" banner on the results page after retaking the test.
+//!
+//! When a new profile is saved, the one it replaces is kept in localStorage with the time it
+//! was saved. The results page compares the two and lists the domains that moved most, with
+//! every domain and facet change one click away.
+
+use bigfive::{PersonalityProfile, ProfileDiff};
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::components::dates::{format_month, format_relative, now_ms};
+use crate::components::domain_color;
+use crate::components::history::{domain_key, facet_key};
+use crate::i18n::use_i18n;
+
+#[cfg(target_arch = "wasm32")]
+const STORAGE_KEY_PROFILE: &str = crate::storage::storage_key!("profile");
+#[cfg(target_arch = "wasm32")]
+const STORAGE_KEY_PROFILE_SAVED_AT: &str = crate::storage::storage_key!("profile_saved_at");
+#[cfg(target_arch = "wasm32")]
+const STORAGE_KEY_PREVIOUS_PROFILE: &str = crate::storage::storage_key!("previous_profile");
+
+/// Smallest domain change (percentage points) mentioned in the banner.
+const NOTABLE_CHANGE: f32 = 5.0;
+
+/// Number of domain changes named in the banner.
+const BANNER_CHANGES: usize = 3;
+
+/// The profile replaced by the latest test.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+struct PreviousProfile {
+    profile: PersonalityProfile,
+    /// When it was saved, in milliseconds since the Unix epoch; unknown for profiles saved
+    /// before this was recorded.
+    saved_at_ms: Option<f64>,
+}
+
+/// Keep the stored profile as the previous one before a new profile replaces it, and stamp
+/// the new one with the current time.
+#[cfg_attr(not(target_arch = "wasm32"), allow(dead_code))]
+pub(super) fn archive_profile() {
+    #[cfg(target_arch = "wasm32")]
+    {
+        let Some(storage) = web_sys::window().and_then(|w| w.local_storage().ok().flatten()) else {
+            return;
+        };
+        let current = storage
+            .get_item(STORAGE_KEY_PROFILE)
+            .ok()
+            .flatten()
+            .and_then(|json| serde_json::from_str::<PersonalityProfile>(&json).ok());
+        if let Some(profile) = current {
+            let saved_at_ms = storage
+                .get_item(STORAGE_KEY_PROFILE_SAVED_AT)
+                .ok()
+                .flatten()
+                .and_then(|v| v.parse().ok());
+            if let Ok(json) = serde_json::to_string(&PreviousProfile {
+                profile,
+                saved_at_ms,
+            }) {
+                let _ = storage.set_item(STORAGE_KEY_PREVIOUS_PROFILE, &json);
+            }
+        }
+        let _ = storage.set_item(
+            STORAGE_KEY_PROFILE_SAVED_AT,
+            &js_sys::Date::now().to_string(),
+        );
+    }
+}
+
+fn load_previous_profile() -> Option<PreviousProfile> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        let json = web_sys::window()?
+            .local_storage()
+            .ok()??
+            .get_item(STORAGE_KEY_PREVIOUS_PROFILE)
+            .ok()??;
+        serde_json::from_str(&json).ok()
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        None
+    }
+}
+
+/// Signed change in whole points, e.g. `+8` or `−6`.
+fn format_delta(delta: f32) -> String {
+    let points = delta.round();
+    if points > 0.0 {
+        format!("+{points:.0}")
+    } else if points < 0.0 {
+        format!("−{:.0}", -points)
+    } else {
+        "0".to_string()
+    }
+}
+
+/// Banner with the largest changes since the previous test; hidden without one.
+#[component]
+pub fn ProfileChanges(profile: PersonalityProfile) -> impl IntoView {
+    let i18n = use_i18n();
+
+    // Loaded on the client only (avoids hydration mismatch)
+    let (previous, set_previous) = signal::<Option<(ProfileDiff, Option<f64>)>>(None);
+    Effect::new(move |_| {
+        set_previous.set(
+            load_previous_profile()
+                .map(|previous| (profile.diff(&previous.profile), previous.saved_at_ms)),
+        );
+    });
+
+    move || {
+        let (diff, saved_at_ms) = previous.get()?;
+        let since = saved_at_ms.map(|ms| {
+            let locale = i18n.get_locale().code();
+            format!(
+                "{}, {}",
+                format_relative(ms, now_ms(), locale),
+                format_month(ms, locale)
+            )
+        });
+        let notable = diff.notable_domains(NOTABLE_CHANGE);
+        let summary = if notable.is_empty() {
+            view! { <span>{move || i18n.t("changes_none")}</span> }.into_any()
+        } else {
+            notable
+                .iter()
+                .take(BANNER_CHANGES)
+                .map(|change| {
+                    let domain = change.domain;
+                    let delta = format_delta(change.delta());
+                    view! {
+                        <span class="inline-flex items-center gap-1.5 font-medium">
+                            <span class="w-2 h-2 rounded-full" style:background-color=domain_color(domain) />
+                            {move || i18n.t(domain_key(domain))}
+                            " "
+                            {delta}
+                        </span>
+                    }
+                })
+                .collect_view()
+                .into_any()
+        };
+
+        Some(view! {
+            <section class="no-print mb-6 p-4 rounded-lg bg-indigo-50 dark:bg-indigo-900/30 border border-indigo-100 dark:border-indigo-800 text-sm text-gray-700 dark:text-gray-200">
+                <p class="flex flex-wrap items-center gap-x-4 gap-y-1">
+                    <span class="font-semibold text-gray-900 dark:text-white">
+                        {move || i18n.t("changes_title")}
+                        {since.map(|since| format!(" ({since})"))}
+                    </span>
+                    {summary}
+                </p>
+                <details class="mt-2">
+                    <summary class="cursor-pointer text-indigo-600 dark:text-indigo-400 hover:underline">
+                        {move || i18n.t("changes_show_all")}
+                    </summary>
+                    <ChangesTable diff=diff />
+                </details>
+            </section>
+        })
+    }
+}
+
+/// Every domain change, each followed by its facets.
+#[component]
+fn ChangesTable(diff: ProfileDiff) -> impl IntoView {
+    let i18n = use_i18n();
+
+    let rows = diff
+        .domains
+        .iter()
+        .map(|domain_change| {
+            let domain = domain_change.domain;
+            let facets = diff
+                .facets
+                .iter()
+                .filter(|f| f.facet.domain() == domain)
+                .map(|change| {
+                    let facet = change.facet;
+                    view! {
+                        <tr class="text-gray-600 dark:text-gray-300">
+                            <td class="py-0.5 pl-4">{move || i18n.t(facet_key(facet))}</td>
+                            <td class="py-0.5 text-right">{format!("{:.0}%", change.before)}</td>
+                            <td class="py-0.5 text-right">{format!("{:.0}%", change.after)}</td>
+                            <td class="py-0.5 text-right">{format_delta(change.delta())}</td>
+                        </tr>
+                    }
+                })
+                .collect_view();
+            view! {
+                <tr class="font-semibold text-gray-900 dark:text-white border-t border-gray-200 dark:border-gray-700">
+                    <td class="pt-2">{move || i18n.t(domain_key(domain))}</td>
+                    <td class="pt-2 text-right">{format!("{:.0}%", domain_change.before)}</td>
+                    <td class="pt-2 text-right">{format!("{:.0}%", domain_change.after)}</td>
+                    <td class="pt-2 text-right">{format_delta(domain_change.delta())}</td>
+                </tr>
+                {facets}
+            }
+        })
+        .collect_view();
+
+    view! {
+        <table class="w-full mt-3">
+            <thead class="text-xs text-gray-500 dark:text-gray-400">
+                <tr>
+                    <th />
+                    <th class="text-right font-normal">{move || i18n.t("changes_before")}</th>
+                    <th class="text-right font-normal">{move || i18n.t("changes_after")}</th>
+                    <th />
+                </tr>
+            </thead>
+            <tbody>{rows}</tbody>
+        </table>
+    }
+}
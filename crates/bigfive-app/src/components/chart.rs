@@ -0,0 +1,147 @@
+//! Chart primitives shared by the home and results pages.
+//!
+//! Colors come from CSS variables defined in `style/tailwind.css`, so charts follow the
+//! light/dark theme toggle and the high-contrast (`prefers-contrast: more`) palette.
+
+use bigfive::Domain;
+use leptos::prelude::*;
+
+/// Duration of the score reveal animation; matches `.score-bar-reveal` in `style/tailwind.css`.
+#[cfg(target_arch = "wasm32")]
+const REVEAL_MS: f64 = 800.0;
+
+/// CSS color value for a domain's chart color.
+pub fn domain_color(domain: Domain) -> &'static str {
+    match domain {
+        Domain::Neuroticism => "var(--color-domain-n)",
+        Domain::Extraversion => "var(--color-domain-e)",
+        Domain::Openness => "var(--color-domain-o)",
+        Domain::Agreeableness => "var(--color-domain-a)",
+        Domain::Conscientiousness => "var(--color-domain-c)",
+    }
+}
+
+/// Horizontal bar showing a score as a percentage (0-100) of its range.
+///
+/// Domain bars are drawn thicker; facet bars are thinner and slightly muted.
+#[component]
+pub fn ScoreBar(
+    domain: Domain,
+    percentage: f32,
+    /// Render the thinner facet variant.
+    #[prop(optional)]
+    facet: bool,
+    /// Animate the bar filling up after this delay (skipped with reduced motion).
+    #[prop(optional)]
+    reveal_delay_ms: Option<u32>,
+    /// Average percentage of other people, drawn as a faint bar behind this one.
+    #[prop(optional, into)]
+    baseline: Signal<Option<f32>>,
+) -> impl IntoView {
+    let (track_class, fill_class) = if facet {
+        (
+            "score-bar-track score-bar-track-facet relative w-full rounded-full h-2",
+            "score-bar-fill relative h-2 rounded-full opacity-70",
+        )
+    } else {
+        (
+            "score-bar-track relative w-full rounded-full h-3",
+            "score-bar-fill relative h-3 rounded-full transition-all duration-500",
+        )
+    };
+
+    let fill_class = match reveal_delay_ms {
+        Some(_) => format!("{} score-bar-reveal", fill_class),
+        None => fill_class.to_string(),
+    };
+
+    view! {
+        <div class=track_class>
+            {move || {
+                baseline
+                    .get()
+                    .map(|mean| {
+                        view! {
+                            <div
+                                class="score-bar-baseline absolute inset-y-0 left-0 rounded-full"
+                                style:width=format!("{}%", mean)
+                            />
+                        }
+                    })
+            }}
+            <div
+                class=fill_class
+                style:background-color=domain_color(domain)
+                style:width=format!("{}%", percentage)
+                style:animation-delay=reveal_delay_ms.map(|ms| format!("{}ms", ms))
+            />
+            {move || {
+                baseline
+                    .get()
+                    .map(|mean| {
+                        view! {
+                            <div
+                                class="score-bar-baseline-marker absolute -inset-y-0.5 w-0.5 rounded-full"
+                                style:left=format!("calc({}% - 1px)", mean)
+                            />
+                        }
+                    })
+            }}
+        </div>
+    }
+}
+
+/// Number that counts up from zero after a delay, in step with an animated [`ScoreBar`].
+///
+/// Shows the final value right away with reduced motion.
+#[component]
+pub fn CountUp(
+    value: u8,
+    #[prop(optional)]
+    #[cfg_attr(not(target_arch = "wasm32"), allow(unused_variables))]
+    delay_ms: u32,
+) -> impl IntoView {
+    #[cfg(target_arch = "wasm32")]
+    let shown = {
+        let shown = RwSignal::new(if prefers_reduced_motion() { value } else { 0 });
+        if shown.get_untracked() != value {
+            leptos::task::spawn_local(async move {
+                gloo_timers::future::TimeoutFuture::new(delay_ms).await;
+                let start = js_sys::Date::now();
+                loop {
+                    let t = ((js_sys::Date::now() - start) / REVEAL_MS).min(1.0);
+                    // Ease out (cubic), like the bar animation
+                    let eased = 1.0 - (1.0 - t).powi(3);
+                    // Stop if the component was unmounted mid-animation
+                    if shown
+                        .try_set((f64::from(value) * eased).round() as u8)
+                        .is_some()
+                    {
+                        return;
+                    }
+                    if t >= 1.0 {
+                        break;
+                    }
+                    gloo_timers::future::TimeoutFuture::new(16).await;
+                }
+            });
+        }
+        shown
+    };
+    #[cfg(not(target_arch = "wasm32"))]
+    let shown = RwSignal::new(value);
+
+    view! { <span class="tabular-nums">{move || shown.get()}</span> }
+}
+
+/// Whether the user asked the system to minimize animations.
+#[cfg(target_arch = "wasm32")]
+fn prefers_reduced_motion() -> bool {
+    web_sys::window()
+        .and_then(|w| {
+            w.match_media("(prefers-reduced-motion: reduce)")
+                .ok()
+                .flatten()
+        })
+        .is_some_and(|media| media.matches())
+}
@@ -0,0 +1,151 @@
+//! Locale-aware dates and times.
+//!
+//! Formatting goes through the browser's `Intl` APIs, so the server renders nothing: only
+//! show these in views filled in on the client (loaded from storage or fetched), or the
+//! hydrated text won't match.
+
+use leptos::prelude::*;
+
+use crate::i18n::use_i18n;
+
+/// Current time in milliseconds since the epoch (0 on the server).
+pub(super) fn now_ms() -> f64 {
+    #[cfg(target_arch = "wasm32")]
+    {
+        js_sys::Date::now()
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        0.0
+    }
+}
+
+/// Format a timestamp as a localized date.
+#[cfg_attr(not(target_arch = "wasm32"), allow(unused_variables))]
+pub(super) fn format_date(ms: f64, locale: &str) -> String {
+    #[cfg(target_arch = "wasm32")]
+    {
+        js_sys::Date::new(&wasm_bindgen::JsValue::from_f64(ms))
+            .to_locale_date_string(locale, &wasm_bindgen::JsValue::UNDEFINED)
+            .into()
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        String::new()
+    }
+}
+
+/// Format a timestamp as a localized date and time.
+#[cfg_attr(not(target_arch = "wasm32"), allow(unused_variables))]
+pub(super) fn format_date_time(ms: f64, locale: &str) -> String {
+    #[cfg(target_arch = "wasm32")]
+    {
+        js_sys::Date::new(&wasm_bindgen::JsValue::from_f64(ms))
+            .to_locale_string(locale, &wasm_bindgen::JsValue::UNDEFINED)
+            .into()
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        String::new()
+    }
+}
+
+/// Format a timestamp as a localized month and year, e.g. "March 2026".
+#[cfg_attr(not(target_arch = "wasm32"), allow(unused_variables))]
+pub(super) fn format_month(ms: f64, locale: &str) -> String {
+    #[cfg(target_arch = "wasm32")]
+    {
+        let options = js_sys::Object::new();
+        let _ = js_sys::Reflect::set(&options, &"month".into(), &"long".into());
+        let _ = js_sys::Reflect::set(&options, &"year".into(), &"numeric".into());
+        js_sys::Date::new(&wasm_bindgen::JsValue::from_f64(ms))
+            .to_locale_date_string(locale, &options)
+            .into()
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        String::new()
+    }
+}
+
+/// Format the distance from `now_ms` to `ms` in words, e.g. "2 weeks ago", "yesterday"
+/// or "in 3 days".
+#[cfg_attr(not(target_arch = "wasm32"), allow(unused_variables))]
+pub(super) fn format_relative(ms: f64, now_ms: f64, locale: &str) -> String {
+    #[cfg(target_arch = "wasm32")]
+    {
+        let (value, unit) = relative_unit((ms - now_ms) / 1000.0);
+        let options = js_sys::Object::new();
+        let _ = js_sys::Reflect::set(&options, &"numeric".into(), &"auto".into());
+        let locales = js_sys::Array::of1(&locale.into());
+        js_sys::Intl::RelativeTimeFormat::new(&locales, &options)
+            .format(value, unit)
+            .into()
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        String::new()
+    }
+}
+
+/// Largest unit in which `secs` (negative for the past) is at least one, rounded to it.
+#[cfg(target_arch = "wasm32")]
+fn relative_unit(secs: f64) -> (f64, &'static str) {
+    const UNITS: [(f64, &str); 6] = [
+        (365.0 * 86_400.0, "year"),
+        (30.0 * 86_400.0, "month"),
+        (7.0 * 86_400.0, "week"),
+        (86_400.0, "day"),
+        (3_600.0, "hour"),
+        (60.0, "minute"),
+    ];
+    UNITS
+        .iter()
+        .find(|(unit_secs, _)| secs.abs() >= *unit_secs)
+        .map_or((secs.round(), "second"), |&(unit_secs, unit)| {
+            ((secs / unit_secs).round(), unit)
+        })
+}
+
+/// A point in time as relative words followed by the absolute date, e.g.
+/// "2 weeks ago · 10/3/2026", with the full date and time on hover.
+///
+/// `ms` is milliseconds since the epoch; `with_time` shows the time of day as well.
+#[component]
+pub(super) fn Timestamp(ms: f64, #[prop(optional)] with_time: bool) -> impl IntoView {
+    let i18n = use_i18n();
+    let now = now_ms();
+
+    let absolute = move || {
+        let locale = i18n.get_locale().code();
+        if with_time {
+            format_date_time(ms, locale)
+        } else {
+            format_date(ms, locale)
+        }
+    };
+    view! {
+        <time
+            datetime=iso_string(ms)
+            title=move || format_date_time(ms, i18n.get_locale().code())
+        >
+            {move || format_relative(ms, now, i18n.get_locale().code())}
+            <span class="opacity-70">" · " {absolute}</span>
+        </time>
+    }
+}
+
+/// ISO 8601 form of a timestamp, for `datetime` attributes.
+#[cfg_attr(not(target_arch = "wasm32"), allow(unused_variables))]
+fn iso_string(ms: f64) -> String {
+    #[cfg(target_arch = "wasm32")]
+    {
+        js_sys::Date::new(&wasm_bindgen::JsValue::from_f64(ms))
+            .to_iso_string()
+            .into()
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        String::new()
+    }
+}
@@ -0,0 +1,219 @@
+//! Optional demographics step shown before the first question.
+//!
+//! Age range, gender and country are kept in localStorage and attached to shared results,
+//! so percentiles can later be computed against a matching norm group. Every field can be
+//! left empty, and the whole step can be skipped.
+
+use bigfive::{AgeBand, Demographics, Gender};
+use leptos::prelude::*;
+
+use crate::i18n::use_i18n;
+
+#[cfg(target_arch = "wasm32")]
+const STORAGE_KEY_DEMOGRAPHICS: &str = crate::storage::storage_key!("demographics");
+
+/// ISO 3166-1 alpha-2 codes offered in the country selector.
+const COUNTRY_CODES: &[&str] = &[
+    "AD", "AE", "AF", "AG", "AL", "AM", "AO", "AR", "AT", "AU", "AZ", "BA", "BB", "BD", "BE", "BF",
+    "BG", "BH", "BI", "BJ", "BN", "BO", "BR", "BS", "BT", "BW", "BY", "BZ", "CA", "CD", "CF", "CG",
+    "CH", "CI", "CL", "CM", "CN", "CO", "CR", "CU", "CV", "CY", "CZ", "DE", "DJ", "DK", "DM", "DO",
+    "DZ", "EC", "EE", "EG", "ER", "ES", "ET", "FI", "FJ", "FM", "FR", "GA", "GB", "GD", "GE", "GH",
+    "GM", "GN", "GQ", "GR", "GT", "GW", "GY", "HK", "HN", "HR", "HT", "HU", "ID", "IE", "IL", "IN",
+    "IQ", "IR", "IS", "IT", "JM", "JO", "JP", "KE", "KG", "KH", "KI", "KM", "KN", "KP", "KR", "KW",
+    "KZ", "LA", "LB", "LC", "LI", "LK", "LR", "LS", "LT", "LU", "LV", "LY", "MA", "MC", "MD", "ME",
+    "MG", "MH", "MK", "ML", "MM", "MN", "MR", "MT", "MU", "MV", "MW", "MX", "MY", "MZ", "NA", "NE",
+    "NG", "NI", "NL", "NO", "NP", "NR", "NZ", "OM", "PA", "PE", "PG", "PH", "PK", "PL", "PS", "PT",
+    "PW", "PY", "QA", "RO", "RS", "RU", "RW", "SA", "SB", "SC", "SD", "SE", "SG", "SI", "SK", "SL",
+    "SM", "SN", "SO", "SR", "SS", "ST", "SV", "SY", "SZ", "TD", "TG", "TH", "TJ", "TL", "TM", "TN",
+    "TO", "TR", "TT", "TV", "TW", "TZ", "UA", "UG", "US", "UY", "UZ", "VA", "VC", "VE", "VN", "VU",
+    "WS", "YE", "ZA", "ZM", "ZW",
+];
+
+/// Demographics entered (or skipped, as an empty value) on this browser.
+///
+/// `None` means the step hasn't been shown yet.
+pub fn load_demographics() -> Option<Demographics> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        web_sys::window()
+            .and_then(|w| w.local_storage().ok().flatten())
+            .and_then(|s| s.get_item(STORAGE_KEY_DEMOGRAPHICS).ok().flatten())
+            .and_then(|json| serde_json::from_str(&json).ok())
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        None
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), allow(unused_variables))]
+fn store_demographics(demographics: &Demographics) {
+    #[cfg(target_arch = "wasm32")]
+    {
+        if let Some(storage) = web_sys::window().and_then(|w| w.local_storage().ok().flatten())
+            && let Ok(json) = serde_json::to_string(demographics)
+        {
+            let _ = storage.set_item(STORAGE_KEY_DEMOGRAPHICS, &json);
+        }
+    }
+}
+
+/// Whether `code` is one of the countries offered in the demographics step.
+#[cfg(feature = "ssr")]
+pub(super) fn is_known_country(code: &str) -> bool {
+    COUNTRY_CODES.contains(&code)
+}
+
+/// Country name in the given locale via `Intl.DisplayNames`, falling back to the code.
+pub(super) fn country_name(code: &str, locale: &str) -> String {
+    #[cfg(target_arch = "wasm32")]
+    {
+        use js_sys::{Array, Function, Object, Reflect};
+        use wasm_bindgen::{JsCast, JsValue};
+
+        let name = (|| {
+            let intl = Reflect::get(&js_sys::global(), &"Intl".into()).ok()?;
+            let constructor: Function = Reflect::get(&intl, &"DisplayNames".into())
+                .ok()?
+                .dyn_into()
+                .ok()?;
+            let options = Object::new();
+            Reflect::set(&options, &"type".into(), &"region".into()).ok()?;
+            let args = Array::of2(&Array::of1(&JsValue::from_str(locale)), &options);
+            let display_names = Reflect::construct(&constructor, &args).ok()?;
+            let of: Function = Reflect::get(&display_names, &"of".into())
+                .ok()?
+                .dyn_into()
+                .ok()?;
+            of.call1(&display_names, &JsValue::from_str(code))
+                .ok()?
+                .as_string()
+        })();
+        name.unwrap_or_else(|| code.to_string())
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let _ = locale;
+        code.to_string()
+    }
+}
+
+/// i18n key of a gender option.
+pub(super) fn gender_key(gender: Gender) -> &'static str {
+    match gender {
+        Gender::Female => "demographics_gender_female",
+        Gender::Male => "demographics_gender_male",
+        Gender::Other => "demographics_gender_other",
+    }
+}
+
+const SELECT_CLASS: &str = "w-full mt-1 px-3 py-2 border border-gray-300 dark:border-gray-600 bg-white dark:bg-gray-700 rounded-lg focus:ring-2 focus:ring-indigo-500 focus:border-indigo-500 text-gray-700 dark:text-gray-200";
+
+/// Pre-test form for age range, gender and country; `on_done` runs after saving or skipping.
+#[component]
+pub fn DemographicsStep(#[prop(into)] on_done: Callback<()>) -> impl IntoView {
+    let i18n = use_i18n();
+    let (demographics, set_demographics) = signal(Demographics::default());
+
+    let finish = move |demographics: Demographics| {
+        store_demographics(&demographics);
+        on_done.run(());
+    };
+
+    // Countries sorted by their localized name
+    let countries = move || {
+        let locale = i18n.get_locale().code();
+        let mut countries: Vec<_> = COUNTRY_CODES
+            .iter()
+            .map(|&code| (code, country_name(code, locale)))
+            .collect();
+        countries.sort_by(|a, b| a.1.cmp(&b.1));
+        countries
+    };
+
+    view! {
+        <div class="max-w-2xl mx-auto px-4 py-8">
+            <div class="bg-white dark:bg-gray-800 rounded-lg shadow-md p-8 transition-colors duration-300">
+                <h1 class="text-2xl font-bold text-gray-900 dark:text-white mb-2">
+                    {move || i18n.t("demographics_title")}
+                </h1>
+                <p class="text-gray-600 dark:text-gray-300 mb-6">{move || i18n.t("demographics_description")}</p>
+                <div class="space-y-4 mb-8">
+                    <label class="block text-sm font-medium text-gray-700 dark:text-gray-300">
+                        {move || i18n.t("demographics_age")}
+                        <select
+                            class=SELECT_CLASS
+                            on:change=move |ev| {
+                                let band = AgeBand::from_code(&event_target_value(&ev));
+                                set_demographics.update(|d| d.age_band = band);
+                            }
+                        >
+                            <option value="">{move || i18n.t("demographics_prefer_not")}</option>
+                            {AgeBand::ALL
+                                .iter()
+                                .map(|band| view! { <option value=band.code()>{band.code()}</option> })
+                                .collect_view()}
+                        </select>
+                    </label>
+                    <label class="block text-sm font-medium text-gray-700 dark:text-gray-300">
+                        {move || i18n.t("demographics_gender")}
+                        <select
+                            class=SELECT_CLASS
+                            on:change=move |ev| {
+                                let gender = Gender::from_code(&event_target_value(&ev));
+                                set_demographics.update(|d| d.gender = gender);
+                            }
+                        >
+                            <option value="">{move || i18n.t("demographics_prefer_not")}</option>
+                            {Gender::ALL
+                                .iter()
+                                .map(|&gender| {
+                                    view! { <option value=gender.code()>{move || i18n.t(gender_key(gender))}</option> }
+                                })
+                                .collect_view()}
+                        </select>
+                    </label>
+                    <label class="block text-sm font-medium text-gray-700 dark:text-gray-300">
+                        {move || i18n.t("demographics_country")}
+                        <select
+                            class=SELECT_CLASS
+                            on:change=move |ev| {
+                                let code = Some(event_target_value(&ev)).filter(|c| !c.is_empty());
+                                set_demographics.update(|d| d.country = code);
+                            }
+                        >
+                            <option value="">{move || i18n.t("demographics_prefer_not")}</option>
+                            {move || {
+                                countries()
+                                    .into_iter()
+                                    .map(|(code, name)| {
+                                        let selected = move || demographics.with(|d| d.country.as_deref() == Some(code));
+                                        view! {
+                                            <option value=code selected=selected>
+                                                {name}
+                                            </option>
+                                        }
+                                    })
+                                    .collect_view()
+                            }}
+                        </select>
+                    </label>
+                </div>
+                <div class="flex flex-wrap gap-3">
+                    <button
+                        on:click=move |_| finish(demographics.get_untracked())
+                        class="px-6 py-2 rounded-lg bg-indigo-600 dark:bg-indigo-500 text-white font-medium hover:bg-indigo-700 dark:hover:bg-indigo-600 transition-colors"
+                    >
+                        {move || i18n.t("demographics_continue")}
+                    </button>
+                    <button
+                        on:click=move |_| finish(Demographics::default())
+                        class="px-6 py-2 border border-gray-300 dark:border-gray-600 text-gray-700 dark:text-gray-300 rounded-lg hover:bg-gray-50 dark:hover:bg-gray-700 transition-colors"
+                    >
+                        {move || i18n.t("demographics_skip")}
+                    </button>
+                </div>
+            </div>
+        </div>
+    }
+}
@@ -0,0 +1,259 @@
+//! Keyboard-accessible dropdown menu shared by the locale and theme selectors.
+//!
+//! Follows the WAI-ARIA menu button pattern: the trigger has `aria-haspopup`/`aria-expanded`,
+//! options are `menuitemradio`s, arrow keys/Home/End move focus (Tab is trapped inside the
+//! open menu), Esc closes and returns focus to the trigger, and a click outside closes it.
+
+use leptos::prelude::*;
+
+/// One selectable option in a [`DropdownMenu`].
+pub struct MenuOption {
+    pub label: Signal<String>,
+    /// Whether this option is the active one (rendered with a check mark and `aria-checked`).
+    pub current: Signal<bool>,
+    pub on_select: Callback<()>,
+}
+
+/// Which option receives focus when the menu opens.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(not(target_arch = "wasm32"), allow(dead_code))]
+enum OpenFocus {
+    Current,
+    Last,
+}
+
+/// Dropdown menu with a trigger button; `children` are the trigger's content.
+#[component]
+pub fn DropdownMenu(
+    /// Id of the menu element, referenced by the trigger's `aria-controls`.
+    id: &'static str,
+    /// Accessible name of the trigger button.
+    #[prop(into)]
+    label: Signal<String>,
+    options: Vec<MenuOption>,
+    /// Tailwind width class of the menu.
+    #[prop(default = "w-40")]
+    width: &'static str,
+    children: Children,
+) -> impl IntoView {
+    let (is_open, set_is_open) = signal(false);
+    // Set when opening; applied once the menu is visible
+    let pending_focus = RwSignal::new(None::<OpenFocus>);
+
+    let root_ref = NodeRef::<leptos::html::Div>::new();
+    let trigger_ref = NodeRef::<leptos::html::Button>::new();
+    let menu_ref = NodeRef::<leptos::html::Div>::new();
+
+    let open_with = move |focus: OpenFocus| {
+        pending_focus.set(Some(focus));
+        set_is_open.set(true);
+    };
+    let close = move |return_focus: bool| {
+        set_is_open.set(false);
+        pending_focus.set(None);
+        if return_focus && let Some(trigger) = trigger_ref.get_untracked() {
+            let _ = trigger.focus();
+        }
+    };
+
+    Effect::new(move |_| {
+        let (Some(menu), Some(focus)) = (menu_ref.get(), pending_focus.get()) else {
+            return;
+        };
+        // Wait a frame so the menu is no longer `hidden` and can take focus
+        #[cfg(target_arch = "wasm32")]
+        request_animation_frame(move || {
+            let items = menu_items(&menu);
+            let target = match focus {
+                OpenFocus::Current => items
+                    .iter()
+                    .position(|item| item.get_attribute("aria-checked").as_deref() == Some("true"))
+                    .unwrap_or(0),
+                OpenFocus::Last => items.len().saturating_sub(1),
+            };
+            if let Some(item) = items.get(target) {
+                let _ = item.focus();
+            }
+        });
+        #[cfg(not(target_arch = "wasm32"))]
+        let _ = (menu, focus);
+        pending_focus.set(None);
+    });
+
+    // Close on a click anywhere outside the dropdown
+    #[cfg(target_arch = "wasm32")]
+    {
+        use wasm_bindgen::JsCast;
+
+        let handle = window_event_listener(leptos::ev::pointerdown, move |ev| {
+            if !is_open.get_untracked() {
+                return;
+            }
+            let inside = root_ref.get_untracked().is_some_and(|root| {
+                ev.target()
+                    .and_then(|t| t.dyn_into::<web_sys::Node>().ok())
+                    .is_some_and(|node| root.contains(Some(&node)))
+            });
+            if !inside {
+                close(false);
+            }
+        });
+        on_cleanup(move || handle.remove());
+    }
+
+    let on_trigger_keydown = move |ev: leptos::ev::KeyboardEvent| match ev.key().as_str() {
+        "ArrowDown" | "Enter" | " " => {
+            ev.prevent_default();
+            open_with(OpenFocus::Current);
+        }
+        "ArrowUp" => {
+            ev.prevent_default();
+            open_with(OpenFocus::Last);
+        }
+        "Escape" if is_open.get_untracked() => close(true),
+        _ => {}
+    };
+
+    let on_menu_keydown = move |ev: leptos::ev::KeyboardEvent| {
+        if ev.key() == "Escape" {
+            ev.prevent_default();
+            close(true);
+        }
+        #[cfg(target_arch = "wasm32")]
+        if let Some(menu) = menu_ref.get_untracked() {
+            move_focus(&menu, &ev);
+        }
+    };
+
+    let options = options
+        .into_iter()
+        .map(|option| {
+            let MenuOption {
+                label,
+                current,
+                on_select,
+            } = option;
+            view! {
+                <button
+                    type="button"
+                    role="menuitemradio"
+                    tabindex="-1"
+                    aria-checked=move || current.get().to_string()
+                    on:click=move |_| {
+                        on_select.run(());
+                        close(true);
+                    }
+                    class=move || {
+                        format!(
+                            "w-full px-4 py-2 text-left text-sm transition-colors focus:outline-none focus:bg-gray-100 dark:focus:bg-gray-700 {}",
+                            if current.get() {
+                                "bg-indigo-50 dark:bg-indigo-900/30 text-indigo-700 dark:text-indigo-300 font-medium"
+                            } else {
+                                "text-gray-700 dark:text-gray-300 hover:bg-gray-50 dark:hover:bg-gray-700"
+                            },
+                        )
+                    }
+                >
+                    <span class="flex items-center gap-2">
+                        <Show when=move || current.get() fallback=|| view! { <span class="w-4" /> }>
+                            <svg class="w-4 h-4" fill="currentColor" viewBox="0 0 20 20" aria-hidden="true">
+                                <path
+                                    fill-rule="evenodd"
+                                    d="M16.707 5.293a1 1 0 010 1.414l-8 8a1 1 0 01-1.414 0l-4-4a1 1 0 011.414-1.414L8 12.586l7.293-7.293a1 1 0 011.414 0z"
+                                    clip-rule="evenodd"
+                                />
+                            </svg>
+                        </Show>
+                        {move || label.get()}
+                    </span>
+                </button>
+            }
+        })
+        .collect_view();
+
+    view! {
+        <div class="relative" node_ref=root_ref>
+            <button
+                type="button"
+                node_ref=trigger_ref
+                aria-haspopup="menu"
+                aria-expanded=move || is_open.get().to_string()
+                aria-controls=id
+                aria-label=move || label.get()
+                on:click=move |_| {
+                    if is_open.get_untracked() { close(false) } else { open_with(OpenFocus::Current) }
+                }
+                on:keydown=on_trigger_keydown
+                class="flex items-center gap-2 px-3 py-2 text-sm font-medium text-gray-700 dark:text-gray-200 bg-white dark:bg-gray-800 border border-gray-300 dark:border-gray-600 rounded-lg hover:bg-gray-50 dark:hover:bg-gray-700 focus:outline-none focus:ring-2 focus:ring-offset-2 focus:ring-indigo-500 transition-colors"
+            >
+                {children()}
+                <svg
+                    class=move || format!("w-4 h-4 transition-transform {}", if is_open.get() { "rotate-180" } else { "" })
+                    fill="none"
+                    stroke="currentColor"
+                    viewBox="0 0 24 24"
+                    aria-hidden="true"
+                >
+                    <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M19 9l-7 7-7-7" />
+                </svg>
+            </button>
+            <div
+                id=id
+                role="menu"
+                node_ref=menu_ref
+                aria-label=move || label.get()
+                on:keydown=on_menu_keydown
+                class=move || {
+                    format!(
+                        "absolute right-0 z-20 mt-2 {} bg-white dark:bg-gray-800 rounded-lg shadow-lg border border-gray-200 dark:border-gray-700 py-1 {}",
+                        width,
+                        if is_open.get() { "" } else { "hidden" },
+                    )
+                }
+            >
+                {options}
+            </div>
+        </div>
+    }
+}
+
+/// Option elements of a menu, in document order.
+#[cfg(target_arch = "wasm32")]
+fn menu_items(menu: &web_sys::HtmlDivElement) -> Vec<web_sys::HtmlElement> {
+    use wasm_bindgen::JsCast;
+
+    let Ok(nodes) = menu.query_selector_all("[role=menuitemradio]") else {
+        return Vec::new();
+    };
+    (0..nodes.length())
+        .filter_map(|i| nodes.item(i)?.dyn_into::<web_sys::HtmlElement>().ok())
+        .collect()
+}
+
+/// Move focus between options for arrow keys, Home/End and Tab.
+#[cfg(target_arch = "wasm32")]
+fn move_focus(menu: &web_sys::HtmlDivElement, ev: &web_sys::KeyboardEvent) {
+    let items = menu_items(menu);
+    if items.is_empty() {
+        return;
+    }
+    let last = items.len() - 1;
+    let focused = web_sys::window()
+        .and_then(|w| w.document())
+        .and_then(|d| d.active_element())
+        .and_then(|active| items.iter().position(|item| **item == active));
+    let next = |i: Option<usize>| i.map_or(0, |i| if i == last { 0 } else { i + 1 });
+    let prev = |i: Option<usize>| i.map_or(last, |i| if i == 0 { last } else { i - 1 });
+    let target = match ev.key().as_str() {
+        "ArrowDown" => next(focused),
+        "ArrowUp" => prev(focused),
+        "Home" => 0,
+        "End" => last,
+        // Keep focus inside the open menu
+        "Tab" if ev.shift_key() => prev(focused),
+        "Tab" => next(focused),
+        _ => return,
+    };
+    ev.prevent_default();
+    let _ = items[target].focus();
+}
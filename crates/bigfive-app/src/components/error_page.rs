@@ -0,0 +1,178 @@
+//! Error screens: the route error boundary fallback and the 404 page.
+
+use leptos::prelude::*;
+use leptos_router::components::A;
+use leptos_router::hooks::use_location;
+
+use crate::i18n::use_i18n;
+
+/// Where "Report a problem" links point to.
+const ISSUES_URL: &str = concat!(env!("CARGO_PKG_REPOSITORY"), "/issues/new");
+
+/// Fallback for the route `ErrorBoundary`: localized message, retry and report link.
+#[component]
+pub fn ErrorPage(errors: ArcRwSignal<Errors>) -> impl IntoView {
+    let i18n = use_i18n();
+    let location = use_location();
+
+    // Request id of the page load, read from the SSR shell (client only, avoids hydration mismatch)
+    let (request_id, set_request_id) = signal::<Option<String>>(None);
+    Effect::new(move |_| {
+        set_request_id.set(page_request_id());
+    });
+
+    let messages = {
+        let errors = errors.clone();
+        move || {
+            errors
+                .get()
+                .into_iter()
+                .map(|(_, e)| e.to_string())
+                .collect::<Vec<_>>()
+        }
+    };
+
+    let report_url = {
+        let messages = messages.clone();
+        move || {
+            let body = format!(
+                "Request ID: {}\nPage: {}\nErrors:\n{}",
+                request_id.get().unwrap_or_else(|| "unknown".to_string()),
+                location.pathname.get(),
+                messages().join("\n"),
+            );
+            format!(
+                "{}?title={}&body={}",
+                ISSUES_URL,
+                percent_encode("Error report"),
+                percent_encode(&body)
+            )
+        }
+    };
+
+    let retry = move |_| errors.set(Errors::default());
+
+    view! {
+        <div class="max-w-2xl mx-auto px-4 py-16">
+            <div class="bg-white dark:bg-gray-800 rounded-lg shadow-md p-8 text-center transition-colors duration-300">
+                <div class="flex justify-center mb-4">
+                    <div class="flex items-center justify-center w-14 h-14 rounded-full bg-red-100 dark:bg-red-900/30">
+                        <svg
+                            class="w-7 h-7 text-red-600 dark:text-red-400"
+                            fill="none"
+                            stroke="currentColor"
+                            viewBox="0 0 24 24"
+                        >
+                            <path
+                                stroke-linecap="round"
+                                stroke-linejoin="round"
+                                stroke-width="2"
+                                d="M12 9v2m0 4h.01m-6.938 4h13.856c1.54 0 2.502-1.667 1.732-3L13.732 4c-.77-1.333-2.694-1.333-3.464 0L3.34 16c-.77 1.333.192 3 1.732 3z"
+                            />
+                        </svg>
+                    </div>
+                </div>
+                <h1 class="text-2xl font-bold text-gray-900 dark:text-white mb-2">{move || i18n.t("error_title")}</h1>
+                <p class="text-gray-600 dark:text-gray-300 mb-6">{move || i18n.t("error_description")}</p>
+
+                <ul class="text-sm text-red-700 dark:text-red-300 mb-6 space-y-1">
+                    {move || messages().into_iter().map(|m| view! { <li>{m}</li> }).collect_view()}
+                </ul>
+
+                <Show when=move || request_id.get().is_some()>
+                    <p class="text-xs text-gray-500 dark:text-gray-400 mb-6 font-mono">
+                        {move || format!("{}: {}", i18n.t("error_reference"), request_id.get().unwrap_or_default())}
+                    </p>
+                </Show>
+
+                <div class="flex flex-wrap justify-center gap-3">
+                    <button
+                        on:click=retry
+                        class="px-6 py-2 bg-indigo-600 dark:bg-indigo-500 text-white rounded-lg hover:bg-indigo-700 dark:hover:bg-indigo-600 transition-colors"
+                    >
+                        {move || i18n.t("error_retry")}
+                    </button>
+                    <A
+                        href=move || i18n.get_locale().path_prefix().to_string()
+                        attr:class="px-6 py-2 border border-gray-300 dark:border-gray-600 text-gray-700 dark:text-gray-300 rounded-lg hover:bg-gray-50 dark:hover:bg-gray-700 transition-colors"
+                    >
+                        {move || i18n.t("error_home")}
+                    </A>
+                    <a
+                        href=report_url
+                        target="_blank"
+                        rel="noopener noreferrer"
+                        class="px-6 py-2 border border-gray-300 dark:border-gray-600 text-gray-700 dark:text-gray-300 rounded-lg hover:bg-gray-50 dark:hover:bg-gray-700 transition-colors"
+                    >
+                        {move || i18n.t("error_report")}
+                    </a>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+/// Router fallback for unknown paths.
+#[component]
+pub fn NotFoundPage() -> impl IntoView {
+    let i18n = use_i18n();
+
+    #[cfg(feature = "ssr")]
+    if let Some(response) = use_context::<leptos_axum::ResponseOptions>() {
+        response.set_status(axum::http::StatusCode::NOT_FOUND);
+    }
+
+    view! {
+        <div class="max-w-2xl mx-auto px-4 py-16">
+            <div class="bg-white dark:bg-gray-800 rounded-lg shadow-md p-8 text-center transition-colors duration-300">
+                <p class="text-6xl font-bold text-indigo-600 dark:text-indigo-400 mb-4">"404"</p>
+                <h1 class="text-2xl font-bold text-gray-900 dark:text-white mb-2">{move || i18n.t("not_found_title")}</h1>
+                <p class="text-gray-600 dark:text-gray-300 mb-8">{move || i18n.t("not_found_description")}</p>
+                <div class="flex flex-wrap justify-center gap-3">
+                    <A
+                        href=move || i18n.get_locale().path_prefix().to_string()
+                        attr:class="px-6 py-2 bg-indigo-600 dark:bg-indigo-500 text-white rounded-lg hover:bg-indigo-700 dark:hover:bg-indigo-600 transition-colors"
+                    >
+                        {move || i18n.t("error_home")}
+                    </A>
+                    <A
+                        href=move || format!("{}/test", i18n.get_locale().path_prefix())
+                        attr:class="px-6 py-2 border border-gray-300 dark:border-gray-600 text-gray-700 dark:text-gray-300 rounded-lg hover:bg-gray-50 dark:hover:bg-gray-700 transition-colors"
+                    >
+                        {move || i18n.t("home_start_button")}
+                    </A>
+                </div>
+            </div>
+        </div>
+    }
+}
+
+/// Read the request id the server put into the page's `<meta name="request-id">`.
+fn page_request_id() -> Option<String> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        web_sys::window()?
+            .document()?
+            .query_selector("meta[name='request-id']")
+            .ok()??
+            .get_attribute("content")
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        None
+    }
+}
+
+/// Percent-encode a string for use in a URL query parameter.
+fn percent_encode(value: &str) -> String {
+    let mut encoded = String::with_capacity(value.len());
+    for byte in value.bytes() {
+        match byte {
+            b'A'..=b'Z' | b'a'..=b'z' | b'0'..=b'9' | b'-' | b'_' | b'.' | b'~' => {
+                encoded.push(byte as char)
+            }
+            _ => encoded.push_str(&format!("%{:02X}", byte)),
+        }
+    }
+    encoded
+}
@@ -0,0 +1,115 @@
+//! "Explain" action next to each facet score on the results page.
+//!
+//! Asks the server for a few sentences about one facet at one level. The text doesn't depend
+//! on the rest of the profile, so the server generates it once per facet, level and language
+//! and serves it from the database afterwards.
+
+use bigfive::{Facet, ScoreLevel};
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+
+use crate::i18n::use_i18n;
+
+/// Whether facet explanations are configured and enabled.
+#[server]
+pub async fn get_facet_explanations_enabled() -> Result<bool, ServerFnError> {
+    use crate::config::get_config;
+
+    let config = get_config().map_err(|e| ServerFnError::new(e.to_string()))?;
+    Ok(config.facet_explanations_enabled())
+}
+
+/// Short explanation of a facet score, from the cache or freshly generated.
+#[server]
+pub async fn explain_facet(
+    facet: Facet,
+    level: ScoreLevel,
+    lang: String,
+) -> Result<String, ServerFnError> {
+    use crate::ai::generate_facet_explanation;
+    use crate::db;
+    use crate::i18n::Locale;
+
+    if !Locale::ALL.iter().any(|l| l.code() == lang) {
+        return Err(ServerFnError::new(format!("Unsupported language: {lang}")));
+    }
+
+    let facet_code = format!("{}{}", facet.domain().code(), facet.index());
+    let level_code = match level {
+        ScoreLevel::Low => "low",
+        ScoreLevel::Neutral => "neutral",
+        ScoreLevel::High => "high",
+    };
+
+    match db::get_facet_explanation(&facet_code, level_code, &lang).await {
+        Ok(Some(text)) => return Ok(text),
+        Ok(None) => {}
+        Err(e) => tracing::warn!("Failed to read cached facet explanation: {}", e),
+    }
+
+    let text = generate_facet_explanation(facet, level, &lang)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    if let Err(e) = db::save_facet_explanation(&facet_code, level_code, &lang, &text).await {
+        tracing::warn!("Failed to cache facet explanation: {}", e);
+    }
+    Ok(text)
+}
+
+/// "Explain" link that loads and shows the explanation of a facet score in place.
+#[component]
+pub fn FacetExplanation(facet: Facet, level: ScoreLevel) -> impl IntoView {
+    let i18n = use_i18n();
+
+    let (explanation, set_explanation) = signal::<Option<String>>(None);
+    let (loading, set_loading) = signal(false);
+    let (error, set_error) = signal(false);
+
+    let explain = move |_| {
+        set_loading.set(true);
+        set_error.set(false);
+        let lang = i18n.get_locale().code().to_string();
+        spawn_local(async move {
+            match explain_facet(facet, level, lang).await {
+                Ok(text) => set_explanation.set(Some(text)),
+                Err(_) => set_error.set(true),
+            }
+            set_loading.set(false);
+        });
+    };
+
+    view! {
+        <div class="no-print mt-1 text-xs">
+            {move || match explanation.get() {
+                Some(text) => {
+                    view! { <p class="text-sm text-gray-600 dark:text-gray-300">{text}</p> }
+                        .into_any()
+                }
+                None => {
+                    view! {
+                        <button
+                            type="button"
+                            on:click=explain
+                            disabled=move || loading.get()
+                            class="text-indigo-600 dark:text-indigo-400 hover:underline disabled:opacity-50 disabled:no-underline"
+                        >
+                            {move || {
+                                if loading.get() {
+                                    i18n.t("facet_explain_loading")
+                                } else {
+                                    i18n.t("facet_explain")
+                                }
+                            }}
+                        </button>
+                        <Show when=move || error.get()>
+                            <span class="ml-2 text-red-600 dark:text-red-400">
+                                {move || i18n.t("facet_explain_error")}
+                            </span>
+                        </Show>
+                    }
+                        .into_any()
+                }
+            }}
+        </div>
+    }
+}
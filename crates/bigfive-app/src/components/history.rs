@@ -0,0 +1,232 @@
+//! Local history of shared results created from this browser.
+//!
+//! Kept in localStorage only, so it works without accounts and never leaves the device.
+
+use bigfive::{CommonProfile, Domain, Facet, PersonalityProfile};
+use leptos::prelude::*;
+use leptos_router::components::A;
+use serde::{Deserialize, Serialize};
+
+use crate::components::dates::{Timestamp, now_ms};
+use crate::components::domain_color;
+use crate::i18n::use_i18n;
+
+#[cfg(target_arch = "wasm32")]
+const STORAGE_KEY_HISTORY: &str = crate::storage::storage_key!("history");
+
+/// Maximum number of entries kept; older ones are dropped.
+const MAX_HISTORY_ENTRIES: usize = 20;
+
+/// Number of highest-scoring domains remembered per entry.
+const TOP_TRAITS: usize = 2;
+
+/// A shared result created from this browser.
+#[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
+pub struct HistoryEntry {
+    pub id: String,
+    /// Locale the result was saved in (path prefix for the link).
+    pub lang: String,
+    /// Creation time in milliseconds since the Unix epoch.
+    pub created_at_ms: f64,
+    /// When the link stops working, in milliseconds since the Unix epoch.
+    pub expires_at_ms: Option<f64>,
+    /// Highest-scoring domains, highest first.
+    pub top_traits: Vec<Domain>,
+}
+
+/// Highest-scoring domains of a profile, highest first.
+///
+/// Compared on the instrument-independent scale, so entries from any inventory line up.
+fn top_traits(profile: &PersonalityProfile) -> Vec<Domain> {
+    let mut domains = CommonProfile::from(profile).domains;
+    domains.sort_by(|a, b| b.1.total_cmp(&a.1));
+    domains.iter().take(TOP_TRAITS).map(|(d, _)| *d).collect()
+}
+
+/// Remember a newly shared result.
+#[cfg_attr(not(target_arch = "wasm32"), allow(dead_code))]
+pub fn record_history(
+    id: &str,
+    lang: &str,
+    profile: &PersonalityProfile,
+    expires_in_days: Option<u32>,
+) {
+    let now = now_ms();
+    let mut entries = load_history();
+    entries.retain(|e| e.id != id);
+    entries.insert(
+        0,
+        HistoryEntry {
+            id: id.to_string(),
+            lang: lang.to_string(),
+            created_at_ms: now,
+            expires_at_ms: expires_in_days.map(|days| now + f64::from(days) * 86_400_000.0),
+            top_traits: top_traits(profile),
+        },
+    );
+    entries.truncate(MAX_HISTORY_ENTRIES);
+    store_history(&entries);
+}
+
+/// Forget a result (e.g. after it was deleted), returning its entry.
+pub fn remove_history(id: &str) -> Option<HistoryEntry> {
+    let mut entries = load_history();
+    let index = entries.iter().position(|e| e.id == id)?;
+    let entry = entries.remove(index);
+    store_history(&entries);
+    Some(entry)
+}
+
+/// Put back an entry returned by [`remove_history`], keeping newest first.
+pub fn restore_history(entry: HistoryEntry) {
+    let mut entries = load_history();
+    entries.retain(|e| e.id != entry.id);
+    let index = entries
+        .iter()
+        .position(|e| e.created_at_ms < entry.created_at_ms)
+        .unwrap_or(entries.len());
+    entries.insert(index, entry);
+    entries.truncate(MAX_HISTORY_ENTRIES);
+    store_history(&entries);
+}
+
+fn load_history() -> Vec<HistoryEntry> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        web_sys::window()
+            .and_then(|w| w.local_storage().ok().flatten())
+            .and_then(|s| s.get_item(STORAGE_KEY_HISTORY).ok().flatten())
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default()
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        Vec::new()
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), allow(unused_variables))]
+fn store_history(entries: &[HistoryEntry]) {
+    #[cfg(target_arch = "wasm32")]
+    {
+        if let Some(storage) = web_sys::window().and_then(|w| w.local_storage().ok().flatten())
+            && let Ok(json) = serde_json::to_string(entries)
+        {
+            let _ = storage.set_item(STORAGE_KEY_HISTORY, &json);
+        }
+    }
+}
+
+/// i18n key of a domain's name.
+pub(crate) fn domain_key(domain: Domain) -> &'static str {
+    match domain {
+        Domain::Neuroticism => "domain_neuroticism",
+        Domain::Extraversion => "domain_extraversion",
+        Domain::Openness => "domain_openness",
+        Domain::Agreeableness => "domain_agreeableness",
+        Domain::Conscientiousness => "domain_conscientiousness",
+    }
+}
+
+/// i18n key of a facet's name.
+pub(crate) fn facet_key(facet: Facet) -> &'static str {
+    match facet {
+        Facet::Anxiety => "facet_anxiety",
+        Facet::Anger => "facet_anger",
+        Facet::Depression => "facet_depression",
+        Facet::SelfConsciousness => "facet_self_consciousness",
+        Facet::Immoderation => "facet_immoderation",
+        Facet::Vulnerability => "facet_vulnerability",
+        Facet::Friendliness => "facet_friendliness",
+        Facet::Gregariousness => "facet_gregariousness",
+        Facet::Assertiveness => "facet_assertiveness",
+        Facet::ActivityLevel => "facet_activity_level",
+        Facet::ExcitementSeeking => "facet_excitement_seeking",
+        Facet::Cheerfulness => "facet_cheerfulness",
+        Facet::Imagination => "facet_imagination",
+        Facet::ArtisticInterests => "facet_artistic_interests",
+        Facet::Emotionality => "facet_emotionality",
+        Facet::Adventurousness => "facet_adventurousness",
+        Facet::Intellect => "facet_intellect",
+        Facet::Liberalism => "facet_liberalism",
+        Facet::Trust => "facet_trust",
+        Facet::Morality => "facet_morality",
+        Facet::Altruism => "facet_altruism",
+        Facet::Cooperation => "facet_cooperation",
+        Facet::Modesty => "facet_modesty",
+        Facet::Sympathy => "facet_sympathy",
+        Facet::SelfEfficacy => "facet_self_efficacy",
+        Facet::Orderliness => "facet_orderliness",
+        Facet::Dutifulness => "facet_dutifulness",
+        Facet::AchievementStriving => "facet_achievement_striving",
+        Facet::SelfDiscipline => "facet_self_discipline",
+        Facet::Cautiousness => "facet_cautiousness",
+    }
+}
+
+/// List of past shared results on the home page; hidden when empty.
+#[component]
+pub fn ResultsHistory() -> impl IntoView {
+    let i18n = use_i18n();
+
+    // Loaded on the client only (avoids hydration mismatch)
+    let (entries, set_entries) = signal(Vec::<HistoryEntry>::new());
+    Effect::new(move |_| {
+        let now = now_ms();
+        let mut list = load_history();
+        list.retain(|e| e.expires_at_ms.is_none_or(|expires| expires > now));
+        set_entries.set(list);
+    });
+
+    view! {
+        <Show when=move || !entries.get().is_empty()>
+            <div class="bg-white dark:bg-gray-800 rounded-lg shadow-md p-6 mt-6 transition-colors duration-300">
+                <h2 class="text-lg font-semibold text-gray-800 dark:text-gray-100 mb-4">
+                    {move || i18n.t("history_title")}
+                </h2>
+                <ul class="divide-y divide-gray-200 dark:divide-gray-700">
+                    {move || {
+                        entries
+                            .get()
+                            .into_iter()
+                            .map(|entry| {
+                                let href = format!("/{}/results/{}", entry.lang, entry.id);
+                                let created_at_ms = entry.created_at_ms;
+                                let traits = entry
+                                    .top_traits
+                                    .iter()
+                                    .map(|&domain| {
+                                        view! {
+                                            <span class="inline-flex items-center gap-1.5">
+                                                <span
+                                                    class="w-2 h-2 rounded-full"
+                                                    style:background-color=domain_color(domain)
+                                                />
+                                                {i18n.t(domain_key(domain))}
+                                            </span>
+                                        }
+                                    })
+                                    .collect_view();
+                                view! {
+                                    <li>
+                                        <A
+                                            href=href
+                                            attr:class="flex flex-wrap items-center justify-between gap-2 py-3 hover:text-indigo-600 dark:hover:text-indigo-400 transition-colors"
+                                        >
+                                            <span class="font-medium text-gray-700 dark:text-gray-200">
+                                                <Timestamp ms=created_at_ms />
+                                            </span>
+                                            <span class="flex flex-wrap gap-3 text-sm text-gray-500 dark:text-gray-400">
+                                                {traits}
+                                            </span>
+                                        </A>
+                                    </li>
+                                }
+                            })
+                            .collect_view()
+                    }}
+                </ul>
+            </div>
+        </Show>
+    }
+}
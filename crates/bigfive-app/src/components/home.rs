@@ -1,17 +1,18 @@
 //! Home page component with landing section and info.
 
+use bigfive::Domain;
 use leptos::prelude::*;
 use leptos_router::components::A;
 
-use crate::components::{LangToggle, ThemeToggle};
+use crate::components::{ResultsHistory, ResumeForm, domain_color};
 use crate::i18n::use_i18n;
 
 /// Domain trait with description.
 #[component]
-fn DomainItem(color: &'static str, name: &'static str, description: &'static str) -> impl IntoView {
+fn DomainItem(domain: Domain, name: &'static str, description: &'static str) -> impl IntoView {
     view! {
         <div class="flex items-start">
-            <div class=format!("w-3 h-3 rounded-full {} mt-1.5 mr-3 flex-shrink-0", color) />
+            <div class="w-3 h-3 rounded-full mt-1.5 mr-3 flex-shrink-0" style:background-color=domain_color(domain) />
             <div>
                 <h4 class="font-medium text-gray-800 dark:text-gray-100">{name}</h4>
                 <p class="text-sm text-gray-500 dark:text-gray-400">{description}</p>
@@ -29,27 +30,27 @@ fn DomainList() -> impl IntoView {
     let domains = Memo::new(move |_| {
         vec![
             (
-                "bg-red-500",
+                Domain::Neuroticism,
                 i18n.t("domain_neuroticism"),
                 i18n.t("domain_n_desc"),
             ),
             (
-                "bg-yellow-500",
+                Domain::Extraversion,
                 i18n.t("domain_extraversion"),
                 i18n.t("domain_e_desc"),
             ),
             (
-                "bg-purple-500",
+                Domain::Openness,
                 i18n.t("domain_openness"),
                 i18n.t("domain_o_desc"),
             ),
             (
-                "bg-green-500",
+                Domain::Agreeableness,
                 i18n.t("domain_agreeableness"),
                 i18n.t("domain_a_desc"),
             ),
             (
-                "bg-blue-500",
+                Domain::Conscientiousness,
                 i18n.t("domain_conscientiousness"),
                 i18n.t("domain_c_desc"),
             ),
@@ -58,8 +59,8 @@ fn DomainList() -> impl IntoView {
 
     view! {
         <div class="space-y-4">
-            <For each=move || domains.get() key=|(color, name, _)| format!("{}{}", color, name) let:item>
-                <DomainItem color=item.0 name=item.1 description=item.2 />
+            <For each=move || domains.get() key=|(domain, name, _)| format!("{}{}", domain.code(), name) let:item>
+                <DomainItem domain=item.0 name=item.1 description=item.2 />
             </For>
         </div>
     }
@@ -82,22 +83,6 @@ fn StatsPills() -> impl IntoView {
     }
 }
 
-/// Header with title and toggles.
-#[component]
-fn PageHeader() -> impl IntoView {
-    let i18n = use_i18n();
-
-    view! {
-        <header class="flex justify-between items-start mb-6">
-            <h1 class="text-3xl font-bold text-gray-900 dark:text-white">{move || i18n.t("title")}</h1>
-            <div class="flex items-center gap-3">
-                <LangToggle />
-                <ThemeToggle />
-            </div>
-        </header>
-    }
-}
-
 /// Home page with landing section.
 #[component]
 pub fn HomePage() -> impl IntoView {
@@ -108,7 +93,7 @@ pub fn HomePage() -> impl IntoView {
             <StatsPills />
 
             <div class="bg-white dark:bg-gray-800 rounded-lg shadow-md p-8 transition-colors duration-300">
-                <PageHeader />
+                <h1 class="text-3xl font-bold text-gray-900 dark:text-white mb-6">{move || i18n.t("title")}</h1>
 
                 <h2 class="text-2xl font-semibold text-gray-800 dark:text-gray-100 mb-4">
                     {move || i18n.t("home_subtitle")}
@@ -130,7 +115,17 @@ pub fn HomePage() -> impl IntoView {
                 >
                     {move || i18n.t("home_start_button")}
                 </A>
+
+                <p class="mt-4 text-sm text-gray-500 dark:text-gray-400">
+                    <A href="import" attr:class="text-indigo-600 dark:text-indigo-400 hover:underline">
+                        {move || i18n.t("import_link")}
+                    </A>
+                </p>
+
+                <ResumeForm />
             </div>
+
+            <ResultsHistory />
         </div>
     }
 }
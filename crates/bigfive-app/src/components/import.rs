@@ -0,0 +1,195 @@
+//! Import answers from another IPIP-NEO-120 administration and score them here.
+
+use bigfive::PersonalityProfile;
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use leptos_router::hooks::use_navigate;
+
+use crate::i18n::use_i18n;
+
+#[cfg_attr(not(target_arch = "wasm32"), allow(dead_code))]
+const STORAGE_KEY_PROFILE: &str = crate::storage::storage_key!("profile");
+
+/// Score answers uploaded as CSV or JSON (see [`crate::import`] for the formats).
+///
+/// Only scores: the browser keeps the profile like one from a finished test, and sharing
+/// it goes through [`save_results`](super::results::save_results) as usual.
+#[server]
+pub async fn score_imported_answers(
+    lang: String,
+    data: String,
+) -> Result<PersonalityProfile, ServerFnError> {
+    use crate::import::{MAX_IMPORT_BYTES, parse_answers};
+
+    if data.len() > MAX_IMPORT_BYTES {
+        return Err(ServerFnError::new(format!(
+            "The file is too large (max {} KB)",
+            MAX_IMPORT_BYTES / 1024
+        )));
+    }
+    let inventory = crate::inventory::inventory(&lang)
+        .ok_or_else(|| ServerFnError::new(format!("Unsupported language: {lang}")))?;
+    let answers = parse_answers(&inventory, &data).map_err(ServerFnError::new)?;
+
+    let issues = bigfive::validate_answers(&inventory, &answers);
+    if let Some(issue) = issues.first() {
+        let problem = match issue {
+            bigfive::AnswerIssue::Missing { .. } => "missing answer",
+            bigfive::AnswerIssue::InvalidValue { .. } => "answer outside 1-5",
+            bigfive::AnswerIssue::Duplicate { .. } => "answered more than once",
+            bigfive::AnswerIssue::UnknownQuestion { .. } => "not a question of this inventory",
+        };
+        return Err(ServerFnError::new(format!(
+            "{} problem(s) in the answers, first: {} ({problem})",
+            issues.len(),
+            issue.question_id()
+        )));
+    }
+
+    let profile =
+        bigfive::calculate(&inventory, &answers).map_err(|e| ServerFnError::new(e.to_string()))?;
+    tracing::info!(lang = %lang, "Scored imported answers");
+    Ok(profile)
+}
+
+/// Page with a file picker and text box for answers from elsewhere; scored answers open on
+/// the results page.
+#[component]
+pub fn ImportPage() -> impl IntoView {
+    let i18n = use_i18n();
+    let navigate = StoredValue::new(use_navigate());
+    let data = RwSignal::new(String::new());
+    let error = RwSignal::new(None::<String>);
+    let pending = RwSignal::new(false);
+
+    let on_file = move |ev: leptos::ev::Event| {
+        #[cfg(target_arch = "wasm32")]
+        {
+            use wasm_bindgen::JsCast;
+
+            let Some(file) = ev
+                .target()
+                .and_then(|t| t.dyn_into::<web_sys::HtmlInputElement>().ok())
+                .and_then(|input| input.files())
+                .and_then(|files| files.get(0))
+            else {
+                return;
+            };
+            spawn_local(async move {
+                match wasm_bindgen_futures::JsFuture::from(file.text()).await {
+                    Ok(text) => {
+                        data.set(text.as_string().unwrap_or_default());
+                        error.set(None);
+                    }
+                    Err(_) => error.set(Some(i18n.t("import_read_failed").to_string())),
+                }
+            });
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        let _ = ev;
+    };
+
+    let submit = move |_| {
+        pending.set(true);
+        error.set(None);
+        let lang = i18n.get_locale().code().to_string();
+        spawn_local(async move {
+            match score_imported_answers(lang, data.get_untracked()).await {
+                Ok(profile) => {
+                    store_profile(&profile);
+                    navigate.with_value(|nav| {
+                        nav(
+                            &format!("{}/results", i18n.get_locale().path_prefix()),
+                            Default::default(),
+                        )
+                    });
+                }
+                Err(e) => {
+                    error.set(Some(match e {
+                        ServerFnError::ServerError(message) => message,
+                        other => other.to_string(),
+                    }));
+                }
+            }
+            pending.set(false);
+        });
+    };
+
+    view! {
+        <div class="max-w-4xl mx-auto px-4 py-8">
+            <div class="bg-white dark:bg-gray-800 rounded-lg shadow-md p-8 transition-colors duration-300">
+                <h1 class="text-3xl font-bold text-gray-900 dark:text-white mb-4">
+                    {move || i18n.t("import_title")}
+                </h1>
+                <p class="text-gray-600 dark:text-gray-300 leading-relaxed mb-2">
+                    {move || i18n.t("import_description")}
+                </p>
+                <p class="text-sm text-gray-500 dark:text-gray-400 mb-6">
+                    {move || i18n.t("import_formats")}
+                </p>
+                <pre class="text-xs bg-gray-50 dark:bg-gray-900 text-gray-700 dark:text-gray-300 rounded-lg p-3 mb-6 overflow-x-auto">
+                    "item,value\n1,4\n2,2\n...\n120,5"
+                </pre>
+
+                <label class="block text-sm font-medium text-gray-700 dark:text-gray-200 mb-2" for="import-file">
+                    {move || i18n.t("import_file_label")}
+                </label>
+                <input
+                    id="import-file"
+                    type="file"
+                    accept=".csv,.json,.txt,text/csv,application/json"
+                    on:change=on_file
+                    class="block w-full text-sm text-gray-600 dark:text-gray-300 mb-4"
+                />
+                <label class="block text-sm font-medium text-gray-700 dark:text-gray-200 mb-2" for="import-data">
+                    {move || i18n.t("import_paste_label")}
+                </label>
+                <textarea
+                    id="import-data"
+                    rows="8"
+                    class="w-full px-3 py-2 font-mono text-sm border border-gray-300 dark:border-gray-600 bg-white dark:bg-gray-700 rounded-lg focus:ring-2 focus:ring-indigo-500 focus:border-indigo-500 text-gray-700 dark:text-gray-200 mb-4"
+                    prop:value=move || data.get()
+                    on:input=move |ev| data.set(event_target_value(&ev))
+                />
+
+                {move || {
+                    error
+                        .get()
+                        .map(|message| {
+                            view! {
+                                <p class="mb-4 p-3 rounded-lg bg-red-50 dark:bg-red-900/30 text-sm text-red-700 dark:text-red-300">
+                                    {format!("{} {}", i18n.t("import_failed"), message)}
+                                </p>
+                            }
+                        })
+                }}
+
+                <button
+                    on:click=submit
+                    disabled=move || pending.get() || data.read().trim().is_empty()
+                    class="w-full sm:w-auto px-8 py-3 bg-indigo-600 dark:bg-indigo-500 text-white font-semibold rounded-lg hover:bg-indigo-700 dark:hover:bg-indigo-600 disabled:opacity-50 disabled:cursor-not-allowed transition-colors"
+                >
+                    {move || {
+                        if pending.get() { i18n.t("import_scoring") } else { i18n.t("import_submit") }
+                    }}
+                </button>
+            </div>
+        </div>
+    }
+}
+
+/// Keep an imported profile as the current result, like the test page does.
+fn store_profile(profile: &PersonalityProfile) {
+    #[cfg(target_arch = "wasm32")]
+    {
+        super::changes::archive_profile();
+        let Some(storage) = web_sys::window().and_then(|w| w.local_storage().ok().flatten()) else {
+            return;
+        };
+        if let Ok(json) = serde_json::to_string(profile) {
+            let _ = storage.set_item(STORAGE_KEY_PROFILE, &json);
+        }
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    let _ = profile;
+}
@@ -0,0 +1,156 @@
+//! Site-wide header and footer shared by all locale pages.
+
+use leptos::prelude::*;
+use leptos_router::components::A;
+
+use crate::branding::branding;
+use crate::components::{LangToggle, ThemeToggle};
+use crate::i18n::use_i18n;
+
+/// Link to the source repository.
+const REPOSITORY_URL: &str = env!("CARGO_PKG_REPOSITORY");
+
+/// Header with home link, navigation and locale/theme toggles.
+#[component]
+pub fn AppHeader() -> impl IntoView {
+    let i18n = use_i18n();
+    let prefix = move || i18n.get_locale().path_prefix();
+    let site = branding();
+
+    view! {
+        <header class="no-print border-b border-gray-200 dark:border-gray-800 bg-white/80 dark:bg-gray-900/80 transition-colors duration-300">
+            <div class="max-w-4xl mx-auto px-4 py-3 flex flex-wrap items-center justify-between gap-3">
+                <A
+                    href=move || prefix().to_string()
+                    attr:class="flex items-center gap-2 text-lg font-bold text-gray-900 dark:text-white hover:text-indigo-600 dark:hover:text-indigo-400 transition-colors"
+                >
+                    {site.logo_url.as_deref().map(|logo| view! { <img src=logo alt="" class="h-8 w-auto" /> })}
+                    {move || site.site_name.as_deref().unwrap_or_else(|| i18n.t("title"))}
+                </A>
+                <div class="flex items-center gap-3">
+                    <nav class="flex items-center gap-4 text-sm">
+                        <A
+                            href=move || format!("{}/about", prefix())
+                            attr:class="text-gray-600 dark:text-gray-300 hover:text-indigo-600 dark:hover:text-indigo-400 transition-colors"
+                        >
+                            {move || i18n.t("nav_about")}
+                        </A>
+                        <A
+                            href=move || format!("{}/about#faq", prefix())
+                            attr:class="text-gray-600 dark:text-gray-300 hover:text-indigo-600 dark:hover:text-indigo-400 transition-colors"
+                        >
+                            {move || i18n.t("nav_faq")}
+                        </A>
+                    </nav>
+                    <LangToggle />
+                    <ThemeToggle />
+                </div>
+            </div>
+        </header>
+    }
+}
+
+/// Footer with navigation, source link and the running server version.
+#[component]
+pub fn AppFooter() -> impl IntoView {
+    let i18n = use_i18n();
+    let prefix = move || i18n.get_locale().path_prefix();
+
+    // Fetched on the client so the footer shows what the server is actually running
+    let (version, set_version) = signal::<Option<String>>(None);
+    Effect::new(move |_| {
+        #[cfg(target_arch = "wasm32")]
+        leptos::task::spawn_local(async move {
+            set_version.set(fetch_version().await);
+        });
+        #[cfg(not(target_arch = "wasm32"))]
+        let _ = set_version;
+    });
+
+    view! {
+        <footer class="no-print border-t border-gray-200 dark:border-gray-800 transition-colors duration-300">
+            <div class="max-w-4xl mx-auto px-4 py-6 flex flex-wrap items-center justify-between gap-3 text-sm text-gray-500 dark:text-gray-400">
+                <nav class="flex flex-wrap items-center gap-4">
+                    <A href=move || prefix().to_string() attr:class="hover:text-indigo-600 dark:hover:text-indigo-400">
+                        {move || i18n.t("nav_home")}
+                    </A>
+                    <A
+                        href=move || format!("{}/about", prefix())
+                        attr:class="hover:text-indigo-600 dark:hover:text-indigo-400"
+                    >
+                        {move || i18n.t("nav_about")}
+                    </A>
+                    <A
+                        href=move || format!("{}/about#faq", prefix())
+                        attr:class="hover:text-indigo-600 dark:hover:text-indigo-400"
+                    >
+                        {move || i18n.t("nav_faq")}
+                    </A>
+                    <A
+                        href=move || format!("{}/stats", prefix())
+                        attr:class="hover:text-indigo-600 dark:hover:text-indigo-400"
+                    >
+                        {move || i18n.t("nav_stats")}
+                    </A>
+                    <a
+                        href=REPOSITORY_URL
+                        target="_blank"
+                        rel="noopener noreferrer"
+                        class="hover:text-indigo-600 dark:hover:text-indigo-400"
+                    >
+                        {move || i18n.t("nav_source")}
+                    </a>
+                    {branding()
+                        .footer_links
+                        .iter()
+                        .map(|(label, url)| {
+                            // Site-relative links stay in this tab
+                            let external = !url.starts_with('/');
+                            view! {
+                                <a
+                                    href=url.clone()
+                                    target=external.then_some("_blank")
+                                    rel=external.then_some("noopener noreferrer")
+                                    class="hover:text-indigo-600 dark:hover:text-indigo-400"
+                                >
+                                    {label.clone()}
+                                </a>
+                            }
+                        })
+                        .collect_view()}
+                </nav>
+                <Show when=move || version.get().is_some()>
+                    <span class="font-mono text-xs">{move || version.get().unwrap_or_default()}</span>
+                </Show>
+            </div>
+        </footer>
+    }
+}
+
+/// Fetch `/api/version` and format it as `v<version> (<git hash>)`.
+#[cfg(target_arch = "wasm32")]
+async fn fetch_version() -> Option<String> {
+    use wasm_bindgen::JsCast;
+    use wasm_bindgen_futures::JsFuture;
+
+    let window = web_sys::window()?;
+    let response: web_sys::Response = JsFuture::from(window.fetch_with_str("/api/version"))
+        .await
+        .ok()?
+        .dyn_into()
+        .ok()?;
+    if !response.ok() {
+        return None;
+    }
+    let text = JsFuture::from(response.text().ok()?)
+        .await
+        .ok()?
+        .as_string()?;
+    let info: serde_json::Value = serde_json::from_str(&text).ok()?;
+
+    let version = info.get("version")?.as_str()?;
+    Some(match info.get("git_hash").and_then(|h| h.as_str()) {
+        Some(hash) if hash != "unknown" => format!("v{} ({})", version, hash),
+        _ => format!("v{}", version),
+    })
+}
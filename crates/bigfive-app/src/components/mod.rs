@@ -1,13 +1,71 @@
 //! UI components for the Big Five test application.
 
+mod about;
+mod admin;
+mod ai_consent;
+mod bookmarks;
+mod changes;
+mod chart;
+mod dates;
+mod demographics;
+mod dropdown;
+mod error_page;
+mod explain;
+mod history;
 mod home;
+mod import;
 mod lang_toggle;
+mod layout;
+mod norms;
+mod question_timing;
+mod quota;
+mod reminder;
+mod research;
 mod results;
+mod resume;
+mod share_dialog;
+mod stats;
 mod test;
 mod theme_toggle;
+mod traits;
 
+pub use about::AboutPage;
+pub use admin::AdminPage;
+pub use chart::{CountUp, ScoreBar, domain_color};
+pub use error_page::{ErrorPage, NotFoundPage};
+pub use history::ResultsHistory;
 pub use home::HomePage;
+pub use import::ImportPage;
 pub use lang_toggle::LangToggle;
+pub use layout::{AppFooter, AppHeader};
+pub use norms::MIN_NORM_SAMPLE;
+pub use reminder::REMINDER_MONTHS;
 pub use results::ResultsPage;
+pub use resume::ResumeForm;
+pub use stats::StatsPage;
 pub use test::TestPage;
 pub use theme_toggle::ThemeToggle;
+pub use traits::TraitPage;
+
+#[cfg(feature = "ssr")]
+pub(crate) use history::{domain_key, facet_key};
+#[cfg(feature = "ssr")]
+pub(crate) use results::level_key;
+
+// Server functions with their own rate limits (see `crate::rate_limit`)
+#[cfg(feature = "ssr")]
+pub(crate) use admin::{
+    AdminAuditLog, AdminCleanupStats, AdminExportResearch, AdminFindProviderLog, AdminPurgeResult,
+    AdminQuestionTimings, AdminRecentAnalyses, AdminRecentProviderLogs, AdminSearchResults,
+    AdminSetUnpublished,
+};
+#[cfg(feature = "ssr")]
+pub(crate) use explain::ExplainFacet;
+#[cfg(feature = "ssr")]
+pub(crate) use import::ScoreImportedAnswers;
+#[cfg(feature = "ssr")]
+pub(crate) use norms::GetNorms;
+#[cfg(feature = "ssr")]
+pub(crate) use results::{SaveResults, StartAnalysis};
+#[cfg(feature = "ssr")]
+pub(crate) use resume::SaveProgress;
@@ -0,0 +1,144 @@
+//! Reference groups for percentile ranks on the results page.
+//!
+//! Norms are built from the results saved on this deployment: everyone, people who took
+//! the test in the same language, or people from the same country (when the demographics
+//! step was answered). The server sends the group's score distribution and the browser
+//! computes the percentiles, so switching groups doesn't resend the profile.
+
+use bigfive::ScoreDistribution;
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::components::demographics::country_name;
+use crate::i18n::use_i18n;
+
+/// Minimum group size before percentiles are shown.
+pub const MIN_NORM_SAMPLE: usize = 30;
+
+/// Reference group percentiles are computed against.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum NormGroup {
+    /// Every saved result.
+    #[default]
+    All,
+    /// Results saved in this interface language.
+    Language(String),
+    /// Results whose demographics name this ISO 3166-1 alpha-2 country.
+    Country(String),
+}
+
+impl NormGroup {
+    /// Value of the group's `<option>`.
+    fn value(&self) -> String {
+        match self {
+            NormGroup::All => "all".to_string(),
+            NormGroup::Language(lang) => format!("lang:{lang}"),
+            NormGroup::Country(country) => format!("country:{country}"),
+        }
+    }
+}
+
+/// Score distribution of a norm group, or `None` if the group is too small to be useful.
+#[server]
+pub async fn get_norms(group: NormGroup) -> Result<Option<ScoreDistribution>, ServerFnError> {
+    use crate::components::demographics::is_known_country;
+    use crate::db;
+    use crate::i18n::Locale;
+    use crate::tenancy::current_namespace;
+
+    // Each group is cached and costs a scan of the saved profiles, so only groups the
+    // picker can offer are accepted
+    let tenant = current_namespace().to_string();
+    let filter = match group {
+        NormGroup::All => db::NormFilter {
+            tenant,
+            ..db::NormFilter::default()
+        },
+        NormGroup::Language(lang) if !Locale::ALL.iter().any(|l| l.code() == lang) => {
+            return Err(ServerFnError::new(format!("Unsupported language: {lang}")));
+        }
+        NormGroup::Country(country) if !is_known_country(&country) => {
+            return Err(ServerFnError::new(format!(
+                "Unknown country code: {country}"
+            )));
+        }
+        NormGroup::Language(lang) => db::NormFilter {
+            tenant,
+            lang: Some(lang),
+            country: None,
+        },
+        NormGroup::Country(country) => db::NormFilter {
+            tenant,
+            lang: None,
+            country: Some(country),
+        },
+    };
+    let distribution = db::score_distribution(&filter)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    Ok(distribution.filter(|d| d.sample_size >= MIN_NORM_SAMPLE))
+}
+
+/// Selector for the norm group, with a note when the group is too small.
+///
+/// Offers the language group for `lang` and, if known, the country group for `country`.
+#[component]
+pub fn NormGroupPicker(
+    group: RwSignal<NormGroup>,
+    #[prop(into)] lang: Signal<String>,
+    #[prop(into)] country: Signal<Option<String>>,
+    /// The selected group has enough results for percentiles.
+    #[prop(into)]
+    available: Signal<bool>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+
+    let groups = move || {
+        let mut groups = vec![NormGroup::All, NormGroup::Language(lang.get())];
+        groups.extend(country.get().map(NormGroup::Country));
+        groups
+    };
+
+    let label = move |g: &NormGroup| match g {
+        NormGroup::All => i18n.t("norms_group_all").to_string(),
+        NormGroup::Language(_) => i18n.t("norms_group_language").to_string(),
+        NormGroup::Country(code) => format!(
+            "{} {}",
+            i18n.t("norms_group_country"),
+            country_name(code, i18n.get_locale().code())
+        ),
+    };
+
+    view! {
+        <div class="no-print flex flex-wrap items-center gap-2 mb-4 text-sm text-gray-600 dark:text-gray-300">
+            <label for="norm-group">{move || i18n.t("norms_compare_with")}</label>
+            <select
+                id="norm-group"
+                class="px-3 py-1.5 border border-gray-300 dark:border-gray-600 bg-white dark:bg-gray-700 rounded-lg text-gray-700 dark:text-gray-200"
+                on:change=move |ev| {
+                    let value = event_target_value(&ev);
+                    if let Some(g) = groups().into_iter().find(|g| g.value() == value) {
+                        group.set(g);
+                    }
+                }
+            >
+                {move || {
+                    groups()
+                        .into_iter()
+                        .map(|g| {
+                            let selected = group.get() == g;
+                            view! {
+                                <option value=g.value() selected=selected>
+                                    {label(&g)}
+                                </option>
+                            }
+                        })
+                        .collect_view()
+                }}
+            </select>
+            <Show when=move || !available.get()>
+                <span class="text-gray-500 dark:text-gray-400">{move || i18n.t("norms_not_enough")}</span>
+            </Show>
+        </div>
+    }
+}
@@ -0,0 +1,204 @@
+//! Opt-in timing of individual questions, for improving item wording.
+//!
+//! Only active when `question_analytics` is enabled in the config. While the test runs,
+//! [`QuestionTimer`] notes how long each question stays on screen and whether the
+//! respondent went back to it. Nothing leaves the browser unless the respondent agrees on
+//! the completion screen; the server then only adds the numbers to per-question totals for
+//! the interface language, so no stored row describes a single test.
+
+use std::collections::HashMap;
+
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use serde::{Deserialize, Serialize};
+
+use crate::components::dates::now_ms;
+use crate::i18n::use_i18n;
+
+/// Time spent on one question during one test.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(not(any(target_arch = "wasm32", feature = "ssr")), allow(dead_code))]
+pub struct QuestionTiming {
+    pub question_id: String,
+    /// Milliseconds on screen, over all visits.
+    pub ms: u64,
+    /// Whether the respondent navigated back to the question after moving past it.
+    pub revisited: bool,
+}
+
+/// Timings of a finished test, sent from the completion screen.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(not(any(target_arch = "wasm32", feature = "ssr")), allow(dead_code))]
+pub struct QuestionTimingReport {
+    pub lang: String,
+    pub questions: Vec<QuestionTiming>,
+}
+
+/// Whether this deployment collects question timings.
+#[server]
+pub async fn get_question_analytics_enabled() -> Result<bool, ServerFnError> {
+    use crate::config::get_config;
+
+    let config = get_config().map_err(|e| ServerFnError::new(e.to_string()))?;
+    Ok(config.question_analytics)
+}
+
+/// Add a test's question timings to the per-language totals.
+#[server]
+pub async fn record_question_timings(report: QuestionTimingReport) -> Result<(), ServerFnError> {
+    use std::collections::HashSet;
+
+    use crate::config::get_config;
+    use crate::db;
+    use crate::i18n::Locale;
+
+    /// Longest time counted for one question; anything above is an idle tab, not reading.
+    const MAX_QUESTION_MS: u64 = 120_000;
+
+    let config = get_config().map_err(|e| ServerFnError::new(e.to_string()))?;
+    if !config.question_analytics {
+        return Err(ServerFnError::new("Question analytics are disabled"));
+    }
+    if !Locale::ALL.iter().any(|l| l.code() == report.lang) {
+        return Err(ServerFnError::new("Unsupported language"));
+    }
+
+    // Question ids are the same in every language
+    let inventory = crate::inventory::reference();
+    let known: HashSet<&str> = inventory
+        .questions()
+        .iter()
+        .map(|q| q.id.as_str())
+        .collect();
+    let mut seen = HashSet::new();
+    let mut timings = Vec::with_capacity(report.questions.len());
+    for question in &report.questions {
+        if !known.contains(question.question_id.as_str()) {
+            return Err(ServerFnError::new("Unknown question id"));
+        }
+        if !seen.insert(question.question_id.as_str()) {
+            return Err(ServerFnError::new("Duplicate question id"));
+        }
+        timings.push(db::QuestionTiming {
+            question_id: question.question_id.clone(),
+            ms: question.ms.min(MAX_QUESTION_MS),
+            revisited: question.revisited,
+        });
+    }
+
+    db::record_question_timings(&report.lang, &timings)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+
+    tracing::info!(questions = timings.len(), "Recorded question timings");
+    Ok(())
+}
+
+/// Collects [`QuestionTiming`]s on the test page (the clock only runs in the browser).
+///
+/// The test page also reads response times for the speed checks from it, so it runs even
+/// with analytics disabled; the timings themselves still only leave with consent.
+#[derive(Clone, Debug, Default)]
+pub struct QuestionTimer {
+    /// Milliseconds on screen and whether it was revisited, by question id.
+    timings: HashMap<String, (f64, bool)>,
+    /// Question on screen and when it appeared.
+    shown: Option<(String, f64)>,
+    /// Index of the last question shown, to detect going back.
+    last_index: Option<usize>,
+}
+
+impl QuestionTimer {
+    /// Note which question is now on screen: its id and index, or `None` while no question
+    /// is shown (review screen, demographics step, completion).
+    pub fn show(&mut self, question: Option<(String, usize)>) {
+        let now = now_ms();
+        if let Some((id, since)) = self.shown.take() {
+            self.timings.entry(id).or_default().0 += (now - since).max(0.0);
+        }
+        if let Some((id, index)) = question {
+            if self.last_index.is_some_and(|last| index < last) {
+                self.timings.entry(id.clone()).or_default().1 = true;
+            }
+            self.last_index = Some(index);
+            self.shown = Some((id, now));
+        }
+    }
+
+    /// Milliseconds the question has been on screen in its current visit, if it is shown.
+    pub fn visible_ms(&self, question_id: &str) -> Option<u32> {
+        let (id, since) = self.shown.as_ref()?;
+        (id == question_id).then(|| (now_ms() - since).max(0.0) as u32)
+    }
+
+    /// Timings collected so far.
+    pub fn timings(&self) -> Vec<QuestionTiming> {
+        self.timings
+            .iter()
+            .map(|(id, &(ms, revisited))| QuestionTiming {
+                question_id: id.clone(),
+                ms: ms as u64,
+                revisited,
+            })
+            .collect()
+    }
+}
+
+/// Consent form for sending the finished test's question timings.
+#[component]
+pub fn QuestionTimingConsent(timings: Vec<QuestionTiming>) -> impl IntoView {
+    let i18n = use_i18n();
+    let timings = StoredValue::new(timings);
+
+    let (sending, set_sending) = signal(false);
+    let (sent, set_sent) = signal(false);
+    let (error, set_error) = signal(false);
+
+    let submit = move |_| {
+        let report = QuestionTimingReport {
+            lang: i18n.get_locale().code().to_string(),
+            questions: timings.get_value(),
+        };
+        set_sending.set(true);
+        set_error.set(false);
+        spawn_local(async move {
+            match record_question_timings(report).await {
+                Ok(()) => set_sent.set(true),
+                Err(_) => set_error.set(true),
+            }
+            set_sending.set(false);
+        });
+    };
+
+    view! {
+        <div class="mb-6 text-left border border-gray-200 dark:border-gray-700 rounded-lg p-4">
+            <Show
+                when=move || !sent.get()
+                fallback=move || {
+                    view! {
+                        <p class="text-sm text-green-700 dark:text-green-400">
+                            {move || i18n.t("question_timing_thanks")}
+                        </p>
+                    }
+                }
+            >
+                <h2 class="font-medium text-gray-800 dark:text-gray-100 mb-1">
+                    {move || i18n.t("question_timing_title")}
+                </h2>
+                <p class="text-sm text-gray-600 dark:text-gray-300 mb-3">
+                    {move || i18n.t("question_timing_description")}
+                </p>
+                <Show when=move || error.get()>
+                    <p class="text-sm text-red-600 dark:text-red-400 mb-3">{move || i18n.t("question_timing_error")}</p>
+                </Show>
+                <button
+                    on:click=submit
+                    disabled=move || sending.get()
+                    class="px-4 py-2 text-sm rounded-lg border border-indigo-600 text-indigo-600 dark:border-indigo-400 dark:text-indigo-400 hover:bg-indigo-50 dark:hover:bg-indigo-900/30 transition-colors disabled:opacity-50"
+                >
+                    {move || i18n.t("question_timing_submit")}
+                </button>
+            </Show>
+        </div>
+    }
+}
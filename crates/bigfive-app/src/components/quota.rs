@@ -0,0 +1,70 @@
+//! The client's daily allowance of AI analyses, when the server limits it.
+
+use leptos::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use crate::components::dates::{format_relative, now_ms};
+use crate::i18n::use_i18n;
+
+/// Today's analyses of this client.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AnalysisQuota {
+    pub limit: u32,
+    pub remaining: u32,
+    /// When the allowance is renewed (Unix seconds).
+    pub resets_at: i64,
+}
+
+/// This client's quota for today, or `None` when analyses aren't limited per client.
+#[server]
+pub async fn get_analysis_quota() -> Result<Option<AnalysisQuota>, ServerFnError> {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_err(|e| ServerFnError::new(e.to_string()))?
+        .as_secs() as i64;
+    let client = crate::quota::request_ip().map_err(|e| ServerFnError::new(e.to_string()))?;
+    let allowance = crate::quota::allowance(client, now)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    Ok(allowance.map(|a| AnalysisQuota {
+        limit: a.limit,
+        remaining: a.limit - a.used,
+        resets_at: a.resets_at,
+    }))
+}
+
+/// "AI analyses left today: 3 / 5", or when the next one is available once they're used up.
+#[component]
+pub(super) fn QuotaNotice(#[prop(into)] quota: Signal<Option<AnalysisQuota>>) -> impl IntoView {
+    let i18n = use_i18n();
+
+    move || {
+        quota.get().map(|quota| {
+            let (class, text) = if quota.remaining == 0 {
+                (
+                    "no-print text-sm text-amber-700 dark:text-amber-400 mb-4",
+                    format!(
+                        "{} {}",
+                        i18n.t("quota_exhausted"),
+                        format_relative(
+                            quota.resets_at as f64 * 1000.0,
+                            now_ms(),
+                            i18n.get_locale().code()
+                        )
+                    ),
+                )
+            } else {
+                (
+                    "no-print text-sm text-gray-500 dark:text-gray-400 mb-4",
+                    format!(
+                        "{} {} / {}",
+                        i18n.t("quota_remaining"),
+                        quota.remaining,
+                        quota.limit
+                    ),
+                )
+            };
+            view! { <p class=class>{text}</p> }
+        })
+    }
+}
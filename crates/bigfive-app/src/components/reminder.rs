@@ -0,0 +1,68 @@
+//! Offer to put a retest reminder in the user's calendar.
+
+use leptos::prelude::*;
+
+use crate::i18n::use_i18n;
+
+/// Months until the reminder that users can choose from.
+pub const REMINDER_MONTHS: &[u32] = &[6, 9, 12];
+
+/// Download link for a calendar reminder to retake the test, for the owner of shared
+/// result `id`. The file is generated by the server (see [`crate::calendar`]).
+#[component]
+pub fn RetestReminder(#[prop(into)] id: Signal<String>) -> impl IntoView {
+    let i18n = use_i18n();
+    let months = RwSignal::new(REMINDER_MONTHS[0]);
+
+    let href = move || {
+        format!(
+            "/api/v1/results/{}/reminder.ics?months={}",
+            id.get(),
+            months.get()
+        )
+    };
+
+    view! {
+        <div class="no-print mt-6 border border-gray-200 dark:border-gray-700 rounded-lg p-4">
+            <h2 class="font-medium text-gray-800 dark:text-gray-100 mb-1">
+                {move || i18n.t("reminder_title")}
+            </h2>
+            <p class="text-sm text-gray-600 dark:text-gray-300 mb-3">
+                {move || i18n.t("reminder_description")}
+            </p>
+            <div class="flex flex-wrap items-center gap-3">
+                <label for="reminder-months" class="sr-only">
+                    {move || i18n.t("reminder_months_label")}
+                </label>
+                <select
+                    id="reminder-months"
+                    class="px-3 py-2 border border-gray-300 dark:border-gray-600 bg-white dark:bg-gray-700 rounded-lg focus:ring-2 focus:ring-indigo-500 focus:border-indigo-500 text-gray-700 dark:text-gray-200"
+                    on:change=move |ev| {
+                        if let Ok(value) = event_target_value(&ev).parse::<u32>() {
+                            months.set(value);
+                        }
+                    }
+                >
+                    {REMINDER_MONTHS
+                        .iter()
+                        .map(|&m| {
+                            view! {
+                                <option value=m.to_string() selected=move || months.get() == m>
+                                    {move || format!("{m} {}", i18n.t("reminder_months_unit"))}
+                                </option>
+                            }
+                        })
+                        .collect_view()}
+                </select>
+                <a
+                    href=href
+                    download="bigfive-retest.ics"
+                    rel="external"
+                    class="px-4 py-2 text-sm rounded-lg border border-indigo-600 text-indigo-600 dark:border-indigo-400 dark:text-indigo-400 hover:bg-indigo-50 dark:hover:bg-indigo-900/30 transition-colors"
+                >
+                    {move || i18n.t("reminder_download")}
+                </a>
+            </div>
+        </div>
+    }
+}
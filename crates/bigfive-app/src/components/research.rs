@@ -0,0 +1,190 @@
+//! Opt-in donation of anonymized answers for research.
+//!
+//! Only offered when `research_dataset` is enabled in the config. A donation holds the
+//! raw answers, the interface language and optional self-reported age band and gender;
+//! nothing ties it to a saved result.
+
+use std::collections::HashMap;
+
+use bigfive::{AgeBand, Gender};
+use leptos::prelude::*;
+use leptos::task::spawn_local;
+use serde::{Deserialize, Serialize};
+
+use crate::components::demographics::{gender_key, load_demographics};
+use crate::i18n::use_i18n;
+
+/// Answers and demographics donated from the completion screen.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(not(any(target_arch = "wasm32", feature = "ssr")), allow(dead_code))]
+pub struct ResearchContribution {
+    /// Answers keyed by question id.
+    pub answers: HashMap<String, u8>,
+    pub lang: String,
+    pub age_band: Option<AgeBand>,
+    pub gender: Option<Gender>,
+}
+
+/// Whether this deployment collects research donations.
+#[server]
+pub async fn get_research_enabled() -> Result<bool, ServerFnError> {
+    use crate::config::get_config;
+
+    let config = get_config().map_err(|e| ServerFnError::new(e.to_string()))?;
+    Ok(config.research_dataset)
+}
+
+/// Store a research donation. Requires a complete, valid set of answers.
+#[server]
+pub async fn contribute_research(contribution: ResearchContribution) -> Result<(), ServerFnError> {
+    use bigfive::Answer;
+
+    use crate::config::get_config;
+    use crate::db;
+
+    let config = get_config().map_err(|e| ServerFnError::new(e.to_string()))?;
+    if !config.research_dataset {
+        return Err(ServerFnError::new("Research donations are disabled"));
+    }
+
+    // Question ids are the same in every language
+    let inventory = crate::inventory::reference();
+    let answers: Vec<Answer> = contribution
+        .answers
+        .iter()
+        .map(|(id, &value)| Answer {
+            question_id: id.clone(),
+            value,
+        })
+        .collect();
+    if !bigfive::validate_answers(&inventory, &answers).is_empty() {
+        return Err(ServerFnError::new("Answers are incomplete or invalid"));
+    }
+
+    db::save_research_response(&db::ResearchResponse {
+        answers: inventory
+            .questions()
+            .iter()
+            .map(|q| contribution.answers[&q.id])
+            .collect(),
+        lang: contribution.lang,
+        age_band: contribution.age_band.map(|a| a.code().to_string()),
+        gender: contribution.gender.map(|g| g.code().to_string()),
+    })
+    .await
+    .map_err(|e| ServerFnError::new(e.to_string()))?;
+
+    tracing::info!("Stored research donation");
+    Ok(())
+}
+
+const SELECT_CLASS: &str = "w-full px-3 py-2 border border-gray-300 dark:border-gray-600 bg-white dark:bg-gray-700 rounded-lg text-gray-700 dark:text-gray-200";
+
+/// Consent form for donating the just-finished answers.
+#[component]
+pub fn ResearchConsent(answers: HashMap<String, u8>) -> impl IntoView {
+    let i18n = use_i18n();
+    let answers = StoredValue::new(answers);
+
+    let (consent, set_consent) = signal(false);
+    // Prefilled from the pre-test step, if it was answered
+    let known = load_demographics().unwrap_or_default();
+    let (age_band, set_age_band) = signal(known.age_band);
+    let (gender, set_gender) = signal(known.gender);
+    let (sending, set_sending) = signal(false);
+    let (sent, set_sent) = signal(false);
+    let (error, set_error) = signal(false);
+
+    let submit = move |_| {
+        let contribution = ResearchContribution {
+            answers: answers.get_value(),
+            lang: i18n.get_locale().code().to_string(),
+            age_band: age_band.get_untracked(),
+            gender: gender.get_untracked(),
+        };
+        set_sending.set(true);
+        set_error.set(false);
+        spawn_local(async move {
+            match contribute_research(contribution).await {
+                Ok(()) => set_sent.set(true),
+                Err(_) => set_error.set(true),
+            }
+            set_sending.set(false);
+        });
+    };
+
+    view! {
+        <div class="mb-6 text-left border border-gray-200 dark:border-gray-700 rounded-lg p-4">
+            <Show
+                when=move || !sent.get()
+                fallback=move || {
+                    view! {
+                        <p class="text-sm text-green-700 dark:text-green-400">{move || i18n.t("research_thanks")}</p>
+                    }
+                }
+            >
+                <h2 class="font-medium text-gray-800 dark:text-gray-100 mb-1">{move || i18n.t("research_title")}</h2>
+                <p class="text-sm text-gray-600 dark:text-gray-300 mb-3">{move || i18n.t("research_description")}</p>
+                <div class="grid grid-cols-1 sm:grid-cols-2 gap-3 mb-3">
+                    <label class="text-sm text-gray-600 dark:text-gray-300">
+                        {move || i18n.t("demographics_age")}
+                        <select
+                            class=SELECT_CLASS
+                            on:change=move |ev| set_age_band.set(AgeBand::from_code(&event_target_value(&ev)))
+                        >
+                            <option value="">{move || i18n.t("demographics_prefer_not")}</option>
+                            {AgeBand::ALL
+                                .into_iter()
+                                .map(|band| {
+                                    view! {
+                                        <option value=band.code() selected=move || age_band.get() == Some(band)>
+                                            {band.code()}
+                                        </option>
+                                    }
+                                })
+                                .collect_view()}
+                        </select>
+                    </label>
+                    <label class="text-sm text-gray-600 dark:text-gray-300">
+                        {move || i18n.t("demographics_gender")}
+                        <select
+                            class=SELECT_CLASS
+                            on:change=move |ev| set_gender.set(Gender::from_code(&event_target_value(&ev)))
+                        >
+                            <option value="">{move || i18n.t("demographics_prefer_not")}</option>
+                            {Gender::ALL
+                                .into_iter()
+                                .map(|g| {
+                                    view! {
+                                        <option value=g.code() selected=move || gender.get() == Some(g)>
+                                            {move || i18n.t(gender_key(g))}
+                                        </option>
+                                    }
+                                })
+                                .collect_view()}
+                        </select>
+                    </label>
+                </div>
+                <label class="flex items-start gap-2 text-sm text-gray-700 dark:text-gray-200 mb-3">
+                    <input
+                        type="checkbox"
+                        class="mt-0.5"
+                        prop:checked=move || consent.get()
+                        on:change=move |ev| set_consent.set(event_target_checked(&ev))
+                    />
+                    {move || i18n.t("research_consent")}
+                </label>
+                <Show when=move || error.get()>
+                    <p class="text-sm text-red-600 dark:text-red-400 mb-3">{move || i18n.t("research_error")}</p>
+                </Show>
+                <button
+                    on:click=submit
+                    disabled=move || !consent.get() || sending.get()
+                    class="px-4 py-2 text-sm rounded-lg border border-indigo-600 text-indigo-600 dark:border-indigo-400 dark:text-indigo-400 hover:bg-indigo-50 dark:hover:bg-indigo-900/30 transition-colors disabled:opacity-50"
+                >
+                    {move || i18n.t("research_submit")}
+                </button>
+            </Show>
+        </div>
+    }
+}
@@ -1,36 +1,95 @@
 //! Results page component with visualization and AI analysis.
 
-use bigfive::{Domain, Facet, PersonalityProfile, ScoreLevel};
+use bigfive::{
+    ArchetypeLabels, Demographics, Domain, DomainScore, Facet, PersonalityProfile, ProfileMeans,
+    ScoreLevel,
+};
 use leptos::prelude::*;
 use leptos::task::spawn_local;
+use leptos_meta::{Meta, Title};
 use leptos_router::components::A;
 use leptos_router::hooks::{use_navigate, use_params_map};
-use pulldown_cmark::{Options, Parser, html};
 use serde::{Deserialize, Serialize};
 
-use crate::components::{LangToggle, ThemeToggle};
+use crate::components::ai_consent::{AiConsentDialog, has_ai_consent};
+use crate::components::bookmarks::ReflectionItems;
+use crate::components::changes::ProfileChanges;
+use crate::components::dates::format_date;
+use crate::components::demographics::load_demographics;
+use crate::components::explain::{FacetExplanation, get_facet_explanations_enabled};
+use crate::components::history::{domain_key, remove_history, restore_history};
+use crate::components::norms::{NormGroup, NormGroupPicker, get_norms};
+use crate::components::quota::{AnalysisQuota, QuotaNotice, get_analysis_quota};
+use crate::components::reminder::RetestReminder;
+use crate::components::share_dialog::ShareDialog;
+use crate::components::traits::domain_slug;
+use crate::components::{CountUp, ScoreBar, domain_color};
 use crate::i18n::use_i18n;
+use crate::pow::{SaveChallenge, SaveProof};
 
 #[cfg(target_arch = "wasm32")]
-const STORAGE_KEY_PROFILE: &str = "bigfive_profile";
+const STORAGE_KEY_PROFILE: &str = crate::storage::storage_key!("profile");
 #[cfg(target_arch = "wasm32")]
-const STORAGE_KEY_CONTEXT: &str = "bigfive_user_context";
+const STORAGE_KEY_CONTEXT: &str = crate::storage::storage_key!("user_context");
+#[cfg(target_arch = "wasm32")]
+const STORAGE_KEY_OWNER_TOKENS: &str = crate::storage::storage_key!("owner_tokens");
+#[cfg(target_arch = "wasm32")]
+const STORAGE_KEY_NICKNAME: &str = crate::storage::storage_key!("nickname");
+
+/// Maximum length (in characters) of the display name attached to a shared result.
+const MAX_NICKNAME_CHARS: usize = 40;
+
+/// Maximum length (in characters) of the notes stored with a shared result, whatever the
+/// AI `max_context_chars` limit is.
+#[cfg(feature = "ssr")]
+const MAX_USER_CONTEXT_CHARS: usize = 10_000;
+
+/// Length range of the client-chosen key that makes repeated saves return one snapshot.
+#[cfg(feature = "ssr")]
+const MIN_IDEMPOTENCY_KEY_LEN: usize = 16;
+#[cfg(feature = "ssr")]
+const MAX_IDEMPOTENCY_KEY_LEN: usize = 64;
+
+/// Maximum length (in characters) of the optional comment left with analysis feedback.
+const MAX_FEEDBACK_COMMENT_CHARS: usize = 500;
+
+/// Prefix of heading and footnote ids in the rendered analysis, e.g. `#analysis-strengths`.
+const ANALYSIS_ID_PREFIX: &str = "analysis-";
+
+/// First polling interval in milliseconds; doubled after every poll up to [`POLL_MAX_MS`]
+#[cfg(target_arch = "wasm32")]
+const POLL_INITIAL_MS: u32 = 1000;
 
-/// Polling interval in milliseconds
+/// Longest polling interval in milliseconds
 #[cfg(target_arch = "wasm32")]
-const POLL_INTERVAL_MS: u32 = 3000;
+const POLL_MAX_MS: u32 = 15_000;
 
-/// Maximum number of poll attempts (3 minutes total with 3s interval)
+/// Give up after this much waiting with the tab visible (3 minutes)
 #[cfg(target_arch = "wasm32")]
-const MAX_POLL_ATTEMPTS: u32 = 60;
+const POLL_TIMEOUT_MS: u32 = 180_000;
+
+/// How often a hidden tab checks whether it is shown again, in milliseconds
+#[cfg(target_arch = "wasm32")]
+const HIDDEN_CHECK_MS: u32 = 1000;
 
 /// Status of a background analysis job (shared between server and client)
 #[derive(Clone, Debug, Serialize, Deserialize, PartialEq)]
 pub enum AnalysisStatus {
     /// Job is queued or processing
-    Pending,
+    Pending {
+        /// Seconds since processing started (0 while queued)
+        elapsed_secs: u64,
+        /// Rough seconds left, if the model has recent runs (0 once overdue)
+        eta_secs: Option<u64>,
+    },
     /// Job completed successfully with result
-    Complete(String),
+    Complete {
+        analysis: String,
+        /// Prompt template version the analysis was generated with
+        prompt_version: String,
+        /// When the user agreed to send their data to the provider (Unix seconds)
+        consented_at: i64,
+    },
     /// Job failed with error message
     Error(String),
 }
@@ -41,6 +100,15 @@ pub struct ClientModelInfo {
     pub id: String,
     pub display_name: String,
     pub default: bool,
+    pub description: Option<String>,
+    /// Typical speed code ("fast", "medium", "slow")
+    pub speed: Option<String>,
+    /// Whether extended thinking is enabled
+    pub thinking: bool,
+    /// Language the model writes in; other interface languages get a translation step
+    pub source_lang: String,
+    /// Whether a translation step is configured
+    pub translation: bool,
 }
 
 /// Saved result data (shared between server and client).
@@ -50,78 +118,457 @@ pub struct SavedResultData {
     pub profile: PersonalityProfile,
     pub user_context: Option<String>,
     pub ai_analysis: Option<String>,
+    /// Prompt template version the analysis was generated with, if known.
+    pub prompt_version: Option<String>,
     pub lang: String,
+    pub nickname: Option<String>,
+    /// Details given before the test, for choosing a norm group.
+    pub demographics: Option<Demographics>,
+    /// The result is protected and was opened without its key, so notes,
+    /// analysis, display name and demographics are withheld.
+    pub locked: bool,
+}
+
+/// AI analysis included in a shared snapshot.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(not(any(target_arch = "wasm32", feature = "ssr")), allow(dead_code))]
+pub struct SharedAnalysis {
+    pub text: String,
+    /// Prompt template version the analysis was generated with, if known.
+    pub prompt_version: Option<String>,
+    /// When the user agreed to send their data to the model provider (Unix seconds).
+    pub consented_at: Option<i64>,
+}
+
+/// How a snapshot is shared.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[cfg_attr(not(any(target_arch = "wasm32", feature = "ssr")), allow(dead_code))]
+pub struct ShareOptions {
+    /// Display name shown on the shared page.
+    pub nickname: Option<String>,
+    /// Days until the link stops working; one of `SHARE_EXPIRY_DAYS`, or never.
+    pub expires_in_days: Option<u32>,
+    /// Require the key from the link fragment to see notes and analysis.
+    pub protected: bool,
 }
 
-/// Get available model presets for the client.
+/// Duplicate and bot protection sent along with a save.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[cfg_attr(not(any(target_arch = "wasm32", feature = "ssr")), allow(dead_code))]
+pub struct SaveAttempt {
+    /// Random key chosen by the client, the same for every retry of one save.
+    pub idempotency_key: Option<String>,
+    /// Solved challenge from [`get_save_challenge`], if the deployment requires one.
+    pub proof: Option<SaveProof>,
+}
+
+/// Handle returned after saving a snapshot.
+///
+/// The owner token is kept in this browser's localStorage and lets it delete the result later.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(not(any(target_arch = "wasm32", feature = "ssr")), allow(dead_code))]
+pub struct SavedResultHandle {
+    pub id: String,
+    pub owner_token: String,
+    /// Key to put in the link fragment when the result was saved as protected.
+    pub access_token: Option<String>,
+}
+
+/// Country code of the current request, from the configured proxy header.
+#[cfg(feature = "ssr")]
+fn request_country(config: &crate::config::AiConfig) -> Option<String> {
+    let header = config.country_header.as_deref()?;
+    use_context::<axum::http::request::Parts>()?
+        .headers
+        .get(header)?
+        .to_str()
+        .ok()
+        .map(str::to_string)
+}
+
+/// Get the model presets offered for an interface language.
+///
+/// The `default` flag reflects the configured default model rules for this request.
 #[server]
-pub async fn get_available_models() -> Result<Vec<ClientModelInfo>, ServerFnError> {
+pub async fn get_available_models(lang: String) -> Result<Vec<ClientModelInfo>, ServerFnError> {
     use crate::config::get_config;
 
     let config = get_config().map_err(|e| ServerFnError::new(e.to_string()))?;
+    if !config.ai_enabled {
+        return Ok(Vec::new());
+    }
+    let tenant = crate::tenancy::current_tenant();
+    let default_id = &config
+        .default_model_for(tenant, &lang, request_country(config).as_deref())
+        .id;
     Ok(config
         .models
         .iter()
+        .filter(|m| m.supports_locale(&lang) && tenant.is_none_or(|t| t.offers_model(&m.id)))
         .map(|m| ClientModelInfo {
             id: m.id.clone(),
             display_name: m.display_name.clone(),
-            default: m.default,
+            default: &m.id == default_id,
+            description: m.description.clone(),
+            speed: m.speed.map(|s| s.code().to_string()),
+            thinking: m.thinking.is_some(),
+            source_lang: m.source_lang.code().to_string(),
+            translation: m.translation.is_some(),
         })
         .collect())
 }
 
-/// Convert markdown text to HTML
-fn markdown_to_html(markdown: &str) -> String {
-    let mut options = Options::empty();
-    options.insert(Options::ENABLE_STRIKETHROUGH);
-    options.insert(Options::ENABLE_TABLES);
+/// Whether AI analyses are offered; `false` turns the results page into a pure score report.
+#[server]
+pub async fn get_ai_enabled() -> Result<bool, ServerFnError> {
+    use crate::config::get_config;
+
+    let config = get_config().map_err(|e| ServerFnError::new(e.to_string()))?;
+    Ok(config.ai_enabled)
+}
+
+/// Average scores of everyone who saved results here, as a "compared to others" baseline.
+///
+/// Returns `None` until enough results exist for the average to mean something.
+#[server]
+pub async fn get_baseline() -> Result<Option<ProfileMeans>, ServerFnError> {
+    use crate::db;
+
+    /// Minimum number of saved results before a baseline is shown.
+    const MIN_BASELINE_SAMPLE: usize = 30;
+
+    let means = db::profile_means(crate::tenancy::current_namespace())
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    Ok(means.filter(|m| m.sample_size >= MIN_BASELINE_SAMPLE))
+}
+
+/// Get the maximum allowed length (in characters) of the user context.
+#[server]
+pub async fn get_context_limit() -> Result<usize, ServerFnError> {
+    use crate::config::get_config;
 
-    let parser = Parser::new_ext(markdown, options);
-    let mut html_output = String::new();
-    html::push_html(&mut html_output, parser);
-    html_output
+    let config = get_config().map_err(|e| ServerFnError::new(e.to_string()))?;
+    Ok(config.max_context_chars)
 }
 
-/// Save a results snapshot to database, returns UUID.
+/// Proof-of-work challenge to solve before saving, if this deployment requires one.
+#[server]
+pub async fn get_save_challenge() -> Result<Option<SaveChallenge>, ServerFnError> {
+    use crate::config::get_config;
+
+    let config = get_config().map_err(|e| ServerFnError::new(e.to_string()))?;
+    Ok((config.save_pow_difficulty > 0).then(|| crate::pow::issue(config.save_pow_difficulty)))
+}
+
+/// Save a results snapshot to database, returns its UUID and owner token.
+///
+/// A protected snapshot also gets an access token; without it (or the owner token)
+/// only the scores can be read. Repeating a save with the same idempotency key returns
+/// the first snapshot instead of storing another one.
 #[server]
 pub async fn save_results(
     profile: PersonalityProfile,
+    demographics: Option<Demographics>,
     user_context: Option<String>,
-    ai_analysis: Option<String>,
+    ai_analysis: Option<SharedAnalysis>,
     lang: String,
-) -> Result<String, ServerFnError> {
+    options: ShareOptions,
+    attempt: SaveAttempt,
+) -> Result<SavedResultHandle, ServerFnError> {
+    use crate::audit::{self, AuditAction};
+    use crate::components::share_dialog::SHARE_EXPIRY_DAYS;
     use crate::db;
 
+    let SaveAttempt {
+        idempotency_key,
+        proof,
+    } = attempt;
+
+    // Scores come from the browser, so they have to look like something `calculate` made
+    if let Err(e) = profile.validate() {
+        tracing::warn!("Rejected result save: {e}");
+        return Err(ServerFnError::new("Invalid profile"));
+    }
+    if let Some(key) = &idempotency_key
+        && !(MIN_IDEMPOTENCY_KEY_LEN..=MAX_IDEMPOTENCY_KEY_LEN).contains(&key.len())
+    {
+        return Err(ServerFnError::new("Invalid idempotency key"));
+    }
+
+    let ShareOptions {
+        nickname,
+        expires_in_days,
+        protected,
+    } = options;
+    let nickname = nickname
+        .map(|n| n.trim().to_string())
+        .filter(|n| !n.is_empty());
+    if nickname
+        .as_ref()
+        .is_some_and(|n| n.chars().count() > MAX_NICKNAME_CHARS)
+    {
+        return Err(ServerFnError::new(format!(
+            "Display name is too long (max {MAX_NICKNAME_CHARS} characters)"
+        )));
+    }
+    if user_context
+        .as_ref()
+        .is_some_and(|c| c.chars().count() > MAX_USER_CONTEXT_CHARS)
+    {
+        return Err(ServerFnError::new(format!(
+            "Notes are too long (max {MAX_USER_CONTEXT_CHARS} characters)"
+        )));
+    }
+
+    let expires_at = match expires_in_days {
+        Some(days) if SHARE_EXPIRY_DAYS.contains(&days) => {
+            let now = std::time::SystemTime::now()
+                .duration_since(std::time::UNIX_EPOCH)
+                .map_err(|e| ServerFnError::new(e.to_string()))?
+                .as_secs() as i64;
+            Some(now + i64::from(days) * 24 * 60 * 60)
+        }
+        Some(days) => {
+            return Err(ServerFnError::new(format!(
+                "Unsupported link expiry: {days} days"
+            )));
+        }
+        None => None,
+    };
+
+    let demographics = demographics.filter(|d| !d.is_empty());
+    if let Some(country) = demographics.as_ref().and_then(|d| d.country.as_deref())
+        && !Demographics::is_country_code(country)
+    {
+        return Err(ServerFnError::new(format!(
+            "Invalid country code: {country}"
+        )));
+    }
+
+    // Checked before the idempotency lookup, so guessing keys costs a proof per attempt
+    if let Ok(config) = crate::config::get_config()
+        && config.save_pow_difficulty > 0
+        && !proof
+            .as_ref()
+            .is_some_and(|p| crate::pow::redeem(p, config.save_pow_difficulty))
+    {
+        return Err(ServerFnError::new("Missing or invalid proof of work"));
+    }
+
+    // A retry of a save whose response got lost: hand out the same snapshot again
+    let tenant = crate::tenancy::current_namespace();
+    if let Some(key) = &idempotency_key
+        && let Some(handle) = db::find_result_by_idempotency_key(key, tenant)
+            .await
+            .map_err(|e| ServerFnError::new(e.to_string()))?
+    {
+        return Ok(SavedResultHandle {
+            id: handle.id,
+            owner_token: handle.owner_token,
+            access_token: handle.access_token,
+        });
+    }
+
+    // Mask personal data before it is persisted, if configured, and cap the analysis length
+    let (user_context, ai_analysis) = match crate::config::get_config() {
+        Ok(config) => (
+            user_context.map(|c| crate::pii::scrub(&config.pii_scrubbing, &c)),
+            ai_analysis.map(|a| SharedAnalysis {
+                text: crate::ai::limit_analysis_length(&a.text, config.max_analysis_chars, &lang),
+                ..a
+            }),
+        ),
+        Err(_) => (user_context, ai_analysis),
+    };
+
     let id = uuid::Uuid::new_v4().to_string();
-    db::save_result(
-        &id,
-        &profile,
-        user_context.as_deref(),
-        ai_analysis.as_deref(),
-        &lang,
-    )
+    let owner_token = uuid::Uuid::new_v4().to_string();
+    let access_token = protected.then(|| uuid::Uuid::new_v4().simple().to_string());
+    db::save_result(&db::NewResult {
+        id: &id,
+        profile: &profile,
+        user_context: user_context.as_deref(),
+        ai_analysis: ai_analysis.as_ref().map(|a| a.text.as_str()),
+        prompt_version: ai_analysis
+            .as_ref()
+            .and_then(|a| a.prompt_version.as_deref()),
+        analysis_consented_at: ai_analysis.as_ref().and_then(|a| a.consented_at),
+        lang: &lang,
+        owner_token: &owner_token,
+        nickname: nickname.as_deref(),
+        expires_at,
+        access_token: access_token.as_deref(),
+        demographics: demographics.as_ref(),
+        tenant,
+        idempotency_key: idempotency_key.as_deref(),
+    })
     .await
     .map_err(|e| ServerFnError::new(e.to_string()))?;
 
-    tracing::info!(result_id = %id, "Saved results snapshot to database");
-    Ok(id)
+    tracing::info!(result_id = %id, protected, "Saved results snapshot to database");
+    audit::record(AuditAction::Save, &id).await;
+    if ai_analysis.is_some() {
+        audit::record(AuditAction::AnalysisAdded, &id).await;
+    }
+    Ok(SavedResultHandle {
+        id,
+        owner_token,
+        access_token,
+    })
 }
 
-/// Get saved results from database.
+/// Record thumbs up/down feedback (with an optional comment) on an AI analysis.
 #[server]
-pub async fn get_saved_results(id: String) -> Result<Option<SavedResultData>, ServerFnError> {
+pub async fn submit_feedback(
+    result_id: Option<String>,
+    model_id: Option<String>,
+    prompt_version: Option<String>,
+    helpful: bool,
+    comment: Option<String>,
+    lang: String,
+) -> Result<(), ServerFnError> {
     use crate::db;
 
-    let result = db::get_result(&id)
+    let comment = comment
+        .map(|c| c.trim().to_string())
+        .filter(|c| !c.is_empty());
+    if comment
+        .as_ref()
+        .is_some_and(|c| c.chars().count() > MAX_FEEDBACK_COMMENT_CHARS)
+    {
+        return Err(ServerFnError::new(format!(
+            "Comment is too long (max {MAX_FEEDBACK_COMMENT_CHARS} characters)"
+        )));
+    }
+
+    db::save_feedback(&db::AnalysisFeedback {
+        result_id: result_id.as_deref(),
+        model_id: model_id.as_deref(),
+        prompt_version: prompt_version.as_deref(),
+        helpful,
+        comment: comment.as_deref(),
+        lang: &lang,
+    })
+    .await
+    .map_err(|e| ServerFnError::new(e.to_string()))?;
+
+    tracing::info!(
+        ?result_id,
+        ?model_id,
+        ?prompt_version,
+        helpful,
+        "Recorded analysis feedback"
+    );
+    Ok(())
+}
+
+/// Delete a saved result. Only succeeds with the owner token issued on save.
+///
+/// Returns until when (Unix seconds) the deletion can be undone with [`restore_results`],
+/// or `None` if the result wasn't deleted.
+#[server]
+pub async fn delete_results(id: String, owner_token: String) -> Result<Option<i64>, ServerFnError> {
+    use crate::audit::{self, AuditAction};
+    use crate::config::get_config;
+    use crate::db;
+
+    let config = get_config().map_err(|e| ServerFnError::new(e.to_string()))?;
+    let deleted = db::delete_result(&id, &owner_token)
         .await
         .map_err(|e| ServerFnError::new(e.to_string()))?;
 
-    Ok(result.map(|r| SavedResultData {
-        id: r.id,
-        profile: r.profile,
-        user_context: r.user_context,
-        ai_analysis: r.ai_analysis,
-        lang: r.lang,
+    if !deleted {
+        tracing::warn!(result_id = %id, "Refused to delete results snapshot: unknown id or wrong owner token");
+        return Ok(None);
+    }
+    tracing::info!(result_id = %id, "Deleted results snapshot");
+    audit::record(AuditAction::Delete, &id).await;
+
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_err(|e| ServerFnError::new(e.to_string()))?
+        .as_secs() as i64;
+    Ok(Some(
+        now + i64::from(config.deleted_result_grace_days) * 86_400,
+    ))
+}
+
+/// Undo a deletion within the grace period. Only succeeds with the owner token.
+#[server]
+pub async fn restore_results(id: String, owner_token: String) -> Result<bool, ServerFnError> {
+    use crate::audit::{self, AuditAction};
+    use crate::config::get_config;
+    use crate::db;
+
+    let config = get_config().map_err(|e| ServerFnError::new(e.to_string()))?;
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_err(|e| ServerFnError::new(e.to_string()))?
+        .as_secs() as i64;
+    let deleted_since = now - i64::from(config.deleted_result_grace_days) * 86_400;
+
+    let restored = db::restore_result(&id, &owner_token, deleted_since)
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    if restored {
+        tracing::info!(result_id = %id, "Restored deleted results snapshot");
+        audit::record(AuditAction::Restore, &id).await;
+    }
+    Ok(restored)
+}
+
+/// Get saved results from database.
+///
+/// `key` is the access token from the link fragment or this browser's owner token.
+/// Protected results opened without a matching key only include the scores.
+#[server]
+pub async fn get_saved_results(
+    id: String,
+    key: Option<String>,
+) -> Result<Option<SavedResultData>, ServerFnError> {
+    use crate::audit::{self, AuditAction};
+    use crate::db;
+
+    let result = db::get_result(&id, crate::tenancy::current_namespace())
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    if result.is_some() {
+        audit::record(AuditAction::View, &id).await;
+    }
+    // Results saved before the length cap may still hold longer analyses
+    let max_analysis_chars = crate::config::get_config()
+        .map_err(|e| ServerFnError::new(e.to_string()))?
+        .max_analysis_chars;
+
+    Ok(result.map(|r| {
+        if r.is_unlocked_by(key.as_deref()) {
+            SavedResultData {
+                id: r.id,
+                profile: r.profile,
+                user_context: r.user_context,
+                ai_analysis: r
+                    .ai_analysis
+                    .map(|a| crate::ai::limit_analysis_length(&a, max_analysis_chars, &r.lang)),
+                prompt_version: r.prompt_version,
+                lang: r.lang,
+                nickname: r.nickname,
+                demographics: r.demographics,
+                locked: false,
+            }
+        } else {
+            SavedResultData {
+                id: r.id,
+                profile: r.profile,
+                user_context: None,
+                ai_analysis: None,
+                prompt_version: None,
+                lang: r.lang,
+                nickname: None,
+                demographics: None,
+                locked: true,
+            }
+        }
     }))
 }
 
@@ -133,15 +580,83 @@ pub async fn start_analysis(
     lang: String,
     user_context: Option<String>,
     model_id: String,
+    consented: bool,
 ) -> Result<String, ServerFnError> {
     use crate::jobs::{self, JobStatus};
 
     // Load .env file for local development
     dotenvy::dotenv().ok();
 
+    let config = crate::config::get_config().map_err(|e| ServerFnError::new(e.to_string()))?;
+    if !config.ai_enabled {
+        return Err(ServerFnError::new(
+            crate::ai::AnalysisError::AiDisabled.to_string(),
+        ));
+    }
+    // The profile and notes leave this server only with the user's go-ahead
+    if !consented {
+        return Err(ServerFnError::new(
+            "Consent to send data to the model provider is required",
+        ));
+    }
+    let consented_at = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_err(|e| ServerFnError::new(e.to_string()))?
+        .as_secs() as i64;
+    let tenant = crate::tenancy::current_tenant();
+
+    // No explicit choice: use the default for this request's tenant, locale and region
+    let model_id = if model_id.is_empty() {
+        config
+            .default_model_for(tenant, &lang, request_country(config).as_deref())
+            .id
+            .clone()
+    } else {
+        model_id
+    };
+    if tenant.is_some_and(|t| !t.offers_model(&model_id)) {
+        return Err(ServerFnError::new(format!(
+            "Model {model_id} is not available on this site"
+        )));
+    }
+
+    // Enforce the size limits up front so the client gets an immediate error
+    if let Some(ref context) = user_context {
+        crate::ai::check_context_length(config, context)
+            .map_err(|e| ServerFnError::new(e.to_string()))?;
+    }
+    if let Some(preset) = config.get_model(&model_id) {
+        if !preset.supports_locale(&lang) {
+            return Err(ServerFnError::new(format!(
+                "Model {model_id} is not available for language {lang}"
+            )));
+        }
+        crate::ai::check_prompt_length(config, preset, &profile, user_context.as_deref())
+            .map_err(|e| ServerFnError::new(e.to_string()))?;
+    }
+
+    // Counted last, so requests rejected above don't use up the quota
+    let reservation = match crate::quota::daily_limit() {
+        Some(limit) => Some(
+            crate::quota::reserve(
+                crate::quota::request_ip().map_err(|e| ServerFnError::new(e.to_string()))?,
+                limit,
+                consented_at,
+            )
+            .await
+            .map_err(|e| ServerFnError::new(e.to_string()))?
+            .ok_or_else(|| {
+                ServerFnError::new(format!(
+                    "This network address has used its {limit} AI analyses for today"
+                ))
+            })?,
+        ),
+        None => None,
+    };
+
     // Generate job ID and create job entry
     let job_id = jobs::generate_job_id();
-    jobs::create_job(&job_id);
+    jobs::create_job(&job_id, &model_id);
 
     tracing::info!(
         job_id = %job_id,
@@ -159,9 +674,18 @@ pub async fn start_analysis(
         use crate::ai;
 
         let start = std::time::Instant::now();
-        jobs::update_job_status(&job_id_clone, JobStatus::Processing);
+        jobs::start_job(&job_id_clone);
+
+        let (result, provider_calls) = ai::debug_log::capture(
+            config.debug_provider_logs,
+            ai::generate_analysis(&model_id, &profile, user_context.as_deref(), &lang),
+        )
+        .await;
+        if let Err(e) = crate::db::record_analysis(&model_id, &lang, result.is_ok()).await {
+            tracing::warn!(job_id = %job_id_clone, error = %e, "Failed to record analysis");
+        }
 
-        match ai::generate_analysis(&model_id, &profile, user_context.as_deref(), &lang).await {
+        match result {
             Ok(description) => {
                 tracing::info!(
                     job_id = %job_id_clone,
@@ -170,7 +694,14 @@ pub async fn start_analysis(
                     "Background analysis completed"
                 );
 
-                jobs::update_job_status(&job_id_clone, JobStatus::Complete(description));
+                jobs::update_job_status(
+                    &job_id_clone,
+                    JobStatus::Complete {
+                        analysis: description,
+                        prompt_version: ai::prompt_version().to_string(),
+                        consented_at,
+                    },
+                );
             }
             Err(e) => {
                 tracing::error!(
@@ -179,6 +710,21 @@ pub async fn start_analysis(
                     elapsed_ms = start.elapsed().as_millis(),
                     "Background analysis failed"
                 );
+                if config.debug_provider_logs
+                    && let Err(e) = crate::db::save_provider_log(
+                        &job_id_clone,
+                        &model_id,
+                        &e.to_string(),
+                        &provider_calls,
+                    )
+                    .await
+                {
+                    tracing::warn!(job_id = %job_id_clone, error = %e, "Failed to save provider log");
+                }
+                // A failed analysis doesn't count against the quota
+                if let Some(reservation) = reservation {
+                    reservation.release().await;
+                }
                 jobs::update_job_status(&job_id_clone, JobStatus::Error(e.to_string()));
             }
         }
@@ -193,11 +739,29 @@ pub async fn get_analysis_status(job_id: String) -> Result<AnalysisStatus, Serve
     use crate::jobs::{self, JobStatus};
 
     match jobs::get_job_status(&job_id) {
-        Some(JobStatus::Pending) | Some(JobStatus::Processing) => Ok(AnalysisStatus::Pending),
-        Some(JobStatus::Complete(result)) => {
+        Some(JobStatus::Pending) => Ok(AnalysisStatus::Pending {
+            elapsed_secs: 0,
+            eta_secs: None,
+        }),
+        Some(JobStatus::Processing {
+            elapsed_secs,
+            eta_secs,
+        }) => Ok(AnalysisStatus::Pending {
+            elapsed_secs,
+            eta_secs,
+        }),
+        Some(JobStatus::Complete {
+            analysis,
+            prompt_version,
+            consented_at,
+        }) => {
             // Clean up job after returning result
             jobs::remove_job(&job_id);
-            Ok(AnalysisStatus::Complete(result))
+            Ok(AnalysisStatus::Complete {
+                analysis,
+                prompt_version,
+                consented_at,
+            })
         }
         Some(JobStatus::Error(err)) => {
             // Clean up job after returning error
@@ -208,6 +772,463 @@ pub async fn get_analysis_status(job_id: String) -> Result<AnalysisStatus, Serve
     }
 }
 
+/// Seconds as `m:ss`.
+fn format_duration(secs: u64) -> String {
+    format!("{}:{:02}", secs / 60, secs % 60)
+}
+
+/// Placeholder shown while the profile loads, mirroring the layout of the real page.
+#[component]
+fn ResultsSkeleton() -> impl IntoView {
+    view! {
+        <div class="animate-pulse" aria-busy="true">
+            // Domain cards
+            <div class="space-y-4 mb-8">
+                {(0..5)
+                    .map(|_| {
+                        view! {
+                            <div class="bg-white dark:bg-gray-800 rounded-lg shadow-md p-4 flex items-center transition-colors duration-300">
+                                <div class="flex-1">
+                                    <div class="flex items-center justify-between mb-2">
+                                        <div class="h-5 w-40 bg-gray-200 dark:bg-gray-700 rounded" />
+                                        <div class="h-4 w-20 bg-gray-200 dark:bg-gray-700 rounded" />
+                                    </div>
+                                    <div class="w-full bg-gray-200 dark:bg-gray-700 rounded-full h-3" />
+                                </div>
+                                <div class="w-5 h-5 ml-4 bg-gray-200 dark:bg-gray-700 rounded" />
+                            </div>
+                        }
+                    })
+                    .collect_view()}
+            </div>
+
+            // AI analysis section
+            <div class="bg-white dark:bg-gray-800 rounded-lg shadow-md p-6 mb-8 transition-colors duration-300">
+                <div class="h-6 w-56 bg-gray-200 dark:bg-gray-700 rounded mb-4" />
+                <div class="space-y-3 mb-6">
+                    <div class="h-4 w-full bg-gray-200 dark:bg-gray-700 rounded" />
+                    <div class="h-4 w-11/12 bg-gray-200 dark:bg-gray-700 rounded" />
+                    <div class="h-4 w-4/5 bg-gray-200 dark:bg-gray-700 rounded" />
+                </div>
+                <div class="h-11 w-52 bg-gray-200 dark:bg-gray-700 rounded-lg" />
+            </div>
+
+            // Actions
+            <div class="flex flex-wrap gap-4">
+                <div class="h-10 w-36 bg-gray-200 dark:bg-gray-700 rounded-lg" />
+                <div class="h-10 w-36 bg-gray-200 dark:bg-gray-700 rounded-lg" />
+                <div class="h-10 w-32 bg-gray-200 dark:bg-gray-700 rounded-lg" />
+            </div>
+        </div>
+    }
+}
+
+/// i18n key of a score level.
+pub(crate) fn level_key(level: ScoreLevel) -> &'static str {
+    match level {
+        ScoreLevel::Low => "level_low",
+        ScoreLevel::Neutral => "level_neutral",
+        ScoreLevel::High => "level_high",
+    }
+}
+
+/// Delay between the reveal animations of consecutive domain bars.
+const REVEAL_STAGGER_MS: u32 = 120;
+
+/// Domains ordered by how far they are from the middle of the range, most pronounced first.
+fn most_pronounced(profile: &PersonalityProfile) -> Vec<&DomainScore> {
+    let mut domains: Vec<_> = profile.domains.iter().collect();
+    domains.sort_by(|a, b| {
+        (b.percentage() - 50.0)
+            .abs()
+            .total_cmp(&(a.percentage() - 50.0).abs())
+    });
+    domains
+}
+
+/// Number of domains highlighted in the summary card.
+const SUMMARY_TRAITS: usize = 2;
+
+/// i18n key of the one-sentence interpretation of a domain level.
+fn summary_key(domain: Domain, level: ScoreLevel) -> &'static str {
+    match (domain, level) {
+        (Domain::Neuroticism, ScoreLevel::Low) => "summary_n_low",
+        (Domain::Neuroticism, ScoreLevel::Neutral) => "summary_n_neutral",
+        (Domain::Neuroticism, ScoreLevel::High) => "summary_n_high",
+        (Domain::Extraversion, ScoreLevel::Low) => "summary_e_low",
+        (Domain::Extraversion, ScoreLevel::Neutral) => "summary_e_neutral",
+        (Domain::Extraversion, ScoreLevel::High) => "summary_e_high",
+        (Domain::Openness, ScoreLevel::Low) => "summary_o_low",
+        (Domain::Openness, ScoreLevel::Neutral) => "summary_o_neutral",
+        (Domain::Openness, ScoreLevel::High) => "summary_o_high",
+        (Domain::Agreeableness, ScoreLevel::Low) => "summary_a_low",
+        (Domain::Agreeableness, ScoreLevel::Neutral) => "summary_a_neutral",
+        (Domain::Agreeableness, ScoreLevel::High) => "summary_a_high",
+        (Domain::Conscientiousness, ScoreLevel::Low) => "summary_c_low",
+        (Domain::Conscientiousness, ScoreLevel::Neutral) => "summary_c_neutral",
+        (Domain::Conscientiousness, ScoreLevel::High) => "summary_c_high",
+    }
+}
+
+/// Card at the top of the results with the most pronounced traits and what they mean.
+#[component]
+fn SummaryCard(profile: PersonalityProfile) -> impl IntoView {
+    let i18n = use_i18n();
+
+    let archetype = profile.archetype();
+    let labels = Memo::new(move |_| ArchetypeLabels::new(i18n.get_locale().code()).ok());
+    let headline = move || {
+        let archetype = archetype?;
+        let labels = labels.read();
+        let labels = labels.as_ref()?;
+        Some(view! {
+            <div class="mb-5 pb-5 border-b border-gray-100 dark:border-gray-700">
+                <p class="text-sm text-gray-500 dark:text-gray-400">{i18n.t("archetype_label")}</p>
+                <p class="text-2xl font-bold text-gray-900 dark:text-white">
+                    {labels.name(archetype).to_string()}
+                    <span class="ml-2 font-mono text-base font-normal text-gray-500 dark:text-gray-400">
+                        {archetype.code()}
+                    </span>
+                </p>
+                <p class="mt-1 text-gray-600 dark:text-gray-300">{labels.summary(archetype)}</p>
+            </div>
+        })
+    };
+
+    let highlights = most_pronounced(&profile)
+        .into_iter()
+        .take(SUMMARY_TRAITS)
+        .map(|score| {
+            let (domain, level) = (score.domain, score.level);
+            view! {
+                <li class="flex items-start gap-3">
+                    <span
+                        class="w-3 h-3 rounded-full mt-1.5 flex-shrink-0"
+                        style:background-color=domain_color(domain)
+                    />
+                    <div>
+                        <p class="font-semibold text-gray-800 dark:text-gray-100">
+                            {move || {
+                                format!("{} — {}", i18n.t(domain_key(domain)), i18n.t(level_key(level)).to_lowercase())
+                            }}
+                        </p>
+                        <p class="text-gray-600 dark:text-gray-300">{move || i18n.t(summary_key(domain, level))}</p>
+                    </div>
+                </li>
+            }
+        })
+        .collect_view();
+
+    view! {
+        <section class="mb-6 bg-white dark:bg-gray-800 rounded-lg shadow-md p-6 transition-colors duration-300">
+            {headline}
+            <h2 class="text-lg font-semibold text-gray-800 dark:text-gray-100 mb-4">
+                {move || i18n.t("summary_title")}
+            </h2>
+            <ul class="space-y-4">{highlights}</ul>
+        </section>
+    }
+}
+
+/// Number of non-average domains named in a shared result's page title.
+const META_TITLE_TRAITS: usize = 2;
+
+/// Page title and description for a shared result.
+///
+/// Rendered during SSR (the route uses `SsrMode::Async`), so link previews and search
+/// engines see the actual profile instead of the generic site title.
+#[component]
+fn SharedResultMeta(id: String) -> impl IntoView {
+    let i18n = use_i18n();
+    let saved = Resource::new(
+        move || id.clone(),
+        // The link key lives in the URL fragment, which never reaches the server
+        |id| async move { get_saved_results(id, None).await.ok().flatten() },
+    );
+
+    view! {
+        <Suspense>
+            {move || Suspend::new(async move {
+                let profile = saved.await?.profile;
+
+                let notable: Vec<_> = most_pronounced(&profile)
+                    .into_iter()
+                    .filter(|d| d.level != ScoreLevel::Neutral)
+                    .collect();
+                let traits = if notable.is_empty() {
+                    i18n.t("results_meta_balanced").to_string()
+                } else {
+                    notable
+                        .iter()
+                        .take(META_TITLE_TRAITS)
+                        .map(|d| {
+                            format!(
+                                "{}: {}",
+                                i18n.t(domain_key(d.domain)),
+                                i18n.t(level_key(d.level)).to_lowercase(),
+                            )
+                        })
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                };
+                let archetype = profile.archetype().and_then(|archetype| {
+                    let labels = ArchetypeLabels::new(i18n.get_locale().code()).ok()?;
+                    Some(format!("{} ({})", labels.name(archetype), archetype.code()))
+                });
+                let title = match archetype {
+                    Some(archetype) => {
+                        format!("{} — {}: {}", i18n.t("results_meta_title"), archetype, traits)
+                    }
+                    None => format!("{} — {}", i18n.t("results_meta_title"), traits),
+                };
+
+                let scores = profile
+                    .domains
+                    .iter()
+                    .map(|d| format!("{} {:.0}%", i18n.t(domain_key(d.domain)), d.percentage()))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                let description = format!("{}. {}", scores, i18n.t("results_meta_cta"));
+
+                Some(view! {
+                    <Title text=title />
+                    <Meta name="description" content=description />
+                })
+            })}
+        </Suspense>
+    }
+}
+
+/// Collapsible legend explaining the Low/Average/High bands and the bar scale.
+#[component]
+fn ScoreLegend(
+    /// Number of results behind the baseline marks, if they are shown.
+    #[prop(into)]
+    sample_size: Signal<Option<usize>>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+
+    let range_text =
+        |range: std::ops::RangeInclusive<u8>| format!("{}–{}", range.start(), range.end());
+
+    view! {
+        <details class="mb-4 bg-white dark:bg-gray-800 rounded-lg shadow-md px-4 py-3 text-sm transition-colors duration-300">
+            <summary class="cursor-pointer font-medium text-gray-700 dark:text-gray-200">
+                {move || i18n.t("legend_title")}
+            </summary>
+            <div class="mt-3 space-y-3 text-gray-600 dark:text-gray-300">
+                <p>{move || i18n.t("legend_scale")}</p>
+                <table class="w-full text-left">
+                    <thead>
+                        <tr class="text-gray-500 dark:text-gray-400">
+                            <th class="font-medium py-1">{move || i18n.t("legend_level")}</th>
+                            <th class="font-medium py-1">{move || i18n.t("legend_domains")}</th>
+                            <th class="font-medium py-1">{move || i18n.t("legend_facets")}</th>
+                        </tr>
+                    </thead>
+                    <tbody>
+                        {ScoreLevel::ALL
+                            .into_iter()
+                            .map(|level| {
+                                view! {
+                                    <tr>
+                                        <td class="py-1">{move || i18n.t(level_key(level))}</td>
+                                        <td class="py-1 tabular-nums">{range_text(level.domain_range())}</td>
+                                        <td class="py-1 tabular-nums">{range_text(level.facet_range())}</td>
+                                    </tr>
+                                }
+                            })
+                            .collect_view()}
+                    </tbody>
+                </table>
+                {move || {
+                    sample_size
+                        .get()
+                        .map(|n| {
+                            view! {
+                                <p class="flex items-center gap-2">
+                                    <span class="relative inline-block w-8 h-2 rounded-full score-bar-baseline">
+                                        <span class="score-bar-baseline-marker absolute -inset-y-0.5 right-0 w-0.5 rounded-full" />
+                                    </span>
+                                    {format!("{} {}", i18n.t("legend_baseline"), n)}
+                                </p>
+                            }
+                        })
+                }}
+                <p class="text-xs text-gray-500 dark:text-gray-400">{move || i18n.t("legend_note")}</p>
+            </div>
+        </details>
+    }
+}
+
+/// Selectable card describing one model preset in the model picker.
+#[component]
+fn ModelCard(
+    model: ClientModelInfo,
+    /// Whether the analysis will be translated into the interface language.
+    translated: bool,
+    #[prop(into)] selected: Signal<bool>,
+    #[prop(into)] on_select: Callback<()>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+
+    let speed_key = model.speed.as_deref().and_then(|speed| match speed {
+        "fast" => Some("results_model_speed_fast"),
+        "medium" => Some("results_model_speed_medium"),
+        "slow" => Some("results_model_speed_slow"),
+        _ => None,
+    });
+    let badge_class =
+        "px-2 py-0.5 rounded-full bg-gray-100 dark:bg-gray-700 text-gray-600 dark:text-gray-300";
+
+    view! {
+        <button
+            type="button"
+            role="radio"
+            aria-checked=move || selected.get().to_string()
+            on:click=move |_| on_select.run(())
+            class=move || {
+                if selected.get() {
+                    "text-left p-4 rounded-lg border-2 border-indigo-500 bg-indigo-50 dark:bg-indigo-900/30 transition-colors"
+                } else {
+                    "text-left p-4 rounded-lg border-2 border-gray-200 dark:border-gray-600 bg-white dark:bg-gray-700 hover:border-indigo-300 dark:hover:border-indigo-400 transition-colors"
+                }
+            }
+        >
+            <div class="font-medium text-gray-900 dark:text-white">{model.display_name}</div>
+            {model
+                .description
+                .map(|description| {
+                    view! { <p class="mt-1 text-sm text-gray-600 dark:text-gray-300">{description}</p> }
+                })}
+            <div class="mt-3 flex flex-wrap gap-2 text-xs">
+                {speed_key.map(|key| view! { <span class=badge_class>{i18n.t(key)}</span> })}
+                {model.thinking.then(|| view! { <span class=badge_class>{i18n.t("results_model_thinking")}</span> })}
+                {translated.then(|| view! { <span class=badge_class>{i18n.t("results_model_translated")}</span> })}
+            </div>
+        </button>
+    }
+}
+
+/// Thumbs up/down buttons under an AI analysis, with an optional comment.
+///
+/// Re-created whenever the analysis changes, so a regenerated analysis can be rated again.
+#[component]
+fn AnalysisFeedback(
+    result_id: Option<String>,
+    model_id: Option<String>,
+    prompt_version: Option<String>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+
+    let (rating, set_rating) = signal::<Option<bool>>(None);
+    let (comment, set_comment) = signal(String::new());
+    let (sending, set_sending) = signal(false);
+    let (sent, set_sent) = signal(false);
+    let (error, set_error) = signal(false);
+    let ids = StoredValue::new((result_id, model_id, prompt_version));
+
+    let send = move |_| {
+        let Some(helpful) = rating.get() else { return };
+        let (result_id, model_id, prompt_version) = ids.get_value();
+        let comment = comment.get();
+        let lang = i18n.get_locale().code().to_string();
+        set_sending.set(true);
+        set_error.set(false);
+        spawn_local(async move {
+            let comment = Some(comment).filter(|c| !c.trim().is_empty());
+            match submit_feedback(result_id, model_id, prompt_version, helpful, comment, lang).await
+            {
+                Ok(()) => set_sent.set(true),
+                Err(_) => set_error.set(true),
+            }
+            set_sending.set(false);
+        });
+    };
+
+    let thumb_class = move |value: bool| {
+        if rating.get() == Some(value) {
+            "p-2 rounded-lg border border-indigo-500 bg-indigo-50 dark:bg-indigo-900/30 text-indigo-600 dark:text-indigo-300 transition-colors"
+        } else {
+            "p-2 rounded-lg border border-gray-300 dark:border-gray-600 text-gray-500 dark:text-gray-400 hover:bg-gray-50 dark:hover:bg-gray-700 transition-colors"
+        }
+    };
+
+    view! {
+        <div class="no-print mb-4 border-t border-gray-200 dark:border-gray-700 pt-4">
+            <Show
+                when=move || !sent.get()
+                fallback=move || {
+                    view! {
+                        <p class="text-sm text-green-700 dark:text-green-400">{move || i18n.t("feedback_thanks")}</p>
+                    }
+                }
+            >
+                <div class="flex items-center gap-3">
+                    <span class="text-sm text-gray-600 dark:text-gray-300">{move || i18n.t("feedback_question")}</span>
+                    <button
+                        type="button"
+                        aria-label=move || i18n.t("feedback_helpful")
+                        aria-pressed=move || (rating.get() == Some(true)).to_string()
+                        on:click=move |_| set_rating.set(Some(true))
+                        class=move || thumb_class(true)
+                    >
+                        <svg class="w-5 h-5" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+                            <path
+                                stroke-linecap="round"
+                                stroke-linejoin="round"
+                                stroke-width="2"
+                                d="M14 10h4.764a2 2 0 011.789 2.894l-3.5 7A2 2 0 0115.263 21h-4.017c-.163 0-.326-.02-.485-.06L7 20m7-10V5a2 2 0 00-2-2h-.095c-.5 0-.905.405-.905.905 0 .714-.211 1.412-.608 2.006L7 11v9m7-10h-2M7 20H5a2 2 0 01-2-2v-6a2 2 0 012-2h2.5"
+                            />
+                        </svg>
+                    </button>
+                    <button
+                        type="button"
+                        aria-label=move || i18n.t("feedback_not_helpful")
+                        aria-pressed=move || (rating.get() == Some(false)).to_string()
+                        on:click=move |_| set_rating.set(Some(false))
+                        class=move || thumb_class(false)
+                    >
+                        <svg class="w-5 h-5" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+                            <path
+                                stroke-linecap="round"
+                                stroke-linejoin="round"
+                                stroke-width="2"
+                                d="M10 14H5.236a2 2 0 01-1.789-2.894l3.5-7A2 2 0 018.736 3h4.018a2 2 0 01.485.06l3.76.94m-7 10v5a2 2 0 002 2h.096c.5 0 .905-.405.905-.904 0-.715.211-1.413.608-2.008L17 13V4m-7 10h2m5-10h2a2 2 0 012 2v6a2 2 0 01-2 2h-2.5"
+                            />
+                        </svg>
+                    </button>
+                </div>
+                <Show when=move || rating.get().is_some()>
+                    <div class="mt-3">
+                        <textarea
+                            rows="2"
+                            maxlength=MAX_FEEDBACK_COMMENT_CHARS.to_string()
+                            placeholder=move || i18n.t("feedback_comment_placeholder")
+                            prop:value=move || comment.get()
+                            on:input=move |ev| set_comment.set(event_target_value(&ev))
+                            class="w-full px-3 py-2 text-sm border border-gray-300 dark:border-gray-600 bg-white dark:bg-gray-700 rounded-lg focus:ring-2 focus:ring-indigo-500 focus:border-indigo-500 text-gray-700 dark:text-gray-200 placeholder-gray-400 dark:placeholder-gray-500"
+                        />
+                        <div class="mt-2 flex items-center gap-3">
+                            <button
+                                type="button"
+                                on:click=send
+                                disabled=move || sending.get()
+                                class="px-4 py-2 text-sm bg-indigo-600 dark:bg-indigo-500 text-white rounded-lg hover:bg-indigo-700 dark:hover:bg-indigo-600 transition-colors disabled:opacity-50"
+                            >
+                                {move || i18n.t("feedback_send")}
+                            </button>
+                            <Show when=move || error.get()>
+                                <span class="text-sm text-red-600 dark:text-red-400">{move || i18n.t("feedback_error")}</span>
+                            </Show>
+                        </div>
+                    </div>
+                </Show>
+            </Show>
+        </div>
+    }
+}
+
 /// Results page with score visualization and AI-generated description.
 #[component]
 pub fn ResultsPage() -> impl IntoView {
@@ -219,10 +1240,7 @@ pub fn ResultsPage() -> impl IntoView {
     let (profile, set_profile) = signal::<Option<PersonalityProfile>>(None);
 
     // "Copied!" / "Saving..." feedback state
-    #[allow(unused_variables)]
-    let (link_copied, set_link_copied) = signal(false);
-    #[allow(unused_variables)]
-    let (share_saving, set_share_saving) = signal(false);
+    let share_open = RwSignal::new(false);
 
     // Expanded domain state (for facet accordion)
     let (expanded_domain, set_expanded_domain) = signal::<Option<Domain>>(None);
@@ -230,28 +1248,116 @@ pub fn ResultsPage() -> impl IntoView {
     // AI description state
     let (ai_description, set_ai_description) = signal::<Option<String>>(None);
     let (ai_loading, set_ai_loading) = signal(false);
+    // Elapsed seconds and rough seconds left of the running analysis
+    let (ai_progress, set_ai_progress) = signal::<Option<(u64, Option<u64>)>>(None);
     let (ai_error, set_ai_error) = signal::<Option<String>>(None);
+    // Model preset that generated the analysis in this session (unknown for saved analyses)
+    let (analysis_model, set_analysis_model) = signal::<Option<String>>(None);
+    // Prompt template version of the shown analysis, stored with shares and feedback
+    let (prompt_version, set_prompt_version) = signal::<Option<String>>(None);
+    // When the user agreed to send the data behind the shown analysis to the provider
+    let (analysis_consented_at, set_analysis_consented_at) = signal::<Option<i64>>(None);
+    // Today's analyses of this client, when the server limits them
+    let (analysis_quota, set_analysis_quota) = signal::<Option<AnalysisQuota>>(None);
+    let refresh_quota = move || {
+        spawn_local(async move {
+            if let Ok(quota) = get_analysis_quota().await {
+                set_analysis_quota.set(quota);
+            }
+        });
+    };
+    Effect::new(move |_| refresh_quota());
+    // Consent dialog before the first analysis request of the session
+    let consent_open = RwSignal::new(false);
 
     // User context for AI (optional self-description)
     let (user_context, set_user_context) = signal(String::new());
 
+    // Display name shown on the shared page (set by owner, read by viewers)
+    let (nickname, set_nickname) = signal(String::new());
+
     // "Not found" state for invalid shared links
     let (not_found, set_not_found) = signal(false);
 
+    // Protected shared result opened without its key: only scores are shown
+    let (locked, set_locked) = signal(false);
+
+    // Shared result shown from the offline copy: AI actions need the server
+    let (offline_copy, set_offline_copy) = signal(false);
+
+    // Owner token for the shared result, if this browser created it
+    let (owner_token, set_owner_token) = signal::<Option<String>>(None);
+
+    // Delete confirmation / in-flight / error state
+    let (confirm_delete, set_confirm_delete) = signal(false);
+    let (deleting, set_deleting) = signal(false);
+    let (delete_error, set_delete_error) = signal(false);
+
+    // After deleting: until when it can be undone (Unix seconds), and what undo puts back
+    let (deleted_until, set_deleted_until) = signal::<Option<i64>>(None);
+    let removed_history = StoredValue::new(None);
+    let (restoring, set_restoring) = signal(false);
+    let (restore_error, set_restore_error) = signal(false);
+
     // Whether this is a viewer (opened shared link with :id)
-    let is_viewer = Memo::new(move |_| params.get().get("id").is_some());
+    // Profile decoded from a `#p=` link, which needs no server storage
+    let (fragment_view, set_fragment_view) = signal(false);
+    let is_viewer = Memo::new(move |_| params.get().get("id").is_some() || fragment_view.get());
 
     // Load available models from server (Resource runs on both server and client)
-    let models_resource =
-        Resource::new(|| (), |_| async move { get_available_models().await.ok() });
+    let models_resource = Resource::new(
+        move || i18n.get_locale(),
+        |locale| async move { get_available_models(locale.code().to_string()).await.ok() },
+    );
+
+    // Average scores of other people, drawn behind the score bars
+    let baseline_resource = Resource::new(
+        || (),
+        |_| async move { get_baseline().await.ok().flatten() },
+    );
+    let baseline = move || baseline_resource.get().flatten();
+
+    // Whether the AI section is offered; assumed until the server says otherwise
+    let ai_resource = Resource::new(|| (), |_| async move { get_ai_enabled().await.ok() });
+    let ai_enabled = move || ai_resource.get().flatten().unwrap_or(true) && !offline_copy.get();
+
+    // Whether facet scores get an "Explain" action
+    let explanations_resource = Resource::new(
+        || (),
+        |_| async move { get_facet_explanations_enabled().await.unwrap_or(false) },
+    );
+    let explanations_enabled =
+        move || explanations_resource.get().unwrap_or(false) && !offline_copy.get();
+
+    // Reference group for percentile ranks, described by the result's language and country
+    let norm_group = RwSignal::new(NormGroup::All);
+    let (result_lang, set_result_lang) = signal::<Option<String>>(None);
+    let (result_country, set_result_country) = signal::<Option<String>>(None);
+    let norms_resource = Resource::new(
+        move || norm_group.get(),
+        |group| async move { get_norms(group).await.ok().flatten() },
+    );
+    let norms = move || norms_resource.get().flatten();
+
+    // Maximum user context length
+    let context_limit = Resource::new(|| (), |_| async move { get_context_limit().await.ok() });
+
+    let context_too_long = move || {
+        context_limit
+            .get()
+            .flatten()
+            .is_some_and(|max| user_context.get().chars().count() > max)
+    };
 
     // Selected model state
     let (selected_model, set_selected_model) = signal::<Option<String>>(None);
 
-    // Set default model when models load
+    // Set default model when models load, or when the selection isn't offered for this locale
     Effect::new(move |_| {
         if let Some(Some(models)) = models_resource.get()
-            && selected_model.get().is_none()
+            && !selected_model
+                .get()
+                .is_some_and(|id| models.iter().any(|m| m.id == id))
         {
             let default_id = models
                 .iter()
@@ -270,27 +1376,60 @@ pub fn ResultsPage() -> impl IntoView {
 
         if let Some(id) = url_id {
             // Viewer: load from database
+            let owner = load_owner_token(&id);
+            let key = link_key().or_else(|| owner.clone());
+            set_owner_token.set(owner);
             let nav = navigate.clone();
             let prefix = i18n.get_locale().path_prefix().to_string();
             spawn_local(async move {
-                match get_saved_results(id).await {
-                    Ok(Some(saved)) => {
-                        set_profile.set(Some(saved.profile));
-                        if let Some(ctx) = saved.user_context {
-                            set_user_context.set(ctx);
-                        }
-                        if let Some(analysis) = saved.ai_analysis {
-                            set_ai_description.set(Some(analysis));
-                        }
-                    }
+                let (saved, from_cache) = match get_saved_results(id.clone(), key).await {
+                    Ok(Some(saved)) => (saved, false),
                     Ok(None) => {
+                        crate::offline::remove_result(&id).await;
                         set_not_found.set(true);
+                        return;
                     }
-                    Err(_) => {
-                        nav(&format!("{}/test", prefix), Default::default());
-                    }
+                    // Server unreachable: fall back to the copy from an earlier visit
+                    Err(_) => match crate::offline::load_result(&id)
+                        .await
+                        .and_then(|json| serde_json::from_str::<SavedResultData>(&json).ok())
+                    {
+                        Some(saved) => (saved, true),
+                        None => {
+                            nav(&format!("{}/test", prefix), Default::default());
+                            return;
+                        }
+                    },
+                };
+                set_offline_copy.set(from_cache);
+                // Serialized before the fields are moved into signals, stored after rendering
+                let cache_json = if from_cache {
+                    None
+                } else {
+                    serde_json::to_string(&saved).ok()
+                };
+                set_locked.set(saved.locked);
+                set_profile.set(Some(saved.profile));
+                set_result_lang.set(Some(saved.lang));
+                set_result_country.set(saved.demographics.and_then(|d| d.country));
+                if let Some(ctx) = saved.user_context {
+                    set_user_context.set(ctx);
+                }
+                if let Some(analysis) = saved.ai_analysis {
+                    set_ai_description.set(Some(analysis));
+                    set_prompt_version.set(saved.prompt_version);
+                }
+                if let Some(name) = saved.nickname {
+                    set_nickname.set(name);
+                }
+                if let Some(json) = cache_json {
+                    crate::offline::save_result(&id, &json).await;
                 }
             });
+        } else if let Some(shared) = fragment_profile() {
+            // Viewer: the whole profile is in the link
+            set_fragment_view.set(true);
+            set_profile.set(Some(shared));
         } else {
             // Owner: load from localStorage
             let loaded = load_profile();
@@ -300,15 +1439,27 @@ pub fn ResultsPage() -> impl IntoView {
                 return;
             }
             set_profile.set(Some(loaded.unwrap()));
+            set_result_country.set(load_demographics().and_then(|d| d.country));
 
             if let Some(ctx) = load_context() {
                 set_user_context.set(ctx);
             }
+            if let Some(name) = load_nickname() {
+                set_nickname.set(name);
+            }
+        }
+    });
+
+    // Deep links to analysis sections: the headings only exist once the analysis is shown
+    Effect::new(move |_| {
+        if ai_description.with(Option::is_some) {
+            #[cfg(target_arch = "wasm32")]
+            scroll_to_analysis_section();
         }
     });
 
     // Request AI description with polling
-    let request_ai = move |_| {
+    let start_ai = move || {
         let Some(prof) = profile.get() else { return };
         let Some(model_id) = selected_model.get() else {
             set_ai_error.set(Some("No model selected".to_string()));
@@ -322,7 +1473,10 @@ pub fn ResultsPage() -> impl IntoView {
             Some(context)
         };
         set_ai_description.set(None);
+        set_analysis_consented_at.set(None);
+        set_analysis_model.set(Some(model_id.clone()));
         set_ai_loading.set(true);
+        set_ai_progress.set(None);
         set_ai_error.set(None);
 
         spawn_local(async move {
@@ -339,10 +1493,12 @@ pub fn ResultsPage() -> impl IntoView {
             );
 
             let job_id =
-                match start_analysis(prof, lang_str.to_string(), context_opt, model_id).await {
+                match start_analysis(prof, lang_str.to_string(), context_opt, model_id, true).await
+                {
                     Ok(id) => {
                         #[cfg(target_arch = "wasm32")]
                         web_sys::console::log_1(&format!("Got job_id: {}", id).into());
+                        refresh_quota();
                         id
                     }
                     Err(e) => {
@@ -350,6 +1506,7 @@ pub fn ResultsPage() -> impl IntoView {
                         web_sys::console::log_1(&format!("start_analysis error: {}", e).into());
                         set_ai_error.set(Some(e.to_string()));
                         set_ai_loading.set(false);
+                        refresh_quota();
                         return;
                     }
                 };
@@ -360,20 +1517,30 @@ pub fn ResultsPage() -> impl IntoView {
                 web_sys::console::log_1(&format!("Starting poll for job {}", job_id).into());
             }
 
+            // Back off exponentially so long analyses don't keep hammering the server
             #[cfg(target_arch = "wasm32")]
-            let mut poll_count: u32 = 0;
+            let (mut interval_ms, mut waited_ms) = (POLL_INITIAL_MS, 0u32);
 
             loop {
                 // Wait before polling
                 #[cfg(target_arch = "wasm32")]
                 {
-                    gloo_timers::future::TimeoutFuture::new(POLL_INTERVAL_MS).await;
-                    poll_count += 1;
+                    gloo_timers::future::TimeoutFuture::new(interval_ms).await;
+                    waited_ms += interval_ms;
+                    interval_ms = (interval_ms * 2).min(POLL_MAX_MS);
+
+                    // Nobody is looking: pause until the tab is shown again, then poll right away
+                    if page_hidden() {
+                        while page_hidden() {
+                            gloo_timers::future::TimeoutFuture::new(HIDDEN_CHECK_MS).await;
+                        }
+                        interval_ms = POLL_INITIAL_MS;
+                    }
                 }
 
                 // Check for timeout
                 #[cfg(target_arch = "wasm32")]
-                if poll_count >= MAX_POLL_ATTEMPTS {
+                if waited_ms >= POLL_TIMEOUT_MS {
                     web_sys::console::log_1(&"Poll timeout reached".into());
                     set_ai_error.set(Some("Analysis timed out. Please try again.".to_string()));
                     set_ai_loading.set(false);
@@ -382,16 +1549,22 @@ pub fn ResultsPage() -> impl IntoView {
 
                 #[cfg(target_arch = "wasm32")]
                 web_sys::console::log_1(
-                    &format!("Polling attempt {} for job {}", poll_count, job_id).into(),
+                    &format!("Polling job {} after {} ms", job_id, waited_ms).into(),
                 );
 
                 match get_analysis_status(job_id.clone()).await {
-                    Ok(AnalysisStatus::Complete(description)) => {
+                    Ok(AnalysisStatus::Complete {
+                        analysis,
+                        prompt_version,
+                        consented_at,
+                    }) => {
                         #[cfg(target_arch = "wasm32")]
                         web_sys::console::log_1(
-                            &format!("Got complete result, len={}", description.len()).into(),
+                            &format!("Got complete result, len={}", analysis.len()).into(),
                         );
-                        set_ai_description.set(Some(description));
+                        set_ai_description.set(Some(analysis));
+                        set_prompt_version.set(Some(prompt_version));
+                        set_analysis_consented_at.set(Some(consented_at));
                         set_ai_loading.set(false);
                         break;
                     }
@@ -400,11 +1573,17 @@ pub fn ResultsPage() -> impl IntoView {
                         web_sys::console::log_1(&format!("Got error: {}", err).into());
                         set_ai_error.set(Some(err));
                         set_ai_loading.set(false);
+                        // The failed analysis was given back
+                        refresh_quota();
                         break;
                     }
-                    Ok(AnalysisStatus::Pending) => {
+                    Ok(AnalysisStatus::Pending {
+                        elapsed_secs,
+                        eta_secs,
+                    }) => {
                         #[cfg(target_arch = "wasm32")]
                         web_sys::console::log_1(&"Status: pending".into());
+                        set_ai_progress.set(Some((elapsed_secs, eta_secs)));
                         // Continue polling
                     }
                     Err(e) => {
@@ -419,60 +1598,68 @@ pub fn ResultsPage() -> impl IntoView {
         });
     };
 
-    // Share: save snapshot to DB and copy link (owner), or just copy current URL (viewer)
-    #[allow(unused_variables)]
-    let share_results = move |_| {
-        #[cfg(target_arch = "wasm32")]
-        {
-            if is_viewer.get() {
-                // Viewer: just copy current URL
-                if let Some(window) = web_sys::window() {
-                    let url = window.location().href().unwrap_or_default();
-                    let clipboard = window.navigator().clipboard();
-                    let _ = clipboard.write_text(&url);
-
-                    set_link_copied.set(true);
-                    spawn_local(async move {
-                        gloo_timers::future::TimeoutFuture::new(2000).await;
-                        set_link_copied.set(false);
-                    });
+    // The first request of the session asks for consent before anything is sent
+    let request_ai = move |_| {
+        if has_ai_consent() {
+            start_ai();
+        } else {
+            consent_open.set(true);
+        }
+    };
+
+    // Delete the shared result (owner only) and clear local copies; it can be undone for a while
+    let delete_result = move |_| {
+        let Some(id) = params.get_untracked().get("id") else {
+            return;
+        };
+        let Some(token) = owner_token.get_untracked() else {
+            return;
+        };
+        let deleted_profile = profile.get_untracked();
+
+        set_deleting.set(true);
+        set_delete_error.set(false);
+        spawn_local(async move {
+            match delete_results(id.clone(), token).await {
+                Ok(Some(until)) => {
+                    remove_owner_token(&id);
+                    removed_history.set_value(remove_history(&id));
+                    if deleted_profile.is_some() && load_profile() == deleted_profile {
+                        clear_local_results();
+                    }
+                    set_confirm_delete.set(false);
+                    set_deleted_until.set(Some(until));
                 }
-            } else {
-                // Owner: save snapshot to DB, then copy link
-                let Some(prof) = profile.get() else { return };
-                let locale = i18n.get_locale();
-                let ctx = {
-                    let c = user_context.get();
-                    if c.trim().is_empty() { None } else { Some(c) }
-                };
-                let analysis = ai_description.get();
-
-                set_share_saving.set(true);
-                spawn_local(async move {
-                    match save_results(prof, ctx, analysis, locale.code().to_string()).await {
-                        Ok(id) => {
-                            if let Some(window) = web_sys::window() {
-                                let origin = window.location().origin().unwrap_or_default();
-                                let url =
-                                    format!("{}{}/results/{}", origin, locale.path_prefix(), id);
-                                let clipboard = window.navigator().clipboard();
-                                let _ = clipboard.write_text(&url);
-                            }
-                            set_share_saving.set(false);
-                            set_link_copied.set(true);
-                            gloo_timers::future::TimeoutFuture::new(2000).await;
-                            set_link_copied.set(false);
-                        }
-                        Err(_e) => {
-                            set_share_saving.set(false);
-                            web_sys::console::log_1(
-                                &format!("Failed to save results: {}", _e).into(),
-                            );
-                        }
+                Ok(None) | Err(_) => set_delete_error.set(true),
+            }
+            set_deleting.set(false);
+        });
+    };
+
+    // Undo the deletion and put the local bookkeeping back
+    let restore_result = move |_| {
+        let Some(id) = params.get_untracked().get("id") else {
+            return;
+        };
+        let Some(token) = owner_token.get_untracked() else {
+            return;
+        };
+
+        set_restoring.set(true);
+        set_restore_error.set(false);
+        spawn_local(async move {
+            match restore_results(id.clone(), token.clone()).await {
+                Ok(true) => {
+                    save_owner_token(&id, &token);
+                    if let Some(entry) = removed_history.get_value() {
+                        restore_history(entry);
                     }
-                });
+                    set_deleted_until.set(None);
+                }
+                Ok(false) | Err(_) => set_restore_error.set(true),
             }
-        }
+            set_restoring.set(false);
+        });
     };
 
     // Toggle domain expansion
@@ -486,17 +1673,6 @@ pub fn ResultsPage() -> impl IntoView {
         });
     };
 
-    // Get domain color class
-    let domain_color = |domain: &Domain| -> &'static str {
-        match domain {
-            Domain::Neuroticism => "bg-red-500",
-            Domain::Extraversion => "bg-yellow-500",
-            Domain::Openness => "bg-purple-500",
-            Domain::Agreeableness => "bg-green-500",
-            Domain::Conscientiousness => "bg-blue-500",
-        }
-    };
-
     // Get level text
     let level_text = move |level: ScoreLevel| -> String {
         match level {
@@ -560,14 +1736,16 @@ pub fn ResultsPage() -> impl IntoView {
 
     view! {
         <div class="max-w-4xl mx-auto px-4 py-8">
-            // Header with language and theme toggles
-            <header class="flex justify-between items-center mb-8">
-                <h1 class="text-2xl font-bold text-gray-900 dark:text-white">{i18n.t("results_title")}</h1>
-                <div class="flex items-center gap-3 no-print">
-                    <LangToggle />
-                    <ThemeToggle />
-                </div>
-            </header>
+            {move || params.get().get("id").map(|id| view! { <SharedResultMeta id=id /> })}
+            <h1 class="text-2xl font-bold text-gray-900 dark:text-white mb-8">{i18n.t("results_title")}</h1>
+
+            // Display name of the person who shared these results
+            <Show when=move || is_viewer.get() && !nickname.get().is_empty()>
+                <p class="-mt-6 mb-8 text-lg text-gray-600 dark:text-gray-300">
+                    {i18n.t("results_profile_of")} " "
+                    <span class="font-semibold text-gray-900 dark:text-white">{move || nickname.get()}</span>
+                </p>
+            </Show>
 
             {move || {
                 if not_found.get() {
@@ -587,24 +1765,48 @@ pub fn ResultsPage() -> impl IntoView {
                 }
 
                 let Some(prof) = profile.get() else {
-                    return view! { <div>"Loading..."</div> }.into_any();
+                    return view! { <ResultsSkeleton /> }.into_any();
                 };
 
                 view! {
                     <div>
+                        <Show when=move || locked.get()>
+                            <p class="mb-6 p-4 rounded-lg bg-gray-50 dark:bg-gray-800 border border-gray-200 dark:border-gray-700 text-sm text-gray-600 dark:text-gray-300">
+                                {move || i18n.t("results_locked_notice")}
+                            </p>
+                        </Show>
+                        <Show when=move || offline_copy.get()>
+                            <p class="no-print mb-6 p-4 rounded-lg bg-amber-50 dark:bg-amber-900/30 border border-amber-200 dark:border-amber-800 text-sm text-amber-800 dark:text-amber-200">
+                                {move || i18n.t("results_offline_notice")}
+                            </p>
+                        </Show>
+                        <SummaryCard profile=prof.clone() />
+                        {(!is_viewer.get()).then(|| view! { <ProfileChanges profile=prof.clone() /> })}
+                        <ScoreLegend sample_size=Signal::derive(move || baseline().map(|m| m.sample_size)) />
+                        <NormGroupPicker
+                            group=norm_group
+                            lang=Signal::derive(move || {
+                                result_lang.get().unwrap_or_else(|| i18n.get_locale().code().to_string())
+                            })
+                            country=result_country
+                            available=Signal::derive(move || norms_resource.get().is_none_or(|n| n.is_some()))
+                        />
+
                         // Domain scores
                         <div class="space-y-4 mb-8">
                             {prof
                                 .domains
                                 .iter()
-                                .map(|domain_score| {
+                                .enumerate()
+                                .map(|(index, domain_score)| {
                                     let domain = domain_score.domain;
                                     let raw = domain_score.raw;
                                     let level = domain_score.level;
                                     let percentage = domain_score.percentage();
                                     let facets = domain_score.facets.clone();
-                                    let color = domain_color(&domain);
                                     let is_expanded = move || expanded_domain.get() == Some(domain);
+                                    // Bars fill one after another on first render
+                                    let reveal_delay_ms = index as u32 * REVEAL_STAGGER_MS;
 
                                     view! {
                                         <div class="bg-white dark:bg-gray-800 rounded-lg shadow-md overflow-hidden transition-colors duration-300">
@@ -619,19 +1821,28 @@ pub fn ResultsPage() -> impl IntoView {
                                                             {domain_name(domain)}
                                                         </h3>
                                                         <span class="text-sm text-gray-500 dark:text-gray-400">
-                                                            {format!("{} ({})", raw, level_text(level))}
+                                                            <CountUp value=raw delay_ms=reveal_delay_ms />
+                                                            {format!(" ({})", level_text(level))}
                                                         </span>
                                                     </div>
                                                     // Score bar
-                                                    <div class="w-full bg-gray-200 dark:bg-gray-600 rounded-full h-3">
-                                                        <div
-                                                            class=format!(
-                                                                "{} h-3 rounded-full transition-all duration-500",
-                                                                color,
-                                                            )
-                                                            style:width=format!("{}%", percentage)
-                                                        />
-                                                    </div>
+                                                    <ScoreBar
+                                                        domain=domain
+                                                        percentage=percentage
+                                                        reveal_delay_ms=reveal_delay_ms
+                                                        baseline=Signal::derive(move || baseline().and_then(|m| m.domain(domain)))
+                                                    />
+                                                    {move || {
+                                                        norms()
+                                                            .and_then(|n| n.percentile(domain, percentage))
+                                                            .map(|rank| {
+                                                                view! {
+                                                                    <p class="mt-1 text-left text-xs text-gray-500 dark:text-gray-400">
+                                                                        {format!("{} {:.0}%", i18n.t("norms_higher_than"), rank)}
+                                                                    </p>
+                                                                }
+                                                            })
+                                                    }}
                                                 </div>
                                                 // Expand icon
                                                 <svg
@@ -658,7 +1869,7 @@ pub fn ResultsPage() -> impl IntoView {
                                             <div class=move || {
                                                 format!(
                                                     "print-expand overflow-hidden transition-all duration-300 {}",
-                                                    if is_expanded() { "max-h-96" } else { "max-h-0" },
+                                                    if is_expanded() { "max-h-[64rem]" } else { "max-h-0" },
                                                 )
                                             }>
                                                 <div class="px-4 pb-4 space-y-3 border-t border-gray-100 dark:border-gray-700 pt-4">
@@ -669,6 +1880,7 @@ pub fn ResultsPage() -> impl IntoView {
                                                             let facet_raw = facet_score.raw;
                                                             let facet_level = facet_score.level;
                                                             let facet_pct = facet_score.percentage();
+                                                            let facet = facet_score.facet;
 
                                                             view! {
                                                                 <div>
@@ -680,16 +1892,33 @@ pub fn ResultsPage() -> impl IntoView {
                                                                             {format!("{} ({})", facet_raw, level_text(facet_level))}
                                                                         </span>
                                                                     </div>
-                                                                    <div class="w-full bg-gray-100 dark:bg-gray-600 rounded-full h-2">
-                                                                        <div
-                                                                            class=format!("{} h-2 rounded-full opacity-70", color)
-                                                                            style:width=format!("{}%", facet_pct)
-                                                                        />
-                                                                    </div>
+                                                                    <ScoreBar
+                                                                        domain=domain
+                                                                        percentage=facet_pct
+                                                                        facet=true
+                                                                        baseline=Signal::derive(move || {
+                                                                            baseline().and_then(|m| m.facet(facet))
+                                                                        })
+                                                                    />
+                                                                    <Show when=explanations_enabled>
+                                                                        <FacetExplanation facet=facet level=facet_level />
+                                                                    </Show>
                                                                 </div>
                                                             }
                                                         })
                                                         .collect_view()}
+                                                    <A
+                                                        href=move || {
+                                                            format!(
+                                                                "{}/traits/{}",
+                                                                i18n.get_locale().path_prefix(),
+                                                                domain_slug(domain),
+                                                            )
+                                                        }
+                                                        attr:class="no-print inline-block text-sm text-indigo-600 dark:text-indigo-400 hover:underline"
+                                                    >
+                                                        {move || format!("{} {} →", i18n.t("traits_learn_more"), domain_name(domain))}
+                                                    </A>
                                                 </div>
                                             </div>
                                         </div>
@@ -698,241 +1927,321 @@ pub fn ResultsPage() -> impl IntoView {
                                 .collect_view()}
                         </div>
 
-                        // AI Analysis section
-                        <div class="bg-white dark:bg-gray-800 rounded-lg shadow-md p-6 mb-8 transition-colors duration-300">
-                            <h2 class="text-xl font-semibold text-gray-800 dark:text-gray-100 mb-4">
-                                {i18n.t("results_ai_title")}
-                            </h2>
-
-                            {move || {
-                                if let Some(description) = ai_description.get() {
-                                    let html_content = markdown_to_html(&description);
-                                    view! {
-                                        <div
-                                            class="markdown max-w-none mb-4 text-gray-700 dark:text-gray-300"
-                                            inner_html=html_content
-                                        />
-                                        {move || {
-                                            if !is_viewer.get() {
-                                                view! {
-                                                    <button
-                                                        on:click=move |_| set_ai_description.set(None)
-                                                        class="no-print px-4 py-2 text-sm border border-gray-300 dark:border-gray-600 text-gray-600 dark:text-gray-300 rounded-lg hover:bg-gray-50 dark:hover:bg-gray-700 transition-colors flex items-center"
-                                                    >
-                                                        <svg
-                                                            class="w-4 h-4 mr-2"
-                                                            fill="none"
-                                                            stroke="currentColor"
-                                                            viewBox="0 0 24 24"
-                                                        >
-                                                            <path
-                                                                stroke-linecap="round"
-                                                                stroke-linejoin="round"
-                                                                stroke-width="2"
-                                                                d="M4 4v5h.582m15.356 2A8.001 8.001 0 004.582 9m0 0H9m11 11v-5h-.581m0 0a8.003 8.003 0 01-15.357-2m15.357 2H15"
-                                                            />
-                                                        </svg>
-                                                        {i18n.t("results_ai_regenerate")}
-                                                    </button>
-                                                }.into_any()
-                                            } else {
-                                                view! { <div /> }.into_any()
+                        // Questions bookmarked during the test (owner only)
+                        <Show when=move || !is_viewer.get()>
+                            <ReflectionItems
+                                can_discuss=Signal::derive(move || {
+                                    ai_enabled() && ai_description.get().is_none()
+                                })
+                                on_discuss=Callback::new(move |text: String| {
+                                    set_user_context
+                                        .update(|context| {
+                                            if !context.trim().is_empty() {
+                                                context.push_str("\n\n");
                                             }
-                                        }}
-                                    }
-                                        .into_any()
-                                } else if ai_loading.get() {
-                                    view! {
-                                        <div class="no-print py-12">
-                                            // Animated AI icon
-                                            <div class="flex justify-center mb-6">
-                                                <div class="relative">
-                                                    // Pulsing rings
-                                                    <div class="absolute inset-0 animate-ping rounded-full bg-indigo-400 dark:bg-indigo-500 opacity-20" />
-                                                    <div class="absolute inset-2 animate-pulse rounded-full bg-indigo-300 dark:bg-indigo-400 opacity-30" />
-                                                    // Brain/AI icon
-                                                    <div class="relative flex items-center justify-center w-16 h-16 rounded-full bg-gradient-to-br from-indigo-500 to-purple-600 shadow-lg">
-                                                        <svg
-                                                            class="w-8 h-8 text-white"
-                                                            fill="none"
-                                                            stroke="currentColor"
-                                                            viewBox="0 0 24 24"
+                                            context.push_str(&text);
+                                            save_context(context);
+                                        });
+                                })
+                            />
+                        </Show>
+
+                        // AI Analysis section (kept for saved analyses when AI is disabled)
+                        <Show when=move || ai_enabled() || ai_description.get().is_some()>
+                            <div class="bg-white dark:bg-gray-800 rounded-lg shadow-md p-6 mb-8 transition-colors duration-300">
+                                <h2 class="text-xl font-semibold text-gray-800 dark:text-gray-100 mb-4">
+                                    {i18n.t("results_ai_title")}
+                                </h2>
+
+                                {move || {
+                                    if let Some(description) = ai_description.get() {
+                                        let html_content = crate::markdown::to_safe_html(&description, ANALYSIS_ID_PREFIX);
+                                        view! {
+                                            <div
+                                                class="markdown max-w-none mb-4 text-gray-700 dark:text-gray-300"
+                                                inner_html=html_content
+                                            />
+                                            <Show when=move || !offline_copy.get()>
+                                                <AnalysisFeedback
+                                                    result_id=params.get_untracked().get("id")
+                                                    model_id=analysis_model.get_untracked()
+                                                    prompt_version=prompt_version.get_untracked()
+                                                />
+                                            </Show>
+                                            {move || {
+                                                if !is_viewer.get() && ai_enabled() {
+                                                    view! {
+                                                        <button
+                                                            on:click=move |_| set_ai_description.set(None)
+                                                            class="no-print px-4 py-2 text-sm border border-gray-300 dark:border-gray-600 text-gray-600 dark:text-gray-300 rounded-lg hover:bg-gray-50 dark:hover:bg-gray-700 transition-colors flex items-center"
                                                         >
-                                                            <path
-                                                                stroke-linecap="round"
-                                                                stroke-linejoin="round"
-                                                                stroke-width="1.5"
-                                                                d="M9.663 17h4.673M12 3v1m6.364 1.636l-.707.707M21 12h-1M4 12H3m3.343-5.657l-.707-.707m2.828 9.9a5 5 0 117.072 0l-.548.547A3.374 3.374 0 0014 18.469V19a2 2 0 11-4 0v-.531c0-.895-.356-1.754-.988-2.386l-.548-.547z"
-                                                            />
-                                                        </svg>
+                                                            <svg
+                                                                class="w-4 h-4 mr-2"
+                                                                fill="none"
+                                                                stroke="currentColor"
+                                                                viewBox="0 0 24 24"
+                                                            >
+                                                                <path
+                                                                    stroke-linecap="round"
+                                                                    stroke-linejoin="round"
+                                                                    stroke-width="2"
+                                                                    d="M4 4v5h.582m15.356 2A8.001 8.001 0 004.582 9m0 0H9m11 11v-5h-.581m0 0a8.003 8.003 0 01-15.357-2m15.357 2H15"
+                                                                />
+                                                            </svg>
+                                                            {i18n.t("results_ai_regenerate")}
+                                                        </button>
+                                                    }.into_any()
+                                                } else {
+                                                    view! { <div /> }.into_any()
+                                                }
+                                            }}
+                                        }
+                                            .into_any()
+                                    } else if ai_loading.get() {
+                                        view! {
+                                            <div class="no-print py-12">
+                                                // Animated AI icon
+                                                <div class="flex justify-center mb-6">
+                                                    <div class="relative">
+                                                        // Pulsing rings
+                                                        <div class="absolute inset-0 animate-ping rounded-full bg-indigo-400 dark:bg-indigo-500 opacity-20" />
+                                                        <div class="absolute inset-2 animate-pulse rounded-full bg-indigo-300 dark:bg-indigo-400 opacity-30" />
+                                                        // Brain/AI icon
+                                                        <div class="relative flex items-center justify-center w-16 h-16 rounded-full bg-gradient-to-br from-indigo-500 to-purple-600 shadow-lg">
+                                                            <svg
+                                                                class="w-8 h-8 text-white"
+                                                                fill="none"
+                                                                stroke="currentColor"
+                                                                viewBox="0 0 24 24"
+                                                            >
+                                                                <path
+                                                                    stroke-linecap="round"
+                                                                    stroke-linejoin="round"
+                                                                    stroke-width="1.5"
+                                                                    d="M9.663 17h4.673M12 3v1m6.364 1.636l-.707.707M21 12h-1M4 12H3m3.343-5.657l-.707-.707m2.828 9.9a5 5 0 117.072 0l-.548.547A3.374 3.374 0 0014 18.469V19a2 2 0 11-4 0v-.531c0-.895-.356-1.754-.988-2.386l-.548-.547z"
+                                                                />
+                                                            </svg>
+                                                        </div>
+                                                    </div>
+                                                </div>
+                                                // Loading text with animated dots
+                                                <div class="text-center">
+                                                    <p class="text-lg font-medium text-gray-700 dark:text-gray-200 mb-2">
+                                                        {i18n.t("results_ai_loading")}
+                                                    </p>
+                                                    <div class="flex justify-center gap-1">
+                                                        <span
+                                                            class="w-2 h-2 bg-indigo-500 rounded-full animate-bounce"
+                                                            style="animation-delay: 0ms"
+                                                        />
+                                                        <span
+                                                            class="w-2 h-2 bg-indigo-500 rounded-full animate-bounce"
+                                                            style="animation-delay: 150ms"
+                                                        />
+                                                        <span
+                                                            class="w-2 h-2 bg-indigo-500 rounded-full animate-bounce"
+                                                            style="animation-delay: 300ms"
+                                                        />
                                                     </div>
+                                                    <p class="text-sm text-gray-500 dark:text-gray-400 mt-4">
+                                                        {move || match ai_progress.get() {
+                                                            Some((_, Some(0))) => i18n.t("results_ai_slow").to_string(),
+                                                            Some((elapsed, eta)) if elapsed > 0 => {
+                                                                let mut text = format!(
+                                                                    "{} {}",
+                                                                    i18n.t("results_ai_elapsed"),
+                                                                    format_duration(elapsed),
+                                                                );
+                                                                if let Some(eta) = eta {
+                                                                    text.push_str(
+                                                                        &format!(" · {} ~{}", i18n.t("results_ai_eta"), format_duration(eta)),
+                                                                    );
+                                                                }
+                                                                text
+                                                            }
+                                                            _ => i18n.t("results_ai_loading_hint").to_string(),
+                                                        }}
+                                                    </p>
                                                 </div>
                                             </div>
-                                            // Loading text with animated dots
-                                            <div class="text-center">
-                                                <p class="text-lg font-medium text-gray-700 dark:text-gray-200 mb-2">
-                                                    {i18n.t("results_ai_loading")}
-                                                </p>
-                                                <div class="flex justify-center gap-1">
-                                                    <span
-                                                        class="w-2 h-2 bg-indigo-500 rounded-full animate-bounce"
-                                                        style="animation-delay: 0ms"
-                                                    />
-                                                    <span
-                                                        class="w-2 h-2 bg-indigo-500 rounded-full animate-bounce"
-                                                        style="animation-delay: 150ms"
-                                                    />
-                                                    <span
-                                                        class="w-2 h-2 bg-indigo-500 rounded-full animate-bounce"
-                                                        style="animation-delay: 300ms"
-                                                    />
+                                        }
+                                            .into_any()
+                                    } else if let Some(error) = ai_error.get() {
+                                        view! {
+                                            <div class="no-print bg-red-50 dark:bg-red-900/30 text-red-700 dark:text-red-300 p-4 rounded-lg mb-4">
+                                                <p class="font-medium">{i18n.t("results_ai_error")}</p>
+                                                <p class="text-sm mt-1">{error}</p>
+                                            </div>
+                                            <button
+                                                on:click=request_ai
+                                                class="no-print px-6 py-2 bg-indigo-600 dark:bg-indigo-500 text-white rounded-lg hover:bg-indigo-700 dark:hover:bg-indigo-600 transition-colors"
+                                            >
+                                                {i18n.t("results_ai_retry")}
+                                            </button>
+                                        }
+                                            .into_any()
+                                    } else if !is_viewer.get() {
+                                        view! {
+                                            <p class="no-print text-gray-600 dark:text-gray-300 mb-4">
+                                                {i18n.t("results_ai_description")}
+                                            </p>
+
+                                            // Optional user context
+                                            <div class="no-print mb-6">
+                                                <label class="block text-sm font-medium text-gray-700 dark:text-gray-300 mb-2">
+                                                    {i18n.t("results_context_label")}
+                                                    <span class="text-gray-400 dark:text-gray-500 font-normal ml-1">
+                                                        {i18n.t("results_context_optional")}
+                                                    </span>
+                                                </label>
+                                                <textarea
+                                                    class="w-full px-4 py-3 border border-gray-300 dark:border-gray-600 bg-white dark:bg-gray-700 rounded-lg focus:ring-2 focus:ring-indigo-500 focus:border-indigo-500 resize-none text-gray-700 dark:text-gray-200 placeholder:text-gray-400 dark:placeholder:text-gray-500"
+                                                    rows="3"
+                                                    placeholder=i18n.t("results_context_placeholder")
+                                                    maxlength=move || context_limit.get().flatten().map(|max| max.to_string())
+                                                    prop:value=move || user_context.get()
+                                                    on:input=move |ev| {
+                                                        let value = event_target_value(&ev);
+                                                        save_context(&value);
+                                                        set_user_context.set(value);
+                                                    }
+                                                />
+                                                <div class="mt-1 flex justify-between gap-4 text-xs">
+                                                    <p class="text-gray-500 dark:text-gray-400">
+                                                        {i18n.t("results_context_hint")}
+                                                    </p>
+                                                    {move || {
+                                                        context_limit
+                                                            .get()
+                                                            .flatten()
+                                                            .map(|max| {
+                                                                let len = user_context.get().chars().count();
+                                                                let class = if len > max {
+                                                                    "flex-shrink-0 tabular-nums font-medium text-red-600 dark:text-red-400"
+                                                                } else if len * 10 >= max * 9 {
+                                                                    "flex-shrink-0 tabular-nums text-amber-600 dark:text-amber-400"
+                                                                } else {
+                                                                    "flex-shrink-0 tabular-nums text-gray-500 dark:text-gray-400"
+                                                                };
+                                                                view! { <span class=class>{format!("{} / {}", len, max)}</span> }
+                                                            })
+                                                    }}
                                                 </div>
-                                                <p class="text-sm text-gray-500 dark:text-gray-400 mt-4">
-                                                    {i18n.t("results_ai_loading_hint")}
+                                            </div>
+
+                                            // Model selector
+                                            <div class="no-print mb-6">
+                                                <p
+                                                    id="model-select-label"
+                                                    class="block text-sm font-medium text-gray-700 dark:text-gray-300 mb-2"
+                                                >
+                                                    {i18n.t("results_model_select")}
                                                 </p>
+                                                <div
+                                                    role="radiogroup"
+                                                    aria-labelledby="model-select-label"
+                                                    class="grid gap-3 sm:grid-cols-2"
+                                                >
+                                                    {move || {
+                                                        let models = models_resource.get().flatten().unwrap_or_default();
+                                                        let locale = i18n.get_locale();
+                                                        models
+                                                            .into_iter()
+                                                            .map(|m| {
+                                                                let id = m.id.clone();
+                                                                let is_selected = {
+                                                                    let id = id.clone();
+                                                                    move || selected_model.get().as_ref() == Some(&id)
+                                                                };
+                                                                let translated = m.translation && m.source_lang != locale.code();
+                                                                view! {
+                                                                    <ModelCard
+                                                                        model=m
+                                                                        translated=translated
+                                                                        selected=Signal::derive(is_selected)
+                                                                        on_select=move || set_selected_model.set(Some(id.clone()))
+                                                                    />
+                                                                }
+                                                            })
+                                                            .collect_view()
+                                                    }}
+                                                </div>
                                             </div>
-                                        </div>
-                                    }
-                                        .into_any()
-                                } else if let Some(error) = ai_error.get() {
-                                    view! {
-                                        <div class="no-print bg-red-50 dark:bg-red-900/30 text-red-700 dark:text-red-300 p-4 rounded-lg mb-4">
-                                            <p class="font-medium">{i18n.t("results_ai_error")}</p>
-                                            <p class="text-sm mt-1">{error}</p>
-                                        </div>
-                                        <button
-                                            on:click=request_ai
-                                            class="no-print px-6 py-2 bg-indigo-600 dark:bg-indigo-500 text-white rounded-lg hover:bg-indigo-700 dark:hover:bg-indigo-600 transition-colors"
-                                        >
-                                            {i18n.t("results_ai_retry")}
-                                        </button>
-                                    }
-                                        .into_any()
-                                } else if !is_viewer.get() {
-                                    view! {
-                                        <p class="no-print text-gray-600 dark:text-gray-300 mb-4">
-                                            {i18n.t("results_ai_description")}
-                                        </p>
-
-                                        // Optional user context
-                                        <div class="no-print mb-6">
-                                            <label class="block text-sm font-medium text-gray-700 dark:text-gray-300 mb-2">
-                                                {i18n.t("results_context_label")}
-                                                <span class="text-gray-400 dark:text-gray-500 font-normal ml-1">
-                                                    {i18n.t("results_context_optional")}
-                                                </span>
-                                            </label>
-                                            <textarea
-                                                class="w-full px-4 py-3 border border-gray-300 dark:border-gray-600 bg-white dark:bg-gray-700 rounded-lg focus:ring-2 focus:ring-indigo-500 focus:border-indigo-500 resize-none text-gray-700 dark:text-gray-200 placeholder:text-gray-400 dark:placeholder:text-gray-500"
-                                                rows="3"
-                                                placeholder=i18n.t("results_context_placeholder")
-                                                prop:value=move || user_context.get()
-                                                on:input=move |ev| {
-                                                    let value = event_target_value(&ev);
-                                                    save_context(&value);
-                                                    set_user_context.set(value);
-                                                }
-                                            />
-                                            <p class="mt-1 text-xs text-gray-500 dark:text-gray-400">
-                                                {i18n.t("results_context_hint")}
-                                            </p>
-                                        </div>
 
-                                        // Model selector
-                                        <div class="no-print mb-6">
-                                            <label class="block text-sm font-medium text-gray-700 dark:text-gray-300 mb-2">
-                                                {i18n.t("results_model_select")}
-                                            </label>
-                                            <select
-                                                class="w-full px-4 py-3 border border-gray-300 dark:border-gray-600 bg-white dark:bg-gray-700 rounded-lg focus:ring-2 focus:ring-indigo-500 focus:border-indigo-500 text-gray-700 dark:text-gray-200"
-                                                on:change=move |ev| {
-                                                    let value = event_target_value(&ev);
-                                                    set_selected_model.set(Some(value));
+                                            <QuotaNotice quota=analysis_quota />
+                                            <button
+                                                on:click=request_ai
+                                                disabled=move || {
+                                                    context_too_long()
+                                                        || analysis_quota.get().is_some_and(|q| q.remaining == 0)
                                                 }
+                                                class="no-print px-6 py-3 bg-indigo-600 dark:bg-indigo-500 text-white font-medium rounded-lg hover:bg-indigo-700 dark:hover:bg-indigo-600 transition-colors flex items-center disabled:opacity-50 disabled:cursor-not-allowed"
                                             >
-                                                {move || {
-                                                    let models = models_resource.get().flatten().unwrap_or_default();
-                                                    let current = selected_model.get();
-                                                    models
-                                                        .iter()
-                                                        .map(|m| {
-                                                            let is_selected = current.as_ref() == Some(&m.id);
-                                                            view! {
-                                                                <option value=m.id.clone() selected=is_selected>
-                                                                    {m.display_name.clone()}
-                                                                </option>
-                                                            }
-                                                        })
-                                                        .collect_view()
-                                                }}
-                                            </select>
-                                        </div>
-
-                                        <button
-                                            on:click=request_ai
-                                            class="no-print px-6 py-3 bg-indigo-600 dark:bg-indigo-500 text-white font-medium rounded-lg hover:bg-indigo-700 dark:hover:bg-indigo-600 transition-colors flex items-center"
-                                        >
-                                            <svg
-                                                class="w-5 h-5 mr-2"
-                                                fill="none"
-                                                stroke="currentColor"
-                                                viewBox="0 0 24 24"
-                                            >
-                                                <path
-                                                    stroke-linecap="round"
-                                                    stroke-linejoin="round"
-                                                    stroke-width="2"
-                                                    d="M13 10V3L4 14h7v7l9-11h-7z"
-                                                />
-                                            </svg>
-                                            {i18n.t("results_ai_button")}
-                                        </button>
+                                                <svg
+                                                    class="w-5 h-5 mr-2"
+                                                    fill="none"
+                                                    stroke="currentColor"
+                                                    viewBox="0 0 24 24"
+                                                >
+                                                    <path
+                                                        stroke-linecap="round"
+                                                        stroke-linejoin="round"
+                                                        stroke-width="2"
+                                                        d="M13 10V3L4 14h7v7l9-11h-7z"
+                                                    />
+                                                </svg>
+                                                {i18n.t("results_ai_button")}
+                                            </button>
+                                        }
+                                            .into_any()
+                                    } else {
+                                        // Viewer (not owner) — no AI analysis generated yet
+                                        view! {
+                                            <p class="text-gray-500 dark:text-gray-400 italic">
+                                                {i18n.t("results_ai_not_generated")}
+                                            </p>
+                                        }
+                                            .into_any()
                                     }
-                                        .into_any()
-                                } else {
-                                    // Viewer (not owner) — no AI analysis generated yet
-                                    view! {
-                                        <p class="text-gray-500 dark:text-gray-400 italic">
-                                            {i18n.t("results_ai_not_generated")}
-                                        </p>
+                                }}
+                            </div>
+                        </Show>
+
+                        // Optional display name for the shared link (owner only)
+                        <Show when=move || !is_viewer.get()>
+                            <div class="no-print mb-4 max-w-sm">
+                                <label class="block text-sm font-medium text-gray-700 dark:text-gray-300 mb-2">
+                                    {i18n.t("results_nickname_label")}
+                                    <span class="text-gray-400 dark:text-gray-500 font-normal ml-1">
+                                        {i18n.t("results_context_optional")}
+                                    </span>
+                                </label>
+                                <input
+                                    type="text"
+                                    maxlength=MAX_NICKNAME_CHARS.to_string()
+                                    class="w-full px-4 py-2 border border-gray-300 dark:border-gray-600 bg-white dark:bg-gray-700 rounded-lg focus:ring-2 focus:ring-indigo-500 focus:border-indigo-500 text-gray-700 dark:text-gray-200 placeholder:text-gray-400 dark:placeholder:text-gray-500"
+                                    placeholder=i18n.t("results_nickname_placeholder")
+                                    prop:value=move || nickname.get()
+                                    on:input=move |ev| {
+                                        let value = event_target_value(&ev);
+                                        save_nickname(&value);
+                                        set_nickname.set(value);
                                     }
-                                        .into_any()
-                                }
-                            }}
-                        </div>
+                                />
+                            </div>
+                        </Show>
 
                         // Actions
                         <div class="no-print flex flex-wrap gap-4">
-                            // Share / Copy Link button
+                            // Share dialog
                             <button
-                                on:click=share_results
-                                disabled=move || share_saving.get()
-                                class="px-6 py-2 bg-indigo-600 dark:bg-indigo-500 text-white rounded-lg hover:bg-indigo-700 dark:hover:bg-indigo-600 transition-colors flex items-center disabled:opacity-50"
+                                on:click=move |_| share_open.set(true)
+                                class="px-6 py-2 bg-indigo-600 dark:bg-indigo-500 text-white rounded-lg hover:bg-indigo-700 dark:hover:bg-indigo-600 transition-colors flex items-center"
                             >
-                                {move || {
-                                    if link_copied.get() {
-                                        view! {
-                                            <svg class="w-5 h-5 mr-2" fill="none" stroke="currentColor" viewBox="0 0 24 24">
-                                                <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M5 13l4 4L19 7" />
-                                            </svg>
-                                        }.into_any()
-                                    } else {
-                                        view! {
-                                            <svg class="w-5 h-5 mr-2" fill="none" stroke="currentColor" viewBox="0 0 24 24">
-                                                <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M13.828 10.172a4 4 0 00-5.656 0l-4 4a4 4 0 105.656 5.656l1.102-1.101m-.758-4.899a4 4 0 005.656 0l4-4a4 4 0 00-5.656-5.656l-1.1 1.1" />
-                                            </svg>
-                                        }.into_any()
-                                    }
-                                }}
-                                {move || {
-                                    if link_copied.get() {
-                                        i18n.t("results_link_copied")
-                                    } else if share_saving.get() {
-                                        i18n.t("results_share_saving")
-                                    } else {
-                                        i18n.t("results_copy_link")
-                                    }
-                                }}
+                                <svg class="w-5 h-5 mr-2" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+                                    <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M13.828 10.172a4 4 0 00-5.656 0l-4 4a4 4 0 105.656 5.656l1.102-1.101m-.758-4.899a4 4 0 005.656 0l4-4a4 4 0 00-5.656-5.656l-1.1 1.1" />
+                                </svg>
+                                {i18n.t("results_share")}
                             </button>
                             // Export as PDF
                             <button
@@ -956,19 +2265,185 @@ pub fn ResultsPage() -> impl IntoView {
                                 </svg>
                                 {i18n.t("results_export_pdf")}
                             </button>
+                            // Export a saved result as a standalone HTML file
+                            <Show when=move || {
+                                params.get().get("id").is_some() && deleted_until.get().is_none()
+                            }>
+                                <form
+                                    method="post"
+                                    action=move || {
+                                        format!(
+                                            "/api/v1/results/{}/export.html",
+                                            params.get().get("id").unwrap_or_default(),
+                                        )
+                                    }
+                                >
+                                    <input
+                                        type="hidden"
+                                        name="key"
+                                        value=move || link_key().or_else(|| owner_token.get()).unwrap_or_default()
+                                    />
+                                    <button
+                                        type="submit"
+                                        class="px-6 py-2 border border-gray-300 dark:border-gray-600 text-gray-700 dark:text-gray-300 rounded-lg hover:bg-gray-50 dark:hover:bg-gray-700 transition-colors flex items-center"
+                                    >
+                                        <svg class="w-5 h-5 mr-2" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+                                            <path
+                                                stroke-linecap="round"
+                                                stroke-linejoin="round"
+                                                stroke-width="2"
+                                                d="M4 16v1a3 3 0 003 3h10a3 3 0 003-3v-1m-4-4l-4 4m0 0l-4-4m4 4V4"
+                                            />
+                                        </svg>
+                                        {i18n.t("results_export_html")}
+                                    </button>
+                                </form>
+                            </Show>
                             <A
                                 href=move || format!("{}/test", i18n.get_locale().path_prefix())
                                 attr:class="px-6 py-2 border border-gray-300 dark:border-gray-600 text-gray-700 dark:text-gray-300 rounded-lg hover:bg-gray-50 dark:hover:bg-gray-700 transition-colors"
                             >
                                 {i18n.t("results_retake")}
                             </A>
+                            <Show when=move || !is_viewer.get()>
+                                <A
+                                    href=move || format!("{}/import", i18n.get_locale().path_prefix())
+                                    attr:class="px-6 py-2 border border-gray-300 dark:border-gray-600 text-gray-700 dark:text-gray-300 rounded-lg hover:bg-gray-50 dark:hover:bg-gray-700 transition-colors"
+                                >
+                                    {i18n.t("results_import")}
+                                </A>
+                            </Show>
                             <A
                                 href=move || i18n.get_locale().path_prefix().to_string()
                                 attr:class="px-6 py-2 border border-gray-300 dark:border-gray-600 text-gray-700 dark:text-gray-300 rounded-lg hover:bg-gray-50 dark:hover:bg-gray-700 transition-colors"
                             >
                                 {i18n.t("results_home")}
                             </A>
+                            <Show when=move || {
+                                owner_token.get().is_some() && !confirm_delete.get() && deleted_until.get().is_none()
+                            }>
+                                <button
+                                    on:click=move |_| set_confirm_delete.set(true)
+                                    class="px-6 py-2 border border-red-300 dark:border-red-700 text-red-600 dark:text-red-400 rounded-lg hover:bg-red-50 dark:hover:bg-red-900/30 transition-colors"
+                                >
+                                    {i18n.t("results_delete")}
+                                </button>
+                            </Show>
                         </div>
+
+                        // Owners of a saved result can schedule a retest
+                        <Show when=move || owner_token.get().is_some() && deleted_until.get().is_none()>
+                            <RetestReminder id=Signal::derive(move || {
+                                params.get().get("id").unwrap_or_default()
+                            }) />
+                        </Show>
+
+                        <ShareDialog
+                            open=share_open
+                            is_viewer=is_viewer
+                            profile=profile
+                            user_context=user_context
+                            ai_description=ai_description
+                            prompt_version=prompt_version
+                            analysis_consented_at=analysis_consented_at
+                            nickname=nickname
+                        />
+
+                        <AiConsentDialog
+                            open=consent_open
+                            model_name=Signal::derive(move || {
+                                let id = selected_model.get()?;
+                                models_resource
+                                    .get()
+                                    .flatten()?
+                                    .into_iter()
+                                    .find(|m| m.id == id)
+                                    .map(|m| m.display_name)
+                            })
+                            has_context=Signal::derive(move || !user_context.get().trim().is_empty())
+                            on_confirm=move |_| start_ai()
+                        />
+
+                        // Deleted: the link is dead until undone
+                        <Show when=move || deleted_until.get().is_some()>
+                            <div class="no-print mt-6 bg-amber-50 dark:bg-amber-900/30 border border-amber-200 dark:border-amber-800 rounded-lg p-4">
+                                <p class="font-medium text-amber-800 dark:text-amber-200">
+                                    {i18n.t("results_deleted_title")}
+                                </p>
+                                <p class="text-sm text-amber-700 dark:text-amber-300 mt-1 mb-4">
+                                    {move || {
+                                        let until = deleted_until.get().unwrap_or_default();
+                                        format!(
+                                            "{} {}.",
+                                            i18n.t("results_deleted_undo_until"),
+                                            format_date(until as f64 * 1000.0, i18n.get_locale().code()),
+                                        )
+                                    }}
+                                </p>
+                                <Show when=move || restore_error.get()>
+                                    <p class="text-sm font-medium text-red-700 dark:text-red-300 mb-4">
+                                        {i18n.t("results_restore_error")}
+                                    </p>
+                                </Show>
+                                <div class="flex flex-wrap gap-3">
+                                    <button
+                                        on:click=restore_result
+                                        disabled=move || restoring.get()
+                                        class="px-4 py-2 bg-indigo-600 dark:bg-indigo-500 text-white rounded-lg hover:bg-indigo-700 dark:hover:bg-indigo-600 transition-colors disabled:opacity-50"
+                                    >
+                                        {i18n.t("results_restore")}
+                                    </button>
+                                    <A
+                                        href=move || i18n.get_locale().path_prefix().to_string()
+                                        attr:class="px-4 py-2 border border-gray-300 dark:border-gray-600 text-gray-700 dark:text-gray-300 rounded-lg hover:bg-gray-50 dark:hover:bg-gray-700 transition-colors"
+                                    >
+                                        {i18n.t("results_home")}
+                                    </A>
+                                </div>
+                            </div>
+                        </Show>
+
+                        // Delete confirmation (owner only)
+                        <Show when=move || owner_token.get().is_some() && confirm_delete.get()>
+                            <div class="no-print mt-6 bg-red-50 dark:bg-red-900/30 border border-red-200 dark:border-red-800 rounded-lg p-4">
+                                <p class="font-medium text-red-700 dark:text-red-300">
+                                    {i18n.t("results_delete_confirm_title")}
+                                </p>
+                                <p class="text-sm text-red-600 dark:text-red-400 mt-1 mb-4">
+                                    {i18n.t("results_delete_confirm_text")}
+                                </p>
+                                <Show when=move || delete_error.get()>
+                                    <p class="text-sm font-medium text-red-700 dark:text-red-300 mb-4">
+                                        {i18n.t("results_delete_error")}
+                                    </p>
+                                </Show>
+                                <div class="flex flex-wrap gap-3">
+                                    <button
+                                        on:click=delete_result
+                                        disabled=move || deleting.get()
+                                        class="px-4 py-2 bg-red-600 dark:bg-red-500 text-white rounded-lg hover:bg-red-700 dark:hover:bg-red-600 transition-colors disabled:opacity-50"
+                                    >
+                                        {move || {
+                                            if deleting.get() {
+                                                i18n.t("results_deleting")
+                                            } else {
+                                                i18n.t("results_delete_confirm_button")
+                                            }
+                                        }}
+                                    </button>
+                                    <button
+                                        on:click=move |_| {
+                                            set_confirm_delete.set(false);
+                                            set_delete_error.set(false);
+                                        }
+                                        disabled=move || deleting.get()
+                                        class="px-4 py-2 border border-gray-300 dark:border-gray-600 text-gray-700 dark:text-gray-300 rounded-lg hover:bg-gray-50 dark:hover:bg-gray-700 transition-colors disabled:opacity-50"
+                                    >
+                                        {i18n.t("results_delete_cancel")}
+                                    </button>
+                                </div>
+                            </div>
+                        </Show>
                     </div>
                 }
                     .into_any()
@@ -977,7 +2452,34 @@ pub fn ResultsPage() -> impl IntoView {
     }
 }
 
-fn load_profile() -> Option<PersonalityProfile> {
+/// Scroll to the analysis section named in the URL fragment, if any.
+#[cfg(target_arch = "wasm32")]
+fn scroll_to_analysis_section() {
+    let Some(hash) = web_sys::window().and_then(|w| w.location().hash().ok()) else {
+        return;
+    };
+    let Some(id) = hash
+        .strip_prefix('#')
+        .filter(|id| id.starts_with(ANALYSIS_ID_PREFIX))
+    else {
+        return;
+    };
+    // Non-ASCII heading ids arrive percent-encoded
+    let id = js_sys::decode_uri_component(id)
+        .map(String::from)
+        .unwrap_or_else(|_| id.to_string());
+    if let Some(element) = document().get_element_by_id(&id) {
+        element.scroll_into_view();
+    }
+}
+
+/// Whether the page is in a background tab or minimized window (Page Visibility API).
+#[cfg(target_arch = "wasm32")]
+fn page_hidden() -> bool {
+    document().hidden()
+}
+
+pub(super) fn load_profile() -> Option<PersonalityProfile> {
     #[cfg(target_arch = "wasm32")]
     {
         let window = web_sys::window()?;
@@ -1018,3 +2520,122 @@ fn save_context(context: &str) {
         let _ = context;
     }
 }
+
+fn load_nickname() -> Option<String> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        let window = web_sys::window()?;
+        let storage = window.local_storage().ok()??;
+        storage.get_item(STORAGE_KEY_NICKNAME).ok()?
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        None
+    }
+}
+
+fn save_nickname(nickname: &str) {
+    #[cfg(target_arch = "wasm32")]
+    {
+        if let Some(window) = web_sys::window()
+            && let Ok(Some(storage)) = window.local_storage()
+        {
+            let _ = storage.set_item(STORAGE_KEY_NICKNAME, nickname);
+        }
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let _ = nickname;
+    }
+}
+
+fn load_owner_tokens() -> std::collections::HashMap<String, String> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        web_sys::window()
+            .and_then(|w| w.local_storage().ok().flatten())
+            .and_then(|s| s.get_item(STORAGE_KEY_OWNER_TOKENS).ok().flatten())
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default()
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        std::collections::HashMap::new()
+    }
+}
+
+fn store_owner_tokens(tokens: &std::collections::HashMap<String, String>) {
+    #[cfg(target_arch = "wasm32")]
+    {
+        if let Some(window) = web_sys::window()
+            && let Ok(Some(storage)) = window.local_storage()
+            && let Ok(json) = serde_json::to_string(tokens)
+        {
+            let _ = storage.set_item(STORAGE_KEY_OWNER_TOKENS, &json);
+        }
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        let _ = tokens;
+    }
+}
+
+/// Access key from the `#key=...` fragment of a protected result link.
+fn link_key() -> Option<String> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        let hash = web_sys::window()?.location().hash().ok()?;
+        hash.strip_prefix("#key=")
+            .filter(|key| !key.is_empty())
+            .map(str::to_string)
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        None
+    }
+}
+
+/// Profile encoded in a `#p=` fragment, if the link has one.
+fn fragment_profile() -> Option<PersonalityProfile> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        let hash = web_sys::window()?.location().hash().ok()?;
+        let encoded = hash.strip_prefix("#p=")?;
+        PersonalityProfile::from_compact_string(encoded).ok()
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        None
+    }
+}
+
+pub(super) fn load_owner_token(id: &str) -> Option<String> {
+    load_owner_tokens().remove(id)
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), allow(dead_code))]
+pub(super) fn save_owner_token(id: &str, token: &str) {
+    let mut tokens = load_owner_tokens();
+    tokens.insert(id.to_string(), token.to_string());
+    store_owner_tokens(&tokens);
+}
+
+fn remove_owner_token(id: &str) {
+    let mut tokens = load_owner_tokens();
+    if tokens.remove(id).is_some() {
+        store_owner_tokens(&tokens);
+    }
+}
+
+/// Remove the locally stored profile and context.
+fn clear_local_results() {
+    #[cfg(target_arch = "wasm32")]
+    {
+        if let Some(window) = web_sys::window()
+            && let Ok(Some(storage)) = window.local_storage()
+        {
+            let _ = storage.remove_item(STORAGE_KEY_PROFILE);
+            let _ = storage.remove_item(STORAGE_KEY_CONTEXT);
+        }
+    }
+}
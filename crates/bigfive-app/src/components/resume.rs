@@ -0,0 +1,409 @@
+//! Saving test progress on the server and resuming it on another device.
+//!
+//! Progress is stored under a short resume code (e.g. `K7QM-2XHP`) that the user can type in
+//! on the home page or open as a `/test?resume=CODE` link. Without the server, the answers
+//! can also travel in a `/test?progress=...` link shown as a QR code, which the other
+//! device scans.
+
+use std::collections::HashMap;
+
+use bigfive::{Answer, TestProgress};
+use leptos::prelude::*;
+#[cfg(target_arch = "wasm32")]
+use leptos::task::spawn_local;
+use leptos_router::hooks::use_navigate;
+use serde::{Deserialize, Serialize};
+
+use crate::components::share_dialog::qr_svg;
+use crate::i18n::use_i18n;
+
+/// Number of characters in a resume code (without the separator).
+pub const RESUME_CODE_LEN: usize = 8;
+
+/// Characters used in resume codes; omits I, O, 0 and 1, which are easily confused.
+#[cfg(feature = "ssr")]
+const RESUME_CODE_ALPHABET: &[u8; 32] = b"ABCDEFGHJKLMNPQRSTUVWXYZ23456789";
+
+/// How long saved progress is kept.
+#[cfg(feature = "ssr")]
+const SESSION_TTL_DAYS: i64 = 30;
+
+/// Test progress restored from a resume code.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SavedProgress {
+    pub answers: HashMap<String, u8>,
+    pub current_index: usize,
+    pub lang: String,
+}
+
+/// Normalize user input to a bare resume code: uppercase, without spaces or dashes.
+///
+/// Returns `None` if the result can't be a valid code.
+pub fn normalize_resume_code(input: &str) -> Option<String> {
+    let code: String = input
+        .chars()
+        .filter(|c| !c.is_whitespace() && *c != '-')
+        .map(|c| c.to_ascii_uppercase())
+        .collect();
+    (code.len() == RESUME_CODE_LEN && code.chars().all(|c| c.is_ascii_alphanumeric()))
+        .then_some(code)
+}
+
+/// Format a resume code for display, e.g. `K7QM2XHP` → `K7QM-2XHP`.
+pub fn format_resume_code(code: &str) -> String {
+    let (head, tail) = code.split_at(code.len() / 2);
+    format!("{}-{}", head, tail)
+}
+
+/// Query parameter of `/test` links carrying the progress itself.
+pub const PROGRESS_PARAM: &str = "progress";
+
+/// In-progress answers as compact text for a `/test?progress=` link, using the inventory
+/// of `lang`.
+pub fn progress_code(
+    answers: &HashMap<String, u8>,
+    current_index: usize,
+    lang: &str,
+) -> Option<String> {
+    let inventory = crate::inventory::inventory(lang)?;
+    let progress = TestProgress {
+        answers: answers
+            .iter()
+            .map(|(id, &value)| Answer {
+                question_id: id.clone(),
+                value,
+            })
+            .collect(),
+        current_index,
+    };
+    Some(progress.to_compact_string(&inventory))
+}
+
+/// Progress from a [`progress_code`] made for `lang`, or `None` if it can't be read.
+pub fn parse_progress_code(code: &str, lang: &str) -> Option<SavedProgress> {
+    let inventory = crate::inventory::inventory(lang)?;
+    let progress = TestProgress::from_compact_string(&inventory, code.trim()).ok()?;
+    Some(SavedProgress {
+        answers: progress
+            .answers
+            .into_iter()
+            .map(|a| (a.question_id, a.value))
+            .collect(),
+        current_index: progress.current_index,
+        lang: lang.to_string(),
+    })
+}
+
+/// Generate a random resume code.
+#[cfg(feature = "ssr")]
+fn generate_resume_code() -> String {
+    // 256 is a multiple of 32, so every character is equally likely
+    uuid::Uuid::new_v4().as_bytes()[..RESUME_CODE_LEN]
+        .iter()
+        .map(|b| RESUME_CODE_ALPHABET[(*b % 32) as usize] as char)
+        .collect()
+}
+
+/// Save in-progress answers on the server, returns the resume code.
+#[server]
+pub async fn save_progress(
+    answers: HashMap<String, u8>,
+    current_index: usize,
+    lang: String,
+) -> Result<String, ServerFnError> {
+    use crate::db;
+    use crate::i18n::Locale;
+
+    let questions = crate::inventory::reference();
+    let valid_ids: std::collections::HashSet<&str> = questions
+        .questions()
+        .iter()
+        .map(|q| q.id.as_str())
+        .collect();
+    if answers.len() > valid_ids.len() || current_index >= valid_ids.len() {
+        return Err(ServerFnError::new("Invalid test progress"));
+    }
+    if answers
+        .iter()
+        .any(|(id, value)| !valid_ids.contains(id.as_str()) || !(1..=5).contains(value))
+    {
+        return Err(ServerFnError::new("Invalid answers"));
+    }
+    let lang = Locale::ALL
+        .iter()
+        .copied()
+        .find(|l| l.code() == lang)
+        .unwrap_or_default()
+        .code()
+        .to_string();
+
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map_err(|e| ServerFnError::new(e.to_string()))?
+        .as_secs() as i64;
+    let expires_at = now + SESSION_TTL_DAYS * 24 * 60 * 60;
+    let session = db::SavedSession {
+        answers,
+        current_index,
+        lang,
+    };
+
+    // Codes are short, so retry on the (unlikely) collision with an existing one
+    for _ in 0..5 {
+        let code = generate_resume_code();
+        if db::save_session(
+            &code,
+            &session,
+            crate::tenancy::current_namespace(),
+            expires_at,
+        )
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?
+        {
+            tracing::info!(answered = session.answers.len(), "Saved test progress");
+            return Ok(code);
+        }
+    }
+    Err(ServerFnError::new("Failed to allocate a resume code"))
+}
+
+/// Load progress saved under a resume code. Returns `None` for unknown or expired codes.
+#[server]
+pub async fn load_progress(code: String) -> Result<Option<SavedProgress>, ServerFnError> {
+    use crate::db;
+
+    let Some(code) = normalize_resume_code(&code) else {
+        return Ok(None);
+    };
+    let session = db::get_session(&code, crate::tenancy::current_namespace())
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+    Ok(session.map(|s| SavedProgress {
+        answers: s.answers,
+        current_index: s.current_index,
+        lang: s.lang,
+    }))
+}
+
+/// "Save & continue later" button with the resulting resume code and link.
+#[component]
+#[cfg_attr(not(target_arch = "wasm32"), allow(unused_variables))]
+pub fn SaveProgress(
+    #[prop(into)] answers: Signal<HashMap<String, u8>>,
+    #[prop(into)] current_index: Signal<usize>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+
+    let code = RwSignal::new(None::<String>);
+    let saving = RwSignal::new(false);
+    let save_error = RwSignal::new(false);
+    let copied = RwSignal::new(false);
+    let show_qr = RwSignal::new(false);
+
+    // A saved code is a snapshot; answering more questions makes it stale
+    Effect::new(move |prev: Option<()>| {
+        answers.track();
+        if prev.is_some() {
+            code.set(None);
+        }
+    });
+
+    let test_url = move |query: String| {
+        #[cfg(target_arch = "wasm32")]
+        let origin = web_sys::window()
+            .and_then(|w| w.location().origin().ok())
+            .unwrap_or_default();
+        #[cfg(not(target_arch = "wasm32"))]
+        let origin = String::new();
+        format!(
+            "{}{}/test?{}",
+            origin,
+            i18n.get_locale().path_prefix(),
+            query
+        )
+    };
+    let resume_url = move |code: &str| test_url(format!("resume={code}"));
+
+    // Follows the answers, so the code always carries the latest ones
+    let progress_qr = move || {
+        let code = progress_code(
+            &answers.get(),
+            current_index.get(),
+            i18n.get_locale().code(),
+        )?;
+        qr_svg(&test_url(format!("{PROGRESS_PARAM}={code}")))
+    };
+
+    let save = move |_| {
+        #[cfg(target_arch = "wasm32")]
+        {
+            let ans = answers.get_untracked();
+            let idx = current_index.get_untracked();
+            let lang = i18n.get_locale().code().to_string();
+            saving.set(true);
+            save_error.set(false);
+            spawn_local(async move {
+                match save_progress(ans, idx, lang).await {
+                    Ok(saved) => code.set(Some(saved)),
+                    Err(e) => {
+                        web_sys::console::log_1(&format!("Failed to save progress: {}", e).into());
+                        save_error.set(true);
+                    }
+                }
+                saving.set(false);
+            });
+        }
+    };
+
+    let copy_link = move |_| {
+        let Some(c) = code.get() else { return };
+        super::share_dialog::copy_to_clipboard(&resume_url(&c));
+        copied.set(true);
+        #[cfg(target_arch = "wasm32")]
+        spawn_local(async move {
+            gloo_timers::future::TimeoutFuture::new(2000).await;
+            copied.set(false);
+        });
+    };
+
+    view! {
+        <div class="mt-6 text-center">
+            {move || match code.get() {
+                Some(c) => {
+                    let url = resume_url(&c);
+                    view! {
+                        <div class="bg-white dark:bg-gray-800 rounded-lg shadow-md p-4 text-left transition-colors duration-300">
+                            <p class="text-sm text-gray-600 dark:text-gray-300 mb-2">{move || i18n.t("resume_saved")}</p>
+                            <p class="text-2xl font-mono font-bold tracking-widest text-gray-900 dark:text-white mb-3">
+                                {format_resume_code(&c)}
+                            </p>
+                            <div class="flex gap-2">
+                                <input
+                                    type="text"
+                                    readonly
+                                    aria-label=move || i18n.t("resume_link_label")
+                                    value=url
+                                    class="flex-1 min-w-0 px-3 py-2 text-sm font-mono border border-gray-300 dark:border-gray-600 bg-gray-50 dark:bg-gray-900 rounded-lg text-gray-700 dark:text-gray-200"
+                                />
+                                <button
+                                    type="button"
+                                    on:click=copy_link
+                                    class="px-4 py-2 text-sm bg-indigo-600 dark:bg-indigo-500 text-white rounded-lg hover:bg-indigo-700 dark:hover:bg-indigo-600 transition-colors"
+                                >
+                                    {move || {
+                                        if copied.get() { i18n.t("results_link_copied") } else { i18n.t("results_copy_link") }
+                                    }}
+                                </button>
+                            </div>
+                            <p class="mt-2 text-xs text-gray-500 dark:text-gray-400">{move || i18n.t("resume_expiry_note")}</p>
+                        </div>
+                    }
+                        .into_any()
+                }
+                None => {
+                    view! {
+                        <button
+                            type="button"
+                            on:click=save
+                            prop:disabled=move || saving.get() || answers.get().is_empty()
+                            class="text-sm text-indigo-600 dark:text-indigo-400 hover:underline disabled:opacity-50 disabled:cursor-not-allowed disabled:no-underline"
+                        >
+                            {move || if saving.get() { i18n.t("resume_saving") } else { i18n.t("resume_save_button") }}
+                        </button>
+                    }
+                        .into_any()
+                }
+            }}
+            <Show when=move || save_error.get()>
+                <p class="mt-2 text-sm text-red-600 dark:text-red-400">{move || i18n.t("resume_save_error")}</p>
+            </Show>
+            <button
+                type="button"
+                on:click=move |_| show_qr.update(|shown| *shown = !*shown)
+                prop:disabled=move || answers.get().is_empty()
+                class="mt-2 text-sm text-indigo-600 dark:text-indigo-400 hover:underline disabled:opacity-50 disabled:cursor-not-allowed disabled:no-underline"
+            >
+                {move || i18n.t(if show_qr.get() { "progress_qr_hide" } else { "progress_qr_button" })}
+            </button>
+            <Show when=move || show_qr.get()>
+                {move || {
+                    progress_qr()
+                        .map(|svg| {
+                            view! {
+                                <div class="mt-3 flex flex-col items-center">
+                                    <div
+                                        class="w-48 h-48 p-2 bg-white rounded-lg [&>svg]:w-full [&>svg]:h-full"
+                                        role="img"
+                                        aria-label=move || i18n.t("progress_qr_label")
+                                        inner_html=svg
+                                    />
+                                    <p class="mt-2 max-w-xs text-xs text-gray-500 dark:text-gray-400">
+                                        {move || i18n.t("progress_qr_hint")}
+                                    </p>
+                                </div>
+                            }
+                        })
+                }}
+            </Show>
+        </div>
+    }
+}
+
+/// Home page form for entering a resume code.
+#[component]
+pub fn ResumeForm() -> impl IntoView {
+    let i18n = use_i18n();
+    let navigate = StoredValue::new_local(use_navigate());
+
+    let input = RwSignal::new(String::new());
+    let invalid = RwSignal::new(false);
+
+    let submit = move |ev: leptos::ev::SubmitEvent| {
+        ev.prevent_default();
+        match normalize_resume_code(&input.get()) {
+            Some(code) => {
+                invalid.set(false);
+                navigate.with_value(|nav| {
+                    nav(
+                        &format!("{}/test?resume={}", i18n.get_locale().path_prefix(), code),
+                        Default::default(),
+                    )
+                });
+            }
+            None => invalid.set(true),
+        }
+    };
+
+    view! {
+        <form on:submit=submit class="mt-6 pt-6 border-t border-gray-200 dark:border-gray-700">
+            <label for="resume-code" class="block text-sm font-medium text-gray-700 dark:text-gray-300 mb-2">
+                {move || i18n.t("resume_enter_label")}
+            </label>
+            <div class="flex gap-2">
+                <input
+                    id="resume-code"
+                    type="text"
+                    autocomplete="off"
+                    spellcheck="false"
+                    maxlength="12"
+                    placeholder="ABCD-EFGH"
+                    prop:value=move || input.get()
+                    on:input=move |ev| {
+                        input.set(event_target_value(&ev));
+                        invalid.set(false);
+                    }
+                    class="flex-1 min-w-0 sm:max-w-xs px-3 py-2 font-mono uppercase border border-gray-300 dark:border-gray-600 bg-white dark:bg-gray-700 rounded-lg focus:ring-2 focus:ring-indigo-500 focus:border-indigo-500 text-gray-700 dark:text-gray-200"
+                />
+                <button
+                    type="submit"
+                    class="px-4 py-2 rounded-lg border border-gray-300 dark:border-gray-600 text-gray-700 dark:text-gray-300 font-medium hover:bg-gray-50 dark:hover:bg-gray-700 transition-colors"
+                >
+                    {move || i18n.t("resume_continue_button")}
+                </button>
+            </div>
+            <Show when=move || invalid.get()>
+                <p class="mt-2 text-sm text-red-600 dark:text-red-400">{move || i18n.t("resume_invalid_code")}</p>
+            </Show>
+        </form>
+    }
+}
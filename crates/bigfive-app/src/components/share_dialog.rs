@@ -0,0 +1,545 @@
+//! Share dialog: share URL, visibility and expiry settings, QR code and copy button.
+
+use bigfive::PersonalityProfile;
+use leptos::prelude::*;
+#[cfg(target_arch = "wasm32")]
+use leptos::task::spawn_local;
+
+use crate::i18n::use_i18n;
+
+/// Fingerprint and id of the last snapshot created from this browser (shared by all tabs).
+#[cfg(target_arch = "wasm32")]
+const STORAGE_KEY_LAST_SHARE: &str = crate::storage::storage_key!("last_share");
+
+/// Link lifetimes (in days) offered in the expiry selector, besides "never".
+pub const SHARE_EXPIRY_DAYS: &[u32] = &[1, 7, 30];
+
+/// What people opening the shared link can see.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum ShareVisibility {
+    /// Scores, the AI analysis and the user's own notes.
+    #[default]
+    Full,
+    /// Only the domain and facet scores.
+    ScoresOnly,
+}
+
+/// Modal for sharing results.
+///
+/// Owners pick what is visible and when the link expires, then create a snapshot;
+/// viewers of an existing shared link just get the current URL.
+#[cfg_attr(not(target_arch = "wasm32"), allow(unused_variables))]
+#[component]
+pub fn ShareDialog(
+    open: RwSignal<bool>,
+    #[prop(into)] is_viewer: Signal<bool>,
+    #[prop(into)] profile: Signal<Option<PersonalityProfile>>,
+    #[prop(into)] user_context: Signal<String>,
+    #[prop(into)] ai_description: Signal<Option<String>>,
+    #[prop(into)] prompt_version: Signal<Option<String>>,
+    #[prop(into)] analysis_consented_at: Signal<Option<i64>>,
+    #[prop(into)] nickname: Signal<String>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+
+    let visibility = RwSignal::new(ShareVisibility::default());
+    let expiry_days = RwSignal::new(None::<u32>);
+    let protected = RwSignal::new(false);
+    let share_url = RwSignal::new(None::<String>);
+    let saving = RwSignal::new(false);
+    let save_error = RwSignal::new(false);
+    let copied = RwSignal::new(false);
+    // Request fingerprint and idempotency key of a save that hasn't succeeded yet
+    let pending_save = StoredValue::new(None::<(String, String)>);
+
+    // Viewers share the page they're on; owners get a fresh snapshot each time the dialog opens
+    Effect::new(move |_| {
+        if !open.get() {
+            return;
+        }
+        copied.set(false);
+        save_error.set(false);
+        share_url.set(if is_viewer.get_untracked() {
+            current_url()
+        } else {
+            None
+        });
+    });
+
+    // Settings only apply to newly created links
+    let set_visibility = move |value| {
+        visibility.set(value);
+        share_url.set(None);
+    };
+    let set_expiry = move |value| {
+        expiry_days.set(value);
+        share_url.set(None);
+    };
+    let set_protected = move |value| {
+        protected.set(value);
+        share_url.set(None);
+    };
+
+    #[allow(unused_variables)]
+    let create_link = move |_| {
+        #[cfg(target_arch = "wasm32")]
+        {
+            use super::results::{
+                SaveAttempt, SavedResultHandle, ShareOptions, SharedAnalysis, get_save_challenge,
+                load_owner_token, save_owner_token, save_results,
+            };
+            use crate::components::demographics::load_demographics;
+            use crate::components::history::record_history;
+
+            let Some(prof) = profile.get() else { return };
+            let locale = i18n.get_locale();
+            let (ctx, analysis) = match visibility.get() {
+                ShareVisibility::Full => {
+                    let c = user_context.get();
+                    (
+                        if c.trim().is_empty() { None } else { Some(c) },
+                        ai_description.get(),
+                    )
+                }
+                ShareVisibility::ScoresOnly => (None, None),
+            };
+            let name = {
+                let n = nickname.get();
+                if n.trim().is_empty() { None } else { Some(n) }
+            };
+            let expires = expiry_days.get();
+            // Scores are always readable, so protection only matters for the full view
+            let protect = protected.get() && visibility.get() == ShareVisibility::Full;
+            let result_url = move |id: &str, key: Option<&str>| {
+                let origin = web_sys::window()
+                    .and_then(|w| w.location().origin().ok())
+                    .unwrap_or_default();
+                let fragment = key.map(|k| format!("#key={}", k)).unwrap_or_default();
+                format!(
+                    "{}{}/results/{}{}",
+                    origin,
+                    locale.path_prefix(),
+                    id,
+                    fragment
+                )
+            };
+
+            // Reuse an identical, still-owned snapshot (e.g. created from another tab)
+            // instead of inserting a duplicate row. Expiring and protected links are always fresh.
+            let fingerprint = snapshot_fingerprint(&prof, &ctx, &analysis, &name, locale.code());
+            if expires.is_none()
+                && !protect
+                && let Some(id) = load_last_share(&fingerprint)
+                && load_owner_token(&id).is_some()
+            {
+                share_url.set(Some(result_url(&id, None)));
+                return;
+            }
+
+            // Retrying the same request reuses its key, so a save whose response got lost
+            // isn't stored twice
+            let request = format!("{fingerprint}:{expires:?}:{protect}");
+            let idempotency_key = match pending_save.get_value() {
+                Some((pending, key)) if pending == request => key,
+                _ => {
+                    let key = new_idempotency_key();
+                    pending_save.set_value(Some((request, key.clone())));
+                    key
+                }
+            };
+
+            saving.set(true);
+            save_error.set(false);
+            spawn_local(async move {
+                let history_profile = prof.clone();
+                let saved = match get_save_challenge().await {
+                    Ok(challenge) => {
+                        let proof = match challenge {
+                            Some(challenge) => Some(crate::pow::solve(challenge).await),
+                            None => None,
+                        };
+                        save_results(
+                            prof,
+                            load_demographics(),
+                            ctx,
+                            analysis.map(|text| SharedAnalysis {
+                                text,
+                                prompt_version: prompt_version.get_untracked(),
+                                consented_at: analysis_consented_at.get_untracked(),
+                            }),
+                            locale.code().to_string(),
+                            ShareOptions {
+                                nickname: name,
+                                expires_in_days: expires,
+                                protected: protect,
+                            },
+                            SaveAttempt {
+                                idempotency_key: Some(idempotency_key),
+                                proof,
+                            },
+                        )
+                        .await
+                    }
+                    Err(e) => Err(e),
+                };
+                match saved {
+                    Ok(SavedResultHandle {
+                        id,
+                        owner_token,
+                        access_token,
+                    }) => {
+                        pending_save.set_value(None);
+                        save_owner_token(&id, &owner_token);
+                        record_history(&id, locale.code(), &history_profile, expires);
+                        if expires.is_none() && access_token.is_none() {
+                            save_last_share(&fingerprint, &id);
+                        }
+                        share_url.set(Some(result_url(&id, access_token.as_deref())));
+                    }
+                    Err(e) => {
+                        web_sys::console::log_1(&format!("Failed to save results: {}", e).into());
+                        save_error.set(true);
+                    }
+                }
+                saving.set(false);
+            });
+        }
+    };
+
+    // Scores only, carried in the URL fragment; nothing is stored on the server
+    let create_fragment_link = move |_| {
+        let Some(prof) = profile.get() else { return };
+        let origin = web_sys::window()
+            .and_then(|w| w.location().origin().ok())
+            .unwrap_or_default();
+        share_url.set(Some(format!(
+            "{}{}/results#p={}",
+            origin,
+            i18n.get_locale().path_prefix(),
+            prof.to_compact_string()
+        )));
+    };
+
+    let copy_link = move |_| {
+        let Some(url) = share_url.get() else { return };
+        copy_to_clipboard(&url);
+        copied.set(true);
+        #[cfg(target_arch = "wasm32")]
+        spawn_local(async move {
+            gloo_timers::future::TimeoutFuture::new(2000).await;
+            copied.set(false);
+        });
+    };
+
+    let option_class = move |active: bool| {
+        if active {
+            "flex-1 px-3 py-2 text-sm rounded-lg border-2 border-indigo-500 bg-indigo-50 dark:bg-indigo-900/30 text-indigo-700 dark:text-indigo-300 transition-colors"
+        } else {
+            "flex-1 px-3 py-2 text-sm rounded-lg border-2 border-gray-200 dark:border-gray-600 text-gray-700 dark:text-gray-300 hover:border-indigo-300 dark:hover:border-indigo-400 transition-colors"
+        }
+    };
+
+    view! {
+        <Show when=move || open.get()>
+            <div
+                class="no-print fixed inset-0 z-50 flex items-center justify-center bg-black/50 px-4"
+                on:click=move |_| open.set(false)
+                on:keydown=move |ev| {
+                    if ev.key() == "Escape" {
+                        open.set(false);
+                    }
+                }
+            >
+                <div
+                    role="dialog"
+                    aria-modal="true"
+                    aria-labelledby="share-dialog-title"
+                    class="w-full max-w-md bg-white dark:bg-gray-800 rounded-lg shadow-xl p-6"
+                    on:click=|ev| ev.stop_propagation()
+                >
+                    <div class="flex items-center justify-between mb-4">
+                        <h2 id="share-dialog-title" class="text-lg font-semibold text-gray-900 dark:text-white">
+                            {move || i18n.t("share_title")}
+                        </h2>
+                        <button
+                            type="button"
+                            aria-label=move || i18n.t("share_close")
+                            on:click=move |_| open.set(false)
+                            class="p-1 rounded-lg text-gray-500 dark:text-gray-400 hover:bg-gray-100 dark:hover:bg-gray-700 transition-colors"
+                        >
+                            <svg class="w-5 h-5" fill="none" stroke="currentColor" viewBox="0 0 24 24">
+                                <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M6 18L18 6M6 6l12 12" />
+                            </svg>
+                        </button>
+                    </div>
+
+                    // Link settings (owner only)
+                    <Show when=move || !is_viewer.get()>
+                        <div class="mb-4">
+                            <p class="text-sm font-medium text-gray-700 dark:text-gray-300 mb-2">
+                                {move || i18n.t("share_visibility_label")}
+                            </p>
+                            <div class="flex gap-2">
+                                <button
+                                    type="button"
+                                    aria-pressed=move || (visibility.get() == ShareVisibility::Full).to_string()
+                                    on:click=move |_| set_visibility(ShareVisibility::Full)
+                                    class=move || option_class(visibility.get() == ShareVisibility::Full)
+                                >
+                                    {move || i18n.t("share_visibility_full")}
+                                </button>
+                                <button
+                                    type="button"
+                                    aria-pressed=move || (visibility.get() == ShareVisibility::ScoresOnly).to_string()
+                                    on:click=move |_| set_visibility(ShareVisibility::ScoresOnly)
+                                    class=move || option_class(visibility.get() == ShareVisibility::ScoresOnly)
+                                >
+                                    {move || i18n.t("share_visibility_scores")}
+                                </button>
+                            </div>
+                            <Show when=move || visibility.get() == ShareVisibility::Full>
+                                <label class="mt-3 flex items-start gap-2 text-sm text-gray-700 dark:text-gray-300">
+                                    <input
+                                        type="checkbox"
+                                        class="mt-0.5 rounded border-gray-300 dark:border-gray-600 text-indigo-600 focus:ring-indigo-500"
+                                        prop:checked=move || protected.get()
+                                        on:change=move |ev| set_protected(event_target_checked(&ev))
+                                    />
+                                    <span>
+                                        {move || i18n.t("share_protected_label")}
+                                        <span class="block text-xs text-gray-500 dark:text-gray-400">
+                                            {move || i18n.t("share_protected_hint")}
+                                        </span>
+                                    </span>
+                                </label>
+                            </Show>
+                        </div>
+                        <div class="mb-4">
+                            <label
+                                for="share-expiry"
+                                class="block text-sm font-medium text-gray-700 dark:text-gray-300 mb-2"
+                            >
+                                {move || i18n.t("share_expiry_label")}
+                            </label>
+                            <select
+                                id="share-expiry"
+                                class="w-full px-3 py-2 border border-gray-300 dark:border-gray-600 bg-white dark:bg-gray-700 rounded-lg focus:ring-2 focus:ring-indigo-500 focus:border-indigo-500 text-gray-700 dark:text-gray-200"
+                                on:change=move |ev| set_expiry(event_target_value(&ev).parse::<u32>().ok())
+                            >
+                                <option value="" selected=move || expiry_days.get().is_none()>
+                                    {move || i18n.t("share_expiry_never")}
+                                </option>
+                                {SHARE_EXPIRY_DAYS
+                                    .iter()
+                                    .map(|&days| {
+                                        view! {
+                                            <option
+                                                value=days.to_string()
+                                                selected=move || expiry_days.get() == Some(days)
+                                            >
+                                                {move || i18n.t(expiry_key(days))}
+                                            </option>
+                                        }
+                                    })
+                                    .collect_view()}
+                            </select>
+                        </div>
+                    </Show>
+
+                    {move || match share_url.get() {
+                        Some(url) => {
+                            let qr = qr_svg(&url);
+                            view! {
+                                <div class="flex gap-2 mb-4">
+                                    <input
+                                        type="text"
+                                        readonly
+                                        aria-label=move || i18n.t("share_title")
+                                        value=url
+                                        on:focus=move |ev| {
+                                            #[cfg(target_arch = "wasm32")]
+                                            {
+                                                use wasm_bindgen::JsCast;
+                                                if let Some(input) = ev
+                                                    .target()
+                                                    .and_then(|t| t.dyn_into::<web_sys::HtmlInputElement>().ok())
+                                                {
+                                                    input.select();
+                                                }
+                                            }
+                                            #[cfg(not(target_arch = "wasm32"))]
+                                            let _ = ev;
+                                        }
+                                        class="flex-1 min-w-0 px-3 py-2 text-sm font-mono border border-gray-300 dark:border-gray-600 bg-gray-50 dark:bg-gray-900 rounded-lg text-gray-700 dark:text-gray-200"
+                                    />
+                                    <button
+                                        type="button"
+                                        on:click=copy_link
+                                        class="px-4 py-2 text-sm bg-indigo-600 dark:bg-indigo-500 text-white rounded-lg hover:bg-indigo-700 dark:hover:bg-indigo-600 transition-colors"
+                                    >
+                                        {move || {
+                                            if copied.get() {
+                                                i18n.t("results_link_copied")
+                                            } else {
+                                                i18n.t("results_copy_link")
+                                            }
+                                        }}
+                                    </button>
+                                </div>
+                                {qr
+                                    .map(|svg| {
+                                        view! {
+                                            <div class="flex flex-col items-center">
+                                                <div
+                                                    class="w-48 h-48 p-2 bg-white rounded-lg [&>svg]:w-full [&>svg]:h-full"
+                                                    role="img"
+                                                    aria-label=move || i18n.t("share_qr_label")
+                                                    inner_html=svg
+                                                />
+                                                <p class="mt-2 text-xs text-gray-500 dark:text-gray-400">
+                                                    {move || i18n.t("share_qr_hint")}
+                                                </p>
+                                            </div>
+                                        }
+                                    })}
+                            }
+                                .into_any()
+                        }
+                        None => {
+                            view! {
+                                <Show when=move || save_error.get()>
+                                    <p class="text-sm text-red-600 dark:text-red-400 mb-3">
+                                        {move || i18n.t("share_error")}
+                                    </p>
+                                </Show>
+                                <button
+                                    type="button"
+                                    on:click=create_link
+                                    disabled=move || saving.get()
+                                    class="w-full px-4 py-2 bg-indigo-600 dark:bg-indigo-500 text-white rounded-lg hover:bg-indigo-700 dark:hover:bg-indigo-600 transition-colors disabled:opacity-50"
+                                >
+                                    {move || {
+                                        if saving.get() {
+                                            i18n.t("results_share_saving")
+                                        } else {
+                                            i18n.t("share_create")
+                                        }
+                                    }}
+                                </button>
+                                <button
+                                    type="button"
+                                    on:click=create_fragment_link
+                                    disabled=move || saving.get()
+                                    class="w-full mt-2 px-4 py-2 text-sm border border-gray-300 dark:border-gray-600 text-gray-700 dark:text-gray-300 rounded-lg hover:bg-gray-50 dark:hover:bg-gray-700 transition-colors disabled:opacity-50"
+                                >
+                                    {move || i18n.t("share_create_fragment")}
+                                </button>
+                                <p class="mt-1 text-xs text-gray-500 dark:text-gray-400">
+                                    {move || i18n.t("share_create_fragment_hint")}
+                                </p>
+                            }
+                                .into_any()
+                        }
+                    }}
+                </div>
+            </div>
+        </Show>
+    }
+}
+
+/// i18n key for an expiry option.
+fn expiry_key(days: u32) -> &'static str {
+    match days {
+        1 => "share_expiry_1_day",
+        7 => "share_expiry_7_days",
+        _ => "share_expiry_30_days",
+    }
+}
+
+/// Render `url` as an inline SVG QR code.
+pub(super) fn qr_svg(url: &str) -> Option<String> {
+    use qrcode::QrCode;
+    use qrcode::render::svg;
+
+    let code = QrCode::new(url.as_bytes()).ok()?;
+    Some(
+        code.render::<svg::Color>()
+            .min_dimensions(176, 176)
+            .dark_color(svg::Color("#000000"))
+            .light_color(svg::Color("#ffffff"))
+            .build(),
+    )
+}
+
+fn current_url() -> Option<String> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        web_sys::window()?.location().href().ok()
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        None
+    }
+}
+
+pub(super) fn copy_to_clipboard(_text: &str) {
+    #[cfg(target_arch = "wasm32")]
+    {
+        if let Some(window) = web_sys::window() {
+            let _ = window.navigator().clipboard().write_text(_text);
+        }
+    }
+}
+
+/// Stable fingerprint of a snapshot's content, used to detect duplicate saves.
+#[cfg(target_arch = "wasm32")]
+fn snapshot_fingerprint(
+    profile: &PersonalityProfile,
+    user_context: &Option<String>,
+    ai_analysis: &Option<String>,
+    nickname: &Option<String>,
+    lang: &str,
+) -> String {
+    use std::hash::{DefaultHasher, Hash, Hasher};
+
+    let mut hasher = DefaultHasher::new();
+    serde_json::to_string(profile)
+        .unwrap_or_default()
+        .hash(&mut hasher);
+    (user_context, ai_analysis, nickname, lang).hash(&mut hasher);
+    format!("{:016x}", hasher.finish())
+}
+
+/// Random key identifying one save request.
+#[cfg(target_arch = "wasm32")]
+fn new_idempotency_key() -> String {
+    (0..4)
+        .map(|_| {
+            format!(
+                "{:08x}",
+                (js_sys::Math::random() * f64::from(u32::MAX)) as u32
+            )
+        })
+        .collect()
+}
+
+/// Id of the last snapshot saved with this fingerprint, if any.
+#[cfg(target_arch = "wasm32")]
+fn load_last_share(fingerprint: &str) -> Option<String> {
+    let json = web_sys::window()?
+        .local_storage()
+        .ok()??
+        .get_item(STORAGE_KEY_LAST_SHARE)
+        .ok()??;
+    let (saved_fingerprint, id): (String, String) = serde_json::from_str(&json).ok()?;
+    (saved_fingerprint == fingerprint).then_some(id)
+}
+
+#[cfg(target_arch = "wasm32")]
+fn save_last_share(fingerprint: &str, id: &str) {
+    if let Some(storage) = web_sys::window().and_then(|w| w.local_storage().ok().flatten())
+        && let Ok(json) = serde_json::to_string(&(fingerprint, id))
+    {
+        let _ = storage.set_item(STORAGE_KEY_LAST_SHARE, &json);
+    }
+}
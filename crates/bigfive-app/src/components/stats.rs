@@ -0,0 +1,185 @@
+//! Public statistics page: how site users' domain scores are spread.
+//!
+//! Histograms come from the `hist:` metrics of the daily statistics, so they only cover
+//! results people chose to share. The visitor's own latest profile, if this browser has
+//! one, is pinned on each histogram.
+
+use bigfive::{Domain, PersonalityProfile};
+use leptos::prelude::*;
+use leptos_meta::Title;
+use leptos_router::components::A;
+use serde::{Deserialize, Serialize};
+
+use crate::components::domain_color;
+use crate::components::history::domain_key;
+use crate::components::results::load_profile;
+use crate::i18n::use_i18n;
+
+/// Domain score histograms over all shared results.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[cfg_attr(not(any(target_arch = "wasm32", feature = "ssr")), allow(dead_code))]
+pub struct PublicStats {
+    /// Number of results counted.
+    pub sample_size: u64,
+    /// Result counts per equal-width percentage bucket, lowest first.
+    pub domains: Vec<(Domain, Vec<u64>)>,
+}
+
+/// Aggregated domain histograms from the daily statistics.
+#[server]
+pub async fn get_public_stats() -> Result<PublicStats, ServerFnError> {
+    use crate::db;
+
+    let totals = db::daily_stat_totals(crate::tenancy::current_namespace(), "hist:")
+        .await
+        .map_err(|e| ServerFnError::new(e.to_string()))?;
+
+    let domains: Vec<(Domain, Vec<u64>)> = Domain::all()
+        .iter()
+        .map(|domain| {
+            let prefix = format!("hist:{}:", domain.code());
+            let mut buckets = vec![0u64; db::HISTOGRAM_BUCKETS];
+            for (metric, count) in &totals {
+                if let Some(bucket) = metric
+                    .strip_prefix(&prefix)
+                    .and_then(|b| b.parse::<usize>().ok())
+                    && let Some(slot) = buckets.get_mut(bucket)
+                {
+                    *slot += *count as u64;
+                }
+            }
+            (*domain, buckets)
+        })
+        .collect();
+    let sample_size = domains
+        .first()
+        .map(|(_, buckets)| buckets.iter().sum())
+        .unwrap_or(0);
+
+    Ok(PublicStats {
+        sample_size,
+        domains,
+    })
+}
+
+/// `/stats`: score distribution per domain, with the visitor's own score pinned.
+#[component]
+pub fn StatsPage() -> impl IntoView {
+    let i18n = use_i18n();
+    let stats = Resource::new(|| (), |_| async move { get_public_stats().await.ok() });
+
+    // Only known in the browser
+    let (own, set_own) = signal::<Option<PersonalityProfile>>(None);
+    Effect::new(move |_| set_own.set(load_profile()));
+
+    view! {
+        <Title text=move || i18n.t("stats_title") />
+        <div class="max-w-4xl mx-auto px-4 py-8">
+            <h1 class="text-3xl font-bold text-gray-900 dark:text-white mb-2">{move || i18n.t("stats_title")}</h1>
+            <p class="text-gray-600 dark:text-gray-300 mb-6">{move || i18n.t("stats_description")}</p>
+            <Suspense>
+                {move || Suspend::new(async move {
+                    let stats = stats.await.filter(|s| s.sample_size > 0);
+                    let Some(stats) = stats else {
+                        return view! {
+                            <p class="p-4 rounded-lg bg-gray-50 dark:bg-gray-800 text-gray-600 dark:text-gray-300">
+                                {move || i18n.t("stats_empty")}
+                            </p>
+                        }
+                            .into_any();
+                    };
+                    let sample_size = stats.sample_size;
+                    view! {
+                        <p class="text-sm text-gray-500 dark:text-gray-400 mb-4">
+                            {move || format!("{} {}", i18n.t("stats_sample_size"), sample_size)}
+                        </p>
+                        <div class="space-y-4">
+                            {stats
+                                .domains
+                                .into_iter()
+                                .map(|(domain, buckets)| {
+                                    let own_score = Signal::derive(move || {
+                                        own.with(|p| {
+                                            p.as_ref()
+                                                .and_then(|p| p.domain_score(domain))
+                                                .map(|d| d.percentage())
+                                        })
+                                    });
+                                    view! { <DomainHistogram domain=domain buckets=buckets own_score=own_score /> }
+                                })
+                                .collect_view()}
+                        </div>
+                    }
+                        .into_any()
+                })}
+            </Suspense>
+            <Show when=move || own.with(Option::is_none)>
+                <p class="mt-6 text-sm text-gray-600 dark:text-gray-300">
+                    {move || i18n.t("stats_take_test")}
+                    " "
+                    <A
+                        href=move || format!("{}/test", i18n.get_locale().path_prefix())
+                        attr:class="text-indigo-600 dark:text-indigo-400 hover:underline"
+                    >
+                        {move || i18n.t("home_start_button")}
+                    </A>
+                </p>
+            </Show>
+        </div>
+    }
+}
+
+/// Bar histogram of one domain, with a "you" marker at `own_score` (a percentage).
+#[component]
+fn DomainHistogram(
+    domain: Domain,
+    buckets: Vec<u64>,
+    #[prop(into)] own_score: Signal<Option<f32>>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+    let tallest = buckets.iter().copied().max().unwrap_or(0).max(1);
+
+    view! {
+        <div class="bg-white dark:bg-gray-800 rounded-lg shadow-md p-4 transition-colors duration-300">
+            <h2 class="font-semibold text-gray-800 dark:text-gray-100 mb-3">{move || i18n.t(domain_key(domain))}</h2>
+            <div class="relative">
+                <div class="flex items-end gap-1 h-24">
+                    {buckets
+                        .into_iter()
+                        .map(|count| {
+                            let height = count as f32 / tallest as f32 * 100.0;
+                            view! {
+                                <div
+                                    class="flex-1 rounded-t opacity-80"
+                                    style=format!("height: {height:.1}%; background-color: {}", domain_color(domain))
+                                    title=count.to_string()
+                                />
+                            }
+                        })
+                        .collect_view()}
+                </div>
+                {move || {
+                    own_score
+                        .get()
+                        .map(|score| {
+                            view! {
+                                <div
+                                    class="absolute inset-y-0 flex flex-col items-center -translate-x-1/2"
+                                    style=format!("left: {score:.1}%")
+                                >
+                                    <span class="px-1.5 rounded bg-gray-900 dark:bg-white text-white dark:text-gray-900 text-xs font-medium">
+                                        {move || i18n.t("stats_you")}
+                                    </span>
+                                    <div class="flex-1 w-0.5 bg-gray-900 dark:bg-white" />
+                                </div>
+                            }
+                        })
+                }}
+            </div>
+            <div class="flex justify-between mt-1 text-xs text-gray-500 dark:text-gray-400">
+                <span>"0%"</span>
+                <span>"100%"</span>
+            </div>
+        </div>
+    }
+}
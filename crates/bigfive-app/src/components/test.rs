@@ -1,29 +1,129 @@
 //! Test page component with quiz UI.
 
-use bigfive::{Answer, Ipip120};
+use std::collections::{HashMap, HashSet};
+
+use bigfive::{AnswerIssue, Ipip120, TimedAnswer};
 use leptos::prelude::*;
-use leptos_router::hooks::use_navigate;
+use leptos_router::hooks::{use_navigate, use_query_map};
 
-use crate::components::{LangToggle, ThemeToggle};
-use crate::i18n::{Locale, use_i18n};
+#[cfg(target_arch = "wasm32")]
+use crate::components::bookmarks::STORAGE_KEY_BOOKMARKS;
+use crate::components::bookmarks::{keep_bookmarks_for_reflection, load_bookmarks, save_bookmarks};
+use crate::components::demographics::{DemographicsStep, load_demographics};
+use crate::components::question_timing::{
+    QuestionTimer, QuestionTiming, QuestionTimingConsent, get_question_analytics_enabled,
+};
+use crate::components::research::{ResearchConsent, get_research_enabled};
+use crate::components::resume::{PROGRESS_PARAM, SaveProgress, parse_progress_code};
+use crate::i18n::use_i18n;
 
 #[cfg(target_arch = "wasm32")]
-const STORAGE_KEY_ANSWERS: &str = "bigfive_answers";
+const STORAGE_KEY_ANSWERS: &str = crate::storage::storage_key!("answers");
+#[cfg(target_arch = "wasm32")]
+const STORAGE_KEY_INDEX: &str = crate::storage::storage_key!("current_index");
 #[cfg(target_arch = "wasm32")]
-const STORAGE_KEY_INDEX: &str = "bigfive_current_index";
+const STORAGE_KEY_PROFILE: &str = crate::storage::storage_key!("profile");
 #[cfg(target_arch = "wasm32")]
-const STORAGE_KEY_PROFILE: &str = "bigfive_profile";
+const STORAGE_KEY_STARTED_AT: &str = crate::storage::storage_key!("started_at");
+#[cfg(target_arch = "wasm32")]
+const STORAGE_KEY_FLAGGED: &str = crate::storage::storage_key!("flagged");
+
+/// Maximum number of scoring issues listed individually.
+const MAX_LISTED_ISSUES: usize = 5;
+
+/// How long the completion screen stays up before moving on to results.
+#[cfg(target_arch = "wasm32")]
+const COMPLETION_SCREEN_MS: u32 = 4000;
+
+/// Finishing faster than this (2 seconds per question) suggests rushed answers.
+const RUSHED_THRESHOLD_SECS: u64 = 240;
+
+/// Share of identical answers above which the profile is likely not informative.
+const UNIFORM_ANSWER_RATIO: f32 = 0.7;
+
+/// Note shown on the completion screen about how the answers look.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum AnswerQuality {
+    /// Nothing unusual.
+    Good,
+    /// Most answers were the same option.
+    Uniform,
+    /// The test was finished very quickly.
+    Rushed,
+}
+
+impl AnswerQuality {
+    /// Judge answer quality from the answer values, time taken and whether the per-question
+    /// response times were implausibly fast.
+    fn assess(values: &[u8], elapsed_secs: Option<u64>, too_fast: bool) -> Self {
+        let most_common = answer_distribution(values).into_iter().max().unwrap_or(0);
+        if !values.is_empty() && most_common as f32 / values.len() as f32 >= UNIFORM_ANSWER_RATIO {
+            AnswerQuality::Uniform
+        } else if too_fast || elapsed_secs.is_some_and(|secs| secs < RUSHED_THRESHOLD_SECS) {
+            AnswerQuality::Rushed
+        } else {
+            AnswerQuality::Good
+        }
+    }
+
+    fn i18n_key(&self) -> &'static str {
+        match self {
+            AnswerQuality::Good => "complete_quality_good",
+            AnswerQuality::Uniform => "complete_quality_uniform",
+            AnswerQuality::Rushed => "complete_quality_rushed",
+        }
+    }
+
+    /// Warning shown on the review screen, while answers can still be revised.
+    fn review_warning_key(&self) -> Option<&'static str> {
+        match self {
+            AnswerQuality::Good => None,
+            AnswerQuality::Uniform => Some("review_quality_uniform"),
+            AnswerQuality::Rushed => Some("review_quality_rushed"),
+        }
+    }
+}
+
+/// Number of answers per option (index `value - 1`).
+fn answer_distribution<'a>(values: impl IntoIterator<Item = &'a u8>) -> [usize; 5] {
+    let mut counts = [0; 5];
+    for &value in values {
+        if let Some(count) = counts.get_mut(usize::from(value).wrapping_sub(1)) {
+            *count += 1;
+        }
+    }
+    counts
+}
+
+/// Summary shown on the completion screen.
+#[derive(Clone, Copy, Debug)]
+struct Completion {
+    elapsed_secs: Option<u64>,
+    quality: AnswerQuality,
+}
 
 /// Test page with one question at a time.
 #[component]
 pub fn TestPage() -> impl IntoView {
     let i18n = use_i18n();
-    let navigate = use_navigate();
+    let navigate = StoredValue::new_local(use_navigate());
+
+    // Set once the last answer is submitted; shows the completion screen
+    let (completion, set_completion) = signal::<Option<Completion>>(None);
+
+    let go_to_results = move || {
+        navigate.with_value(|nav| {
+            nav(
+                &format!("{}/results", i18n.get_locale().path_prefix()),
+                Default::default(),
+            )
+        });
+    };
 
     // Load inventory based on language
-    let inventory = Memo::new(move |_| match i18n.get_locale() {
-        Locale::En => Ipip120::english(),
-        Locale::Ru => Ipip120::russian(),
+    let inventory = Memo::new(move |_| {
+        crate::inventory::inventory(i18n.get_locale().code())
+            .expect("every locale has an inventory")
     });
 
     // Current question index (0-119)
@@ -32,6 +132,122 @@ pub fn TestPage() -> impl IntoView {
     // Answers map: question_id -> value
     let (answers, set_answers) = signal(load_answers());
 
+    // Ids of questions flagged for review before submitting
+    let (flagged, set_flagged) = signal(load_flagged());
+
+    // Ids of questions bookmarked for reflecting on after the test
+    let (bookmarks, set_bookmarks) = signal(load_bookmarks());
+
+    // Pre-submit review screen
+    let (reviewing, set_reviewing) = signal(false);
+
+    // Optional demographics step before the first question, unless answered or skipped before
+    let (asking_demographics, set_asking_demographics) = signal(false);
+    Effect::new(move |_| {
+        if load_demographics().is_none() && answers.get_untracked().is_empty() {
+            set_asking_demographics.set(true);
+        }
+    });
+
+    // Whether to offer donating the answers for research on the completion screen
+    let (research_enabled, set_research_enabled) = signal(false);
+    Effect::new(move |_| {
+        leptos::task::spawn_local(async move {
+            set_research_enabled.set(get_research_enabled().await.unwrap_or(false));
+        });
+    });
+
+    // Whether to time questions and offer sending the timings on the completion screen
+    let (timing_enabled, set_timing_enabled) = signal(false);
+    Effect::new(move |_| {
+        leptos::task::spawn_local(async move {
+            set_timing_enabled.set(get_question_analytics_enabled().await.unwrap_or(false));
+        });
+    });
+    let timer = StoredValue::new(QuestionTimer::default());
+    // Time to the first answer of each question, by question id
+    let response_ms = StoredValue::new(HashMap::<String, u32>::new());
+
+    // Set when scoring fails; lists what's wrong with the answers
+    let (scoring_error, set_scoring_error) = signal::<Option<Vec<AnswerIssue>>>(None);
+
+    // Any change to the answers may have fixed the problem
+    Effect::new(move |prev: Option<()>| {
+        answers.track();
+        if prev.is_some() {
+            set_scoring_error.set(None);
+        }
+    });
+
+    // Shown when a `?resume=` code is unknown or expired
+    let (resume_not_found, set_resume_not_found) = signal(false);
+
+    // Restore progress saved on another device (client only, after hydration). The query
+    // parameter is dropped afterwards so a reload doesn't overwrite newer local answers.
+    let query = use_query_map();
+    Effect::new(move |_| {
+        let Some(code) = query.with(|q| q.get("resume")) else {
+            return;
+        };
+        #[cfg(target_arch = "wasm32")]
+        leptos::task::spawn_local(async move {
+            use crate::components::resume::load_progress;
+
+            match load_progress(code).await {
+                Ok(Some(progress)) => {
+                    set_resume_not_found.set(false);
+                    set_asking_demographics.set(false);
+                    set_answers.set(progress.answers);
+                    set_current_index.set(progress.current_index.min(119));
+                }
+                Ok(None) => set_resume_not_found.set(true),
+                Err(e) => {
+                    leptos::logging::error!("Failed to load saved progress: {}", e);
+                    set_resume_not_found.set(true);
+                }
+            }
+            navigate.with_value(|nav| {
+                nav(
+                    &format!("{}/test", i18n.get_locale().path_prefix()),
+                    leptos_router::NavigateOptions {
+                        replace: true,
+                        ..Default::default()
+                    },
+                )
+            });
+        });
+        #[cfg(not(target_arch = "wasm32"))]
+        let _ = (code, set_resume_not_found);
+    });
+
+    // Shown when a `?progress=` code from another device's QR code can't be read
+    let (progress_invalid, set_progress_invalid) = signal(false);
+
+    // Restore progress carried in the link itself, dropping it from the URL the same way
+    Effect::new(move |_| {
+        let Some(code) = query.with(|q| q.get(PROGRESS_PARAM)) else {
+            return;
+        };
+        match parse_progress_code(&code, i18n.get_locale().code()) {
+            Some(progress) => {
+                set_progress_invalid.set(false);
+                set_asking_demographics.set(false);
+                set_answers.set(progress.answers);
+                set_current_index.set(progress.current_index);
+            }
+            None => set_progress_invalid.set(true),
+        }
+        navigate.with_value(|nav| {
+            nav(
+                &format!("{}/test", i18n.get_locale().path_prefix()),
+                leptos_router::NavigateOptions {
+                    replace: true,
+                    ..Default::default()
+                },
+            )
+        });
+    });
+
     // Save state to localStorage whenever it changes
     Effect::new(move |_| {
         save_current_index(current_index.get());
@@ -41,6 +257,59 @@ pub fn TestPage() -> impl IntoView {
         save_answers(&answers.get());
     });
 
+    Effect::new(move |_| {
+        save_flagged(&flagged.get());
+    });
+
+    Effect::new(move |_| {
+        save_bookmarks(&bookmarks.get());
+    });
+
+    // Keep progress in sync with other tabs. `storage` events only fire in the tabs that
+    // didn't make the change, and re-saving an identical value doesn't fire them, so this
+    // doesn't ping-pong between tabs.
+    #[cfg(target_arch = "wasm32")]
+    {
+        let handle = window_event_listener(leptos::ev::storage, move |ev| {
+            match ev.key().as_deref() {
+                Some(STORAGE_KEY_ANSWERS) => {
+                    let synced = ev
+                        .new_value()
+                        .and_then(|json| serde_json::from_str(&json).ok())
+                        .unwrap_or_default();
+                    set_answers.set(synced);
+                }
+                Some(STORAGE_KEY_FLAGGED) => {
+                    let synced = ev
+                        .new_value()
+                        .and_then(|json| serde_json::from_str(&json).ok())
+                        .unwrap_or_default();
+                    set_flagged.set(synced);
+                }
+                Some(STORAGE_KEY_BOOKMARKS) => {
+                    let synced = ev
+                        .new_value()
+                        .and_then(|json| serde_json::from_str(&json).ok())
+                        .unwrap_or_default();
+                    set_bookmarks.set(synced);
+                }
+                Some(STORAGE_KEY_INDEX) => {
+                    if let Some(index) = ev.new_value().and_then(|v| v.parse::<usize>().ok()) {
+                        set_current_index.set(index.min(119));
+                    }
+                }
+                // Another tab finished the test: follow it to the results
+                Some(STORAGE_KEY_PROFILE)
+                    if ev.new_value().is_some() && completion.get_untracked().is_none() =>
+                {
+                    go_to_results();
+                }
+                _ => {}
+            }
+        });
+        on_cleanup(move || handle.remove());
+    }
+
     // Get current question
     let current_question = move || {
         let inv = inventory.get();
@@ -54,6 +323,47 @@ pub fn TestPage() -> impl IntoView {
         answers.get().get(&q.id).copied()
     };
 
+    // Question on screen (id and index), for the timer
+    let visible_question = Memo::new(move |_| {
+        if asking_demographics.get() || reviewing.get() || completion.get().is_some() {
+            return None;
+        }
+        current_question().map(|q| (q.id, current_index.get()))
+    });
+    Effect::new(move |_| {
+        let visible = visible_question.get();
+        timer.update_value(|t| t.show(visible));
+    });
+
+    let is_flagged = move || current_question().is_some_and(|q| flagged.get().contains(&q.id));
+
+    let toggle_flag = move |_| {
+        if let Some(q) = current_question() {
+            set_flagged.update(|ids| {
+                if !ids.remove(&q.id) {
+                    ids.insert(q.id.clone());
+                }
+            });
+        }
+    };
+
+    let is_bookmarked = move || current_question().is_some_and(|q| bookmarks.get().contains(&q.id));
+
+    let toggle_bookmark = move |_| {
+        if let Some(q) = current_question() {
+            set_bookmarks.update(|ids| {
+                if !ids.remove(&q.id) {
+                    ids.insert(q.id.clone());
+                }
+            });
+        }
+    };
+
+    let jump_to = Callback::new(move |index: usize| {
+        set_reviewing.set(false);
+        set_current_index.set(index.min(119));
+    });
+
     // Check if we can go to results
     let all_answered = move || {
         let inv = inventory.get();
@@ -64,6 +374,14 @@ pub fn TestPage() -> impl IntoView {
     // Handle answer selection
     let select_answer = move |value: u8| {
         if let Some(q) = current_question() {
+            mark_started();
+            if !answers.with_untracked(|ans| ans.contains_key(&q.id))
+                && let Some(ms) = timer.with_value(|t| t.visible_ms(&q.id))
+            {
+                response_ms.update_value(|times| {
+                    times.insert(q.id.clone(), ms);
+                });
+            }
             set_answers.update(|ans| {
                 ans.insert(q.id.clone(), value);
             });
@@ -95,190 +413,793 @@ pub fn TestPage() -> impl IntoView {
     let submit_action = Action::new(move |_: &()| {
         let inv = inventory.get();
         let ans = answers.get();
-        let nav = navigate.clone();
-        let locale = i18n.get_locale();
+        let times = response_ms.get_value();
 
         async move {
-            let answer_vec: Vec<Answer> = inv
+            let answer_vec: Vec<TimedAnswer> = inv
                 .questions()
                 .iter()
                 .filter_map(|q| {
-                    ans.get(&q.id).map(|&value| Answer {
+                    ans.get(&q.id).map(|&value| TimedAnswer {
                         question_id: q.id.clone(),
                         value,
+                        response_ms: times.get(&q.id).copied(),
                     })
                 })
                 .collect();
 
-            if answer_vec.len() == 120 {
-                match bigfive::calculate(&inv, &answer_vec) {
-                    Ok(profile) => {
-                        save_profile(&profile);
-                        clear_test_progress();
-                        nav(
-                            &format!("{}/results", locale.path_prefix()),
-                            Default::default(),
-                        );
-                    }
-                    Err(e) => {
-                        leptos::logging::error!("Failed to calculate profile: {}", e);
+            match bigfive::calculate_timed(&inv, &answer_vec) {
+                Ok(profile) => {
+                    let too_fast = profile
+                        .validity
+                        .as_ref()
+                        .and_then(|v| v.speed.as_ref())
+                        .is_some_and(|s| s.too_fast);
+                    let elapsed_secs = elapsed_since_start();
+                    let values: Vec<u8> = answer_vec.iter().map(|a| a.value).collect();
+                    save_profile(&profile);
+                    keep_bookmarks_for_reflection();
+                    clear_test_progress();
+                    timer.update_value(|t| t.show(None));
+                    set_completion.set(Some(Completion {
+                        elapsed_secs,
+                        quality: AnswerQuality::assess(&values, elapsed_secs, too_fast),
+                    }));
+
+                    // Stays put while a consent form is shown
+                    #[cfg(target_arch = "wasm32")]
+                    if !research_enabled.get_untracked() && !timing_enabled.get_untracked() {
+                        leptos::task::spawn_local(async move {
+                            gloo_timers::future::TimeoutFuture::new(COMPLETION_SCREEN_MS).await;
+                            // Skip if the user already moved on (page unmounted)
+                            if completion.try_get_untracked().flatten().is_some() {
+                                go_to_results();
+                            }
+                        });
                     }
                 }
+                Err(e) => {
+                    leptos::logging::error!("Failed to calculate profile: {}", e);
+                    set_scoring_error.set(Some(bigfive::validate_answers(
+                        &inv,
+                        &answer_vec
+                            .iter()
+                            .map(TimedAnswer::to_answer)
+                            .collect::<Vec<_>>(),
+                    )));
+                }
             }
         }
     });
 
-    // Answer button labels
+    // Answer button labels, from the inventory's own scale
     let answer_labels = move || {
-        vec![
-            (1u8, i18n.t("answer_1").to_string()),
-            (2u8, i18n.t("answer_2").to_string()),
-            (3u8, i18n.t("answer_3").to_string()),
-            (4u8, i18n.t("answer_4").to_string()),
-            (5u8, i18n.t("answer_5").to_string()),
-        ]
+        inventory.with(|inv| {
+            (1u8..)
+                .zip(inv.scale_labels().iter().cloned())
+                .collect::<Vec<_>>()
+        })
     };
 
     view! {
-        <div class="max-w-2xl mx-auto px-4 py-8">
-            // Header with language and theme toggles
-            <header class="flex justify-between items-center mb-8">
-                <h1 class="text-2xl font-bold text-gray-900 dark:text-white">{move || i18n.t("title")}</h1>
-                <div class="flex items-center gap-3">
-                    <LangToggle />
-                    <ThemeToggle />
-                </div>
-            </header>
+        <Show
+            when=move || !asking_demographics.get()
+            fallback=move || view! { <DemographicsStep on_done=move |_| set_asking_demographics.set(false) /> }
+        >
+            <Show
+                when=move || completion.get().is_none()
+                fallback=move || {
+                    completion
+                        .get()
+                        .map(|c| {
+                            let research_answers = research_enabled.get_untracked().then(|| answers.get_untracked());
+                            let question_timings = timing_enabled
+                                .get_untracked()
+                                .then(|| timer.with_value(QuestionTimer::timings));
+                            view! {
+                                <CompletionScreen completion=c research_answers question_timings on_continue=go_to_results />
+                            }
+                        })
+                }
+            >
+                <div class="max-w-2xl mx-auto px-4 py-8">
+                    <Show when=move || resume_not_found.get()>
+                        <div
+                            role="alert"
+                            class="mb-6 p-4 rounded-lg bg-amber-50 dark:bg-amber-900/20 border border-amber-200 dark:border-amber-800 text-sm text-amber-800 dark:text-amber-200"
+                        >
+                            {move || i18n.t("resume_not_found")}
+                        </div>
+                    </Show>
+                    <Show when=move || progress_invalid.get()>
+                        <div
+                            role="alert"
+                            class="mb-6 p-4 rounded-lg bg-amber-50 dark:bg-amber-900/20 border border-amber-200 dark:border-amber-800 text-sm text-amber-800 dark:text-amber-200"
+                        >
+                            {move || i18n.t("progress_code_invalid")}
+                        </div>
+                    </Show>
 
-            // Progress bar
-            <div class="mb-8">
-                <div class="flex justify-between text-sm text-gray-600 dark:text-gray-400 mb-2">
-                    <span>
-                        {move || { format!("{} {}/120", i18n.t("test_question"), current_index.get() + 1) }}
-                    </span>
-                    <span>{move || { format!("{}%", ((current_index.get() + 1) as f32 / 120.0 * 100.0) as u8) }}</span>
-                </div>
-                <div class="w-full bg-gray-200 dark:bg-gray-700 rounded-full h-2.5">
-                    <div
-                        class="bg-indigo-600 dark:bg-indigo-500 h-2.5 rounded-full transition-all duration-300"
-                        style:width=move || { format!("{}%", ((current_index.get() + 1) as f32 / 120.0 * 100.0)) }
-                    />
+                    {move || {
+                        scoring_error
+                            .get()
+                            .map(|issues| {
+                                view! { <ScoringError inventory=inventory issues=issues on_jump=jump_to /> }
+                            })
+                    }}
+
+                    <Show
+                        when=move || !reviewing.get()
+                        fallback=move || {
+                            view! {
+                                <ReviewScreen
+                                    inventory=inventory
+                                    answers=answers
+                                    flagged=flagged
+                                    on_unflag=Callback::new(move |id: String| set_flagged.update(|ids| { ids.remove(&id); }))
+                                    on_jump=jump_to
+                                    on_back=Callback::new(move |_| set_reviewing.set(false))
+                                    on_submit=Callback::new(move |_| {
+                                        submit_action.dispatch(());
+                                    })
+                                />
+                            }
+                        }
+                    >
+                        // Progress bar
+                        <div class="mb-8">
+                            <div class="flex justify-between text-sm text-gray-600 dark:text-gray-400 mb-2">
+                                <span>
+                                    {move || { format!("{} {}/120", i18n.t("test_question"), current_index.get() + 1) }}
+                                </span>
+                                <span>{move || { format!("{}%", ((current_index.get() + 1) as f32 / 120.0 * 100.0) as u8) }}</span>
+                            </div>
+                            <div class="w-full bg-gray-200 dark:bg-gray-700 rounded-full h-2.5">
+                                <div
+                                    class="bg-indigo-600 dark:bg-indigo-500 h-2.5 rounded-full transition-all duration-300"
+                                    style:width=move || { format!("{}%", ((current_index.get() + 1) as f32 / 120.0 * 100.0)) }
+                                />
+                            </div>
+                        </div>
+
+                        // Question card
+                        <div class="bg-white dark:bg-gray-800 rounded-lg shadow-md p-6 mb-6 transition-colors duration-300">
+                            <div class="flex justify-end gap-1 -mt-2 -mr-2 mb-2">
+                                <button
+                                    type="button"
+                                    on:click=toggle_bookmark
+                                    aria-pressed=move || is_bookmarked().to_string()
+                                    class=move || {
+                                        format!(
+                                            "flex items-center gap-1.5 px-2 py-1 text-sm rounded-lg transition-colors {}",
+                                            if is_bookmarked() {
+                                                "text-indigo-700 dark:text-indigo-300 bg-indigo-50 dark:bg-indigo-900/30"
+                                            } else {
+                                                "text-gray-500 dark:text-gray-400 hover:bg-gray-100 dark:hover:bg-gray-700"
+                                            },
+                                        )
+                                    }
+                                >
+                                    <svg
+                                        class="w-4 h-4"
+                                        fill=move || if is_bookmarked() { "currentColor" } else { "none" }
+                                        stroke="currentColor"
+                                        viewBox="0 0 24 24"
+                                        aria-hidden="true"
+                                    >
+                                        <path
+                                            stroke-linecap="round"
+                                            stroke-linejoin="round"
+                                            stroke-width="2"
+                                            d="M5 5a2 2 0 012-2h10a2 2 0 012 2v16l-7-3.5L5 21V5z"
+                                        />
+                                    </svg>
+                                    {move || if is_bookmarked() { i18n.t("test_bookmarked") } else { i18n.t("test_bookmark") }}
+                                </button>
+                                <button
+                                    type="button"
+                                    on:click=toggle_flag
+                                    aria-pressed=move || is_flagged().to_string()
+                                    class=move || {
+                                        format!(
+                                            "flex items-center gap-1.5 px-2 py-1 text-sm rounded-lg transition-colors {}",
+                                            if is_flagged() {
+                                                "text-amber-700 dark:text-amber-300 bg-amber-50 dark:bg-amber-900/30"
+                                            } else {
+                                                "text-gray-500 dark:text-gray-400 hover:bg-gray-100 dark:hover:bg-gray-700"
+                                            },
+                                        )
+                                    }
+                                >
+                                    <svg
+                                        class="w-4 h-4"
+                                        fill=move || if is_flagged() { "currentColor" } else { "none" }
+                                        stroke="currentColor"
+                                        viewBox="0 0 24 24"
+                                        aria-hidden="true"
+                                    >
+                                        <path
+                                            stroke-linecap="round"
+                                            stroke-linejoin="round"
+                                            stroke-width="2"
+                                            d="M3 21v-4m0 0V5a2 2 0 012-2h6.5l1 1H21l-3 6 3 6h-8.5l-1-1H5a2 2 0 00-2 2z"
+                                        />
+                                    </svg>
+                                    {move || if is_flagged() { i18n.t("test_flagged") } else { i18n.t("test_flag") }}
+                                </button>
+                            </div>
+                            <p class="text-xl text-gray-800 dark:text-gray-100 text-center mb-8 min-h-[3rem]">
+                                {move || current_question().map(|q| q.text.clone()).unwrap_or_default()}
+                            </p>
+
+                            // Answer buttons
+                            <div class="space-y-3">
+                                {move || {
+                                    let current = current_answer();
+                                    answer_labels()
+                                        .into_iter()
+                                        .map(|(value, label)| {
+                                            let is_selected = current == Some(value);
+                                            let select = move |_| select_answer(value);
+
+                                            view! {
+                                                <button
+                                                    on:click=select
+                                                    class=move || {
+                                                        let base = "w-full py-3 px-4 rounded-lg border-2 font-medium transition-all duration-200 text-left";
+                                                        if is_selected {
+                                                            format!(
+                                                                "{} border-indigo-600 dark:border-indigo-400 bg-indigo-50 dark:bg-indigo-900/30 text-indigo-700 dark:text-indigo-300",
+                                                                base,
+                                                            )
+                                                        } else {
+                                                            format!(
+                                                                "{} border-gray-200 dark:border-gray-600 hover:border-indigo-300 dark:hover:border-indigo-500 hover:bg-gray-50 dark:hover:bg-gray-700 text-gray-700 dark:text-gray-300",
+                                                                base,
+                                                            )
+                                                        }
+                                                    }
+                                                >
+                                                    <span class="flex items-center">
+                                                        <span class=move || {
+                                                            let base = "w-6 h-6 rounded-full border-2 mr-3 flex items-center justify-center";
+                                                            if is_selected {
+                                                                format!(
+                                                                    "{} border-indigo-600 dark:border-indigo-400 bg-indigo-600 dark:bg-indigo-500",
+                                                                    base,
+                                                                )
+                                                            } else {
+                                                                format!("{} border-gray-300 dark:border-gray-500", base)
+                                                            }
+                                                        }>
+                                                            {move || {
+                                                                if is_selected {
+                                                                    view! { <span class="w-2 h-2 rounded-full bg-white" /> }
+                                                                        .into_any()
+                                                                } else {
+                                                                    view! { <span /> }.into_any()
+                                                                }
+                                                            }}
+                                                        </span>
+                                                        {label.clone()}
+                                                    </span>
+                                                </button>
+                                            }
+                                        })
+                                        .collect_view()
+                                }}
+                            </div>
+                        </div>
+
+                        // Navigation buttons
+                        <div class="flex justify-between items-center">
+                            <button
+                                on:click=go_prev
+                                prop:disabled=move || current_index.get() == 0
+                                class="px-6 py-2 rounded-lg border border-gray-300 dark:border-gray-600 text-gray-700 dark:text-gray-300 font-medium hover:bg-gray-50 dark:hover:bg-gray-700 disabled:opacity-50 disabled:cursor-not-allowed transition-colors"
+                            >
+                                {move || i18n.t("test_back")}
+                            </button>
+
+                            {move || {
+                                let idx = current_index.get();
+                                let all_done = all_answered();
+                                let is_last = idx >= 119;
+                                if idx == 119 && all_done {
+
+                                    view! {
+                                        <button
+                                            on:click=move |_| set_reviewing.set(true)
+                                            class="px-6 py-2 rounded-lg bg-green-600 dark:bg-green-500 text-white font-medium hover:bg-green-700 dark:hover:bg-green-600 transition-colors"
+                                        >
+                                            {move || i18n.t("test_show_results")}
+                                        </button>
+                                    }
+                                        .into_any()
+                                } else {
+                                    view! {
+                                        <button
+                                            on:click=go_next
+                                            prop:disabled=is_last
+                                            class="px-6 py-2 rounded-lg bg-indigo-600 dark:bg-indigo-500 text-white font-medium hover:bg-indigo-700 dark:hover:bg-indigo-600 disabled:opacity-50 disabled:cursor-not-allowed transition-colors"
+                                        >
+                                            {move || i18n.t("test_next")}
+                                        </button>
+                                    }
+                                        .into_any()
+                                }
+                            }}
+                        </div>
+
+                        // Answered count
+                        <div class="mt-6 text-center text-sm text-gray-500 dark:text-gray-400">
+                            {move || {
+                                let answered = answers.get().len();
+                                format!("{}: {}/120", i18n.t("test_answered"), answered)
+                            }}
+                        </div>
+
+                        <QuestionNavigator
+                            inventory=inventory
+                            answers=answers
+                            flagged=flagged
+                            current_index=current_index
+                            on_jump=jump_to
+                        />
+
+                        <SaveProgress answers=answers current_index=current_index />
+                    </Show>
                 </div>
-            </div>
+            </Show>
+        </Show>
+    }
+}
 
-            // Question card
-            <div class="bg-white dark:bg-gray-800 rounded-lg shadow-md p-6 mb-6 transition-colors duration-300">
-                <p class="text-xl text-gray-800 dark:text-gray-100 text-center mb-8 min-h-[3rem]">
-                    {move || current_question().map(|q| q.text.clone()).unwrap_or_default()}
-                </p>
+/// Error state shown when scoring fails, with a way to jump to each offending question.
+#[component]
+fn ScoringError(
+    inventory: Memo<Ipip120>,
+    issues: Vec<AnswerIssue>,
+    on_jump: Callback<usize>,
+) -> impl IntoView {
+    let i18n = use_i18n();
 
-                // Answer buttons
-                <div class="space-y-3">
-                    {move || {
-                        let current = current_answer();
-                        answer_labels()
-                            .into_iter()
-                            .map(|(value, label)| {
-                                let is_selected = current == Some(value);
-                                let select = move |_| select_answer(value);
+    // Scoring failed for a reason validation doesn't know about
+    let no_details = issues.is_empty();
+    let hidden = issues.len().saturating_sub(MAX_LISTED_ISSUES);
+    let items = issues
+        .into_iter()
+        .take(MAX_LISTED_ISSUES)
+        .map(|issue| {
+            let index = inventory.with_untracked(|inv| {
+                inv.questions().iter().position(|q| q.id == issue.question_id())
+            });
+            let description = move || {
+                let problem = match &issue {
+                    AnswerIssue::Missing { .. } => i18n.t("scoring_issue_missing").to_string(),
+                    AnswerIssue::InvalidValue { value, .. } => {
+                        format!("{} ({})", i18n.t("scoring_issue_invalid_value"), value)
+                    }
+                    AnswerIssue::Duplicate { .. } => i18n.t("scoring_issue_duplicate").to_string(),
+                    AnswerIssue::UnknownQuestion { .. } => i18n.t("scoring_issue_unknown").to_string(),
+                };
+                match index {
+                    Some(i) => format!("{} {}: {}", i18n.t("test_question"), i + 1, problem),
+                    None => problem,
+                }
+            };
+            view! {
+                <li class="flex flex-wrap items-center justify-between gap-2">
+                    <span>{description}</span>
+                    {index
+                        .map(|i| {
+                            view! {
+                                <button
+                                    type="button"
+                                    on:click=move |_| on_jump.run(i)
+                                    class="px-3 py-1 rounded-lg border border-red-300 dark:border-red-700 font-medium hover:bg-red-100 dark:hover:bg-red-900/40 transition-colors"
+                                >
+                                    {move || i18n.t("scoring_go_to_question")}
+                                </button>
+                            }
+                        })}
+                </li>
+            }
+        })
+        .collect_view();
+
+    view! {
+        <div
+            role="alert"
+            class="mb-6 p-4 rounded-lg bg-red-50 dark:bg-red-900/20 border border-red-200 dark:border-red-800 text-sm text-red-800 dark:text-red-200"
+        >
+            <p class="font-semibold mb-2">{move || i18n.t("scoring_error_title")}</p>
+            {no_details.then(|| view! { <p>{move || i18n.t("scoring_error_unknown")}</p> })}
+            <ul class="space-y-2">{items}</ul>
+            {(hidden > 0)
+                .then(|| {
+                    view! {
+                        <p class="mt-2">{move || format!("{} {}", i18n.t("scoring_more_issues"), hidden)}</p>
+                    }
+                })}
+        </div>
+    }
+}
+
+/// Grid of all questions for jumping around; shows answered, flagged and current ones.
+#[component]
+fn QuestionNavigator(
+    inventory: Memo<Ipip120>,
+    #[prop(into)] answers: Signal<HashMap<String, u8>>,
+    #[prop(into)] flagged: Signal<HashSet<String>>,
+    #[prop(into)] current_index: Signal<usize>,
+    on_jump: Callback<usize>,
+) -> impl IntoView {
+    let i18n = use_i18n();
 
+    view! {
+        <details class="mt-6 bg-white dark:bg-gray-800 rounded-lg shadow-md transition-colors duration-300">
+            <summary class="cursor-pointer px-4 py-3 text-sm font-medium text-gray-700 dark:text-gray-200">
+                {move || i18n.t("test_navigator")}
+            </summary>
+            <div class="px-4 pb-4">
+                <div class="grid grid-cols-10 gap-1.5">
+                    {move || {
+                        let ans = answers.get();
+                        let flags = flagged.get();
+                        let current = current_index.get();
+                        inventory
+                            .get()
+                            .questions()
+                            .iter()
+                            .enumerate()
+                            .map(|(index, q)| {
+                                let is_flagged = flags.contains(&q.id);
+                                let state = if is_flagged {
+                                    "bg-amber-100 dark:bg-amber-900/40 text-amber-800 dark:text-amber-200"
+                                } else if ans.contains_key(&q.id) {
+                                    "bg-indigo-100 dark:bg-indigo-900/40 text-indigo-700 dark:text-indigo-300"
+                                } else {
+                                    "bg-gray-100 dark:bg-gray-700 text-gray-500 dark:text-gray-400"
+                                };
+                                let ring = if index == current { "ring-2 ring-indigo-500" } else { "" };
+                                let label = if is_flagged {
+                                    format!("{} {} ({})", i18n.t("test_question"), index + 1, i18n.t("test_flagged"))
+                                } else {
+                                    format!("{} {}", i18n.t("test_question"), index + 1)
+                                };
                                 view! {
                                     <button
-                                        on:click=select
-                                        class=move || {
-                                            let base = "w-full py-3 px-4 rounded-lg border-2 font-medium transition-all duration-200 text-left";
-                                            if is_selected {
-                                                format!(
-                                                    "{} border-indigo-600 dark:border-indigo-400 bg-indigo-50 dark:bg-indigo-900/30 text-indigo-700 dark:text-indigo-300",
-                                                    base,
-                                                )
-                                            } else {
-                                                format!(
-                                                    "{} border-gray-200 dark:border-gray-600 hover:border-indigo-300 dark:hover:border-indigo-500 hover:bg-gray-50 dark:hover:bg-gray-700 text-gray-700 dark:text-gray-300",
-                                                    base,
-                                                )
-                                            }
-                                        }
+                                        type="button"
+                                        on:click=move |_| on_jump.run(index)
+                                        aria-label=label
+                                        aria-current=(index == current).then_some("step")
+                                        class=format!(
+                                            "h-8 text-xs font-medium rounded hover:opacity-80 transition-opacity {} {}",
+                                            state,
+                                            ring,
+                                        )
                                     >
-                                        <span class="flex items-center">
-                                            <span class=move || {
-                                                let base = "w-6 h-6 rounded-full border-2 mr-3 flex items-center justify-center";
-                                                if is_selected {
-                                                    format!(
-                                                        "{} border-indigo-600 dark:border-indigo-400 bg-indigo-600 dark:bg-indigo-500",
-                                                        base,
-                                                    )
-                                                } else {
-                                                    format!("{} border-gray-300 dark:border-gray-500", base)
-                                                }
-                                            }>
-                                                {move || {
-                                                    if is_selected {
-                                                        view! { <span class="w-2 h-2 rounded-full bg-white" /> }
-                                                            .into_any()
-                                                    } else {
-                                                        view! { <span /> }.into_any()
-                                                    }
-                                                }}
-                                            </span>
-                                            {label.clone()}
-                                        </span>
+                                        {index + 1}
                                     </button>
                                 }
                             })
                             .collect_view()
                     }}
                 </div>
+                <div class="flex flex-wrap gap-4 mt-3 text-xs text-gray-500 dark:text-gray-400">
+                    <span class="flex items-center gap-1.5">
+                        <span class="w-3 h-3 rounded bg-indigo-100 dark:bg-indigo-900/40" />
+                        {move || i18n.t("test_answered")}
+                    </span>
+                    <span class="flex items-center gap-1.5">
+                        <span class="w-3 h-3 rounded bg-amber-100 dark:bg-amber-900/40" />
+                        {move || i18n.t("test_flagged")}
+                    </span>
+                    <span class="flex items-center gap-1.5">
+                        <span class="w-3 h-3 rounded bg-gray-100 dark:bg-gray-700" />
+                        {move || i18n.t("test_unanswered")}
+                    </span>
+                </div>
             </div>
+        </details>
+    }
+}
 
-            // Navigation buttons
-            <div class="flex justify-between items-center">
-                <button
-                    on:click=go_prev
-                    prop:disabled=move || current_index.get() == 0
-                    class="px-6 py-2 rounded-lg border border-gray-300 dark:border-gray-600 text-gray-700 dark:text-gray-300 font-medium hover:bg-gray-50 dark:hover:bg-gray-700 disabled:opacity-50 disabled:cursor-not-allowed transition-colors"
-                >
-                    {move || i18n.t("test_back")}
-                </button>
+/// How the answers are spread over the five options, with the most common one called out.
+#[component]
+fn AnswerDistribution(
+    inventory: Memo<Ipip120>,
+    #[prop(into)] answers: Signal<HashMap<String, u8>>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+
+    let counts = move || answer_distribution(answers.get().values());
+    let percent = move |count: usize| {
+        let total = answers.with(|a| a.len()).max(1);
+        count as f32 / total as f32 * 100.0
+    };
 
+    view! {
+        <div class="mb-6">
+            <h2 class="text-sm font-semibold text-gray-700 dark:text-gray-200 mb-1">
+                {move || i18n.t("review_distribution_title")}
+            </h2>
+            <p class="text-sm text-gray-600 dark:text-gray-300 mb-3">
+                {move || {
+                    let counts = counts();
+                    let (index, &count) = counts.iter().enumerate().max_by_key(|&(_, c)| *c).unwrap_or((0, &0));
+                    format!(
+                        "{:.0}% {} \"{}\"",
+                        percent(count),
+                        i18n.t("review_most_common"),
+                        inventory.with(|inv| inv.scale_labels()[index].clone()),
+                    )
+                }}
+            </p>
+            <div class="space-y-1.5">
                 {move || {
-                    let idx = current_index.get();
-                    let all_done = all_answered();
-                    let is_last = idx >= 119;
-                    if idx == 119 && all_done {
+                    counts()
+                        .into_iter()
+                        .zip(inventory.get().scale_labels().clone())
+                        .map(|(count, label)| {
+                            let pct = percent(count);
+                            view! {
+                                <div class="flex items-center gap-3 text-xs">
+                                    <span class="w-40 flex-shrink-0 truncate text-gray-600 dark:text-gray-300">
+                                        {label}
+                                    </span>
+                                    <div class="flex-1 bg-gray-200 dark:bg-gray-700 rounded-full h-2">
+                                        <div
+                                            class="bg-indigo-600 dark:bg-indigo-500 h-2 rounded-full"
+                                            style:width=format!("{}%", pct)
+                                        />
+                                    </div>
+                                    <span class="w-10 text-right text-gray-500 dark:text-gray-400">
+                                        {format!("{:.0}%", pct)}
+                                    </span>
+                                </div>
+                            }
+                        })
+                        .collect_view()
+                }}
+            </div>
+        </div>
+    }
+}
 
+/// Review step before submitting: answer distribution, a quality warning and the
+/// flagged questions, so answers can be revised before results are calculated.
+#[component]
+fn ReviewScreen(
+    inventory: Memo<Ipip120>,
+    #[prop(into)] answers: Signal<HashMap<String, u8>>,
+    #[prop(into)] flagged: Signal<HashSet<String>>,
+    on_unflag: Callback<String>,
+    on_jump: Callback<usize>,
+    on_back: Callback<()>,
+    on_submit: Callback<()>,
+) -> impl IntoView {
+    let i18n = use_i18n();
+
+    // Same check as on the completion screen, but while answers can still change
+    let elapsed_secs = elapsed_since_start();
+    let quality = Memo::new(move |_| {
+        let values: Vec<u8> = answers.get().values().copied().collect();
+        AnswerQuality::assess(&values, elapsed_secs, false)
+    });
+
+    // (index, question id, text) of flagged questions, in test order
+    let flagged_questions = move || {
+        let flags = flagged.get();
+        inventory
+            .get()
+            .questions()
+            .iter()
+            .enumerate()
+            .filter(|(_, q)| flags.contains(&q.id))
+            .map(|(index, q)| (index, q.id.clone(), q.text.clone()))
+            .collect::<Vec<_>>()
+    };
+
+    view! {
+        <div class="bg-white dark:bg-gray-800 rounded-lg shadow-md p-6 transition-colors duration-300">
+            <h1 class="text-2xl font-bold text-gray-900 dark:text-white mb-4">{move || i18n.t("review_title")}</h1>
+            <AnswerDistribution inventory=inventory answers=answers />
+            {move || {
+                quality
+                    .get()
+                    .review_warning_key()
+                    .map(|key| {
                         view! {
-                            <button
-                                on:click=move |_| {
-                                    submit_action.dispatch(());
-                                }
-                                class="px-6 py-2 rounded-lg bg-green-600 dark:bg-green-500 text-white font-medium hover:bg-green-700 dark:hover:bg-green-600 transition-colors"
+                            <div
+                                role="alert"
+                                class="mb-6 p-4 rounded-lg bg-amber-50 dark:bg-amber-900/20 border border-amber-200 dark:border-amber-800 text-sm text-amber-800 dark:text-amber-200"
                             >
-                                {move || i18n.t("test_show_results")}
-                            </button>
+                                <p class="mb-3">{i18n.t(key)}</p>
+                                <button
+                                    type="button"
+                                    on:click=move |_| on_jump.run(0)
+                                    class="px-3 py-1 rounded-lg border border-amber-300 dark:border-amber-700 font-medium hover:bg-amber-100 dark:hover:bg-amber-900/40 transition-colors"
+                                >
+                                    {move || i18n.t("review_revise")}
+                                </button>
+                            </div>
                         }
-                            .into_any()
+                    })
+            }}
+            <p class="text-gray-600 dark:text-gray-300 mb-6">
+                {move || {
+                    if flagged.get().is_empty() {
+                        i18n.t("review_no_flagged").to_string()
                     } else {
-                        view! {
-                            <button
-                                on:click=go_next
-                                prop:disabled=is_last
-                                class="px-6 py-2 rounded-lg bg-indigo-600 dark:bg-indigo-500 text-white font-medium hover:bg-indigo-700 dark:hover:bg-indigo-600 disabled:opacity-50 disabled:cursor-not-allowed transition-colors"
-                            >
-                                {move || i18n.t("test_next")}
-                            </button>
-                        }
-                            .into_any()
+                        format!("{} {}", i18n.t("review_flagged_count"), flagged.get().len())
                     }
                 }}
-            </div>
-
-            // Answered count
-            <div class="mt-6 text-center text-sm text-gray-500 dark:text-gray-400">
+            </p>
+            <ul class="divide-y divide-gray-200 dark:divide-gray-700 mb-6">
                 {move || {
-                    let answered = answers.get().len();
-                    format!("{}: {}/120", i18n.t("test_answered"), answered)
+                    let ans = answers.get();
+                    let labels = inventory.get().scale_labels().clone();
+                    flagged_questions()
+                        .into_iter()
+                        .map(|(index, id, text)| {
+                            let answer = ans
+                                .get(&id)
+                                .and_then(|&v| labels.get(usize::from(v).wrapping_sub(1)))
+                                .cloned()
+                                .unwrap_or_else(|| i18n.t("test_unanswered").to_string());
+                            view! {
+                                <li class="py-3 flex flex-wrap items-center justify-between gap-3">
+                                    <div class="min-w-0 flex-1">
+                                        <p class="text-gray-800 dark:text-gray-100">
+                                            <span class="font-medium">{format!("{}. ", index + 1)}</span>
+                                            {text}
+                                        </p>
+                                        <p class="text-sm text-gray-500 dark:text-gray-400">{answer}</p>
+                                    </div>
+                                    <div class="flex gap-2">
+                                        <button
+                                            type="button"
+                                            on:click=move |_| on_jump.run(index)
+                                            class="px-3 py-1 text-sm rounded-lg border border-gray-300 dark:border-gray-600 text-gray-700 dark:text-gray-300 hover:bg-gray-50 dark:hover:bg-gray-700 transition-colors"
+                                        >
+                                            {move || i18n.t("review_revisit")}
+                                        </button>
+                                        <button
+                                            type="button"
+                                            on:click=move |_| on_unflag.run(id.clone())
+                                            class="px-3 py-1 text-sm rounded-lg text-gray-500 dark:text-gray-400 hover:bg-gray-100 dark:hover:bg-gray-700 transition-colors"
+                                        >
+                                            {move || i18n.t("review_unflag")}
+                                        </button>
+                                    </div>
+                                </li>
+                            }
+                        })
+                        .collect_view()
                 }}
+            </ul>
+            <div class="flex justify-between items-center">
+                <button
+                    type="button"
+                    on:click=move |_| on_back.run(())
+                    class="px-6 py-2 rounded-lg border border-gray-300 dark:border-gray-600 text-gray-700 dark:text-gray-300 font-medium hover:bg-gray-50 dark:hover:bg-gray-700 transition-colors"
+                >
+                    {move || i18n.t("test_back")}
+                </button>
+                <button
+                    type="button"
+                    on:click=move |_| on_submit.run(())
+                    class="px-6 py-2 rounded-lg bg-green-600 dark:bg-green-500 text-white font-medium hover:bg-green-700 dark:hover:bg-green-600 transition-colors"
+                >
+                    {move || {
+                        if quality.get() == AnswerQuality::Good {
+                            i18n.t("test_show_results")
+                        } else {
+                            i18n.t("review_submit_anyway")
+                        }
+                    }}
+                </button>
+            </div>
+        </div>
+    }
+}
+
+/// Confetti colors, taken from the domain chart palette.
+const CONFETTI_COLORS: [&str; 5] = [
+    "--color-domain-n",
+    "--color-domain-e",
+    "--color-domain-o",
+    "--color-domain-a",
+    "--color-domain-c",
+];
+
+/// Short celebration after the last answer, shown while results are prepared.
+#[component]
+fn CompletionScreen(
+    completion: Completion,
+    /// Answers to offer for research, if this deployment collects them.
+    research_answers: Option<HashMap<String, u8>>,
+    /// Question timings to offer sending, if this deployment collects them.
+    question_timings: Option<Vec<QuestionTiming>>,
+    on_continue: impl Fn() + Send + Sync + 'static,
+) -> impl IntoView {
+    let i18n = use_i18n();
+
+    // Deterministic spread of confetti pieces across the card
+    let confetti = (0..36u32)
+        .map(|i| {
+            let left = (i * 37) % 100;
+            let delay = (i * 53) % 900;
+            let color = CONFETTI_COLORS[i as usize % CONFETTI_COLORS.len()];
+            view! {
+                <span
+                    class="confetti-piece"
+                    style:left=format!("{}%", left)
+                    style:animation-delay=format!("{}ms", delay)
+                    style:background-color=format!("var({})", color)
+                />
+            }
+        })
+        .collect_view();
+
+    let time_taken = completion.elapsed_secs.map(|secs| {
+        let minutes = secs / 60;
+        let seconds = secs % 60;
+        move || {
+            format!(
+                "{}: {} {} {} {}",
+                i18n.t("complete_time"),
+                minutes,
+                i18n.t("unit_min"),
+                seconds,
+                i18n.t("unit_sec")
+            )
+        }
+    });
+
+    let quality_class = if completion.quality == AnswerQuality::Good {
+        "text-sm text-gray-600 dark:text-gray-300 mb-8"
+    } else {
+        "text-sm text-amber-700 dark:text-amber-400 mb-8"
+    };
+
+    view! {
+        <div class="max-w-2xl mx-auto px-4 py-8">
+            <div
+                class="relative overflow-hidden bg-white dark:bg-gray-800 rounded-lg shadow-md p-8 text-center transition-colors duration-300"
+                role="status"
+            >
+                <div aria-hidden="true" class="pointer-events-none absolute inset-0">
+                    {confetti}
+                </div>
+                <div class="flex justify-center mb-4">
+                    <div class="flex items-center justify-center w-14 h-14 rounded-full bg-green-100 dark:bg-green-900/30">
+                        <svg
+                            class="w-7 h-7 text-green-600 dark:text-green-400"
+                            fill="none"
+                            stroke="currentColor"
+                            viewBox="0 0 24 24"
+                        >
+                            <path stroke-linecap="round" stroke-linejoin="round" stroke-width="2" d="M5 13l4 4L19 7" />
+                        </svg>
+                    </div>
+                </div>
+                <h1 class="text-2xl font-bold text-gray-900 dark:text-white mb-2">{move || i18n.t("complete_title")}</h1>
+                <p class="text-gray-600 dark:text-gray-300 mb-4">{move || i18n.t("complete_description")}</p>
+                {time_taken.map(|text| view! { <p class="text-sm text-gray-500 dark:text-gray-400 mb-2">{text}</p> })}
+                <p class=quality_class>{move || i18n.t(completion.quality.i18n_key())}</p>
+                {research_answers.map(|answers| view! { <ResearchConsent answers /> })}
+                {question_timings
+                    .filter(|timings| !timings.is_empty())
+                    .map(|timings| view! { <QuestionTimingConsent timings /> })}
+                <button
+                    on:click=move |_| on_continue()
+                    class="px-6 py-2 rounded-lg bg-indigo-600 dark:bg-indigo-500 text-white font-medium hover:bg-indigo-700 dark:hover:bg-indigo-600 transition-colors"
+                >
+                    {move || i18n.t("test_show_results")}
+                </button>
             </div>
         </div>
     }
@@ -322,7 +1243,7 @@ fn save_current_index(index: usize) {
 #[cfg(not(target_arch = "wasm32"))]
 fn save_current_index(_index: usize) {}
 
-fn load_answers() -> std::collections::HashMap<String, u8> {
+fn load_answers() -> HashMap<String, u8> {
     #[cfg(target_arch = "wasm32")]
     {
         let window = web_sys::window().expect("no window");
@@ -340,12 +1261,12 @@ fn load_answers() -> std::collections::HashMap<String, u8> {
     }
     #[cfg(not(target_arch = "wasm32"))]
     {
-        std::collections::HashMap::new()
+        HashMap::new()
     }
 }
 
 #[cfg(target_arch = "wasm32")]
-fn save_answers(answers: &std::collections::HashMap<String, u8>) {
+fn save_answers(answers: &HashMap<String, u8>) {
     let window = web_sys::window().expect("no window");
     let storage = window
         .local_storage()
@@ -358,10 +1279,38 @@ fn save_answers(answers: &std::collections::HashMap<String, u8>) {
 }
 
 #[cfg(not(target_arch = "wasm32"))]
-fn save_answers(_answers: &std::collections::HashMap<String, u8>) {}
+fn save_answers(_answers: &HashMap<String, u8>) {}
+
+fn load_flagged() -> HashSet<String> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        web_sys::window()
+            .and_then(|w| w.local_storage().ok().flatten())
+            .and_then(|s| s.get_item(STORAGE_KEY_FLAGGED).ok().flatten())
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default()
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        HashSet::new()
+    }
+}
+
+#[cfg_attr(not(target_arch = "wasm32"), allow(unused_variables))]
+fn save_flagged(flagged: &HashSet<String>) {
+    #[cfg(target_arch = "wasm32")]
+    {
+        if let Some(storage) = web_sys::window().and_then(|w| w.local_storage().ok().flatten())
+            && let Ok(json) = serde_json::to_string(flagged)
+        {
+            let _ = storage.set_item(STORAGE_KEY_FLAGGED, &json);
+        }
+    }
+}
 
 #[cfg(target_arch = "wasm32")]
 fn save_profile(profile: &bigfive::PersonalityProfile) {
+    super::changes::archive_profile();
     let window = web_sys::window().expect("no window");
     let storage = window
         .local_storage()
@@ -387,5 +1336,45 @@ fn clear_test_progress() {
             .expect("no localStorage");
         let _ = storage.remove_item(STORAGE_KEY_ANSWERS);
         let _ = storage.remove_item(STORAGE_KEY_INDEX);
+        let _ = storage.remove_item(STORAGE_KEY_STARTED_AT);
+        let _ = storage.remove_item(STORAGE_KEY_FLAGGED);
+    }
+}
+
+/// Record when the test was started (first answer), unless already recorded.
+fn mark_started() {
+    #[cfg(target_arch = "wasm32")]
+    {
+        let Some(storage) = web_sys::window().and_then(|w| w.local_storage().ok().flatten()) else {
+            return;
+        };
+        if storage
+            .get_item(STORAGE_KEY_STARTED_AT)
+            .ok()
+            .flatten()
+            .is_none()
+        {
+            let _ = storage.set_item(STORAGE_KEY_STARTED_AT, &js_sys::Date::now().to_string());
+        }
+    }
+}
+
+/// Seconds since the test was started, if the start time is known.
+fn elapsed_since_start() -> Option<u64> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        let started_at: f64 = web_sys::window()?
+            .local_storage()
+            .ok()??
+            .get_item(STORAGE_KEY_STARTED_AT)
+            .ok()??
+            .parse()
+            .ok()?;
+        let elapsed_ms = js_sys::Date::now() - started_at;
+        (elapsed_ms >= 0.0).then(|| (elapsed_ms / 1000.0) as u64)
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        None
     }
 }
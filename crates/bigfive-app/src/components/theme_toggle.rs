@@ -2,6 +2,9 @@
 
 use leptos::prelude::*;
 
+use crate::components::dropdown::{DropdownMenu, MenuOption};
+use crate::i18n::use_i18n;
+
 /// Get system preferred color scheme
 #[cfg(target_arch = "wasm32")]
 fn get_system_theme() -> &'static str {
@@ -35,66 +38,118 @@ fn save_theme_preference(theme: &str) {
     }
 }
 
+/// Remove the saved theme preference (follow the system again)
+#[cfg(target_arch = "wasm32")]
+fn clear_theme_preference() {
+    if let Some(window) = web_sys::window()
+        && let Ok(Some(storage)) = window.local_storage()
+    {
+        let _ = storage.remove_item("theme");
+    }
+}
+
 /// Load theme preference from localStorage
 #[cfg(target_arch = "wasm32")]
-fn load_theme_preference() -> Option<String> {
+fn load_theme_preference() -> Option<ThemeMode> {
     if let Some(window) = web_sys::window()
         && let Ok(Some(storage)) = window.local_storage()
         && let Ok(Some(theme)) = storage.get_item("theme")
     {
-        return Some(theme);
+        return match theme.as_str() {
+            "light" => Some(ThemeMode::Light),
+            "dark" => Some(ThemeMode::Dark),
+            _ => None,
+        };
     }
     None
 }
 
-/// Theme toggle component that switches between light and dark modes.
+/// Theme selected in the menu.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum ThemeMode {
+    Light,
+    Dark,
+    /// Follow the system color scheme (no saved preference).
+    System,
+}
+
+impl ThemeMode {
+    const ALL: [ThemeMode; 3] = [ThemeMode::Light, ThemeMode::Dark, ThemeMode::System];
+
+    fn i18n_key(&self) -> &'static str {
+        match self {
+            ThemeMode::Light => "theme_light",
+            ThemeMode::Dark => "theme_dark",
+            ThemeMode::System => "theme_system",
+        }
+    }
+}
+
+/// Theme selector with light, dark and system options.
 /// Uses the system color scheme as default when no preference is saved.
 #[component]
 pub fn ThemeToggle() -> AnyView {
-    let (mode, set_mode) = signal("light".to_string());
+    let i18n = use_i18n();
+    let (mode, set_mode) = signal(ThemeMode::System);
+    // Theme actually applied, for the trigger icon
+    let (is_dark, set_is_dark) = signal(false);
 
     // Initialize theme on mount
     Effect::new(move |_| {
         #[cfg(target_arch = "wasm32")]
         {
-            let current_mode =
-                load_theme_preference().unwrap_or_else(|| get_system_theme().to_string());
-            set_mode.set(current_mode.clone());
-            apply_theme(&current_mode);
+            let current_mode = load_theme_preference().unwrap_or(ThemeMode::System);
+            set_mode.set(current_mode);
+            set_is_dark.set(match current_mode {
+                ThemeMode::Light => false,
+                ThemeMode::Dark => true,
+                ThemeMode::System => get_system_theme() == "dark",
+            });
         }
     });
 
-    let toggle_theme = move |_| {
-        let new_mode = match mode.get().as_str() {
-            "light" => "dark",
-            _ => "light",
-        };
-
-        set_mode.set(new_mode.to_string());
+    let select = move |new_mode: ThemeMode| {
+        set_mode.set(new_mode);
 
         #[cfg(target_arch = "wasm32")]
         {
-            apply_theme(new_mode);
-            save_theme_preference(new_mode);
+            let theme = match new_mode {
+                ThemeMode::Light => "light",
+                ThemeMode::Dark => "dark",
+                ThemeMode::System => get_system_theme(),
+            };
+            apply_theme(theme);
+            match new_mode {
+                ThemeMode::System => clear_theme_preference(),
+                _ => save_theme_preference(theme),
+            }
+            set_is_dark.set(theme == "dark");
         }
+        #[cfg(not(target_arch = "wasm32"))]
+        let _ = set_is_dark;
     };
 
+    let options = ThemeMode::ALL
+        .into_iter()
+        .map(|option| MenuOption {
+            label: Signal::derive(move || i18n.t(option.i18n_key()).to_string()),
+            current: Signal::derive(move || mode.get() == option),
+            on_select: Callback::new(move |_| select(option)),
+        })
+        .collect();
+
+    let label = Signal::derive(move || {
+        format!(
+            "{}: {}",
+            i18n.t("theme_label"),
+            i18n.t(mode.get().i18n_key())
+        )
+    });
+
     view! {
-        <button
-            class="theme-toggle"
-            on:click=toggle_theme
-            title=move || {
-                match mode.get().as_str() {
-                    "light" => "Theme: light",
-                    _ => "Theme: dark",
-                }
-            }
-        >
-            {move || match mode.get().as_str() {
-                "light" => "☀️",
-                _ => "🌙",
-            }}
-        </button>
+        <DropdownMenu id="theme-menu" label=label options=options>
+            <span aria-hidden="true">{move || if is_dark.get() { "🌙" } else { "☀️" }}</span>
+        </DropdownMenu>
     }
     .into_any()
 }
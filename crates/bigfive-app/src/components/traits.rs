@@ -0,0 +1,145 @@
+//! Deep-dive pages for each domain, e.g. `/en/traits/openness`.
+
+use bigfive::{Descriptions, Domain, DomainDescription};
+use leptos::prelude::*;
+use leptos_meta::{Meta, Title};
+use leptos_router::components::A;
+use leptos_router::hooks::use_params_map;
+
+use crate::components::history::{domain_key, facet_key};
+use crate::components::{NotFoundPage, domain_color};
+use crate::i18n::use_i18n;
+
+/// URL slug of a domain's page.
+pub(super) fn domain_slug(domain: Domain) -> &'static str {
+    match domain {
+        Domain::Neuroticism => "neuroticism",
+        Domain::Extraversion => "extraversion",
+        Domain::Openness => "openness",
+        Domain::Agreeableness => "agreeableness",
+        Domain::Conscientiousness => "conscientiousness",
+    }
+}
+
+/// Trait page for the `:domain` slug: what the domain measures, what high and low scores
+/// look like, and its six facets. Unknown slugs get the 404 page.
+#[component]
+pub fn TraitPage() -> impl IntoView {
+    let params = use_params_map();
+    let domain = move || {
+        let slug = params.read().get("domain")?;
+        Domain::all()
+            .iter()
+            .copied()
+            .find(|d| domain_slug(*d) == slug)
+    };
+
+    move || match domain() {
+        Some(domain) => view! { <TraitDetails domain=domain /> }.into_any(),
+        None => view! { <NotFoundPage /> }.into_any(),
+    }
+}
+
+#[component]
+fn TraitDetails(domain: Domain) -> impl IntoView {
+    let i18n = use_i18n();
+    let descriptions = Memo::new(move |_| Descriptions::new(i18n.get_locale().code()).ok());
+    let name = move || i18n.t(domain_key(domain));
+    let text = move |field: fn(&DomainDescription) -> &String| {
+        descriptions
+            .read()
+            .as_ref()
+            .map(|d| field(d.domain(domain)).clone())
+            .unwrap_or_default()
+    };
+    let summary = move || text(|d| &d.summary);
+    let high = move || text(|d| &d.high);
+    let low = move || text(|d| &d.low);
+    let color = domain_color(domain);
+
+    view! {
+        <Title text=move || format!("{} | {}", name(), i18n.t("traits_title_suffix")) />
+        <Meta name="description" content=summary />
+        <div class="max-w-4xl mx-auto px-4 py-8">
+            <div class="bg-white dark:bg-gray-800 rounded-lg shadow-md p-8 transition-colors duration-300">
+                <h1 class="text-3xl font-bold text-gray-900 dark:text-white mb-4">{name}</h1>
+                <p class="text-gray-600 dark:text-gray-300 leading-relaxed mb-8">{summary}</p>
+
+                <div class="grid gap-4 sm:grid-cols-2 mb-10">
+                    <div class="border border-gray-200 dark:border-gray-700 rounded-lg p-4">
+                        <h2 class="font-semibold text-gray-800 dark:text-gray-100 mb-2">
+                            {move || i18n.t("traits_high_heading")}
+                        </h2>
+                        <p class="text-sm text-gray-600 dark:text-gray-300 leading-relaxed">{high}</p>
+                    </div>
+                    <div class="border border-gray-200 dark:border-gray-700 rounded-lg p-4">
+                        <h2 class="font-semibold text-gray-800 dark:text-gray-100 mb-2">
+                            {move || i18n.t("traits_low_heading")}
+                        </h2>
+                        <p class="text-sm text-gray-600 dark:text-gray-300 leading-relaxed">{low}</p>
+                    </div>
+                </div>
+
+                <h2 class="text-2xl font-semibold text-gray-800 dark:text-gray-100 mb-4">
+                    {move || i18n.t("traits_facets_heading")}
+                </h2>
+                <ul class="space-y-4 mb-10">
+                    {domain
+                        .facets()
+                        .iter()
+                        .map(|&facet| {
+                            let description = move || {
+                                descriptions
+                                    .read()
+                                    .as_ref()
+                                    .map(|d| d.facet(facet).to_string())
+                                    .unwrap_or_default()
+                            };
+                            view! {
+                                <li class="border-l-4 pl-4" style=format!("border-color: {color}")>
+                                    <h3 class="font-medium text-gray-800 dark:text-gray-100">
+                                        {move || i18n.t(facet_key(facet))}
+                                    </h3>
+                                    <p class="text-sm text-gray-600 dark:text-gray-300 leading-relaxed">
+                                        {description}
+                                    </p>
+                                </li>
+                            }
+                        })
+                        .collect_view()}
+                </ul>
+
+                <nav class="flex flex-wrap gap-2 mb-8" aria-label=move || i18n.t("traits_other_label")>
+                    {Domain::all()
+                        .iter()
+                        .copied()
+                        .filter(|d| *d != domain)
+                        .map(|other| {
+                            view! {
+                                <A
+                                    href=move || {
+                                        format!(
+                                            "{}/traits/{}",
+                                            i18n.get_locale().path_prefix(),
+                                            domain_slug(other),
+                                        )
+                                    }
+                                    attr:class="px-3 py-1 text-sm rounded-full border border-gray-300 dark:border-gray-600 text-gray-700 dark:text-gray-200 hover:bg-gray-50 dark:hover:bg-gray-700 transition-colors"
+                                >
+                                    {move || i18n.t(domain_key(other))}
+                                </A>
+                            }
+                        })
+                        .collect_view()}
+                </nav>
+
+                <A
+                    href=move || format!("{}/test", i18n.get_locale().path_prefix())
+                    attr:class="inline-block w-full sm:w-auto text-center px-8 py-3 bg-indigo-600 dark:bg-indigo-500 text-white font-semibold rounded-lg hover:bg-indigo-700 dark:hover:bg-indigo-600 transition-colors"
+                >
+                    {move || i18n.t("home_start_button")}
+                </A>
+            </div>
+        </div>
+    }
+}
@@ -2,13 +2,22 @@
 //!
 //! Loads configuration from TOML file specified by `AI_CONFIG_PATH` env var
 //! or defaults to `./ai_config.toml`.
+//!
+//! Without a config file, or with `AI_DISABLED=1` in the environment, the app runs as a
+//! pure scoring tool: every setting takes its default and AI features are turned off.
 
-use std::path::PathBuf;
+use std::net::IpAddr;
+use std::path::{Path, PathBuf};
 
+use ipnet::IpNet;
 use once_cell::sync::OnceCell;
 use serde::{Deserialize, Serialize};
 use thiserror::Error;
 
+use crate::i18n::Locale;
+use crate::inventory::TextOverrides;
+use crate::translations::UiTexts;
+
 /// Global config instance (loaded once on first access)
 static CONFIG: OnceCell<AiConfig> = OnceCell::new();
 
@@ -23,13 +32,29 @@ fn load_config() -> Result<AiConfig, ConfigError> {
         .map(PathBuf::from)
         .unwrap_or_else(|_| PathBuf::from("ai_config.toml"));
 
-    let content = std::fs::read_to_string(&path).map_err(|e| ConfigError::ReadFile {
-        path: path.clone(),
-        source: e,
-    })?;
+    let content = match std::fs::read_to_string(&path) {
+        Ok(content) => content,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => {
+            tracing::warn!(
+                "Config file '{}' not found, running without AI features",
+                path.display()
+            );
+            "ai_enabled = false".to_string()
+        }
+        Err(e) => return Err(ConfigError::ReadFile { path, source: e }),
+    };
 
-    let config: AiConfig =
+    let mut config: AiConfig =
         toml::from_str(&content).map_err(|e| ConfigError::Parse { path, source: e })?;
+    if std::env::var("AI_DISABLED").is_ok_and(|v| matches!(v.as_str(), "1" | "true")) {
+        config.ai_enabled = false;
+    }
+    if let Some(ref overrides_path) = config.question_overrides {
+        config.question_texts = load_question_overrides(overrides_path)?;
+    }
+    if let Some(ref dir) = config.translations_dir {
+        config.ui_texts = load_translations(dir)?;
+    }
 
     // Validate configuration
     config.validate()?;
@@ -37,6 +62,47 @@ fn load_config() -> Result<AiConfig, ConfigError> {
     Ok(config)
 }
 
+/// Load question text overrides: one table per language, mapping question ids to texts.
+fn load_question_overrides(path: &Path) -> Result<TextOverrides, ConfigError> {
+    let content = std::fs::read_to_string(path).map_err(|e| ConfigError::ReadFile {
+        path: path.to_path_buf(),
+        source: e,
+    })?;
+    toml::from_str(&content).map_err(|e| ConfigError::Parse {
+        path: path.to_path_buf(),
+        source: e,
+    })
+}
+
+/// Load interface string overrides: one `<lang>.toml` file per language, mapping
+/// translation keys to strings. Other files are ignored.
+fn load_translations(dir: &Path) -> Result<UiTexts, ConfigError> {
+    let read_error = |source| ConfigError::ReadFile {
+        path: dir.to_path_buf(),
+        source,
+    };
+    let mut texts = UiTexts::new();
+    for entry in std::fs::read_dir(dir).map_err(read_error)? {
+        let path = entry.map_err(read_error)?.path();
+        if path.extension().is_none_or(|ext| ext != "toml") {
+            continue;
+        }
+        let Some(lang) = path.file_stem().and_then(|s| s.to_str()) else {
+            continue;
+        };
+        let content = std::fs::read_to_string(&path).map_err(|e| ConfigError::ReadFile {
+            path: path.clone(),
+            source: e,
+        })?;
+        let strings = toml::from_str(&content).map_err(|e| ConfigError::Parse {
+            path: path.clone(),
+            source: e,
+        })?;
+        texts.insert(lang.to_string(), strings);
+    }
+    Ok(texts)
+}
+
 /// Configuration loading errors.
 #[derive(Debug, Error)]
 pub enum ConfigError {
@@ -59,11 +125,112 @@ pub enum ConfigError {
 /// Root AI configuration structure.
 #[derive(Debug, Deserialize)]
 pub struct AiConfig {
+    /// Whether AI analyses and explanations are offered; when off, the app only scores tests
+    #[serde(default = "default_true")]
+    pub ai_enabled: bool,
+
     /// Optional safeguard configuration for prompt injection protection (shared for all models)
     #[serde(default)]
     pub safeguard: Option<SafeguardConfig>,
 
-    /// Available model presets
+    /// Optional short per-facet explanations from a cheap model, cached in the database
+    #[serde(default)]
+    pub facet_explanations: Option<FacetExplanationsConfig>,
+
+    /// Maximum length (in characters) of the optional user context
+    #[serde(default = "default_max_context_chars")]
+    pub max_context_chars: usize,
+
+    /// Maximum length (in characters) of the full analysis prompt, including the profile
+    #[serde(default = "default_max_prompt_chars")]
+    pub max_prompt_chars: usize,
+
+    /// Maximum length (in characters) of an analysis; longer ones are cut with a notice
+    #[serde(default = "default_max_analysis_chars")]
+    pub max_analysis_chars: usize,
+
+    /// Follow-up requests asking a model to continue a response cut off by `max_tokens`
+    #[serde(default = "default_max_continuations")]
+    pub max_continuations: u32,
+
+    /// Masking of personal data in the user context before it is stored or sent to a model
+    #[serde(default)]
+    pub pii_scrubbing: PiiScrubbingConfig,
+
+    /// Reverse proxies whose forwarding headers are trusted for the client IP
+    #[serde(default)]
+    pub proxy: ProxyConfig,
+
+    /// Request header with the client's country code, set by a proxy or CDN (e.g. `CF-IPCountry`)
+    #[serde(default)]
+    pub country_header: Option<String>,
+
+    /// Rules choosing the default preset by interface language and/or country, first match wins
+    #[serde(default)]
+    pub default_model_rules: Vec<DefaultModelRule>,
+
+    /// Offer users to donate their anonymized answers for research (admin CSV export)
+    #[serde(default)]
+    pub research_dataset: bool,
+
+    /// Offer users to share per-question timings after the test (admin report of slow items)
+    #[serde(default)]
+    pub question_analytics: bool,
+
+    /// Leading zero bits of the proof-of-work required to save a shared result; 0 disables it
+    #[serde(default)]
+    pub save_pow_difficulty: u8,
+
+    /// Days a deleted shared result can still be restored by its owner before it is purged
+    #[serde(default = "default_deleted_result_grace_days")]
+    pub deleted_result_grace_days: u32,
+
+    /// Keep redacted provider requests and responses of failed analysis jobs for the admin page
+    #[serde(default)]
+    pub debug_provider_logs: bool,
+
+    /// Webhook notified when a model preset's analyses keep failing
+    #[serde(default)]
+    pub provider_alerts: Option<ProviderAlertsConfig>,
+
+    /// Append-only log of saves, views and deletions of shared results, with hashed IPs
+    #[serde(default)]
+    pub audit_log: AuditLogConfig,
+
+    /// Daily number of AI analyses per browser, on top of the per-IP rate limits
+    #[serde(default)]
+    pub analysis_quota: AnalysisQuotaConfig,
+
+    /// Machine-readable result summaries for embedding in external dashboards
+    #[serde(default)]
+    pub summary_api: SummaryApiConfig,
+
+    /// Site name, logo, accent color and footer links for self-hosted deployments
+    #[serde(default)]
+    pub branding: BrandingConfig,
+
+    /// TOML file replacing question texts of the bundled inventories (typo fixes, phrasing)
+    #[serde(default)]
+    pub question_overrides: Option<PathBuf>,
+
+    /// Texts loaded from [`Self::question_overrides`], by language and question id
+    #[serde(skip)]
+    pub question_texts: TextOverrides,
+
+    /// Directory with `<lang>.toml` files replacing or adding interface strings
+    #[serde(default)]
+    pub translations_dir: Option<PathBuf>,
+
+    /// Strings loaded from [`Self::translations_dir`], by language and translation key
+    #[serde(skip)]
+    pub ui_texts: UiTexts,
+
+    /// Branded instances served from this process, chosen by the request's hostname
+    #[serde(default)]
+    pub tenants: Vec<TenantConfig>,
+
+    /// Available model presets; required while AI features are enabled
+    #[serde(default)]
     pub models: Vec<ModelPreset>,
 }
 
@@ -71,12 +238,115 @@ impl AiConfig {
     /// Validate the configuration.
     fn validate(&self) -> Result<(), ConfigError> {
         // Must have at least one model
-        if self.models.is_empty() {
+        if self.ai_enabled && self.models.is_empty() {
             return Err(ConfigError::Validation(
                 "At least one model preset is required".to_string(),
             ));
         }
 
+        if self.max_context_chars == 0 {
+            return Err(ConfigError::Validation(
+                "max_context_chars must be greater than 0".to_string(),
+            ));
+        }
+
+        if self.max_prompt_chars <= self.max_context_chars {
+            return Err(ConfigError::Validation(
+                "max_prompt_chars must be greater than max_context_chars".to_string(),
+            ));
+        }
+
+        if self.max_analysis_chars == 0 {
+            return Err(ConfigError::Validation(
+                "max_analysis_chars must be greater than 0".to_string(),
+            ));
+        }
+
+        if self.max_continuations > 5 {
+            return Err(ConfigError::Validation(
+                "max_continuations must be at most 5".to_string(),
+            ));
+        }
+
+        if self.deleted_result_grace_days > 365 {
+            return Err(ConfigError::Validation(
+                "deleted_result_grace_days must be at most 365".to_string(),
+            ));
+        }
+
+        if self.save_pow_difficulty > 24 {
+            return Err(ConfigError::Validation(
+                "save_pow_difficulty must be at most 24".to_string(),
+            ));
+        }
+
+        self.branding.validate()?;
+        if let Some(ref alerts) = self.provider_alerts {
+            alerts.validate()?;
+        }
+        self.audit_log.validate()?;
+        self.analysis_quota.validate()?;
+        self.summary_api.validate()?;
+
+        // Overrides may only reword existing questions
+        for (lang, texts) in &self.question_texts {
+            bigfive::Ipip120::new(lang)
+                .and_then(|inventory| inventory.with_text_overrides(texts))
+                .map_err(|e| {
+                    ConfigError::Validation(format!("question_overrides [{lang}]: {e}"))
+                })?;
+        }
+
+        // Translation files may only cover existing languages and keys
+        for (lang, texts) in &self.ui_texts {
+            if !Locale::ALL.iter().any(|l| l.code() == lang) {
+                return Err(ConfigError::Validation(format!(
+                    "translations_dir: unsupported language '{lang}'"
+                )));
+            }
+            for (key, text) in texts {
+                if !crate::i18n::is_known_key(key) {
+                    return Err(ConfigError::Validation(format!(
+                        "translations_dir [{lang}]: unknown key '{key}'"
+                    )));
+                }
+                if text.trim().is_empty() {
+                    return Err(ConfigError::Validation(format!(
+                        "translations_dir [{lang}]: empty text for '{key}'"
+                    )));
+                }
+            }
+        }
+
+        if self.ai_enabled {
+            self.validate_ai()?;
+        }
+
+        for (i, tenant) in self.tenants.iter().enumerate() {
+            tenant.validate(&format!("tenants[{}]", i), self)?;
+            if self.tenants[..i].iter().any(|t| t.id == tenant.id) {
+                return Err(ConfigError::Validation(format!(
+                    "tenants[{}].id: duplicate tenant id '{}'",
+                    i, tenant.id
+                )));
+            }
+            if let Some(host) = tenant
+                .hosts
+                .iter()
+                .find(|host| self.tenants[..i].iter().any(|t| t.serves_host(host)))
+            {
+                return Err(ConfigError::Validation(format!(
+                    "tenants[{}].hosts: '{}' is already served by another tenant",
+                    i, host
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Validate the model presets and AI settings, which are unused while AI is disabled.
+    fn validate_ai(&self) -> Result<(), ConfigError> {
         // Validate safeguard API if present
         if let Some(ref safeguard) = self.safeguard
             && safeguard.enabled
@@ -84,11 +354,31 @@ impl AiConfig {
             safeguard.api.validate("safeguard.api")?;
         }
 
+        if let Some(ref explanations) = self.facet_explanations
+            && explanations.enabled
+        {
+            explanations.api.validate("facet_explanations.api")?;
+        }
+
         // Validate each model preset
         for (i, preset) in self.models.iter().enumerate() {
             preset.validate(&format!("models[{}]", i))?;
         }
 
+        // Every interface language needs at least one preset
+        for locale in Locale::ALL {
+            if !self.models.iter().any(|m| m.supports_locale(locale.code())) {
+                return Err(ConfigError::Validation(format!(
+                    "No model preset is available for interface language '{}'",
+                    locale.code()
+                )));
+            }
+        }
+
+        for (i, rule) in self.default_model_rules.iter().enumerate() {
+            rule.validate(&format!("default_model_rules[{}]", i), self)?;
+        }
+
         // Check that exactly one model is marked as default (or none, then first is default)
         let default_count = self.models.iter().filter(|m| m.default).count();
         if default_count > 1 {
@@ -100,6 +390,11 @@ impl AiConfig {
         Ok(())
     }
 
+    /// Whether facet explanations are offered.
+    pub fn facet_explanations_enabled(&self) -> bool {
+        self.ai_enabled && self.facet_explanations.as_ref().is_some_and(|e| e.enabled)
+    }
+
     /// Get the default model preset.
     pub fn default_model(&self) -> &ModelPreset {
         self.models
@@ -112,6 +407,197 @@ impl AiConfig {
     pub fn get_model(&self, id: &str) -> Option<&ModelPreset> {
         self.models.iter().find(|m| m.id == id)
     }
+
+    /// Default preset for a request, applying [`Self::default_model_rules`].
+    ///
+    /// Only presets the tenant offers are considered. Falls back to the tenant's default, the
+    /// configured default, then to the first preset offered for the locale.
+    pub fn default_model_for(
+        &self,
+        tenant: Option<&TenantConfig>,
+        locale: &str,
+        country: Option<&str>,
+    ) -> &ModelPreset {
+        let offered = |preset: &&ModelPreset| {
+            preset.supports_locale(locale) && tenant.is_none_or(|t| t.offers_model(&preset.id))
+        };
+        self.default_model_rules
+            .iter()
+            .filter(|rule| rule.matches(locale, country))
+            .filter_map(|rule| self.get_model(&rule.model))
+            .find(offered)
+            .or_else(|| {
+                tenant
+                    .and_then(|t| t.default_model.as_deref())
+                    .and_then(|id| self.get_model(id))
+                    .filter(offered)
+            })
+            .or_else(|| Some(self.default_model()).filter(offered))
+            .or_else(|| self.models.iter().find(offered))
+            .unwrap_or_else(|| self.default_model())
+    }
+
+    /// Tenant serving `host` (a Host header value, port optional); `None` for the default site.
+    pub fn tenant_for_host(&self, host: &str) -> Option<&TenantConfig> {
+        let host = host.rsplit_once(':').map_or(host, |(name, port)| {
+            if port.chars().all(|c| c.is_ascii_digit()) {
+                name
+            } else {
+                host
+            }
+        });
+        self.tenants.iter().find(|t| t.serves_host(host))
+    }
+
+    /// Branding of a tenant, or of the default site.
+    pub fn branding_for<'a>(&'a self, tenant: Option<&'a TenantConfig>) -> &'a BrandingConfig {
+        tenant
+            .and_then(|t| t.branding.as_ref())
+            .unwrap_or(&self.branding)
+    }
+}
+
+/// A branded instance served on its own hostnames.
+#[derive(Debug, Deserialize)]
+pub struct TenantConfig {
+    /// Identifier keeping the tenant's shared results and resume codes apart (letters,
+    /// digits, `-` and `_`)
+    pub id: String,
+
+    /// Hostnames served as this tenant (e.g. `["tests.example.com"]`), without port
+    pub hosts: Vec<String>,
+
+    /// IDs of the model presets offered; empty means all
+    #[serde(default)]
+    pub models: Vec<String>,
+
+    /// ID of the preset used as the default when no default model rule applies
+    #[serde(default)]
+    pub default_model: Option<String>,
+
+    /// Branding replacing the top-level `[branding]` section
+    #[serde(default)]
+    pub branding: Option<BrandingConfig>,
+}
+
+impl TenantConfig {
+    /// Validate the tenant against the loaded presets.
+    fn validate(&self, section: &str, config: &AiConfig) -> Result<(), ConfigError> {
+        if self.id.is_empty()
+            || !self
+                .id
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_')
+        {
+            return Err(ConfigError::Validation(format!(
+                "{}.id: expected letters, digits, '-' and '_', got '{}'",
+                section, self.id
+            )));
+        }
+        if self.hosts.is_empty() {
+            return Err(ConfigError::Validation(format!(
+                "{}.hosts: at least one hostname is required",
+                section
+            )));
+        }
+        if let Some(unknown) = self
+            .models
+            .iter()
+            .chain(&self.default_model)
+            .find(|id| config.get_model(id).is_none())
+        {
+            return Err(ConfigError::Validation(format!(
+                "{}: unknown model preset '{}'",
+                section, unknown
+            )));
+        }
+        if let Some(ref default) = self.default_model
+            && !self.offers_model(default)
+        {
+            return Err(ConfigError::Validation(format!(
+                "{}.default_model: '{}' is not one of the tenant's models",
+                section, default
+            )));
+        }
+        for locale in Locale::ALL {
+            if !config
+                .models
+                .iter()
+                .any(|m| m.supports_locale(locale.code()) && self.offers_model(&m.id))
+            {
+                return Err(ConfigError::Validation(format!(
+                    "{}: no model preset is available for interface language '{}'",
+                    section,
+                    locale.code()
+                )));
+            }
+        }
+        if let Some(ref branding) = self.branding {
+            branding.validate()?;
+        }
+        Ok(())
+    }
+
+    /// Whether the tenant offers the preset with this ID.
+    pub fn offers_model(&self, id: &str) -> bool {
+        self.models.is_empty() || self.models.iter().any(|m| m == id)
+    }
+
+    /// Whether `host` (without port) is one of the tenant's hostnames.
+    fn serves_host(&self, host: &str) -> bool {
+        self.hosts.iter().any(|h| h.eq_ignore_ascii_case(host))
+    }
+}
+
+/// Rule choosing the default model preset for some requests.
+#[derive(Debug, Deserialize)]
+pub struct DefaultModelRule {
+    /// Interface language the rule applies to (e.g. "ru"); any if unset
+    #[serde(default)]
+    pub locale: Option<String>,
+
+    /// ISO country code from [`AiConfig::country_header`] (e.g. "KZ"); any if unset
+    #[serde(default)]
+    pub country: Option<String>,
+
+    /// ID of the preset to use as the default
+    pub model: String,
+}
+
+impl DefaultModelRule {
+    /// Validate the rule against the loaded presets.
+    fn validate(&self, section: &str, config: &AiConfig) -> Result<(), ConfigError> {
+        if self.locale.is_none() && self.country.is_none() {
+            return Err(ConfigError::Validation(format!(
+                "{}: set at least one of locale or country",
+                section
+            )));
+        }
+        if let Some(ref locale) = self.locale
+            && !Locale::ALL.iter().any(|l| l.code() == locale.as_str())
+        {
+            return Err(ConfigError::Validation(format!(
+                "{}.locale: unknown interface language '{}'",
+                section, locale
+            )));
+        }
+        if config.get_model(&self.model).is_none() {
+            return Err(ConfigError::Validation(format!(
+                "{}.model: unknown model preset '{}'",
+                section, self.model
+            )));
+        }
+        Ok(())
+    }
+
+    /// Whether the rule applies to a request.
+    fn matches(&self, locale: &str, country: Option<&str>) -> bool {
+        self.locale.as_deref().is_none_or(|l| l == locale)
+            && self
+                .country
+                .as_deref()
+                .is_none_or(|c| country.is_some_and(|country| c.eq_ignore_ascii_case(country)))
+    }
 }
 
 /// API configuration for a provider.
@@ -120,11 +606,16 @@ pub struct ApiConfig {
     /// API provider type
     pub provider: Provider,
 
-    /// Name of environment variable containing the API key
+    /// Name of environment variable containing the API key (not needed for `mock`)
+    #[serde(default)]
     pub api_key_env: String,
 
     /// API endpoint URL (required for OpenAI-compatible providers)
     pub api_url: Option<String>,
+
+    /// Simulated response time of the `mock` provider, in milliseconds
+    #[serde(default = "default_mock_delay_ms")]
+    pub mock_delay_ms: u64,
 }
 
 impl ApiConfig {
@@ -135,6 +626,12 @@ impl ApiConfig {
                 "[{section}] api_url is required for 'openai' provider"
             )));
         }
+        if self.provider != Provider::Mock && self.api_key_env.is_empty() {
+            return Err(ConfigError::Validation(format!(
+                "[{section}] api_key_env is required for '{}' provider",
+                self.provider.as_str()
+            )));
+        }
         Ok(())
     }
 
@@ -149,7 +646,28 @@ impl ApiConfig {
     }
 }
 
-pub use llm_relay::types::common::Provider;
+/// LLM provider type.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Provider {
+    #[default]
+    Anthropic,
+    #[serde(alias = "openai")]
+    OpenAiCompatible,
+    /// Canned responses without network calls, for development and demos
+    Mock,
+}
+
+impl Provider {
+    /// Name used in the config file.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Anthropic => "anthropic",
+            Self::OpenAiCompatible => "openai",
+            Self::Mock => "mock",
+        }
+    }
+}
 
 /// Safeguard configuration for prompt injection protection.
 #[derive(Debug, Deserialize)]
@@ -169,6 +687,363 @@ pub struct SafeguardConfig {
     pub api: ApiConfig,
 }
 
+/// Model answering per-facet "explain this" requests.
+#[derive(Debug, Deserialize)]
+pub struct FacetExplanationsConfig {
+    /// Whether the explain action is offered
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Model to use; a small, cheap one is enough
+    pub model: String,
+
+    /// Maximum tokens for an explanation
+    #[serde(default = "default_facet_explanation_max_tokens")]
+    pub max_tokens: u32,
+
+    /// API configuration for the model
+    pub api: ApiConfig,
+}
+
+/// When and where to report a model preset whose analyses keep failing.
+#[derive(Debug, Deserialize)]
+pub struct ProviderAlertsConfig {
+    /// URL receiving a JSON `POST` per alert
+    pub webhook_url: String,
+
+    /// Share of failed analyses (0-1] within the window that triggers an alert
+    #[serde(default = "default_alert_error_rate")]
+    pub error_rate: f64,
+
+    /// Analyses a preset needs within the window before its error rate counts
+    #[serde(default = "default_alert_min_calls")]
+    pub min_calls: usize,
+
+    /// Length of the sliding window, in seconds
+    #[serde(default = "default_alert_window_secs")]
+    pub window_secs: u64,
+
+    /// Seconds before the same preset can trigger another alert
+    #[serde(default = "default_alert_cooldown_secs")]
+    pub cooldown_secs: u64,
+}
+
+impl ProviderAlertsConfig {
+    /// Validate the alert settings.
+    fn validate(&self) -> Result<(), ConfigError> {
+        if !(self.webhook_url.starts_with("https://") || self.webhook_url.starts_with("http://")) {
+            return Err(ConfigError::Validation(format!(
+                "provider_alerts.webhook_url: expected an http(s) URL, got '{}'",
+                self.webhook_url
+            )));
+        }
+        if !(self.error_rate > 0.0 && self.error_rate <= 1.0) {
+            return Err(ConfigError::Validation(
+                "provider_alerts.error_rate must be above 0 and at most 1".to_string(),
+            ));
+        }
+        if self.min_calls == 0 {
+            return Err(ConfigError::Validation(
+                "provider_alerts.min_calls must be greater than 0".to_string(),
+            ));
+        }
+        if self.window_secs == 0 {
+            return Err(ConfigError::Validation(
+                "provider_alerts.window_secs must be greater than 0".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Recording of accesses to shared results.
+#[derive(Debug, Deserialize)]
+pub struct AuditLogConfig {
+    /// Whether accesses are recorded (off by default)
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Days an entry is kept before the cleanup job removes it
+    #[serde(default = "default_audit_retention_days")]
+    pub retention_days: u32,
+}
+
+impl AuditLogConfig {
+    /// Validate the retention period.
+    fn validate(&self) -> Result<(), ConfigError> {
+        if !(1..=3650).contains(&self.retention_days) {
+            return Err(ConfigError::Validation(
+                "audit_log.retention_days must be between 1 and 3650".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl Default for AuditLogConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            retention_days: default_audit_retention_days(),
+        }
+    }
+}
+
+/// Per-client daily quota of AI analyses.
+#[derive(Debug, Deserialize)]
+pub struct AnalysisQuotaConfig {
+    /// Whether the quota is enforced (off by default)
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Analyses a client address may start per UTC day
+    #[serde(default = "default_analyses_per_day")]
+    pub per_day: u32,
+}
+
+impl AnalysisQuotaConfig {
+    /// Validate the daily allowance.
+    fn validate(&self) -> Result<(), ConfigError> {
+        if !(1..=1000).contains(&self.per_day) {
+            return Err(ConfigError::Validation(
+                "analysis_quota.per_day must be between 1 and 1000".to_string(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+impl Default for AnalysisQuotaConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            per_day: default_analyses_per_day(),
+        }
+    }
+}
+
+/// `GET /api/v1/results/:id/summary` for third-party dashboards.
+#[derive(Debug, Default, Deserialize)]
+pub struct SummaryApiConfig {
+    /// Whether the endpoint answers (off by default)
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Origins whose pages may call the endpoint from a browser, or `"*"` for any
+    #[serde(default)]
+    pub allowed_origins: Vec<String>,
+
+    /// Environment variable with comma-separated API keys; when set, requests need one of
+    /// them in the `X-Api-Key` header
+    #[serde(default)]
+    pub api_keys_env: Option<String>,
+}
+
+impl SummaryApiConfig {
+    /// Validate the allowed origins.
+    fn validate(&self) -> Result<(), ConfigError> {
+        for origin in &self.allowed_origins {
+            let valid = origin == "*"
+                || ((origin.starts_with("https://") || origin.starts_with("http://"))
+                    && !origin.ends_with('/'));
+            if !valid {
+                return Err(ConfigError::Validation(format!(
+                    "summary_api.allowed_origins: '{origin}' must be \"*\" or a scheme and \
+                     host without a trailing slash"
+                )));
+            }
+        }
+        if self.api_keys_env.as_deref().is_some_and(str::is_empty) {
+            return Err(ConfigError::Validation(
+                "summary_api.api_keys_env must not be empty".to_string(),
+            ));
+        }
+        Ok(())
+    }
+
+    /// Whether pages on `origin` may read responses.
+    pub fn allows_origin(&self, origin: &str) -> bool {
+        self.allowed_origins
+            .iter()
+            .any(|allowed| allowed == "*" || allowed == origin)
+    }
+}
+
+/// Which kinds of personal data to mask in the user context.
+#[derive(Debug, Deserialize)]
+pub struct PiiScrubbingConfig {
+    /// Whether scrubbing is enabled (off by default)
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Mask email addresses
+    #[serde(default = "default_true")]
+    pub emails: bool,
+
+    /// Mask phone numbers
+    #[serde(default = "default_true")]
+    pub phones: bool,
+
+    /// Mask full names (capitalized first + last name pairs)
+    #[serde(default = "default_true")]
+    pub names: bool,
+}
+
+impl Default for PiiScrubbingConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            emails: true,
+            phones: true,
+            names: true,
+        }
+    }
+}
+
+/// Which peers may tell the client's IP address, and in which header.
+#[derive(Debug, Deserialize)]
+pub struct ProxyConfig {
+    /// Networks of the reverse proxies in front of the app (loopback only by default)
+    #[serde(default = "default_trusted_proxies")]
+    pub trusted: Vec<IpNet>,
+
+    /// Header the proxies put the client IP in
+    #[serde(default)]
+    pub client_ip_header: ClientIpHeader,
+}
+
+impl Default for ProxyConfig {
+    fn default() -> Self {
+        Self {
+            trusted: default_trusted_proxies(),
+            client_ip_header: ClientIpHeader::default(),
+        }
+    }
+}
+
+impl ProxyConfig {
+    /// Whether `ip` belongs to a trusted proxy.
+    pub fn trusts(&self, ip: IpAddr) -> bool {
+        // IPv4 peers may show up as IPv4-mapped IPv6 addresses on dual-stack sockets
+        let ip = match ip {
+            IpAddr::V6(v6) => v6.to_ipv4_mapped().map_or(ip, IpAddr::V4),
+            ip => ip,
+        };
+        self.trusted.iter().any(|net| net.contains(&ip))
+    }
+}
+
+/// Forwarding header carrying the client IP.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ClientIpHeader {
+    /// `X-Forwarded-For`, read from the right past trusted proxies (nginx, Caddy, Traefik)
+    #[default]
+    XForwardedFor,
+    /// `CF-Connecting-IP`, set by Cloudflare
+    CfConnectingIp,
+    /// `X-Real-IP`, a single address set by the proxy
+    XRealIp,
+}
+
+impl ClientIpHeader {
+    /// Lowercase header name.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::XForwardedFor => "x-forwarded-for",
+            Self::CfConnectingIp => "cf-connecting-ip",
+            Self::XRealIp => "x-real-ip",
+        }
+    }
+}
+
+fn default_trusted_proxies() -> Vec<IpNet> {
+    ["127.0.0.0/8", "::1/128"]
+        .iter()
+        .map(|net| net.parse().expect("valid network"))
+        .collect()
+}
+
+/// White-label settings; every field falls back to the stock look when unset.
+#[derive(Debug, Default, Deserialize)]
+pub struct BrandingConfig {
+    /// Site name shown in the header and page title instead of the localized test name
+    #[serde(default)]
+    pub site_name: Option<String>,
+
+    /// URL of a logo image shown next to the site name
+    #[serde(default)]
+    pub logo_url: Option<String>,
+
+    /// Accent color replacing the default indigo, as `#rrggbb`
+    #[serde(default)]
+    pub accent_color: Option<String>,
+
+    /// Extra links appended to the footer navigation
+    #[serde(default)]
+    pub footer_links: Vec<FooterLink>,
+}
+
+/// A footer link from [`BrandingConfig::footer_links`].
+#[derive(Debug, Deserialize)]
+pub struct FooterLink {
+    pub label: String,
+    /// Absolute `http(s)://` URL or a site path starting with `/`
+    pub url: String,
+}
+
+impl BrandingConfig {
+    /// Validate the branding settings.
+    fn validate(&self) -> Result<(), ConfigError> {
+        let is_url = |url: &str| {
+            url.starts_with("https://") || url.starts_with("http://") || url.starts_with('/')
+        };
+
+        if self
+            .site_name
+            .as_deref()
+            .is_some_and(|n| n.trim().is_empty())
+        {
+            return Err(ConfigError::Validation(
+                "branding.site_name must not be empty".to_string(),
+            ));
+        }
+        if let Some(ref logo) = self.logo_url
+            && !is_url(logo)
+        {
+            return Err(ConfigError::Validation(format!(
+                "branding.logo_url: expected an http(s) URL or a path, got '{}'",
+                logo
+            )));
+        }
+        if let Some(ref color) = self.accent_color
+            && !(color.len() == 7
+                && color.starts_with('#')
+                && color[1..].chars().all(|c| c.is_ascii_hexdigit()))
+        {
+            return Err(ConfigError::Validation(format!(
+                "branding.accent_color: expected a color like '#0d9488', got '{}'",
+                color
+            )));
+        }
+        for (i, link) in self.footer_links.iter().enumerate() {
+            if link.label.trim().is_empty() {
+                return Err(ConfigError::Validation(format!(
+                    "branding.footer_links[{}].label must not be empty",
+                    i
+                )));
+            }
+            if !is_url(&link.url) {
+                return Err(ConfigError::Validation(format!(
+                    "branding.footer_links[{}].url: expected an http(s) URL or a path, got '{}'",
+                    i, link.url
+                )));
+            }
+        }
+        Ok(())
+    }
+}
+
 /// Model preset configuration.
 #[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct ModelPreset {
@@ -192,6 +1067,14 @@ pub struct ModelPreset {
     #[serde(default)]
     pub default: bool,
 
+    /// Optional short description shown on the model card
+    #[serde(default)]
+    pub description: Option<String>,
+
+    /// Optional typical response speed shown on the model card
+    #[serde(default)]
+    pub speed: Option<ModelSpeed>,
+
     /// API configuration for this model
     pub api: ApiConfig,
 
@@ -201,6 +1084,10 @@ pub struct ModelPreset {
 
     /// Optional translation configuration
     pub translation: Option<TranslationConfig>,
+
+    /// Interface languages this preset is offered for (e.g. `["ru"]`); empty means all
+    #[serde(default)]
+    pub locales: Vec<String>,
 }
 
 impl ModelPreset {
@@ -214,8 +1101,24 @@ impl ModelPreset {
                 .validate(&format!("{}.translation.api", section))?;
         }
 
+        if let Some(unknown) = self
+            .locales
+            .iter()
+            .find(|code| !Locale::ALL.iter().any(|l| l.code() == code.as_str()))
+        {
+            return Err(ConfigError::Validation(format!(
+                "{}.locales: unknown interface language '{}'",
+                section, unknown
+            )));
+        }
+
         Ok(())
     }
+
+    /// Whether this preset is offered for the given interface language.
+    pub fn supports_locale(&self, code: &str) -> bool {
+        self.locales.is_empty() || self.locales.iter().any(|l| l == code)
+    }
 }
 
 pub use llm_relay::types::common::{EffortLevel, ThinkingConfig};
@@ -266,6 +1169,26 @@ impl SourceLanguage {
     }
 }
 
+/// Typical response speed of a model preset.
+#[derive(Debug, Clone, Copy, Deserialize, Serialize, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum ModelSpeed {
+    Fast,
+    Medium,
+    Slow,
+}
+
+impl ModelSpeed {
+    /// Get the speed code.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Fast => "fast",
+            Self::Medium => "medium",
+            Self::Slow => "slow",
+        }
+    }
+}
+
 /// Model info for client (subset of ModelPreset)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ModelInfo {
@@ -285,10 +1208,66 @@ impl From<&ModelPreset> for ModelInfo {
 }
 
 // Default values
+fn default_true() -> bool {
+    true
+}
+
+fn default_max_context_chars() -> usize {
+    2000
+}
+
+fn default_max_prompt_chars() -> usize {
+    16000
+}
+
+fn default_max_analysis_chars() -> usize {
+    30000
+}
+
+fn default_max_continuations() -> u32 {
+    1
+}
+
+fn default_deleted_result_grace_days() -> u32 {
+    7
+}
+
+fn default_alert_error_rate() -> f64 {
+    0.5
+}
+
+fn default_alert_min_calls() -> usize {
+    5
+}
+
+fn default_alert_window_secs() -> u64 {
+    900
+}
+
+fn default_alert_cooldown_secs() -> u64 {
+    3600
+}
+
+fn default_audit_retention_days() -> u32 {
+    90
+}
+
+fn default_analyses_per_day() -> u32 {
+    5
+}
+
+fn default_mock_delay_ms() -> u64 {
+    3000
+}
+
 fn default_safeguard_max_tokens() -> u32 {
     1024
 }
 
+fn default_facet_explanation_max_tokens() -> u32 {
+    400
+}
+
 fn default_analysis_max_tokens() -> u32 {
     8192
 }
@@ -1,18 +1,72 @@
 //! Database module using Turso (embedded SQLite).
 //!
-//! Stores personality test results for shareable URLs.
+//! Stores personality test results for shareable URLs. Results go through the
+//! [`ResultStore`] picked by [`init_database`]; everything else lives in the database.
 
 use anyhow::{Context, Result};
-use bigfive::PersonalityProfile;
+use bigfive::{Demographics, Domain, PersonalityProfile, ProfileMeans, ScoreDistribution};
 use serde::{Deserialize, Serialize};
-use std::sync::Arc;
+use std::collections::HashMap;
+use std::sync::{Arc, LazyLock, Mutex, OnceLock};
+use std::time::{Duration, Instant};
 use tokio::sync::OnceCell;
 use tracing::info;
 use turso::{Builder, Connection, Database};
 
+use crate::ai::debug_log::ProviderCall;
+use crate::auth::tokens_match;
+use crate::config::get_config;
+use crate::result_cache;
+use crate::store::{MemoryResultStore, ProfileFilter, ResultStore, TursoResultStore};
+
 /// Global database instance
 static DATABASE: OnceCell<Arc<Database>> = OnceCell::const_new();
 
+/// Where saved results are kept, chosen by [`init_database`].
+static RESULT_STORE: OnceLock<Box<dyn ResultStore>> = OnceLock::new();
+
+/// `DATABASE_PATH` that keeps everything in memory.
+pub const IN_MEMORY_PATH: &str = ":memory:";
+
+/// How long computed score means are reused before querying again.
+const PROFILE_MEANS_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// Score means with the time they were computed, per tenant namespace.
+type CachedMeans = HashMap<String, (Instant, Option<ProfileMeans>)>;
+
+static PROFILE_MEANS: LazyLock<Mutex<CachedMeans>> = LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Score distributions with the time they were computed, per [`NormFilter`].
+type CachedDistributions = HashMap<NormFilter, (Instant, Option<ScoreDistribution>)>;
+
+static SCORE_DISTRIBUTIONS: LazyLock<Mutex<CachedDistributions>> =
+    LazyLock::new(|| Mutex::new(HashMap::new()));
+
+/// Most norm groups kept in [`SCORE_DISTRIBUTIONS`]; the oldest entry makes room beyond that.
+const MAX_CACHED_DISTRIBUTIONS: usize = 512;
+
+/// Columns added to `results` after the initial schema.
+/// Applied on startup so existing databases pick them up.
+const RESULTS_MIGRATIONS: &[(&str, &str)] = &[
+    ("owner_token", "TEXT"),
+    ("nickname", "TEXT"),
+    ("expires_at", "INTEGER"),
+    ("access_token", "TEXT"),
+    ("prompt_version", "TEXT"),
+    ("unpublished", "INTEGER"),
+    ("deleted_at", "INTEGER"),
+    ("demographics_json", "TEXT"),
+    ("tenant", "TEXT"),
+    ("analysis_consented_at", "INTEGER"),
+    ("idempotency_key", "TEXT"),
+];
+
+/// Columns added to `analysis_feedback` after the initial schema.
+const FEEDBACK_MIGRATIONS: &[(&str, &str)] = &[("prompt_version", "TEXT")];
+
+/// Columns added to `sessions` after the initial schema.
+const SESSIONS_MIGRATIONS: &[(&str, &str)] = &[("tenant", "TEXT")];
+
 /// A saved test result.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SavedResult {
@@ -20,14 +74,56 @@ pub struct SavedResult {
     pub profile: PersonalityProfile,
     pub user_context: Option<String>,
     pub ai_analysis: Option<String>,
+    /// Prompt template version the analysis was generated with, if known.
+    pub prompt_version: Option<String>,
     pub lang: String,
     pub created_at: i64,
+    /// Optional display name/emoji chosen by the owner.
+    pub nickname: Option<String>,
+    /// Unix timestamp after which the link stops working, if any.
+    pub expires_at: Option<i64>,
+    /// Secret from the link fragment required to see notes and analysis, if protected.
+    pub access_token: Option<String>,
+    /// Secret issued to the browser that saved the result.
+    pub owner_token: Option<String>,
+    /// Hidden from the shared link by a moderator.
+    pub unpublished: bool,
+    /// When the owner deleted it (Unix seconds); purged after the grace period.
+    pub deleted_at: Option<i64>,
+    /// Details the respondent chose to give before the test.
+    pub demographics: Option<Demographics>,
+}
+
+impl SavedResult {
+    /// Whether `key` unlocks the full result.
+    ///
+    /// Unprotected results are always unlocked; protected ones need the access token
+    /// from the link or the owner token.
+    pub fn is_unlocked_by(&self, key: Option<&str>) -> bool {
+        let Some(access_token) = &self.access_token else {
+            return true;
+        };
+        let Some(key) = key else {
+            return false;
+        };
+        // Both tokens are always compared, in constant time, so the time taken doesn't
+        // reveal which one matched or where a guess differs
+        let matches_owner = self
+            .owner_token
+            .as_deref()
+            .is_some_and(|owner| tokens_match(owner, key));
+        tokens_match(access_token, key) | matches_owner
+    }
 }
 
 /// Initialize the database and create tables.
+///
+/// With [`IN_MEMORY_PATH`], results go to a [`MemoryResultStore`] and the remaining tables
+/// to an in-memory database, so nothing is written to disk.
 pub async fn init_database(path: &str) -> Result<()> {
+    let in_memory = path == IN_MEMORY_PATH;
     // Ensure directory exists
-    if let Some(parent) = std::path::Path::new(path).parent() {
+    if !in_memory && let Some(parent) = std::path::Path::new(path).parent() {
         std::fs::create_dir_all(parent).context("Failed to create database directory")?;
     }
 
@@ -54,14 +150,275 @@ pub async fn init_database(path: &str) -> Result<()> {
     .await
     .context("Failed to create results table")?;
 
+    add_missing_columns(&conn, "results", RESULTS_MIGRATIONS).await?;
+
+    conn.execute(
+        r#"
+        CREATE TABLE IF NOT EXISTS analysis_feedback (
+            id TEXT PRIMARY KEY,
+            result_id TEXT,
+            model_id TEXT,
+            helpful INTEGER NOT NULL,
+            comment TEXT,
+            lang TEXT NOT NULL DEFAULT 'en',
+            created_at INTEGER NOT NULL
+        )
+        "#,
+        (),
+    )
+    .await
+    .context("Failed to create analysis_feedback table")?;
+
+    add_missing_columns(&conn, "analysis_feedback", FEEDBACK_MIGRATIONS).await?;
+
+    conn.execute(
+        r#"
+        CREATE TABLE IF NOT EXISTS sessions (
+            code TEXT PRIMARY KEY,
+            answers_json TEXT NOT NULL,
+            current_index INTEGER NOT NULL,
+            lang TEXT NOT NULL DEFAULT 'en',
+            created_at INTEGER NOT NULL,
+            expires_at INTEGER NOT NULL
+        )
+        "#,
+        (),
+    )
+    .await
+    .context("Failed to create sessions table")?;
+
+    add_missing_columns(&conn, "sessions", SESSIONS_MIGRATIONS).await?;
+
+    conn.execute(
+        r#"
+        CREATE TABLE IF NOT EXISTS analysis_log (
+            id TEXT PRIMARY KEY,
+            model_id TEXT NOT NULL,
+            lang TEXT NOT NULL,
+            success INTEGER NOT NULL,
+            created_at INTEGER NOT NULL
+        )
+        "#,
+        (),
+    )
+    .await
+    .context("Failed to create analysis_log table")?;
+
+    let has_tenants = get_config().is_ok_and(|config| !config.tenants.is_empty());
+    migrate_daily_stats(&conn, !has_tenants).await?;
+    conn.execute(
+        format!("CREATE TABLE IF NOT EXISTS daily_stats {DAILY_STATS_SCHEMA}"),
+        (),
+    )
+    .await
+    .context("Failed to create daily_stats table")?;
+
+    // Deliberately unlinked from results: no id, timestamp or free text
+    conn.execute(
+        r#"
+        CREATE TABLE IF NOT EXISTS research_responses (
+            answers_json TEXT NOT NULL,
+            lang TEXT NOT NULL,
+            age_band TEXT,
+            gender TEXT
+        )
+        "#,
+        (),
+    )
+    .await
+    .context("Failed to create research_responses table")?;
+
+    // Running totals per question and language only; a test's timings are added, not stored
+    conn.execute(
+        r#"
+        CREATE TABLE IF NOT EXISTS question_stats (
+            question_id TEXT NOT NULL,
+            lang TEXT NOT NULL,
+            responses INTEGER NOT NULL,
+            total_ms INTEGER NOT NULL,
+            revisits INTEGER NOT NULL,
+            PRIMARY KEY (question_id, lang)
+        )
+        "#,
+        (),
+    )
+    .await
+    .context("Failed to create question_stats table")?;
+
+    conn.execute(
+        r#"
+        CREATE TABLE IF NOT EXISTS facet_explanations (
+            facet TEXT NOT NULL,
+            level TEXT NOT NULL,
+            lang TEXT NOT NULL,
+            text TEXT NOT NULL,
+            created_at INTEGER NOT NULL,
+            PRIMARY KEY (facet, level, lang)
+        )
+        "#,
+        (),
+    )
+    .await
+    .context("Failed to create facet_explanations table")?;
+
+    conn.execute(
+        r#"
+        CREATE TABLE IF NOT EXISTS provider_logs (
+            job_id TEXT PRIMARY KEY,
+            model_id TEXT NOT NULL,
+            error TEXT NOT NULL,
+            calls_json TEXT NOT NULL,
+            created_at INTEGER NOT NULL
+        )
+        "#,
+        (),
+    )
+    .await
+    .context("Failed to create provider_logs table")?;
+
+    conn.execute(
+        r#"
+        CREATE TABLE IF NOT EXISTS audit_log (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            result_id TEXT NOT NULL,
+            action TEXT NOT NULL,
+            ip_hash TEXT,
+            tenant TEXT NOT NULL DEFAULT '',
+            created_at INTEGER NOT NULL
+        )
+        "#,
+        (),
+    )
+    .await
+    .context("Failed to create audit_log table")?;
+
+    conn.execute(
+        r#"
+        CREATE TABLE IF NOT EXISTS analysis_quota (
+            client_hash TEXT NOT NULL,
+            day INTEGER NOT NULL,
+            used INTEGER NOT NULL DEFAULT 0,
+            PRIMARY KEY (client_hash, day)
+        )
+        "#,
+        (),
+    )
+    .await
+    .context("Failed to create analysis_quota table")?;
+
     DATABASE
         .set(Arc::new(db))
         .map_err(|_| anyhow::anyhow!("Database already initialized"))?;
+    let store: Box<dyn ResultStore> = if in_memory {
+        Box::new(MemoryResultStore::default())
+    } else {
+        Box::new(TursoResultStore)
+    };
+    RESULT_STORE
+        .set(store)
+        .map_err(|_| anyhow::anyhow!("Result store already initialized"))?;
+
+    if in_memory {
+        info!("Database initialized in memory; results are lost on restart");
+    } else {
+        info!("Database initialized at {}", path);
+    }
+    Ok(())
+}
+
+/// Add any of `columns` that don't exist yet in `table`.
+async fn add_missing_columns(
+    conn: &Connection,
+    table: &str,
+    columns: &[(&str, &str)],
+) -> Result<()> {
+    let mut existing = Vec::new();
+    let mut rows = conn
+        .query(format!("PRAGMA table_info({table})"), ())
+        .await
+        .context("Failed to read table info")?;
+    while let Some(row) = rows.next().await? {
+        existing.push(row.get::<String>(1)?);
+    }
+
+    for (name, definition) in columns {
+        if existing.iter().any(|c| c == name) {
+            continue;
+        }
+        conn.execute(
+            format!("ALTER TABLE {table} ADD COLUMN {name} {definition}"),
+            (),
+        )
+        .await
+        .with_context(|| format!("Failed to add column {table}.{name}"))?;
+        info!("Added column {}.{}", table, name);
+    }
 
-    info!("Database initialized at {}", path);
     Ok(())
 }
 
+/// Columns of `daily_stats`, one row per tenant, day and metric.
+const DAILY_STATS_SCHEMA: &str = "(
+    day INTEGER NOT NULL,
+    metric TEXT NOT NULL,
+    value REAL NOT NULL,
+    tenant TEXT NOT NULL DEFAULT '',
+    PRIMARY KEY (tenant, day, metric)
+)";
+
+/// Rebuild a `daily_stats` table from before tenants, keyed on day and metric alone.
+///
+/// Its rows counted the results of every tenant together. With `keep_rows` (a deployment
+/// without tenants) they become the default site's; otherwise they are dropped and the
+/// statistics job recomputes the last days per tenant.
+async fn migrate_daily_stats(conn: &Connection, keep_rows: bool) -> Result<()> {
+    let mut columns = Vec::new();
+    let mut rows = conn
+        .query("PRAGMA table_info(daily_stats)", ())
+        .await
+        .context("Failed to read table info")?;
+    while let Some(row) = rows.next().await? {
+        columns.push(row.get::<String>(1)?);
+    }
+    if columns.is_empty() || columns.iter().any(|c| c == "tenant") {
+        return Ok(());
+    }
+
+    conn.execute(
+        format!("CREATE TABLE daily_stats_by_tenant {DAILY_STATS_SCHEMA}"),
+        (),
+    )
+    .await
+    .context("Failed to create daily_stats_by_tenant table")?;
+    if keep_rows {
+        conn.execute(
+            "INSERT INTO daily_stats_by_tenant (day, metric, value, tenant) SELECT day, metric, value, '' FROM daily_stats",
+            (),
+        )
+        .await
+        .context("Failed to copy daily stats")?;
+    }
+    conn.execute("DROP TABLE daily_stats", ())
+        .await
+        .context("Failed to drop old daily_stats table")?;
+    conn.execute(
+        "ALTER TABLE daily_stats_by_tenant RENAME TO daily_stats",
+        (),
+    )
+    .await
+    .context("Failed to rename daily_stats_by_tenant table")?;
+    info!("Rebuilt daily_stats with a tenant column (kept old rows: {keep_rows})");
+    Ok(())
+}
+
+/// Current time as a Unix timestamp in seconds.
+fn unix_now() -> Result<i64> {
+    Ok(std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .context("System time error")?
+        .as_secs() as i64)
+}
+
 /// Get a database connection.
 pub fn get_connection() -> Result<Connection> {
     let db = DATABASE
@@ -71,69 +428,1106 @@ pub fn get_connection() -> Result<Connection> {
     db.connect().context("Failed to get database connection")
 }
 
+fn result_store() -> Result<&'static dyn ResultStore> {
+    RESULT_STORE
+        .get()
+        .map(Box::as_ref)
+        .ok_or_else(|| anyhow::anyhow!("Database not initialized"))
+}
+
+/// A test result snapshot to be saved.
+#[derive(Debug, Clone)]
+pub struct NewResult<'a> {
+    pub id: &'a str,
+    pub profile: &'a PersonalityProfile,
+    pub user_context: Option<&'a str>,
+    pub ai_analysis: Option<&'a str>,
+    /// Prompt template version the analysis was generated with, if known.
+    pub prompt_version: Option<&'a str>,
+    /// When the user agreed to send their data to the model provider (Unix seconds).
+    pub analysis_consented_at: Option<i64>,
+    pub lang: &'a str,
+    /// Secret that allows deleting the result later.
+    pub owner_token: &'a str,
+    pub nickname: Option<&'a str>,
+    /// Unix timestamp after which the link stops working, if any.
+    pub expires_at: Option<i64>,
+    /// Secret required to see notes and analysis; `None` for unprotected links.
+    pub access_token: Option<&'a str>,
+    pub demographics: Option<&'a Demographics>,
+    /// Tenant namespace the result belongs to; empty for the default site.
+    pub tenant: &'a str,
+    /// Client-chosen key identifying the save request, for returning this result on retries.
+    pub idempotency_key: Option<&'a str>,
+}
+
 /// Save a test result snapshot to the database.
-pub async fn save_result(
-    id: &str,
-    profile: &PersonalityProfile,
-    user_context: Option<&str>,
-    ai_analysis: Option<&str>,
-    lang: &str,
-) -> Result<()> {
+pub async fn save_result(result: &NewResult<'_>) -> Result<()> {
+    result_store()?.save(result, unix_now()?).await?;
+    // A lookup of the id may have been cached as a miss
+    result_cache::invalidate(result.id);
+
+    Ok(())
+}
+
+/// Secrets of a result, as handed to the browser that saved it.
+#[derive(Debug, Clone)]
+pub struct ResultHandle {
+    pub id: String,
+    pub owner_token: String,
+    pub access_token: Option<String>,
+}
+
+/// The result saved earlier with `key` in `tenant`, unless it was deleted since.
+pub async fn find_result_by_idempotency_key(
+    key: &str,
+    tenant: &str,
+) -> Result<Option<ResultHandle>> {
+    result_store()?.find_by_idempotency_key(key, tenant).await
+}
+
+/// Get a saved result by ID within a tenant namespace.
+///
+/// Expired, unpublished and deleted results, and those of other tenants, are treated as missing.
+///
+/// Served from [`result_cache`] when the same lookup was made recently.
+pub async fn get_result(id: &str, tenant: &str) -> Result<Option<SavedResult>> {
+    let now = unix_now()?;
+    if let Some(result) = result_cache::get(tenant, id, now) {
+        return Ok(result);
+    }
+    let result = result_store()?.get(id, tenant, now).await?;
+    result_cache::insert(tenant, id, result.clone());
+    Ok(result)
+}
+
+/// Filters for [`search_results`]; unset fields match everything.
+#[derive(Debug, Clone, Default)]
+pub struct ResultFilter<'a> {
+    pub id_prefix: Option<&'a str>,
+    pub lang: Option<&'a str>,
+    /// Earliest `created_at` (Unix seconds, inclusive).
+    pub created_from: Option<i64>,
+    /// Latest `created_at` (Unix seconds, exclusive).
+    pub created_to: Option<i64>,
+}
+
+/// Find results for moderation, newest first. Includes expired, unpublished and deleted ones.
+pub async fn search_results(filter: &ResultFilter<'_>, limit: u32) -> Result<Vec<SavedResult>> {
+    result_store()?.search(filter, limit).await
+}
+
+/// Hide a result from its shared link (or show it again) without deleting it.
+///
+/// Returns `false` if no such result exists.
+pub async fn set_result_unpublished(id: &str, unpublished: bool) -> Result<bool> {
+    let updated = result_store()?.set_unpublished(id, unpublished).await?;
+    result_cache::invalidate(id);
+    Ok(updated)
+}
+
+/// Permanently delete a result, regardless of owner. Returns `false` if it didn't exist.
+pub async fn purge_result(id: &str) -> Result<bool> {
+    let purged = result_store()?.purge(id).await?;
+    result_cache::invalidate(id);
+    Ok(purged)
+}
+
+/// Mean scores over the results saved in a tenant namespace, or `None` if there are none.
+///
+/// Cached per tenant for [`PROFILE_MEANS_TTL`], since it reads every stored profile.
+pub async fn profile_means(tenant: &str) -> Result<Option<ProfileMeans>> {
+    if let Some((computed_at, means)) = PROFILE_MEANS.lock().unwrap().get(tenant)
+        && computed_at.elapsed() < PROFILE_MEANS_TTL
+    {
+        return Ok(means.clone());
+    }
+
+    // Rows from older formats are skipped rather than failing the whole aggregate
+    let profiles: Vec<_> = result_store()?
+        .profiles(&ProfileFilter {
+            tenant: Some(tenant),
+            ..ProfileFilter::default()
+        })
+        .await?
+        .into_iter()
+        .filter_map(|p| p.profile)
+        .collect();
+
+    let means = ProfileMeans::from_profiles(&profiles);
+    PROFILE_MEANS
+        .lock()
+        .unwrap()
+        .insert(tenant.to_string(), (Instant::now(), means.clone()));
+    Ok(means)
+}
+
+/// Which saved results of a tenant form a norm group; unset fields match everything.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct NormFilter {
+    /// Namespace of the tenant whose results form the group; empty for the default site.
+    pub tenant: String,
+    pub lang: Option<String>,
+    /// ISO 3166-1 alpha-2 code from the respondent's demographics.
+    pub country: Option<String>,
+}
+
+/// Domain score distribution of the saved results matching `filter`, or `None` if
+/// there are none.
+///
+/// Cached per filter for [`PROFILE_MEANS_TTL`], like [`profile_means`], keeping at most
+/// [`MAX_CACHED_DISTRIBUTIONS`] filters.
+pub async fn score_distribution(filter: &NormFilter) -> Result<Option<ScoreDistribution>> {
+    if let Some((computed_at, distribution)) = SCORE_DISTRIBUTIONS.lock().unwrap().get(filter)
+        && computed_at.elapsed() < PROFILE_MEANS_TTL
+    {
+        return Ok(distribution.clone());
+    }
+
+    let stored = result_store()?
+        .profiles(&ProfileFilter {
+            tenant: Some(&filter.tenant),
+            lang: filter.lang.as_deref(),
+            ..ProfileFilter::default()
+        })
+        .await?;
+    // Rows from older formats are skipped rather than failing the whole aggregate
+    let profiles: Vec<_> = stored
+        .into_iter()
+        .filter(|p| {
+            filter.country.is_none()
+                || p.demographics.as_ref().and_then(|d| d.country.as_ref())
+                    == filter.country.as_ref()
+        })
+        .filter_map(|p| p.profile)
+        .collect();
+
+    let distribution = ScoreDistribution::from_profiles(&profiles);
+    let mut cache = SCORE_DISTRIBUTIONS.lock().unwrap();
+    if cache.len() >= MAX_CACHED_DISTRIBUTIONS && !cache.contains_key(filter) {
+        cache.retain(|_, (computed_at, _)| computed_at.elapsed() < PROFILE_MEANS_TTL);
+        if cache.len() >= MAX_CACHED_DISTRIBUTIONS
+            && let Some(oldest) = cache
+                .iter()
+                .min_by_key(|(_, (computed_at, _))| *computed_at)
+                .map(|(key, _)| key.clone())
+        {
+            cache.remove(&oldest);
+        }
+    }
+    cache.insert(filter.clone(), (Instant::now(), distribution.clone()));
+    Ok(distribution)
+}
+
+/// Soft-delete a saved result if `owner_token` matches the one issued on save.
+///
+/// The row stays until [`purge_deleted_results`] removes it, so [`restore_result`] can
+/// undo the deletion. Returns `false` when no such result exists, it is already deleted
+/// or the token doesn't match.
+pub async fn delete_result(id: &str, owner_token: &str) -> Result<bool> {
+    if owner_token.is_empty() {
+        return Ok(false);
+    }
+
+    let deleted = result_store()?.delete(id, owner_token, unix_now()?).await?;
+    result_cache::invalidate(id);
+
+    Ok(deleted)
+}
+
+/// Undo [`delete_result`] for a result deleted at or after `deleted_since` (Unix seconds).
+///
+/// Returns `false` when there is nothing to restore or the token doesn't match.
+pub async fn restore_result(id: &str, owner_token: &str, deleted_since: i64) -> Result<bool> {
+    if owner_token.is_empty() {
+        return Ok(false);
+    }
+
+    let restored = result_store()?
+        .restore(id, owner_token, deleted_since)
+        .await?;
+    result_cache::invalidate(id);
+
+    Ok(restored)
+}
+
+/// Permanently remove results soft-deleted before `deleted_before` (Unix seconds).
+///
+/// Returns the number of purged results.
+pub async fn purge_deleted_results(deleted_before: i64) -> Result<u64> {
+    result_store()?.purge_deleted(deleted_before).await
+}
+
+/// Remove resume sessions that expired before `now` (Unix seconds).
+///
+/// Returns the number of removed sessions.
+pub async fn purge_expired_sessions(now: i64) -> Result<u64> {
     let conn = get_connection()?;
-    let profile_json = serde_json::to_string(profile).context("Failed to serialize profile")?;
-    let now = std::time::SystemTime::now()
-        .duration_since(std::time::UNIX_EPOCH)
-        .context("System time error")?
-        .as_secs() as i64;
+    let purged = conn
+        .execute("DELETE FROM sessions WHERE expires_at <= ?", (now,))
+        .await
+        .context("Failed to purge expired sessions")?;
+
+    Ok(purged)
+}
+
+/// Remove analysis feedback left behind by results that no longer exist.
+///
+/// Feedback given without saving the result is kept. Returns the number of removed rows.
+pub async fn purge_orphaned_feedback() -> Result<u64> {
+    let conn = get_connection()?;
+    // Results may be kept outside the database, so each one is looked up in the result store
+    let mut rows = conn
+        .query(
+            "SELECT id, result_id FROM analysis_feedback WHERE result_id IS NOT NULL",
+            (),
+        )
+        .await
+        .context("Failed to query feedback")?;
+    let mut feedback = Vec::new();
+    while let Some(row) = rows.next().await? {
+        feedback.push((row.get::<String>(0)?, row.get::<String>(1)?));
+    }
+    drop(rows);
+
+    let store = result_store()?;
+    let mut ids = Vec::new();
+    for (id, result_id) in feedback {
+        if !store.exists(&result_id).await? {
+            ids.push(id);
+        }
+    }
+
+    let mut purged = 0;
+    for id in &ids {
+        purged += conn
+            .execute("DELETE FROM analysis_feedback WHERE id = ?", (id.as_str(),))
+            .await
+            .context("Failed to purge orphaned feedback")?;
+    }
+    Ok(purged)
+}
+
+/// Thumbs up/down feedback on a generated AI analysis.
+#[derive(Debug, Clone)]
+pub struct AnalysisFeedback<'a> {
+    /// Shared result the analysis belongs to, if it was saved.
+    pub result_id: Option<&'a str>,
+    /// Model preset that generated the analysis, if known.
+    pub model_id: Option<&'a str>,
+    /// Prompt template version the analysis was generated with, if known.
+    pub prompt_version: Option<&'a str>,
+    pub helpful: bool,
+    pub comment: Option<&'a str>,
+    pub lang: &'a str,
+}
+
+/// Store feedback on an AI analysis.
+pub async fn save_feedback(feedback: &AnalysisFeedback<'_>) -> Result<()> {
+    let conn = get_connection()?;
+    let id = uuid::Uuid::new_v4().to_string();
+    let now = unix_now()?;
 
     conn.execute(
-        "INSERT INTO results (id, profile_json, user_context, ai_analysis, lang, created_at) VALUES (?, ?, ?, ?, ?, ?)",
-        (id, profile_json.as_str(), user_context.unwrap_or(""), ai_analysis.unwrap_or(""), lang, now),
+        "INSERT INTO analysis_feedback (id, result_id, model_id, helpful, comment, lang, created_at, prompt_version) VALUES (?, ?, ?, ?, ?, ?, ?, ?)",
+        (
+            id.as_str(),
+            feedback.result_id.unwrap_or(""),
+            feedback.model_id.unwrap_or(""),
+            feedback.helpful as i64,
+            feedback.comment.unwrap_or(""),
+            feedback.lang,
+            now,
+            feedback.prompt_version.unwrap_or(""),
+        ),
     )
     .await
-    .context("Failed to insert result")?;
+    .context("Failed to insert feedback")?;
 
     Ok(())
 }
 
-/// Get a saved result by ID.
-pub async fn get_result(id: &str) -> Result<Option<SavedResult>> {
+/// In-progress test answers saved so they can be resumed on another device.
+#[derive(Debug, Clone)]
+pub struct SavedSession {
+    /// Answers keyed by question id.
+    pub answers: HashMap<String, u8>,
+    pub current_index: usize,
+    pub lang: String,
+}
+
+/// Save test progress under a resume code in a tenant namespace, valid until `expires_at`
+/// (Unix seconds).
+///
+/// Returns `false` if the code is already taken.
+pub async fn save_session(
+    code: &str,
+    session: &SavedSession,
+    tenant: &str,
+    expires_at: i64,
+) -> Result<bool> {
+    let conn = get_connection()?;
+    let answers_json =
+        serde_json::to_string(&session.answers).context("Failed to serialize answers")?;
+    let now = unix_now()?;
+
+    let inserted = conn
+        .execute(
+            "INSERT OR IGNORE INTO sessions (code, answers_json, current_index, lang, created_at, expires_at, tenant) VALUES (?, ?, ?, ?, ?, ?, ?)",
+            (
+                code,
+                answers_json.as_str(),
+                session.current_index as i64,
+                session.lang.as_str(),
+                now,
+                expires_at,
+                tenant,
+            ),
+        )
+        .await
+        .context("Failed to insert session")?;
+
+    Ok(inserted > 0)
+}
+
+/// Get saved test progress by resume code within a tenant namespace. Expired sessions and
+/// those of other tenants are treated as missing.
+pub async fn get_session(code: &str, tenant: &str) -> Result<Option<SavedSession>> {
     let conn = get_connection()?;
+    let now = unix_now()?;
 
     let mut rows = conn
         .query(
-            "SELECT id, profile_json, user_context, ai_analysis, lang, created_at FROM results WHERE id = ?",
-            [id],
+            "SELECT answers_json, current_index, lang FROM sessions WHERE code = ? AND expires_at > ? AND COALESCE(tenant, '') = ?",
+            (code, now, tenant),
         )
         .await
-        .context("Failed to query result")?;
+        .context("Failed to query session")?;
+
+    let Some(row) = rows.next().await? else {
+        return Ok(None);
+    };
+    let answers_json: String = row.get(0)?;
+    let current_index: i64 = row.get(1)?;
+    let lang: String = row.get(2)?;
+
+    Ok(Some(SavedSession {
+        answers: serde_json::from_str(&answers_json).context("Failed to deserialize answers")?,
+        current_index: current_index.max(0) as usize,
+        lang,
+    }))
+}
+
+/// Record a finished AI analysis for the daily statistics.
+pub async fn record_analysis(model_id: &str, lang: &str, success: bool) -> Result<()> {
+    let conn = get_connection()?;
+    let id = uuid::Uuid::new_v4().to_string();
+    let now = unix_now()?;
+
+    conn.execute(
+        "INSERT INTO analysis_log (id, model_id, lang, success, created_at) VALUES (?, ?, ?, ?, ?)",
+        (id.as_str(), model_id, lang, success as i64, now),
+    )
+    .await
+    .context("Failed to insert analysis log entry")?;
+
+    Ok(())
+}
+
+/// One aggregated counter or average for a day.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DailyStat {
+    /// Namespace of the tenant; empty for the default site.
+    pub tenant: String,
+    /// Days since the Unix epoch (UTC).
+    pub day: i64,
+    /// Metric name, e.g. `results_saved` or `analyses:<model_id>`.
+    pub metric: String,
+    pub value: f64,
+}
+
+/// Whether statistics for `day` (days since the Unix epoch) were already computed.
+pub async fn has_daily_stats(day: i64) -> Result<bool> {
+    let conn = get_connection()?;
+    let mut rows = conn
+        .query("SELECT 1 FROM daily_stats WHERE day = ? LIMIT 1", (day,))
+        .await
+        .context("Failed to query daily stats")?;
+    Ok(rows.next().await?.is_some())
+}
 
-    if let Some(row) = rows.next().await? {
-        let id: String = row.get(0)?;
-        let profile_json: String = row.get(1)?;
-        let user_context: String = row.get(2)?;
-        let ai_analysis: Option<String> = row.get::<String>(3).ok().filter(|s| !s.is_empty());
-        let lang: String = row.get(4)?;
-        let created_at: i64 = row.get(5)?;
+/// Compute and store the statistics for `day` (days since the Unix epoch), replacing
+/// any earlier values. Returns the number of metrics written.
+///
+/// Metrics, per tenant namespace (empty for the default site):
+/// - `results_saved`: shared results created that day
+/// - `mean:<domain code>`: average domain percentage over that day's results
+/// - `hist:<domain code>:<bucket>`: number of that day's results whose domain percentage
+///   falls in bucket 0-9 (0-10%, 10-20%, ..., 90-100%)
+///
+/// And for the whole deployment, stored under the default site since the analysis log
+/// isn't split by tenant:
+/// - `analyses:<model_id>` / `analyses_failed:<model_id>`: finished analyses per model preset
+pub async fn compute_daily_stats(day: i64) -> Result<usize> {
+    const DAY_SECS: i64 = 24 * 60 * 60;
 
-        let profile: PersonalityProfile =
-            serde_json::from_str(&profile_json).context("Failed to deserialize profile")?;
+    let conn = get_connection()?;
+    let (start, end) = (day * DAY_SECS, (day + 1) * DAY_SECS);
+    let mut stats: Vec<(String, String, f64)> = Vec::new();
+
+    let mut tenants = vec![String::new()];
+    if let Ok(config) = get_config() {
+        tenants.extend(config.tenants.iter().map(|t| t.id.clone()));
+    }
+    for tenant in &tenants {
+        let stored = result_store()?
+            .profiles(&ProfileFilter {
+                tenant: Some(tenant),
+                created_from: Some(start),
+                created_to: Some(end),
+                include_deleted: true,
+                ..ProfileFilter::default()
+            })
+            .await?;
+        let results_saved = stored.len();
+        let profiles: Vec<_> = stored.into_iter().filter_map(|p| p.profile).collect();
+        let mut push = |metric: String, value: f64| stats.push((tenant.clone(), metric, value));
+        push("results_saved".to_string(), results_saved as f64);
+        if let Some(means) = ProfileMeans::from_profiles(&profiles) {
+            for (domain, mean) in &means.domains {
+                push(format!("mean:{}", domain.code()), f64::from(*mean));
+            }
+        }
+        for domain in Domain::all() {
+            let mut buckets = [0u32; HISTOGRAM_BUCKETS];
+            for score in profiles.iter().filter_map(|p| p.domain_score(*domain)) {
+                buckets[histogram_bucket(score.percentage())] += 1;
+            }
+            for (bucket, count) in buckets.iter().enumerate().filter(|(_, c)| **c > 0) {
+                push(
+                    format!("hist:{}:{bucket}", domain.code()),
+                    f64::from(*count),
+                );
+            }
+        }
+    }
 
-        let user_context = if user_context.is_empty() {
-            None
+    let mut rows = conn
+        .query(
+            "SELECT model_id, success, COUNT(*) FROM analysis_log WHERE created_at >= ? AND created_at < ? GROUP BY model_id, success",
+            (start, end),
+        )
+        .await
+        .context("Failed to query analysis log")?;
+    while let Some(row) = rows.next().await? {
+        let model_id: String = row.get(0)?;
+        let success: i64 = row.get(1)?;
+        let count: i64 = row.get(2)?;
+        let metric = if success != 0 {
+            format!("analyses:{model_id}")
         } else {
-            Some(user_context)
+            format!("analyses_failed:{model_id}")
         };
+        stats.push((String::new(), metric, count as f64));
+    }
 
-        Ok(Some(SavedResult {
-            id,
-            profile,
-            user_context,
-            ai_analysis,
-            lang,
-            created_at,
-        }))
-    } else {
-        Ok(None)
+    for (tenant, metric, value) in &stats {
+        conn.execute(
+            "INSERT OR REPLACE INTO daily_stats (tenant, day, metric, value) VALUES (?, ?, ?, ?)",
+            (tenant.as_str(), day, metric.as_str(), *value),
+        )
+        .await
+        .context("Failed to store daily stat")?;
+    }
+
+    Ok(stats.len())
+}
+
+/// Number of buckets in the `hist:` daily statistics.
+pub const HISTOGRAM_BUCKETS: usize = 10;
+
+/// Histogram bucket (0-9) of a percentage (0-100).
+fn histogram_bucket(percentage: f32) -> usize {
+    ((percentage / 100.0 * HISTOGRAM_BUCKETS as f32) as usize).min(HISTOGRAM_BUCKETS - 1)
+}
+
+/// Totals of every daily statistic of a tenant whose name starts with `prefix`, summed
+/// over all days.
+pub async fn daily_stat_totals(tenant: &str, prefix: &str) -> Result<Vec<(String, f64)>> {
+    let conn = get_connection()?;
+    let mut rows = conn
+        .query(
+            "SELECT metric, SUM(value) FROM daily_stats WHERE tenant = ?2 AND substr(metric, 1, length(?1)) = ?1 GROUP BY metric",
+            (prefix, tenant),
+        )
+        .await
+        .context("Failed to query daily stat totals")?;
+
+    let mut totals = Vec::new();
+    while let Some(row) = rows.next().await? {
+        totals.push((row.get(0)?, row.get(1)?));
+    }
+    Ok(totals)
+}
+
+/// Stored daily statistics of every tenant from `since_day` on, oldest first.
+pub async fn daily_stats(since_day: i64) -> Result<Vec<DailyStat>> {
+    let conn = get_connection()?;
+    let mut rows = conn
+        .query(
+            "SELECT tenant, day, metric, value FROM daily_stats WHERE day >= ? ORDER BY day, tenant, metric",
+            (since_day,),
+        )
+        .await
+        .context("Failed to query daily stats")?;
+
+    let mut stats = Vec::new();
+    while let Some(row) = rows.next().await? {
+        stats.push(DailyStat {
+            tenant: row.get(0)?,
+            day: row.get(1)?,
+            metric: row.get(2)?,
+            value: row.get(3)?,
+        });
+    }
+    Ok(stats)
+}
+
+/// A finished AI analysis from the analysis log.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AnalysisLogEntry {
+    pub model_id: String,
+    pub lang: String,
+    pub success: bool,
+    pub created_at: i64,
+}
+
+/// Most recent finished analyses, newest first.
+pub async fn recent_analyses(limit: u32) -> Result<Vec<AnalysisLogEntry>> {
+    let conn = get_connection()?;
+    let mut rows = conn
+        .query(
+            "SELECT model_id, lang, success, created_at FROM analysis_log ORDER BY created_at DESC LIMIT ?",
+            (i64::from(limit),),
+        )
+        .await
+        .context("Failed to query analysis log")?;
+
+    let mut entries = Vec::new();
+    while let Some(row) = rows.next().await? {
+        entries.push(AnalysisLogEntry {
+            model_id: row.get(0)?,
+            lang: row.get(1)?,
+            success: row.get::<i64>(2)? != 0,
+            created_at: row.get(3)?,
+        });
+    }
+    Ok(entries)
+}
+
+/// Answers donated for research, with optional self-reported demographics.
+#[derive(Debug, Clone)]
+pub struct ResearchResponse {
+    /// Answer values (1-5) in inventory order.
+    pub answers: Vec<u8>,
+    pub lang: String,
+    pub age_band: Option<String>,
+    pub gender: Option<String>,
+}
+
+/// Store a research contribution.
+pub async fn save_research_response(response: &ResearchResponse) -> Result<()> {
+    let conn = get_connection()?;
+    let answers_json =
+        serde_json::to_string(&response.answers).context("Failed to serialize answers")?;
+
+    conn.execute(
+        "INSERT INTO research_responses (answers_json, lang, age_band, gender) VALUES (?, ?, ?, ?)",
+        (
+            answers_json.as_str(),
+            response.lang.as_str(),
+            response.age_band.as_deref(),
+            response.gender.as_deref(),
+        ),
+    )
+    .await
+    .context("Failed to save research response")?;
+
+    Ok(())
+}
+
+/// All research contributions, in random order so row order says nothing about when
+/// they were made.
+pub async fn research_responses() -> Result<Vec<ResearchResponse>> {
+    let conn = get_connection()?;
+    let mut rows = conn
+        .query(
+            "SELECT answers_json, lang, age_band, gender FROM research_responses ORDER BY RANDOM()",
+            (),
+        )
+        .await
+        .context("Failed to query research responses")?;
+
+    let mut responses = Vec::new();
+    while let Some(row) = rows.next().await? {
+        let answers_json: String = row.get(0)?;
+        responses.push(ResearchResponse {
+            answers: serde_json::from_str(&answers_json)
+                .context("Failed to deserialize answers")?,
+            lang: row.get(1)?,
+            age_band: row.get::<String>(2).ok(),
+            gender: row.get::<String>(3).ok(),
+        });
+    }
+    Ok(responses)
+}
+
+/// Time a respondent spent on one question.
+#[derive(Debug, Clone)]
+pub struct QuestionTiming {
+    pub question_id: String,
+    pub ms: u64,
+    /// Whether they went back to the question after moving past it.
+    pub revisited: bool,
+}
+
+/// Add one test's question timings to the totals for `lang`.
+pub async fn record_question_timings(lang: &str, timings: &[QuestionTiming]) -> Result<()> {
+    let conn = get_connection()?;
+    for timing in timings {
+        conn.execute(
+            r#"
+            INSERT INTO question_stats (question_id, lang, responses, total_ms, revisits)
+            VALUES (?, ?, 1, ?, ?)
+            ON CONFLICT (question_id, lang) DO UPDATE SET
+                responses = responses + 1,
+                total_ms = total_ms + excluded.total_ms,
+                revisits = revisits + excluded.revisits
+            "#,
+            (
+                timing.question_id.as_str(),
+                lang,
+                i64::try_from(timing.ms).unwrap_or(i64::MAX),
+                i64::from(timing.revisited),
+            ),
+        )
+        .await
+        .context("Failed to record question timing")?;
+    }
+    Ok(())
+}
+
+/// Timing totals of one question in one language.
+#[derive(Debug, Clone)]
+pub struct QuestionStats {
+    pub question_id: String,
+    pub lang: String,
+    /// Tests that reported a time for the question.
+    pub responses: i64,
+    pub total_ms: i64,
+    /// Tests in which the respondent went back to the question.
+    pub revisits: i64,
+}
+
+/// Timing totals of every question, by language.
+pub async fn question_stats() -> Result<Vec<QuestionStats>> {
+    let conn = get_connection()?;
+    let mut rows = conn
+        .query(
+            "SELECT question_id, lang, responses, total_ms, revisits FROM question_stats ORDER BY lang",
+            (),
+        )
+        .await
+        .context("Failed to query question stats")?;
+
+    let mut stats = Vec::new();
+    while let Some(row) = rows.next().await? {
+        stats.push(QuestionStats {
+            question_id: row.get(0)?,
+            lang: row.get(1)?,
+            responses: row.get(2)?,
+            total_ms: row.get(3)?,
+            revisits: row.get(4)?,
+        });
+    }
+    Ok(stats)
+}
+
+/// Cached explanation of a facet score, by facet code (e.g. `N1`), level and language.
+pub async fn get_facet_explanation(facet: &str, level: &str, lang: &str) -> Result<Option<String>> {
+    let conn = get_connection()?;
+    let mut rows = conn
+        .query(
+            "SELECT text FROM facet_explanations WHERE facet = ? AND level = ? AND lang = ?",
+            (facet, level, lang),
+        )
+        .await
+        .context("Failed to query facet explanation")?;
+
+    match rows.next().await? {
+        Some(row) => Ok(Some(row.get(0)?)),
+        None => Ok(None),
+    }
+}
+
+/// Cache an explanation of a facet score, replacing any earlier one.
+pub async fn save_facet_explanation(
+    facet: &str,
+    level: &str,
+    lang: &str,
+    text: &str,
+) -> Result<()> {
+    let conn = get_connection()?;
+    let now = unix_now()?;
+
+    conn.execute(
+        "INSERT OR REPLACE INTO facet_explanations (facet, level, lang, text, created_at) VALUES (?, ?, ?, ?, ?)",
+        (facet, level, lang, text, now),
+    )
+    .await
+    .context("Failed to save facet explanation")?;
+
+    Ok(())
+}
+
+/// Redacted provider calls of a failed analysis job.
+#[derive(Debug, Clone)]
+pub struct ProviderLog {
+    pub job_id: String,
+    pub model_id: String,
+    /// Error the job failed with.
+    pub error: String,
+    pub calls: Vec<ProviderCall>,
+    pub created_at: i64,
+}
+
+/// Store the provider calls of a failed analysis job.
+pub async fn save_provider_log(
+    job_id: &str,
+    model_id: &str,
+    error: &str,
+    calls: &[ProviderCall],
+) -> Result<()> {
+    let conn = get_connection()?;
+    let now = unix_now()?;
+    let calls_json = serde_json::to_string(calls).context("Failed to serialize provider calls")?;
+
+    conn.execute(
+        "INSERT OR REPLACE INTO provider_logs (job_id, model_id, error, calls_json, created_at) VALUES (?, ?, ?, ?, ?)",
+        (job_id, model_id, error, calls_json.as_str(), now),
+    )
+    .await
+    .context("Failed to save provider log")?;
+
+    Ok(())
+}
+
+/// Provider log of a failed analysis job, by job id.
+pub async fn get_provider_log(job_id: &str) -> Result<Option<ProviderLog>> {
+    let conn = get_connection()?;
+    let mut rows = conn
+        .query(
+            "SELECT job_id, model_id, error, calls_json, created_at FROM provider_logs WHERE job_id = ?",
+            (job_id,),
+        )
+        .await
+        .context("Failed to query provider log")?;
+
+    match rows.next().await? {
+        Some(row) => Ok(Some(provider_log_from_row(&row)?)),
+        None => Ok(None),
+    }
+}
+
+/// Most recent provider logs, newest first.
+pub async fn recent_provider_logs(limit: u32) -> Result<Vec<ProviderLog>> {
+    let conn = get_connection()?;
+    let mut rows = conn
+        .query(
+            "SELECT job_id, model_id, error, calls_json, created_at FROM provider_logs ORDER BY created_at DESC LIMIT ?",
+            (i64::from(limit),),
+        )
+        .await
+        .context("Failed to query provider logs")?;
+
+    let mut logs = Vec::new();
+    while let Some(row) = rows.next().await? {
+        logs.push(provider_log_from_row(&row)?);
+    }
+    Ok(logs)
+}
+
+fn provider_log_from_row(row: &turso::Row) -> Result<ProviderLog> {
+    let calls_json: String = row.get(3)?;
+    Ok(ProviderLog {
+        job_id: row.get(0)?,
+        model_id: row.get(1)?,
+        error: row.get(2)?,
+        calls: serde_json::from_str(&calls_json).context("Failed to parse provider calls")?,
+        created_at: row.get(4)?,
+    })
+}
+
+/// Remove provider logs created before `created_before` (Unix seconds).
+///
+/// Returns the number of removed logs.
+pub async fn purge_provider_logs(created_before: i64) -> Result<u64> {
+    let conn = get_connection()?;
+    let purged = conn
+        .execute(
+            "DELETE FROM provider_logs WHERE created_at < ?",
+            (created_before,),
+        )
+        .await
+        .context("Failed to purge provider logs")?;
+
+    Ok(purged)
+}
+
+/// One recorded access or change of a saved result.
+#[derive(Debug, Clone)]
+pub struct AuditEntry {
+    pub result_id: String,
+    pub action: String,
+    /// Salted hash of the client address, if it was known.
+    pub ip_hash: Option<String>,
+    pub tenant: String,
+    pub created_at: i64,
+}
+
+/// Append an entry to the audit log.
+pub async fn append_audit_entry(
+    result_id: &str,
+    action: &str,
+    ip_hash: Option<&str>,
+    tenant: &str,
+) -> Result<()> {
+    let conn = get_connection()?;
+    let now = unix_now()?;
+
+    conn.execute(
+        "INSERT INTO audit_log (result_id, action, ip_hash, tenant, created_at) VALUES (?, ?, ?, ?, ?)",
+        (result_id, action, ip_hash, tenant, now),
+    )
+    .await
+    .context("Failed to append audit log entry")?;
+
+    Ok(())
+}
+
+/// Audit log entries of a result, newest first.
+pub async fn audit_entries(result_id: &str, limit: u32) -> Result<Vec<AuditEntry>> {
+    let conn = get_connection()?;
+    let mut rows = conn
+        .query(
+            "SELECT result_id, action, ip_hash, tenant, created_at FROM audit_log WHERE result_id = ? ORDER BY id DESC LIMIT ?",
+            (result_id, i64::from(limit)),
+        )
+        .await
+        .context("Failed to query audit log")?;
+
+    let mut entries = Vec::new();
+    while let Some(row) = rows.next().await? {
+        entries.push(AuditEntry {
+            result_id: row.get(0)?,
+            action: row.get(1)?,
+            ip_hash: row.get(2)?,
+            tenant: row.get(3)?,
+            created_at: row.get(4)?,
+        });
+    }
+    Ok(entries)
+}
+
+/// Remove audit log entries created before `created_before` (Unix seconds).
+///
+/// Returns the number of removed entries.
+pub async fn purge_audit_log(created_before: i64) -> Result<u64> {
+    let conn = get_connection()?;
+    let purged = conn
+        .execute(
+            "DELETE FROM audit_log WHERE created_at < ?",
+            (created_before,),
+        )
+        .await
+        .context("Failed to purge audit log")?;
+
+    Ok(purged)
+}
+
+/// Count one analysis against a client's quota for `day` (days since the Unix epoch).
+///
+/// Returns `false`, counting nothing, when `limit` analyses were already started that day.
+pub async fn use_analysis_quota(client_hash: &str, day: i64, limit: u32) -> Result<bool> {
+    let conn = get_connection()?;
+    conn.execute(
+        "INSERT OR IGNORE INTO analysis_quota (client_hash, day, used) VALUES (?, ?, 0)",
+        (client_hash, day),
+    )
+    .await
+    .context("Failed to create analysis quota counter")?;
+    // A single conditional update, so concurrent requests can't both take the last one
+    let updated = conn
+        .execute(
+            "UPDATE analysis_quota SET used = used + 1 WHERE client_hash = ? AND day = ? AND used < ?",
+            (client_hash, day, i64::from(limit)),
+        )
+        .await
+        .context("Failed to update analysis quota counter")?;
+
+    Ok(updated > 0)
+}
+
+/// Give back an analysis counted by [`use_analysis_quota`], e.g. when it failed.
+pub async fn release_analysis_quota(client_hash: &str, day: i64) -> Result<()> {
+    let conn = get_connection()?;
+    conn.execute(
+        "UPDATE analysis_quota SET used = used - 1 WHERE client_hash = ? AND day = ? AND used > 0",
+        (client_hash, day),
+    )
+    .await
+    .context("Failed to release analysis quota")?;
+
+    Ok(())
+}
+
+/// Analyses a client started on `day`.
+pub async fn analysis_quota_used(client_hash: &str, day: i64) -> Result<u32> {
+    let conn = get_connection()?;
+    let mut rows = conn
+        .query(
+            "SELECT used FROM analysis_quota WHERE client_hash = ? AND day = ?",
+            (client_hash, day),
+        )
+        .await
+        .context("Failed to query analysis quota")?;
+
+    match rows.next().await? {
+        Some(row) => Ok(row.get::<i64>(0)?.try_into().unwrap_or(0)),
+        None => Ok(0),
+    }
+}
+
+/// Remove quota counters of days before `before_day`.
+///
+/// Returns the number of removed counters.
+pub async fn purge_analysis_quota(before_day: i64) -> Result<u64> {
+    let conn = get_connection()?;
+    let purged = conn
+        .execute("DELETE FROM analysis_quota WHERE day < ?", (before_day,))
+        .await
+        .context("Failed to purge analysis quota counters")?;
+
+    Ok(purged)
+}
+
+#[cfg(test)]
+mod tests {
+    use bigfive::{Answer, calculate};
+
+    use super::*;
+
+    fn result(access_token: Option<&str>, owner_token: Option<&str>) -> SavedResult {
+        let inventory = crate::inventory::reference();
+        let answers: Vec<Answer> = inventory
+            .questions()
+            .iter()
+            .map(|q| Answer {
+                question_id: q.id.clone(),
+                value: 3,
+            })
+            .collect();
+        SavedResult {
+            id: "id".to_string(),
+            profile: calculate(&inventory, &answers).unwrap(),
+            user_context: None,
+            ai_analysis: None,
+            prompt_version: None,
+            lang: "en".to_string(),
+            created_at: 0,
+            nickname: None,
+            expires_at: None,
+            access_token: access_token.map(str::to_string),
+            owner_token: owner_token.map(str::to_string),
+            unpublished: false,
+            deleted_at: None,
+            demographics: None,
+        }
+    }
+
+    #[test]
+    fn unprotected_results_are_always_unlocked() {
+        let saved = result(None, Some("owner"));
+        assert!(saved.is_unlocked_by(None));
+        assert!(saved.is_unlocked_by(Some("anything")));
+    }
+
+    #[test]
+    fn protected_results_need_a_matching_token() {
+        let saved = result(Some("access"), Some("owner"));
+        assert!(!saved.is_unlocked_by(None));
+        assert!(!saved.is_unlocked_by(Some("")));
+        assert!(!saved.is_unlocked_by(Some("acces")));
+        assert!(!saved.is_unlocked_by(Some("access-token")));
+        assert!(saved.is_unlocked_by(Some("access")));
+        assert!(saved.is_unlocked_by(Some("owner")));
+    }
+
+    #[test]
+    fn protected_results_without_an_owner_need_the_access_token() {
+        let saved = result(Some("access"), None);
+        assert!(!saved.is_unlocked_by(Some("owner")));
+        assert!(saved.is_unlocked_by(Some("access")));
+    }
+
+    /// Connection to a fresh database with a `daily_stats` table from before tenants.
+    async fn old_daily_stats() -> Connection {
+        let db = Builder::new_local(":memory:").build().await.unwrap();
+        let conn = db.connect().unwrap();
+        conn.execute(
+            "CREATE TABLE daily_stats (day INTEGER NOT NULL, metric TEXT NOT NULL, value REAL NOT NULL, PRIMARY KEY (day, metric))",
+            (),
+        )
+        .await
+        .unwrap();
+        conn.execute(
+            "INSERT INTO daily_stats (day, metric, value) VALUES (1, 'results_saved', 4)",
+            (),
+        )
+        .await
+        .unwrap();
+        conn
+    }
+
+    async fn count_rows(conn: &Connection, tenant: &str) -> i64 {
+        let mut rows = conn
+            .query(
+                "SELECT COUNT(*) FROM daily_stats WHERE tenant = ?",
+                (tenant,),
+            )
+            .await
+            .unwrap();
+        rows.next().await.unwrap().unwrap().get(0).unwrap()
+    }
+
+    #[tokio::test]
+    async fn daily_stats_migration_adds_the_tenant() {
+        let conn = old_daily_stats().await;
+        migrate_daily_stats(&conn, true).await.unwrap();
+        assert_eq!(count_rows(&conn, "").await, 1);
+        // The same metric can now be stored for another tenant, and migrating is idempotent
+        conn.execute(
+            "INSERT INTO daily_stats (tenant, day, metric, value) VALUES ('acme', 1, 'results_saved', 2)",
+            (),
+        )
+        .await
+        .unwrap();
+        migrate_daily_stats(&conn, true).await.unwrap();
+        assert_eq!(count_rows(&conn, "acme").await, 1);
+
+        // With tenants, rows that mixed them are dropped
+        let conn = old_daily_stats().await;
+        migrate_daily_stats(&conn, false).await.unwrap();
+        assert_eq!(count_rows(&conn, "").await, 0);
     }
 }
@@ -0,0 +1,73 @@
+//! ETags for shared result pages.
+//!
+//! [`etag_results`] hashes the rendered page of `/results/{id}` (in any locale) and
+//! answers a matching `If-None-Match` with `304 Not Modified`, so browsers and caching
+//! proxies revalidating a popular link get an empty response instead of the full page.
+//! The page is still rendered each time, from [`crate::result_cache`] for popular links.
+
+use axum::body::{Body, to_bytes};
+use axum::extract::Request;
+use axum::http::header::{CACHE_CONTROL, CONTENT_LENGTH, ETAG, IF_NONE_MATCH, SET_COOKIE};
+use axum::http::{HeaderValue, Method, StatusCode};
+use axum::middleware::Next;
+use axum::response::{IntoResponse, Response};
+use sha2::{Digest, Sha256};
+
+/// Limit for buffering a page to hash it; result pages stay far below.
+const MAX_PAGE_BYTES: usize = 4 * 1024 * 1024;
+
+/// Middleware adding ETags to shared result pages.
+pub async fn etag_results(req: Request, next: Next) -> Response {
+    if !matches!(*req.method(), Method::GET | Method::HEAD) || !is_result_page(req.uri().path()) {
+        return next.run(req).await;
+    }
+    let if_none_match = req.headers().get(IF_NONE_MATCH).cloned();
+
+    let response = next.run(req).await;
+    // Pages setting cookies are specific to the visitor
+    if response.status() != StatusCode::OK || response.headers().contains_key(SET_COOKIE) {
+        return response;
+    }
+    let (mut parts, body) = response.into_parts();
+    let Ok(bytes) = to_bytes(body, MAX_PAGE_BYTES).await else {
+        return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+    };
+
+    let hash = Sha256::digest(&bytes);
+    let hex: String = hash[..16].iter().map(|b| format!("{b:02x}")).collect();
+    let etag = format!("\"{hex}\"");
+    let etag = HeaderValue::from_str(&etag).expect("hex ETag is a valid header value");
+    // Cacheable, but only after checking back that the result hasn't changed
+    parts
+        .headers
+        .insert(CACHE_CONTROL, HeaderValue::from_static("no-cache"));
+    parts.headers.insert(ETAG, etag.clone());
+
+    if if_none_match.is_some_and(|value| matches_etag(&value, &etag)) {
+        parts.status = StatusCode::NOT_MODIFIED;
+        parts.headers.remove(CONTENT_LENGTH);
+        return Response::from_parts(parts, Body::empty());
+    }
+    Response::from_parts(parts, Body::from(bytes))
+}
+
+/// Whether `path` is a result page: `/results/{id}` or `/{locale}/results/{id}`.
+fn is_result_page(path: &str) -> bool {
+    let segments: Vec<&str> = path.trim_matches('/').split('/').collect();
+    matches!(
+        segments.as_slice(),
+        ["results", id] | [_, "results", id] if !id.is_empty()
+    )
+}
+
+/// Whether an `If-None-Match` header value lists `etag`.
+fn matches_etag(if_none_match: &HeaderValue, etag: &HeaderValue) -> bool {
+    let Ok(value) = if_none_match.to_str() else {
+        return false;
+    };
+    let etag = etag.to_str().unwrap_or_default();
+    value
+        .split(',')
+        .map(|tag| tag.trim().trim_start_matches("W/"))
+        .any(|tag| tag == "*" || tag == etag)
+}
@@ -0,0 +1,281 @@
+//! Self-contained HTML export of a saved result.
+//!
+//! `POST /api/v1/results/{id}/export.html` answers with a single HTML file holding the
+//! scores as static SVG bar charts, the owner's notes and the AI analysis, with the CSS
+//! inlined and no scripts or remote resources, so it can be archived or emailed and still
+//! opens years later. The link key travels in the form body rather than the URL to keep it
+//! out of request logs; without it, a protected result exports only its scores, as on the
+//! shared page.
+
+use std::fmt::Write;
+
+use axum::Form;
+use axum::extract::{Extension, Path};
+use axum::http::StatusCode;
+use axum::http::header::{CONTENT_DISPOSITION, CONTENT_TYPE};
+use axum::response::{IntoResponse, Response};
+use bigfive::Domain;
+use serde::Deserialize;
+
+use crate::calendar::civil_from_days;
+use crate::components::{domain_key, facet_key, level_key};
+use crate::config::get_config;
+use crate::db::{self, SavedResult};
+use crate::i18n::{self, Locale};
+use crate::tenancy::CurrentTenant;
+
+/// Form fields of `POST /api/v1/results/:id/export.html`.
+#[derive(Debug, Deserialize)]
+pub struct ExportForm {
+    /// Access token from the link fragment or the browser's owner token.
+    #[serde(default)]
+    key: Option<String>,
+}
+
+/// `POST /api/v1/results/:id/export.html`
+pub async fn export_handler(
+    Path(id): Path<String>,
+    Extension(CurrentTenant(tenant)): Extension<CurrentTenant>,
+    Form(form): Form<ExportForm>,
+) -> Response {
+    let namespace = tenant.map_or("", |t| t.id.as_str());
+    let result = match db::get_result(&id, namespace).await {
+        Ok(Some(result)) => result,
+        Ok(None) => return StatusCode::NOT_FOUND.into_response(),
+        Err(e) => {
+            tracing::error!(result_id = %id, "Failed to load result for export: {e}");
+            return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+        }
+    };
+    let Ok(config) = get_config() else {
+        return StatusCode::INTERNAL_SERVER_ERROR.into_response();
+    };
+    let locale = Locale::ALL
+        .iter()
+        .copied()
+        .find(|l| l.code() == result.lang)
+        .unwrap_or_default();
+    let site_name = config
+        .branding_for(tenant)
+        .site_name
+        .as_deref()
+        .unwrap_or_else(|| i18n::t(locale, "title"));
+
+    let unlocked = result.is_unlocked_by(form.key.as_deref().filter(|k| !k.is_empty()));
+    let analysis = result
+        .ai_analysis
+        .as_deref()
+        .filter(|_| unlocked)
+        .map(|a| crate::ai::limit_analysis_length(a, config.max_analysis_chars, &result.lang));
+
+    let html = export_html(&ExportedResult {
+        result: &result,
+        unlocked,
+        analysis: analysis.as_deref(),
+        site_name,
+        locale,
+    });
+    (
+        [
+            (CONTENT_TYPE, "text/html; charset=utf-8".to_string()),
+            (
+                CONTENT_DISPOSITION,
+                format!("attachment; filename=\"bigfive-{}.html\"", result.id),
+            ),
+        ],
+        html,
+    )
+        .into_response()
+}
+
+/// What goes into an export.
+struct ExportedResult<'a> {
+    result: &'a SavedResult,
+    /// Whether notes and analysis may be included.
+    unlocked: bool,
+    /// Analysis markdown, already cut to the configured length.
+    analysis: Option<&'a str>,
+    site_name: &'a str,
+    locale: Locale,
+}
+
+/// Stylesheet of the exported page, light colors only so it also prints well.
+const STYLE: &str = "\
+body{font-family:system-ui,-apple-system,'Segoe UI',Roboto,sans-serif;color:#1f2937;background:#f9fafb;margin:0;line-height:1.6}\
+main{max-width:760px;margin:0 auto;padding:32px 20px}\
+h1{font-size:1.8em;margin:0 0 4px}\
+h2{font-size:1.3em;margin:32px 0 12px;border-bottom:1px solid #e5e7eb;padding-bottom:4px}\
+h3{font-size:1.05em;margin:20px 0 6px}\
+.meta{color:#6b7280;margin:0}\
+.notice{background:#fef3c7;border-radius:8px;padding:12px 16px}\
+.notes{white-space:pre-wrap;background:#fff;border:1px solid #e5e7eb;border-radius:8px;padding:12px 16px}\
+.analysis{background:#fff;border:1px solid #e5e7eb;border-radius:8px;padding:4px 20px}\
+.analysis a.heading-anchor{display:none}\
+svg{display:block;width:100%;height:auto}\
+footer{color:#9ca3af;font-size:.85em;margin-top:40px;text-align:center}";
+
+/// Width of the chart's label column and bar area in SVG units.
+const LABEL_WIDTH: u32 = 220;
+const BAR_WIDTH: u32 = 420;
+
+/// The complete export document.
+fn export_html(export: &ExportedResult) -> String {
+    let ExportedResult { result, locale, .. } = *export;
+    let t = |key| i18n::t(locale, key);
+    let (year, month, day) = civil_from_days(result.created_at.div_euclid(86_400));
+
+    let mut html = String::new();
+    let _ = write!(
+        html,
+        "<!DOCTYPE html>\n<html lang=\"{lang}\">\n<head>\n<meta charset=\"utf-8\">\n\
+         <meta name=\"viewport\" content=\"width=device-width, initial-scale=1\">\n\
+         <title>{title}</title>\n<style>{STYLE}</style>\n</head>\n<body>\n<main>\n\
+         <h1>{title}</h1>\n",
+        lang = locale.code(),
+        title = escape(t("results_title")),
+    );
+    if let Some(nickname) = result.nickname.as_deref().filter(|_| export.unlocked) {
+        let _ = writeln!(
+            html,
+            "<p class=\"meta\">{} {}</p>",
+            escape(t("results_profile_of")),
+            escape(nickname)
+        );
+    }
+    let _ = writeln!(
+        html,
+        "<p class=\"meta\"><time datetime=\"{year:04}-{month:02}-{day:02}\">{year:04}-{month:02}-{day:02}</time> · {}</p>",
+        escape(export.site_name)
+    );
+    if !export.unlocked {
+        let _ = writeln!(
+            html,
+            "<p class=\"notice\">{}</p>",
+            escape(t("results_locked_notice"))
+        );
+    }
+
+    // Domains overview, then each domain's facets
+    let _ = writeln!(html, "<h2>{}</h2>", escape(t("legend_domains")));
+    let rows: Vec<_> = result
+        .profile
+        .domains
+        .iter()
+        .map(|d| BarRow {
+            label: t(domain_key(d.domain)),
+            detail: t(level_key(d.level)),
+            percentage: d.percentage(),
+            color: domain_hex(d.domain),
+        })
+        .collect();
+    html.push_str(&bar_chart(&rows));
+
+    let _ = writeln!(html, "<h2>{}</h2>", escape(t("legend_facets")));
+    for domain in &result.profile.domains {
+        let _ = writeln!(html, "<h3>{}</h3>", escape(t(domain_key(domain.domain))));
+        let rows: Vec<_> = domain
+            .facets
+            .iter()
+            .map(|f| BarRow {
+                label: t(facet_key(f.facet)),
+                detail: t(level_key(f.level)),
+                percentage: f.percentage(),
+                color: domain_hex(domain.domain),
+            })
+            .collect();
+        html.push_str(&bar_chart(&rows));
+    }
+
+    if let Some(notes) = result.user_context.as_deref().filter(|_| export.unlocked) {
+        let _ = writeln!(
+            html,
+            "<h2>{}</h2>\n<div class=\"notes\">{}</div>",
+            escape(t("export_notes_title")),
+            escape(notes)
+        );
+    }
+    if let Some(analysis) = export.analysis {
+        let _ = writeln!(
+            html,
+            "<h2>{}</h2>\n<div class=\"analysis\">{}</div>",
+            escape(t("results_ai_title")),
+            crate::markdown::to_safe_html(analysis, "analysis-")
+        );
+    }
+
+    let _ = write!(
+        html,
+        "<footer>{}</footer>\n</main>\n</body>\n</html>\n",
+        escape(t("export_footer"))
+    );
+    html
+}
+
+/// One bar of a chart.
+struct BarRow<'a> {
+    label: &'a str,
+    /// Shown after the percentage, e.g. the level.
+    detail: &'a str,
+    /// 0-100.
+    percentage: f32,
+    color: &'static str,
+}
+
+/// Horizontal bar chart as an inline SVG, one labelled row per bar.
+fn bar_chart(rows: &[BarRow]) -> String {
+    const ROW_HEIGHT: u32 = 32;
+    const BAR_HEIGHT: u32 = 14;
+
+    let width = LABEL_WIDTH + BAR_WIDTH + 120;
+    let height = ROW_HEIGHT * rows.len() as u32;
+    let mut svg = format!(
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" viewBox=\"0 0 {width} {height}\" role=\"img\">\n"
+    );
+    for (i, row) in rows.iter().enumerate() {
+        let y = ROW_HEIGHT * i as u32;
+        let bar_y = y + (ROW_HEIGHT - BAR_HEIGHT) / 2;
+        let text_y = y + ROW_HEIGHT / 2 + 5;
+        let filled = BAR_WIDTH as f32 * row.percentage.clamp(0.0, 100.0) / 100.0;
+        let _ = writeln!(
+            svg,
+            "<text x=\"0\" y=\"{text_y}\" font-size=\"14\" fill=\"#374151\">{label}</text>\
+             <rect x=\"{LABEL_WIDTH}\" y=\"{bar_y}\" width=\"{BAR_WIDTH}\" height=\"{BAR_HEIGHT}\" rx=\"7\" fill=\"#e5e7eb\"/>\
+             <rect x=\"{LABEL_WIDTH}\" y=\"{bar_y}\" width=\"{filled:.1}\" height=\"{BAR_HEIGHT}\" rx=\"7\" fill=\"{color}\"/>\
+             <text x=\"{text_x}\" y=\"{text_y}\" font-size=\"13\" fill=\"#6b7280\">{percentage:.0}% · {detail}</text>",
+            label = escape(row.label),
+            color = row.color,
+            text_x = LABEL_WIDTH + BAR_WIDTH + 10,
+            percentage = row.percentage,
+            detail = escape(row.detail),
+        );
+    }
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// Chart color of a domain, matching the light theme of the site.
+fn domain_hex(domain: Domain) -> &'static str {
+    match domain {
+        Domain::Neuroticism => "#ef4444",
+        Domain::Extraversion => "#eab308",
+        Domain::Openness => "#a855f7",
+        Domain::Agreeableness => "#22c55e",
+        Domain::Conscientiousness => "#3b82f6",
+    }
+}
+
+/// Escape text for HTML element content and attribute values.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
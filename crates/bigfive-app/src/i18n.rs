@@ -7,21 +7,32 @@ use leptos_router::hooks::use_location;
 use serde::{Deserialize, Serialize};
 
 /// Supported locales.
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub enum Locale {
-    #[default]
     En,
     Ru,
 }
 
+#[cfg(not(any(feature = "lang-en", feature = "lang-ru")))]
+compile_error!("enable at least one language feature (lang-en, lang-ru)");
+
 impl Locale {
-    /// Get locale from URL path segment.
+    /// Locales of this build, one per enabled `lang-*` feature; the first is the default.
+    pub const ALL: &'static [Locale] = &[
+        #[cfg(feature = "lang-en")]
+        Locale::En,
+        #[cfg(feature = "lang-ru")]
+        Locale::Ru,
+    ];
+
+    /// Get locale from URL path segment, the default one if it names none of this build.
     pub fn from_path(path: &str) -> Self {
         let first_segment = path.trim_start_matches('/').split('/').next().unwrap_or("");
-        match first_segment {
-            "ru" => Locale::Ru,
-            _ => Locale::En,
-        }
+        Locale::ALL
+            .iter()
+            .copied()
+            .find(|l| l.code() == first_segment)
+            .unwrap_or_default()
     }
 
     /// Get the URL path prefix for this locale.
@@ -41,6 +52,12 @@ impl Locale {
     }
 }
 
+impl Default for Locale {
+    fn default() -> Self {
+        Locale::ALL[0]
+    }
+}
+
 /// I18n context holding the current locale.
 #[derive(Clone, Copy)]
 pub struct I18nContext {
@@ -118,13 +135,172 @@ pub fn use_i18n() -> I18nContext {
 // Translations
 // ============================================================================
 
-/// Get a translation string.
+/// Shown for keys without a translation.
+const MISSING: &str = "[?]";
+
+/// Get a translation string, preferring this deployment's overrides.
 pub fn t(locale: Locale, key: &str) -> &'static str {
+    if let Some(text) = crate::translations::override_text(locale, key) {
+        return text;
+    }
+    let text = builtin(locale, key);
+    #[cfg(debug_assertions)]
+    if text == MISSING {
+        leptos::logging::warn!("Missing translation for key: {}", key);
+    }
+    text
+}
+
+/// Whether `key` has a built-in translation in any locale.
+pub fn is_known_key(key: &str) -> bool {
+    Locale::ALL
+        .iter()
+        .any(|&locale| builtin(locale, key) != MISSING)
+}
+
+/// Built-in translation string.
+fn builtin(locale: Locale, key: &str) -> &'static str {
     match (locale, key) {
         // Title
         (Locale::En, "title") => "Big Five Personality Test",
         (Locale::Ru, "title") => "Тест личности Big Five",
 
+        // Navigation
+        (Locale::En, "nav_home") => "Home",
+        (Locale::Ru, "nav_home") => "Главная",
+        (Locale::En, "nav_about") => "About",
+        (Locale::Ru, "nav_about") => "О тесте",
+        (Locale::En, "nav_faq") => "FAQ",
+        (Locale::Ru, "nav_faq") => "Вопросы и ответы",
+        (Locale::En, "nav_stats") => "Statistics",
+        (Locale::Ru, "nav_stats") => "Статистика",
+        (Locale::En, "nav_source") => "Source code",
+        (Locale::Ru, "nav_source") => "Исходный код",
+
+        // About page
+        (Locale::En, "traits_title_suffix") => "Big Five trait",
+        (Locale::Ru, "traits_title_suffix") => "Черта «Большой пятёрки»",
+        (Locale::En, "traits_high_heading") => "High scores",
+        (Locale::Ru, "traits_high_heading") => "Высокие баллы",
+        (Locale::En, "traits_low_heading") => "Low scores",
+        (Locale::Ru, "traits_low_heading") => "Низкие баллы",
+        (Locale::En, "traits_facets_heading") => "Facets",
+        (Locale::Ru, "traits_facets_heading") => "Аспекты",
+        (Locale::En, "traits_other_label") => "Other traits",
+        (Locale::Ru, "traits_other_label") => "Другие черты",
+        (Locale::En, "traits_learn_more") => "More about",
+        (Locale::Ru, "traits_learn_more") => "Подробнее:",
+        (Locale::En, "archetype_label") => "Your type",
+        (Locale::Ru, "archetype_label") => "Ваш тип",
+        (Locale::En, "import_link") => "Took the IPIP-NEO-120 elsewhere? Import your answers",
+        (Locale::Ru, "import_link") => "Проходили IPIP-NEO-120 в другом месте? Импортируйте ответы",
+        (Locale::En, "results_import") => "Import answers",
+        (Locale::Ru, "results_import") => "Импорт ответов",
+        (Locale::En, "import_title") => "Import answers",
+        (Locale::Ru, "import_title") => "Импорт ответов",
+        (Locale::En, "import_description") => {
+            "Score the answers from another IPIP-NEO-120 administration here. They are scored on the server, and the result opens like a finished test: you can save it, share a link and request an AI analysis."
+        }
+        (Locale::Ru, "import_description") => {
+            "Рассчитайте результаты по ответам на IPIP-NEO-120, пройденный в другом месте. Ответы обрабатываются на сервере, а результат открывается как после пройденного теста: его можно сохранить, поделиться ссылкой и запросить ИИ-анализ."
+        }
+        (Locale::En, "import_formats") => {
+            "CSV with a header and one answer (1-5) per row, identifying the question by item number (1-120, in the order of this test) or question id; or JSON: a list of {\"item\" or \"question_id\", \"value\"} objects, or an object mapping question ids to values."
+        }
+        (Locale::Ru, "import_formats") => {
+            "CSV с заголовком и одним ответом (1–5) в строке; вопрос указывается номером (1–120, в порядке этого теста) или идентификатором. Либо JSON: список объектов {\"item\" или \"question_id\", \"value\"} или объект, сопоставляющий идентификаторам вопросов ответы."
+        }
+        (Locale::En, "import_file_label") => "Upload a file",
+        (Locale::Ru, "import_file_label") => "Загрузите файл",
+        (Locale::En, "import_paste_label") => "…or paste the answers",
+        (Locale::Ru, "import_paste_label") => "…или вставьте ответы",
+        (Locale::En, "import_submit") => "Score answers",
+        (Locale::Ru, "import_submit") => "Рассчитать",
+        (Locale::En, "import_scoring") => "Scoring...",
+        (Locale::Ru, "import_scoring") => "Расчёт...",
+        (Locale::En, "import_failed") => "Could not import the answers:",
+        (Locale::Ru, "import_failed") => "Не удалось импортировать ответы:",
+        (Locale::En, "import_read_failed") => "The file could not be read.",
+        (Locale::Ru, "import_read_failed") => "Не удалось прочитать файл.",
+        (Locale::En, "reflection_title") => "Questions to reflect on",
+        (Locale::Ru, "reflection_title") => "Вопросы, над которыми стоит подумать",
+        (Locale::En, "reflection_description") => {
+            "You marked these questions during the test. Each one is listed with the facet it measures."
+        }
+        (Locale::Ru, "reflection_description") => {
+            "Вы отметили эти вопросы во время теста. Рядом с каждым указан аспект, который он измеряет."
+        }
+        (Locale::En, "reflection_add_to_context") => "Discuss them in the AI analysis",
+        (Locale::Ru, "reflection_add_to_context") => "Обсудить их в AI-анализе",
+        (Locale::En, "reflection_added") => "Added to your context",
+        (Locale::Ru, "reflection_added") => "Добавлено в контекст",
+        (Locale::En, "reflection_context_intro") => {
+            "Test questions I would like to reflect on, please discuss them:"
+        }
+        (Locale::Ru, "reflection_context_intro") => {
+            "Вопросы теста, над которыми я хочу подумать, пожалуйста, обсуди их:"
+        }
+        (Locale::En, "about_title") => "About the Test",
+        (Locale::Ru, "about_title") => "О тесте",
+        (Locale::En, "about_intro") => {
+            "The Big Five model describes personality along five broad domains: Neuroticism, Extraversion, Openness to Experience, Agreeableness and Conscientiousness. It is the most widely used personality model in psychological research."
+        }
+        (Locale::Ru, "about_intro") => {
+            "Модель Big Five описывает личность через пять широких измерений: нейротизм, экстраверсию, открытость опыту, доброжелательность и добросовестность. Это самая распространённая модель личности в психологических исследованиях."
+        }
+        (Locale::En, "about_scoring") => {
+            "This test uses the IPIP-NEO-120 inventory from the public-domain International Personality Item Pool. Each domain is split into six facets of four questions each; answers are scored on a 1-5 scale, giving 4-20 points per facet and 24-120 per domain."
+        }
+        (Locale::Ru, "about_scoring") => {
+            "Тест использует опросник IPIP-NEO-120 из общедоступного банка International Personality Item Pool. Каждое измерение делится на шесть аспектов по четыре вопроса; ответы оцениваются по шкале 1-5, что даёт 4-20 баллов на аспект и 24-120 на измерение."
+        }
+        (Locale::En, "about_ai") => {
+            "After the test you can optionally ask an AI model to write a narrative analysis of your profile. It is a reflection aid, not a clinical assessment."
+        }
+        (Locale::Ru, "about_ai") => {
+            "После теста можно по желанию попросить ИИ-модель написать развёрнутый анализ вашего профиля. Это инструмент для размышлений, а не клиническая оценка."
+        }
+        (Locale::En, "faq_accuracy_q") => "How accurate is this test?",
+        (Locale::Ru, "faq_accuracy_q") => "Насколько точен этот тест?",
+        (Locale::En, "faq_accuracy_a") => {
+            "The IPIP-NEO-120 is a well-validated research instrument, but any self-report reflects how you see yourself at the moment. Treat the results as a starting point for reflection, not a diagnosis."
+        }
+        (Locale::Ru, "faq_accuracy_a") => {
+            "IPIP-NEO-120 — хорошо валидированный исследовательский инструмент, но любой самоотчёт отражает то, как вы видите себя сейчас. Воспринимайте результаты как повод для размышлений, а не как диагноз."
+        }
+        (Locale::En, "faq_time_q") => "How long does it take?",
+        (Locale::Ru, "faq_time_q") => "Сколько времени это займёт?",
+        (Locale::En, "faq_time_a") => {
+            "About 15 minutes for 120 short statements. Your answers are saved in the browser as you go."
+        }
+        (Locale::Ru, "faq_time_a") => {
+            "Около 15 минут на 120 коротких утверждений. Ответы сохраняются в браузере по ходу теста."
+        }
+        (Locale::En, "faq_data_q") => "Where are my answers stored?",
+        (Locale::Ru, "faq_data_q") => "Где хранятся мои ответы?",
+        (Locale::En, "faq_data_a") => {
+            "Your answers and scores stay in your browser. Results are only stored on the server when you create a share link, and you can delete a shared result at any time."
+        }
+        (Locale::Ru, "faq_data_a") => {
+            "Ответы и баллы остаются в вашем браузере. Результаты сохраняются на сервере только при создании ссылки, и вы можете удалить их в любой момент."
+        }
+        (Locale::En, "faq_ai_q") => "What is sent to the AI model?",
+        (Locale::Ru, "faq_ai_q") => "Что отправляется ИИ-модели?",
+        (Locale::En, "faq_ai_a") => {
+            "Only your scores and the optional notes you write about yourself, and only when you request an analysis."
+        }
+        (Locale::Ru, "faq_ai_a") => {
+            "Только ваши баллы и необязательные заметки о себе, и только когда вы запрашиваете анализ."
+        }
+        (Locale::En, "faq_share_q") => "Can I share my results?",
+        (Locale::Ru, "faq_share_q") => "Можно ли поделиться результатами?",
+        (Locale::En, "faq_share_a") => {
+            "Yes. The Share button creates a link; you choose whether it includes the analysis and your notes, and when it expires."
+        }
+        (Locale::Ru, "faq_share_a") => {
+            "Да. Кнопка «Поделиться» создаёт ссылку; вы выбираете, будут ли в ней анализ и заметки и когда она истечёт."
+        }
+
         // Home page
         (Locale::En, "home_subtitle") => "Discover Your Personality",
         (Locale::Ru, "home_subtitle") => "Узнайте свою личность",
@@ -145,6 +321,81 @@ pub fn t(locale: Locale, key: &str) -> &'static str {
         (Locale::En, "home_time_estimate") => "~15 minutes",
         (Locale::Ru, "home_time_estimate") => "~15 минут",
 
+        (Locale::En, "changes_title") => "Since your previous test",
+        (Locale::Ru, "changes_title") => "С прошлого теста",
+        (Locale::En, "changes_none") => "No big changes",
+        (Locale::Ru, "changes_none") => "Без заметных изменений",
+        (Locale::En, "changes_show_all") => "See all changes",
+        (Locale::Ru, "changes_show_all") => "Все изменения",
+        (Locale::En, "changes_before") => "Before",
+        (Locale::Ru, "changes_before") => "Было",
+        (Locale::En, "changes_after") => "Now",
+        (Locale::Ru, "changes_after") => "Сейчас",
+        (Locale::En, "history_title") => "Your shared results",
+        (Locale::Ru, "history_title") => "Ваши сохранённые результаты",
+
+        // Locale and theme selectors
+        (Locale::En, "lang_label") => "Language",
+        (Locale::Ru, "lang_label") => "Язык",
+        (Locale::En, "theme_label") => "Theme",
+        (Locale::Ru, "theme_label") => "Тема",
+        (Locale::En, "theme_light") => "Light",
+        (Locale::Ru, "theme_light") => "Светлая",
+        (Locale::En, "theme_dark") => "Dark",
+        (Locale::Ru, "theme_dark") => "Тёмная",
+        (Locale::En, "theme_system") => "System",
+        (Locale::Ru, "theme_system") => "Системная",
+
+        // Save & continue later
+        (Locale::En, "resume_save_button") => "Save & continue later",
+        (Locale::Ru, "resume_save_button") => "Сохранить и продолжить позже",
+        (Locale::En, "resume_saving") => "Saving...",
+        (Locale::Ru, "resume_saving") => "Сохранение...",
+        (Locale::En, "resume_saved") => {
+            "Progress saved. Use this code or link to continue on any device:"
+        }
+        (Locale::Ru, "resume_saved") => {
+            "Прогресс сохранён. Продолжите на любом устройстве по этому коду или ссылке:"
+        }
+        (Locale::En, "resume_link_label") => "Resume link",
+        (Locale::Ru, "resume_link_label") => "Ссылка для продолжения",
+        (Locale::En, "resume_expiry_note") => "Saved progress is kept for 30 days.",
+        (Locale::Ru, "resume_expiry_note") => "Сохранённый прогресс хранится 30 дней.",
+        (Locale::En, "resume_save_error") => "Failed to save progress. Please try again.",
+        (Locale::Ru, "resume_save_error") => "Не удалось сохранить прогресс. Попробуйте ещё раз.",
+        (Locale::En, "resume_enter_label") => "Have a resume code? Continue where you left off:",
+        (Locale::Ru, "resume_enter_label") => "Есть код продолжения? Продолжите с того же места:",
+        (Locale::En, "resume_continue_button") => "Continue",
+        (Locale::Ru, "resume_continue_button") => "Продолжить",
+        (Locale::En, "resume_invalid_code") => {
+            "A resume code has 8 letters and digits, e.g. ABCD-EFGH."
+        }
+        (Locale::Ru, "resume_invalid_code") => "Код состоит из 8 букв и цифр, например ABCD-EFGH.",
+        (Locale::En, "resume_not_found") => {
+            "This resume code is unknown or has expired. Your local progress is unchanged."
+        }
+        (Locale::Ru, "resume_not_found") => {
+            "Код не найден или срок его действия истёк. Локальный прогресс не изменён."
+        }
+        (Locale::En, "progress_qr_button") => "Continue on another device",
+        (Locale::Ru, "progress_qr_button") => "Продолжить на другом устройстве",
+        (Locale::En, "progress_qr_hide") => "Hide QR code",
+        (Locale::Ru, "progress_qr_hide") => "Скрыть QR-код",
+        (Locale::En, "progress_qr_label") => "QR code with your answers so far",
+        (Locale::Ru, "progress_qr_label") => "QR-код с вашими ответами",
+        (Locale::En, "progress_qr_hint") => {
+            "Scan it with the other device's camera to continue there. The answers are in the code itself and are not stored on the server."
+        }
+        (Locale::Ru, "progress_qr_hint") => {
+            "Отсканируйте его камерой другого устройства, чтобы продолжить там. Ответы хранятся в самом коде и не сохраняются на сервере."
+        }
+        (Locale::En, "progress_code_invalid") => {
+            "This link's answers can't be read. Your local progress is unchanged."
+        }
+        (Locale::Ru, "progress_code_invalid") => {
+            "Не удалось прочитать ответы из ссылки. Локальный прогресс не изменён."
+        }
+
         (Locale::En, "home_start_button") => "Start Test",
         (Locale::Ru, "home_start_button") => "Начать тест",
 
@@ -177,28 +428,209 @@ pub fn t(locale: Locale, key: &str) -> &'static str {
         (Locale::En, "test_show_results") => "Show Results",
         (Locale::Ru, "test_show_results") => "Показать результаты",
 
+        (Locale::En, "complete_title") => "All done!",
+        (Locale::Ru, "complete_title") => "Готово!",
+        (Locale::En, "complete_description") => {
+            "You've answered all 120 questions. Preparing your results..."
+        }
+        (Locale::Ru, "complete_description") => {
+            "Вы ответили на все 120 вопросов. Готовим результаты..."
+        }
+        (Locale::En, "complete_time") => "Time taken",
+        (Locale::Ru, "complete_time") => "Затраченное время",
+        (Locale::En, "unit_min") => "min",
+        (Locale::Ru, "unit_min") => "мин",
+        (Locale::En, "unit_sec") => "s",
+        (Locale::Ru, "unit_sec") => "с",
+        (Locale::En, "complete_quality_good") => {
+            "Your answers look varied and considered, so your profile should be informative."
+        }
+        (Locale::Ru, "complete_quality_good") => {
+            "Ваши ответы разнообразны и обдуманы, так что профиль должен быть информативным."
+        }
+        (Locale::En, "complete_quality_uniform") => {
+            "You picked the same answer for most questions, so your scores may not reflect your personality well."
+        }
+        (Locale::Ru, "complete_quality_uniform") => {
+            "Вы выбирали один и тот же ответ на большинство вопросов, поэтому баллы могут плохо отражать вашу личность."
+        }
+        (Locale::En, "complete_quality_rushed") => {
+            "You finished very quickly. If you rushed, consider retaking the test later for more reliable scores."
+        }
+        (Locale::Ru, "complete_quality_rushed") => {
+            "Вы закончили очень быстро. Если вы торопились, пройдите тест позже ещё раз для более надёжных результатов."
+        }
+
         (Locale::En, "test_answered") => "Answered",
         (Locale::Ru, "test_answered") => "Отвечено",
-
-        // Answer options
-        (Locale::En, "answer_1") => "Very Inaccurate",
-        (Locale::Ru, "answer_1") => "Совершенно не соответствует",
-
-        (Locale::En, "answer_2") => "Moderately Inaccurate",
-        (Locale::Ru, "answer_2") => "Скорее не соответствует",
-
-        (Locale::En, "answer_3") => "Neither Accurate Nor Inaccurate",
-        (Locale::Ru, "answer_3") => "Нейтрально",
-
-        (Locale::En, "answer_4") => "Moderately Accurate",
-        (Locale::Ru, "answer_4") => "Скорее соответствует",
-
-        (Locale::En, "answer_5") => "Very Accurate",
-        (Locale::Ru, "answer_5") => "Полностью соответствует",
+        (Locale::En, "test_unanswered") => "Not answered",
+        (Locale::Ru, "test_unanswered") => "Без ответа",
+        (Locale::En, "test_bookmark") => "Reflect on later",
+        (Locale::Ru, "test_bookmark") => "Обдумать позже",
+        (Locale::En, "test_bookmarked") => "Saved for later",
+        (Locale::Ru, "test_bookmarked") => "Сохранён на потом",
+        (Locale::En, "test_flag") => "Flag for review",
+        (Locale::Ru, "test_flag") => "Отметить для проверки",
+        (Locale::En, "test_flagged") => "Flagged",
+        (Locale::Ru, "test_flagged") => "Отмечен",
+        (Locale::En, "test_navigator") => "All questions",
+        (Locale::Ru, "test_navigator") => "Все вопросы",
+        (Locale::En, "review_title") => "Review before submitting",
+        (Locale::Ru, "review_title") => "Проверка перед отправкой",
+        (Locale::En, "review_no_flagged") => {
+            "All questions are answered and none are flagged. Ready to see your results?"
+        }
+        (Locale::Ru, "review_no_flagged") => {
+            "Все вопросы отвечены, отмеченных нет. Готовы увидеть результаты?"
+        }
+        (Locale::En, "review_flagged_count") => "Questions you flagged to revisit:",
+        (Locale::Ru, "review_flagged_count") => "Вопросы, отмеченные для проверки:",
+        (Locale::En, "review_revisit") => "Revisit",
+        (Locale::Ru, "review_revisit") => "Вернуться",
+        (Locale::En, "review_unflag") => "Unflag",
+        (Locale::Ru, "review_unflag") => "Снять отметку",
+        (Locale::En, "review_distribution_title") => "Your answers",
+        (Locale::Ru, "review_distribution_title") => "Ваши ответы",
+        (Locale::En, "review_most_common") => "of your answers were",
+        (Locale::Ru, "review_most_common") => "ваших ответов —",
+        (Locale::En, "review_quality_uniform") => {
+            "You picked the same answer for most questions. Scores from such answers often don't reflect your personality well; consider revising before you continue."
+        }
+        (Locale::Ru, "review_quality_uniform") => {
+            "Вы выбрали один и тот же ответ на большинство вопросов. Такие ответы часто плохо отражают личность; возможно, стоит их пересмотреть."
+        }
+        (Locale::En, "review_quality_rushed") => {
+            "You answered very quickly. Consider going through your answers once more before you continue."
+        }
+        (Locale::Ru, "review_quality_rushed") => {
+            "Вы ответили очень быстро. Возможно, стоит ещё раз просмотреть ответы перед продолжением."
+        }
+        (Locale::En, "review_revise") => "Revise answers",
+        (Locale::Ru, "review_revise") => "Пересмотреть ответы",
+        (Locale::En, "review_submit_anyway") => "Show results anyway",
+        (Locale::Ru, "review_submit_anyway") => "Всё равно показать результаты",
+        (Locale::En, "scoring_error_title") => "We couldn't calculate your results",
+        (Locale::Ru, "scoring_error_title") => "Не удалось рассчитать результаты",
+        (Locale::En, "scoring_error_unknown") => {
+            "Something went wrong while scoring your answers. Please try again, or restart the test if it keeps happening."
+        }
+        (Locale::Ru, "scoring_error_unknown") => {
+            "При подсчёте ответов произошла ошибка. Попробуйте ещё раз или начните тест заново, если ошибка повторяется."
+        }
+        (Locale::En, "scoring_issue_missing") => "no answer",
+        (Locale::Ru, "scoring_issue_missing") => "нет ответа",
+        (Locale::En, "scoring_issue_invalid_value") => "invalid answer",
+        (Locale::Ru, "scoring_issue_invalid_value") => "недопустимый ответ",
+        (Locale::En, "scoring_issue_duplicate") => "answered more than once",
+        (Locale::Ru, "scoring_issue_duplicate") => "ответ дан несколько раз",
+        (Locale::En, "scoring_issue_unknown") => "an answer refers to an unknown question",
+        (Locale::Ru, "scoring_issue_unknown") => "ответ относится к неизвестному вопросу",
+        (Locale::En, "scoring_go_to_question") => "Go to question",
+        (Locale::Ru, "scoring_go_to_question") => "Перейти к вопросу",
+        (Locale::En, "scoring_more_issues") => "More issues:",
+        (Locale::Ru, "scoring_more_issues") => "Ещё проблем:",
 
         // Results page
         (Locale::En, "results_title") => "Your Results",
         (Locale::Ru, "results_title") => "Ваши результаты",
+        (Locale::En, "summary_title") => "At a glance",
+        (Locale::Ru, "summary_title") => "Кратко",
+        (Locale::En, "summary_n_low") => {
+            "You tend to stay calm under pressure and recover quickly from setbacks."
+        }
+        (Locale::Ru, "summary_n_low") => {
+            "Вы обычно сохраняете спокойствие под давлением и быстро восстанавливаетесь после неудач."
+        }
+        (Locale::En, "summary_n_neutral") => {
+            "You feel stress and worry about as often as most people, without being ruled by them."
+        }
+        (Locale::Ru, "summary_n_neutral") => {
+            "Вы испытываете стресс и тревогу примерно так же часто, как большинство людей, но они вами не управляют."
+        }
+        (Locale::En, "summary_n_high") => {
+            "You feel emotions intensely and are quick to notice when something might go wrong."
+        }
+        (Locale::Ru, "summary_n_high") => {
+            "Вы остро переживаете эмоции и быстро замечаете, когда что-то может пойти не так."
+        }
+        (Locale::En, "summary_e_low") => {
+            "You prefer quieter settings and recharge best with time on your own."
+        }
+        (Locale::Ru, "summary_e_low") => {
+            "Вы предпочитаете спокойную обстановку и лучше всего восстанавливаетесь наедине с собой."
+        }
+        (Locale::En, "summary_e_neutral") => {
+            "You enjoy company but also value your own time, adapting to the situation."
+        }
+        (Locale::Ru, "summary_e_neutral") => {
+            "Вам нравится общество, но вы цените и время для себя, подстраиваясь под ситуацию."
+        }
+        (Locale::En, "summary_e_high") => {
+            "You draw energy from people and activity and are comfortable taking the lead."
+        }
+        (Locale::Ru, "summary_e_high") => {
+            "Вы черпаете энергию в людях и активности и легко берёте на себя инициативу."
+        }
+        (Locale::En, "summary_o_low") => {
+            "You value the familiar and practical, and prefer proven ways of doing things."
+        }
+        (Locale::Ru, "summary_o_low") => {
+            "Вы цените привычное и практичное и предпочитаете проверенные способы действий."
+        }
+        (Locale::En, "summary_o_neutral") => {
+            "You balance curiosity about new ideas with a preference for what works."
+        }
+        (Locale::Ru, "summary_o_neutral") => {
+            "Вы сочетаете интерес к новым идеям с предпочтением того, что работает."
+        }
+        (Locale::En, "summary_o_high") => {
+            "You are curious and imaginative, drawn to new ideas, art and experiences."
+        }
+        (Locale::Ru, "summary_o_high") => {
+            "Вы любознательны и изобретательны, вас привлекают новые идеи, искусство и впечатления."
+        }
+        (Locale::En, "summary_a_low") => {
+            "You are direct and competitive, and not afraid to put your own interests first."
+        }
+        (Locale::Ru, "summary_a_low") => {
+            "Вы прямолинейны и склонны к соперничеству и не боитесь ставить свои интересы на первое место."
+        }
+        (Locale::En, "summary_a_neutral") => {
+            "You are generally cooperative but can stand your ground when it matters."
+        }
+        (Locale::Ru, "summary_a_neutral") => {
+            "Вы в целом склонны к сотрудничеству, но умеете отстоять свою позицию, когда это важно."
+        }
+        (Locale::En, "summary_a_high") => {
+            "You are warm and trusting, and care a lot about getting along with others."
+        }
+        (Locale::Ru, "summary_a_high") => {
+            "Вы доброжелательны и доверчивы и очень цените хорошие отношения с другими."
+        }
+        (Locale::En, "summary_c_low") => {
+            "You are flexible and spontaneous, preferring to keep your options open over strict plans."
+        }
+        (Locale::Ru, "summary_c_low") => {
+            "Вы гибки и спонтанны и предпочитаете оставлять варианты открытыми, а не следовать строгим планам."
+        }
+        (Locale::En, "summary_c_neutral") => {
+            "You can be organized when needed while staying open to change."
+        }
+        (Locale::Ru, "summary_c_neutral") => {
+            "Вы умеете быть организованным, когда нужно, оставаясь открытым к переменам."
+        }
+        (Locale::En, "summary_c_high") => {
+            "You are organized and dependable, and like to plan ahead and see things through."
+        }
+        (Locale::Ru, "summary_c_high") => {
+            "Вы организованны и надёжны, любите планировать заранее и доводить дела до конца."
+        }
+        (Locale::En, "results_meta_title") => "Big Five results",
+        (Locale::Ru, "results_meta_title") => "Результаты Big Five",
+        (Locale::En, "results_meta_balanced") => "balanced profile",
+        (Locale::Ru, "results_meta_balanced") => "сбалансированный профиль",
+        (Locale::En, "results_meta_cta") => "Take the free IPIP-NEO-120 personality test yourself.",
+        (Locale::Ru, "results_meta_cta") => "Пройдите бесплатный тест личности IPIP-NEO-120 сами.",
 
         (Locale::En, "results_ai_title") => "AI Personality Analysis",
         (Locale::Ru, "results_ai_title") => "AI-анализ личности",
@@ -218,6 +650,16 @@ pub fn t(locale: Locale, key: &str) -> &'static str {
 
         (Locale::En, "results_ai_loading_hint") => "This usually takes about a minute...",
         (Locale::Ru, "results_ai_loading_hint") => "Обычно это занимает около минуты...",
+        (Locale::En, "results_ai_elapsed") => "Elapsed:",
+        (Locale::Ru, "results_ai_elapsed") => "Прошло:",
+        (Locale::En, "results_ai_eta") => "time left:",
+        (Locale::Ru, "results_ai_eta") => "осталось:",
+        (Locale::En, "results_ai_slow") => {
+            "Taking longer than usual for this model. You can keep waiting, or try again later with a faster model."
+        }
+        (Locale::Ru, "results_ai_slow") => {
+            "Для этой модели это дольше обычного. Можно подождать ещё или позже попробовать более быструю модель."
+        }
 
         (Locale::En, "results_ai_error") => "Failed to generate analysis",
         (Locale::Ru, "results_ai_error") => "Не удалось сгенерировать анализ",
@@ -225,6 +667,33 @@ pub fn t(locale: Locale, key: &str) -> &'static str {
         (Locale::En, "results_ai_retry") => "Try Again",
         (Locale::Ru, "results_ai_retry") => "Попробовать снова",
 
+        (Locale::En, "ai_consent_title") => "Send your results to an AI model?",
+        (Locale::Ru, "ai_consent_title") => "Отправить результаты AI-модели?",
+        (Locale::En, "ai_consent_text") => {
+            "To write the analysis, your test scores are sent to the selected model, which is run by a third-party provider. The provider processes them under its own terms."
+        }
+        (Locale::Ru, "ai_consent_text") => {
+            "Чтобы составить анализ, ваши баллы отправляются выбранной модели, которую обслуживает сторонний провайдер. Провайдер обрабатывает их на своих условиях."
+        }
+        (Locale::En, "ai_consent_text_context") => {
+            "To write the analysis, your test scores and the notes you wrote about yourself are sent to the selected model, which is run by a third-party provider. The provider processes them under its own terms."
+        }
+        (Locale::Ru, "ai_consent_text_context") => {
+            "Чтобы составить анализ, ваши баллы и заметки о себе отправляются выбранной модели, которую обслуживает сторонний провайдер. Провайдер обрабатывает их на своих условиях."
+        }
+        (Locale::En, "ai_consent_model") => "Model:",
+        (Locale::Ru, "ai_consent_model") => "Модель:",
+        (Locale::En, "ai_consent_note") => {
+            "Avoid including names or contact details in your notes. You'll only be asked once per browser session."
+        }
+        (Locale::Ru, "ai_consent_note") => {
+            "Не указывайте в заметках имена и контактные данные. Этот вопрос задаётся один раз за сеанс браузера."
+        }
+        (Locale::En, "ai_consent_confirm") => "Agree and continue",
+        (Locale::Ru, "ai_consent_confirm") => "Согласиться и продолжить",
+        (Locale::En, "ai_consent_cancel") => "Cancel",
+        (Locale::Ru, "ai_consent_cancel") => "Отмена",
+
         (Locale::En, "results_ai_regenerate") => "Regenerate",
         (Locale::Ru, "results_ai_regenerate") => "Сгенерировать заново",
 
@@ -250,6 +719,30 @@ pub fn t(locale: Locale, key: &str) -> &'static str {
 
         (Locale::En, "results_model_select") => "Analysis Model",
         (Locale::Ru, "results_model_select") => "Модель анализа",
+        (Locale::En, "feedback_question") => "Was this analysis helpful?",
+        (Locale::Ru, "feedback_question") => "Был ли этот анализ полезен?",
+        (Locale::En, "feedback_helpful") => "Helpful",
+        (Locale::Ru, "feedback_helpful") => "Полезно",
+        (Locale::En, "feedback_not_helpful") => "Not helpful",
+        (Locale::Ru, "feedback_not_helpful") => "Бесполезно",
+        (Locale::En, "feedback_comment_placeholder") => "Anything to add? (optional)",
+        (Locale::Ru, "feedback_comment_placeholder") => "Хотите что-то добавить? (необязательно)",
+        (Locale::En, "feedback_send") => "Send feedback",
+        (Locale::Ru, "feedback_send") => "Отправить отзыв",
+        (Locale::En, "feedback_thanks") => "Thanks for your feedback!",
+        (Locale::Ru, "feedback_thanks") => "Спасибо за отзыв!",
+        (Locale::En, "feedback_error") => "Couldn't send feedback. Please try again.",
+        (Locale::Ru, "feedback_error") => "Не удалось отправить отзыв. Попробуйте ещё раз.",
+        (Locale::En, "results_model_speed_fast") => "Fast",
+        (Locale::Ru, "results_model_speed_fast") => "Быстрая",
+        (Locale::En, "results_model_speed_medium") => "Medium speed",
+        (Locale::Ru, "results_model_speed_medium") => "Средняя скорость",
+        (Locale::En, "results_model_speed_slow") => "Slow",
+        (Locale::Ru, "results_model_speed_slow") => "Медленная",
+        (Locale::En, "results_model_thinking") => "Extended thinking",
+        (Locale::Ru, "results_model_thinking") => "Расширенное мышление",
+        (Locale::En, "results_model_translated") => "Translated",
+        (Locale::Ru, "results_model_translated") => "С переводом",
 
         (Locale::En, "results_retake") => "Retake Test",
         (Locale::Ru, "results_retake") => "Пройти тест заново",
@@ -260,6 +753,73 @@ pub fn t(locale: Locale, key: &str) -> &'static str {
         (Locale::En, "results_export_pdf") => "Export as PDF",
         (Locale::Ru, "results_export_pdf") => "Экспорт в PDF",
 
+        (Locale::En, "results_export_html") => "Export as HTML",
+        (Locale::Ru, "results_export_html") => "Экспорт в HTML",
+
+        (Locale::En, "export_notes_title") => "Notes",
+        (Locale::Ru, "export_notes_title") => "Заметки",
+
+        (Locale::En, "export_footer") => {
+            "Scored with the IPIP-NEO-120 inventory. A personality test is a self-description, not a diagnosis."
+        }
+        (Locale::Ru, "export_footer") => {
+            "Подсчитано по опроснику IPIP-NEO-120. Тест личности — это самоописание, а не диагноз."
+        }
+
+        (Locale::En, "quota_remaining") => "AI analyses left today:",
+        (Locale::Ru, "quota_remaining") => "AI-анализов осталось на сегодня:",
+
+        (Locale::En, "quota_exhausted") => {
+            "You've used today's AI analyses. The next one is available"
+        }
+        (Locale::Ru, "quota_exhausted") => {
+            "AI-анализы на сегодня закончились. Следующий будет доступен"
+        }
+
+        (Locale::En, "results_share") => "Share",
+        (Locale::Ru, "results_share") => "Поделиться",
+        (Locale::En, "share_title") => "Share results",
+        (Locale::Ru, "share_title") => "Поделиться результатами",
+        (Locale::En, "share_close") => "Close",
+        (Locale::Ru, "share_close") => "Закрыть",
+        (Locale::En, "share_visibility_label") => "People with the link see",
+        (Locale::Ru, "share_visibility_label") => "По ссылке будет видно",
+        (Locale::En, "share_visibility_full") => "Scores, analysis and notes",
+        (Locale::Ru, "share_visibility_full") => "Баллы, анализ и заметки",
+        (Locale::En, "share_visibility_scores") => "Scores only",
+        (Locale::Ru, "share_visibility_scores") => "Только баллы",
+        (Locale::En, "share_protected_label") => "Protect notes and analysis with a link key",
+        (Locale::Ru, "share_protected_label") => "Защитить заметки и анализ ключом в ссылке",
+        (Locale::En, "share_protected_hint") => {
+            "Without the full link, only the scores can be seen."
+        }
+        (Locale::Ru, "share_protected_hint") => "Без полной ссылки будут видны только баллы.",
+        (Locale::En, "share_expiry_label") => "Link expires",
+        (Locale::Ru, "share_expiry_label") => "Срок действия ссылки",
+        (Locale::En, "share_expiry_never") => "Never",
+        (Locale::Ru, "share_expiry_never") => "Бессрочно",
+        (Locale::En, "share_expiry_1_day") => "After 1 day",
+        (Locale::Ru, "share_expiry_1_day") => "Через 1 день",
+        (Locale::En, "share_expiry_7_days") => "After 7 days",
+        (Locale::Ru, "share_expiry_7_days") => "Через 7 дней",
+        (Locale::En, "share_expiry_30_days") => "After 30 days",
+        (Locale::Ru, "share_expiry_30_days") => "Через 30 дней",
+        (Locale::En, "share_create") => "Create link",
+        (Locale::Ru, "share_create") => "Создать ссылку",
+        (Locale::En, "share_create_fragment") => "Link without saving",
+        (Locale::Ru, "share_create_fragment") => "Ссылка без сохранения",
+        (Locale::En, "share_create_fragment_hint") => {
+            "Scores only, stored in the link itself. Nothing is saved on the server, so it can't be deleted or expire."
+        }
+        (Locale::Ru, "share_create_fragment_hint") => {
+            "Только баллы, записанные в самой ссылке. На сервере ничего не сохраняется, поэтому её нельзя удалить и у неё нет срока действия."
+        }
+        (Locale::En, "share_error") => "Couldn't create the link. Please try again.",
+        (Locale::Ru, "share_error") => "Не удалось создать ссылку. Попробуйте ещё раз.",
+        (Locale::En, "share_qr_label") => "QR code for the share link",
+        (Locale::Ru, "share_qr_label") => "QR-код ссылки",
+        (Locale::En, "share_qr_hint") => "Scan to open on another device",
+        (Locale::Ru, "share_qr_hint") => "Отсканируйте, чтобы открыть на другом устройстве",
         (Locale::En, "results_copy_link") => "Copy Link",
         (Locale::Ru, "results_copy_link") => "Скопировать ссылку",
 
@@ -269,13 +829,399 @@ pub fn t(locale: Locale, key: &str) -> &'static str {
         (Locale::En, "results_share_saving") => "Saving...",
         (Locale::Ru, "results_share_saving") => "Сохранение...",
 
+        (Locale::En, "results_nickname_label") => "Name on the shared link",
+        (Locale::Ru, "results_nickname_label") => "Имя для ссылки",
+
+        (Locale::En, "results_nickname_placeholder") => "e.g. Alex 🦊",
+        (Locale::Ru, "results_nickname_placeholder") => "например, Саша 🦊",
+
+        (Locale::En, "results_profile_of") => "Profile of",
+        (Locale::Ru, "results_profile_of") => "Профиль:",
+
+        (Locale::En, "reminder_title") => "Remind me to retake the test",
+        (Locale::Ru, "reminder_title") => "Напомнить пройти тест снова",
+
+        (Locale::En, "reminder_description") => {
+            "Personality changes slowly. Add a reminder to your calendar to take the test again in a few months and compare the results with this one."
+        }
+        (Locale::Ru, "reminder_description") => {
+            "Личность меняется медленно. Добавьте в календарь напоминание пройти тест через несколько месяцев и сравнить результаты с этим."
+        }
+
+        (Locale::En, "reminder_months_label") => "Remind me in",
+        (Locale::Ru, "reminder_months_label") => "Напомнить через",
+
+        (Locale::En, "reminder_months_unit") => "months",
+        (Locale::Ru, "reminder_months_unit") => "мес.",
+
+        (Locale::En, "reminder_download") => "Add to calendar (.ics)",
+        (Locale::Ru, "reminder_download") => "Добавить в календарь (.ics)",
+
+        (Locale::En, "reminder_event_summary") => "Retake the Big Five personality test",
+        (Locale::Ru, "reminder_event_summary") => "Пройти тест «Большая пятёрка» снова",
+
+        (Locale::En, "reminder_event_description") => {
+            "Time to take the test again and see how your scores have changed."
+        }
+        (Locale::Ru, "reminder_event_description") => {
+            "Пора пройти тест ещё раз и посмотреть, как изменились результаты."
+        }
+
+        (Locale::En, "reminder_event_test_link") => "Take the test",
+        (Locale::Ru, "reminder_event_test_link") => "Пройти тест",
+
+        (Locale::En, "reminder_event_result_link") => "Previous result",
+        (Locale::Ru, "reminder_event_result_link") => "Прошлый результат",
+
+        (Locale::En, "results_delete") => "Delete this result",
+        (Locale::Ru, "results_delete") => "Удалить результат",
+
+        (Locale::En, "results_delete_confirm_title") => "Delete this result?",
+        (Locale::Ru, "results_delete_confirm_title") => "Удалить этот результат?",
+
+        (Locale::En, "results_delete_confirm_text") => {
+            "The shared link will stop working for everyone. You can undo this for a few days."
+        }
+        (Locale::Ru, "results_delete_confirm_text") => {
+            "Ссылка перестанет работать для всех. Удаление можно отменить в течение нескольких дней."
+        }
+
+        (Locale::En, "results_delete_confirm_button") => "Delete",
+        (Locale::Ru, "results_delete_confirm_button") => "Удалить",
+
+        (Locale::En, "results_delete_cancel") => "Cancel",
+        (Locale::Ru, "results_delete_cancel") => "Отмена",
+
+        (Locale::En, "results_deleted_title") => "Result deleted. The shared link no longer works.",
+        (Locale::Ru, "results_deleted_title") => "Результат удалён. Ссылка больше не работает.",
+
+        (Locale::En, "results_deleted_undo_until") => "You can undo this until",
+        (Locale::Ru, "results_deleted_undo_until") => "Удаление можно отменить до",
+
+        (Locale::En, "results_restore") => "Undo delete",
+        (Locale::Ru, "results_restore") => "Отменить удаление",
+
+        (Locale::En, "results_restore_error") => "Failed to restore the result.",
+        (Locale::Ru, "results_restore_error") => "Не удалось восстановить результат.",
+
+        (Locale::En, "results_deleting") => "Deleting...",
+        (Locale::Ru, "results_deleting") => "Удаление...",
+
+        (Locale::En, "results_delete_error") => "Failed to delete the result. Please try again.",
+        (Locale::Ru, "results_delete_error") => "Не удалось удалить результат. Попробуйте ещё раз.",
+
         (Locale::En, "results_not_found") => "Results not found",
         (Locale::Ru, "results_not_found") => "Результаты не найдены",
 
+        (Locale::En, "results_offline_notice") => {
+            "You're offline, so this is the copy saved when you last opened the link. AI features come back once you're connected."
+        }
+        (Locale::Ru, "results_offline_notice") => {
+            "Нет подключения к сети, поэтому показана копия, сохранённая при прошлом открытии ссылки. AI-функции станут доступны после подключения."
+        }
+        (Locale::En, "results_locked_notice") => {
+            "Notes and analysis for this result are only visible with the full link."
+        }
+        (Locale::Ru, "results_locked_notice") => {
+            "Заметки и анализ этого результата доступны только по полной ссылке."
+        }
+
         (Locale::En, "results_ai_not_generated") => "AI analysis has not been generated yet.",
         (Locale::Ru, "results_ai_not_generated") => "AI-анализ ещё не был сгенерирован.",
 
+        // Demographics step
+        (Locale::En, "demographics_title") => "A few questions about you",
+        (Locale::Ru, "demographics_title") => "Несколько вопросов о вас",
+        (Locale::En, "demographics_description") => {
+            "Optional: your age range, gender and country let results be compared with people like you. Every field can be left empty, or you can skip this step."
+        }
+        (Locale::Ru, "demographics_description") => {
+            "Необязательно: возраст, пол и страна позволяют сравнить результаты с похожими на вас людьми. Любое поле можно оставить пустым или пропустить этот шаг."
+        }
+        (Locale::En, "demographics_age") => "Age",
+        (Locale::Ru, "demographics_age") => "Возраст",
+        (Locale::En, "demographics_gender") => "Gender",
+        (Locale::Ru, "demographics_gender") => "Пол",
+        (Locale::En, "demographics_country") => "Country",
+        (Locale::Ru, "demographics_country") => "Страна",
+        (Locale::En, "demographics_prefer_not") => "Prefer not to say",
+        (Locale::Ru, "demographics_prefer_not") => "Не указывать",
+        (Locale::En, "demographics_gender_female") => "Female",
+        (Locale::Ru, "demographics_gender_female") => "Женский",
+        (Locale::En, "demographics_gender_male") => "Male",
+        (Locale::Ru, "demographics_gender_male") => "Мужской",
+        (Locale::En, "demographics_gender_other") => "Other",
+        (Locale::Ru, "demographics_gender_other") => "Другой",
+        (Locale::En, "demographics_continue") => "Continue to the test",
+        (Locale::Ru, "demographics_continue") => "Перейти к тесту",
+        (Locale::En, "demographics_skip") => "Skip",
+        (Locale::Ru, "demographics_skip") => "Пропустить",
+
+        // Statistics page
+        (Locale::En, "stats_title") => "How people score",
+        (Locale::Ru, "stats_title") => "Результаты других",
+        (Locale::En, "stats_description") => {
+            "Distribution of each trait over the results people chose to share on this site. Your latest result from this browser is marked."
+        }
+        (Locale::Ru, "stats_description") => {
+            "Распределение каждой черты по результатам, которыми пользователи решили поделиться на этом сайте. Ваш последний результат в этом браузере отмечен."
+        }
+        (Locale::En, "stats_sample_size") => "Shared results counted:",
+        (Locale::Ru, "stats_sample_size") => "Учтено результатов:",
+        (Locale::En, "stats_empty") => "No statistics yet. They are updated once a day.",
+        (Locale::Ru, "stats_empty") => "Статистики пока нет. Она обновляется раз в день.",
+        (Locale::En, "stats_you") => "You",
+        (Locale::Ru, "stats_you") => "Вы",
+        (Locale::En, "stats_take_test") => "Take the test to see where you fall.",
+        (Locale::Ru, "stats_take_test") => "Пройдите тест, чтобы увидеть своё место.",
+
+        // Norm groups
+        (Locale::En, "norms_compare_with") => "Compare with",
+        (Locale::Ru, "norms_compare_with") => "Сравнивать с",
+        (Locale::En, "norms_group_all") => "Everyone who took the test",
+        (Locale::Ru, "norms_group_all") => "Все, кто прошёл тест",
+        (Locale::En, "norms_group_language") => "People who took it in this language",
+        (Locale::Ru, "norms_group_language") => "Прошедшие тест на этом языке",
+        (Locale::En, "norms_group_country") => "People from",
+        (Locale::Ru, "norms_group_country") => "Жители страны:",
+        (Locale::En, "norms_not_enough") => "Not enough results in this group yet.",
+        (Locale::Ru, "norms_not_enough") => "В этой группе пока недостаточно результатов.",
+        (Locale::En, "norms_higher_than") => "Higher than in this group:",
+        (Locale::Ru, "norms_higher_than") => "Выше, чем у группы:",
+
+        // Research donation
+        (Locale::En, "research_title") => "Help research",
+        (Locale::Ru, "research_title") => "Помогите исследованиям",
+        (Locale::En, "research_description") => {
+            "You can donate your answers to an anonymized research dataset used to compute norms. Only your answers, the interface language and the optional details below are stored, with no link to your results."
+        }
+        (Locale::Ru, "research_description") => {
+            "Вы можете передать свои ответы в анонимный набор данных для расчёта норм. Сохраняются только ответы, язык интерфейса и необязательные данные ниже, без связи с вашими результатами."
+        }
+        (Locale::En, "research_consent") => "I agree to donate my anonymized answers for research",
+        (Locale::Ru, "research_consent") => {
+            "Я согласен(на) передать свои анонимные ответы для исследований"
+        }
+        (Locale::En, "research_submit") => "Donate answers",
+        (Locale::Ru, "research_submit") => "Передать ответы",
+        (Locale::En, "research_thanks") => {
+            "Thank you! Your answers were added to the research dataset."
+        }
+        (Locale::Ru, "research_thanks") => "Спасибо! Ваши ответы добавлены в набор данных.",
+        (Locale::En, "research_error") => "Failed to send your answers. Please try again.",
+        (Locale::Ru, "research_error") => "Не удалось отправить ответы. Попробуйте ещё раз.",
+
+        // Question timing consent
+        (Locale::En, "question_timing_title") => "Help improve the questions",
+        (Locale::Ru, "question_timing_title") => "Помогите улучшить вопросы",
+        (Locale::En, "question_timing_description") => {
+            "You can share how long you spent on each question and which ones you went back to. Only totals per question are kept, without your answers or any link to your results."
+        }
+        (Locale::Ru, "question_timing_description") => {
+            "Вы можете передать, сколько времени заняли вопросы и к каким вы возвращались. Сохраняются только суммы по каждому вопросу, без ваших ответов и связи с результатами."
+        }
+        (Locale::En, "question_timing_submit") => "Share timings",
+        (Locale::Ru, "question_timing_submit") => "Передать время",
+        (Locale::En, "question_timing_thanks") => "Thank you! Your timings were added.",
+        (Locale::Ru, "question_timing_thanks") => "Спасибо! Данные о времени добавлены.",
+        (Locale::En, "question_timing_error") => "Failed to send the timings. Please try again.",
+        (Locale::Ru, "question_timing_error") => {
+            "Не удалось отправить данные о времени. Попробуйте ещё раз."
+        }
+
+        // Admin page
+        (Locale::En, "admin_title") => "Result moderation",
+        (Locale::Ru, "admin_title") => "Модерация результатов",
+        (Locale::En, "admin_token") => "Admin token",
+        (Locale::Ru, "admin_token") => "Токен администратора",
+        (Locale::En, "admin_filter_id") => "Result ID",
+        (Locale::Ru, "admin_filter_id") => "ID результата",
+        (Locale::En, "admin_filter_lang") => "Language",
+        (Locale::Ru, "admin_filter_lang") => "Язык",
+        (Locale::En, "admin_filter_from") => "From",
+        (Locale::Ru, "admin_filter_from") => "С",
+        (Locale::En, "admin_filter_to") => "To",
+        (Locale::Ru, "admin_filter_to") => "По",
+        (Locale::En, "admin_search") => "Search",
+        (Locale::Ru, "admin_search") => "Найти",
+        (Locale::En, "admin_results") => "Results",
+        (Locale::Ru, "admin_results") => "Результаты",
+        (Locale::En, "admin_recent_analyses") => "Recent AI analyses",
+        (Locale::Ru, "admin_recent_analyses") => "Последние AI-анализы",
+        (Locale::En, "admin_success") => "Succeeded",
+        (Locale::Ru, "admin_success") => "Успешно",
+        (Locale::En, "admin_failed") => "Failed",
+        (Locale::Ru, "admin_failed") => "Ошибка",
+        (Locale::En, "admin_expires") => "Expires",
+        (Locale::Ru, "admin_expires") => "Истекает",
+        (Locale::En, "admin_protected") => "Key-protected",
+        (Locale::Ru, "admin_protected") => "Защищён ключом",
+        (Locale::En, "admin_unpublished") => "Unpublished",
+        (Locale::Ru, "admin_unpublished") => "Скрыт",
+        (Locale::En, "admin_deleted") => "Deleted by owner",
+        (Locale::Ru, "admin_deleted") => "Удалён владельцем",
+        (Locale::En, "admin_has_analysis") => "AI analysis",
+        (Locale::Ru, "admin_has_analysis") => "AI-анализ",
+        (Locale::En, "admin_no_analysis") => "No AI analysis",
+        (Locale::Ru, "admin_no_analysis") => "Без AI-анализа",
+        (Locale::En, "admin_show_details") => "Show details",
+        (Locale::Ru, "admin_show_details") => "Подробнее",
+        (Locale::En, "admin_hide_details") => "Hide details",
+        (Locale::Ru, "admin_hide_details") => "Скрыть",
+        (Locale::En, "admin_publish") => "Publish",
+        (Locale::Ru, "admin_publish") => "Опубликовать",
+        (Locale::En, "admin_unpublish") => "Unpublish",
+        (Locale::Ru, "admin_unpublish") => "Скрыть ссылку",
+        (Locale::En, "admin_purge") => "Purge",
+        (Locale::Ru, "admin_purge") => "Удалить",
+        (Locale::En, "admin_purge_confirm") => "Delete permanently",
+        (Locale::Ru, "admin_purge_confirm") => "Удалить навсегда",
+        (Locale::En, "admin_cancel") => "Cancel",
+        (Locale::Ru, "admin_cancel") => "Отмена",
+        (Locale::En, "admin_questions_title") => "Question timings",
+        (Locale::Ru, "admin_questions_title") => "Время на вопросы",
+        (Locale::En, "admin_questions_description") => {
+            "Shared by users after the test. Highlighted questions take much longer or are revisited much more often than the rest of their language, which often points at unclear wording or translation."
+        }
+        (Locale::Ru, "admin_questions_description") => {
+            "Передано пользователями после теста. Выделены вопросы, которые занимают намного больше времени или к которым возвращаются намного чаще, чем к остальным на том же языке, — часто это признак неясной формулировки или перевода."
+        }
+        (Locale::En, "admin_questions_empty") => "No timings shared yet.",
+        (Locale::Ru, "admin_questions_empty") => "Данных о времени пока нет.",
+        (Locale::En, "admin_questions_question") => "Question",
+        (Locale::Ru, "admin_questions_question") => "Вопрос",
+        (Locale::En, "admin_questions_responses") => "Tests",
+        (Locale::Ru, "admin_questions_responses") => "Тестов",
+        (Locale::En, "admin_questions_time") => "Average time",
+        (Locale::Ru, "admin_questions_time") => "Среднее время",
+        (Locale::En, "admin_questions_revisits") => "Went back",
+        (Locale::Ru, "admin_questions_revisits") => "Возвраты",
+        (Locale::En, "admin_research_title") => "Research dataset",
+        (Locale::Ru, "admin_research_title") => "Данные для исследований",
+        (Locale::En, "admin_research_description") => {
+            "Answers donated by users, with optional age band and gender. No ids, dates or context text."
+        }
+        (Locale::Ru, "admin_research_description") => {
+            "Ответы, переданные пользователями, с необязательными возрастом и полом. Без идентификаторов, дат и текста контекста."
+        }
+        (Locale::En, "admin_research_prepare") => "Prepare CSV export",
+        (Locale::Ru, "admin_research_prepare") => "Подготовить CSV",
+        (Locale::En, "admin_research_download") => "Download CSV",
+        (Locale::Ru, "admin_research_download") => "Скачать CSV",
+        (Locale::En, "admin_provider_logs_title") => "Provider logs",
+        (Locale::Ru, "admin_provider_logs_title") => "Журнал запросов к моделям",
+        (Locale::En, "admin_provider_logs_description") => {
+            "Redacted requests and responses of failed analyses, kept for 14 days while debug_provider_logs is enabled."
+        }
+        (Locale::Ru, "admin_provider_logs_description") => {
+            "Запросы и ответы неудачных анализов без ключей и личных данных. Хранятся 14 дней, пока включён debug_provider_logs."
+        }
+        (Locale::En, "admin_provider_logs_job_id") => "Job id",
+        (Locale::Ru, "admin_provider_logs_job_id") => "Идентификатор задачи",
+        (Locale::En, "admin_provider_logs_find") => "Find",
+        (Locale::Ru, "admin_provider_logs_find") => "Найти",
+        (Locale::En, "admin_provider_logs_not_found") => "No log for this job.",
+        (Locale::Ru, "admin_provider_logs_not_found") => "Для этой задачи журнала нет.",
+        (Locale::En, "admin_cleanup_title") => "Cleanup",
+        (Locale::Ru, "admin_cleanup_title") => "Очистка",
+        (Locale::En, "admin_cleanup_description") => {
+            "Stale data removed by the hourly cleanup since the server started."
+        }
+        (Locale::Ru, "admin_cleanup_description") => {
+            "Устаревшие данные, удалённые ежечасной очисткой с момента запуска сервера."
+        }
+        (Locale::En, "admin_cleanup_runs") => "Runs",
+        (Locale::Ru, "admin_cleanup_runs") => "Запусков",
+        (Locale::En, "admin_cleanup_last_run") => "Last run",
+        (Locale::Ru, "admin_cleanup_last_run") => "Последний запуск",
+        (Locale::En, "admin_cleanup_results") => "Deleted results purged",
+        (Locale::Ru, "admin_cleanup_results") => "Удалённых результатов",
+        (Locale::En, "admin_cleanup_sessions") => "Expired resume sessions",
+        (Locale::Ru, "admin_cleanup_sessions") => "Истёкших сессий",
+        (Locale::En, "admin_cleanup_jobs") => "Abandoned analysis jobs",
+        (Locale::Ru, "admin_cleanup_jobs") => "Брошенных задач анализа",
+        (Locale::En, "admin_cleanup_feedback") => "Feedback of removed results",
+        (Locale::Ru, "admin_cleanup_feedback") => "Отзывов к удалённым результатам",
+        (Locale::En, "admin_cleanup_provider_logs") => "Old provider logs",
+        (Locale::Ru, "admin_cleanup_provider_logs") => "Старых журналов запросов",
+        (Locale::En, "admin_cleanup_audit_entries") => "Expired audit log entries",
+        (Locale::Ru, "admin_cleanup_audit_entries") => "Устаревших записей аудита",
+        (Locale::En, "admin_cleanup_quota_counters") => "Past days' analysis quota counters",
+        (Locale::Ru, "admin_cleanup_quota_counters") => "Счётчиков лимита анализов за прошлые дни",
+        (Locale::En, "admin_audit_title") => "Access log",
+        (Locale::Ru, "admin_audit_title") => "Журнал доступа",
+        (Locale::En, "admin_audit_empty") => {
+            "No recorded accesses (the audit log may be disabled)."
+        }
+        (Locale::Ru, "admin_audit_empty") => {
+            "Обращений не записано (журнал аудита может быть выключен)."
+        }
+        (Locale::En, "admin_context") => "Context",
+        (Locale::Ru, "admin_context") => "Контекст",
+        (Locale::En, "admin_analysis") => "Analysis",
+        (Locale::Ru, "admin_analysis") => "Анализ",
+
+        // Error pages
+        (Locale::En, "error_title") => "Something went wrong",
+        (Locale::Ru, "error_title") => "Что-то пошло не так",
+
+        (Locale::En, "error_description") => {
+            "An unexpected error occurred while loading this page. Try again, or report the problem if it keeps happening."
+        }
+        (Locale::Ru, "error_description") => {
+            "При загрузке страницы произошла непредвиденная ошибка. Попробуйте ещё раз или сообщите о проблеме, если она повторяется."
+        }
+
+        (Locale::En, "error_retry") => "Try Again",
+        (Locale::Ru, "error_retry") => "Попробовать снова",
+
+        (Locale::En, "error_home") => "Back to Home",
+        (Locale::Ru, "error_home") => "На главную",
+
+        (Locale::En, "error_report") => "Report a problem",
+        (Locale::Ru, "error_report") => "Сообщить о проблеме",
+
+        (Locale::En, "error_reference") => "Reference",
+        (Locale::Ru, "error_reference") => "Код запроса",
+
+        (Locale::En, "not_found_title") => "Page not found",
+        (Locale::Ru, "not_found_title") => "Страница не найдена",
+
+        (Locale::En, "not_found_description") => {
+            "The page you're looking for doesn't exist or has been moved."
+        }
+        (Locale::Ru, "not_found_description") => {
+            "Страница, которую вы ищете, не существует или была перемещена."
+        }
+
         // Score levels
+        (Locale::En, "legend_title") => "How to read these scores",
+        (Locale::Ru, "legend_title") => "Как читать эти баллы",
+        (Locale::En, "legend_scale") => {
+            "Each bar runs from the lowest to the highest possible score: 24–120 for a domain, 4–20 for a facet."
+        }
+        (Locale::Ru, "legend_scale") => {
+            "Каждая шкала идёт от минимально до максимально возможного балла: 24–120 для измерения, 4–20 для аспекта."
+        }
+        (Locale::En, "legend_level") => "Level",
+        (Locale::Ru, "legend_level") => "Уровень",
+        (Locale::En, "legend_domains") => "Domains",
+        (Locale::Ru, "legend_domains") => "Измерения",
+        (Locale::En, "legend_facets") => "Facets",
+        (Locale::Ru, "legend_facets") => "Аспекты",
+        (Locale::En, "legend_baseline") => {
+            "Faint bar and tick: average score of people who saved results here; sample size:"
+        }
+        (Locale::Ru, "legend_baseline") => {
+            "Бледная полоса и отметка: средний балл людей, сохранивших здесь результаты; размер выборки:"
+        }
+        (Locale::En, "legend_note") => {
+            "Levels split the possible range into rough thirds; they describe your answers, not how you compare to other people."
+        }
+        (Locale::Ru, "legend_note") => {
+            "Уровни делят возможный диапазон примерно на трети; они описывают ваши ответы, а не сравнение с другими людьми."
+        }
+
         (Locale::En, "level_low") => "Low",
         (Locale::Ru, "level_low") => "Низкий",
 
@@ -396,12 +1342,15 @@ pub fn t(locale: Locale, key: &str) -> &'static str {
         (Locale::En, "facet_cautiousness") => "Cautiousness",
         (Locale::Ru, "facet_cautiousness") => "Осторожность",
 
-        // Fallback - return empty string for unknown keys
-        (_, _key) => {
-            #[cfg(debug_assertions)]
-            leptos::logging::warn!("Missing translation for key: {}", _key);
-            "[?]"
-        }
+        (Locale::En, "facet_explain") => "What does this mean?",
+        (Locale::Ru, "facet_explain") => "Что это значит?",
+        (Locale::En, "facet_explain_loading") => "Loading...",
+        (Locale::Ru, "facet_explain_loading") => "Загрузка...",
+        (Locale::En, "facet_explain_error") => "Couldn't load the explanation",
+        (Locale::Ru, "facet_explain_error") => "Не удалось загрузить пояснение",
+
+        // Fallback for unknown keys
+        (_, _) => MISSING,
     }
 }
 
@@ -0,0 +1,157 @@
+//! Parsing answers from another IPIP-NEO-120 administration.
+//!
+//! Two formats are accepted, told apart by the first character:
+//!
+//! - JSON: a list of `{"question_id": "...", "value": 4}` (or `"item": 1` for the 1-based
+//!   position in the inventory instead of the id), or an object mapping ids to values.
+//! - CSV (comma, semicolon or tab separated) with a header naming a `question_id` or `item`
+//!   column and a `value` column, one answer per row.
+//!
+//! Parsing only checks the shape of the data; [`bigfive::validate_answers`] reports missing,
+//! duplicate and out-of-range answers.
+
+use std::collections::BTreeMap;
+
+use bigfive::{Answer, Inventory};
+use serde::Deserialize;
+
+/// Largest accepted upload; 120 answers take a few kilobytes in either format.
+pub const MAX_IMPORT_BYTES: usize = 64 * 1024;
+
+/// Column names accepted for the question id, the 1-based item number and the value.
+const ID_COLUMNS: &[&str] = &["question_id", "id"];
+const ITEM_COLUMNS: &[&str] = &["item", "position"];
+const VALUE_COLUMNS: &[&str] = &["value", "answer", "score"];
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum JsonAnswers {
+    List(Vec<JsonAnswer>),
+    Map(BTreeMap<String, u8>),
+}
+
+#[derive(Deserialize)]
+struct JsonAnswer {
+    #[serde(alias = "id")]
+    question_id: Option<String>,
+    #[serde(alias = "position")]
+    item: Option<usize>,
+    value: u8,
+}
+
+/// Answers in `data`, with item numbers resolved against `inventory`.
+///
+/// The error describes the first problem found, for showing to the user.
+pub fn parse_answers<I: Inventory + ?Sized>(
+    inventory: &I,
+    data: &str,
+) -> Result<Vec<Answer>, String> {
+    let data = data.trim_start_matches('\u{feff}').trim();
+    if data.is_empty() {
+        return Err("No answers found".to_string());
+    }
+    if data.starts_with(['[', '{']) {
+        parse_json(inventory, data)
+    } else {
+        parse_csv(inventory, data)
+    }
+}
+
+fn parse_json<I: Inventory + ?Sized>(inventory: &I, data: &str) -> Result<Vec<Answer>, String> {
+    let answers: JsonAnswers =
+        serde_json::from_str(data).map_err(|e| format!("Invalid JSON: {e}"))?;
+    match answers {
+        JsonAnswers::Map(map) => Ok(map
+            .into_iter()
+            .map(|(question_id, value)| Answer { question_id, value })
+            .collect()),
+        JsonAnswers::List(list) => list
+            .into_iter()
+            .enumerate()
+            .map(|(index, answer)| {
+                let question_id = match (answer.question_id, answer.item) {
+                    (Some(id), _) => id,
+                    (None, Some(item)) => item_id(inventory, item)?,
+                    (None, None) => {
+                        return Err(format!(
+                            "Answer {} has neither a question_id nor an item",
+                            index + 1
+                        ));
+                    }
+                };
+                Ok(Answer {
+                    question_id,
+                    value: answer.value,
+                })
+            })
+            .collect(),
+    }
+}
+
+fn parse_csv<I: Inventory + ?Sized>(inventory: &I, data: &str) -> Result<Vec<Answer>, String> {
+    let mut lines = data
+        .lines()
+        .enumerate()
+        .filter(|(_, l)| !l.trim().is_empty());
+    let (_, header) = lines.next().ok_or("No answers found")?;
+    let delimiter = [',', ';', '\t']
+        .into_iter()
+        .max_by_key(|d| header.matches(*d).count())
+        .unwrap_or(',');
+    let columns: Vec<String> = header
+        .split(delimiter)
+        .map(|c| c.trim().trim_matches('"').to_ascii_lowercase())
+        .collect();
+    let find = |names: &[&str]| columns.iter().position(|c| names.contains(&c.as_str()));
+
+    let id_column = find(ID_COLUMNS);
+    let item_column = find(ITEM_COLUMNS);
+    let value_column = find(VALUE_COLUMNS)
+        .ok_or("The CSV header needs a value column (value, answer or score)")?;
+    if id_column.is_none() && item_column.is_none() {
+        return Err(
+            "The CSV header needs a question_id column or an item column (1-based)".to_string(),
+        );
+    }
+
+    lines
+        .map(|(index, line)| {
+            let line_number = index + 1;
+            let cells: Vec<&str> = line
+                .split(delimiter)
+                .map(|c| c.trim().trim_matches('"'))
+                .collect();
+            let cell = |column: usize| {
+                cells
+                    .get(column)
+                    .copied()
+                    .filter(|c| !c.is_empty())
+                    .ok_or_else(|| format!("Line {line_number} is missing a column"))
+            };
+            let question_id = match id_column {
+                Some(column) => cell(column)?.to_string(),
+                None => {
+                    let item = cell(item_column.unwrap_or_default())?;
+                    let item = item.parse().map_err(|_| {
+                        format!("Line {line_number}: item {item:?} is not a number")
+                    })?;
+                    item_id(inventory, item)?
+                }
+            };
+            let value = cell(value_column)?;
+            let value = value
+                .parse()
+                .map_err(|_| format!("Line {line_number}: value {value:?} is not a number"))?;
+            Ok(Answer { question_id, value })
+        })
+        .collect()
+}
+
+/// Id of the question at 1-based position `item`.
+fn item_id<I: Inventory + ?Sized>(inventory: &I, item: usize) -> Result<String, String> {
+    let questions = inventory.questions();
+    item.checked_sub(1)
+        .and_then(|index| questions.get(index))
+        .map(|q| q.id.clone())
+        .ok_or_else(|| format!("Item {item} is outside 1-{}", questions.len()))
+}
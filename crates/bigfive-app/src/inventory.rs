@@ -0,0 +1,77 @@
+//! Question inventories with this deployment's text overrides applied.
+//!
+//! Overrides come from the file named by `question_overrides` in the config and are
+//! checked against the bundled inventories when the config loads. The SSR shell embeds
+//! them as JSON, so the test page in the browser shows the same wording as the server.
+
+use std::collections::HashMap;
+
+use bigfive::Ipip120;
+
+use crate::i18n::Locale;
+
+/// Id of the shell `<script>` element holding the overrides JSON.
+pub const OVERRIDES_ELEMENT_ID: &str = "question-overrides";
+
+/// Replacement question texts by language, then question id.
+pub type TextOverrides = HashMap<String, HashMap<String, String>>;
+
+/// Bundled inventory of the default locale, for what every language shares: question ids,
+/// facets and keying.
+pub fn reference() -> Ipip120 {
+    Ipip120::new(Locale::default().code()).expect("every locale of the build has an inventory")
+}
+
+/// Inventory for `lang` with the text overrides applied; `None` for unsupported languages.
+pub fn inventory(lang: &str) -> Option<Ipip120> {
+    let bundled = Ipip120::new(lang).ok()?;
+    Some(match text_overrides().get(lang) {
+        // Checked at config load; a mismatch here means stale page data, so keep the bundled texts
+        Some(texts) => bundled
+            .clone()
+            .with_text_overrides(texts)
+            .unwrap_or(bundled),
+        None => bundled,
+    })
+}
+
+#[cfg(feature = "ssr")]
+fn text_overrides() -> &'static TextOverrides {
+    static EMPTY: std::sync::OnceLock<TextOverrides> = std::sync::OnceLock::new();
+    match crate::config::get_config() {
+        Ok(config) => &config.question_texts,
+        Err(_) => EMPTY.get_or_init(TextOverrides::new),
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+fn text_overrides() -> &'static TextOverrides {
+    static OVERRIDES: std::sync::OnceLock<TextOverrides> = std::sync::OnceLock::new();
+    OVERRIDES.get_or_init(|| {
+        #[cfg(target_arch = "wasm32")]
+        {
+            leptos::prelude::document()
+                .get_element_by_id(OVERRIDES_ELEMENT_ID)
+                .and_then(|e| e.text_content())
+                .and_then(|json| serde_json::from_str(&json).ok())
+                .unwrap_or_default()
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            TextOverrides::new()
+        }
+    })
+}
+
+/// Overrides JSON for the shell, safe to place inside a `<script>` element; `None` when
+/// nothing is overridden.
+#[cfg(feature = "ssr")]
+pub fn overrides_json() -> Option<String> {
+    let overrides = text_overrides();
+    if overrides.is_empty() {
+        return None;
+    }
+    serde_json::to_string(overrides)
+        .ok()
+        .map(|json| json.replace('<', "\\u003c"))
+}
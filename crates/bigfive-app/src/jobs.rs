@@ -1,7 +1,7 @@
 //! Background job management for async AI analysis.
 
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::collections::{HashMap, VecDeque};
 use std::sync::{LazyLock, Mutex};
 use std::time::{Duration, Instant};
 
@@ -14,36 +14,73 @@ pub enum JobStatus {
     /// Job is queued, waiting to start
     Pending,
     /// Job is currently processing
-    Processing,
+    Processing {
+        /// Seconds since processing started
+        elapsed_secs: u64,
+        /// Rough seconds left, from recent runs of the same model (0 once overdue)
+        eta_secs: Option<u64>,
+    },
     /// Job completed successfully with result
-    Complete(String),
+    Complete {
+        analysis: String,
+        /// Prompt template version the analysis was generated with
+        prompt_version: String,
+        /// When the user agreed to send their data to the provider (Unix seconds)
+        consented_at: i64,
+    },
     /// Job failed with error message
     Error(String),
 }
 
+/// Number of recent successful runs per model used for the ETA
+const DURATION_SAMPLES: usize = 10;
+
 /// Internal job data with metadata
 struct JobEntry {
     status: JobStatus,
     created_at: Instant,
+    model_id: String,
+    /// When processing started
+    started_at: Option<Instant>,
 }
 
 /// In-memory job store
 struct JobStore {
     jobs: HashMap<JobId, JobEntry>,
+    /// Durations of recent successful jobs per model, oldest first
+    durations: HashMap<String, VecDeque<Duration>>,
 }
 
 impl JobStore {
     fn new() -> Self {
         Self {
             jobs: HashMap::new(),
+            durations: HashMap::new(),
+        }
+    }
+
+    /// Median duration of recent successful jobs for a model
+    fn expected_duration(&self, model_id: &str) -> Option<Duration> {
+        let mut durations: Vec<Duration> = self.durations.get(model_id)?.iter().copied().collect();
+        durations.sort();
+        durations.get(durations.len() / 2).copied()
+    }
+
+    fn record_duration(&mut self, model_id: &str, duration: Duration) {
+        let durations = self.durations.entry(model_id.to_string()).or_default();
+        if durations.len() == DURATION_SAMPLES {
+            durations.pop_front();
         }
+        durations.push_back(duration);
     }
 
-    /// Clean up old jobs (older than 1 hour)
-    fn cleanup_old_jobs(&mut self) {
+    /// Clean up old jobs (older than 1 hour), returning how many were removed
+    fn cleanup_old_jobs(&mut self) -> usize {
         let max_age = Duration::from_secs(3600); // 1 hour
+        let before = self.jobs.len();
         self.jobs
             .retain(|_, entry| entry.created_at.elapsed() < max_age);
+        before - self.jobs.len()
     }
 }
 
@@ -55,8 +92,8 @@ pub fn generate_job_id() -> JobId {
     uuid::Uuid::new_v4().to_string()
 }
 
-/// Create a new job with Pending status
-pub fn create_job(job_id: &JobId) {
+/// Create a new job with Pending status for the given model
+pub fn create_job(job_id: &JobId, model_id: &str) {
     let mut store = JOB_STORE.lock().unwrap();
     store.cleanup_old_jobs();
     store.jobs.insert(
@@ -64,22 +101,64 @@ pub fn create_job(job_id: &JobId) {
         JobEntry {
             status: JobStatus::Pending,
             created_at: Instant::now(),
+            model_id: model_id.to_string(),
+            started_at: None,
         },
     );
 }
 
-/// Update job status
-pub fn update_job_status(job_id: &JobId, status: JobStatus) {
+/// Mark a job as processing and start its clock
+pub fn start_job(job_id: &JobId) {
     let mut store = JOB_STORE.lock().unwrap();
     if let Some(entry) = store.jobs.get_mut(job_id) {
-        entry.status = status;
+        entry.started_at = Some(Instant::now());
+        entry.status = JobStatus::Processing {
+            elapsed_secs: 0,
+            eta_secs: None,
+        };
+    }
+}
+
+/// Update job status; completing a started job feeds the model's ETA
+pub fn update_job_status(job_id: &JobId, status: JobStatus) {
+    let mut store = JOB_STORE.lock().unwrap();
+    let Some(entry) = store.jobs.get_mut(job_id) else {
+        return;
+    };
+    let finished = match status {
+        JobStatus::Complete { .. } => entry
+            .started_at
+            .map(|started| (entry.model_id.clone(), started.elapsed())),
+        _ => None,
+    };
+    entry.status = status;
+    if let Some((model_id, duration)) = finished {
+        store.record_duration(&model_id, duration);
     }
 }
 
-/// Get job status
+/// Get job status, with progress filled in for processing jobs
 pub fn get_job_status(job_id: &JobId) -> Option<JobStatus> {
     let store = JOB_STORE.lock().unwrap();
-    store.jobs.get(job_id).map(|entry| entry.status.clone())
+    let entry = store.jobs.get(job_id)?;
+    match (&entry.status, entry.started_at) {
+        (JobStatus::Processing { .. }, Some(started)) => {
+            let elapsed = started.elapsed();
+            Some(JobStatus::Processing {
+                elapsed_secs: elapsed.as_secs(),
+                eta_secs: store
+                    .expected_duration(&entry.model_id)
+                    .map(|expected| expected.saturating_sub(elapsed).as_secs()),
+            })
+        }
+        (status, _) => Some(status.clone()),
+    }
+}
+
+/// Remove jobs whose results were never picked up, e.g. because the browser was closed.
+/// Returns the number of removed jobs.
+pub fn purge_stale_jobs() -> usize {
+    JOB_STORE.lock().unwrap().cleanup_old_jobs()
 }
 
 /// Remove a completed job (optional cleanup)
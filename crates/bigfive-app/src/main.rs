@@ -12,16 +12,16 @@ pub const BUILD_TIME: &str = env!("BUILD_TIME");
 async fn main() {
     use axum::Router;
     use axum::extract::Request;
+    use axum::http::HeaderValue;
     use axum::middleware::{self, Next};
     use axum::response::{IntoResponse, Json};
-    use axum::routing::get;
+    use axum::routing::{get, post};
     use axum_governor::GovernorLayer;
     use bigfive_app::app::*;
     use bigfive_app::config::get_config;
-    use lazy_limit::{Duration, RuleConfig, init_rate_limiter};
     use leptos::prelude::*;
     use leptos_axum::{LeptosRoutes, generate_route_list};
-    use real::{RealIp, RealIpLayer};
+    use real::RealIp;
     use serde_json::json;
     use tracing::info;
     use tracing_subscriber::{EnvFilter, layer::SubscriberExt, util::SubscriberInitExt};
@@ -44,9 +44,14 @@ async fn main() {
     bigfive_app::db::init_database(&db_path)
         .await
         .expect("Failed to initialize database");
+    bigfive_app::stats::spawn_daily_stats_job();
+    bigfive_app::purge::spawn_purge_job();
 
     // Load and display AI configuration
     match get_config() {
+        Ok(config) if !config.ai_enabled => {
+            info!("AI features disabled, running as a pure scoring tool");
+        }
         Ok(config) => {
             info!("AI Configuration loaded:");
             info!("  Available models:");
@@ -61,6 +66,9 @@ async fn main() {
                     preset.source_lang
                 );
             }
+            for tenant in &config.tenants {
+                info!("  Tenant {}: {}", tenant.id, tenant.hosts.join(", "));
+            }
             if let Some(ref safeguard) = config.safeguard {
                 if safeguard.enabled {
                     info!("  Safeguard: {} (enabled)", safeguard.model);
@@ -90,47 +98,89 @@ async fn main() {
         }))
     }
 
-    // Rate limiting configuration (per IP):
-    // - Default: 60 requests per 10 seconds (generous for normal browsing)
-    // - AI analysis: 2 requests per minute (protects expensive API calls)
-    init_rate_limiter!(
-        default: RuleConfig::new(Duration::seconds(10), 60),
-        routes: [
-            ("/api/start_analysis", RuleConfig::new(Duration::seconds(60), 2))
-        ]
-    )
-    .await;
-    info!("Rate limiting enabled: 60 req/10s default, 2 req/min for AI analysis");
+    // Rate limiting per IP: a default for normal browsing, tighter rules for endpoints
+    // that are expensive or guessable
+    let ai_enabled = get_config().is_ok_and(|config| config.ai_enabled);
+    let (limits, rules) = bigfive_app::rate_limit::limiter_config(ai_enabled);
+    lazy_limit::initialize_limiter(limits).await;
+    info!(
+        "Rate limiting enabled: {} req/10s default",
+        bigfive_app::rate_limit::DEFAULT_PER_10_SECONDS
+    );
+    for (path, per_minute) in rules {
+        info!("  {path}: {per_minute} req/min");
+    }
+
+    // Request id middleware: tag every request with a fresh id and echo it in the
+    // response, so error reports from users can be matched to log lines
+    async fn assign_request_id(mut req: Request, next: Next) -> impl IntoResponse {
+        let id = HeaderValue::from_str(&uuid::Uuid::new_v4().to_string())
+            .expect("UUID is a valid header value");
+        req.headers_mut().insert(REQUEST_ID_HEADER, id.clone());
+        let mut response = next.run(req).await;
+        response.headers_mut().insert(REQUEST_ID_HEADER, id);
+        response
+    }
 
     // Logging middleware that captures real IP
     async fn log_request(req: Request, next: Next) -> impl IntoResponse {
         let ip = req.extensions().get::<RealIp>().map(|r| r.0);
         let method = req.method().clone();
         let uri = req.uri().clone();
-        tracing::info!(%method, %uri, ?ip, "request");
+        let request_id = req
+            .headers()
+            .get(REQUEST_ID_HEADER)
+            .and_then(|v| v.to_str().ok())
+            .unwrap_or_default()
+            .to_string();
+        tracing::info!(%method, %uri, ?ip, %request_id, "request");
         next.run(req).await
     }
 
     let app = Router::new()
         .route("/api/version", get(version_handler))
+        .route(
+            "/api/v1/inventory/{lang}",
+            get(bigfive_app::api::inventory_handler),
+        )
+        .route(
+            "/api/v1/results/{id}/summary",
+            get(bigfive_app::api::summary_handler)
+                .options(bigfive_app::api::summary_preflight_handler),
+        )
+        .route(
+            "/api/v1/results/{id}/reminder.ics",
+            get(bigfive_app::calendar::reminder_handler),
+        )
+        .route(
+            "/api/v1/results/{id}/export.html",
+            post(bigfive_app::export::export_handler),
+        )
         .leptos_routes(&leptos_options, routes, {
             let leptos_options = leptos_options.clone();
             move || shell(leptos_options.clone())
         })
         .fallback(leptos_axum::file_and_error_handler(shell))
+        .layer(middleware::from_fn(bigfive_app::etag::etag_results))
         .layer(middleware::from_fn(log_request))
+        .layer(middleware::from_fn(assign_request_id))
+        .layer(middleware::from_fn(bigfive_app::tenancy::resolve_tenant))
         .layer(
             tower::ServiceBuilder::new()
-                .layer(RealIpLayer::default())
+                .layer(middleware::from_fn(bigfive_app::proxy::resolve_client_ip))
                 .layer(GovernorLayer::default()),
         )
         .with_state(leptos_options);
 
     let listener = tokio::net::TcpListener::bind(&addr).await.unwrap();
     info!("Server listening on http://{}", &addr);
-    axum::serve(listener, app.into_make_service())
-        .await
-        .unwrap();
+    // Peer addresses are needed to decide whether forwarding headers can be trusted
+    axum::serve(
+        listener,
+        app.into_make_service_with_connect_info::<std::net::SocketAddr>(),
+    )
+    .await
+    .unwrap();
 }
 
 #[cfg(not(feature = "ssr"))]
@@ -0,0 +1,277 @@
+//! Markdown rendering for AI analyses.
+//!
+//! The HTML is injected with `inner_html` on shared pages, so the model's output is treated
+//! as untrusted: raw HTML in the markdown is shown as text, links keep only safe schemes and
+//! images are reduced to their alt text. Everything else is markup pulldown-cmark writes
+//! itself, with text and attributes escaped.
+//!
+//! Headings get ids (plus a `#` anchor) so sections can be linked to, and footnotes and task
+//! lists are supported. Ids carry a caller-chosen prefix to stay clear of the page's own.
+
+use std::collections::HashSet;
+
+use pulldown_cmark::{CowStr, Event, Options, Parser, Tag, TagEnd, html};
+
+/// Render untrusted markdown to HTML that is safe to inject into the page.
+///
+/// Heading and footnote ids start with `id_prefix`, e.g. `analysis-strengths`.
+pub fn to_safe_html(markdown: &str, id_prefix: &str) -> String {
+    let mut options = Options::empty();
+    options.insert(Options::ENABLE_STRIKETHROUGH);
+    options.insert(Options::ENABLE_TABLES);
+    options.insert(Options::ENABLE_FOOTNOTES);
+    options.insert(Options::ENABLE_TASKLISTS);
+
+    let events = Parser::new_ext(markdown, options).filter_map(sanitize());
+    let events = with_ids(events, id_prefix);
+
+    let mut html_output = String::new();
+    html::push_html(&mut html_output, events.into_iter());
+    html_output
+}
+
+/// Event filter enforcing the allowlist described in the module docs.
+fn sanitize<'a>() -> impl FnMut(Event<'a>) -> Option<Event<'a>> {
+    let mut in_unsafe_link = false;
+    move |event| match event {
+        // Raw HTML becomes visible text; an HTML block renders as a paragraph
+        Event::Html(raw) | Event::InlineHtml(raw) => Some(Event::Text(raw)),
+        Event::Start(Tag::HtmlBlock) => Some(Event::Start(Tag::Paragraph)),
+        Event::End(TagEnd::HtmlBlock) => Some(Event::End(TagEnd::Paragraph)),
+
+        // Links can't nest, so one flag tracks whether the current one was dropped
+        Event::Start(Tag::Link { ref dest_url, .. }) if !is_safe_url(dest_url) => {
+            in_unsafe_link = true;
+            None
+        }
+        Event::End(TagEnd::Link) if in_unsafe_link => {
+            in_unsafe_link = false;
+            None
+        }
+
+        // No remote images: they could track readers of shared results
+        Event::Start(Tag::Image { .. }) | Event::End(TagEnd::Image) => None,
+
+        event => Some(event),
+    }
+}
+
+/// Whether a link target is an http(s) or mailto URL, or a path/fragment on this site.
+fn is_safe_url(url: &CowStr) -> bool {
+    // Browsers drop tabs and newlines inside URLs and treat `\` like `/`, so `/\host` and
+    // `/\t/host` are protocol-relative links to another site
+    let url: String = url
+        .trim()
+        .chars()
+        .filter(|c| !matches!(c, '\t' | '\n' | '\r'))
+        .collect::<String>()
+        .to_ascii_lowercase();
+    ["https://", "http://", "mailto:", "/", "#"]
+        .iter()
+        .any(|prefix| url.starts_with(prefix))
+        && !url.starts_with("//")
+        && !url.starts_with("/\\")
+}
+
+/// Give headings unique ids from their text with a `#` anchor, and prefix footnote ids.
+///
+/// Runs after [`sanitize`], so the anchor markup added here is the only raw HTML.
+fn with_ids<'a>(events: impl Iterator<Item = Event<'a>>, prefix: &str) -> Vec<Event<'a>> {
+    let mut output = Vec::new();
+    let mut used_ids = HashSet::new();
+    // Position of the open heading's start event in `output`
+    let mut heading_start = None;
+
+    for event in events {
+        match event {
+            Event::Start(Tag::Heading { .. }) => {
+                heading_start = Some(output.len());
+                output.push(event);
+            }
+            Event::End(TagEnd::Heading(_)) => {
+                if let Some(start) = heading_start.take() {
+                    let text: String = output[start + 1..]
+                        .iter()
+                        .filter_map(|e| match e {
+                            Event::Text(text) | Event::Code(text) => Some(text.as_ref()),
+                            _ => None,
+                        })
+                        .collect();
+                    let id = unique_id(&format!("{prefix}{}", slug(&text)), &mut used_ids);
+                    if let Event::Start(Tag::Heading { id: heading_id, .. }) = &mut output[start] {
+                        *heading_id = Some(CowStr::from(id.clone()));
+                    }
+                    output.push(Event::Html(CowStr::from(format!(
+                        r##" <a class="heading-anchor" href="#{id}" aria-hidden="true">#</a>"##
+                    ))));
+                }
+                output.push(event);
+            }
+            Event::FootnoteReference(name) => {
+                output.push(Event::FootnoteReference(footnote_id(prefix, &name)));
+            }
+            Event::Start(Tag::FootnoteDefinition(name)) => {
+                output.push(Event::Start(Tag::FootnoteDefinition(footnote_id(
+                    prefix, &name,
+                ))));
+            }
+            event => output.push(event),
+        }
+    }
+    output
+}
+
+/// Heading text as an id: lowercase letters and digits joined by `-`.
+fn slug(text: &str) -> String {
+    let slug = text
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect::<Vec<_>>()
+        .join("-");
+    if slug.is_empty() {
+        "section".to_string()
+    } else {
+        slug
+    }
+}
+
+/// `id`, or `id-2`, `id-3`, ... if already taken.
+fn unique_id(id: &str, used: &mut HashSet<String>) -> String {
+    let mut candidate = id.to_string();
+    let mut n = 1;
+    while !used.insert(candidate.clone()) {
+        n += 1;
+        candidate = format!("{id}-{n}");
+    }
+    candidate
+}
+
+fn footnote_id<'a>(prefix: &str, name: &str) -> CowStr<'a> {
+    CowStr::from(format!("{prefix}fn-{name}"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render(markdown: &str) -> String {
+        to_safe_html(markdown, "analysis-")
+    }
+
+    #[test]
+    fn html_blocks_are_shown_as_text() {
+        let html = render("<script>alert(1)</script>\n\nafter");
+        assert!(!html.contains("<script"));
+        assert!(html.contains("&lt;script&gt;alert(1)&lt;/script&gt;"));
+        assert!(html.contains("<p>after</p>"));
+    }
+
+    #[test]
+    fn inline_html_is_shown_as_text() {
+        let html = render("text <img src=x onerror=alert(1)> and <b>bold</b>");
+        assert!(!html.contains("<img"));
+        assert!(!html.contains("<b>"));
+        assert!(html.contains("&lt;img src=x onerror=alert(1)&gt;"));
+    }
+
+    #[test]
+    fn unsafe_link_schemes_are_dropped() {
+        for url in [
+            "javascript:alert(1)",
+            "JavaScript:alert(1)",
+            "JAVASCRIPT:alert(1)",
+            "data:text/html;base64,PHNjcmlwdD4=",
+            "DATA:text/html,x",
+            "vbscript:msgbox(1)",
+            "VBScript:msgbox(1)",
+            "<  javascript:alert(1)>",
+            "<\tjavascript:alert(1)>",
+            "//evil.example",
+            "/\\evil.example",
+            "</\t/evil.example>",
+        ] {
+            let html = render(&format!("[click]({url})"));
+            assert!(!html.contains("<a"), "{url} rendered as {html}");
+            assert!(html.contains("click"), "{url} lost its text");
+        }
+    }
+
+    #[test]
+    fn safe_links_are_kept() {
+        for url in [
+            "https://example.com/a",
+            "http://example.com",
+            "mailto:someone@example.com",
+            "/results/abc",
+            "#analysis-strengths",
+        ] {
+            let html = render(&format!("[link]({url})"));
+            assert!(
+                html.contains(&format!(r#"<a href="{url}">link</a>"#)),
+                "{html}"
+            );
+        }
+    }
+
+    #[test]
+    fn images_are_reduced_to_alt_text() {
+        let html = render("![tracking pixel](https://evil.example/pixel.png)");
+        assert!(!html.contains("<img"));
+        assert!(!html.contains("evil.example"));
+        assert!(html.contains("tracking pixel"));
+    }
+
+    #[test]
+    fn attributes_are_escaped() {
+        let html = render(r#"[x](https://example.com/"onmouseover="alert(1) "a \"title\" <b>")"#);
+        assert!(!html.contains(r#"" onmouseover"#));
+        assert!(!html.contains(r#""onmouseover="#));
+        assert!(html.contains("%22onmouseover=%22alert(1)"));
+        assert!(html.contains(r#"title="a &quot;title&quot; &lt;b&gt;""#));
+    }
+
+    #[test]
+    fn slugs_keep_lowercase_words() {
+        assert_eq!(slug("Strengths & Growth Areas"), "strengths-growth-areas");
+        assert_eq!(slug("  Работа  и  отдых "), "работа-и-отдых");
+        assert_eq!(slug("Top 3 tips!"), "top-3-tips");
+        assert_eq!(slug("!!!"), "section");
+    }
+
+    #[test]
+    fn duplicate_ids_get_numbered_suffixes() {
+        let mut used = HashSet::new();
+        assert_eq!(unique_id("a-notes", &mut used), "a-notes");
+        assert_eq!(unique_id("a-notes", &mut used), "a-notes-2");
+        assert_eq!(unique_id("a-notes", &mut used), "a-notes-3");
+        assert_eq!(unique_id("a-notes-2", &mut used), "a-notes-2-2");
+    }
+
+    #[test]
+    fn headings_get_prefixed_ids_and_anchors() {
+        let html = render("## Strengths\n\n## Strengths\n\n# `Work` style");
+        assert!(html.contains(
+            r##"<h2 id="analysis-strengths">Strengths <a class="heading-anchor" href="#analysis-strengths" aria-hidden="true">#</a></h2>"##
+        ));
+        assert!(html.contains(r#"<h2 id="analysis-strengths-2">"#));
+        assert!(html.contains(r##"href="#analysis-strengths-2""##));
+        assert!(html.contains(r#"<h1 id="analysis-work-style">"#));
+    }
+
+    #[test]
+    fn heading_anchors_stay_the_only_raw_html() {
+        let html = render(r#"## <a href="javascript:alert(1)">x</a> "quoted""#);
+        assert!(html.contains(r#"<h2 id="analysis-a-href-javascript-alert-1-x-a-quoted">"#));
+        assert_eq!(html.matches("<a ").count(), 1);
+        assert!(html.contains(r#"&lt;a href="javascript:alert(1)"&gt;x&lt;/a&gt;"#));
+    }
+
+    #[test]
+    fn footnote_ids_are_prefixed() {
+        assert_eq!(footnote_id("analysis-", "1").as_ref(), "analysis-fn-1");
+        let html = render("Claim[^src].\n\n[^src]: Source.");
+        assert!(html.contains(r##"href="#analysis-fn-src""##));
+        assert!(html.contains(r#"id="analysis-fn-src""#));
+    }
+}
@@ -0,0 +1,159 @@
+//! Offline copies of shared results.
+//!
+//! The results page stores every shared result it loads in IndexedDB (the newest
+//! [`MAX_CACHED_RESULTS`]), and falls back to that copy when the server can't be reached.
+//! The service worker in `public/sw.js` keeps the result pages and the app bundle cached, so
+//! a previously opened link renders its scores and last analysis without a connection.
+
+/// Shared results kept for offline viewing; older ones are dropped.
+#[cfg_attr(not(target_arch = "wasm32"), allow(dead_code))]
+const MAX_CACHED_RESULTS: usize = 20;
+
+/// Register the service worker that caches pages for offline use.
+pub fn register_service_worker() {
+    #[cfg(target_arch = "wasm32")]
+    if let Some(window) = web_sys::window() {
+        // Rejected in browsers or contexts without service workers (e.g. plain http)
+        let _ = window.navigator().service_worker().register("/sw.js");
+    }
+}
+
+/// Store the serialized result `json` for offline viewing.
+#[cfg_attr(not(target_arch = "wasm32"), allow(unused_variables))]
+pub async fn save_result(id: &str, json: &str) {
+    #[cfg(target_arch = "wasm32")]
+    if let Err(e) = idb::save(id, json).await {
+        web_sys::console::warn_2(&"Failed to cache result for offline use:".into(), &e);
+    }
+}
+
+/// The stored copy of a result, if it was viewed before.
+#[cfg_attr(not(target_arch = "wasm32"), allow(unused_variables))]
+pub async fn load_result(id: &str) -> Option<String> {
+    #[cfg(target_arch = "wasm32")]
+    {
+        idb::load(id).await.ok().flatten()
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    {
+        None
+    }
+}
+
+/// Drop the stored copy of a result that no longer exists.
+#[cfg_attr(not(target_arch = "wasm32"), allow(unused_variables))]
+pub async fn remove_result(id: &str) {
+    #[cfg(target_arch = "wasm32")]
+    let _ = idb::remove(id).await;
+}
+
+#[cfg(target_arch = "wasm32")]
+mod idb {
+    use js_sys::{Array, Function, Object, Promise, Reflect};
+    use wasm_bindgen::closure::Closure;
+    use wasm_bindgen::{JsCast, JsValue};
+    use wasm_bindgen_futures::JsFuture;
+    use web_sys::{Event, IdbDatabase, IdbObjectStore, IdbRequest, IdbTransactionMode};
+
+    use super::MAX_CACHED_RESULTS;
+
+    const DB_NAME: &str = crate::storage::storage_key!("offline");
+    const STORE: &str = "results";
+
+    type Handler = Closure<dyn FnMut(Event)>;
+
+    /// Wait for a request to finish and return its result.
+    async fn finished(request: &IdbRequest) -> Result<JsValue, JsValue> {
+        // Both handlers stay alive until the request settles; only one of them ever runs
+        let mut handlers: Option<(Handler, Handler)> = None;
+        let promise = Promise::new(&mut |resolve: Function, reject: Function| {
+            let on_success = Closure::new(move |_: Event| {
+                let _ = resolve.call0(&JsValue::NULL);
+            });
+            let on_error = Closure::new(move |_: Event| {
+                let _ = reject.call0(&JsValue::NULL);
+            });
+            request.set_onsuccess(Some(on_success.as_ref().unchecked_ref()));
+            request.set_onerror(Some(on_error.as_ref().unchecked_ref()));
+            handlers = Some((on_success, on_error));
+        });
+        let outcome = JsFuture::from(promise).await;
+        request.set_onsuccess(None);
+        request.set_onerror(None);
+        drop(handlers);
+        match outcome {
+            Ok(_) => request.result(),
+            Err(_) => Err(request
+                .error()
+                .ok()
+                .flatten()
+                .map_or(JsValue::NULL, JsValue::from)),
+        }
+    }
+
+    async fn open() -> Result<IdbDatabase, JsValue> {
+        let factory = web_sys::window()
+            .ok_or(JsValue::NULL)?
+            .indexed_db()?
+            .ok_or(JsValue::NULL)?;
+        let request = factory.open_with_u32(DB_NAME, 1)?;
+        let on_upgrade: Handler = Closure::new(|event: Event| {
+            let db = event
+                .target()
+                .and_then(|target| target.dyn_into::<IdbRequest>().ok())
+                .and_then(|request| request.result().ok())
+                .and_then(|result| result.dyn_into::<IdbDatabase>().ok());
+            if let Some(db) = db {
+                let _ = db.create_object_store(STORE);
+            }
+        });
+        request.set_onupgradeneeded(Some(on_upgrade.as_ref().unchecked_ref()));
+        let db = finished(&request).await;
+        request.set_onupgradeneeded(None);
+        db?.dyn_into()
+    }
+
+    fn store(db: &IdbDatabase) -> Result<IdbObjectStore, JsValue> {
+        db.transaction_with_str_and_mode(STORE, IdbTransactionMode::Readwrite)?
+            .object_store(STORE)
+    }
+
+    pub(super) async fn save(id: &str, json: &str) -> Result<(), JsValue> {
+        let db = open().await?;
+        let entry = Object::new();
+        Reflect::set(&entry, &"id".into(), &id.into())?;
+        Reflect::set(&entry, &"json".into(), &json.into())?;
+        Reflect::set(&entry, &"saved_at".into(), &js_sys::Date::now().into())?;
+        finished(&store(&db)?.put_with_key(&entry, &id.into())?).await?;
+
+        // Keep only the newest entries
+        let entries: Array = finished(&store(&db)?.get_all()?).await?.dyn_into()?;
+        let mut saved: Vec<(f64, JsValue)> = entries
+            .iter()
+            .filter_map(|entry| {
+                let saved_at = Reflect::get(&entry, &"saved_at".into()).ok()?.as_f64()?;
+                Some((saved_at, Reflect::get(&entry, &"id".into()).ok()?))
+            })
+            .collect();
+        saved.sort_by(|a, b| b.0.total_cmp(&a.0));
+        for (_, old_id) in saved.into_iter().skip(MAX_CACHED_RESULTS) {
+            finished(&store(&db)?.delete(&old_id)?).await?;
+        }
+        Ok(())
+    }
+
+    pub(super) async fn load(id: &str) -> Result<Option<String>, JsValue> {
+        let db = open().await?;
+        let entry = finished(&store(&db)?.get(&id.into())?).await?;
+        if entry.is_undefined() {
+            return Ok(None);
+        }
+        Ok(Reflect::get(&entry, &"json".into())?.as_string())
+    }
+
+    pub(super) async fn remove(id: &str) -> Result<(), JsValue> {
+        let db = open().await?;
+        finished(&store(&db)?.delete(&id.into())?).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,158 @@
+//! Masking of personal data in user-provided context.
+//!
+//! Detection is pattern-based: emails and phone numbers are matched fairly reliably,
+//! full names only as two or three adjacent capitalized words, so some names slip
+//! through and some capitalized phrases get masked.
+
+use std::sync::LazyLock;
+
+use regex::{Captures, Regex};
+
+use crate::config::PiiScrubbingConfig;
+
+const EMAIL_MASK: &str = "[email]";
+const PHONE_MASK: &str = "[phone]";
+const NAME_MASK: &str = "[name]";
+
+static EMAIL: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"[\w.+-]+@[\w-]+(?:\.[\w-]+)+").unwrap());
+
+static PHONE: LazyLock<Regex> = LazyLock::new(|| Regex::new(r"\+?\(?\d[\d\s().-]{5,}\d").unwrap());
+
+/// Two or three capitalized words in a row, Latin or Cyrillic.
+static FULL_NAME: LazyLock<Regex> =
+    LazyLock::new(|| Regex::new(r"\b\p{Lu}\p{Ll}+(?:[ \t]+\p{Lu}\p{Ll}+){1,2}\b").unwrap());
+
+/// Mask the kinds of personal data enabled in `config`. Returns the text unchanged
+/// when scrubbing is disabled.
+pub fn scrub(config: &PiiScrubbingConfig, text: &str) -> String {
+    if !config.enabled {
+        return text.to_string();
+    }
+
+    let mut text = text.to_string();
+    if config.emails {
+        text = EMAIL.replace_all(&text, EMAIL_MASK).into_owned();
+    }
+    if config.phones {
+        text = PHONE
+            .replace_all(&text, |caps: &Captures| {
+                let matched = &caps[0];
+                if is_phone_number(matched) {
+                    PHONE_MASK.to_string()
+                } else {
+                    matched.to_string()
+                }
+            })
+            .into_owned();
+    }
+    if config.names {
+        text = FULL_NAME.replace_all(&text, NAME_MASK).into_owned();
+    }
+    text
+}
+
+/// Whether a digit run looks like a phone number rather than e.g. a year range.
+fn is_phone_number(candidate: &str) -> bool {
+    let digits = candidate.chars().filter(char::is_ascii_digit).count();
+    let international = candidate.starts_with('+');
+    (if international { 7 } else { 10 }..=15).contains(&digits)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn enabled() -> PiiScrubbingConfig {
+        PiiScrubbingConfig {
+            enabled: true,
+            ..PiiScrubbingConfig::default()
+        }
+    }
+
+    #[test]
+    fn test_masks_emails() {
+        assert_eq!(
+            scrub(
+                &enabled(),
+                "write to john.doe+bigfive@mail.example.co.uk, please"
+            ),
+            "write to [email], please"
+        );
+        assert_eq!(
+            scrub(&enabled(), "почта: ivan_petrov@yandex.ru."),
+            "почта: [email]."
+        );
+        assert_eq!(scrub(&enabled(), "rated 5@home"), "rated 5@home");
+    }
+
+    #[test]
+    fn test_masks_phone_numbers() {
+        let config = enabled();
+        // International numbers need 7 digits or more
+        assert_eq!(scrub(&config, "call +7 (912) 345-67-89"), "call [phone]");
+        assert_eq!(scrub(&config, "or +44 20 7946 0958."), "or [phone].");
+        assert_eq!(scrub(&config, "+1 555-0123"), "[phone]");
+        // Local numbers need 10 digits or more
+        assert_eq!(scrub(&config, "home (555) 123-4567"), "home [phone]");
+        assert_eq!(scrub(&config, "8 912 345 67 89"), "[phone]");
+        assert_eq!(scrub(&config, "office 123-45-67"), "office 123-45-67");
+    }
+
+    #[test]
+    fn test_keeps_years_and_other_numbers() {
+        let config = enabled();
+        for text in [
+            "worked there 1990-2020",
+            "in 1990 - 2020 and 2021",
+            "scored 42 out of 120",
+            "on 12.03.2024",
+            "ticket #1234567",
+        ] {
+            assert_eq!(scrub(&config, text), text);
+        }
+    }
+
+    #[test]
+    fn test_masks_full_names() {
+        let config = enabled();
+        assert_eq!(
+            scrub(&config, "I argue with Anna Smith a lot"),
+            "I argue with [name] a lot"
+        );
+        assert_eq!(
+            scrub(&config, "my boss, Anna Maria Smith, said"),
+            "my boss, [name], said"
+        );
+        assert_eq!(
+            scrub(&config, "мой брат Иван Петров работает"),
+            "мой брат [name] работает"
+        );
+        assert_eq!(scrub(&config, "Анна Сергеевна Иванова"), "[name]");
+        // Single capitalized words, e.g. at the start of a sentence, are kept
+        assert_eq!(
+            scrub(&config, "Anna thinks. Work is hard"),
+            "Anna thinks. Work is hard"
+        );
+        // Known false positive: any capitalized pair is taken for a name
+        assert_eq!(scrub(&config, "moved to New York"), "moved to [name]");
+        // Known false negative: lowercase names are kept
+        assert_eq!(scrub(&config, "anna smith"), "anna smith");
+    }
+
+    #[test]
+    fn test_respects_config() {
+        let text = "Anna Smith, anna@example.com, +7 912 345 67 89";
+        assert_eq!(scrub(&PiiScrubbingConfig::default(), text), text);
+        let config = PiiScrubbingConfig {
+            emails: false,
+            names: false,
+            ..enabled()
+        };
+        assert_eq!(
+            scrub(&config, text),
+            "Anna Smith, anna@example.com, [phone]"
+        );
+        assert_eq!(scrub(&enabled(), text), "[name], [email], [phone]");
+    }
+}
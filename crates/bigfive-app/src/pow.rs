@@ -0,0 +1,117 @@
+//! Proof-of-work for saving shared results.
+//!
+//! With `save_pow_difficulty` set in the config, `save_results` only stores a result that
+//! comes with the solution to a fresh challenge: a nonce whose SHA-256 hash together with
+//! the challenge starts with that many zero bits. The browser finds one in a moment; a
+//! script flooding the database has to pay for every row. Challenges live in memory and
+//! can be redeemed once.
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// A challenge issued by the server.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SaveChallenge {
+    pub challenge: String,
+    /// Leading zero bits the hash needs.
+    pub difficulty: u8,
+}
+
+/// A solved challenge, sent along with a save.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct SaveProof {
+    pub challenge: String,
+    pub nonce: u64,
+}
+
+/// Whether `nonce` solves `challenge` at `difficulty`.
+pub fn is_solution(challenge: &str, nonce: u64, difficulty: u8) -> bool {
+    let hash = Sha256::new()
+        .chain_update(challenge.as_bytes())
+        .chain_update(nonce.to_le_bytes())
+        .finalize();
+    leading_zero_bits(&hash) >= u32::from(difficulty)
+}
+
+fn leading_zero_bits(bytes: &[u8]) -> u32 {
+    let mut bits = 0;
+    for &byte in bytes {
+        if byte != 0 {
+            return bits + byte.leading_zeros();
+        }
+        bits += 8;
+    }
+    bits
+}
+
+/// Find a nonce solving `challenge`, yielding to the browser now and then so the page
+/// stays responsive.
+pub async fn solve(challenge: SaveChallenge) -> SaveProof {
+    let mut nonce = 0u64;
+    while !is_solution(&challenge.challenge, nonce, challenge.difficulty) {
+        nonce += 1;
+        #[cfg(target_arch = "wasm32")]
+        if nonce % 4096 == 0 {
+            gloo_timers::future::TimeoutFuture::new(0).await;
+        }
+    }
+    SaveProof {
+        challenge: challenge.challenge,
+        nonce,
+    }
+}
+
+#[cfg(feature = "ssr")]
+pub use issued::{issue, redeem};
+
+#[cfg(feature = "ssr")]
+mod issued {
+    use std::collections::HashMap;
+    use std::sync::{LazyLock, Mutex};
+    use std::time::{Duration, Instant};
+
+    use super::{SaveChallenge, SaveProof, is_solution};
+
+    /// How long a challenge can be redeemed after it was issued.
+    const CHALLENGE_TTL: Duration = Duration::from_secs(10 * 60);
+
+    /// Challenges kept at once; the oldest is dropped to make room.
+    const MAX_OPEN_CHALLENGES: usize = 10_000;
+
+    /// Open challenges and when they were issued.
+    static ISSUED: LazyLock<Mutex<HashMap<String, Instant>>> =
+        LazyLock::new(|| Mutex::new(HashMap::new()));
+
+    /// Issue a new challenge at `difficulty`.
+    pub fn issue(difficulty: u8) -> SaveChallenge {
+        let challenge = uuid::Uuid::new_v4().simple().to_string();
+        let now = Instant::now();
+        let mut issued = ISSUED.lock().unwrap();
+        issued.retain(|_, at| now.duration_since(*at) < CHALLENGE_TTL);
+        if issued.len() >= MAX_OPEN_CHALLENGES
+            && let Some(oldest) = issued
+                .iter()
+                .min_by_key(|(_, at)| **at)
+                .map(|(c, _)| c.clone())
+        {
+            issued.remove(&oldest);
+        }
+        issued.insert(challenge.clone(), now);
+        SaveChallenge {
+            challenge,
+            difficulty,
+        }
+    }
+
+    /// Check a proof and use up its challenge. Unknown, expired or already redeemed
+    /// challenges fail.
+    pub fn redeem(proof: &SaveProof, difficulty: u8) -> bool {
+        if !is_solution(&proof.challenge, proof.nonce, difficulty) {
+            return false;
+        }
+        let mut issued = ISSUED.lock().unwrap();
+        issued
+            .remove(&proof.challenge)
+            .is_some_and(|at| at.elapsed() < CHALLENGE_TTL)
+    }
+}
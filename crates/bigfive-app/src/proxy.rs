@@ -0,0 +1,65 @@
+//! Client IP resolution behind reverse proxies.
+//!
+//! [`resolve_client_ip`] runs before rate limiting and request logging and stores the
+//! client's address as [`RealIp`]. Forwarding headers are only believed when the direct
+//! peer is one of the `[proxy]` `trusted` networks; otherwise the peer address itself is
+//! used, so clients can't pick their own rate limit bucket by sending a header.
+
+use std::net::{IpAddr, SocketAddr};
+
+use axum::extract::{ConnectInfo, Request};
+use axum::http::HeaderMap;
+use axum::middleware::Next;
+use axum::response::Response;
+use real::RealIp;
+
+use crate::config::{ClientIpHeader, ProxyConfig, get_config};
+
+/// Middleware storing the request's client address in its extensions.
+pub async fn resolve_client_ip(mut req: Request, next: Next) -> Response {
+    let peer = req
+        .extensions()
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|info| info.0.ip());
+    if let Some(peer) = peer {
+        let default = ProxyConfig::default();
+        let proxy = get_config().map_or(&default, |config| &config.proxy);
+        let ip = client_ip(proxy, peer, req.headers());
+        req.extensions_mut().insert(RealIp(ip));
+    }
+    next.run(req).await
+}
+
+/// Client address of a request from `peer`.
+fn client_ip(proxy: &ProxyConfig, peer: IpAddr, headers: &HeaderMap) -> IpAddr {
+    if !proxy.trusts(peer) {
+        return peer;
+    }
+    let Some(value) = headers
+        .get(proxy.client_ip_header.name())
+        .and_then(|v| v.to_str().ok())
+    else {
+        return peer;
+    };
+
+    match proxy.client_ip_header {
+        // Each proxy appends the address it got the request from: the client is the
+        // rightmost one not added by a trusted proxy
+        ClientIpHeader::XForwardedFor => {
+            let mut client = peer;
+            for hop in value.rsplit(',') {
+                let Ok(ip) = hop.trim().parse::<IpAddr>() else {
+                    break;
+                };
+                client = ip;
+                if !proxy.trusts(ip) {
+                    break;
+                }
+            }
+            client
+        }
+        ClientIpHeader::CfConnectingIp | ClientIpHeader::XRealIp => {
+            value.trim().parse().unwrap_or(peer)
+        }
+    }
+}
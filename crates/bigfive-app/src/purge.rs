@@ -0,0 +1,127 @@
+//! Background job that removes data nobody can reach anymore.
+//!
+//! Deleting a shared result only marks it as deleted, so its owner can undo the
+//! deletion for `deleted_result_grace_days`. Once an hour this job removes results
+//! whose grace period has passed, along with:
+//! - provider logs of failed analyses older than [`PROVIDER_LOG_RETENTION_DAYS`]
+//! - expired resume sessions
+//! - analysis jobs whose results were never picked up
+//! - analysis feedback tied to results that no longer exist
+//! - audit log entries older than `audit_log.retention_days`
+//! - analysis quota counters of past days
+//!
+//! Counts of what was removed since startup are kept for the admin page.
+
+use std::sync::{LazyLock, Mutex};
+use std::time::Duration;
+
+use tracing::{info, warn};
+
+use crate::config::get_config;
+use crate::db;
+use crate::jobs;
+
+/// How often to look for data to purge.
+const CHECK_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// Days the redacted provider calls of a failed analysis are kept.
+pub const PROVIDER_LOG_RETENTION_DAYS: i64 = 14;
+
+/// What the cleanup job removed since the server started.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CleanupTotals {
+    /// Number of finished cleanup runs.
+    pub runs: u64,
+    /// When the last run finished (Unix seconds).
+    pub last_run_at: Option<i64>,
+    pub deleted_results: u64,
+    pub provider_logs: u64,
+    pub sessions: u64,
+    pub jobs: u64,
+    pub feedback: u64,
+    pub audit_entries: u64,
+    pub quota_counters: u64,
+}
+
+static TOTALS: LazyLock<Mutex<CleanupTotals>> =
+    LazyLock::new(|| Mutex::new(CleanupTotals::default()));
+
+/// Counts of removed data since startup.
+pub fn cleanup_totals() -> CleanupTotals {
+    *TOTALS.lock().unwrap()
+}
+
+/// Spawn the purge job on the current Tokio runtime.
+pub fn spawn_purge_job() {
+    tokio::spawn(async {
+        let mut interval = tokio::time::interval(CHECK_INTERVAL);
+        loop {
+            interval.tick().await;
+            if let Err(e) = run_cleanup().await {
+                warn!(error = %e, "Cleanup failed");
+            }
+        }
+    });
+}
+
+/// Run every cleanup step once; a failing step doesn't stop the others.
+async fn run_cleanup() -> anyhow::Result<()> {
+    let now = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)?
+        .as_secs() as i64;
+    let config = get_config()?;
+    let grace_days = config.deleted_result_grace_days;
+
+    let step = |name: &'static str, result: anyhow::Result<u64>| match result {
+        Ok(count) => count,
+        Err(e) => {
+            warn!(error = %e, step = name, "Cleanup step failed");
+            0
+        }
+    };
+    let deleted_results = step(
+        "deleted_results",
+        db::purge_deleted_results(now - i64::from(grace_days) * 24 * 60 * 60).await,
+    );
+    let provider_logs = step(
+        "provider_logs",
+        db::purge_provider_logs(now - PROVIDER_LOG_RETENTION_DAYS * 24 * 60 * 60).await,
+    );
+    let sessions = step("sessions", db::purge_expired_sessions(now).await);
+    let jobs = jobs::purge_stale_jobs() as u64;
+    // After results, so feedback of results purged in this run goes too
+    let feedback = step("feedback", db::purge_orphaned_feedback().await);
+    // Entries are dropped even while recording is off, so disabling it doesn't keep them forever
+    let audit_entries = step(
+        "audit_entries",
+        db::purge_audit_log(now - i64::from(config.audit_log.retention_days) * 24 * 60 * 60).await,
+    );
+    let quota_counters = step("quota_counters", crate::quota::purge(now).await);
+
+    if deleted_results + provider_logs + sessions + jobs + feedback + audit_entries + quota_counters
+        > 0
+    {
+        info!(
+            deleted_results,
+            provider_logs,
+            sessions,
+            jobs,
+            feedback,
+            audit_entries,
+            quota_counters,
+            "Cleaned up stale data"
+        );
+    }
+
+    let mut totals = TOTALS.lock().unwrap();
+    totals.runs += 1;
+    totals.last_run_at = Some(now);
+    totals.deleted_results += deleted_results;
+    totals.provider_logs += provider_logs;
+    totals.sessions += sessions;
+    totals.jobs += jobs;
+    totals.feedback += feedback;
+    totals.audit_entries += audit_entries;
+    totals.quota_counters += quota_counters;
+    Ok(())
+}
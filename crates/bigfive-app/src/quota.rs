@@ -0,0 +1,123 @@
+//! Per-client daily quota of AI analyses.
+//!
+//! With `[analysis_quota]` enabled each client address gets `per_day` analyses per UTC
+//! day. Clients are told apart by the address [`crate::proxy`] resolves, so they can't
+//! start a fresh quota by picking a new identity; IPv6 clients are counted per /64, the
+//! block a single subscriber usually gets. Only a hash of the address is stored.
+
+use std::net::IpAddr;
+
+use leptos::prelude::use_context;
+use real::RealIp;
+use sha2::{Digest, Sha256};
+use tracing::warn;
+
+use crate::config::get_config;
+use crate::db;
+
+/// Seconds in a quota day.
+const DAY_SECS: i64 = 24 * 60 * 60;
+
+/// Days of counters kept, so a day's count survives until well after it ended everywhere.
+const KEPT_DAYS: i64 = 2;
+
+/// A client's allowance for today.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Allowance {
+    pub limit: u32,
+    pub used: u32,
+    /// When the next day's allowance starts (Unix seconds).
+    pub resets_at: i64,
+}
+
+/// An analysis counted against a client's quota.
+#[derive(Debug)]
+pub struct Reservation {
+    client_hash: String,
+    day: i64,
+}
+
+impl Reservation {
+    /// Give the analysis back, e.g. because it failed. Failures are only logged.
+    pub async fn release(self) {
+        if let Err(e) = db::release_analysis_quota(&self.client_hash, self.day).await {
+            warn!(error = %e, "Failed to release analysis quota");
+        }
+    }
+}
+
+/// Daily limit, or `None` when quotas are off.
+pub fn daily_limit() -> Option<u32> {
+    get_config()
+        .ok()
+        .filter(|config| config.analysis_quota.enabled)
+        .map(|config| config.analysis_quota.per_day)
+}
+
+/// Address of the client making the current server function request.
+pub fn request_ip() -> anyhow::Result<IpAddr> {
+    use_context::<axum::http::request::Parts>()
+        .and_then(|parts| parts.extensions.get::<RealIp>().map(|ip| ip.0))
+        .ok_or_else(|| anyhow::anyhow!("Client address unknown"))
+}
+
+/// Today's allowance of a client, or `None` when quotas are off.
+pub async fn allowance(client: IpAddr, now: i64) -> anyhow::Result<Option<Allowance>> {
+    let Some(limit) = daily_limit() else {
+        return Ok(None);
+    };
+    let day = now.div_euclid(DAY_SECS);
+    let used = db::analysis_quota_used(&client_hash(client), day).await?;
+    Ok(Some(Allowance {
+        limit,
+        used: used.min(limit),
+        resets_at: (day + 1) * DAY_SECS,
+    }))
+}
+
+/// Count an analysis against the client's quota for today.
+///
+/// Returns `None` when today's analyses are used up, and a reservation to release if the
+/// analysis fails. Callers check [`daily_limit`] first.
+pub async fn reserve(client: IpAddr, limit: u32, now: i64) -> anyhow::Result<Option<Reservation>> {
+    let client_hash = client_hash(client);
+    let day = now.div_euclid(DAY_SECS);
+    Ok(db::use_analysis_quota(&client_hash, day, limit)
+        .await?
+        .then_some(Reservation { client_hash, day }))
+}
+
+/// Remove counters of days that have ended everywhere.
+pub async fn purge(now: i64) -> anyhow::Result<u64> {
+    db::purge_analysis_quota(now.div_euclid(DAY_SECS) - KEPT_DAYS + 1).await
+}
+
+/// Stored form of a client address: IPv4 addresses whole, IPv6 ones by their /64.
+fn client_hash(client: IpAddr) -> String {
+    let key = match client {
+        IpAddr::V4(ip) => ip.to_string(),
+        IpAddr::V6(ip) => match ip.to_ipv4_mapped() {
+            Some(ip) => ip.to_string(),
+            None => format!("{:x}/64", u128::from(ip) >> 64),
+        },
+    };
+    let hash = Sha256::digest(key.as_bytes());
+    hash.iter().map(|b| format!("{b:02x}")).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hash(ip: &str) -> String {
+        client_hash(ip.parse().unwrap())
+    }
+
+    #[test]
+    fn ipv6_clients_are_counted_per_64_block() {
+        assert_eq!(hash("2001:db8:1:2::1"), hash("2001:db8:1:2:ffff::9"));
+        assert_ne!(hash("2001:db8:1:2::1"), hash("2001:db8:1:3::1"));
+        assert_eq!(hash("::ffff:192.0.2.1"), hash("192.0.2.1"));
+        assert_ne!(hash("192.0.2.1"), hash("192.0.2.2"));
+    }
+}
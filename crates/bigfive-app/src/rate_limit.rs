@@ -0,0 +1,93 @@
+//! Per-IP rate limits of the server functions.
+//!
+//! Server function URLs end in a hash of their module (`/api/save_results1234...`), so
+//! rules are keyed on each function's [`ServerFn::PATH`] rather than a handwritten path.
+
+use lazy_limit::{Duration, LimiterConfig, RuleConfig};
+use leptos::server_fn::ServerFn;
+
+use crate::components::{
+    AdminAuditLog, AdminCleanupStats, AdminExportResearch, AdminFindProviderLog, AdminPurgeResult,
+    AdminQuestionTimings, AdminRecentAnalyses, AdminRecentProviderLogs, AdminSearchResults,
+    AdminSetUnpublished, ExplainFacet, GetNorms, SaveProgress, SaveResults, ScoreImportedAnswers,
+    StartAnalysis,
+};
+
+/// Requests per 10 seconds to any other route, generous for normal browsing.
+pub const DEFAULT_PER_10_SECONDS: u32 = 60;
+
+/// Requests per minute to each admin server function. The admin page loads a few at once,
+/// but nobody needs more to moderate, while guessing the token needs many.
+const ADMIN_PER_MINUTE: u32 = 20;
+
+/// Server function paths of the admin page.
+const ADMIN_PATHS: [&str; 10] = [
+    AdminSearchResults::PATH,
+    AdminSetUnpublished::PATH,
+    AdminPurgeResult::PATH,
+    AdminRecentAnalyses::PATH,
+    AdminRecentProviderLogs::PATH,
+    AdminFindProviderLog::PATH,
+    AdminCleanupStats::PATH,
+    AdminAuditLog::PATH,
+    AdminQuestionTimings::PATH,
+    AdminExportResearch::PATH,
+];
+
+/// Rate limit rules, with `(path, requests per minute)` for logging.
+///
+/// AI rules are left out when AI is disabled, as those endpoints only return errors then.
+pub fn limiter_config(ai_enabled: bool) -> (LimiterConfig, Vec<(&'static str, u32)>) {
+    let mut per_minute = vec![
+        // Saved results are public pages; each also costs a proof of work
+        (SaveResults::PATH, 10),
+        (SaveProgress::PATH, 10),
+        // Each new norm group scans the saved profiles
+        (GetNorms::PATH, 20),
+        // Scoring an upload parses up to the import size limit
+        (ScoreImportedAnswers::PATH, 10),
+    ];
+    if ai_enabled {
+        // Expensive provider calls
+        per_minute.push((StartAnalysis::PATH, 2));
+        per_minute.push((ExplainFacet::PATH, 20));
+    }
+    per_minute.extend(ADMIN_PATHS.map(|path| (path, ADMIN_PER_MINUTE)));
+
+    let config = per_minute.iter().fold(
+        LimiterConfig::new(RuleConfig::new(
+            Duration::seconds(10),
+            DEFAULT_PER_10_SECONDS,
+        )),
+        |config, (path, limit)| {
+            config.add_route_rule(path, RuleConfig::new(Duration::seconds(60), *limit))
+        },
+    );
+    (config, per_minute)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+
+    #[test]
+    fn rules_cover_each_server_function_once() {
+        let (_, rules) = limiter_config(true);
+        let paths: HashSet<&str> = rules.iter().map(|(path, _)| *path).collect();
+        assert_eq!(paths.len(), rules.len());
+        assert!(paths.iter().all(|path| path.starts_with("/api/")));
+        assert!(paths.contains(SaveResults::PATH));
+        assert!(paths.contains(GetNorms::PATH));
+        assert!(paths.contains(AdminPurgeResult::PATH));
+    }
+
+    #[test]
+    fn ai_rules_only_with_ai_enabled() {
+        let (_, rules) = limiter_config(false);
+        assert!(!rules.iter().any(|(path, _)| *path == StartAnalysis::PATH));
+        let (_, rules) = limiter_config(true);
+        assert!(rules.contains(&(StartAnalysis::PATH, 2)));
+    }
+}
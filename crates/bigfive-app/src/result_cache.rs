@@ -0,0 +1,95 @@
+//! In-memory cache in front of [`db::get_result`](crate::db::get_result).
+//!
+//! A shared link that goes viral is opened by many people within minutes, each page view
+//! loading the same row. Lookups are kept here, misses included, for [`RESULT_CACHE_TTL`],
+//! and the least recently used ones make room once [`RESULT_CACHE_CAPACITY`] is reached.
+//! Changes going through [`crate::db`] drop the entry right away; the TTL bounds how long
+//! other server instances sharing the database may show an outdated result.
+
+use std::collections::HashMap;
+use std::sync::{LazyLock, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::db::SavedResult;
+
+/// How long a lookup is reused.
+const RESULT_CACHE_TTL: Duration = Duration::from_secs(60);
+
+/// Lookups kept at once.
+const RESULT_CACHE_CAPACITY: usize = 1024;
+
+struct Entry {
+    result: Option<SavedResult>,
+    cached_at: Instant,
+    /// Value of [`Cache::clock`] when last read, for finding the least recently used entry.
+    last_used: u64,
+}
+
+#[derive(Default)]
+struct Cache {
+    /// Keyed by tenant namespace and result id.
+    entries: HashMap<(String, String), Entry>,
+    clock: u64,
+}
+
+static CACHE: LazyLock<Mutex<Cache>> = LazyLock::new(|| Mutex::new(Cache::default()));
+
+/// The cached lookup of result `id` for `tenant`: `Some(None)` for a cached miss, `None`
+/// when it has to be loaded.
+pub fn get(tenant: &str, id: &str, now: i64) -> Option<Option<SavedResult>> {
+    let mut cache = CACHE.lock().unwrap();
+    cache.clock += 1;
+    let clock = cache.clock;
+    let key = (tenant.to_string(), id.to_string());
+    let entry = cache.entries.get_mut(&key)?;
+    if entry.cached_at.elapsed() >= RESULT_CACHE_TTL {
+        cache.entries.remove(&key);
+        return None;
+    }
+    entry.last_used = clock;
+    // A cached result can run out while in the cache
+    Some(
+        entry
+            .result
+            .clone()
+            .filter(|r| r.expires_at.is_none_or(|expires_at| expires_at > now)),
+    )
+}
+
+/// Remember the lookup of result `id` for `tenant`.
+pub fn insert(tenant: &str, id: &str, result: Option<SavedResult>) {
+    let mut cache = CACHE.lock().unwrap();
+    cache.clock += 1;
+    let clock = cache.clock;
+    if cache.entries.len() >= RESULT_CACHE_CAPACITY {
+        cache
+            .entries
+            .retain(|_, entry| entry.cached_at.elapsed() < RESULT_CACHE_TTL);
+    }
+    if cache.entries.len() >= RESULT_CACHE_CAPACITY
+        && let Some(oldest) = cache
+            .entries
+            .iter()
+            .min_by_key(|(_, entry)| entry.last_used)
+            .map(|(key, _)| key.clone())
+    {
+        cache.entries.remove(&oldest);
+    }
+    cache.entries.insert(
+        (tenant.to_string(), id.to_string()),
+        Entry {
+            result,
+            cached_at: Instant::now(),
+            last_used: clock,
+        },
+    );
+}
+
+/// Forget result `id` after it changed, for every tenant.
+pub fn invalidate(id: &str) {
+    CACHE
+        .lock()
+        .unwrap()
+        .entries
+        .retain(|(_, cached_id), _| cached_id != id);
+}
@@ -0,0 +1,47 @@
+//! Background job that aggregates daily statistics.
+//!
+//! Once an hour it fills in [`db::compute_daily_stats`] for recently finished days
+//! that don't have statistics yet, so dashboards can read a small `daily_stats`
+//! table instead of scanning all results.
+
+use std::time::Duration;
+
+use tracing::{info, warn};
+
+use crate::db;
+
+/// How often to check for days missing statistics.
+const CHECK_INTERVAL: Duration = Duration::from_secs(60 * 60);
+
+/// How many finished days to backfill, e.g. after downtime.
+const BACKFILL_DAYS: i64 = 7;
+
+/// Spawn the statistics job on the current Tokio runtime.
+pub fn spawn_daily_stats_job() {
+    tokio::spawn(async {
+        let mut interval = tokio::time::interval(CHECK_INTERVAL);
+        loop {
+            interval.tick().await;
+            if let Err(e) = fill_missing_days().await {
+                warn!(error = %e, "Failed to compute daily statistics");
+            }
+        }
+    });
+}
+
+/// Compute statistics for finished days within the backfill window that lack them.
+async fn fill_missing_days() -> anyhow::Result<()> {
+    let today = std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)?
+        .as_secs() as i64
+        / (24 * 60 * 60);
+
+    for day in (today - BACKFILL_DAYS)..today {
+        if db::has_daily_stats(day).await? {
+            continue;
+        }
+        let metrics = db::compute_daily_stats(day).await?;
+        info!(day, metrics, "Computed daily statistics");
+    }
+    Ok(())
+}
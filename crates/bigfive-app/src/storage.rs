@@ -0,0 +1,109 @@
+//! Versioned browser localStorage schema.
+//!
+//! Test progress, the computed profile and the user context are persisted in localStorage
+//! across app releases. The schema version is stored next to them, and [`migrate`] runs once
+//! on startup (before hydration) to bring older data up to date, so a change to
+//! `PersonalityProfile` or the answers map doesn't silently break in-progress tests.
+//!
+//! To change a stored format: bump [`SCHEMA_VERSION`] and append a step to `MIGRATIONS`.
+//!
+//! Keys are built with [`storage_key!`] and start with the `STORAGE_NAMESPACE` set at build
+//! time (default `bigfive`), so e.g. staging and production served from one domain keep
+//! separate data, each with its own schema version. The theme preference is read by an inline
+//! script before hydration and stays under the shared `theme` key.
+
+/// Namespaced storage key for `name`, e.g. `storage_key!("answers")` is `"bigfive_answers"`.
+#[cfg_attr(not(target_arch = "wasm32"), allow(unused_macros))]
+macro_rules! storage_key {
+    ($name:literal) => {
+        concat!(env!("STORAGE_NAMESPACE"), "_", $name)
+    };
+}
+#[cfg_attr(not(target_arch = "wasm32"), allow(unused_imports))]
+pub(crate) use storage_key;
+
+/// Current version of the localStorage schema.
+pub const SCHEMA_VERSION: u32 = 1;
+
+#[cfg(target_arch = "wasm32")]
+const STORAGE_KEY_SCHEMA_VERSION: &str = storage_key!("schema_version");
+#[cfg(target_arch = "wasm32")]
+const STORAGE_KEY_ANSWERS: &str = storage_key!("answers");
+#[cfg(target_arch = "wasm32")]
+const STORAGE_KEY_INDEX: &str = storage_key!("current_index");
+#[cfg(target_arch = "wasm32")]
+const STORAGE_KEY_PROFILE: &str = storage_key!("profile");
+
+/// Migration steps; `MIGRATIONS[n]` upgrades data from version `n` to `n + 1`.
+#[cfg(target_arch = "wasm32")]
+const MIGRATIONS: &[fn(&web_sys::Storage)] = &[migrate_v0_to_v1];
+
+/// Bring stored data up to [`SCHEMA_VERSION`].
+///
+/// Data written by a newer release (e.g. after a rollback) is left untouched.
+pub fn migrate() {
+    #[cfg(target_arch = "wasm32")]
+    {
+        let Some(storage) = web_sys::window().and_then(|w| w.local_storage().ok().flatten()) else {
+            return;
+        };
+
+        // Unversioned data predates the schema version and counts as version 0
+        let version = storage
+            .get_item(STORAGE_KEY_SCHEMA_VERSION)
+            .ok()
+            .flatten()
+            .and_then(|v| v.parse::<u32>().ok())
+            .unwrap_or(0);
+        if version >= SCHEMA_VERSION {
+            return;
+        }
+
+        for step in &MIGRATIONS[version as usize..] {
+            step(&storage);
+        }
+        let _ = storage.set_item(STORAGE_KEY_SCHEMA_VERSION, &SCHEMA_VERSION.to_string());
+    }
+}
+
+/// v0 → v1: keep the existing formats but drop anything that no longer parses.
+///
+/// Answers outside 1-5 are removed (the question is simply asked again), an out-of-range
+/// question index is reset, and an unreadable profile is discarded so the results page
+/// sends the user back to the test instead of failing.
+#[cfg(target_arch = "wasm32")]
+fn migrate_v0_to_v1(storage: &web_sys::Storage) {
+    use std::collections::HashMap;
+
+    if let Ok(Some(json)) = storage.get_item(STORAGE_KEY_ANSWERS) {
+        match serde_json::from_str::<HashMap<String, serde_json::Value>>(&json) {
+            Ok(raw) => {
+                let answers: HashMap<String, u8> = raw
+                    .into_iter()
+                    .filter_map(|(id, value)| {
+                        let value = u8::try_from(value.as_u64()?).ok()?;
+                        (1..=5).contains(&value).then_some((id, value))
+                    })
+                    .collect();
+                if let Ok(json) = serde_json::to_string(&answers) {
+                    let _ = storage.set_item(STORAGE_KEY_ANSWERS, &json);
+                }
+            }
+            Err(_) => {
+                let _ = storage.remove_item(STORAGE_KEY_ANSWERS);
+            }
+        }
+    }
+
+    if let Ok(Some(index)) = storage.get_item(STORAGE_KEY_INDEX)
+        && !index.parse::<usize>().is_ok_and(|i| i < 120)
+    {
+        let _ = storage.remove_item(STORAGE_KEY_INDEX);
+    }
+
+    if let Ok(Some(json)) = storage.get_item(STORAGE_KEY_PROFILE)
+        && serde_json::from_str::<bigfive::PersonalityProfile>(&json).is_err()
+    {
+        let _ = storage.remove_item(STORAGE_KEY_PROFILE);
+    }
+}
@@ -0,0 +1,692 @@
+//! Storage backends for saved results.
+//!
+//! [`crate::db`] keeps the result functions the rest of the app calls and forwards them
+//! to the [`ResultStore`] chosen at startup: [`TursoResultStore`] for the database file,
+//! or [`MemoryResultStore`] when `DATABASE_PATH` is `:memory:`, for demo deployments that
+//! shouldn't write anything to disk. The in-memory store loses everything on restart.
+//!
+//! Only the `results` table is behind the trait. Feedback, sessions, audit entries,
+//! quotas and the other tables are queried in [`crate::db`] directly; with `:memory:`
+//! they live in an in-memory Turso database instead of a file.
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use anyhow::{Context, Result, bail};
+use async_trait::async_trait;
+use bigfive::{Demographics, PersonalityProfile};
+
+use crate::db::{NewResult, ResultFilter, ResultHandle, SavedResult, get_connection};
+
+/// Which stored profiles [`ResultStore::profiles`] returns; unset fields match everything.
+#[derive(Debug, Clone, Default)]
+pub struct ProfileFilter<'a> {
+    /// Namespace of the tenant whose results to read; empty for the default site.
+    pub tenant: Option<&'a str>,
+    pub lang: Option<&'a str>,
+    /// Earliest `created_at` (Unix seconds, inclusive).
+    pub created_from: Option<i64>,
+    /// Latest `created_at` (Unix seconds, exclusive).
+    pub created_to: Option<i64>,
+    /// Also return results deleted by their owner but not purged yet.
+    pub include_deleted: bool,
+}
+
+/// Profile of a stored result, as read by the aggregates.
+#[derive(Debug, Clone)]
+pub struct StoredProfile {
+    /// `None` when the stored profile is from an older format that can't be read.
+    pub profile: Option<PersonalityProfile>,
+    pub demographics: Option<Demographics>,
+}
+
+/// Where saved results live.
+///
+/// Implementations only store and filter; caching and the side effects of changes stay in
+/// [`crate::db`].
+#[async_trait]
+pub trait ResultStore: Send + Sync {
+    /// Insert a new result created at `created_at`. Fails if the id is taken.
+    async fn save(&self, result: &NewResult<'_>, created_at: i64) -> Result<()>;
+
+    /// The live result saved with idempotency `key` in `tenant`.
+    async fn find_by_idempotency_key(
+        &self,
+        key: &str,
+        tenant: &str,
+    ) -> Result<Option<ResultHandle>>;
+
+    /// Result `id` of `tenant`, unless expired at `now`, unpublished or deleted.
+    async fn get(&self, id: &str, tenant: &str, now: i64) -> Result<Option<SavedResult>>;
+
+    /// Results matching `filter`, newest first, including hidden ones.
+    async fn search(&self, filter: &ResultFilter<'_>, limit: u32) -> Result<Vec<SavedResult>>;
+
+    /// Whether a result with this id is stored, whatever its state.
+    async fn exists(&self, id: &str) -> Result<bool>;
+
+    /// Returns `false` if no such result exists.
+    async fn set_unpublished(&self, id: &str, unpublished: bool) -> Result<bool>;
+
+    /// Remove a result for good. Returns `false` if it didn't exist.
+    async fn purge(&self, id: &str) -> Result<bool>;
+
+    /// Mark a live result with a matching owner token as deleted at `now`.
+    async fn delete(&self, id: &str, owner_token: &str, now: i64) -> Result<bool>;
+
+    /// Undo a deletion made at or after `deleted_since` if the owner token matches.
+    async fn restore(&self, id: &str, owner_token: &str, deleted_since: i64) -> Result<bool>;
+
+    /// Remove results deleted before `deleted_before`, returning how many.
+    async fn purge_deleted(&self, deleted_before: i64) -> Result<u64>;
+
+    /// Profiles of the results matching `filter`.
+    async fn profiles(&self, filter: &ProfileFilter<'_>) -> Result<Vec<StoredProfile>>;
+}
+
+/// Results in the `results` table of the Turso database.
+#[derive(Debug, Default)]
+pub struct TursoResultStore;
+
+/// Columns read into a [`SavedResult`], in the order [`result_from_row`] expects.
+const RESULT_COLUMNS: &str = "id, profile_json, user_context, ai_analysis, lang, created_at, nickname, expires_at, access_token, owner_token, prompt_version, unpublished, deleted_at, demographics_json";
+
+fn result_from_row(row: &turso::Row) -> Result<SavedResult> {
+    let non_empty = |idx| row.get::<String>(idx).ok().filter(|s| !s.is_empty());
+
+    let profile_json: String = row.get(1)?;
+    let profile: PersonalityProfile =
+        serde_json::from_str(&profile_json).context("Failed to deserialize profile")?;
+
+    Ok(SavedResult {
+        id: row.get(0)?,
+        profile,
+        user_context: non_empty(2),
+        ai_analysis: non_empty(3),
+        prompt_version: non_empty(10),
+        lang: row.get(4)?,
+        created_at: row.get(5)?,
+        nickname: non_empty(6),
+        expires_at: row.get::<i64>(7).ok(),
+        access_token: non_empty(8),
+        owner_token: non_empty(9),
+        unpublished: row.get::<i64>(11).is_ok_and(|v| v != 0),
+        deleted_at: row.get::<i64>(12).ok(),
+        // Unreadable demographics only lose the norm group, not the result
+        demographics: non_empty(13).and_then(|json| serde_json::from_str(&json).ok()),
+    })
+}
+
+#[async_trait]
+impl ResultStore for TursoResultStore {
+    async fn save(&self, result: &NewResult<'_>, created_at: i64) -> Result<()> {
+        let conn = get_connection()?;
+        let profile_json =
+            serde_json::to_string(result.profile).context("Failed to serialize profile")?;
+        let demographics_json = result
+            .demographics
+            .map(serde_json::to_string)
+            .transpose()
+            .context("Failed to serialize demographics")?;
+
+        conn.execute(
+            "INSERT INTO results (id, profile_json, user_context, ai_analysis, lang, created_at, owner_token, nickname, expires_at, access_token, prompt_version, demographics_json, tenant, analysis_consented_at, idempotency_key) VALUES (?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?, ?)",
+            (
+                result.id,
+                profile_json.as_str(),
+                result.user_context.unwrap_or(""),
+                result.ai_analysis.unwrap_or(""),
+                result.lang,
+                created_at,
+                result.owner_token,
+                result.nickname.unwrap_or(""),
+                result.expires_at,
+                result.access_token,
+                result.prompt_version,
+                demographics_json.as_deref(),
+                result.tenant,
+                result.analysis_consented_at,
+                result.idempotency_key,
+            ),
+        )
+        .await
+        .context("Failed to insert result")?;
+        Ok(())
+    }
+
+    async fn find_by_idempotency_key(
+        &self,
+        key: &str,
+        tenant: &str,
+    ) -> Result<Option<ResultHandle>> {
+        let conn = get_connection()?;
+        let mut rows = conn
+            .query(
+                "SELECT id, owner_token, access_token FROM results WHERE idempotency_key = ? AND COALESCE(tenant, '') = ? AND deleted_at IS NULL",
+                (key, tenant),
+            )
+            .await
+            .context("Failed to query result by idempotency key")?;
+
+        match rows.next().await? {
+            Some(row) => Ok(Some(ResultHandle {
+                id: row.get(0)?,
+                owner_token: row.get(1)?,
+                access_token: row.get::<String>(2).ok(),
+            })),
+            None => Ok(None),
+        }
+    }
+
+    async fn get(&self, id: &str, tenant: &str, now: i64) -> Result<Option<SavedResult>> {
+        let conn = get_connection()?;
+        let mut rows = conn
+            .query(
+                format!("SELECT {RESULT_COLUMNS} FROM results WHERE id = ? AND (expires_at IS NULL OR expires_at > ?) AND COALESCE(unpublished, 0) = 0 AND deleted_at IS NULL AND COALESCE(tenant, '') = ?"),
+                (id, now, tenant),
+            )
+            .await
+            .context("Failed to query result")?;
+
+        match rows.next().await? {
+            Some(row) => Ok(Some(result_from_row(&row)?)),
+            None => Ok(None),
+        }
+    }
+
+    async fn search(&self, filter: &ResultFilter<'_>, limit: u32) -> Result<Vec<SavedResult>> {
+        let conn = get_connection()?;
+        let mut rows = conn
+            .query(
+                format!(
+                    "SELECT {RESULT_COLUMNS} FROM results WHERE (?1 IS NULL OR id LIKE ?1 || '%') AND (?2 IS NULL OR lang = ?2) AND (?3 IS NULL OR created_at >= ?3) AND (?4 IS NULL OR created_at < ?4) ORDER BY created_at DESC LIMIT ?5"
+                ),
+                (
+                    filter.id_prefix,
+                    filter.lang,
+                    filter.created_from,
+                    filter.created_to,
+                    i64::from(limit),
+                ),
+            )
+            .await
+            .context("Failed to search results")?;
+
+        let mut results = Vec::new();
+        while let Some(row) = rows.next().await? {
+            results.push(result_from_row(&row)?);
+        }
+        Ok(results)
+    }
+
+    async fn exists(&self, id: &str) -> Result<bool> {
+        let conn = get_connection()?;
+        let mut rows = conn
+            .query("SELECT 1 FROM results WHERE id = ?", (id,))
+            .await
+            .context("Failed to query result")?;
+        Ok(rows.next().await?.is_some())
+    }
+
+    async fn set_unpublished(&self, id: &str, unpublished: bool) -> Result<bool> {
+        let conn = get_connection()?;
+        let updated = conn
+            .execute(
+                "UPDATE results SET unpublished = ? WHERE id = ?",
+                (unpublished as i64, id),
+            )
+            .await
+            .context("Failed to update result")?;
+        Ok(updated > 0)
+    }
+
+    async fn purge(&self, id: &str) -> Result<bool> {
+        let conn = get_connection()?;
+        let deleted = conn
+            .execute("DELETE FROM results WHERE id = ?", (id,))
+            .await
+            .context("Failed to purge result")?;
+        Ok(deleted > 0)
+    }
+
+    async fn delete(&self, id: &str, owner_token: &str, now: i64) -> Result<bool> {
+        let conn = get_connection()?;
+        let deleted = conn
+            .execute(
+                "UPDATE results SET deleted_at = ? WHERE id = ? AND owner_token = ? AND deleted_at IS NULL",
+                (now, id, owner_token),
+            )
+            .await
+            .context("Failed to delete result")?;
+        Ok(deleted > 0)
+    }
+
+    async fn restore(&self, id: &str, owner_token: &str, deleted_since: i64) -> Result<bool> {
+        let conn = get_connection()?;
+        let restored = conn
+            .execute(
+                "UPDATE results SET deleted_at = NULL WHERE id = ? AND owner_token = ? AND deleted_at >= ?",
+                (id, owner_token, deleted_since),
+            )
+            .await
+            .context("Failed to restore result")?;
+        Ok(restored > 0)
+    }
+
+    async fn purge_deleted(&self, deleted_before: i64) -> Result<u64> {
+        let conn = get_connection()?;
+        let purged = conn
+            .execute(
+                "DELETE FROM results WHERE deleted_at IS NOT NULL AND deleted_at < ?",
+                (deleted_before,),
+            )
+            .await
+            .context("Failed to purge deleted results")?;
+        Ok(purged)
+    }
+
+    async fn profiles(&self, filter: &ProfileFilter<'_>) -> Result<Vec<StoredProfile>> {
+        let conn = get_connection()?;
+        let mut rows = conn
+            .query(
+                "SELECT profile_json, demographics_json FROM results WHERE (?1 IS NULL OR lang = ?1) AND (?2 IS NULL OR created_at >= ?2) AND (?3 IS NULL OR created_at < ?3) AND (?4 <> 0 OR deleted_at IS NULL) AND (?5 IS NULL OR COALESCE(tenant, '') = ?5)",
+                (
+                    filter.lang,
+                    filter.created_from,
+                    filter.created_to,
+                    i64::from(filter.include_deleted),
+                    filter.tenant,
+                ),
+            )
+            .await
+            .context("Failed to query profiles")?;
+
+        let mut profiles = Vec::new();
+        while let Some(row) = rows.next().await? {
+            let profile_json: String = row.get(0)?;
+            profiles.push(StoredProfile {
+                profile: serde_json::from_str(&profile_json).ok(),
+                demographics: row
+                    .get::<String>(1)
+                    .ok()
+                    .and_then(|json| serde_json::from_str(&json).ok()),
+            });
+        }
+        Ok(profiles)
+    }
+}
+
+/// A result kept by [`MemoryResultStore`] with the columns only used for lookups.
+#[derive(Debug, Clone)]
+struct MemoryRow {
+    result: SavedResult,
+    tenant: String,
+    idempotency_key: Option<String>,
+}
+
+/// Results kept in process memory, lost on restart.
+#[derive(Debug, Default)]
+pub struct MemoryResultStore {
+    rows: Mutex<HashMap<String, MemoryRow>>,
+}
+
+#[async_trait]
+impl ResultStore for MemoryResultStore {
+    async fn save(&self, result: &NewResult<'_>, created_at: i64) -> Result<()> {
+        let non_empty = |s: Option<&str>| s.filter(|s| !s.is_empty()).map(str::to_string);
+
+        let mut rows = self.rows.lock().unwrap();
+        if rows.contains_key(result.id) {
+            bail!("Failed to insert result: id {} already exists", result.id);
+        }
+        rows.insert(
+            result.id.to_string(),
+            MemoryRow {
+                result: SavedResult {
+                    id: result.id.to_string(),
+                    profile: result.profile.clone(),
+                    user_context: non_empty(result.user_context),
+                    ai_analysis: non_empty(result.ai_analysis),
+                    prompt_version: non_empty(result.prompt_version),
+                    lang: result.lang.to_string(),
+                    created_at,
+                    nickname: non_empty(result.nickname),
+                    expires_at: result.expires_at,
+                    access_token: non_empty(result.access_token),
+                    owner_token: non_empty(Some(result.owner_token)),
+                    unpublished: false,
+                    deleted_at: None,
+                    demographics: result.demographics.cloned(),
+                },
+                tenant: result.tenant.to_string(),
+                idempotency_key: result.idempotency_key.map(str::to_string),
+            },
+        );
+        Ok(())
+    }
+
+    async fn find_by_idempotency_key(
+        &self,
+        key: &str,
+        tenant: &str,
+    ) -> Result<Option<ResultHandle>> {
+        let rows = self.rows.lock().unwrap();
+        Ok(rows
+            .values()
+            .find(|row| {
+                row.idempotency_key.as_deref() == Some(key)
+                    && row.tenant == tenant
+                    && row.result.deleted_at.is_none()
+            })
+            .map(|row| ResultHandle {
+                id: row.result.id.clone(),
+                owner_token: row.result.owner_token.clone().unwrap_or_default(),
+                access_token: row.result.access_token.clone(),
+            }))
+    }
+
+    async fn get(&self, id: &str, tenant: &str, now: i64) -> Result<Option<SavedResult>> {
+        let rows = self.rows.lock().unwrap();
+        Ok(rows
+            .get(id)
+            .filter(|row| {
+                row.tenant == tenant
+                    && row
+                        .result
+                        .expires_at
+                        .is_none_or(|expires_at| expires_at > now)
+                    && !row.result.unpublished
+                    && row.result.deleted_at.is_none()
+            })
+            .map(|row| row.result.clone()))
+    }
+
+    async fn search(&self, filter: &ResultFilter<'_>, limit: u32) -> Result<Vec<SavedResult>> {
+        let rows = self.rows.lock().unwrap();
+        let mut results: Vec<_> = rows
+            .values()
+            .map(|row| &row.result)
+            .filter(|r| {
+                filter
+                    .id_prefix
+                    .is_none_or(|prefix| r.id.starts_with(prefix))
+            })
+            .filter(|r| filter.lang.is_none_or(|lang| r.lang == lang))
+            .filter(|r| filter.created_from.is_none_or(|from| r.created_at >= from))
+            .filter(|r| filter.created_to.is_none_or(|to| r.created_at < to))
+            .cloned()
+            .collect();
+        results.sort_by_key(|r| std::cmp::Reverse(r.created_at));
+        results.truncate(limit as usize);
+        Ok(results)
+    }
+
+    async fn exists(&self, id: &str) -> Result<bool> {
+        Ok(self.rows.lock().unwrap().contains_key(id))
+    }
+
+    async fn set_unpublished(&self, id: &str, unpublished: bool) -> Result<bool> {
+        let mut rows = self.rows.lock().unwrap();
+        Ok(rows
+            .get_mut(id)
+            .map(|row| row.result.unpublished = unpublished)
+            .is_some())
+    }
+
+    async fn purge(&self, id: &str) -> Result<bool> {
+        Ok(self.rows.lock().unwrap().remove(id).is_some())
+    }
+
+    async fn delete(&self, id: &str, owner_token: &str, now: i64) -> Result<bool> {
+        let mut rows = self.rows.lock().unwrap();
+        let Some(result) = rows
+            .get_mut(id)
+            .map(|row| &mut row.result)
+            .filter(|r| r.owner_token.as_deref() == Some(owner_token) && r.deleted_at.is_none())
+        else {
+            return Ok(false);
+        };
+        result.deleted_at = Some(now);
+        Ok(true)
+    }
+
+    async fn restore(&self, id: &str, owner_token: &str, deleted_since: i64) -> Result<bool> {
+        let mut rows = self.rows.lock().unwrap();
+        let Some(result) = rows.get_mut(id).map(|row| &mut row.result).filter(|r| {
+            r.owner_token.as_deref() == Some(owner_token)
+                && r.deleted_at.is_some_and(|at| at >= deleted_since)
+        }) else {
+            return Ok(false);
+        };
+        result.deleted_at = None;
+        Ok(true)
+    }
+
+    async fn purge_deleted(&self, deleted_before: i64) -> Result<u64> {
+        let mut rows = self.rows.lock().unwrap();
+        let before = rows.len();
+        rows.retain(|_, row| row.result.deleted_at.is_none_or(|at| at >= deleted_before));
+        Ok((before - rows.len()) as u64)
+    }
+
+    async fn profiles(&self, filter: &ProfileFilter<'_>) -> Result<Vec<StoredProfile>> {
+        let rows = self.rows.lock().unwrap();
+        Ok(rows
+            .values()
+            .filter(|row| filter.tenant.is_none_or(|tenant| row.tenant == tenant))
+            .map(|row| &row.result)
+            .filter(|r| filter.lang.is_none_or(|lang| r.lang == lang))
+            .filter(|r| filter.created_from.is_none_or(|from| r.created_at >= from))
+            .filter(|r| filter.created_to.is_none_or(|to| r.created_at < to))
+            .filter(|r| filter.include_deleted || r.deleted_at.is_none())
+            .map(|r| StoredProfile {
+                profile: Some(r.profile.clone()),
+                demographics: r.demographics.clone(),
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bigfive::{Answer, calculate};
+
+    use super::*;
+
+    fn profile() -> PersonalityProfile {
+        let inventory = crate::inventory::reference();
+        let answers: Vec<Answer> = inventory
+            .questions()
+            .iter()
+            .enumerate()
+            .map(|(i, q)| Answer {
+                question_id: q.id.clone(),
+                value: (i % 5) as u8 + 1,
+            })
+            .collect();
+        calculate(&inventory, &answers).unwrap()
+    }
+
+    fn new_result<'a>(id: &'a str, profile: &'a PersonalityProfile) -> NewResult<'a> {
+        NewResult {
+            id,
+            profile,
+            user_context: None,
+            ai_analysis: None,
+            prompt_version: None,
+            analysis_consented_at: None,
+            lang: "en",
+            owner_token: "owner",
+            nickname: None,
+            expires_at: None,
+            access_token: None,
+            demographics: None,
+            tenant: "",
+            idempotency_key: None,
+        }
+    }
+
+    /// Behaviour both backends must share, starting from an empty store.
+    async fn check_store(store: &dyn ResultStore) {
+        let profile = profile();
+        store
+            .save(
+                &NewResult {
+                    ai_analysis: Some("analysis"),
+                    access_token: Some("access"),
+                    idempotency_key: Some("retry-key"),
+                    ..new_result("plain", &profile)
+                },
+                100,
+            )
+            .await
+            .unwrap();
+        store
+            .save(
+                &NewResult {
+                    lang: "ru",
+                    expires_at: Some(500),
+                    tenant: "acme",
+                    ..new_result("expiring", &profile)
+                },
+                200,
+            )
+            .await
+            .unwrap();
+        assert!(
+            store
+                .save(&new_result("plain", &profile), 300)
+                .await
+                .is_err()
+        );
+
+        let saved = store.get("plain", "", 150).await.unwrap().unwrap();
+        assert_eq!(saved.created_at, 100);
+        assert_eq!(saved.ai_analysis.as_deref(), Some("analysis"));
+        assert_eq!(saved.user_context, None);
+        assert_eq!(saved.profile.domains, profile.domains);
+        assert!(!saved.is_unlocked_by(None));
+        assert!(saved.is_unlocked_by(Some("owner")));
+
+        // Other tenants and expired links don't see a result
+        assert!(store.get("expiring", "", 300).await.unwrap().is_none());
+        assert!(store.get("expiring", "acme", 300).await.unwrap().is_some());
+        assert!(store.get("expiring", "acme", 500).await.unwrap().is_none());
+
+        let handle = store
+            .find_by_idempotency_key("retry-key", "")
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(handle.id, "plain");
+        assert_eq!(handle.access_token.as_deref(), Some("access"));
+        assert!(
+            store
+                .find_by_idempotency_key("retry-key", "acme")
+                .await
+                .unwrap()
+                .is_none()
+        );
+
+        // Searches include hidden results, newest first
+        assert!(store.set_unpublished("plain", true).await.unwrap());
+        assert!(store.get("plain", "", 150).await.unwrap().is_none());
+        let found = store.search(&ResultFilter::default(), 10).await.unwrap();
+        let ids: Vec<_> = found.iter().map(|r| r.id.as_str()).collect();
+        assert_eq!(ids, ["expiring", "plain"]);
+        let filter = ResultFilter {
+            lang: Some("ru"),
+            ..ResultFilter::default()
+        };
+        assert_eq!(store.search(&filter, 10).await.unwrap().len(), 1);
+        assert!(store.set_unpublished("plain", false).await.unwrap());
+        assert!(!store.set_unpublished("missing", true).await.unwrap());
+
+        // Deletion needs the owner token and can be undone within the grace period
+        assert!(!store.delete("plain", "not-owner", 1000).await.unwrap());
+        assert!(store.delete("plain", "owner", 1000).await.unwrap());
+        assert!(!store.delete("plain", "owner", 1001).await.unwrap());
+        assert!(store.get("plain", "", 150).await.unwrap().is_none());
+        assert!(store.exists("plain").await.unwrap());
+        assert_eq!(
+            store
+                .profiles(&ProfileFilter::default())
+                .await
+                .unwrap()
+                .len(),
+            1
+        );
+        let all = ProfileFilter {
+            include_deleted: true,
+            ..ProfileFilter::default()
+        };
+        assert_eq!(store.profiles(&all).await.unwrap().len(), 2);
+        assert!(!store.restore("plain", "owner", 1001).await.unwrap());
+        assert!(store.restore("plain", "owner", 900).await.unwrap());
+        assert!(store.get("plain", "", 150).await.unwrap().is_some());
+
+        // Purging
+        assert!(store.delete("plain", "owner", 1000).await.unwrap());
+        assert_eq!(store.purge_deleted(1000).await.unwrap(), 0);
+        assert_eq!(store.purge_deleted(1001).await.unwrap(), 1);
+        assert!(!store.exists("plain").await.unwrap());
+        assert!(store.purge("expiring").await.unwrap());
+        assert!(!store.purge("expiring").await.unwrap());
+        assert!(store.profiles(&all).await.unwrap().is_empty());
+
+        // Aggregates of one tenant don't see another's results
+        for (id, tenant) in [("home", ""), ("acme-1", "acme"), ("acme-2", "acme")] {
+            store
+                .save(
+                    &NewResult {
+                        tenant,
+                        ..new_result(id, &profile)
+                    },
+                    100,
+                )
+                .await
+                .unwrap();
+        }
+        let tenant_profiles = |tenant| ProfileFilter {
+            tenant: Some(tenant),
+            ..ProfileFilter::default()
+        };
+        assert_eq!(store.profiles(&tenant_profiles("")).await.unwrap().len(), 1);
+        assert_eq!(
+            store
+                .profiles(&tenant_profiles("acme"))
+                .await
+                .unwrap()
+                .len(),
+            2
+        );
+        assert!(
+            store
+                .profiles(&tenant_profiles("other"))
+                .await
+                .unwrap()
+                .is_empty()
+        );
+        assert_eq!(
+            store
+                .profiles(&ProfileFilter::default())
+                .await
+                .unwrap()
+                .len(),
+            3
+        );
+    }
+
+    #[tokio::test]
+    async fn test_memory_store() {
+        check_store(&MemoryResultStore::default()).await;
+    }
+
+    #[tokio::test]
+    async fn test_turso_store() {
+        crate::db::init_database(crate::db::IN_MEMORY_PATH)
+            .await
+            .unwrap();
+        check_store(&TursoResultStore).await;
+    }
+}
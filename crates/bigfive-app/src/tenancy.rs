@@ -0,0 +1,45 @@
+//! Multi-tenant mode: several branded instances served from one process.
+//!
+//! [`resolve_tenant`] runs on every request and records which `[[tenants]]` entry its Host
+//! header belongs to. Server functions and SSR components read it back with
+//! [`current_tenant`] to pick the tenant's branding, model presets and database namespace.
+//! Requests for unknown hosts are served as the default site.
+
+use axum::extract::Request;
+use axum::http::header::HOST;
+use axum::middleware::Next;
+use axum::response::Response;
+use leptos::prelude::use_context;
+
+use crate::config::{TenantConfig, get_config};
+
+/// Tenant of a request, stored in its extensions by [`resolve_tenant`].
+#[derive(Debug, Clone, Copy)]
+pub struct CurrentTenant(pub Option<&'static TenantConfig>);
+
+/// Middleware resolving the request's tenant from its Host header.
+pub async fn resolve_tenant(mut req: Request, next: Next) -> Response {
+    let tenant = get_config().ok().and_then(|config| {
+        let host = req
+            .headers()
+            .get(HOST)
+            .and_then(|h| h.to_str().ok())
+            .or_else(|| req.uri().host())?;
+        config.tenant_for_host(host)
+    });
+    req.extensions_mut().insert(CurrentTenant(tenant));
+    next.run(req).await
+}
+
+/// Tenant of the request being handled; `None` for the default site.
+pub fn current_tenant() -> Option<&'static TenantConfig> {
+    use_context::<axum::http::request::Parts>()?
+        .extensions
+        .get::<CurrentTenant>()?
+        .0
+}
+
+/// Database namespace of the current tenant; empty for the default site.
+pub fn current_namespace() -> &'static str {
+    current_tenant().map_or("", |t| t.id.as_str())
+}
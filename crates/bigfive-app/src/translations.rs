@@ -0,0 +1,65 @@
+//! Interface strings overridden by this deployment.
+//!
+//! Files in the `translations_dir` named in the config (`en.toml`, `ru.toml`) map
+//! translation keys to new strings and take precedence over the built-in ones in
+//! [`crate::i18n`]. They are checked when the config loads and embedded in the SSR shell as
+//! JSON, so the WASM bundle picks them up without a rebuild.
+
+use std::collections::HashMap;
+
+use crate::i18n::Locale;
+
+/// Id of the shell `<script>` element holding the overrides JSON.
+pub const TRANSLATIONS_ELEMENT_ID: &str = "ui-translations";
+
+/// Replacement interface strings by language code, then translation key.
+pub type UiTexts = HashMap<String, HashMap<String, String>>;
+
+/// The overridden string for `key`, if this deployment replaces it.
+pub fn override_text(locale: Locale, key: &str) -> Option<&'static str> {
+    ui_texts()
+        .get(locale.code())
+        .and_then(|texts| texts.get(key))
+        .map(String::as_str)
+}
+
+#[cfg(feature = "ssr")]
+fn ui_texts() -> &'static UiTexts {
+    static EMPTY: std::sync::OnceLock<UiTexts> = std::sync::OnceLock::new();
+    match crate::config::get_config() {
+        Ok(config) => &config.ui_texts,
+        Err(_) => EMPTY.get_or_init(UiTexts::new),
+    }
+}
+
+#[cfg(not(feature = "ssr"))]
+fn ui_texts() -> &'static UiTexts {
+    static TEXTS: std::sync::OnceLock<UiTexts> = std::sync::OnceLock::new();
+    TEXTS.get_or_init(|| {
+        #[cfg(target_arch = "wasm32")]
+        {
+            leptos::prelude::document()
+                .get_element_by_id(TRANSLATIONS_ELEMENT_ID)
+                .and_then(|e| e.text_content())
+                .and_then(|json| serde_json::from_str(&json).ok())
+                .unwrap_or_default()
+        }
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            UiTexts::new()
+        }
+    })
+}
+
+/// Overrides JSON for the shell, safe to place inside a `<script>` element; `None` when
+/// nothing is overridden.
+#[cfg(feature = "ssr")]
+pub fn translations_json() -> Option<String> {
+    let texts = ui_texts();
+    if texts.is_empty() {
+        return None;
+    }
+    serde_json::to_string(texts)
+        .ok()
+        .map(|json| json.replace('<', "\\u003c"))
+}
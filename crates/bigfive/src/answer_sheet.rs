@@ -0,0 +1,236 @@
+//! Complete answer sets with a compact URL-safe encoding.
+//!
+//! An encoded sheet is a format version byte, the number of answers as a LEB128 varint
+//! and the answers packed three bits each. All 120 answers of the IPIP-NEO-120 fit in
+//! 47 bytes, 63 characters of unpadded base64url, so a link can carry them in its
+//! fragment and be scored by whoever opens it without anything stored on a server.
+
+use crate::Error;
+use crate::compact::{
+    decode_base64url, encode_base64url, pack_answers, packed_len, unpack_answers,
+};
+use crate::inventory::Inventory;
+use crate::types::Answer;
+
+/// Encoding version of answer sheets, distinct from the profile and progress encodings.
+const FORMAT_VERSION: u8 = 4;
+
+/// One answer (1-5) to every question of an inventory, in the inventory's order.
+///
+/// Question ids aren't kept, so turning a sheet back into [`Answer`]s needs the inventory
+/// it was made for.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AnswerSheet {
+    values: Vec<u8>,
+}
+
+impl AnswerSheet {
+    /// Sheet of an answer set that [`calculate`](crate::calculate) would accept: one
+    /// answer of 1-5 to each question of `inventory`.
+    pub fn from_answers<I: Inventory + ?Sized>(
+        inventory: &I,
+        answers: &[Answer],
+    ) -> Result<AnswerSheet, Error> {
+        let questions = inventory.questions();
+        if answers.len() != questions.len() {
+            return Err(Error::InvalidAnswerCount(answers.len()));
+        }
+        let mut values = vec![0; questions.len()];
+        for answer in answers {
+            if !(1..=5).contains(&answer.value) {
+                return Err(Error::InvalidAnswerValue(answer.value));
+            }
+            if let Some(position) = questions.iter().position(|q| q.id == answer.question_id) {
+                values[position] = answer.value;
+            }
+        }
+        if let Some(missing) = values.iter().position(|&v| v == 0) {
+            return Err(Error::MissingAnswer(questions[missing].id.clone()));
+        }
+        Ok(AnswerSheet { values })
+    }
+
+    /// Answer values in inventory order.
+    pub fn values(&self) -> &[u8] {
+        &self.values
+    }
+
+    /// The answers to the questions of `inventory`, which must have as many questions as
+    /// the sheet has answers.
+    pub fn answers<I: Inventory + ?Sized>(&self, inventory: &I) -> Result<Vec<Answer>, Error> {
+        let questions = inventory.questions();
+        if questions.len() != self.values.len() {
+            return Err(Error::InvalidAnswerCount(self.values.len()));
+        }
+        Ok(questions
+            .iter()
+            .zip(&self.values)
+            .map(|(question, &value)| Answer {
+                question_id: question.id.clone(),
+                value,
+            })
+            .collect())
+    }
+
+    /// Encode the sheet as unpadded base64url text.
+    pub fn encode(&self) -> String {
+        let mut bytes = vec![FORMAT_VERSION];
+        let mut count = self.values.len();
+        loop {
+            let byte = (count & 0x7f) as u8;
+            count >>= 7;
+            if count == 0 {
+                bytes.push(byte);
+                break;
+            }
+            bytes.push(byte | 0x80);
+        }
+        bytes.extend(pack_answers(&self.values));
+        encode_base64url(&bytes)
+    }
+
+    /// Decode a sheet produced by [`AnswerSheet::encode`].
+    pub fn decode(text: &str) -> Result<AnswerSheet, Error> {
+        let invalid = |message: &str| Error::InvalidAnswerSheet(message.to_string());
+        let bytes = decode_base64url(text).ok_or_else(|| invalid("invalid base64url text"))?;
+        let (&version, rest) = bytes.split_first().ok_or_else(|| invalid("empty"))?;
+        if version != FORMAT_VERSION {
+            return Err(Error::InvalidAnswerSheet(format!(
+                "unsupported version {version}"
+            )));
+        }
+
+        // The count is bounded by what the remaining bytes can hold, so 3 bytes suffice
+        let mut count = 0usize;
+        let mut header_len = 0;
+        loop {
+            let &byte = rest
+                .get(header_len)
+                .filter(|_| header_len < 3)
+                .ok_or_else(|| invalid("invalid answer count"))?;
+            count |= usize::from(byte & 0x7f) << (7 * header_len);
+            header_len += 1;
+            if byte & 0x80 == 0 {
+                break;
+            }
+        }
+
+        let packed = &rest[header_len..];
+        if packed.len() != packed_len(count) {
+            return Err(Error::InvalidAnswerSheet(format!(
+                "expected {} bytes of answers, got {}",
+                packed_len(count),
+                packed.len()
+            )));
+        }
+        let values = unpack_answers(packed, count);
+        if let Some(&value) = values.iter().find(|v| !(1..=5).contains(*v)) {
+            return Err(Error::InvalidAnswerValue(value));
+        }
+        Ok(AnswerSheet { values })
+    }
+}
+
+#[cfg(all(test, feature = "lang-en"))]
+mod tests {
+    use super::*;
+    use crate::{Ipip120, Ipip120Subset60, calculate};
+
+    fn answers(inventory: &impl Inventory) -> Vec<Answer> {
+        inventory
+            .questions()
+            .iter()
+            .enumerate()
+            .map(|(i, q)| Answer {
+                question_id: q.id.clone(),
+                value: (i * 7 % 5) as u8 + 1,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_encode_round_trip() {
+        let inventory = Ipip120::english();
+        let mut answers = answers(&inventory);
+        // Order of the answers doesn't matter
+        answers.reverse();
+        let sheet = AnswerSheet::from_answers(&inventory, &answers).unwrap();
+        let text = sheet.encode();
+        assert_eq!(text.len(), 63);
+        assert!(
+            text.bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_')
+        );
+
+        let decoded = AnswerSheet::decode(&text).unwrap();
+        assert_eq!(decoded, sheet);
+        assert_eq!(
+            calculate(&inventory, &decoded.answers(&inventory).unwrap())
+                .unwrap()
+                .domains,
+            calculate(&inventory, &answers).unwrap().domains
+        );
+    }
+
+    #[test]
+    fn test_short_form_sheet_needs_short_form() {
+        let short = Ipip120Subset60::english();
+        let sheet = AnswerSheet::from_answers(&short, &answers(&short)).unwrap();
+        let decoded = AnswerSheet::decode(&sheet.encode()).unwrap();
+        assert_eq!(decoded.values().len(), 60);
+        assert!(decoded.answers(&short).is_ok());
+        assert!(matches!(
+            decoded.answers(&Ipip120::english()),
+            Err(Error::InvalidAnswerCount(60))
+        ));
+    }
+
+    #[test]
+    fn test_rejects_incomplete_answers() {
+        let inventory = Ipip120::english();
+        let mut answers = answers(&inventory);
+        answers[3].value = 0;
+        assert!(matches!(
+            AnswerSheet::from_answers(&inventory, &answers),
+            Err(Error::InvalidAnswerValue(0))
+        ));
+        answers[3].value = 2;
+        answers[3].question_id = answers[4].question_id.clone();
+        assert!(matches!(
+            AnswerSheet::from_answers(&inventory, &answers),
+            Err(Error::MissingAnswer(_))
+        ));
+        answers.pop();
+        assert!(matches!(
+            AnswerSheet::from_answers(&inventory, &answers),
+            Err(Error::InvalidAnswerCount(119))
+        ));
+    }
+
+    #[test]
+    fn test_decode_rejects_invalid_text() {
+        assert!(AnswerSheet::decode("").is_err());
+        assert!(AnswerSheet::decode("not base64!").is_err());
+
+        let inventory = Ipip120::english();
+        let sheet = AnswerSheet::from_answers(&inventory, &answers(&inventory)).unwrap();
+        let text = sheet.encode();
+        // Truncated
+        assert!(AnswerSheet::decode(&text[..40]).is_err());
+        // A profile link's encoding
+        let profile = calculate(&inventory, &answers(&inventory)).unwrap();
+        assert!(AnswerSheet::decode(&profile.to_compact_string()).is_err());
+        // Count claiming more answers than there are bytes for
+        let mut bytes = decode_base64url(&text).unwrap();
+        bytes[1] = 0x81;
+        bytes.insert(2, 0x01);
+        assert!(AnswerSheet::decode(&encode_base64url(&bytes)).is_err());
+        // An answer of 0
+        let mut bytes = decode_base64url(&text).unwrap();
+        bytes[2] &= !0b111;
+        assert!(matches!(
+            AnswerSheet::decode(&encode_base64url(&bytes)),
+            Err(Error::InvalidAnswerValue(0))
+        ));
+    }
+}
@@ -0,0 +1,250 @@
+//! Descriptive archetype labels for profiles.
+//!
+//! Each domain is split at the midpoint of its score range, giving 32 types named with the
+//! five-letter SLOAN code (Social/Reserved, Limbic/Calm, Organized/Unstructured,
+//! Accommodating/Egocentric, Inquisitive/Non-curious), e.g. "RCOAI" for someone reserved,
+//! calm, organized, accommodating and inquisitive. Types are a lay summary: two people with
+//! the same code can differ more than two people either side of a midpoint.
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::Error;
+use crate::types::{Domain, PersonalityProfile};
+
+/// Domains in the order of their letters in an archetype code.
+const CODE_ORDER: [Domain; 5] = [
+    Domain::Extraversion,
+    Domain::Neuroticism,
+    Domain::Conscientiousness,
+    Domain::Agreeableness,
+    Domain::Openness,
+];
+
+/// One of the 32 archetypes: whether each domain is in the upper half of its range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Archetype {
+    /// Indexed like [`CODE_ORDER`].
+    high: [bool; 5],
+}
+
+impl Archetype {
+    /// All 32 archetypes, in code order from "SLOAI" to "RCUEN".
+    pub fn all() -> impl Iterator<Item = Archetype> {
+        (0..32u8).map(|bits| Archetype {
+            high: std::array::from_fn(|i| bits & (16 >> i) == 0),
+        })
+    }
+
+    /// Parse a five-letter code such as "RCOAI".
+    pub fn from_code(code: &str) -> Option<Archetype> {
+        Archetype::all().find(|a| a.code() == code)
+    }
+
+    /// Whether `domain` is in the upper half of its range.
+    pub fn is_high(&self, domain: Domain) -> bool {
+        let index = CODE_ORDER.iter().position(|d| *d == domain).unwrap();
+        self.high[index]
+    }
+
+    /// Five-letter code, one letter per domain.
+    pub fn code(&self) -> String {
+        CODE_ORDER
+            .iter()
+            .zip(self.high)
+            .map(|(domain, high)| letter(*domain, high))
+            .collect()
+    }
+}
+
+/// Letter of a domain's pole in archetype codes.
+fn letter(domain: Domain, high: bool) -> char {
+    match (domain, high) {
+        (Domain::Extraversion, true) => 'S',
+        (Domain::Extraversion, false) => 'R',
+        (Domain::Neuroticism, true) => 'L',
+        (Domain::Neuroticism, false) => 'C',
+        (Domain::Conscientiousness, true) => 'O',
+        (Domain::Conscientiousness, false) => 'U',
+        (Domain::Agreeableness, true) => 'A',
+        (Domain::Agreeableness, false) => 'E',
+        (Domain::Openness, true) => 'I',
+        (Domain::Openness, false) => 'N',
+    }
+}
+
+impl PersonalityProfile {
+    /// The profile's archetype, counting scores from the midpoint of the range up as high.
+    ///
+    /// `None` if a domain is missing from the profile.
+    pub fn archetype(&self) -> Option<Archetype> {
+        let mut high = [false; 5];
+        for (high, domain) in high.iter_mut().zip(CODE_ORDER) {
+            *high = self.domain_score(domain)?.percentage() >= 50.0;
+        }
+        Some(Archetype { high })
+    }
+}
+
+#[derive(Debug, Deserialize)]
+struct RawArchetypeLabels {
+    /// Keyed by archetype code.
+    names: HashMap<String, String>,
+    /// One sentence per pole, keyed by its letter.
+    poles: HashMap<char, String>,
+}
+
+/// Names and summaries of the archetypes in one language.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArchetypeLabels {
+    lang: String,
+    names: HashMap<Archetype, String>,
+    poles: HashMap<char, String>,
+}
+
+impl ArchetypeLabels {
+    /// Load the bundled labels for a language.
+    ///
+    /// Supported languages: "en" (English) and "ru" (Russian), each only when its `lang-*`
+    /// feature is enabled.
+    pub fn new(lang: &str) -> Result<Self, Error> {
+        let json_data: &str = match lang {
+            #[cfg(feature = "lang-en")]
+            "en" => Some(include_str!("../data/archetypes/en.json")),
+            #[cfg(feature = "lang-ru")]
+            "ru" => Some(include_str!("../data/archetypes/ru.json")),
+            _ => None,
+        }
+        .ok_or_else(|| Error::UnsupportedLanguage(lang.to_string()))?;
+
+        Self::from_json(lang, json_data)
+    }
+
+    /// Parse labels from JSON with `names` keyed by archetype code and `poles` keyed by
+    /// letter, each a sentence describing that pole.
+    ///
+    /// All 32 archetypes and 10 poles must be covered, with no unknown codes.
+    pub fn from_json(lang: &str, json: &str) -> Result<Self, Error> {
+        let raw: RawArchetypeLabels =
+            serde_json::from_str(json).map_err(|e| Error::ParseError(e.to_string()))?;
+
+        let mut names = HashMap::new();
+        for (code, name) in raw.names {
+            let archetype = Archetype::from_code(&code)
+                .ok_or_else(|| Error::MissingDescription(format!("unknown archetype {code}")))?;
+            names.insert(archetype, name);
+        }
+        if let Some(archetype) = Archetype::all().find(|a| !names.contains_key(a)) {
+            return Err(Error::MissingDescription(archetype.code()));
+        }
+        let letters: Vec<char> = CODE_ORDER
+            .iter()
+            .flat_map(|d| [letter(*d, true), letter(*d, false)])
+            .collect();
+        if let Some(unknown) = raw.poles.keys().find(|c| !letters.contains(c)) {
+            return Err(Error::MissingDescription(format!("unknown pole {unknown}")));
+        }
+        if let Some(missing) = letters.iter().find(|c| !raw.poles.contains_key(c)) {
+            return Err(Error::MissingDescription(format!("pole {missing}")));
+        }
+
+        Ok(Self {
+            lang: lang.to_string(),
+            names,
+            poles: raw.poles,
+        })
+    }
+
+    /// Returns the language code.
+    pub fn lang(&self) -> &str {
+        &self.lang
+    }
+
+    /// Name of an archetype, e.g. "The Sage".
+    pub fn name(&self, archetype: Archetype) -> &str {
+        &self.names[&archetype]
+    }
+
+    /// Summary of an archetype: one sentence per domain, in code order.
+    pub fn summary(&self, archetype: Archetype) -> String {
+        archetype
+            .code()
+            .chars()
+            .map(|c| self.poles[&c].as_str())
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+}
+
+#[cfg(all(test, feature = "lang-en"))]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::inventory::Ipip120;
+    use crate::scoring::calculate;
+    use crate::types::Answer;
+
+    #[test]
+    fn test_codes_are_distinct() {
+        let codes: HashSet<String> = Archetype::all().map(|a| a.code()).collect();
+        assert_eq!(codes.len(), 32);
+        for archetype in Archetype::all() {
+            assert_eq!(Archetype::from_code(&archetype.code()), Some(archetype));
+        }
+        assert_eq!(Archetype::from_code("RCOAX"), None);
+    }
+
+    #[test]
+    fn test_profile_archetype() {
+        let inventory = Ipip120::english();
+        let high = [
+            Domain::Conscientiousness,
+            Domain::Agreeableness,
+            Domain::Openness,
+        ];
+        let answers: Vec<Answer> = inventory
+            .questions()
+            .iter()
+            .map(|q| {
+                let score = if high.contains(&q.domain) { 4 } else { 2 };
+                Answer {
+                    question_id: q.id.clone(),
+                    value: if q.reversed { 6 - score } else { score },
+                }
+            })
+            .collect();
+        let archetype = calculate(&inventory, &answers)
+            .unwrap()
+            .archetype()
+            .unwrap();
+        assert_eq!(archetype.code(), "RCOAI");
+        assert!(archetype.is_high(Domain::Openness));
+        assert!(!archetype.is_high(Domain::Neuroticism));
+
+        let labels = ArchetypeLabels::new("en").unwrap();
+        assert_eq!(labels.name(archetype), "The Sage");
+        assert!(
+            labels
+                .summary(archetype)
+                .starts_with("Prefers calm settings")
+        );
+    }
+
+    #[test]
+    fn test_bundled_labels_complete() {
+        for lang in ["en", "ru"] {
+            if let Ok(labels) = ArchetypeLabels::new(lang) {
+                assert!(Archetype::all().all(|a| !labels.name(a).is_empty()));
+            }
+        }
+        let mut json: serde_json::Value =
+            serde_json::from_str(include_str!("../data/archetypes/en.json")).unwrap();
+        json["names"].as_object_mut().unwrap().remove("RLUEN");
+        assert!(matches!(
+            ArchetypeLabels::from_json("en", &json.to_string()),
+            Err(Error::MissingDescription(code)) if code == "RLUEN"
+        ));
+    }
+}
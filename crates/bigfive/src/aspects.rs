@@ -0,0 +1,253 @@
+//! Big Five Aspects: two aspects per domain, between domains and facets.
+//!
+//! DeYoung, Quilty & Peterson (2007) found that the facets of each domain cluster into two
+//! correlated aspects, measured directly by the BFAS. Here the aspects are scored from the
+//! IPIP-NEO facets grouped the way they load in that study, so each facet counts towards
+//! exactly one aspect. This approximates BFAS scores rather than replacing them; Liberalism,
+//! which loads weakly on both Openness/Intellect aspects, is counted under Intellect.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::scoring::ScoreRange;
+use crate::types::{Domain, Facet, PersonalityProfile, ScoreLevel};
+
+/// The ten aspects of the Big Five, two per domain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum Aspect {
+    // Neuroticism
+    Withdrawal,
+    Volatility,
+    // Extraversion
+    Enthusiasm,
+    Assertiveness,
+    // Openness
+    Intellect,
+    Openness,
+    // Agreeableness
+    Compassion,
+    Politeness,
+    // Conscientiousness
+    Industriousness,
+    Orderliness,
+}
+
+impl Aspect {
+    /// All aspects, in domain order.
+    pub fn all() -> &'static [Aspect] {
+        &[
+            Aspect::Withdrawal,
+            Aspect::Volatility,
+            Aspect::Enthusiasm,
+            Aspect::Assertiveness,
+            Aspect::Intellect,
+            Aspect::Openness,
+            Aspect::Compassion,
+            Aspect::Politeness,
+            Aspect::Industriousness,
+            Aspect::Orderliness,
+        ]
+    }
+
+    /// Returns the name of the aspect.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Aspect::Withdrawal => "Withdrawal",
+            Aspect::Volatility => "Volatility",
+            Aspect::Enthusiasm => "Enthusiasm",
+            Aspect::Assertiveness => "Assertiveness",
+            Aspect::Intellect => "Intellect",
+            Aspect::Openness => "Openness",
+            Aspect::Compassion => "Compassion",
+            Aspect::Politeness => "Politeness",
+            Aspect::Industriousness => "Industriousness",
+            Aspect::Orderliness => "Orderliness",
+        }
+    }
+
+    /// Returns the domain this aspect belongs to.
+    pub fn domain(&self) -> Domain {
+        match self {
+            Aspect::Withdrawal | Aspect::Volatility => Domain::Neuroticism,
+            Aspect::Enthusiasm | Aspect::Assertiveness => Domain::Extraversion,
+            Aspect::Intellect | Aspect::Openness => Domain::Openness,
+            Aspect::Compassion | Aspect::Politeness => Domain::Agreeableness,
+            Aspect::Industriousness | Aspect::Orderliness => Domain::Conscientiousness,
+        }
+    }
+
+    /// Facets summed into this aspect.
+    pub fn facets(&self) -> &'static [Facet] {
+        match self {
+            Aspect::Withdrawal => &[
+                Facet::Anxiety,
+                Facet::Depression,
+                Facet::SelfConsciousness,
+                Facet::Vulnerability,
+            ],
+            Aspect::Volatility => &[Facet::Anger, Facet::Immoderation],
+            Aspect::Enthusiasm => &[
+                Facet::Friendliness,
+                Facet::Gregariousness,
+                Facet::Cheerfulness,
+            ],
+            Aspect::Assertiveness => &[
+                Facet::Assertiveness,
+                Facet::ActivityLevel,
+                Facet::ExcitementSeeking,
+            ],
+            Aspect::Intellect => &[Facet::Adventurousness, Facet::Intellect, Facet::Liberalism],
+            Aspect::Openness => &[
+                Facet::Imagination,
+                Facet::ArtisticInterests,
+                Facet::Emotionality,
+            ],
+            Aspect::Compassion => &[Facet::Trust, Facet::Altruism, Facet::Sympathy],
+            Aspect::Politeness => &[Facet::Morality, Facet::Cooperation, Facet::Modesty],
+            Aspect::Industriousness => &[
+                Facet::SelfEfficacy,
+                Facet::AchievementStriving,
+                Facet::SelfDiscipline,
+            ],
+            Aspect::Orderliness => &[Facet::Orderliness, Facet::Dutifulness, Facet::Cautiousness],
+        }
+    }
+}
+
+/// Score for an aspect, the sum of its facet scores.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct AspectScore {
+    /// The aspect being scored.
+    pub aspect: Aspect,
+    /// Raw score, e.g. 12-60 for three IPIP-NEO-120 facets.
+    pub raw: u8,
+    /// Categorized level.
+    pub level: ScoreLevel,
+    /// Number of questions summed into the raw score.
+    pub questions: u8,
+}
+
+impl AspectScore {
+    /// Calculate percentage (0-100) of the raw score range.
+    pub fn percentage(&self) -> f32 {
+        ScoreRange::percentage(self)
+    }
+}
+
+impl ScoreRange for AspectScore {
+    fn raw(&self) -> u8 {
+        self.raw
+    }
+
+    fn questions(&self) -> u8 {
+        self.questions
+    }
+}
+
+impl PersonalityProfile {
+    /// Aspect scores derived from the facet scores, in [`Aspect::all`] order.
+    ///
+    /// Aspects with a facet missing from the profile (or scores too large to add up) are
+    /// left out.
+    pub fn aspects(&self) -> Vec<AspectScore> {
+        Aspect::all()
+            .iter()
+            .filter_map(|aspect| {
+                let (raw, questions) =
+                    aspect
+                        .facets()
+                        .iter()
+                        .try_fold((0u8, 0u8), |(raw, questions), facet| {
+                            let score = self.facet_score(*facet)?;
+                            Some((
+                                raw.checked_add(score.raw)?,
+                                questions.checked_add(score.questions)?,
+                            ))
+                        })?;
+                Some(AspectScore {
+                    aspect: *aspect,
+                    raw,
+                    level: ScoreLevel::for_raw(raw, questions),
+                    questions,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(all(test, feature = "lang-en"))]
+mod tests {
+    use std::collections::HashSet;
+
+    use super::*;
+    use crate::inventory::Ipip120;
+    use crate::scoring::calculate;
+    use crate::types::Answer;
+
+    #[test]
+    fn test_aspects_partition_facets() {
+        let mut seen = HashSet::new();
+        for aspect in Aspect::all() {
+            for facet in aspect.facets() {
+                assert_eq!(facet.domain(), aspect.domain(), "{facet:?} in {aspect:?}");
+                assert!(seen.insert(*facet), "{facet:?} in two aspects");
+            }
+        }
+        assert_eq!(seen.len(), 30);
+    }
+
+    #[test]
+    fn test_aspect_scores() {
+        let inventory = Ipip120::english();
+        // Scored 5 on every item of the Anxiety and Anger facets, 3 elsewhere
+        let answers: Vec<Answer> = inventory
+            .questions()
+            .iter()
+            .map(|q| {
+                let score = if q.domain == Domain::Neuroticism && q.facet_index <= 2 {
+                    5
+                } else {
+                    3
+                };
+                Answer {
+                    question_id: q.id.clone(),
+                    value: if q.reversed { 6 - score } else { score },
+                }
+            })
+            .collect();
+        let profile = calculate(&inventory, &answers).unwrap();
+        let aspects = profile.aspects();
+
+        assert_eq!(aspects.len(), 10);
+        let withdrawal = &aspects[0];
+        assert_eq!(withdrawal.aspect, Aspect::Withdrawal);
+        assert_eq!((withdrawal.raw, withdrawal.questions), (20 + 12 * 3, 16));
+        let volatility = &aspects[1];
+        assert_eq!((volatility.raw, volatility.questions), (20 + 12, 8));
+        assert_eq!(volatility.level, ScoreLevel::High);
+        assert!(aspects[2..].iter().all(|a| a.level == ScoreLevel::Neutral));
+    }
+
+    #[test]
+    fn test_missing_facet_drops_aspect() {
+        let inventory = Ipip120::english();
+        let answers: Vec<Answer> = inventory
+            .questions()
+            .iter()
+            .map(|q| Answer {
+                question_id: q.id.clone(),
+                value: 3,
+            })
+            .collect();
+        let mut profile = calculate(&inventory, &answers).unwrap();
+        profile.domains[0]
+            .facets
+            .retain(|f| f.facet != Facet::Anger);
+
+        let aspects: Vec<Aspect> = profile.aspects().iter().map(|a| a.aspect).collect();
+        assert_eq!(aspects.len(), 9);
+        assert!(!aspects.contains(&Aspect::Volatility));
+    }
+}
@@ -0,0 +1,193 @@
+//! Instrument-independent view of a personality profile.
+//!
+//! Raw scores only mean something within one inventory (4-20 per facet here, other
+//! instruments use other item counts and scales). A [`CommonProfile`] keeps each score as a
+//! percentage of its instrument's range, and can express it as a T-score against a
+//! reference group, so results from different inventories can be shown side by side.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::inventory::Ipip120;
+use crate::short_form::Ipip120Subset60;
+use crate::types::{Domain, Facet, PersonalityProfile};
+
+/// Scale a [`CommonProfile`] score is expressed on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ScoreScale {
+    /// Percentage of the instrument's score range (0-100).
+    Percent,
+    /// Standardized score with mean 50 and standard deviation 10 in a reference group.
+    TScore,
+}
+
+/// Mean and standard deviation of a reference group, on the percent scale.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ScaleNorm {
+    pub mean: f32,
+    pub sd: f32,
+}
+
+impl ScaleNorm {
+    /// Norm of a group of percent scores. Returns `None` for fewer than two scores or no
+    /// spread, where a T-score is undefined.
+    pub fn from_scores(scores: impl IntoIterator<Item = f32>) -> Option<Self> {
+        let scores: Vec<f64> = scores.into_iter().map(f64::from).collect();
+        if scores.len() < 2 {
+            return None;
+        }
+        let n = scores.len() as f64;
+        let mean = scores.iter().sum::<f64>() / n;
+        let variance = scores.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / (n - 1.0);
+        let sd = variance.sqrt();
+        (sd > 0.0).then_some(ScaleNorm {
+            mean: mean as f32,
+            sd: sd as f32,
+        })
+    }
+
+    /// T-score of a percent score.
+    pub fn t_score(&self, percent: f32) -> f32 {
+        50.0 + 10.0 * (percent - self.mean) / self.sd
+    }
+}
+
+/// Profile scores on a scale shared by all instruments.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CommonProfile {
+    /// Instrument the scores came from, e.g. [`Ipip120::INSTRUMENT`].
+    pub instrument: String,
+    /// Domain scores as a percentage of the instrument's range.
+    pub domains: Vec<(Domain, f32)>,
+    /// Facet scores as a percentage of the instrument's range; empty for instruments
+    /// without facets.
+    pub facets: Vec<(Facet, f32)>,
+}
+
+impl CommonProfile {
+    /// Domain score on the given scale.
+    ///
+    /// [`ScoreScale::TScore`] needs the reference group's `norm` and gives `None` without it.
+    pub fn domain(
+        &self,
+        domain: Domain,
+        scale: ScoreScale,
+        norm: Option<&ScaleNorm>,
+    ) -> Option<f32> {
+        let percent = self
+            .domains
+            .iter()
+            .find(|(d, _)| *d == domain)
+            .map(|(_, p)| *p)?;
+        convert(percent, scale, norm)
+    }
+
+    /// Facet score on the given scale, like [`CommonProfile::domain`].
+    pub fn facet(&self, facet: Facet, scale: ScoreScale, norm: Option<&ScaleNorm>) -> Option<f32> {
+        let percent = self
+            .facets
+            .iter()
+            .find(|(f, _)| *f == facet)
+            .map(|(_, p)| *p)?;
+        convert(percent, scale, norm)
+    }
+}
+
+fn convert(percent: f32, scale: ScoreScale, norm: Option<&ScaleNorm>) -> Option<f32> {
+    match scale {
+        ScoreScale::Percent => Some(percent),
+        ScoreScale::TScore => norm.map(|n| n.t_score(percent)),
+    }
+}
+
+impl From<&PersonalityProfile> for CommonProfile {
+    fn from(profile: &PersonalityProfile) -> Self {
+        let instrument =
+            if profile.questions_per_facet() == Some(Ipip120Subset60::QUESTIONS_PER_FACET) {
+                Ipip120Subset60::INSTRUMENT
+            } else {
+                Ipip120::INSTRUMENT
+            };
+        CommonProfile {
+            instrument: instrument.to_string(),
+            domains: profile
+                .domains
+                .iter()
+                .map(|d| (d.domain, d.percentage()))
+                .collect(),
+            facets: profile
+                .domains
+                .iter()
+                .flat_map(|d| &d.facets)
+                .map(|f| (f.facet, f.percentage()))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(all(test, feature = "lang-en"))]
+mod tests {
+    use super::*;
+    use crate::{Answer, calculate};
+
+    fn uniform_profile(value: u8) -> PersonalityProfile {
+        let inventory = Ipip120::english();
+        let answers: Vec<Answer> = inventory
+            .questions()
+            .iter()
+            .map(|q| Answer {
+                question_id: q.id.clone(),
+                value,
+            })
+            .collect();
+        calculate(&inventory, &answers).unwrap()
+    }
+
+    #[test]
+    fn test_from_ipip120() {
+        let profile = uniform_profile(4);
+        let common = CommonProfile::from(&profile);
+        assert_eq!(common.instrument, "ipip-neo-120");
+        assert_eq!(common.domains.len(), 5);
+        assert_eq!(common.facets.len(), 30);
+        for score in &profile.domains {
+            assert_eq!(
+                common.domain(score.domain, ScoreScale::Percent, None),
+                Some(score.percentage())
+            );
+        }
+        let facet = Facet::from_domain_and_index(Domain::Openness, 3).unwrap();
+        assert_eq!(
+            common.facet(facet, ScoreScale::Percent, None),
+            profile.facet_score(facet).map(|f| f.percentage())
+        );
+    }
+
+    #[test]
+    fn test_t_scores() {
+        let norm = ScaleNorm::from_scores([40.0, 50.0, 60.0]).unwrap();
+        assert_eq!(norm.mean, 50.0);
+        assert_eq!(norm.sd, 10.0);
+        assert_eq!(norm.t_score(50.0), 50.0);
+        assert_eq!(norm.t_score(60.0), 60.0);
+        assert_eq!(norm.t_score(30.0), 30.0);
+
+        let common = CommonProfile::from(&uniform_profile(3));
+        let domain = Domain::Agreeableness;
+        assert_eq!(common.domain(domain, ScoreScale::TScore, None), None);
+        assert_eq!(
+            common.domain(domain, ScoreScale::TScore, Some(&norm)),
+            Some(50.0)
+        );
+    }
+
+    #[test]
+    fn test_norm_needs_spread() {
+        assert!(ScaleNorm::from_scores([]).is_none());
+        assert!(ScaleNorm::from_scores([50.0]).is_none());
+        assert!(ScaleNorm::from_scores([50.0, 50.0]).is_none());
+    }
+}
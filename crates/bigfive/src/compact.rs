@@ -0,0 +1,273 @@
+//! Compact binary encoding of a personality profile.
+//!
+//! A profile is fully determined by its 30 facet scores, so the encoding is a format
+//! version byte followed by one byte per facet, in [`Domain::all`] and [`Domain::facets`]
+//! order. Domain scores and levels are recomputed when decoding, with the default
+//! [`LevelCutoffs`](crate::LevelCutoffs). The text form is
+//! unpadded base64url, short enough to put in a URL.
+//!
+//! The version also tells the inventory length: version 1 is the IPIP-NEO-120 and
+//! version 2 the 60-question subset. Versions 3 and 4 are taken by
+//! [`TestProgress`](crate::TestProgress) and [`AnswerSheet`](crate::AnswerSheet), so
+//! the encodings can't be confused.
+
+use crate::Error;
+use crate::inventory::Ipip120;
+use crate::short_form::Ipip120Subset60;
+use crate::types::{Domain, DomainScore, FacetScore, PersonalityProfile, ScoreLevel};
+
+/// Encoding version of IPIP-NEO-120 profiles, stored in the first byte.
+const FORMAT_VERSION: u8 = 1;
+
+/// Encoding version of short-form profiles.
+const SHORT_FORMAT_VERSION: u8 = 2;
+
+/// Length of an encoded profile: version byte plus 30 facet scores.
+const ENCODED_LEN: usize = 1 + 30;
+
+const BASE64URL: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+impl PersonalityProfile {
+    /// Encode the profile as 31 bytes.
+    ///
+    /// Missing facets are written as 0, which [`PersonalityProfile::from_bytes`] rejects.
+    /// Only facet scores are encoded; the validity report is left out.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let short = self.questions_per_facet() == Some(Ipip120Subset60::QUESTIONS_PER_FACET);
+        let mut bytes = Vec::with_capacity(ENCODED_LEN);
+        bytes.push(if short {
+            SHORT_FORMAT_VERSION
+        } else {
+            FORMAT_VERSION
+        });
+        for domain in Domain::all() {
+            for facet in domain.facets() {
+                bytes.push(self.facet_score(*facet).map_or(0, |f| f.raw));
+            }
+        }
+        bytes
+    }
+
+    /// Decode a profile produced by [`PersonalityProfile::to_bytes`].
+    pub fn from_bytes(bytes: &[u8]) -> Result<PersonalityProfile, Error> {
+        if bytes.len() != ENCODED_LEN {
+            return Err(Error::InvalidProfileData(format!(
+                "expected {ENCODED_LEN} bytes, got {}",
+                bytes.len()
+            )));
+        }
+        let per_facet = match bytes[0] {
+            FORMAT_VERSION => Ipip120::QUESTIONS_PER_FACET,
+            SHORT_FORMAT_VERSION => Ipip120Subset60::QUESTIONS_PER_FACET,
+            version => {
+                return Err(Error::InvalidProfileData(format!(
+                    "unsupported version {version}"
+                )));
+            }
+        };
+        let (min, max) = (per_facet, per_facet * 5);
+
+        let mut raws = bytes[1..].iter();
+        let mut domains = Vec::with_capacity(Domain::all().len());
+        for domain in Domain::all() {
+            let mut facets = Vec::with_capacity(domain.facets().len());
+            for facet in domain.facets() {
+                let raw = *raws.next().expect("length checked above");
+                if !(min..=max).contains(&raw) {
+                    return Err(Error::InvalidProfileData(format!(
+                        "facet score {raw} out of range (must be {min}-{max})"
+                    )));
+                }
+                facets.push(FacetScore {
+                    facet: *facet,
+                    raw,
+                    level: ScoreLevel::for_raw(raw, per_facet),
+                    questions: per_facet,
+                });
+            }
+            let raw = facets.iter().map(|f| f.raw).sum();
+            let questions = per_facet * facets.len() as u8;
+            domains.push(DomainScore {
+                domain: *domain,
+                raw,
+                level: ScoreLevel::for_raw(raw, questions),
+                facets,
+                questions,
+            });
+        }
+        Ok(PersonalityProfile {
+            domains,
+            validity: None,
+        })
+    }
+
+    /// Encode the profile as unpadded base64url text (42 characters).
+    pub fn to_compact_string(&self) -> String {
+        encode_base64url(&self.to_bytes())
+    }
+
+    /// Decode a profile produced by [`PersonalityProfile::to_compact_string`].
+    pub fn from_compact_string(text: &str) -> Result<PersonalityProfile, Error> {
+        let bytes = decode_base64url(text)
+            .ok_or_else(|| Error::InvalidProfileData("invalid base64url text".to_string()))?;
+        PersonalityProfile::from_bytes(&bytes)
+    }
+}
+
+/// Bits per packed answer value.
+const ANSWER_BITS: usize = 3;
+
+/// Length of `count` answer values packed by [`pack_answers`].
+pub(crate) fn packed_len(count: usize) -> usize {
+    (count * ANSWER_BITS).div_ceil(8)
+}
+
+/// Answer values (0-7) packed three bits each, lowest bits first.
+pub(crate) fn pack_answers(values: &[u8]) -> Vec<u8> {
+    let mut packed = vec![0; packed_len(values.len())];
+    for (i, &value) in values.iter().enumerate() {
+        let bit = i * ANSWER_BITS;
+        let shifted = u16::from(value & 0b111) << (bit % 8);
+        packed[bit / 8] |= shifted as u8;
+        if shifted > 0xff {
+            packed[bit / 8 + 1] |= (shifted >> 8) as u8;
+        }
+    }
+    packed
+}
+
+/// The first `count` values of [`pack_answers`] output, which must be long enough.
+pub(crate) fn unpack_answers(packed: &[u8], count: usize) -> Vec<u8> {
+    (0..count)
+        .map(|i| {
+            let bit = i * ANSWER_BITS;
+            let low = u16::from(packed[bit / 8]);
+            let high = packed.get(bit / 8 + 1).map_or(0, |&b| u16::from(b));
+            ((low | high << 8) >> (bit % 8) & 0b111) as u8
+        })
+        .collect()
+}
+
+/// Unpadded base64url text of `bytes`.
+pub(crate) fn encode_base64url(bytes: &[u8]) -> String {
+    let mut text = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let group = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |acc, (i, &b)| acc | (b as u32) << (16 - 8 * i));
+        for i in 0..=chunk.len() {
+            text.push(BASE64URL[(group >> (18 - 6 * i) & 0x3f) as usize] as char);
+        }
+    }
+    text
+}
+
+/// Bytes of unpadded base64url `text`, or `None` if it isn't valid base64url.
+pub(crate) fn decode_base64url(text: &str) -> Option<Vec<u8>> {
+    let mut bytes = Vec::with_capacity(text.len() * 3 / 4);
+    for chunk in text.as_bytes().chunks(4) {
+        if chunk.len() == 1 {
+            return None;
+        }
+        let mut group = 0u32;
+        for (i, &c) in chunk.iter().enumerate() {
+            let value = BASE64URL.iter().position(|&b| b == c)?;
+            group |= (value as u32) << (18 - 6 * i);
+        }
+        for i in 0..chunk.len() - 1 {
+            bytes.push((group >> (16 - 8 * i)) as u8);
+        }
+    }
+    Some(bytes)
+}
+
+#[cfg(all(test, feature = "lang-en"))]
+mod tests {
+    use super::*;
+    use crate::{Answer, Ipip120, calculate};
+
+    fn sample_profile() -> PersonalityProfile {
+        let inventory = Ipip120::english();
+        let answers: Vec<Answer> = inventory
+            .questions()
+            .iter()
+            .enumerate()
+            .map(|(i, q)| Answer {
+                question_id: q.id.clone(),
+                value: (i % 5) as u8 + 1,
+            })
+            .collect();
+        calculate(&inventory, &answers).unwrap()
+    }
+
+    #[test]
+    fn test_bytes_round_trip() {
+        let profile = sample_profile();
+        let bytes = profile.to_bytes();
+        assert_eq!(bytes.len(), ENCODED_LEN);
+        assert_eq!(
+            PersonalityProfile::from_bytes(&bytes).unwrap().domains,
+            profile.domains
+        );
+    }
+
+    #[test]
+    fn test_compact_string_round_trip() {
+        let profile = sample_profile();
+        let text = profile.to_compact_string();
+        assert_eq!(text.len(), 42);
+        assert!(
+            text.bytes()
+                .all(|b| b.is_ascii_alphanumeric() || b == b'-' || b == b'_')
+        );
+        assert_eq!(
+            PersonalityProfile::from_compact_string(&text)
+                .unwrap()
+                .domains,
+            profile.domains
+        );
+    }
+
+    #[test]
+    fn test_short_form_round_trip() {
+        let inventory = Ipip120Subset60::english();
+        let answers: Vec<Answer> = inventory
+            .questions()
+            .iter()
+            .enumerate()
+            .map(|(i, q)| Answer {
+                question_id: q.id.clone(),
+                value: (i % 5) as u8 + 1,
+            })
+            .collect();
+        let profile = calculate(&inventory, &answers).unwrap();
+        let bytes = profile.to_bytes();
+        assert_eq!(bytes[0], SHORT_FORMAT_VERSION);
+        assert_eq!(
+            PersonalityProfile::from_bytes(&bytes).unwrap().domains,
+            profile.domains
+        );
+    }
+
+    #[test]
+    fn test_rejects_invalid_data() {
+        let mut bytes = sample_profile().to_bytes();
+        assert!(PersonalityProfile::from_bytes(&bytes[..30]).is_err());
+
+        bytes[5] = 21;
+        assert!(PersonalityProfile::from_bytes(&bytes).is_err());
+
+        bytes[5] = 10;
+        bytes[0] = 3;
+        assert!(PersonalityProfile::from_bytes(&bytes).is_err());
+
+        // Full-length scores above the short form's maximum of 10
+        bytes[0] = SHORT_FORMAT_VERSION;
+        bytes[5] = 12;
+        assert!(PersonalityProfile::from_bytes(&bytes).is_err());
+
+        assert!(PersonalityProfile::from_compact_string("not base64!").is_err());
+        assert!(PersonalityProfile::from_compact_string("").is_err());
+    }
+}
@@ -0,0 +1,257 @@
+//! Friction and synergy indicators for two people's profiles, e.g. partners.
+//!
+//! The rules follow what relationship research reports fairly consistently: satisfaction
+//! goes with both partners being low in Neuroticism and high in Agreeableness and
+//! Conscientiousness, and depends on each partner's own levels far more than on how similar
+//! the two are (Malouff et al., 2010; Dyrenforth et al., 2010). Similarity matters mostly where
+//! a gap shapes daily life together: how sociable, curious and organized each partner is.
+//! These are tendencies across many couples, not predictions for one.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::types::{Domain, PersonalityProfile, ScoreLevel};
+
+/// Gap in percentage points from which partners count as far apart on a domain.
+pub const LARGE_GAP_POINTS: f32 = 35.0;
+
+/// Gap in percentage points up to which partners count as alike on a domain.
+pub const SIMILAR_POINTS: f32 = 15.0;
+
+/// Whether an indicator helps or strains the relationship.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum IndicatorKind {
+    Synergy,
+    Friction,
+}
+
+/// Pattern of the two scores behind an indicator.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum PairPattern {
+    /// Both scores are high.
+    BothHigh,
+    /// Both scores are low.
+    BothLow,
+    /// One score is high and the other isn't.
+    OneHigh,
+    /// The scores are at least [`LARGE_GAP_POINTS`] apart.
+    LargeGap,
+    /// The scores are at most [`SIMILAR_POINTS`] apart.
+    Similar,
+}
+
+/// One friction or synergy found on a domain.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CompatibilityIndicator {
+    pub kind: IndicatorKind,
+    pub pattern: PairPattern,
+}
+
+/// Both people's scores on a domain and what they mean together.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DomainCompatibility {
+    pub domain: Domain,
+    /// First person's score as a percentage of the range.
+    pub first: f32,
+    /// Second person's score as a percentage of the range.
+    pub second: f32,
+    /// Indicators found, possibly none.
+    pub indicators: Vec<CompatibilityIndicator>,
+}
+
+impl DomainCompatibility {
+    /// Distance between the scores in percentage points.
+    pub fn gap(&self) -> f32 {
+        (self.first - self.second).abs()
+    }
+}
+
+/// Compatibility indicators for every domain both profiles have, in profile order.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CompatibilityReport {
+    pub domains: Vec<DomainCompatibility>,
+}
+
+impl CompatibilityReport {
+    /// Indicators of one kind with their domains, in profile order.
+    pub fn indicators(&self, kind: IndicatorKind) -> Vec<(Domain, CompatibilityIndicator)> {
+        self.domains
+            .iter()
+            .flat_map(|d| d.indicators.iter().map(|i| (d.domain, *i)))
+            .filter(|(_, i)| i.kind == kind)
+            .collect()
+    }
+}
+
+impl PersonalityProfile {
+    /// Friction and synergy indicators between this profile and `other`.
+    ///
+    /// The result is the same either way round, apart from which scores are `first`.
+    /// Domains missing from either profile are left out.
+    pub fn compatibility(&self, other: &PersonalityProfile) -> CompatibilityReport {
+        let domains = self
+            .domains
+            .iter()
+            .filter_map(|first| {
+                let second = other.domain_score(first.domain)?;
+                let mut domain = DomainCompatibility {
+                    domain: first.domain,
+                    first: first.percentage(),
+                    second: second.percentage(),
+                    indicators: Vec::new(),
+                };
+                domain.indicators = indicators(&domain, first.level, second.level);
+                Some(domain)
+            })
+            .collect();
+        CompatibilityReport { domains }
+    }
+}
+
+/// Indicators for one domain from the levels and gap of the two scores.
+fn indicators(
+    domain: &DomainCompatibility,
+    first: ScoreLevel,
+    second: ScoreLevel,
+) -> Vec<CompatibilityIndicator> {
+    use IndicatorKind::{Friction, Synergy};
+    use PairPattern::*;
+
+    let both = |level| first == level && second == level;
+    let one_high = (first == ScoreLevel::High) != (second == ScoreLevel::High);
+    let large_gap = domain.gap() >= LARGE_GAP_POINTS;
+    let similar = domain.gap() <= SIMILAR_POINTS;
+
+    let found: &[(bool, IndicatorKind, PairPattern)] = match domain.domain {
+        // Two anxious partners escalate; one carries the other's stress
+        Domain::Neuroticism => &[
+            (both(ScoreLevel::High), Friction, BothHigh),
+            (one_high, Friction, OneHigh),
+            (both(ScoreLevel::Low), Synergy, BothLow),
+        ],
+        // Mismatched appetite for company and activity
+        Domain::Extraversion => &[
+            (large_gap, Friction, LargeGap),
+            (both(ScoreLevel::High), Synergy, BothHigh),
+        ],
+        // Shared or diverging interests and views
+        Domain::Openness => &[(large_gap, Friction, LargeGap), (similar, Synergy, Similar)],
+        // Neither backs down in conflicts; or both accommodate
+        Domain::Agreeableness => &[
+            (both(ScoreLevel::Low), Friction, BothLow),
+            (both(ScoreLevel::High), Synergy, BothHigh),
+        ],
+        // Chores and plans left undone, or one partner picking up after the other
+        Domain::Conscientiousness => &[
+            (both(ScoreLevel::Low), Friction, BothLow),
+            (large_gap, Friction, LargeGap),
+            (both(ScoreLevel::High), Synergy, BothHigh),
+        ],
+    };
+    found
+        .iter()
+        .filter(|(applies, _, _)| *applies)
+        .map(|&(_, kind, pattern)| CompatibilityIndicator { kind, pattern })
+        .collect()
+}
+
+#[cfg(all(test, feature = "lang-en"))]
+mod tests {
+    use super::*;
+    use crate::inventory::Ipip120;
+    use crate::scoring::calculate;
+    use crate::types::Answer;
+
+    /// Profile answering every item of each domain with the given score (N, E, O, A, C).
+    fn profile(scores: [u8; 5]) -> PersonalityProfile {
+        let inventory = Ipip120::english();
+        let answers: Vec<Answer> = inventory
+            .questions()
+            .iter()
+            .map(|q| {
+                let index = Domain::all().iter().position(|d| *d == q.domain).unwrap();
+                let score = scores[index];
+                Answer {
+                    question_id: q.id.clone(),
+                    value: if q.reversed { 6 - score } else { score },
+                }
+            })
+            .collect();
+        calculate(&inventory, &answers).unwrap()
+    }
+
+    fn patterns(report: &CompatibilityReport, domain: Domain) -> Vec<PairPattern> {
+        report
+            .domains
+            .iter()
+            .find(|d| d.domain == domain)
+            .unwrap()
+            .indicators
+            .iter()
+            .map(|i| i.pattern)
+            .collect()
+    }
+
+    #[test]
+    fn test_low_agreeableness_and_high_neuroticism_clash() {
+        let first = profile([5, 3, 3, 1, 3]);
+        let second = profile([5, 3, 3, 2, 3]);
+        let report = first.compatibility(&second);
+
+        assert_eq!(
+            patterns(&report, Domain::Neuroticism),
+            [PairPattern::BothHigh]
+        );
+        assert_eq!(
+            patterns(&report, Domain::Agreeableness),
+            [PairPattern::BothLow]
+        );
+        assert_eq!(patterns(&report, Domain::Openness), [PairPattern::Similar]);
+        let frictions = report.indicators(IndicatorKind::Friction);
+        assert_eq!(frictions.len(), 2);
+        assert_eq!(frictions[0].0, Domain::Neuroticism);
+    }
+
+    #[test]
+    fn test_gaps() {
+        let first = profile([1, 5, 5, 4, 5]);
+        let second = profile([4, 2, 2, 4, 1]);
+        let report = first.compatibility(&second);
+
+        assert_eq!(
+            patterns(&report, Domain::Neuroticism),
+            [PairPattern::OneHigh]
+        );
+        assert_eq!(
+            patterns(&report, Domain::Extraversion),
+            [PairPattern::LargeGap]
+        );
+        assert_eq!(patterns(&report, Domain::Openness), [PairPattern::LargeGap]);
+        assert_eq!(
+            patterns(&report, Domain::Agreeableness),
+            [PairPattern::BothHigh]
+        );
+        assert_eq!(
+            patterns(&report, Domain::Conscientiousness),
+            [PairPattern::LargeGap]
+        );
+        assert_eq!(report.indicators(IndicatorKind::Synergy).len(), 1);
+    }
+
+    #[test]
+    fn test_symmetric() {
+        let first = profile([2, 4, 1, 2, 4]);
+        let second = profile([1, 1, 5, 1, 5]);
+        let there = first.compatibility(&second);
+        let back = second.compatibility(&first);
+        for (a, b) in there.domains.iter().zip(&back.domains) {
+            assert_eq!(a.indicators, b.indicators);
+            assert_eq!(a.gap(), b.gap());
+        }
+    }
+}
@@ -0,0 +1,177 @@
+//! Self-reported respondent details.
+//!
+//! Every field is optional: respondents may skip any of them. They are meant for choosing
+//! a norm group when converting scores to percentiles, and for research datasets.
+
+use std::ops::RangeInclusive;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Age range of a respondent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum AgeBand {
+    #[cfg_attr(feature = "serde", serde(rename = "under-18"))]
+    Under18,
+    #[cfg_attr(feature = "serde", serde(rename = "18-24"))]
+    From18To24,
+    #[cfg_attr(feature = "serde", serde(rename = "25-34"))]
+    From25To34,
+    #[cfg_attr(feature = "serde", serde(rename = "35-44"))]
+    From35To44,
+    #[cfg_attr(feature = "serde", serde(rename = "45-54"))]
+    From45To54,
+    #[cfg_attr(feature = "serde", serde(rename = "55-64"))]
+    From55To64,
+    #[cfg_attr(feature = "serde", serde(rename = "65+"))]
+    Over64,
+}
+
+impl AgeBand {
+    /// All age bands, youngest first.
+    pub const ALL: [AgeBand; 7] = [
+        AgeBand::Under18,
+        AgeBand::From18To24,
+        AgeBand::From25To34,
+        AgeBand::From35To44,
+        AgeBand::From45To54,
+        AgeBand::From55To64,
+        AgeBand::Over64,
+    ];
+
+    /// Short code, e.g. `"25-34"`; also the display label.
+    pub fn code(&self) -> &'static str {
+        match self {
+            AgeBand::Under18 => "under-18",
+            AgeBand::From18To24 => "18-24",
+            AgeBand::From25To34 => "25-34",
+            AgeBand::From35To44 => "35-44",
+            AgeBand::From45To54 => "45-54",
+            AgeBand::From55To64 => "55-64",
+            AgeBand::Over64 => "65+",
+        }
+    }
+
+    /// Parse an age band from its code.
+    pub fn from_code(code: &str) -> Option<AgeBand> {
+        AgeBand::ALL.into_iter().find(|band| band.code() == code)
+    }
+
+    /// Ages in the band, in years; the oldest band is open-ended up to `u8::MAX`.
+    pub fn years(&self) -> RangeInclusive<u8> {
+        match self {
+            AgeBand::Under18 => 0..=17,
+            AgeBand::From18To24 => 18..=24,
+            AgeBand::From25To34 => 25..=34,
+            AgeBand::From35To44 => 35..=44,
+            AgeBand::From45To54 => 45..=54,
+            AgeBand::From55To64 => 55..=64,
+            AgeBand::Over64 => 65..=u8::MAX,
+        }
+    }
+}
+
+/// Self-described gender of a respondent.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(rename_all = "lowercase"))]
+pub enum Gender {
+    Female,
+    Male,
+    Other,
+}
+
+impl Gender {
+    /// All genders.
+    pub const ALL: [Gender; 3] = [Gender::Female, Gender::Male, Gender::Other];
+
+    /// Lowercase code, e.g. `"female"`.
+    pub fn code(&self) -> &'static str {
+        match self {
+            Gender::Female => "female",
+            Gender::Male => "male",
+            Gender::Other => "other",
+        }
+    }
+
+    /// Parse a gender from its code.
+    pub fn from_code(code: &str) -> Option<Gender> {
+        Gender::ALL.into_iter().find(|gender| gender.code() == code)
+    }
+}
+
+/// Optional details about the respondent.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct Demographics {
+    pub age_band: Option<AgeBand>,
+    pub gender: Option<Gender>,
+    /// ISO 3166-1 alpha-2 country code, uppercase (e.g. `"DE"`).
+    pub country: Option<String>,
+}
+
+impl Demographics {
+    /// Whether nothing was provided.
+    pub fn is_empty(&self) -> bool {
+        self.age_band.is_none() && self.gender.is_none() && self.country.is_none()
+    }
+
+    /// Whether `code` looks like an ISO 3166-1 alpha-2 country code (two uppercase letters).
+    pub fn is_country_code(code: &str) -> bool {
+        code.len() == 2 && code.bytes().all(|b| b.is_ascii_uppercase())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_codes_round_trip() {
+        for band in AgeBand::ALL {
+            assert_eq!(AgeBand::from_code(band.code()), Some(band));
+        }
+        for gender in Gender::ALL {
+            assert_eq!(Gender::from_code(gender.code()), Some(gender));
+        }
+        assert_eq!(AgeBand::from_code("30"), None);
+        assert_eq!(Gender::from_code("Female"), None);
+    }
+
+    #[test]
+    fn test_years_follow_on() {
+        assert_eq!(*AgeBand::ALL[0].years().start(), 0);
+        for pair in AgeBand::ALL.windows(2) {
+            assert_eq!(*pair[0].years().end() + 1, *pair[1].years().start());
+        }
+        assert_eq!(*AgeBand::Over64.years().end(), u8::MAX);
+    }
+
+    #[test]
+    fn test_country_code() {
+        assert!(Demographics::is_country_code("DE"));
+        assert!(!Demographics::is_country_code("de"));
+        assert!(!Demographics::is_country_code("DEU"));
+        assert!(!Demographics::is_country_code(""));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_serde_uses_codes() {
+        let demographics = Demographics {
+            age_band: Some(AgeBand::From25To34),
+            gender: Some(Gender::Female),
+            country: Some("DE".to_string()),
+        };
+        let json = serde_json::to_string(&demographics).unwrap();
+        assert_eq!(
+            json,
+            r#"{"age_band":"25-34","gender":"female","country":"DE"}"#
+        );
+        assert_eq!(
+            serde_json::from_str::<Demographics>(&json).unwrap(),
+            demographics
+        );
+    }
+}
@@ -0,0 +1,157 @@
+//! Plain-language descriptions of the domains and facets.
+
+use std::collections::HashMap;
+
+use serde::Deserialize;
+
+use crate::Error;
+use crate::types::{Domain, Facet};
+
+/// What a domain measures and what high and low scores look like.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct DomainDescription {
+    /// One or two sentences on what the domain measures.
+    pub summary: String,
+    /// How people scoring high tend to be.
+    pub high: String,
+    /// How people scoring low tend to be.
+    pub low: String,
+}
+
+#[derive(Debug, Deserialize)]
+struct RawDescriptions {
+    /// Keyed by domain code, e.g. "N".
+    domains: HashMap<String, DomainDescription>,
+    /// Keyed by domain code and facet number, e.g. "N1".
+    facets: HashMap<String, String>,
+}
+
+/// Descriptions of all five domains and 30 facets in one language.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Descriptions {
+    lang: String,
+    domains: HashMap<Domain, DomainDescription>,
+    facets: HashMap<Facet, String>,
+}
+
+impl Descriptions {
+    /// Load the bundled descriptions for a language.
+    ///
+    /// Supported languages: "en" (English) and "ru" (Russian), each only when its `lang-*`
+    /// feature is enabled.
+    pub fn new(lang: &str) -> Result<Self, Error> {
+        let json_data: &str = match lang {
+            #[cfg(feature = "lang-en")]
+            "en" => Some(include_str!("../data/descriptions/en.json")),
+            #[cfg(feature = "lang-ru")]
+            "ru" => Some(include_str!("../data/descriptions/ru.json")),
+            _ => None,
+        }
+        .ok_or_else(|| Error::UnsupportedLanguage(lang.to_string()))?;
+
+        Self::from_json(lang, json_data)
+    }
+
+    /// Parse descriptions from JSON with `domains` keyed by domain code (each with `summary`,
+    /// `high` and `low`) and `facets` keyed by facet code such as "N1".
+    ///
+    /// Every domain and facet must be described, with no unknown codes.
+    pub fn from_json(lang: &str, json: &str) -> Result<Self, Error> {
+        let raw: RawDescriptions =
+            serde_json::from_str(json).map_err(|e| Error::ParseError(e.to_string()))?;
+
+        let mut domains = HashMap::new();
+        for (code, description) in raw.domains {
+            let domain =
+                Domain::from_code(&code).ok_or_else(|| Error::InvalidDomain(code.clone()))?;
+            domains.insert(domain, description);
+        }
+        let mut facets = HashMap::new();
+        for (code, description) in raw.facets {
+            let facet = Domain::all()
+                .iter()
+                .flat_map(|d| d.facets())
+                .find(|f| facet_code(**f) == code)
+                .ok_or_else(|| Error::MissingDescription(format!("unknown facet {code}")))?;
+            facets.insert(*facet, description);
+        }
+
+        for domain in Domain::all() {
+            if !domains.contains_key(domain) {
+                return Err(Error::MissingDescription(domain.code().to_string()));
+            }
+            for facet in domain.facets() {
+                if !facets.contains_key(facet) {
+                    return Err(Error::MissingDescription(facet_code(*facet)));
+                }
+            }
+        }
+
+        Ok(Self {
+            lang: lang.to_string(),
+            domains,
+            facets,
+        })
+    }
+
+    /// Returns the language code.
+    pub fn lang(&self) -> &str {
+        &self.lang
+    }
+
+    /// Description of a domain.
+    pub fn domain(&self, domain: Domain) -> &DomainDescription {
+        &self.domains[&domain]
+    }
+
+    /// Description of a facet, starting with its name.
+    pub fn facet(&self, facet: Facet) -> &str {
+        &self.facets[&facet]
+    }
+}
+
+fn facet_code(facet: Facet) -> String {
+    format!("{}{}", facet.domain().code(), facet.index())
+}
+
+#[cfg(all(test, feature = "lang-en"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bundled_descriptions_complete() {
+        for lang in ["en", "ru"] {
+            let Ok(descriptions) = Descriptions::new(lang) else {
+                continue;
+            };
+            for domain in Domain::all() {
+                let d = descriptions.domain(*domain);
+                assert!(!d.summary.is_empty() && !d.high.is_empty() && !d.low.is_empty());
+                for facet in domain.facets() {
+                    assert!(!descriptions.facet(*facet).is_empty());
+                }
+            }
+        }
+        let en = Descriptions::new("en").unwrap();
+        assert!(en.facet(Facet::Anxiety).starts_with("Anxiety"));
+    }
+
+    #[test]
+    fn test_missing_facet_rejected() {
+        let mut json: serde_json::Value =
+            serde_json::from_str(include_str!("../data/descriptions/en.json")).unwrap();
+        json["facets"].as_object_mut().unwrap().remove("C6");
+        assert!(matches!(
+            Descriptions::from_json("en", &json.to_string()),
+            Err(Error::MissingDescription(code)) if code == "C6"
+        ));
+    }
+
+    #[test]
+    fn test_unsupported_language() {
+        assert!(matches!(
+            Descriptions::new("de"),
+            Err(Error::UnsupportedLanguage(_))
+        ));
+    }
+}
@@ -0,0 +1,160 @@
+//! Score changes between two profiles, e.g. after retaking the test.
+//!
+//! Changes are in percentage points of the score range, so a domain and a facet moving by
+//! the same share of their range get the same number.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::types::{Domain, Facet, PersonalityProfile};
+
+/// Change of one domain's score.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct DomainChange {
+    pub domain: Domain,
+    /// Earlier score as a percentage of the range.
+    pub before: f32,
+    /// Later score as a percentage of the range.
+    pub after: f32,
+}
+
+impl DomainChange {
+    /// Change in percentage points; positive when the score went up.
+    pub fn delta(&self) -> f32 {
+        self.after - self.before
+    }
+}
+
+/// Change of one facet's score.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct FacetChange {
+    pub facet: Facet,
+    /// Earlier score as a percentage of the range.
+    pub before: f32,
+    /// Later score as a percentage of the range.
+    pub after: f32,
+}
+
+impl FacetChange {
+    /// Change in percentage points; positive when the score went up.
+    pub fn delta(&self) -> f32 {
+        self.after - self.before
+    }
+}
+
+/// Every domain and facet score change between two profiles, in profile order.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ProfileDiff {
+    pub domains: Vec<DomainChange>,
+    pub facets: Vec<FacetChange>,
+}
+
+impl ProfileDiff {
+    /// Domains that moved by at least `min_points`, largest change first.
+    pub fn notable_domains(&self, min_points: f32) -> Vec<DomainChange> {
+        let mut notable: Vec<DomainChange> = self
+            .domains
+            .iter()
+            .filter(|c| c.delta().abs() >= min_points)
+            .copied()
+            .collect();
+        notable.sort_by(|a, b| b.delta().abs().total_cmp(&a.delta().abs()));
+        notable
+    }
+}
+
+impl PersonalityProfile {
+    /// Score changes from an `earlier` profile to this one.
+    ///
+    /// Domains and facets missing from either profile are left out.
+    pub fn diff(&self, earlier: &PersonalityProfile) -> ProfileDiff {
+        let domains = self
+            .domains
+            .iter()
+            .filter_map(|after| {
+                let before = earlier.domain_score(after.domain)?;
+                Some(DomainChange {
+                    domain: after.domain,
+                    before: before.percentage(),
+                    after: after.percentage(),
+                })
+            })
+            .collect();
+        let facets = self
+            .domains
+            .iter()
+            .flat_map(|d| &d.facets)
+            .filter_map(|after| {
+                let before = earlier.facet_score(after.facet)?;
+                Some(FacetChange {
+                    facet: after.facet,
+                    before: before.percentage(),
+                    after: after.percentage(),
+                })
+            })
+            .collect();
+        ProfileDiff { domains, facets }
+    }
+}
+
+#[cfg(all(test, feature = "lang-en"))]
+mod tests {
+    use super::*;
+    use crate::{Answer, Ipip120, calculate};
+
+    /// Profile with every answer `value`, except Conscientiousness items scored as
+    /// `conscientiousness` (reverse-keyed ones answered accordingly).
+    fn profile(value: u8, conscientiousness: u8) -> PersonalityProfile {
+        let inventory = Ipip120::english();
+        let answers: Vec<Answer> = inventory
+            .questions()
+            .iter()
+            .map(|q| Answer {
+                question_id: q.id.clone(),
+                value: match (q.domain, q.reversed) {
+                    (Domain::Conscientiousness, false) => conscientiousness,
+                    (Domain::Conscientiousness, true) => 6 - conscientiousness,
+                    _ => value,
+                },
+            })
+            .collect();
+        calculate(&inventory, &answers).unwrap()
+    }
+
+    #[test]
+    fn test_diff_same_profile() {
+        let p = profile(3, 3);
+        let diff = p.diff(&p);
+        assert_eq!(diff.domains.len(), 5);
+        assert_eq!(diff.facets.len(), 30);
+        assert!(diff.domains.iter().all(|c| c.delta() == 0.0));
+        assert!(diff.facets.iter().all(|c| c.delta() == 0.0));
+        assert!(diff.notable_domains(1.0).is_empty());
+    }
+
+    #[test]
+    fn test_diff_notable_domains() {
+        let earlier = profile(3, 3);
+        let later = profile(3, 5);
+        let diff = later.diff(&earlier);
+
+        let notable = diff.notable_domains(5.0);
+        assert_eq!(notable.len(), 1);
+        assert_eq!(notable[0].domain, Domain::Conscientiousness);
+        assert!(notable[0].delta() > 0.0);
+        assert_eq!(
+            notable[0].after,
+            later
+                .domain_score(Domain::Conscientiousness)
+                .unwrap()
+                .percentage()
+        );
+
+        // Reversed direction gives the opposite sign
+        let back = earlier.diff(&later).notable_domains(5.0);
+        assert_eq!(back[0].delta(), -notable[0].delta());
+    }
+}
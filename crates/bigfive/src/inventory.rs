@@ -1,9 +1,42 @@
-//! IPIP-NEO-120 inventory loader.
+//! IPIP-NEO-120 inventory loader, and the [`Inventory`] trait scoring works with.
+
+use std::collections::HashSet;
+use std::io::Read;
 
 use crate::Error;
 use crate::types::{Domain, Question};
 use serde::Deserialize;
 
+/// Largest question file accepted by [`Ipip120::from_reader`] (the bundled ones are ~20 KB).
+pub const MAX_INVENTORY_BYTES: usize = 1024 * 1024;
+
+/// A set of questions that can be scored with [`calculate`](crate::calculate).
+///
+/// Every facet of every domain needs the same number of questions, answered on the 1-5
+/// scale. Implement it to score a custom or shortened instrument.
+pub trait Inventory {
+    /// All questions, in the order they are presented.
+    fn questions(&self) -> &[Question];
+
+    /// Number of questions measuring each facet.
+    fn questions_per_facet(&self) -> usize;
+
+    /// Get a question by its ID.
+    fn question_by_id(&self, id: &str) -> Option<&Question> {
+        self.questions().iter().find(|q| q.id == id)
+    }
+
+    /// Get the number of questions.
+    fn len(&self) -> usize {
+        self.questions().len()
+    }
+
+    /// Check if the inventory is empty.
+    fn is_empty(&self) -> bool {
+        self.questions().is_empty()
+    }
+}
+
 /// Raw question format from the Alheimsins JSON data.
 #[derive(Debug, Deserialize)]
 struct RawQuestion {
@@ -14,6 +47,36 @@ struct RawQuestion {
     facet: u8,
 }
 
+/// Answer scale labels of the bundled English inventory, from 1 to 5.
+const ENGLISH_SCALE: [&str; 5] = [
+    "Very Inaccurate",
+    "Moderately Inaccurate",
+    "Neither Accurate Nor Inaccurate",
+    "Moderately Accurate",
+    "Very Accurate",
+];
+
+/// Answer scale labels of the bundled Russian inventory, from 1 to 5.
+const RUSSIAN_SCALE: [&str; 5] = [
+    "Совершенно не соответствует",
+    "Скорее не соответствует",
+    "Нейтрально",
+    "Скорее соответствует",
+    "Полностью соответствует",
+];
+
+/// Question data: either a bare list of questions, or the questions with their own
+/// answer scale labels.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum RawInventory {
+    Questions(Vec<RawQuestion>),
+    WithScale {
+        scale: Vec<String>,
+        questions: Vec<RawQuestion>,
+    },
+}
+
 /// The IPIP-NEO-120 personality inventory.
 ///
 /// Contains 120 questions measuring the Big Five personality traits,
@@ -22,34 +85,95 @@ struct RawQuestion {
 pub struct Ipip120 {
     questions: Vec<Question>,
     lang: String,
+    scale_labels: [String; 5],
 }
 
 impl Ipip120 {
+    /// Instrument id used in [`CommonProfile`](crate::CommonProfile).
+    pub const INSTRUMENT: &'static str = "ipip-neo-120";
+
+    /// Number of questions per facet.
+    pub const QUESTIONS_PER_FACET: u8 = 4;
+
     /// Load the inventory for a specific language.
     ///
-    /// Supported languages: "en" (English), "ru" (Russian)
+    /// Supported languages: "en" (English) and "ru" (Russian), each only when its `lang-*`
+    /// feature is enabled.
     pub fn new(lang: &str) -> Result<Self, Error> {
-        let json_data = match lang {
-            "en" => include_str!("../data/en.json"),
-            "ru" => include_str!("../data/ru.json"),
-            _ => return Err(Error::UnsupportedLanguage(lang.to_string())),
-        };
+        let json_data: &str = match lang {
+            #[cfg(feature = "lang-en")]
+            "en" => Some(include_str!("../data/en.json")),
+            #[cfg(feature = "lang-ru")]
+            "ru" => Some(include_str!("../data/ru.json")),
+            _ => None,
+        }
+        .ok_or_else(|| Error::UnsupportedLanguage(lang.to_string()))?;
 
-        let raw_questions: Vec<RawQuestion> =
-            serde_json::from_str(json_data).map_err(|e| Error::ParseError(e.to_string()))?;
+        Self::from_reader(lang, json_data.as_bytes())
+    }
 
+    /// Load an inventory at runtime from question data in the Alheimsins JSON format.
+    ///
+    /// The data is either the list of questions, or an object with `questions` and a
+    /// `scale` of five answer labels (from 1 to 5) for instruments with other wording.
+    /// Without a scale, the bundled labels for `lang` are used (English for unknown languages).
+    ///
+    /// Input is limited to [`MAX_INVENTORY_BYTES`]. Returns the first problem found: a
+    /// malformed question, a duplicate id, questions not adding up to 4 per facet, or a
+    /// scale without exactly five labels.
+    pub fn from_reader(lang: &str, reader: impl Read) -> Result<Self, Error> {
+        let mut json_data = Vec::new();
+        reader
+            .take(MAX_INVENTORY_BYTES as u64 + 1)
+            .read_to_end(&mut json_data)
+            .map_err(|e| Error::ParseError(e.to_string()))?;
+        if json_data.len() > MAX_INVENTORY_BYTES {
+            return Err(Error::InputTooLarge(MAX_INVENTORY_BYTES));
+        }
+
+        let raw: RawInventory =
+            serde_json::from_slice(&json_data).map_err(|e| Error::ParseError(e.to_string()))?;
+        let (raw_questions, scale_labels) = match raw {
+            RawInventory::Questions(questions) => {
+                let default = if lang == "ru" {
+                    RUSSIAN_SCALE
+                } else {
+                    ENGLISH_SCALE
+                };
+                (questions, default.map(str::to_string))
+            }
+            RawInventory::WithScale { scale, questions } => {
+                let count = scale.len();
+                let labels = <[String; 5]>::try_from(scale)
+                    .map_err(|_| Error::InvalidScaleLabelCount(count))?;
+                (questions, labels)
+            }
+        };
+
+        let mut seen_ids = HashSet::new();
         let questions = raw_questions
             .into_iter()
             .map(|q| {
                 let domain = Domain::from_code(&q.domain)
                     .ok_or_else(|| Error::InvalidDomain(q.domain.clone()))?;
+                if !(1..=6).contains(&q.facet) {
+                    return Err(Error::InvalidFacet(q.id, q.facet));
+                }
+                let reversed = match q.keyed.as_str() {
+                    "plus" => false,
+                    "minus" => true,
+                    _ => return Err(Error::InvalidKeyed(q.id, q.keyed)),
+                };
+                if !seen_ids.insert(q.id.clone()) {
+                    return Err(Error::DuplicateQuestionId(q.id));
+                }
 
                 Ok(Question {
                     id: q.id,
                     text: q.text,
                     domain,
                     facet_index: q.facet,
-                    reversed: q.keyed == "minus",
+                    reversed,
                 })
             })
             .collect::<Result<Vec<_>, Error>>()?;
@@ -57,19 +181,61 @@ impl Ipip120 {
         if questions.len() != 120 {
             return Err(Error::InvalidQuestionCount(questions.len()));
         }
+        for domain in Domain::all() {
+            for facet in 1..=6 {
+                let count = questions
+                    .iter()
+                    .filter(|q| q.domain == *domain && q.facet_index == facet)
+                    .count();
+                if count != 4 {
+                    return Err(Error::InvalidFacetQuestionCount(*domain, facet, count));
+                }
+            }
+        }
 
         Ok(Self {
             questions,
             lang: lang.to_string(),
+            scale_labels,
         })
     }
 
+    /// Replace the wording of some questions, e.g. to fix a typo or adjust phrasing for
+    /// one deployment, given as (question id, new text) pairs.
+    ///
+    /// Only texts change: ids, keying and facets stay as loaded, so scoring is unaffected.
+    /// Fails on an id not in the inventory or an empty text.
+    pub fn with_text_overrides<K, V>(
+        mut self,
+        overrides: impl IntoIterator<Item = (K, V)>,
+    ) -> Result<Self, Error>
+    where
+        K: AsRef<str>,
+        V: AsRef<str>,
+    {
+        for (id, text) in overrides {
+            let (id, text) = (id.as_ref(), text.as_ref().trim());
+            let question = self
+                .questions
+                .iter_mut()
+                .find(|q| q.id == id)
+                .ok_or_else(|| Error::UnknownQuestionId(id.to_string()))?;
+            if text.is_empty() {
+                return Err(Error::EmptyQuestionText(id.to_string()));
+            }
+            question.text = text.to_string();
+        }
+        Ok(self)
+    }
+
     /// Load the English inventory.
+    #[cfg(feature = "lang-en")]
     pub fn english() -> Self {
         Self::new("en").expect("English inventory should always be valid")
     }
 
     /// Load the Russian inventory.
+    #[cfg(feature = "lang-ru")]
     pub fn russian() -> Self {
         Self::new("ru").expect("Russian inventory should always be valid")
     }
@@ -89,6 +255,11 @@ impl Ipip120 {
         &self.lang
     }
 
+    /// Labels of the five answer options, for values 1 to 5.
+    pub fn scale_labels(&self) -> &[String; 5] {
+        &self.scale_labels
+    }
+
     /// Get the number of questions.
     pub fn len(&self) -> usize {
         self.questions.len()
@@ -100,8 +271,21 @@ impl Ipip120 {
     }
 }
 
-#[cfg(test)]
+impl Inventory for Ipip120 {
+    fn questions(&self) -> &[Question] {
+        &self.questions
+    }
+
+    fn questions_per_facet(&self) -> usize {
+        Self::QUESTIONS_PER_FACET.into()
+    }
+}
+
+#[cfg(all(test, feature = "lang-en", feature = "lang-ru"))]
 mod tests {
+    use proptest::prelude::*;
+    use serde_json::Value;
+
     use super::*;
 
     #[test]
@@ -159,4 +343,287 @@ mod tests {
         );
         assert!(reversed_count < 120, "Not all questions should be reversed");
     }
+
+    /// Bundled English question data as mutable JSON values.
+    fn english_json() -> Vec<serde_json::Value> {
+        serde_json::from_str(include_str!("../data/en.json")).unwrap()
+    }
+
+    fn load(questions: &[serde_json::Value]) -> Result<Ipip120, Error> {
+        Ipip120::from_reader("en", serde_json::to_vec(questions).unwrap().as_slice())
+    }
+
+    #[test]
+    fn test_from_reader_matches_bundled() {
+        let loaded = load(&english_json()).unwrap();
+        assert_eq!(loaded, Ipip120::english());
+    }
+
+    #[test]
+    fn test_from_reader_malformed_fields() {
+        let cases: [(&str, serde_json::Value); 4] = [
+            ("domain", "X".into()),
+            ("facet", 7.into()),
+            ("facet", 0.into()),
+            ("keyed", "both".into()),
+        ];
+        // Break each question in turn; the error must name what's wrong
+        for (field, value) in cases {
+            for i in (0..120).step_by(7) {
+                let mut questions = english_json();
+                questions[i][field] = value.clone();
+                let id = questions[i]["id"].as_str().unwrap().to_string();
+                match (field, load(&questions)) {
+                    ("domain", Err(Error::InvalidDomain(code))) => assert_eq!(code, "X"),
+                    ("facet", Err(Error::InvalidFacet(q, _))) => assert_eq!(q, id),
+                    ("keyed", Err(Error::InvalidKeyed(q, keyed))) => {
+                        assert_eq!((q, keyed.as_str()), (id, "both"))
+                    }
+                    (_, other) => panic!("{field} of question {i}: unexpected {other:?}"),
+                }
+            }
+        }
+
+        let mut questions = english_json();
+        questions[3]["facet"] = "one".into();
+        assert!(matches!(load(&questions), Err(Error::ParseError(_))));
+        assert!(matches!(
+            Ipip120::from_reader("en", &b"[{"[..]),
+            Err(Error::ParseError(_))
+        ));
+    }
+
+    #[test]
+    fn test_scale_labels() {
+        assert_eq!(Ipip120::english().scale_labels()[0], "Very Inaccurate");
+        assert_eq!(
+            Ipip120::russian().scale_labels()[4],
+            "Полностью соответствует"
+        );
+
+        let scale = [
+            "Disagree",
+            "Slightly disagree",
+            "Neutral",
+            "Slightly agree",
+            "Agree",
+        ];
+        let data = serde_json::json!({ "scale": scale, "questions": english_json() });
+        let loaded =
+            Ipip120::from_reader("en", serde_json::to_vec(&data).unwrap().as_slice()).unwrap();
+        assert_eq!(loaded.scale_labels(), &scale.map(str::to_string));
+        assert_eq!(loaded.questions(), Ipip120::english().questions());
+
+        let data = serde_json::json!({ "scale": &scale[..4], "questions": english_json() });
+        assert!(matches!(
+            Ipip120::from_reader("en", serde_json::to_vec(&data).unwrap().as_slice()),
+            Err(Error::InvalidScaleLabelCount(4))
+        ));
+    }
+
+    #[test]
+    fn test_from_reader_duplicate_ids() {
+        for i in (1..120).step_by(11) {
+            let mut questions = english_json();
+            questions[i]["id"] = questions[i - 1]["id"].clone();
+            let id = questions[i]["id"].as_str().unwrap().to_string();
+            assert!(matches!(load(&questions), Err(Error::DuplicateQuestionId(d)) if d == id));
+        }
+    }
+
+    #[test]
+    fn test_from_reader_wrong_counts() {
+        for len in [0, 1, 119] {
+            let questions = &english_json()[..len];
+            assert!(matches!(load(questions), Err(Error::InvalidQuestionCount(n)) if n == len));
+        }
+        let mut questions = english_json();
+        let mut extra = questions[0].clone();
+        extra["id"] = "extra".into();
+        questions.push(extra);
+        assert!(matches!(
+            load(&questions),
+            Err(Error::InvalidQuestionCount(121))
+        ));
+
+        // Right total, but one facet borrows a question from another
+        let mut questions = english_json();
+        let from = questions[0]["facet"].as_u64().unwrap();
+        questions[0]["facet"] = (from % 6 + 1).into();
+        assert!(matches!(
+            load(&questions),
+            Err(Error::InvalidFacetQuestionCount(_, _, n)) if n == 3 || n == 5
+        ));
+    }
+
+    #[test]
+    fn test_text_overrides() {
+        let english = Ipip120::english();
+        let id = english.questions()[7].id.clone();
+        let overridden = english
+            .clone()
+            .with_text_overrides([(id.as_str(), " Worry about many things ")])
+            .unwrap();
+        assert_eq!(
+            overridden.question_by_id(&id).unwrap().text,
+            "Worry about many things"
+        );
+        for (a, b) in english.questions().iter().zip(overridden.questions()) {
+            assert_eq!(
+                (&a.id, a.domain, a.facet_index, a.reversed),
+                (&b.id, b.domain, b.facet_index, b.reversed)
+            );
+        }
+
+        assert!(matches!(
+            english.clone().with_text_overrides([("no-such-id", "Text")]),
+            Err(Error::UnknownQuestionId(i)) if i == "no-such-id"
+        ));
+        assert!(matches!(
+            english.with_text_overrides([(id.as_str(), "  ")]),
+            Err(Error::EmptyQuestionText(i)) if i == id
+        ));
+    }
+
+    #[test]
+    fn test_from_reader_huge_input() {
+        let huge = vec![b' '; MAX_INVENTORY_BYTES + 1];
+        assert!(matches!(
+            Ipip120::from_reader("en", huge.as_slice()),
+            Err(Error::InputTooLarge(MAX_INVENTORY_BYTES))
+        ));
+
+        // Stops reading at the limit instead of consuming an endless stream
+        assert!(matches!(
+            Ipip120::from_reader("en", std::io::repeat(b' ')),
+            Err(Error::InputTooLarge(_))
+        ));
+    }
+
+    /// A change that makes valid question data invalid.
+    #[derive(Debug, Clone)]
+    enum Mutation {
+        Domain(usize, String),
+        Facet(usize, i64),
+        Keyed(usize, String),
+        /// Give question `.0` the id of question `.1`.
+        DuplicateId(usize, usize),
+        /// Move question `.0` to the next facet of its domain.
+        MoveFacet(usize),
+        /// Replace a field of question `.0` with a value of the wrong type.
+        WrongType(usize, &'static str, Value),
+        RemoveField(usize, &'static str),
+        /// Keep the first `.0` questions.
+        Truncate(usize),
+        /// Append copies of the first `.0` questions under new ids.
+        Extra(usize),
+        /// Cut the JSON text after `.0` percent of its bytes.
+        CutBytes(u8),
+    }
+
+    fn mutation() -> impl Strategy<Value = Mutation> {
+        let question = 0..120usize;
+        let field = prop_oneof![
+            Just("id"),
+            Just("text"),
+            Just("domain"),
+            Just("facet"),
+            Just("keyed")
+        ];
+        prop_oneof![
+            (question.clone(), "\\PC{0,3}")
+                .prop_filter("not a domain code", |(_, d)| Domain::from_code(d).is_none())
+                .prop_map(|(i, d)| Mutation::Domain(i, d)),
+            (question.clone(), any::<i64>())
+                .prop_filter("not a facet", |(_, f)| !(1..=6).contains(f))
+                .prop_map(|(i, f)| Mutation::Facet(i, f)),
+            (question.clone(), "\\PC{0,6}")
+                .prop_filter("not a key", |(_, k)| k != "plus" && k != "minus")
+                .prop_map(|(i, k)| Mutation::Keyed(i, k)),
+            (question.clone(), question.clone())
+                .prop_filter("two questions", |(i, j)| i != j)
+                .prop_map(|(i, j)| Mutation::DuplicateId(i, j)),
+            question.clone().prop_map(Mutation::MoveFacet),
+            (
+                question.clone(),
+                field.clone(),
+                prop_oneof![
+                    Just(Value::Null),
+                    Just(Value::Bool(true)),
+                    Just(serde_json::json!([1])),
+                    Just(serde_json::json!({})),
+                ]
+            )
+                .prop_map(|(i, f, v)| Mutation::WrongType(i, f, v)),
+            (question.clone(), field).prop_map(|(i, f)| Mutation::RemoveField(i, f)),
+            (0..120usize).prop_map(Mutation::Truncate),
+            (1..=120usize).prop_map(Mutation::Extra),
+            (0..100u8).prop_map(Mutation::CutBytes),
+        ]
+    }
+
+    /// Bundled English question data with `mutation` applied, as JSON bytes.
+    fn mutated_json(mutation: &Mutation) -> Vec<u8> {
+        let mut questions = english_json();
+        match mutation {
+            Mutation::Domain(i, domain) => questions[*i]["domain"] = domain.as_str().into(),
+            Mutation::Facet(i, facet) => questions[*i]["facet"] = (*facet).into(),
+            Mutation::Keyed(i, keyed) => questions[*i]["keyed"] = keyed.as_str().into(),
+            Mutation::DuplicateId(i, j) => questions[*i]["id"] = questions[*j]["id"].clone(),
+            Mutation::MoveFacet(i) => {
+                let facet = questions[*i]["facet"].as_u64().unwrap();
+                questions[*i]["facet"] = (facet % 6 + 1).into();
+            }
+            Mutation::WrongType(i, field, value) => questions[*i][*field] = value.clone(),
+            Mutation::RemoveField(i, field) => {
+                questions[*i].as_object_mut().unwrap().remove(*field);
+            }
+            Mutation::Truncate(len) => questions.truncate(*len),
+            Mutation::Extra(count) => {
+                let extra: Vec<Value> = questions[..*count]
+                    .iter()
+                    .enumerate()
+                    .map(|(n, q)| {
+                        let mut q = q.clone();
+                        q["id"] = format!("extra-{n}").into();
+                        q
+                    })
+                    .collect();
+                questions.extend(extra);
+            }
+            Mutation::CutBytes(percent) => {
+                let json = serde_json::to_vec(&questions).unwrap();
+                let len = json.len() * usize::from(*percent) / 100;
+                return json[..len].to_vec();
+            }
+        }
+        serde_json::to_vec(&questions).unwrap()
+    }
+
+    proptest! {
+        #[test]
+        fn test_from_reader_rejects_mutated_data(mutation in mutation()) {
+            let json = mutated_json(&mutation);
+            prop_assert!(Ipip120::from_reader("en", json.as_slice()).is_err());
+        }
+
+        #[test]
+        fn test_from_reader_never_panics_on_corrupted_bytes(
+            changes in prop::collection::vec((any::<prop::sample::Index>(), any::<u8>()), 1..8),
+        ) {
+            let mut json = serde_json::to_vec(&english_json()).unwrap();
+            for (index, byte) in changes {
+                let i = index.index(json.len());
+                json[i] = byte;
+            }
+            let _ = Ipip120::from_reader("en", json.as_slice());
+        }
+
+        #[test]
+        fn test_from_reader_rejects_arbitrary_bytes(
+            bytes in prop::collection::vec(any::<u8>(), 0..512),
+        ) {
+            prop_assert!(Ipip120::from_reader("en", bytes.as_slice()).is_err());
+        }
+    }
 }
@@ -0,0 +1,286 @@
+//! CSV import and export in the wide format of survey tools and statistics packages.
+//!
+//! Each row is one respondent. Answer files have an optional `id` column and one column
+//! per question id, holding 1-5 or nothing for an unanswered question; profile files
+//! have the raw score of each domain and facet under its code (`N`, `N1`, ..., `C6`).
+//! Fields may be quoted as in RFC 4180, but can't span lines.
+//!
+//! ```
+//! use bigfive::io::{answers_from_csv, profile_to_csv};
+//! use bigfive::{Ipip120, calculate};
+//!
+//! let inventory = Ipip120::english();
+//! let header: Vec<&str> = inventory.questions().iter().map(|q| q.id.as_str()).collect();
+//! let csv = format!("id,{}\nr1,{}\n", header.join(","), vec!["3"; 120].join(","));
+//!
+//! let respondents = answers_from_csv(csv.as_bytes()).unwrap();
+//! let profile = calculate(&inventory, &respondents[0].answers).unwrap();
+//! let scores = profile_to_csv([(respondents[0].id.as_str(), &profile)]);
+//! assert!(scores.starts_with("id,N,N1,"));
+//! ```
+
+use std::io::Read;
+
+use crate::Error;
+use crate::inventory::Inventory;
+use crate::psychometrics::ScaleId;
+use crate::types::{Answer, Domain, PersonalityProfile};
+
+/// Answers of one row of an answers file.
+#[derive(Debug, Clone)]
+pub struct Respondent {
+    /// Value of the `id` column, or the 1-based row number without one.
+    pub id: String,
+    /// Answered questions, in column order.
+    pub answers: Vec<Answer>,
+}
+
+/// Read an answers file, one [`Respondent`] per non-empty row.
+///
+/// Every column other than `id` is taken as a question id; whether those exist in an
+/// inventory is left to scoring. Fails on rows with the wrong number of fields and on
+/// answers other than 1-5.
+pub fn answers_from_csv(mut reader: impl Read) -> Result<Vec<Respondent>, Error> {
+    let mut text = String::new();
+    reader
+        .read_to_string(&mut text)
+        .map_err(|e| Error::InvalidCsv(e.to_string()))?;
+    // Spreadsheet exports often start with a byte order mark
+    let text = text.strip_prefix('\u{feff}').unwrap_or(&text);
+    let mut lines = text
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty());
+
+    let (_, header) = lines
+        .next()
+        .ok_or_else(|| Error::InvalidCsv("empty CSV".to_string()))?;
+    let header = split_row(header).map_err(|e| Error::InvalidCsv(format!("line 1: {e}")))?;
+    let id_col = header.iter().position(|h| h == "id");
+
+    let mut respondents = Vec::new();
+    for (index, line) in lines {
+        let row_error =
+            |message: String| Error::InvalidCsv(format!("line {}: {message}", index + 1));
+        let fields = split_row(line).map_err(row_error)?;
+        if fields.len() != header.len() {
+            return Err(row_error(format!(
+                "expected {} fields, got {}",
+                header.len(),
+                fields.len()
+            )));
+        }
+        let mut answers = Vec::new();
+        for (col, (question_id, field)) in header.iter().zip(&fields).enumerate() {
+            if Some(col) == id_col || field.is_empty() {
+                continue;
+            }
+            let value = field
+                .parse::<u8>()
+                .ok()
+                .filter(|v| (1..=5).contains(v))
+                .ok_or_else(|| row_error(format!("invalid answer to {question_id}: {field}")))?;
+            answers.push(Answer {
+                question_id: question_id.clone(),
+                value,
+            });
+        }
+        let id = match id_col {
+            Some(col) => fields[col].clone(),
+            None => (respondents.len() + 1).to_string(),
+        };
+        respondents.push(Respondent { id, answers });
+    }
+    Ok(respondents)
+}
+
+/// Write an answers file with a column per question of `inventory`, in its order.
+///
+/// Answers to questions the inventory doesn't have are left out.
+pub fn answers_to_csv<I: Inventory + ?Sized>(inventory: &I, respondents: &[Respondent]) -> String {
+    let questions = inventory.questions();
+    let mut csv = String::from("id");
+    for question in questions {
+        csv.push(',');
+        csv.push_str(&quote(&question.id));
+    }
+    csv.push('\n');
+
+    for respondent in respondents {
+        csv.push_str(&quote(&respondent.id));
+        for question in questions {
+            csv.push(',');
+            if let Some(answer) = respondent
+                .answers
+                .iter()
+                .find(|a| a.question_id == question.id)
+            {
+                csv.push_str(&answer.value.to_string());
+            }
+        }
+        csv.push('\n');
+    }
+    csv
+}
+
+/// Write the raw domain and facet scores of `(id, profile)` pairs, one row each.
+///
+/// Columns follow [`Domain::all`], each domain followed by its facets. Scores missing
+/// from a profile are left empty.
+pub fn profile_to_csv<'a>(
+    profiles: impl IntoIterator<Item = (&'a str, &'a PersonalityProfile)>,
+) -> String {
+    let mut csv = String::from("id");
+    for domain in Domain::all() {
+        csv.push(',');
+        csv.push_str(domain.code());
+        for facet in domain.facets() {
+            csv.push(',');
+            csv.push_str(&ScaleId::Facet(*facet).code());
+        }
+    }
+    csv.push('\n');
+
+    for (id, profile) in profiles {
+        csv.push_str(&quote(id));
+        for domain in Domain::all() {
+            csv.push(',');
+            if let Some(score) = profile.domain_score(*domain) {
+                csv.push_str(&score.raw.to_string());
+            }
+            for facet in domain.facets() {
+                csv.push(',');
+                if let Some(score) = profile.facet_score(*facet) {
+                    csv.push_str(&score.raw.to_string());
+                }
+            }
+        }
+        csv.push('\n');
+    }
+    csv
+}
+
+/// Fields of one line, unquoted. Unquoted fields are trimmed.
+fn split_row(line: &str) -> Result<Vec<String>, String> {
+    let mut fields = Vec::new();
+    let mut chars = line.chars().peekable();
+    loop {
+        let mut field = String::new();
+        while chars.next_if(|c| *c == ' ' || *c == '\t').is_some() {}
+        if chars.next_if_eq(&'"').is_some() {
+            loop {
+                match chars.next() {
+                    Some('"') if chars.next_if_eq(&'"').is_some() => field.push('"'),
+                    Some('"') => break,
+                    Some(c) => field.push(c),
+                    None => return Err("unterminated quoted field".to_string()),
+                }
+            }
+            while chars.next_if(|c| *c != ',').is_some() {}
+        } else {
+            while let Some(c) = chars.next_if(|c| *c != ',') {
+                field.push(c);
+            }
+            field.truncate(field.trim_end().len());
+        }
+        fields.push(field);
+        if chars.next().is_none() {
+            return Ok(fields);
+        }
+    }
+}
+
+/// `field` quoted if it needs to be.
+fn quote(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) || field.trim() != field {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(all(test, feature = "lang-en"))]
+mod tests {
+    use super::*;
+    use crate::{Ipip120, calculate};
+
+    fn respondent(id: &str, offset: usize) -> Respondent {
+        Respondent {
+            id: id.to_string(),
+            answers: Ipip120::english()
+                .questions()
+                .iter()
+                .enumerate()
+                .map(|(i, q)| Answer {
+                    question_id: q.id.clone(),
+                    value: ((i + offset) % 5) as u8 + 1,
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn test_answers_round_trip() {
+        let inventory = Ipip120::english();
+        let mut partial = respondent("has, comma", 2);
+        partial.answers.truncate(50);
+        let respondents = vec![respondent("r1", 0), partial];
+
+        let csv = answers_to_csv(&inventory, &respondents);
+        let read = answers_from_csv(csv.as_bytes()).unwrap();
+        assert_eq!(read.len(), 2);
+        assert_eq!(read[1].id, "has, comma");
+        assert_eq!(read[1].answers.len(), 50);
+        for (read, written) in read.iter().zip(&respondents) {
+            let values: Vec<_> = read
+                .answers
+                .iter()
+                .map(|a| (&a.question_id, a.value))
+                .collect();
+            let expected: Vec<_> = written
+                .answers
+                .iter()
+                .map(|a| (&a.question_id, a.value))
+                .collect();
+            assert_eq!(values, expected);
+        }
+    }
+
+    #[test]
+    fn test_answers_from_csv_without_id_column() {
+        let csv = "\u{feff}q1, \"q2\"\n\n5,\n 1 ,3\n";
+        let read = answers_from_csv(csv.as_bytes()).unwrap();
+        assert_eq!(read.len(), 2);
+        assert_eq!(read[0].id, "1");
+        assert_eq!(read[0].answers.len(), 1);
+        assert_eq!(read[1].id, "2");
+        assert_eq!(read[1].answers[1].question_id, "q2");
+        assert_eq!(read[1].answers[1].value, 3);
+    }
+
+    #[test]
+    fn test_answers_from_csv_rejects_bad_rows() {
+        assert!(answers_from_csv("".as_bytes()).is_err());
+        assert!(answers_from_csv("id,q1\nr1,6\n".as_bytes()).is_err());
+        assert!(answers_from_csv("id,q1\nr1,yes\n".as_bytes()).is_err());
+        assert!(answers_from_csv("id,q1\nr1\n".as_bytes()).is_err());
+        assert!(answers_from_csv("id,q1\n\"r1,3\n".as_bytes()).is_err());
+    }
+
+    #[test]
+    fn test_profile_to_csv() {
+        let inventory = Ipip120::english();
+        let profile = calculate(&inventory, &respondent("r1", 0).answers).unwrap();
+        let csv = profile_to_csv([("r1", &profile)]);
+
+        let mut lines = csv.lines();
+        let header: Vec<_> = lines.next().unwrap().split(',').collect();
+        assert_eq!(header.len(), 1 + 5 + 30);
+        assert_eq!(&header[..3], ["id", "N", "N1"]);
+        let row: Vec<_> = lines.next().unwrap().split(',').collect();
+        assert_eq!(row[0], "r1");
+        assert_eq!(row[1], profile.domains[0].raw.to_string());
+        assert_eq!(row[2], profile.domains[0].facets[0].raw.to_string());
+        assert!(lines.next().is_none());
+    }
+}
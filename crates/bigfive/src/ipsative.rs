@@ -0,0 +1,111 @@
+//! Ipsatized (within-person standardized) domain scores.
+//!
+//! Normative scores say how someone compares with other people; ipsatized scores say how
+//! each of a person's domains compares with their own other domains. Each domain's
+//! percentage is turned into a z-score against the mean and standard deviation of the
+//! person's five domain percentages, so the most positive z is what stands out most about
+//! them, whatever their overall response level.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::types::{Domain, PersonalityProfile};
+
+/// A domain's score relative to the person's own other domains.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct IpsatizedScore {
+    pub domain: Domain,
+    /// Standard deviations above (positive) or below the person's mean domain score.
+    pub z: f32,
+}
+
+impl PersonalityProfile {
+    /// Domain scores as z-scores against this person's own mean and standard deviation,
+    /// in profile order.
+    ///
+    /// All z-scores are 0 when every domain has the same percentage. Empty for a profile
+    /// without domains.
+    pub fn ipsatized(&self) -> Vec<IpsatizedScore> {
+        if self.domains.is_empty() {
+            return Vec::new();
+        }
+        let scores: Vec<f64> = self
+            .domains
+            .iter()
+            .map(|d| f64::from(d.percentage()))
+            .collect();
+        let n = scores.len() as f64;
+        let mean = scores.iter().sum::<f64>() / n;
+        let sd = (scores.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / n).sqrt();
+
+        self.domains
+            .iter()
+            .zip(&scores)
+            .map(|(domain, score)| IpsatizedScore {
+                domain: domain.domain,
+                z: if sd > 0.0 {
+                    ((score - mean) / sd) as f32
+                } else {
+                    0.0
+                },
+            })
+            .collect()
+    }
+}
+
+#[cfg(all(test, feature = "lang-en"))]
+mod tests {
+    use super::*;
+    use crate::inventory::Ipip120;
+    use crate::scoring::calculate;
+    use crate::types::Answer;
+
+    /// Profile answering every item of each domain with the given score (N, E, O, A, C).
+    fn profile(scores: [u8; 5]) -> PersonalityProfile {
+        let inventory = Ipip120::english();
+        let answers: Vec<Answer> = inventory
+            .questions()
+            .iter()
+            .map(|q| {
+                let index = Domain::all().iter().position(|d| *d == q.domain).unwrap();
+                let score = scores[index];
+                Answer {
+                    question_id: q.id.clone(),
+                    value: if q.reversed { 6 - score } else { score },
+                }
+            })
+            .collect();
+        calculate(&inventory, &answers).unwrap()
+    }
+
+    #[test]
+    fn test_flat_profile() {
+        let scores = profile([4, 4, 4, 4, 4]).ipsatized();
+        assert_eq!(scores.len(), 5);
+        assert!(scores.iter().all(|s| s.z == 0.0));
+    }
+
+    #[test]
+    fn test_z_scores() {
+        // Percentages 0, 25, 50, 75, 100: mean 50, SD sqrt(1250)
+        let scores = profile([1, 2, 3, 4, 5]).ipsatized();
+        let sd = 1250.0f32.sqrt();
+        assert_eq!(scores[0].domain, Domain::Neuroticism);
+        assert!((scores[0].z + 50.0 / sd).abs() < 0.001);
+        assert_eq!(scores[2].z, 0.0);
+        assert!((scores[4].z - 50.0 / sd).abs() < 0.001);
+        let sum: f32 = scores.iter().map(|s| s.z).sum();
+        assert!(sum.abs() < 0.001);
+    }
+
+    #[test]
+    fn test_independent_of_overall_level() {
+        // Same shape, shifted up: same relative salience
+        let low = profile([1, 2, 1, 2, 1]).ipsatized();
+        let high = profile([4, 5, 4, 5, 4]).ipsatized();
+        for (a, b) in low.iter().zip(&high) {
+            assert!((a.z - b.z).abs() < 0.001);
+        }
+    }
+}
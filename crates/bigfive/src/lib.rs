@@ -1,11 +1,14 @@
 //! Big Five personality test (IPIP-NEO-120) library.
 //!
 //! This crate provides types and scoring logic for the Big Five personality test,
-//! based on the IPIP-NEO-120 inventory.
+//! based on the IPIP-NEO-120 inventory, and an unvalidated 60-question subset of it
+//! ([`Ipip120Subset60`]).
+//! Other instruments can be scored by implementing [`Inventory`].
 //!
 //! # Example
 //!
 //! ```
+//! # #[cfg(feature = "lang-en")] {
 //! use bigfive::{Ipip120, Answer, calculate};
 //!
 //! // Load the English inventory
@@ -32,21 +35,151 @@
 //!         domain_score.level
 //!     );
 //! }
+//! # }
 //! ```
 //!
+//! To score many respondents to one inventory, such as an archival dataset,
+//! [`calculate_batch`] builds the question lookup once and returns a result per respondent.
+//! An unfinished test fits in a short string with [`TestProgress::to_compact_string`], for
+//! continuing it on another device, and a finished one with [`AnswerSheet::encode`], for
+//! links that carry the answers themselves.
+//!
+//! # Score levels
+//!
+//! Scores are banded into [`ScoreLevel`]s by splitting the score range into thirds. To band
+//! them differently (say low below 30% and high from 70% of the range), pass
+//! [`LevelCutoffs`] in [`ScoringOptions`] to [`calculate_with`]. For finer wording,
+//! [`ScoreBand`] splits the range into fifths, from very low to very high.
+//!
+//! # Aspects
+//!
+//! [`PersonalityProfile::aspects`] derives the ten aspects of DeYoung's Big Five Aspects
+//! model (two per domain, e.g. Withdrawal and Volatility) by grouping the facet scores.
+//! One level up, [`PersonalityProfile::meta_traits`] sums the domains into Stability
+//! (Agreeableness, Conscientiousness, low Neuroticism) and Plasticity (Extraversion, Openness).
+//! [`PersonalityProfile::ipsatized`] standardizes the domains against the person's own mean
+//! and standard deviation, showing what is relatively most salient about them.
+//!
+//! # Archetypes
+//!
+//! With the `archetypes` feature, [`PersonalityProfile::archetype`] splits each domain at the
+//! midpoint into one of 32 [`Archetype`]s with a five-letter code like "RCOAI", named and
+//! summarized in [`ArchetypeLabels`].
+//!
+//! # Compatibility
+//!
+//! [`PersonalityProfile::compatibility`] compares two people's profiles, e.g. a couple's, and
+//! reports friction and synergy [`CompatibilityIndicator`]s per domain, such as both partners
+//! being low in Agreeableness or far apart in Conscientiousness.
+//!
+//! # Teams
+//!
+//! [`TeamProfile::from_profiles`] aggregates a group's profiles into the mean, spread and
+//! level diversity of every domain and facet, and points out members far from the rest of
+//! the group on a domain.
+//!
+//! # Descriptions
+//!
+//! [`Descriptions`] has a short text on what each domain and facet measures, and what high
+//! and low domain scores look like, bundled with the `lang-*` features.
+//!
+//! # Validity
+//!
+//! Every profile computed by [`calculate`] carries a [`ValidityReport`] with careless-responding
+//! indices (longstring, response variability, infrequency) and flags for straight-lined or
+//! random protocols. With response times ([`TimedAnswer`], [`calculate_timed`]) it also
+//! covers speeding and single items answered unusually fast or slow.
+//!
+//! # Norms
+//!
+//! [`calculate_normed`] turns answers into T-scores and percentile ranks against a
+//! [`NormTable`] of IPIP-NEO-120 means and standard deviations by sex and age range.
+//! No norms are bundled: load published or local ones from JSON ([`NormTable::from_reader`])
+//! or CSV ([`NormTable::from_csv`]), or compute them from a sample of profiles with
+//! [`NormStratum::from_profiles`].
+//!
+//! # Item analysis
+//!
+//! [`ItemAnalysis::from_answers`] takes the answer sets of a sample and reports each item's
+//! mean, standard deviation and corrected item-total correlations, and Cronbach's alpha of
+//! every facet and domain, e.g. to check a translation of the inventory.
+//! [`CorrelationMatrix::from_profiles`] correlates all domain and facet scores of a sample,
+//! with CSV output for further analysis; [`stats::correlation_matrix`] is the same as a
+//! free function.
+//!
 //! # Features
 //!
-//! - `serde` (default): Enables serialization/deserialization of types
+//! - `serde` (default): Enables serialization/deserialization of types, and loading norm
+//!   tables from JSON with [`NormTable::from_reader`]
+//! - `lang-en`, `lang-ru` (default): Bundle the question texts of that language. Builds
+//!   without them (e.g. for WASM) stay small and load inventories at runtime with
+//!   [`Ipip120::from_reader`]
+//! - `archetypes` (default): [`Archetype`] labels for profiles, with names and summaries
+//!   bundled for the enabled languages
+//! - `csv` (default): the [`io`] module, reading and writing answers and profiles as CSV
+//!   with one row per respondent
 
+mod answer_sheet;
+#[cfg(feature = "archetypes")]
+mod archetypes;
+mod aspects;
+mod common;
+mod compact;
+mod compatibility;
+mod demographics;
+mod descriptions;
+mod diff;
 mod inventory;
+#[cfg(feature = "csv")]
+pub mod io;
+mod ipsative;
+mod means;
+mod meta_traits;
+mod norms;
+mod percentiles;
+mod progress;
+mod psychometrics;
 mod scoring;
+mod short_form;
+mod speed;
+pub mod stats;
+mod team;
 mod types;
+mod validation;
+mod validity;
 
-pub use inventory::Ipip120;
-pub use scoring::calculate;
+pub use answer_sheet::AnswerSheet;
+#[cfg(feature = "archetypes")]
+pub use archetypes::{Archetype, ArchetypeLabels};
+pub use aspects::{Aspect, AspectScore};
+pub use common::{CommonProfile, ScaleNorm, ScoreScale};
+pub use compatibility::{
+    CompatibilityIndicator, CompatibilityReport, DomainCompatibility, IndicatorKind,
+    LARGE_GAP_POINTS, PairPattern, SIMILAR_POINTS,
+};
+pub use demographics::{AgeBand, Demographics, Gender};
+pub use descriptions::{Descriptions, DomainDescription};
+pub use diff::{DomainChange, FacetChange, ProfileDiff};
+pub use inventory::{Inventory, Ipip120, MAX_INVENTORY_BYTES};
+pub use ipsative::IpsatizedScore;
+pub use means::ProfileMeans;
+pub use meta_traits::{MetaTrait, MetaTraitScore};
+pub use norms::{NormStats, NormStratum, NormTable, NormedProfile, NormedScore, calculate_normed};
+pub use percentiles::ScoreDistribution;
+pub use progress::TestProgress;
+pub use psychometrics::{CorrelationMatrix, ItemAnalysis, ItemStats, ScaleId, ScaleReliability};
+pub use scoring::{
+    ScoreRange, ScoringOptions, calculate, calculate_batch, calculate_batch_with, calculate_with,
+};
+pub use short_form::Ipip120Subset60;
+pub use speed::{OutlierKind, SpeedReport, TimedAnswer, TimingOutlier, calculate_timed};
+pub use team::{MIN_OUTLIER_TEAM, OUTLIER_POINTS, TeamOutlier, TeamProfile, TeamScale};
 pub use types::{
-    Answer, Domain, DomainScore, Facet, FacetScore, PersonalityProfile, Question, ScoreLevel,
+    Answer, Domain, DomainScore, Facet, FacetScore, LevelCutoffs, PersonalityProfile, Question,
+    ScoreBand, ScoreLevel,
 };
+pub use validation::{AnswerIssue, validate_answers};
+pub use validity::{ValidityFlag, ValidityReport};
 
 use thiserror::Error;
 
@@ -69,8 +202,40 @@ pub enum Error {
     #[error("expected 120 questions, got {0}")]
     InvalidQuestionCount(usize),
 
+    /// Facet number outside 1-6 in question data.
+    #[error("invalid facet {1} for question {0} (must be 1-6)")]
+    InvalidFacet(String, u8),
+
+    /// Keying other than "plus" or "minus" in question data.
+    #[error("invalid keying {1:?} for question {0} (must be \"plus\" or \"minus\")")]
+    InvalidKeyed(String, String),
+
+    /// The same question id appears more than once in question data.
+    #[error("duplicate question id: {0}")]
+    DuplicateQuestionId(String),
+
+    /// A question text override names a question that isn't in the inventory.
+    #[error("unknown question id in text overrides: {0}")]
+    UnknownQuestionId(String),
+
+    /// A question text override is empty.
+    #[error("empty text override for question: {0}")]
+    EmptyQuestionText(String),
+
+    /// Answer scale in question data doesn't have five labels.
+    #[error("expected 5 answer scale labels, got {0}")]
+    InvalidScaleLabelCount(usize),
+
+    /// Question data exceeds the size limit.
+    #[error("question data is larger than {0} bytes")]
+    InputTooLarge(usize),
+
+    /// An inventory's questions per facet don't fit the raw score range.
+    #[error("unsupported number of questions per facet: {0} (must be 1-8)")]
+    UnsupportedQuestionsPerFacet(usize),
+
     /// Wrong number of answers provided.
-    #[error("expected 120 answers, got {0}")]
+    #[error("expected one answer per question, got {0}")]
     InvalidAnswerCount(usize),
 
     /// Answer value out of valid range (1-5).
@@ -86,6 +251,46 @@ pub enum Error {
     MissingFacetData(Domain, u8),
 
     /// Wrong number of questions for a facet.
-    #[error("expected 4 questions for domain {0:?} facet {1}, got {2}")]
+    #[error("wrong number of questions for domain {0:?} facet {1}: {2}")]
     InvalidFacetQuestionCount(Domain, u8, usize),
+
+    /// Norm data is malformed or incomplete.
+    #[error("invalid norm data: {0}")]
+    InvalidNorms(String),
+
+    /// No stratum of a norm table covers the respondent.
+    #[error("no norm stratum covers the respondent")]
+    NoNormStratum,
+
+    /// Norms were applied to an inventory they weren't computed for.
+    #[error("norms are for 4 questions per facet, the inventory has {0}")]
+    NormsInventoryMismatch(usize),
+
+    /// A profile's scores are inconsistent or out of range.
+    #[error("invalid profile: {0}")]
+    InvalidProfile(String),
+
+    /// Encoded profile data is malformed.
+    #[error("invalid profile data: {0}")]
+    InvalidProfileData(String),
+
+    /// A CSV file can't be read.
+    #[error("invalid CSV: {0}")]
+    InvalidCsv(String),
+
+    /// An encoded answer sheet is malformed.
+    #[error("invalid answer sheet: {0}")]
+    InvalidAnswerSheet(String),
+
+    /// Encoded test progress is malformed or for another inventory.
+    #[error("invalid test progress data: {0}")]
+    InvalidProgressData(String),
+
+    /// Score level cutoffs that aren't increasing percentages of the score range.
+    #[error("invalid level cutoffs: neutral from {0}%, high from {1}%")]
+    InvalidLevelCutoffs(f64, f64),
+
+    /// Description data doesn't describe exactly the five domains and 30 facets.
+    #[error("missing or unknown description: {0}")]
+    MissingDescription(String),
 }
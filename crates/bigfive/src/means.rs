@@ -0,0 +1,132 @@
+//! Average scores over many profiles, used as a "compared to others" baseline.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::types::{Domain, Facet, PersonalityProfile};
+
+/// Mean domain and facet scores over a set of profiles.
+///
+/// Means are percentages of each score's range (0-100), like
+/// [`DomainScore::percentage`](crate::DomainScore::percentage).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ProfileMeans {
+    /// Number of profiles averaged.
+    pub sample_size: usize,
+    /// Mean percentage per domain.
+    pub domains: Vec<(Domain, f32)>,
+    /// Mean percentage per facet.
+    pub facets: Vec<(Facet, f32)>,
+}
+
+impl ProfileMeans {
+    /// Average the given profiles. Returns `None` if there are none.
+    pub fn from_profiles<'a>(
+        profiles: impl IntoIterator<Item = &'a PersonalityProfile>,
+    ) -> Option<Self> {
+        let mut sample_size = 0;
+        let mut domain_sums = [0.0f64; 5];
+        let mut facet_sums = [[0.0f64; 6]; 5];
+
+        for profile in profiles {
+            sample_size += 1;
+            for (d, domain) in Domain::all().iter().enumerate() {
+                let Some(score) = profile.domain_score(*domain) else {
+                    continue;
+                };
+                domain_sums[d] += f64::from(score.percentage());
+                for (f, facet) in domain.facets().iter().enumerate() {
+                    if let Some(facet_score) = score.facets.iter().find(|s| s.facet == *facet) {
+                        facet_sums[d][f] += f64::from(facet_score.percentage());
+                    }
+                }
+            }
+        }
+
+        if sample_size == 0 {
+            return None;
+        }
+        let mean = |sum: f64| (sum / sample_size as f64) as f32;
+
+        Some(ProfileMeans {
+            sample_size,
+            domains: Domain::all()
+                .iter()
+                .zip(domain_sums)
+                .map(|(domain, sum)| (*domain, mean(sum)))
+                .collect(),
+            facets: Domain::all()
+                .iter()
+                .zip(facet_sums)
+                .flat_map(|(domain, sums)| {
+                    domain
+                        .facets()
+                        .iter()
+                        .zip(sums)
+                        .map(|(facet, sum)| (*facet, mean(sum)))
+                })
+                .collect(),
+        })
+    }
+
+    /// Mean percentage for a domain.
+    pub fn domain(&self, domain: Domain) -> Option<f32> {
+        self.domains
+            .iter()
+            .find(|(d, _)| *d == domain)
+            .map(|(_, mean)| *mean)
+    }
+
+    /// Mean percentage for a facet.
+    pub fn facet(&self, facet: Facet) -> Option<f32> {
+        self.facets
+            .iter()
+            .find(|(f, _)| *f == facet)
+            .map(|(_, mean)| *mean)
+    }
+}
+
+#[cfg(all(test, feature = "lang-en"))]
+mod tests {
+    use super::*;
+    use crate::{Answer, Ipip120, calculate};
+
+    fn uniform_profile(value: u8) -> PersonalityProfile {
+        let inventory = Ipip120::english();
+        let answers: Vec<Answer> = inventory
+            .questions()
+            .iter()
+            .map(|q| Answer {
+                question_id: q.id.clone(),
+                value,
+            })
+            .collect();
+        calculate(&inventory, &answers).unwrap()
+    }
+
+    #[test]
+    fn test_empty() {
+        assert_eq!(ProfileMeans::from_profiles(&[]), None);
+    }
+
+    #[test]
+    fn test_means() {
+        // All-3 answers score exactly the middle of every range
+        let neutral = uniform_profile(3);
+        let means = ProfileMeans::from_profiles([&neutral, &neutral]).unwrap();
+        assert_eq!(means.sample_size, 2);
+        assert_eq!(means.domains.len(), 5);
+        assert_eq!(means.facets.len(), 30);
+        assert!((means.domain(Domain::Openness).unwrap() - 50.0).abs() < 0.01);
+        assert!((means.facet(Facet::Anxiety).unwrap() - 50.0).abs() < 0.01);
+
+        let low = uniform_profile(1);
+        let high = uniform_profile(5);
+        let means = ProfileMeans::from_profiles([&low, &high]).unwrap();
+        let all_means = means.domains.iter().map(|(_, m)| m);
+        for mean in all_means.chain(means.facets.iter().map(|(_, m)| m)) {
+            assert!((mean - 50.0).abs() < 0.01);
+        }
+    }
+}
@@ -0,0 +1,185 @@
+//! Higher-order meta-traits above the five domains.
+//!
+//! Digman (1997) found that the domains themselves correlate in two clusters: Alpha, or
+//! Stability (Agreeableness, Conscientiousness and low Neuroticism), and Beta, or Plasticity
+//! (Extraversion and Openness). DeYoung (2006) named them Stability and Plasticity. Here each
+//! is scored as the sum of its domain scores, with Neuroticism reversed, so domains with the
+//! same number of questions weigh the same.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::scoring::ScoreRange;
+use crate::types::{Domain, PersonalityProfile, ScoreLevel};
+
+/// The two meta-traits of the Big Five.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum MetaTrait {
+    /// Alpha: Agreeableness, Conscientiousness and (low) Neuroticism.
+    Stability,
+    /// Beta: Extraversion and Openness.
+    Plasticity,
+}
+
+impl MetaTrait {
+    /// Both meta-traits.
+    pub fn all() -> &'static [MetaTrait] {
+        &[MetaTrait::Stability, MetaTrait::Plasticity]
+    }
+
+    /// Returns the name of the meta-trait.
+    pub fn name(&self) -> &'static str {
+        match self {
+            MetaTrait::Stability => "Stability",
+            MetaTrait::Plasticity => "Plasticity",
+        }
+    }
+
+    /// Domains summed into this meta-trait, with whether each is reversed.
+    pub fn domains(&self) -> &'static [(Domain, bool)] {
+        match self {
+            MetaTrait::Stability => &[
+                (Domain::Agreeableness, false),
+                (Domain::Conscientiousness, false),
+                (Domain::Neuroticism, true),
+            ],
+            MetaTrait::Plasticity => &[(Domain::Extraversion, false), (Domain::Openness, false)],
+        }
+    }
+}
+
+/// Score for a meta-trait, the sum of its (reversed where needed) domain scores.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct MetaTraitScore {
+    /// The meta-trait being scored.
+    pub meta_trait: MetaTrait,
+    /// Raw score, e.g. 72-360 for Stability on the IPIP-NEO-120. Wider than domain scores,
+    /// so not a [`ScoreRange`].
+    pub raw: u16,
+    /// Categorized level, splitting the score range into thirds like domain levels.
+    pub level: ScoreLevel,
+    /// Number of questions summed into the raw score.
+    pub questions: u16,
+}
+
+impl MetaTraitScore {
+    /// Calculate percentage (0-100) of the raw score range.
+    pub fn percentage(&self) -> f32 {
+        let span = 4 * self.questions;
+        if span == 0 {
+            return 0.0;
+        }
+        f32::from(self.raw - self.questions) / f32::from(span) * 100.0
+    }
+
+    /// Level of `raw` out of `questions` answers, with band edges rounded up.
+    fn level_for(raw: u16, questions: u16) -> ScoreLevel {
+        let (offset, span) = (u32::from(raw - questions), 4 * u32::from(questions));
+        if 3 * offset < span {
+            ScoreLevel::Low
+        } else if 3 * offset < 2 * span {
+            ScoreLevel::Neutral
+        } else {
+            ScoreLevel::High
+        }
+    }
+}
+
+impl PersonalityProfile {
+    /// Stability and Plasticity scores derived from the domain scores, in
+    /// [`MetaTrait::all`] order.
+    ///
+    /// Meta-traits with a domain missing from the profile are left out.
+    pub fn meta_traits(&self) -> Vec<MetaTraitScore> {
+        MetaTrait::all()
+            .iter()
+            .filter_map(|meta_trait| {
+                let (raw, questions) = meta_trait.domains().iter().try_fold(
+                    (0u16, 0u16),
+                    |(raw, questions), (domain, reversed)| {
+                        let score = self.domain_score(*domain)?;
+                        let domain_raw = if *reversed {
+                            u16::from(score.min_raw()) + u16::from(score.max_raw())
+                                - u16::from(score.raw)
+                        } else {
+                            u16::from(score.raw)
+                        };
+                        Some((raw + domain_raw, questions + u16::from(score.questions)))
+                    },
+                )?;
+                Some(MetaTraitScore {
+                    meta_trait: *meta_trait,
+                    raw,
+                    level: MetaTraitScore::level_for(raw, questions),
+                    questions,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(all(test, feature = "lang-en"))]
+mod tests {
+    use super::*;
+    use crate::inventory::Ipip120;
+    use crate::scoring::calculate;
+    use crate::types::Answer;
+
+    /// Profile with every item of `high` domains scored 5 and the rest 1.
+    fn profile(high: &[Domain]) -> PersonalityProfile {
+        let inventory = Ipip120::english();
+        let answers: Vec<Answer> = inventory
+            .questions()
+            .iter()
+            .map(|q| {
+                let score = if high.contains(&q.domain) { 5 } else { 1 };
+                Answer {
+                    question_id: q.id.clone(),
+                    value: if q.reversed { 6 - score } else { score },
+                }
+            })
+            .collect();
+        calculate(&inventory, &answers).unwrap()
+    }
+
+    #[test]
+    fn test_stability_reverses_neuroticism() {
+        let scores = profile(&[Domain::Agreeableness, Domain::Conscientiousness]).meta_traits();
+        assert_eq!(scores.len(), 2);
+        let stability = &scores[0];
+        assert_eq!(stability.meta_trait, MetaTrait::Stability);
+        assert_eq!((stability.raw, stability.questions), (360, 72));
+        assert_eq!(stability.percentage(), 100.0);
+        assert_eq!(stability.level, ScoreLevel::High);
+        let plasticity = &scores[1];
+        assert_eq!((plasticity.raw, plasticity.questions), (48, 48));
+        assert_eq!(plasticity.percentage(), 0.0);
+        assert_eq!(plasticity.level, ScoreLevel::Low);
+    }
+
+    #[test]
+    fn test_mixed_domains_are_neutral() {
+        // High Neuroticism offsets high Agreeableness
+        let scores = profile(&[
+            Domain::Neuroticism,
+            Domain::Agreeableness,
+            Domain::Extraversion,
+        ])
+        .meta_traits();
+        assert_eq!(scores[0].raw, 120 + 24 + 24);
+        assert_eq!(scores[0].level, ScoreLevel::Neutral);
+        assert_eq!(scores[1].percentage(), 50.0);
+        assert_eq!(scores[1].level, ScoreLevel::Neutral);
+    }
+
+    #[test]
+    fn test_missing_domain_drops_meta_trait() {
+        let mut profile = profile(&[]);
+        profile.domains.retain(|d| d.domain != Domain::Openness);
+        let scores = profile.meta_traits();
+        assert_eq!(scores.len(), 1);
+        assert_eq!(scores[0].meta_trait, MetaTrait::Stability);
+    }
+}
@@ -0,0 +1,930 @@
+//! Population norms: T-scores and percentile ranks against a reference group.
+//!
+//! Levels from fixed thirds of the score range ([`ScoreLevel::range`]) say nothing about how
+//! common a score is. A [`NormTable`] holds means and standard deviations of raw
+//! IPIP-NEO-120 scores per stratum of sex and age range, the layout of Johnson's published
+//! norms, and [`calculate_normed`] scores answers against the stratum that fits the
+//! respondent best.
+//!
+//! Strata cover any range of ages, so tables grouped differently from [`AgeBand`] (Johnson's
+//! use under 21, 21-40, 41-60 and over 60) load as published; a respondent's band is matched
+//! to the range it overlaps most. Nothing is bundled: tables come from JSON or CSV files
+//! (published norms, or a lab's own), or are computed from a sample with
+//! [`NormStratum::from_profiles`].
+
+use std::collections::{BTreeMap, HashSet};
+use std::io::Read;
+use std::ops::RangeInclusive;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::Error;
+use crate::common::ScaleNorm;
+use crate::demographics::{AgeBand, Demographics, Gender};
+use crate::inventory::{Inventory, Ipip120};
+use crate::scoring::calculate;
+use crate::types::{Answer, Domain, Facet, PersonalityProfile, ScoreLevel};
+
+/// Largest norm file accepted by [`NormTable::from_reader`] and [`NormTable::from_csv`].
+const MAX_NORMS_BYTES: usize = 1024 * 1024;
+
+/// Percentile ranks below this are low and above `100 -` this high, as in Johnson's
+/// IPIP-NEO reports.
+const LEVEL_PERCENTILE: f32 = 30.0;
+
+/// Mean and standard deviation of a raw score in a reference group.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NormStats {
+    pub mean: f32,
+    pub sd: f32,
+}
+
+impl NormStats {
+    /// T-score (mean 50, standard deviation 10) of a raw score.
+    pub fn t_score(&self, raw: u8) -> f32 {
+        50.0 + 10.0 * (f32::from(raw) - self.mean) / self.sd
+    }
+
+    /// Percentile rank (0-100) of a raw score, assuming normally distributed scores.
+    pub fn percentile(&self, raw: u8) -> f32 {
+        let z = (f64::from(raw) - f64::from(self.mean)) / f64::from(self.sd);
+        (normal_cdf(z) * 100.0) as f32
+    }
+}
+
+/// Norms of one group of respondents.
+///
+/// A stratum without a sex or age range covers everyone, e.g. a table's overall norms.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "serde", serde(try_from = "RawStratum", into = "RawStratum"))]
+pub struct NormStratum {
+    pub gender: Option<Gender>,
+    /// Ages covered, in years; an open-ended range goes up to `u8::MAX`.
+    pub age: Option<RangeInclusive<u8>>,
+    /// Number of respondents the norms were computed from, if known.
+    pub sample_size: Option<u32>,
+    /// Indexed like [`Domain::all`].
+    domains: [NormStats; 5],
+    /// Indexed like [`Domain::all`], then by facet index.
+    facets: [[NormStats; 6]; 5],
+}
+
+impl NormStratum {
+    /// Norms computed from IPIP-NEO-120 profiles, e.g. a lab's own sample.
+    ///
+    /// Profiles scored on other inventories are skipped. Returns `None` with fewer than two
+    /// profiles left, or when some score is the same in all of them.
+    pub fn from_profiles<'a>(
+        gender: Option<Gender>,
+        age: Option<RangeInclusive<u8>>,
+        profiles: impl IntoIterator<Item = &'a PersonalityProfile>,
+    ) -> Option<Self> {
+        let profiles: Vec<&PersonalityProfile> = profiles
+            .into_iter()
+            .filter(|p| p.questions_per_facet() == Some(Ipip120::QUESTIONS_PER_FACET))
+            .collect();
+        let stats = |raw: &dyn Fn(&PersonalityProfile) -> Option<u8>| {
+            let scores: Vec<f32> = profiles
+                .iter()
+                .map(|p| raw(p).map(f32::from))
+                .collect::<Option<_>>()?;
+            ScaleNorm::from_scores(scores).map(|n| NormStats {
+                mean: n.mean,
+                sd: n.sd,
+            })
+        };
+
+        let mut stratum = NormStratum {
+            gender,
+            age,
+            sample_size: u32::try_from(profiles.len()).ok(),
+            domains: [NormStats { mean: 0.0, sd: 1.0 }; 5],
+            facets: [[NormStats { mean: 0.0, sd: 1.0 }; 6]; 5],
+        };
+        for (d, domain) in Domain::all().iter().enumerate() {
+            stratum.domains[d] = stats(&|p| p.domain_score(*domain).map(|s| s.raw))?;
+            for facet in domain.facets() {
+                stratum.facets[d][usize::from(facet.index()) - 1] =
+                    stats(&|p| p.facet_score(*facet).map(|s| s.raw))?;
+            }
+        }
+        Some(stratum)
+    }
+
+    /// Norms of a raw domain score (24-120).
+    pub fn domain(&self, domain: Domain) -> NormStats {
+        self.domains[domain_index(domain)]
+    }
+
+    /// Norms of a raw facet score (4-20).
+    pub fn facet(&self, facet: Facet) -> NormStats {
+        self.facets[domain_index(facet.domain())][usize::from(facet.index()) - 1]
+    }
+
+    /// How well the stratum covers a respondent: `None` if not at all, otherwise the years
+    /// its age range shares with the respondent's band (0 without an age range).
+    /// [`Gender::Other`] and missing details are only covered by strata that don't split by
+    /// them.
+    fn coverage(&self, demographics: &Demographics) -> Option<u16> {
+        let gender = demographics.gender.filter(|g| *g != Gender::Other);
+        if self.gender.is_some_and(|g| Some(g) != gender) {
+            return None;
+        }
+        match (&self.age, demographics.age_band) {
+            (None, _) => Some(0),
+            (Some(age), Some(band)) => Some(overlap(age, &band.years())).filter(|n| *n > 0),
+            (Some(_), None) => None,
+        }
+    }
+}
+
+/// Number of ages two ranges have in common.
+fn overlap(a: &RangeInclusive<u8>, b: &RangeInclusive<u8>) -> u16 {
+    let start = *a.start().max(b.start());
+    let end = *a.end().min(b.end());
+    if start > end {
+        0
+    } else {
+        u16::from(end - start) + 1
+    }
+}
+
+/// Code of an age range in norm files: `"21-40"`, or `"61+"` when open-ended.
+fn age_code(age: &RangeInclusive<u8>) -> String {
+    if *age.end() == u8::MAX {
+        format!("{}+", age.start())
+    } else {
+        format!("{}-{}", age.start(), age.end())
+    }
+}
+
+/// Parse an age range written as by [`age_code`], or an [`AgeBand`] code.
+fn parse_age(code: &str) -> Option<RangeInclusive<u8>> {
+    if let Some(band) = AgeBand::from_code(code) {
+        return Some(band.years());
+    }
+    if let Some(start) = code.strip_suffix('+') {
+        return Some(start.parse().ok()?..=u8::MAX);
+    }
+    let (start, end) = code.split_once('-')?;
+    let (start, end): (u8, u8) = (start.parse().ok()?, end.parse().ok()?);
+    (start <= end).then_some(start..=end)
+}
+
+/// Stratum as stored in JSON or collected from CSV rows, with the age range as a code and
+/// scores keyed by code (`"N"`, `"N1"`, ...).
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+struct RawStratum {
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    gender: Option<Gender>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, alias = "age_band", skip_serializing_if = "Option::is_none")
+    )]
+    age: Option<String>,
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    sample_size: Option<u32>,
+    domains: BTreeMap<String, NormStats>,
+    facets: BTreeMap<String, NormStats>,
+}
+
+impl TryFrom<RawStratum> for NormStratum {
+    type Error = Error;
+
+    fn try_from(raw: RawStratum) -> Result<Self, Error> {
+        let mut domains = raw.domains;
+        let mut facets = raw.facets;
+        let take = |scores: &mut BTreeMap<String, NormStats>, code: String| {
+            let stats = scores
+                .remove(&code)
+                .ok_or_else(|| Error::InvalidNorms(format!("missing norms for {code}")))?;
+            if !(stats.mean.is_finite() && stats.sd.is_finite() && stats.sd > 0.0) {
+                return Err(Error::InvalidNorms(format!(
+                    "norms for {code} need a finite mean and a positive standard deviation"
+                )));
+            }
+            Ok(stats)
+        };
+
+        let age = match raw.age {
+            Some(code) => Some(
+                parse_age(&code)
+                    .ok_or_else(|| Error::InvalidNorms(format!("invalid age range: {code}")))?,
+            ),
+            None => None,
+        };
+        let mut stratum = NormStratum {
+            gender: raw.gender,
+            age,
+            sample_size: raw.sample_size,
+            domains: [NormStats { mean: 0.0, sd: 1.0 }; 5],
+            facets: [[NormStats { mean: 0.0, sd: 1.0 }; 6]; 5],
+        };
+        for (d, domain) in Domain::all().iter().enumerate() {
+            stratum.domains[d] = take(&mut domains, domain.code().to_string())?;
+            for facet in domain.facets() {
+                stratum.facets[d][usize::from(facet.index()) - 1] =
+                    take(&mut facets, facet_code(*facet))?;
+            }
+        }
+        if let Some(code) = domains.keys().chain(facets.keys()).next() {
+            return Err(Error::InvalidNorms(format!("unknown score code: {code}")));
+        }
+        Ok(stratum)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<NormStratum> for RawStratum {
+    fn from(stratum: NormStratum) -> Self {
+        RawStratum {
+            gender: stratum.gender,
+            age: stratum.age.as_ref().map(age_code),
+            sample_size: stratum.sample_size,
+            domains: Domain::all()
+                .iter()
+                .map(|d| (d.code().to_string(), stratum.domain(*d)))
+                .collect(),
+            facets: Domain::all()
+                .iter()
+                .flat_map(|d| d.facets())
+                .map(|f| (facet_code(*f), stratum.facet(*f)))
+                .collect(),
+        }
+    }
+}
+
+/// Raw score norms of the IPIP-NEO-120 by demographic stratum.
+///
+/// In JSON, each stratum has an optional `gender` code (as in [`Demographics`]), an optional
+/// `age` range (`"21-40"`, `"61+"`, or an [`AgeBand`] code; `age_band` is accepted as its
+/// name too) and a `mean`/`sd` pair for every domain and facet code:
+///
+/// ```json
+/// {
+///   "source": "Local sample, 2024",
+///   "strata": [
+///     {
+///       "gender": "female",
+///       "age": "21-40",
+///       "sample_size": 812,
+///       "domains": { "N": { "mean": 72.4, "sd": 15.1 }, "E": { "mean": 80.2, "sd": 14.0 } },
+///       "facets": { "N1": { "mean": 12.9, "sd": 3.4 }, "N2": { "mean": 11.1, "sd": 3.6 } }
+///     }
+///   ]
+/// }
+/// ```
+///
+/// (shortened: all 5 domains and 30 facets are required). Reading JSON needs the `serde`
+/// feature; [`NormTable::from_csv`] works without it.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NormTable {
+    /// Where the norms come from, e.g. a citation.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub source: String,
+    pub strata: Vec<NormStratum>,
+}
+
+impl NormTable {
+    /// Load a norm table from JSON.
+    ///
+    /// Fails on input over 1 MiB, a table without strata, two strata for the same sex with
+    /// overlapping age ranges, or a stratum missing a domain or facet.
+    #[cfg(feature = "serde")]
+    pub fn from_reader(reader: impl Read) -> Result<Self, Error> {
+        let json_data = read_limited(reader)?;
+        let table: NormTable =
+            serde_json::from_slice(&json_data).map_err(|e| Error::InvalidNorms(e.to_string()))?;
+        table.validate()?;
+        Ok(table)
+    }
+
+    /// Load a norm table from CSV with one row per stratum and score:
+    ///
+    /// ```csv
+    /// gender,age,scale,mean,sd,sample_size
+    /// female,21-40,N,72.4,15.1,812
+    /// female,21-40,N1,12.9,3.4,812
+    /// ,,N,70.8,15.6,
+    /// ```
+    ///
+    /// Columns may come in any order, `age` may be called `age_band`, and `sample_size` is
+    /// optional. An empty `gender` or `age` makes the stratum cover everyone, as in JSON.
+    /// Fails on input over 1 MiB, a malformed row, a table without strata, two strata for
+    /// the same sex with overlapping age ranges, or a stratum missing a domain or facet.
+    pub fn from_csv(source: impl Into<String>, reader: impl Read) -> Result<Self, Error> {
+        let data = read_limited(reader)?;
+        let text = std::str::from_utf8(&data).map_err(|e| Error::InvalidNorms(e.to_string()))?;
+        let mut lines = text
+            .lines()
+            .enumerate()
+            .filter(|(_, line)| !line.trim().is_empty());
+
+        let (_, header) = lines
+            .next()
+            .ok_or_else(|| Error::InvalidNorms("empty CSV".to_string()))?;
+        let header: Vec<&str> = header.split(',').map(str::trim).collect();
+        let column = |name: &str| header.iter().position(|h| *h == name);
+        let required = |name: &str| {
+            column(name).ok_or_else(|| Error::InvalidNorms(format!("missing column: {name}")))
+        };
+        let gender_col = required("gender")?;
+        let age_col = column("age").map_or_else(|| required("age_band"), Ok)?;
+        let (scale_col, mean_col, sd_col) =
+            (required("scale")?, required("mean")?, required("sd")?);
+        let sample_col = column("sample_size");
+
+        // Strata in order of first appearance
+        let mut strata: Vec<RawStratum> = Vec::new();
+        for (index, line) in lines {
+            let row_error =
+                |message: String| Error::InvalidNorms(format!("line {}: {message}", index + 1));
+            let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+            if fields.len() != header.len() {
+                return Err(row_error(format!(
+                    "expected {} fields, got {}",
+                    header.len(),
+                    fields.len()
+                )));
+            }
+            let gender = match fields[gender_col] {
+                "" => None,
+                code => Some(
+                    Gender::from_code(code)
+                        .ok_or_else(|| row_error(format!("unknown gender: {code}")))?,
+                ),
+            };
+            let age = match fields[age_col] {
+                "" => None,
+                code => {
+                    Some(age_code(&parse_age(code).ok_or_else(|| {
+                        row_error(format!("invalid age range: {code}"))
+                    })?))
+                }
+            };
+            let number = |col: usize| {
+                fields[col]
+                    .parse::<f32>()
+                    .map_err(|_| row_error(format!("invalid number: {}", fields[col])))
+            };
+            let stats = NormStats {
+                mean: number(mean_col)?,
+                sd: number(sd_col)?,
+            };
+            let sample_size = match sample_col.map(|col| fields[col]) {
+                None | Some("") => None,
+                Some(n) => Some(
+                    n.parse::<u32>()
+                        .map_err(|_| row_error(format!("invalid sample size: {n}")))?,
+                ),
+            };
+
+            let position = strata
+                .iter()
+                .position(|s| s.gender == gender && s.age == age);
+            let stratum = match position {
+                Some(position) => &mut strata[position],
+                None => {
+                    strata.push(RawStratum {
+                        gender,
+                        age: age.clone(),
+                        sample_size: None,
+                        domains: BTreeMap::new(),
+                        facets: BTreeMap::new(),
+                    });
+                    strata.last_mut().expect("just pushed")
+                }
+            };
+            stratum.sample_size = stratum.sample_size.or(sample_size);
+            let scale = fields[scale_col];
+            let scores = if Domain::from_code(scale).is_some() {
+                &mut stratum.domains
+            } else {
+                &mut stratum.facets
+            };
+            if scores.insert(scale.to_string(), stats).is_some() {
+                return Err(row_error(format!("duplicate row for {scale}")));
+            }
+        }
+
+        let table = NormTable {
+            source: source.into(),
+            strata: strata
+                .into_iter()
+                .map(NormStratum::try_from)
+                .collect::<Result<_, _>>()?,
+        };
+        table.validate()?;
+        Ok(table)
+    }
+
+    /// Check for strata that can't be told apart, or none at all.
+    fn validate(&self) -> Result<(), Error> {
+        if self.strata.is_empty() {
+            return Err(Error::InvalidNorms("no strata".to_string()));
+        }
+        let mut groups = HashSet::new();
+        for (i, stratum) in self.strata.iter().enumerate() {
+            let gender = stratum.gender.map(|g| g.code());
+            if !groups.insert((stratum.gender, stratum.age.clone())) {
+                return Err(Error::InvalidNorms(format!(
+                    "duplicate stratum for gender {gender:?}, age {:?}",
+                    stratum.age.as_ref().map(age_code)
+                )));
+            }
+            let Some(age) = &stratum.age else {
+                continue;
+            };
+            let overlapping = self.strata[..i].iter().find_map(|other| {
+                other.age.as_ref().filter(|other_age| {
+                    other.gender == stratum.gender && overlap(age, other_age) > 0
+                })
+            });
+            if let Some(other_age) = overlapping {
+                return Err(Error::InvalidNorms(format!(
+                    "overlapping age ranges {} and {} for gender {gender:?}",
+                    age_code(other_age),
+                    age_code(age)
+                )));
+            }
+        }
+        Ok(())
+    }
+
+    /// Most specific stratum covering a respondent: sex and age, then sex alone, then age
+    /// alone, then the overall norms. Of the age ranges overlapping the respondent's band,
+    /// the one sharing the most years is used.
+    pub fn stratum(&self, demographics: &Demographics) -> Option<&NormStratum> {
+        self.strata
+            .iter()
+            .filter_map(|s| s.coverage(demographics).map(|years| (s, years)))
+            .max_by_key(|(s, years)| (s.gender.is_some(), s.age.is_some(), *years))
+            .map(|(s, _)| s)
+    }
+}
+
+/// Read at most [`MAX_NORMS_BYTES`] of norm data.
+fn read_limited(reader: impl Read) -> Result<Vec<u8>, Error> {
+    let mut data = Vec::new();
+    reader
+        .take(MAX_NORMS_BYTES as u64 + 1)
+        .read_to_end(&mut data)
+        .map_err(|e| Error::ParseError(e.to_string()))?;
+    if data.len() > MAX_NORMS_BYTES {
+        return Err(Error::InputTooLarge(MAX_NORMS_BYTES));
+    }
+    Ok(data)
+}
+
+/// A raw score placed in a reference group.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NormedScore {
+    pub raw: u8,
+    /// Standardized score, mean 50 and standard deviation 10 in the reference group.
+    pub t_score: f32,
+    /// Share of the reference group (0-100) expected to score lower.
+    pub percentile: f32,
+    /// Low below the 30th percentile, high above the 70th.
+    pub level: ScoreLevel,
+}
+
+impl NormedScore {
+    fn new(raw: u8, stats: NormStats) -> Self {
+        let percentile = stats.percentile(raw);
+        let level = if percentile < LEVEL_PERCENTILE {
+            ScoreLevel::Low
+        } else if percentile > 100.0 - LEVEL_PERCENTILE {
+            ScoreLevel::High
+        } else {
+            ScoreLevel::Neutral
+        };
+        NormedScore {
+            raw,
+            t_score: stats.t_score(raw),
+            percentile,
+            level,
+        }
+    }
+}
+
+/// Domain and facet scores relative to a norm stratum.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct NormedProfile {
+    /// Sex of the stratum used, `None` if it covers everyone.
+    pub gender: Option<Gender>,
+    /// Age range of the stratum used, `None` if it covers all ages.
+    pub age: Option<RangeInclusive<u8>>,
+    pub domains: Vec<(Domain, NormedScore)>,
+    pub facets: Vec<(Facet, NormedScore)>,
+}
+
+impl NormedProfile {
+    /// Normed score of a domain.
+    pub fn domain(&self, domain: Domain) -> Option<&NormedScore> {
+        self.domains
+            .iter()
+            .find(|(d, _)| *d == domain)
+            .map(|(_, s)| s)
+    }
+
+    /// Normed score of a facet.
+    pub fn facet(&self, facet: Facet) -> Option<&NormedScore> {
+        self.facets
+            .iter()
+            .find(|(f, _)| *f == facet)
+            .map(|(_, s)| s)
+    }
+}
+
+/// Calculate T-scores and percentile ranks from answers, against the norm stratum that
+/// fits `demographics` best (see [`NormTable::stratum`]).
+///
+/// Norms are for raw IPIP-NEO-120 scores, so the inventory needs 4 questions per facet.
+pub fn calculate_normed<I: Inventory + ?Sized>(
+    inventory: &I,
+    answers: &[Answer],
+    demographics: &Demographics,
+    norms: &NormTable,
+) -> Result<NormedProfile, Error> {
+    let per_facet = inventory.questions_per_facet();
+    if per_facet != usize::from(Ipip120::QUESTIONS_PER_FACET) {
+        return Err(Error::NormsInventoryMismatch(per_facet));
+    }
+    let stratum = norms.stratum(demographics).ok_or(Error::NoNormStratum)?;
+    let profile = calculate(inventory, answers)?;
+
+    Ok(NormedProfile {
+        gender: stratum.gender,
+        age: stratum.age.clone(),
+        domains: profile
+            .domains
+            .iter()
+            .map(|d| (d.domain, NormedScore::new(d.raw, stratum.domain(d.domain))))
+            .collect(),
+        facets: profile
+            .domains
+            .iter()
+            .flat_map(|d| &d.facets)
+            .map(|f| (f.facet, NormedScore::new(f.raw, stratum.facet(f.facet))))
+            .collect(),
+    })
+}
+
+fn domain_index(domain: Domain) -> usize {
+    Domain::all()
+        .iter()
+        .position(|d| *d == domain)
+        .expect("every domain is in Domain::all")
+}
+
+/// Facet code used in norm tables, e.g. `"N1"`.
+fn facet_code(facet: Facet) -> String {
+    format!("{}{}", facet.domain().code(), facet.index())
+}
+
+/// Standard normal cumulative distribution function.
+fn normal_cdf(z: f64) -> f64 {
+    // Abramowitz & Stegun 7.1.26 approximation of erf, absolute error below 1.5e-7
+    let x = z.abs() / std::f64::consts::SQRT_2;
+    let t = 1.0 / (1.0 + 0.327_591_1 * x);
+    let poly = t
+        * (0.254_829_592
+            + t * (-0.284_496_736
+                + t * (1.421_413_741 + t * (-1.453_152_027 + t * 1.061_405_429))));
+    let erf = 1.0 - poly * (-x * x).exp();
+    if z >= 0.0 {
+        0.5 * (1.0 + erf)
+    } else {
+        0.5 * (1.0 - erf)
+    }
+}
+
+#[cfg(all(test, feature = "lang-en", feature = "serde"))]
+mod tests {
+    use super::*;
+
+    /// Norms placing a raw score of 3 per answer at the mean, with `mean_shift` added.
+    fn stratum_json(gender: Option<&str>, age: Option<&str>, mean_shift: f32) -> String {
+        let domains: Vec<String> = Domain::all()
+            .iter()
+            .map(|d| {
+                format!(
+                    r#""{}": {{"mean": {}, "sd": 12.0}}"#,
+                    d.code(),
+                    72.0 + mean_shift
+                )
+            })
+            .collect();
+        let facets: Vec<String> = Domain::all()
+            .iter()
+            .flat_map(|d| d.facets())
+            .map(|f| format!(r#""{}": {{"mean": 12.0, "sd": 3.0}}"#, facet_code(*f)))
+            .collect();
+        let field = |name: &str, value: Option<&str>| {
+            value.map_or(String::new(), |v| format!(r#""{name}": "{v}", "#))
+        };
+        format!(
+            r#"{{{}{}"domains": {{{}}}, "facets": {{{}}}}}"#,
+            field("gender", gender),
+            field("age", age),
+            domains.join(", "),
+            facets.join(", ")
+        )
+    }
+
+    fn table(strata: &[String]) -> Result<NormTable, Error> {
+        let json = format!(r#"{{"strata": [{}]}}"#, strata.join(", "));
+        NormTable::from_reader(json.as_bytes())
+    }
+
+    fn answers(inventory: &Ipip120, value: u8) -> Vec<Answer> {
+        inventory
+            .questions()
+            .iter()
+            .map(|q| Answer {
+                question_id: q.id.clone(),
+                value,
+            })
+            .collect()
+    }
+
+    /// Answers scoring `value` on every item once reverse keying is applied.
+    fn keyed_answers(inventory: &Ipip120, value: u8) -> Vec<Answer> {
+        inventory
+            .questions()
+            .iter()
+            .map(|q| Answer {
+                question_id: q.id.clone(),
+                value: if q.reversed { 6 - value } else { value },
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_normal_cdf() {
+        assert!((normal_cdf(0.0) - 0.5).abs() < 1e-6);
+        assert!((normal_cdf(1.0) - 0.841_345).abs() < 1e-5);
+        assert!((normal_cdf(-1.96) - 0.024_998).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_calculate_normed() {
+        let inventory = Ipip120::english();
+        let norms = table(&[stratum_json(None, None, 0.0)]).unwrap();
+
+        let normed = calculate_normed(
+            &inventory,
+            &answers(&inventory, 3),
+            &Demographics::default(),
+            &norms,
+        )
+        .unwrap();
+        assert_eq!(normed.domains.len(), 5);
+        assert_eq!(normed.facets.len(), 30);
+        let score = normed.domain(Domain::Openness).unwrap();
+        assert_eq!(score.raw, 72);
+        assert_eq!(score.t_score, 50.0);
+        assert!((score.percentile - 50.0).abs() < 0.01);
+        assert_eq!(score.level, ScoreLevel::Neutral);
+
+        // Top score on every facet: raw 20 is 2.67 standard deviations up
+        let highest = keyed_answers(&inventory, 5);
+        let normed =
+            calculate_normed(&inventory, &highest, &Demographics::default(), &norms).unwrap();
+        let facet = Facet::from_domain_and_index(Domain::Neuroticism, 2).unwrap();
+        let score = normed.facet(facet).unwrap();
+        assert_eq!(score.raw, 20);
+        assert!((score.t_score - 76.667).abs() < 0.01);
+        assert!(score.percentile > 99.0);
+        assert_eq!(score.level, ScoreLevel::High);
+    }
+
+    #[test]
+    fn test_stratum_selection() {
+        let norms = table(&[
+            stratum_json(None, None, 0.0),
+            stratum_json(Some("female"), None, 1.0),
+            stratum_json(None, Some("25-34"), 2.0),
+            stratum_json(Some("female"), Some("25-34"), 3.0),
+        ])
+        .unwrap();
+        let mean = |gender, age_band| {
+            let demographics = Demographics {
+                gender,
+                age_band,
+                country: None,
+            };
+            norms
+                .stratum(&demographics)
+                .unwrap()
+                .domain(Domain::Extraversion)
+                .mean
+        };
+
+        assert_eq!(mean(None, None), 72.0);
+        assert_eq!(mean(Some(Gender::Female), None), 73.0);
+        assert_eq!(mean(Some(Gender::Male), Some(AgeBand::From25To34)), 74.0);
+        assert_eq!(mean(Some(Gender::Other), Some(AgeBand::From25To34)), 74.0);
+        assert_eq!(mean(Some(Gender::Female), Some(AgeBand::From25To34)), 75.0);
+        assert_eq!(mean(Some(Gender::Female), Some(AgeBand::Over64)), 73.0);
+
+        // Age groups other than the bands, as in Johnson's tables: the band goes to the
+        // group it shares the most years with
+        let norms = table(&[
+            stratum_json(None, Some("0-20"), 0.0),
+            stratum_json(None, Some("21-40"), 1.0),
+            stratum_json(None, Some("41-60"), 2.0),
+            stratum_json(None, Some("61+"), 3.0),
+        ])
+        .unwrap();
+        let mean = |age_band| {
+            let demographics = Demographics {
+                gender: None,
+                age_band: Some(age_band),
+                country: None,
+            };
+            norms
+                .stratum(&demographics)
+                .unwrap()
+                .domain(Domain::Extraversion)
+                .mean
+        };
+        assert_eq!(mean(AgeBand::Under18), 72.0);
+        assert_eq!(mean(AgeBand::From18To24), 73.0);
+        assert_eq!(mean(AgeBand::From35To44), 73.0);
+        assert_eq!(mean(AgeBand::From55To64), 74.0);
+        assert_eq!(mean(AgeBand::Over64), 75.0);
+        assert!(norms.stratum(&Demographics::default()).is_none());
+
+        // Without overall norms, a respondent outside every stratum has none
+        let norms = table(&[stratum_json(Some("female"), None, 0.0)]).unwrap();
+        let inventory = Ipip120::english();
+        let result = calculate_normed(
+            &inventory,
+            &answers(&inventory, 3),
+            &Demographics::default(),
+            &norms,
+        );
+        assert!(matches!(result, Err(Error::NoNormStratum)));
+    }
+
+    #[test]
+    fn test_invalid_tables() {
+        assert!(matches!(table(&[]), Err(Error::InvalidNorms(_))));
+        assert!(matches!(
+            table(&[stratum_json(None, None, 0.0), stratum_json(None, None, 1.0)]),
+            Err(Error::InvalidNorms(_))
+        ));
+
+        assert!(matches!(
+            table(&[
+                stratum_json(None, Some("21-40"), 0.0),
+                stratum_json(None, Some("35-44"), 1.0),
+            ]),
+            Err(Error::InvalidNorms(e)) if e.contains("overlapping")
+        ));
+        assert!(matches!(
+            table(&[stratum_json(None, Some("40-21"), 0.0)]),
+            Err(Error::InvalidNorms(e)) if e.contains("40-21")
+        ));
+        // The same ages for each sex are fine
+        assert!(
+            table(&[
+                stratum_json(Some("female"), Some("21-40"), 0.0),
+                stratum_json(Some("male"), Some("21-40"), 0.0),
+            ])
+            .is_ok()
+        );
+
+        let missing = stratum_json(None, None, 0.0).replace(r#""C6": "#, r#""X9": "#);
+        assert!(matches!(table(&[missing]), Err(Error::InvalidNorms(_))));
+        let no_spread = stratum_json(None, None, 0.0).replace(
+            r#""C6": {"mean": 12.0, "sd": 3.0}"#,
+            r#""C6": {"mean": 12.0, "sd": 0.0}"#,
+        );
+        assert!(matches!(table(&[no_spread]), Err(Error::InvalidNorms(_))));
+    }
+
+    /// CSV rows of the stratum [`stratum_json`] builds for everyone.
+    fn overall_csv_rows() -> Vec<String> {
+        let domains = Domain::all()
+            .iter()
+            .map(|d| format!(",,{},72,12", d.code()));
+        let facets = Domain::all()
+            .iter()
+            .flat_map(|d| d.facets())
+            .map(|f| format!(",,{},12,3", facet_code(*f)));
+        domains.chain(facets).collect()
+    }
+
+    #[test]
+    fn test_from_csv() {
+        let csv = format!(
+            "gender,age_band,scale,mean,sd\n{}\n",
+            overall_csv_rows().join("\n")
+        );
+        let norms = NormTable::from_csv("test", csv.as_bytes()).unwrap();
+        let expected = table(&[stratum_json(None, None, 0.0)]).unwrap();
+        assert_eq!(norms.source, "test");
+        assert_eq!(norms.strata, expected.strata);
+
+        // Columns in another order, with a sample size
+        let rows: Vec<String> = overall_csv_rows()
+            .iter()
+            .map(|row| {
+                let fields: Vec<&str> = row.split(',').collect();
+                format!("{},{},male,,{},250", fields[3], fields[4], fields[2])
+            })
+            .collect();
+        let csv = format!(
+            "mean,sd,gender,age_band,scale,sample_size\n{}",
+            rows.join("\n")
+        );
+        let norms = NormTable::from_csv("", csv.as_bytes()).unwrap();
+        assert_eq!(norms.strata[0].gender, Some(Gender::Male));
+        assert_eq!(norms.strata[0].sample_size, Some(250));
+        assert_eq!(norms.strata[0].facet(Facet::Anxiety).mean, 12.0);
+
+        // An `age` column instead of `age_band`
+        let rows: Vec<String> = overall_csv_rows()
+            .iter()
+            .map(|row| row.replacen(",,", ",61+,", 1))
+            .collect();
+        let csv = format!("gender,age,scale,mean,sd\n{}", rows.join("\n"));
+        let norms = NormTable::from_csv("", csv.as_bytes()).unwrap();
+        assert_eq!(norms.strata[0].age, Some(61..=u8::MAX));
+    }
+
+    #[test]
+    fn test_from_csv_errors() {
+        let load = |rows: &[String]| {
+            let csv = format!("gender,age_band,scale,mean,sd\n{}", rows.join("\n"));
+            NormTable::from_csv("", csv.as_bytes())
+        };
+        let mut rows = overall_csv_rows();
+        rows.push(",,N,70,12".to_string());
+        assert!(matches!(load(&rows), Err(Error::InvalidNorms(e)) if e.contains("duplicate")));
+
+        let mut rows = overall_csv_rows();
+        rows[0] = "nonbinary,,N,72,12".to_string();
+        assert!(matches!(load(&rows), Err(Error::InvalidNorms(e)) if e.contains("line 2")));
+
+        let mut rows = overall_csv_rows();
+        rows[3] = ",,A,high,12".to_string();
+        assert!(matches!(load(&rows), Err(Error::InvalidNorms(_))));
+
+        let mut rows = overall_csv_rows();
+        rows.pop();
+        assert!(matches!(load(&rows), Err(Error::InvalidNorms(e)) if e.contains("C6")));
+
+        assert!(matches!(
+            NormTable::from_csv("", "scale,mean,sd\nN,72,12".as_bytes()),
+            Err(Error::InvalidNorms(e)) if e.contains("gender")
+        ));
+    }
+
+    #[test]
+    fn test_from_profiles() {
+        let inventory = Ipip120::english();
+        let profiles: Vec<PersonalityProfile> = [2, 3, 4]
+            .into_iter()
+            .map(|value| calculate(&inventory, &keyed_answers(&inventory, value)).unwrap())
+            .collect();
+
+        let stratum = NormStratum::from_profiles(Some(Gender::Female), None, &profiles).unwrap();
+        assert_eq!(stratum.sample_size, Some(3));
+        let stats = stratum.domain(Domain::Conscientiousness);
+        assert_eq!(stats.mean, 72.0);
+        assert_eq!(stats.sd, 24.0);
+        assert_eq!(stratum.facet(Facet::Anxiety).sd, 4.0);
+
+        assert!(NormStratum::from_profiles(None, None, &profiles[..1]).is_none());
+        let same = [profiles[1].clone(), profiles[1].clone()];
+        assert!(NormStratum::from_profiles(None, None, &same).is_none());
+    }
+
+    #[test]
+    fn test_serde_round_trip() {
+        let norms = table(&[stratum_json(Some("male"), Some("65+"), 0.5)]).unwrap();
+        assert_eq!(norms.strata[0].age, Some(AgeBand::Over64.years()));
+        let json = serde_json::to_string(&norms).unwrap();
+        assert!(json.contains(r#""gender":"male","age":"65+""#));
+        assert_eq!(NormTable::from_reader(json.as_bytes()).unwrap(), norms);
+
+        // Tables written before strata had age ranges
+        let old = stratum_json(None, Some("18-24"), 0.0).replace(r#""age""#, r#""age_band""#);
+        assert_eq!(table(&[old]).unwrap().strata[0].age, Some(18..=24));
+    }
+}
@@ -0,0 +1,139 @@
+//! Score distribution of a reference group, used to turn scores into percentile ranks.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::types::{Domain, PersonalityProfile};
+
+/// Number of quantile points kept per domain (0th to 100th percentile).
+const QUANTILE_POINTS: usize = 101;
+
+/// Domain score distribution of a group of profiles.
+///
+/// Keeps 101 quantiles per domain instead of every score, so it stays small enough to
+/// send to a browser regardless of group size.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ScoreDistribution {
+    /// Number of profiles in the group.
+    pub sample_size: usize,
+    /// Quantiles of each domain's percentage (0-100), from the 0th to the 100th percentile.
+    pub domains: Vec<(Domain, Vec<f32>)>,
+}
+
+impl ScoreDistribution {
+    /// Distribution of the given profiles. Returns `None` if there are none.
+    pub fn from_profiles<'a>(
+        profiles: impl IntoIterator<Item = &'a PersonalityProfile>,
+    ) -> Option<Self> {
+        let mut scores: Vec<Vec<f32>> = vec![Vec::new(); Domain::all().len()];
+        let mut sample_size = 0;
+        for profile in profiles {
+            sample_size += 1;
+            for (d, domain) in Domain::all().iter().enumerate() {
+                if let Some(score) = profile.domain_score(*domain) {
+                    scores[d].push(score.percentage());
+                }
+            }
+        }
+        if sample_size == 0 {
+            return None;
+        }
+
+        let domains = Domain::all()
+            .iter()
+            .zip(scores)
+            .filter(|(_, values)| !values.is_empty())
+            .map(|(domain, mut values)| {
+                values.sort_by(f32::total_cmp);
+                let last = values.len() - 1;
+                let quantiles = (0..QUANTILE_POINTS)
+                    .map(|k| {
+                        let index = (k * last + (QUANTILE_POINTS - 1) / 2) / (QUANTILE_POINTS - 1);
+                        values[index]
+                    })
+                    .collect();
+                (*domain, quantiles)
+            })
+            .collect();
+
+        Some(ScoreDistribution {
+            sample_size,
+            domains,
+        })
+    }
+
+    /// Share of the group (0-100) scoring below `percentage` on `domain`.
+    ///
+    /// People with the same score count as half below, so the middle of a tie lands on 50.
+    pub fn percentile(&self, domain: Domain, percentage: f32) -> Option<f32> {
+        let (_, quantiles) = self.domains.iter().find(|(d, _)| *d == domain)?;
+        if quantiles.is_empty() {
+            return None;
+        }
+        let below = quantiles.iter().filter(|&&q| q < percentage).count();
+        let equal = quantiles.iter().filter(|&&q| q == percentage).count();
+        let rank = (below as f32 + equal as f32 / 2.0) / quantiles.len() as f32 * 100.0;
+        Some(rank.clamp(0.0, 100.0))
+    }
+}
+
+#[cfg(all(test, feature = "lang-en"))]
+mod tests {
+    use super::*;
+    use crate::{Answer, Ipip120, calculate};
+
+    /// Profile where every answer has the same value.
+    fn uniform_profile(value: u8) -> PersonalityProfile {
+        let inventory = Ipip120::english();
+        let answers: Vec<Answer> = inventory
+            .questions()
+            .iter()
+            .map(|q| Answer {
+                question_id: q.id.clone(),
+                value,
+            })
+            .collect();
+        calculate(&inventory, &answers).unwrap()
+    }
+
+    #[test]
+    fn test_empty_group() {
+        assert!(ScoreDistribution::from_profiles(&[]).is_none());
+    }
+
+    #[test]
+    fn test_quantiles_cover_every_domain() {
+        let profiles = [uniform_profile(1), uniform_profile(5)];
+        let distribution = ScoreDistribution::from_profiles(&profiles).unwrap();
+        assert_eq!(distribution.sample_size, 2);
+        assert_eq!(distribution.domains.len(), 5);
+        for (_, quantiles) in &distribution.domains {
+            assert_eq!(quantiles.len(), QUANTILE_POINTS);
+            assert!(quantiles.windows(2).all(|w| w[0] <= w[1]));
+        }
+    }
+
+    #[test]
+    fn test_percentile_ranks() {
+        let profiles: Vec<_> = (1..=5).map(uniform_profile).collect();
+        let distribution = ScoreDistribution::from_profiles(&profiles).unwrap();
+        let domain = Domain::Extraversion;
+        let score = |p: &PersonalityProfile| p.domain_score(domain).unwrap().percentage();
+
+        let lowest = distribution
+            .percentile(domain, score(&profiles[0]))
+            .unwrap();
+        let middle = distribution
+            .percentile(domain, score(&profiles[2]))
+            .unwrap();
+        let highest = distribution
+            .percentile(domain, score(&profiles[4]))
+            .unwrap();
+        assert!(lowest < middle && middle < highest);
+        assert!((middle - 50.0).abs() < 1.0, "middle = {middle}");
+
+        assert_eq!(distribution.percentile(domain, -1.0), Some(0.0));
+        assert_eq!(distribution.percentile(domain, 101.0), Some(100.0));
+    }
+}
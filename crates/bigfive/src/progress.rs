@@ -0,0 +1,205 @@
+//! Compact binary encoding of a test in progress.
+//!
+//! Answers are written in the inventory's question order, three bits each (0 for
+//! unanswered), after a format version byte and the current question index as a
+//! little-endian `u16`. For the IPIP-NEO-120 that is 48 bytes, or 64 characters of
+//! base64url text, small enough for a QR code that another device scans to continue
+//! the test. Question ids aren't stored, so decoding needs the same inventory.
+
+use crate::Error;
+use crate::compact::{
+    decode_base64url, encode_base64url, pack_answers, packed_len, unpack_answers,
+};
+use crate::inventory::Inventory;
+use crate::types::Answer;
+
+/// Encoding version of test progress, distinct from the profile encodings.
+const FORMAT_VERSION: u8 = 3;
+
+/// Length of the version byte and question index.
+const HEADER_LEN: usize = 3;
+
+/// Answers given so far and the question the respondent is on.
+#[derive(Debug, Clone)]
+pub struct TestProgress {
+    pub answers: Vec<Answer>,
+    /// Index of the current question in the inventory.
+    pub current_index: usize,
+}
+
+impl TestProgress {
+    /// Encode the progress for `inventory`.
+    ///
+    /// Answers to questions not in the inventory or with values outside 1-5 are left out,
+    /// and the index is capped at the last question.
+    pub fn to_bytes<I: Inventory + ?Sized>(&self, inventory: &I) -> Vec<u8> {
+        let questions = inventory.questions();
+        let index = self.current_index.min(questions.len().saturating_sub(1));
+        let mut values = vec![0; questions.len()];
+        for answer in self.answers.iter().filter(|a| (1..=5).contains(&a.value)) {
+            if let Some(position) = questions.iter().position(|q| q.id == answer.question_id) {
+                values[position] = answer.value;
+            }
+        }
+
+        let mut bytes = vec![FORMAT_VERSION];
+        bytes.extend((index.min(u16::MAX as usize) as u16).to_le_bytes());
+        bytes.extend(pack_answers(&values));
+        bytes
+    }
+
+    /// Decode progress produced by [`TestProgress::to_bytes`] with the same inventory.
+    pub fn from_bytes<I: Inventory + ?Sized>(
+        inventory: &I,
+        bytes: &[u8],
+    ) -> Result<TestProgress, Error> {
+        let questions = inventory.questions();
+        let expected = encoded_len(questions.len());
+        if bytes.len() != expected {
+            return Err(Error::InvalidProgressData(format!(
+                "expected {expected} bytes, got {}",
+                bytes.len()
+            )));
+        }
+        if bytes[0] != FORMAT_VERSION {
+            return Err(Error::InvalidProgressData(format!(
+                "unsupported version {}",
+                bytes[0]
+            )));
+        }
+        let current_index = u16::from_le_bytes([bytes[1], bytes[2]]) as usize;
+        if current_index >= questions.len().max(1) {
+            return Err(Error::InvalidProgressData(format!(
+                "question index {current_index} out of range"
+            )));
+        }
+
+        let values = unpack_answers(&bytes[HEADER_LEN..], questions.len());
+        let mut answers = Vec::new();
+        for (question, value) in questions.iter().zip(values) {
+            match value {
+                0 => {}
+                1..=5 => answers.push(Answer {
+                    question_id: question.id.clone(),
+                    value,
+                }),
+                _ => {
+                    return Err(Error::InvalidProgressData(format!(
+                        "invalid answer value {value} for question {}",
+                        question.id
+                    )));
+                }
+            }
+        }
+        Ok(TestProgress {
+            answers,
+            current_index,
+        })
+    }
+
+    /// Encode the progress for `inventory` as unpadded base64url text.
+    pub fn to_compact_string<I: Inventory + ?Sized>(&self, inventory: &I) -> String {
+        encode_base64url(&self.to_bytes(inventory))
+    }
+
+    /// Decode progress produced by [`TestProgress::to_compact_string`].
+    pub fn from_compact_string<I: Inventory + ?Sized>(
+        inventory: &I,
+        text: &str,
+    ) -> Result<TestProgress, Error> {
+        let bytes = decode_base64url(text)
+            .ok_or_else(|| Error::InvalidProgressData("invalid base64url text".to_string()))?;
+        TestProgress::from_bytes(inventory, &bytes)
+    }
+}
+
+/// Encoded length for an inventory of `questions` questions.
+fn encoded_len(questions: usize) -> usize {
+    HEADER_LEN + packed_len(questions)
+}
+
+#[cfg(all(test, feature = "lang-en"))]
+mod tests {
+    use super::*;
+    use crate::{Ipip120, Ipip120Subset60};
+
+    fn partial_progress(inventory: &impl Inventory) -> TestProgress {
+        let answers = inventory
+            .questions()
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| i % 7 != 3)
+            .take(90)
+            .map(|(i, q)| Answer {
+                question_id: q.id.clone(),
+                value: (i % 5) as u8 + 1,
+            })
+            .collect();
+        TestProgress {
+            answers,
+            current_index: 97,
+        }
+    }
+
+    fn values(progress: &TestProgress) -> Vec<(String, u8)> {
+        let mut values: Vec<_> = progress
+            .answers
+            .iter()
+            .map(|a| (a.question_id.clone(), a.value))
+            .collect();
+        values.sort();
+        values
+    }
+
+    #[test]
+    fn test_compact_string_round_trip() {
+        let inventory = Ipip120::english();
+        let progress = partial_progress(&inventory);
+        let text = progress.to_compact_string(&inventory);
+        assert_eq!(text.len(), 64);
+
+        let decoded = TestProgress::from_compact_string(&inventory, &text).unwrap();
+        assert_eq!(decoded.current_index, 97);
+        assert_eq!(values(&decoded), values(&progress));
+    }
+
+    #[test]
+    fn test_skips_unknown_answers_and_caps_index() {
+        let inventory = Ipip120Subset60::english();
+        let mut progress = partial_progress(&inventory);
+        progress.answers.push(Answer {
+            question_id: "not-a-question".to_string(),
+            value: 4,
+        });
+        progress.answers[0].value = 9;
+        progress.current_index = 500;
+
+        let decoded = TestProgress::from_bytes(&inventory, &progress.to_bytes(&inventory)).unwrap();
+        assert_eq!(decoded.current_index, inventory.len() - 1);
+        assert_eq!(decoded.answers.len(), progress.answers.len() - 2);
+    }
+
+    #[test]
+    fn test_rejects_invalid_data() {
+        let inventory = Ipip120::english();
+        let mut bytes = partial_progress(&inventory).to_bytes(&inventory);
+
+        // Encoded for a different inventory
+        assert!(TestProgress::from_bytes(&Ipip120Subset60::english(), &bytes).is_err());
+
+        bytes[0] = 1;
+        assert!(TestProgress::from_bytes(&inventory, &bytes).is_err());
+        bytes[0] = FORMAT_VERSION;
+
+        bytes[1] = 120;
+        assert!(TestProgress::from_bytes(&inventory, &bytes).is_err());
+        bytes[1] = 0;
+
+        // First answer set to 7
+        bytes[HEADER_LEN] |= 0b111;
+        assert!(TestProgress::from_bytes(&inventory, &bytes).is_err());
+
+        assert!(TestProgress::from_compact_string(&inventory, "not base64!").is_err());
+        assert!(TestProgress::from_compact_string(&inventory, "").is_err());
+    }
+}
@@ -0,0 +1,549 @@
+//! Item and scale statistics over a sample of answer sets.
+//!
+//! [`ItemAnalysis`] computes what is usually checked when validating an inventory or a
+//! translation of it: each item's mean and standard deviation, its corrected item-total
+//! correlation with the rest of its facet and domain, and Cronbach's alpha of every facet
+//! and domain. Answers are reverse-scored first, so all items of a scale point the same way.
+//!
+//! [`CorrelationMatrix`] works on scored profiles instead, correlating every domain and facet
+//! with every other, to check that a new language version reproduces the usual structure
+//! (facets correlating most with the other facets of their own domain).
+
+use std::collections::HashMap;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::inventory::Inventory;
+use crate::types::{Answer, Domain, Facet, PersonalityProfile, Question};
+
+/// Statistics of one item over the sample.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ItemStats {
+    pub question_id: String,
+    pub facet: Facet,
+    pub reversed: bool,
+    /// Mean reverse-scored answer (1-5).
+    pub mean: f32,
+    /// Sample standard deviation of the reverse-scored answers.
+    pub sd: f32,
+    /// Correlation with the sum of the other items of its facet; `None` for a facet with a
+    /// single item, or when either has no variance.
+    pub item_total: Option<f32>,
+    /// Correlation with the sum of the other items of its domain; `None` when either has no
+    /// variance.
+    pub item_domain: Option<f32>,
+}
+
+/// Internal consistency of a facet or domain.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ScaleReliability {
+    /// Number of items in the scale.
+    pub items: usize,
+    /// Cronbach's alpha; `None` for a single item or when the scale score has no variance.
+    pub alpha: Option<f32>,
+}
+
+/// Item statistics and scale reliabilities of a sample of answer sets.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ItemAnalysis {
+    /// Answer sets the statistics were computed from.
+    pub respondents: usize,
+    /// Answer sets left out because [`calculate`](crate::calculate) would reject them.
+    pub excluded: usize,
+    /// Per item, in inventory order.
+    pub items: Vec<ItemStats>,
+    /// Per facet, in domain then facet order.
+    pub facets: Vec<(Facet, ScaleReliability)>,
+    /// Per domain, in [`Domain::all`] order.
+    pub domains: Vec<(Domain, ScaleReliability)>,
+}
+
+impl ItemAnalysis {
+    /// Analyze the answer sets of a sample that took `inventory`.
+    ///
+    /// Incomplete or invalid answer sets are skipped and counted in
+    /// [`excluded`](Self::excluded). Returns `None` with fewer than two answer sets left.
+    pub fn from_answers<I: Inventory + ?Sized>(
+        inventory: &I,
+        respondents: &[Vec<Answer>],
+    ) -> Option<Self> {
+        // Only questions measuring a facet are analyzed
+        let questions: Vec<(&Question, Facet)> = inventory
+            .questions()
+            .iter()
+            .filter_map(|q| Some((q, q.facet()?)))
+            .collect();
+        let positions: HashMap<&str, usize> = inventory
+            .questions()
+            .iter()
+            .enumerate()
+            .map(|(i, q)| (q.id.as_str(), i))
+            .collect();
+
+        // One column of reverse-scored answers per analyzed question
+        let mut columns: Vec<Vec<f64>> =
+            vec![Vec::with_capacity(respondents.len()); questions.len()];
+        let mut excluded = 0;
+        for answers in respondents {
+            let Some(values) = answer_values(inventory.len(), &positions, answers) else {
+                excluded += 1;
+                continue;
+            };
+            for ((question, _), column) in questions.iter().zip(&mut columns) {
+                let value = values[positions[question.id.as_str()]];
+                let score = if question.reversed { 6 - value } else { value };
+                column.push(f64::from(score));
+            }
+        }
+        let n = respondents.len() - excluded;
+        if n < 2 {
+            return None;
+        }
+
+        let facet_items = |facet: Facet| -> Vec<usize> {
+            (0..questions.len())
+                .filter(|&i| questions[i].1 == facet)
+                .collect()
+        };
+        let domain_items = |domain: Domain| -> Vec<usize> {
+            (0..questions.len())
+                .filter(|&i| questions[i].0.domain == domain)
+                .collect()
+        };
+
+        let items = questions
+            .iter()
+            .enumerate()
+            .map(|(i, (question, facet))| {
+                let (mean, sd) = mean_sd(&columns[i]);
+                let facet_rest = rest_score(&columns, &facet_items(*facet), i, n);
+                let domain_rest = rest_score(&columns, &domain_items(question.domain), i, n);
+                ItemStats {
+                    question_id: question.id.clone(),
+                    facet: *facet,
+                    reversed: question.reversed,
+                    mean: mean as f32,
+                    sd: sd as f32,
+                    item_total: facet_rest
+                        .and_then(|rest| correlation(&columns[i], &rest))
+                        .map(|r| r as f32),
+                    item_domain: domain_rest
+                        .and_then(|rest| correlation(&columns[i], &rest))
+                        .map(|r| r as f32),
+                }
+            })
+            .collect();
+
+        let reliability = |indices: Vec<usize>| ScaleReliability {
+            items: indices.len(),
+            alpha: cronbach_alpha(&columns, &indices, n).map(|a| a as f32),
+        };
+        let mut facets = Vec::new();
+        let mut domains = Vec::new();
+        for &domain in Domain::all() {
+            let indices = domain_items(domain);
+            if indices.is_empty() {
+                continue;
+            }
+            domains.push((domain, reliability(indices)));
+            for &facet in domain.facets() {
+                let indices = facet_items(facet);
+                if !indices.is_empty() {
+                    facets.push((facet, reliability(indices)));
+                }
+            }
+        }
+
+        Some(ItemAnalysis {
+            respondents: n,
+            excluded,
+            items,
+            facets,
+            domains,
+        })
+    }
+
+    /// Statistics of a question.
+    pub fn item(&self, question_id: &str) -> Option<&ItemStats> {
+        self.items.iter().find(|i| i.question_id == question_id)
+    }
+
+    /// Reliability of a facet.
+    pub fn facet(&self, facet: Facet) -> Option<&ScaleReliability> {
+        self.facets
+            .iter()
+            .find(|(f, _)| *f == facet)
+            .map(|(_, r)| r)
+    }
+
+    /// Reliability of a domain.
+    pub fn domain(&self, domain: Domain) -> Option<&ScaleReliability> {
+        self.domains
+            .iter()
+            .find(|(d, _)| *d == domain)
+            .map(|(_, r)| r)
+    }
+}
+
+/// A domain or facet, as a row and column of a [`CorrelationMatrix`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ScaleId {
+    Domain(Domain),
+    Facet(Facet),
+}
+
+impl ScaleId {
+    /// Short code, e.g. `"N"` for Neuroticism and `"N1"` for Anxiety.
+    pub fn code(&self) -> String {
+        match self {
+            ScaleId::Domain(domain) => domain.code().to_string(),
+            ScaleId::Facet(facet) => format!("{}{}", facet.domain().code(), facet.index()),
+        }
+    }
+
+    /// Score of this scale in a profile as a percentage of its range.
+    fn percentage(&self, profile: &PersonalityProfile) -> Option<f32> {
+        match self {
+            ScaleId::Domain(domain) => profile.domain_score(*domain).map(|s| s.percentage()),
+            ScaleId::Facet(facet) => profile.facet_score(*facet).map(|s| s.percentage()),
+        }
+    }
+}
+
+/// Pearson correlations between all domain and facet scores of a sample of profiles.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct CorrelationMatrix {
+    /// Profiles the correlations were computed from.
+    pub sample_size: usize,
+    /// Rows and columns: the five domains in [`Domain::all`] order, then the 30 facets in
+    /// domain then facet order.
+    pub scales: Vec<ScaleId>,
+    /// Row-major; `None` where a scale has no variance in the sample.
+    values: Vec<Option<f32>>,
+}
+
+impl CorrelationMatrix {
+    /// Correlate the scores of a sample of profiles.
+    ///
+    /// Scores are compared as percentages of their range, so profiles may come from
+    /// different inventories. Profiles missing a domain or facet are skipped. Returns `None`
+    /// with fewer than two profiles left.
+    pub fn from_profiles(profiles: &[PersonalityProfile]) -> Option<Self> {
+        let scales: Vec<ScaleId> = Domain::all()
+            .iter()
+            .map(|d| ScaleId::Domain(*d))
+            .chain(
+                Domain::all()
+                    .iter()
+                    .flat_map(|d| d.facets())
+                    .map(|f| ScaleId::Facet(*f)),
+            )
+            .collect();
+
+        // One column of scores per scale
+        let mut columns: Vec<Vec<f64>> = vec![Vec::with_capacity(profiles.len()); scales.len()];
+        let mut sample_size = 0;
+        for profile in profiles {
+            let Some(scores) = scales
+                .iter()
+                .map(|s| s.percentage(profile))
+                .collect::<Option<Vec<f32>>>()
+            else {
+                continue;
+            };
+            for (column, score) in columns.iter_mut().zip(scores) {
+                column.push(f64::from(score));
+            }
+            sample_size += 1;
+        }
+        if sample_size < 2 {
+            return None;
+        }
+
+        let mut values = vec![None; scales.len() * scales.len()];
+        for i in 0..scales.len() {
+            for j in i..scales.len() {
+                let r = correlation(&columns[i], &columns[j]).map(|r| r as f32);
+                values[i * scales.len() + j] = r;
+                values[j * scales.len() + i] = r;
+            }
+        }
+        Some(CorrelationMatrix {
+            sample_size,
+            scales,
+            values,
+        })
+    }
+
+    /// Correlation between two scales; `None` where either has no variance.
+    pub fn get(&self, a: ScaleId, b: ScaleId) -> Option<f32> {
+        let i = self.scales.iter().position(|s| *s == a)?;
+        let j = self.scales.iter().position(|s| *s == b)?;
+        self.values[i * self.scales.len() + j]
+    }
+
+    /// The matrix as CSV, with scale codes as the header row and first column and empty
+    /// cells where a correlation is undefined:
+    ///
+    /// ```csv
+    /// ,N,E,...
+    /// N,1.000,-0.312,...
+    /// ```
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::new();
+        for scale in &self.scales {
+            csv.push(',');
+            csv.push_str(&scale.code());
+        }
+        csv.push('\n');
+        for (i, scale) in self.scales.iter().enumerate() {
+            csv.push_str(&scale.code());
+            for value in &self.values[i * self.scales.len()..(i + 1) * self.scales.len()] {
+                csv.push(',');
+                if let Some(r) = value {
+                    csv.push_str(&format!("{r:.3}"));
+                }
+            }
+            csv.push('\n');
+        }
+        csv
+    }
+}
+
+/// Answers in inventory order, or `None` when `calculate` would reject the answer set.
+fn answer_values(
+    questions: usize,
+    positions: &HashMap<&str, usize>,
+    answers: &[Answer],
+) -> Option<Vec<u8>> {
+    if answers.len() != questions || answers.iter().any(|a| !(1..=5).contains(&a.value)) {
+        return None;
+    }
+    // 0 marks a question without an answer
+    let mut values = vec![0; questions];
+    for answer in answers {
+        if let Some(&i) = positions.get(answer.question_id.as_str()) {
+            values[i] = answer.value;
+        }
+    }
+    (!values.contains(&0)).then_some(values)
+}
+
+/// Per respondent, the sum of the items in `scale` other than `item`; `None` if there are
+/// no other items.
+fn rest_score(columns: &[Vec<f64>], scale: &[usize], item: usize, n: usize) -> Option<Vec<f64>> {
+    let others: Vec<usize> = scale.iter().copied().filter(|&i| i != item).collect();
+    if others.is_empty() {
+        return None;
+    }
+    Some(
+        (0..n)
+            .map(|r| others.iter().map(|&i| columns[i][r]).sum())
+            .collect(),
+    )
+}
+
+/// Mean and sample standard deviation.
+fn mean_sd(values: &[f64]) -> (f64, f64) {
+    let n = values.len() as f64;
+    let mean = values.iter().sum::<f64>() / n;
+    let variance = values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1.0);
+    (mean, variance.sqrt())
+}
+
+/// Pearson correlation; `None` when either variable is constant.
+fn correlation(x: &[f64], y: &[f64]) -> Option<f64> {
+    let (mean_x, sd_x) = mean_sd(x);
+    let (mean_y, sd_y) = mean_sd(y);
+    if sd_x == 0.0 || sd_y == 0.0 {
+        return None;
+    }
+    let covariance = x
+        .iter()
+        .zip(y)
+        .map(|(a, b)| (a - mean_x) * (b - mean_y))
+        .sum::<f64>()
+        / (x.len() as f64 - 1.0);
+    Some(covariance / (sd_x * sd_y))
+}
+
+/// Cronbach's alpha of the items in `scale`.
+fn cronbach_alpha(columns: &[Vec<f64>], scale: &[usize], n: usize) -> Option<f64> {
+    let k = scale.len() as f64;
+    if scale.len() < 2 {
+        return None;
+    }
+    let item_variance: f64 = scale.iter().map(|&i| mean_sd(&columns[i]).1.powi(2)).sum();
+    let totals: Vec<f64> = (0..n)
+        .map(|r| scale.iter().map(|&i| columns[i][r]).sum())
+        .collect();
+    let total_variance = mean_sd(&totals).1.powi(2);
+    (total_variance > 0.0).then(|| k / (k - 1.0) * (1.0 - item_variance / total_variance))
+}
+
+#[cfg(all(test, feature = "lang-en"))]
+mod tests {
+    use super::*;
+    use crate::inventory::Ipip120;
+
+    /// Answer set where every item is answered in line with a single trait level (1-5).
+    fn consistent_answers(inventory: &Ipip120, level: u8) -> Vec<Answer> {
+        inventory
+            .questions()
+            .iter()
+            .map(|q| Answer {
+                question_id: q.id.clone(),
+                value: if q.reversed { 6 - level } else { level },
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_perfectly_consistent_sample() {
+        let inventory = Ipip120::english();
+        let sample: Vec<_> = [1, 2, 3, 4, 5, 4, 2]
+            .into_iter()
+            .map(|level| consistent_answers(&inventory, level))
+            .collect();
+        let analysis = ItemAnalysis::from_answers(&inventory, &sample).unwrap();
+
+        assert_eq!(analysis.respondents, 7);
+        assert_eq!(analysis.excluded, 0);
+        assert_eq!(analysis.items.len(), 120);
+        assert_eq!(analysis.facets.len(), 30);
+        assert_eq!(analysis.domains.len(), 5);
+
+        let anxiety = analysis.facet(Facet::Anxiety).unwrap();
+        assert_eq!(anxiety.items, 4);
+        assert!((anxiety.alpha.unwrap() - 1.0).abs() < 1e-5);
+        let neuroticism = analysis.domain(Domain::Neuroticism).unwrap();
+        assert_eq!(neuroticism.items, 24);
+        assert!((neuroticism.alpha.unwrap() - 1.0).abs() < 1e-5);
+
+        let item = &analysis.items[0];
+        assert!((item.mean - 3.0).abs() < 1e-5);
+        assert!((item.item_total.unwrap() - 1.0).abs() < 1e-5);
+        assert!((item.item_domain.unwrap() - 1.0).abs() < 1e-5);
+    }
+
+    #[test]
+    fn test_inconsistent_item() {
+        let inventory = Ipip120::english();
+        let item_id = inventory.questions()[0].id.clone();
+        let facet = inventory.questions()[0].facet().unwrap();
+        let sample: Vec<_> = [1, 2, 3, 4, 5]
+            .into_iter()
+            .map(|level| {
+                let mut answers = consistent_answers(&inventory, level);
+                // Runs against the rest of its facet
+                answers[0].value = if inventory.questions()[0].reversed {
+                    level
+                } else {
+                    6 - level
+                };
+                answers
+            })
+            .collect();
+        let analysis = ItemAnalysis::from_answers(&inventory, &sample).unwrap();
+
+        let item = analysis.item(&item_id).unwrap();
+        assert!((item.item_total.unwrap() + 1.0).abs() < 1e-5);
+        assert!(analysis.facet(facet).unwrap().alpha.unwrap() < 0.5);
+
+        // A constant item has no correlation
+        let sample: Vec<_> = [1, 3, 5]
+            .into_iter()
+            .map(|level| {
+                let mut answers = consistent_answers(&inventory, level);
+                answers[0].value = 3;
+                answers
+            })
+            .collect();
+        let analysis = ItemAnalysis::from_answers(&inventory, &sample).unwrap();
+        let item = analysis.item(&item_id).unwrap();
+        assert_eq!(item.sd, 0.0);
+        assert_eq!(item.item_total, None);
+    }
+
+    #[test]
+    fn test_excludes_invalid_answer_sets() {
+        let inventory = Ipip120::english();
+        let mut invalid = consistent_answers(&inventory, 3);
+        invalid[7].value = 9;
+        let incomplete = consistent_answers(&inventory, 3)[1..].to_vec();
+
+        let sample = vec![
+            consistent_answers(&inventory, 2),
+            invalid.clone(),
+            consistent_answers(&inventory, 4),
+            incomplete.clone(),
+        ];
+        let analysis = ItemAnalysis::from_answers(&inventory, &sample).unwrap();
+        assert_eq!(analysis.respondents, 2);
+        assert_eq!(analysis.excluded, 2);
+
+        let too_few = vec![consistent_answers(&inventory, 2), invalid, incomplete];
+        assert_eq!(ItemAnalysis::from_answers(&inventory, &too_few), None);
+    }
+
+    /// Profile answering every item of each domain with the given score (N, E, O, A, C).
+    fn profile(scores: [u8; 5]) -> PersonalityProfile {
+        let inventory = Ipip120::english();
+        let answers: Vec<Answer> = inventory
+            .questions()
+            .iter()
+            .map(|q| {
+                let index = Domain::all().iter().position(|d| *d == q.domain).unwrap();
+                let score = scores[index];
+                Answer {
+                    question_id: q.id.clone(),
+                    value: if q.reversed { 6 - score } else { score },
+                }
+            })
+            .collect();
+        crate::calculate(&inventory, &answers).unwrap()
+    }
+
+    #[test]
+    fn test_correlation_matrix() {
+        let mut incomplete = profile([3, 3, 3, 3, 3]);
+        incomplete.domains.pop();
+        let profiles = [
+            profile([1, 1, 5, 2, 3]),
+            profile([3, 3, 3, 4, 3]),
+            profile([5, 5, 1, 3, 3]),
+            incomplete,
+        ];
+        let matrix = CorrelationMatrix::from_profiles(&profiles).unwrap();
+        assert_eq!(matrix.sample_size, 3);
+        assert_eq!(matrix.scales.len(), 35);
+
+        let n = ScaleId::Domain(Domain::Neuroticism);
+        let e = ScaleId::Domain(Domain::Extraversion);
+        let o = ScaleId::Domain(Domain::Openness);
+        let c = ScaleId::Domain(Domain::Conscientiousness);
+        assert!((matrix.get(n, n).unwrap() - 1.0).abs() < 1e-5);
+        assert!((matrix.get(n, e).unwrap() - 1.0).abs() < 1e-5);
+        assert!((matrix.get(o, n).unwrap() + 1.0).abs() < 1e-5);
+        assert_eq!(matrix.get(n, c), None);
+        let anxiety = ScaleId::Facet(Facet::Anxiety);
+        assert_eq!(matrix.get(anxiety, n), matrix.get(n, anxiety));
+        assert!((matrix.get(anxiety, n).unwrap() - 1.0).abs() < 1e-5);
+
+        let csv = matrix.to_csv();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines.len(), 36);
+        assert!(lines[0].starts_with(",N,E,O,A,C,N1,N2"));
+        assert!(lines[1].starts_with("N,1.000,1.000,-1.000,"));
+        // Conscientiousness is constant: its correlations are empty
+        assert!(lines[5].starts_with("C,,,,,,"));
+
+        assert_eq!(CorrelationMatrix::from_profiles(&profiles[..1]), None);
+    }
+}
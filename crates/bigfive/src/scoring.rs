@@ -2,132 +2,372 @@
 
 use std::collections::HashMap;
 
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
 use crate::Error;
-use crate::inventory::Ipip120;
-#[cfg(test)]
+use crate::inventory::Inventory;
+#[cfg(all(test, feature = "lang-en"))]
 use crate::types::Facet;
-use crate::types::{Answer, Domain, DomainScore, FacetScore, PersonalityProfile, ScoreLevel};
+use crate::types::{
+    Answer, Domain, DomainScore, FacetScore, LevelCutoffs, PersonalityProfile, Question, ScoreBand,
+    ScoreLevel,
+};
+use crate::validity::ValidityReport;
 
-/// Calculate the personality profile from answers.
-///
-/// # Arguments
-/// * `inventory` - The question inventory used
-/// * `answers` - Vector of answers (must have exactly 120 answers)
+/// Most questions per facet a score fits: a domain sums 6 facets of answers up to 5, and
+/// raw scores are bytes.
+const MAX_QUESTIONS_PER_FACET: u8 = 8;
+
+/// Raw score range shared by facet and domain scores, whatever the inventory length.
 ///
-/// # Returns
-/// A `PersonalityProfile` with scores for all domains and facets.
-pub fn calculate(inventory: &Ipip120, answers: &[Answer]) -> Result<PersonalityProfile, Error> {
-    if answers.len() != 120 {
-        return Err(Error::InvalidAnswerCount(answers.len()));
+/// A raw score is the sum of `questions` answers scored 1-5, so its bounds, percentage
+/// and level follow from the question count alone.
+pub trait ScoreRange {
+    /// Raw score.
+    fn raw(&self) -> u8;
+
+    /// Number of questions summed into the raw score.
+    fn questions(&self) -> u8;
+
+    /// Lowest possible raw score (every answer scored 1).
+    fn min_raw(&self) -> u8 {
+        self.questions()
     }
 
-    // Create a map of question_id -> answer for quick lookup
-    let answer_map: HashMap<&str, u8> = answers
-        .iter()
-        .map(|a| (a.question_id.as_str(), a.value))
-        .collect();
+    /// Highest possible raw score (every answer scored 5).
+    fn max_raw(&self) -> u8 {
+        self.questions().saturating_mul(5)
+    }
 
-    // Validate all answers have values 1-5
-    for answer in answers {
-        if answer.value < 1 || answer.value > 5 {
-            return Err(Error::InvalidAnswerValue(answer.value));
+    /// Raw score as a percentage (0-100) of the range.
+    fn percentage(&self) -> f32 {
+        let span = f32::from(self.max_raw() - self.min_raw());
+        if span == 0.0 {
+            return 0.0;
         }
+        (f32::from(self.raw()) - f32::from(self.min_raw())) / span * 100.0
     }
 
-    // Calculate scores for each facet
-    // Key: (Domain, facet_index) -> Vec<scores>
-    let mut facet_scores_raw: HashMap<(Domain, u8), Vec<u8>> = HashMap::new();
+    /// Level the raw score falls into, see [`ScoreLevel::range`].
+    fn raw_level(&self) -> ScoreLevel {
+        ScoreLevel::for_raw(self.raw(), self.questions())
+    }
 
-    for question in inventory.questions() {
-        let answer_value = answer_map
-            .get(question.id.as_str())
-            .ok_or_else(|| Error::MissingAnswer(question.id.clone()))?;
+    /// Five-band category of the raw score, see [`ScoreBand::range`].
+    fn band(&self) -> ScoreBand {
+        ScoreBand::for_raw(self.raw(), self.questions())
+    }
+}
 
-        // Apply reverse scoring if needed
-        let score = if question.reversed {
-            6 - answer_value // 1->5, 2->4, 3->3, 4->2, 5->1
-        } else {
-            *answer_value
-        };
+impl ScoreRange for FacetScore {
+    fn raw(&self) -> u8 {
+        self.raw
+    }
+
+    fn questions(&self) -> u8 {
+        self.questions
+    }
+}
 
-        facet_scores_raw
-            .entry((question.domain, question.facet_index))
-            .or_default()
-            .push(score);
+impl ScoreRange for DomainScore {
+    fn raw(&self) -> u8 {
+        self.raw
     }
 
-    // Build domain scores
-    let mut domains = Vec::new();
+    fn questions(&self) -> u8 {
+        self.questions
+    }
+}
+
+/// Settings for [`calculate_with`].
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ScoringOptions {
+    /// Where facet and domain scores turn neutral and high.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub levels: LevelCutoffs,
+}
+
+/// Calculate the personality profile from answers.
+///
+/// # Arguments
+/// * `inventory` - The question inventory used, e.g. [`Ipip120`](crate::Ipip120)
+/// * `answers` - Vector of answers (exactly one per question)
+///
+/// # Returns
+/// A `PersonalityProfile` with scores for all domains and facets. Score ranges follow from
+/// the inventory's questions per facet, e.g. 4-20 per facet for the IPIP-NEO-120.
+pub fn calculate<I: Inventory + ?Sized>(
+    inventory: &I,
+    answers: &[Answer],
+) -> Result<PersonalityProfile, Error> {
+    calculate_with(inventory, answers, &ScoringOptions::default())
+}
+
+/// Calculate the personality profile from answers, like [`calculate`] but with levels
+/// assigned as set in `options`.
+pub fn calculate_with<I: Inventory + ?Sized>(
+    inventory: &I,
+    answers: &[Answer],
+    options: &ScoringOptions,
+) -> Result<PersonalityProfile, Error> {
+    Scorer::new(inventory, options)?.score(answers, &mut Vec::new())
+}
 
-    for domain in Domain::all() {
-        let mut facets = Vec::new();
-        let mut domain_total: u16 = 0;
+/// Calculate the profiles of many respondents to the same inventory, e.g. an archival
+/// dataset, with one result per respondent in input order.
+///
+/// Gives the same results as calling [`calculate`] for each respondent, but works out which
+/// facet every question belongs to once and reuses its buffers, so the per-respondent cost
+/// is little more than summing the answers.
+pub fn calculate_batch<I: Inventory + ?Sized>(
+    inventory: &I,
+    respondents: &[Vec<Answer>],
+) -> Vec<Result<PersonalityProfile, Error>> {
+    calculate_batch_with(inventory, respondents, &ScoringOptions::default())
+}
 
-        for facet in domain.facets() {
-            let facet_index = facet.index();
-            let scores = facet_scores_raw
-                .get(&(*domain, facet_index))
-                .ok_or(Error::MissingFacetData(*domain, facet_index))?;
+/// Like [`calculate_batch`], with levels assigned as set in `options`.
+pub fn calculate_batch_with<I: Inventory + ?Sized>(
+    inventory: &I,
+    respondents: &[Vec<Answer>],
+    options: &ScoringOptions,
+) -> Vec<Result<PersonalityProfile, Error>> {
+    let scorer = match Scorer::new(inventory, options) {
+        Ok(scorer) => scorer,
+        Err(_) => {
+            let per_facet = inventory.questions_per_facet();
+            return respondents
+                .iter()
+                .map(|_| Err(Error::UnsupportedQuestionsPerFacet(per_facet)))
+                .collect();
+        }
+    };
+    let mut values = Vec::with_capacity(inventory.len());
+    respondents
+        .iter()
+        .map(|answers| scorer.score(answers, &mut values))
+        .collect()
+}
+
+/// Scoring state that only depends on the inventory, shared by all respondents.
+struct Scorer<'a> {
+    questions: &'a [Question],
+    per_facet: u8,
+    options: ScoringOptions,
+    /// Position of each question by id.
+    positions: HashMap<&'a str, usize>,
+    /// Facet slot (domain position * 6 + facet index - 1) of each question, in inventory
+    /// order; `None` for questions outside the 30 facets, which aren't scored.
+    slots: Vec<Option<usize>>,
+    /// Questions per facet slot.
+    counts: [usize; 30],
+}
 
-            if scores.len() != 4 {
-                return Err(Error::InvalidFacetQuestionCount(
-                    *domain,
-                    facet_index,
-                    scores.len(),
-                ));
+impl<'a> Scorer<'a> {
+    fn new<I: Inventory + ?Sized>(
+        inventory: &'a I,
+        options: &ScoringOptions,
+    ) -> Result<Self, Error> {
+        let per_facet = u8::try_from(inventory.questions_per_facet())
+            .ok()
+            .filter(|n| (1..=MAX_QUESTIONS_PER_FACET).contains(n))
+            .ok_or(Error::UnsupportedQuestionsPerFacet(
+                inventory.questions_per_facet(),
+            ))?;
+        let questions = inventory.questions();
+
+        let mut counts = [0; 30];
+        let slots = questions
+            .iter()
+            .map(|q| {
+                let domain = Domain::all().iter().position(|d| *d == q.domain)?;
+                let slot = (1..=6)
+                    .contains(&q.facet_index)
+                    .then(|| domain * 6 + usize::from(q.facet_index) - 1)?;
+                counts[slot] += 1;
+                Some(slot)
+            })
+            .collect();
+
+        Ok(Scorer {
+            questions,
+            per_facet,
+            options: *options,
+            positions: questions
+                .iter()
+                .enumerate()
+                .map(|(i, q)| (q.id.as_str(), i))
+                .collect(),
+            slots,
+            counts,
+        })
+    }
+
+    /// Profile of one respondent; `values` is scratch space for the answers in inventory
+    /// order.
+    fn score(&self, answers: &[Answer], values: &mut Vec<u8>) -> Result<PersonalityProfile, Error> {
+        let questions = self.questions;
+        if answers.len() != questions.len() {
+            return Err(Error::InvalidAnswerCount(answers.len()));
+        }
+        if let Some(answer) = answers.iter().find(|a| !(1..=5).contains(&a.value)) {
+            return Err(Error::InvalidAnswerValue(answer.value));
+        }
+
+        // 0 marks a question without an answer; a repeated id keeps its last answer
+        values.clear();
+        values.resize(questions.len(), 0);
+        for (i, answer) in answers.iter().enumerate() {
+            // Answers usually come in inventory order, which saves the lookup
+            let position = if questions[i].id == answer.question_id {
+                Some(i)
+            } else {
+                self.positions.get(answer.question_id.as_str()).copied()
+            };
+            if let Some(position) = position {
+                values[position] = answer.value;
+            }
+        }
+        if let Some(missing) = values.iter().position(|&v| v == 0) {
+            return Err(Error::MissingAnswer(questions[missing].id.clone()));
+        }
+
+        // Sum the facets, applying reverse scoring (1->5, 2->4, 3->3, 4->2, 5->1)
+        let mut sums = [0u16; 30];
+        for ((question, slot), &value) in questions.iter().zip(&self.slots).zip(values.iter()) {
+            if let Some(slot) = slot {
+                let score = if question.reversed { 6 - value } else { value };
+                sums[*slot] += u16::from(score);
             }
+        }
+
+        let per_facet = self.per_facet;
+        let levels = &self.options.levels;
+        let mut domains = Vec::with_capacity(Domain::all().len());
+        for (d, domain) in Domain::all().iter().enumerate() {
+            let mut facets = Vec::with_capacity(domain.facets().len());
+            let mut domain_total: u16 = 0;
+
+            for facet in domain.facets() {
+                let facet_index = facet.index();
+                let slot = d * 6 + usize::from(facet_index) - 1;
+                match self.counts[slot] {
+                    0 => return Err(Error::MissingFacetData(*domain, facet_index)),
+                    n if n != usize::from(per_facet) => {
+                        return Err(Error::InvalidFacetQuestionCount(*domain, facet_index, n));
+                    }
+                    _ => {}
+                }
 
-            let raw: u8 = scores.iter().map(|&s| s as u16).sum::<u16>() as u8;
-            domain_total += raw as u16;
+                let raw = sums[slot] as u8;
+                domain_total += u16::from(raw);
+                facets.push(FacetScore {
+                    facet: *facet,
+                    raw,
+                    level: ScoreLevel::for_raw_with(raw, per_facet, levels),
+                    questions: per_facet,
+                });
+            }
 
-            facets.push(FacetScore {
-                facet: *facet,
-                raw,
-                level: facet_level(raw),
+            let questions = per_facet * domain.facets().len() as u8;
+            domains.push(DomainScore {
+                domain: *domain,
+                raw: domain_total as u8,
+                level: ScoreLevel::for_raw_with(domain_total as u8, questions, levels),
+                facets,
+                questions,
             });
         }
 
-        domains.push(DomainScore {
-            domain: *domain,
-            raw: domain_total as u8,
-            level: domain_level(domain_total as u8),
-            facets,
-        });
+        Ok(PersonalityProfile {
+            domains,
+            validity: Some(ValidityReport::assess(questions, values)),
+        })
     }
-
-    Ok(PersonalityProfile { domains })
 }
 
-/// Determine the level for a facet score (range 4-20).
-fn facet_level(raw: u8) -> ScoreLevel {
-    // Divide into roughly thirds:
-    // Low: 4-9 (6 values)
-    // Neutral: 10-14 (5 values)
-    // High: 15-20 (6 values)
-    match raw {
-        4..=9 => ScoreLevel::Low,
-        10..=14 => ScoreLevel::Neutral,
-        15..=20 => ScoreLevel::High,
-        _ => ScoreLevel::Neutral, // Should not happen with valid data
+impl PersonalityProfile {
+    /// Check that the profile could have come from [`calculate`]: every domain and facet
+    /// exactly once, raw scores within range and adding up, levels matching the scores and
+    /// plausible validity indices.
+    ///
+    /// Meant for profiles received from untrusted clients before they are stored.
+    pub fn validate(&self) -> Result<(), Error> {
+        self.validate_with(&ScoringOptions::default())
     }
-}
 
-/// Determine the level for a domain score (range 24-120).
-fn domain_level(raw: u8) -> ScoreLevel {
-    // Divide into roughly thirds:
-    // Range is 24-120, span is 96
-    // Low: 24-55 (32 values)
-    // Neutral: 56-87 (32 values)
-    // High: 88-120 (33 values)
-    match raw {
-        24..=55 => ScoreLevel::Low,
-        56..=87 => ScoreLevel::Neutral,
-        88..=120 => ScoreLevel::High,
-        _ => ScoreLevel::Neutral, // Should not happen with valid data
+    /// Like [`validate`](Self::validate), for profiles calculated with `options`.
+    pub fn validate_with(&self, options: &ScoringOptions) -> Result<(), Error> {
+        let invalid = |msg: String| Err(Error::InvalidProfile(msg));
+
+        if self.domains.len() != Domain::all().len() {
+            return invalid(format!("expected 5 domains, got {}", self.domains.len()));
+        }
+        let per_facet = self
+            .questions_per_facet()
+            .ok_or_else(|| Error::InvalidProfile("no facet scores".to_string()))?;
+        if !(1..=MAX_QUESTIONS_PER_FACET).contains(&per_facet) {
+            return invalid(format!("unsupported questions per facet: {per_facet}"));
+        }
+
+        for domain in Domain::all() {
+            let mut matching = self.domains.iter().filter(|d| d.domain == *domain);
+            let (Some(score), None) = (matching.next(), matching.next()) else {
+                return invalid(format!("expected one {domain:?} score"));
+            };
+            if score.facets.len() != domain.facets().len() {
+                return invalid(format!("expected 6 facets for {domain:?}"));
+            }
+
+            let mut total: u16 = 0;
+            for facet in domain.facets() {
+                let mut matching = score.facets.iter().filter(|f| f.facet == *facet);
+                let (Some(facet_score), None) = (matching.next(), matching.next()) else {
+                    return invalid(format!("expected one {facet:?} score"));
+                };
+                if facet_score.questions != per_facet {
+                    return invalid(format!("{facet:?} has a different question count"));
+                }
+                if !(per_facet..=per_facet * 5).contains(&facet_score.raw) {
+                    return invalid(format!("{facet:?} score {} out of range", facet_score.raw));
+                }
+                if facet_score.level
+                    != ScoreLevel::for_raw_with(facet_score.raw, per_facet, &options.levels)
+                {
+                    return invalid(format!("{facet:?} level doesn't match its score"));
+                }
+                total += u16::from(facet_score.raw);
+            }
+
+            let questions = per_facet * domain.facets().len() as u8;
+            if score.questions != questions {
+                return invalid(format!("{domain:?} has a wrong question count"));
+            }
+            if u16::from(score.raw) != total {
+                return invalid(format!("{domain:?} score isn't the sum of its facets"));
+            }
+            if score.level != ScoreLevel::for_raw_with(score.raw, questions, &options.levels) {
+                return invalid(format!("{domain:?} level doesn't match its score"));
+            }
+        }
+
+        if let Some(validity) = &self.validity {
+            let total_questions = usize::from(per_facet) * 30;
+            if validity.longstring > total_questions
+                || !(0.0..=2.0).contains(&validity.irv)
+                || validity.infrequency > 30
+            {
+                return invalid("validity indices out of range".to_string());
+            }
+        }
+
+        Ok(())
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "lang-en"))]
 mod tests {
     use super::*;
     use crate::inventory::Ipip120;
@@ -260,12 +500,162 @@ mod tests {
         assert!(matches!(result, Err(Error::InvalidAnswerValue(6))));
     }
 
+    #[test]
+    fn test_batch_matches_individual_scoring() {
+        let inventory = Ipip120::english();
+        let respondents: Vec<Vec<Answer>> = (0..20u8)
+            .map(|seed| {
+                inventory
+                    .questions()
+                    .iter()
+                    .enumerate()
+                    .map(|(i, q)| Answer {
+                        question_id: q.id.clone(),
+                        value: ((i as u8).wrapping_mul(7).wrapping_add(seed) % 5) + 1,
+                    })
+                    .collect()
+            })
+            .collect();
+
+        let batch = calculate_batch(&inventory, &respondents);
+        assert_eq!(batch.len(), respondents.len());
+        for (answers, profile) in respondents.iter().zip(batch) {
+            assert_eq!(profile.unwrap(), calculate(&inventory, answers).unwrap());
+        }
+
+        // Answer order doesn't matter
+        let mut shuffled = respondents[3].clone();
+        shuffled.reverse();
+        shuffled.swap(0, 57);
+        let batch = calculate_batch(&inventory, &[shuffled]);
+        assert_eq!(
+            batch[0].as_ref().unwrap(),
+            &calculate(&inventory, &respondents[3]).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_batch_errors_per_respondent() {
+        let inventory = Ipip120::english();
+        let valid = create_uniform_answers(&inventory, 4);
+        let mut invalid_value = valid.clone();
+        invalid_value[10].value = 0;
+        let mut missing = valid.clone();
+        missing[5].question_id = "unknown".to_string();
+        let missing_id = inventory.questions()[5].id.clone();
+
+        let batch = calculate_batch(
+            &inventory,
+            &[
+                valid.clone(),
+                invalid_value,
+                missing,
+                valid[1..].to_vec(),
+                valid,
+            ],
+        );
+        assert!(batch[0].is_ok());
+        assert!(matches!(batch[1], Err(Error::InvalidAnswerValue(0))));
+        assert!(matches!(&batch[2], Err(Error::MissingAnswer(id)) if *id == missing_id));
+        assert!(matches!(batch[3], Err(Error::InvalidAnswerCount(119))));
+        assert!(batch[4].is_ok());
+
+        // An unsupported inventory fails every respondent
+        let inventory = FirstQuestions::new(0);
+        let batch = calculate_batch(&inventory, &[Vec::new(), Vec::new()]);
+        assert!(
+            batch
+                .iter()
+                .all(|r| matches!(r, Err(Error::UnsupportedQuestionsPerFacet(0))))
+        );
+    }
+
+    /// Custom instrument with the first `per_facet` IPIP-NEO-120 questions of each facet.
+    struct FirstQuestions {
+        questions: Vec<crate::Question>,
+        per_facet: usize,
+    }
+
+    impl FirstQuestions {
+        fn new(per_facet: usize) -> Self {
+            let full = Ipip120::english();
+            let mut questions = Vec::new();
+            for domain in Domain::all() {
+                for facet in 1..=6 {
+                    questions.extend(
+                        full.questions()
+                            .iter()
+                            .filter(|q| q.domain == *domain && q.facet_index == facet)
+                            .take(per_facet)
+                            .cloned(),
+                    );
+                }
+            }
+            Self {
+                questions,
+                per_facet,
+            }
+        }
+    }
+
+    impl Inventory for FirstQuestions {
+        fn questions(&self) -> &[crate::Question] {
+            &self.questions
+        }
+
+        fn questions_per_facet(&self) -> usize {
+            self.per_facet
+        }
+    }
+
+    #[test]
+    fn test_custom_inventory() {
+        let inventory = FirstQuestions::new(1);
+        let answers: Vec<Answer> = inventory
+            .questions()
+            .iter()
+            .map(|q| Answer {
+                question_id: q.id.clone(),
+                value: if q.reversed { 5 } else { 1 },
+            })
+            .collect();
+        let profile = calculate(&inventory, &answers).unwrap();
+        for domain_score in &profile.domains {
+            assert_eq!((domain_score.raw, domain_score.questions), (6, 6));
+            assert_eq!(domain_score.percentage(), 0.0);
+            assert_eq!(domain_score.level, ScoreLevel::Low);
+        }
+
+        // Claiming more questions per facet than the inventory has
+        let mut inventory = FirstQuestions::new(2);
+        let answers: Vec<Answer> = inventory
+            .questions()
+            .iter()
+            .map(|q| Answer {
+                question_id: q.id.clone(),
+                value: 3,
+            })
+            .collect();
+        inventory.per_facet = 3;
+        assert!(matches!(
+            calculate(&inventory, &answers),
+            Err(Error::InvalidFacetQuestionCount(_, 1, 2))
+        ));
+
+        inventory.per_facet = 9;
+        assert!(matches!(
+            calculate(&inventory, &answers),
+            Err(Error::UnsupportedQuestionsPerFacet(9))
+        ));
+    }
+
     #[test]
     fn test_percentage_calculations() {
         let facet_score = FacetScore {
             facet: Facet::Anxiety,
             raw: 12,
             level: ScoreLevel::Neutral,
+            questions: 4,
         };
         assert!((facet_score.percentage() - 50.0).abs() < 0.01);
 
@@ -273,6 +663,7 @@ mod tests {
             facet: Facet::Anxiety,
             raw: 4,
             level: ScoreLevel::Low,
+            questions: 4,
         };
         assert!((facet_min.percentage() - 0.0).abs() < 0.01);
 
@@ -280,7 +671,224 @@ mod tests {
             facet: Facet::Anxiety,
             raw: 20,
             level: ScoreLevel::High,
+            questions: 4,
         };
         assert!((facet_max.percentage() - 100.0).abs() < 0.01);
     }
+
+    #[test]
+    fn test_score_bands() {
+        assert_eq!(ScoreBand::VeryLow.range(4), 4..=7);
+        assert_eq!(ScoreBand::Low.range(4), 8..=10);
+        assert_eq!(ScoreBand::Neutral.range(4), 11..=13);
+        assert_eq!(ScoreBand::High.range(4), 14..=16);
+        assert_eq!(ScoreBand::VeryHigh.range(4), 17..=20);
+        assert_eq!(ScoreBand::for_raw(72, 24), ScoreBand::Neutral);
+        assert_eq!(ScoreBand::for_raw(120, 24), ScoreBand::VeryHigh);
+
+        for questions in [4, 24, 2, 12] {
+            let bands: Vec<_> = ScoreBand::ALL.iter().map(|b| b.range(questions)).collect();
+            assert_eq!(*bands[0].start(), questions);
+            assert_eq!(*bands[4].end(), questions * 5);
+            for pair in bands.windows(2) {
+                assert_eq!(*pair[0].end() + 1, *pair[1].start());
+            }
+        }
+
+        let inventory = Ipip120::english();
+        let profile = calculate(&inventory, &create_uniform_answers(&inventory, 3)).unwrap();
+        assert!(
+            profile
+                .domains
+                .iter()
+                .all(|d| d.band() == ScoreBand::Neutral)
+        );
+    }
+
+    #[test]
+    fn test_custom_level_cutoffs() {
+        let inventory = Ipip120::english();
+        let cutoffs = LevelCutoffs::new(30.0, 70.0).unwrap();
+        let options = ScoringOptions { levels: cutoffs };
+
+        // Facet bands for 4 questions (range 4-20): 30% is 8.8 and 70% is 15.2
+        assert_eq!(ScoreLevel::Low.range_with(4, &cutoffs), 4..=8);
+        assert_eq!(ScoreLevel::Neutral.range_with(4, &cutoffs), 9..=15);
+        assert_eq!(ScoreLevel::High.range_with(4, &cutoffs), 16..=20);
+
+        // Every item scored 2 (25% of the range), except one anxiety item scored 3
+        let mut bumped = false;
+        let answers: Vec<Answer> = inventory
+            .questions()
+            .iter()
+            .map(|q| {
+                let mut score = 2;
+                if !bumped && q.domain == Domain::Neuroticism && q.facet_index == 1 {
+                    score = 3;
+                    bumped = true;
+                }
+                Answer {
+                    question_id: q.id.clone(),
+                    value: if q.reversed { 6 - score } else { score },
+                }
+            })
+            .collect();
+        let custom = calculate_with(&inventory, &answers, &options).unwrap();
+        assert!(custom.domains.iter().all(|d| d.level == ScoreLevel::Low));
+
+        // Raw 9 is low in thirds but neutral from 30%
+        let thirds = calculate(&inventory, &answers).unwrap();
+        let anxiety = |p: &PersonalityProfile| p.facet_score(Facet::Anxiety).unwrap().clone();
+        assert_eq!(anxiety(&thirds).raw, 9);
+        assert_eq!(anxiety(&thirds).level, ScoreLevel::Low);
+        assert_eq!(anxiety(&custom).level, ScoreLevel::Neutral);
+
+        // Levels are checked against the cutoffs the profile was scored with
+        assert!(custom.validate_with(&options).is_ok());
+        assert!(custom.validate().is_err());
+    }
+
+    #[test]
+    fn test_invalid_level_cutoffs() {
+        for (neutral, high) in [(0.0, 50.0), (60.0, 40.0), (50.0, 50.0), (30.0, 120.0)] {
+            assert!(matches!(
+                LevelCutoffs::new(neutral, high),
+                Err(Error::InvalidLevelCutoffs(..))
+            ));
+        }
+        assert!(LevelCutoffs::new(30.0, 100.0).is_ok());
+    }
+
+    #[test]
+    fn test_level_bands() {
+        assert_eq!(ScoreLevel::for_raw(9, 4), ScoreLevel::Low);
+        assert_eq!(ScoreLevel::for_raw(10, 4), ScoreLevel::Neutral);
+        assert_eq!(ScoreLevel::for_raw(15, 4), ScoreLevel::High);
+        assert_eq!(ScoreLevel::for_raw(55, 24), ScoreLevel::Low);
+        assert_eq!(ScoreLevel::for_raw(56, 24), ScoreLevel::Neutral);
+        assert_eq!(ScoreLevel::for_raw(88, 24), ScoreLevel::High);
+        assert_eq!(ScoreLevel::Low.facet_range(), 4..=9);
+        assert_eq!(ScoreLevel::High.domain_range(), 88..=120);
+
+        // Thirds match the exact integer split for every supported question count
+        for questions in 1..=48u8 {
+            let q = u16::from(questions);
+            assert_eq!(
+                *ScoreLevel::Neutral.range(questions).start(),
+                (7 * q).div_ceil(3) as u8
+            );
+            assert_eq!(
+                *ScoreLevel::High.range(questions).start(),
+                (11 * q).div_ceil(3) as u8
+            );
+        }
+
+        // Bands are contiguous and cover the full score range, for the full and short forms
+        for questions in [4, 24, 2, 12] {
+            let bands: Vec<_> = ScoreLevel::ALL.iter().map(|l| l.range(questions)).collect();
+            assert_eq!(*bands[0].start(), questions);
+            assert_eq!(*bands[2].end(), questions * 5);
+            for pair in bands.windows(2) {
+                assert_eq!(*pair[0].end() + 1, *pair[1].start());
+            }
+        }
+    }
+
+    /// Golden test against answer sheets scored like the Alheimsins reference
+    /// implementation (see `testdata/generate_reference.js`).
+    ///
+    /// Only raw scores are compared: the reference splits levels at the scale
+    /// midpoint, while this crate bands scores into thirds.
+    #[test]
+    fn test_matches_reference_scores() {
+        let corpus: serde_json::Value =
+            serde_json::from_str(include_str!("../testdata/reference_scores.json")).unwrap();
+        let inventory = Ipip120::english();
+
+        for sheet in corpus.as_array().unwrap() {
+            let name = sheet["name"].as_str().unwrap();
+            let answers: Vec<Answer> = inventory
+                .questions()
+                .iter()
+                .zip(sheet["answers"].as_array().unwrap())
+                .map(|(q, value)| Answer {
+                    question_id: q.id.clone(),
+                    value: value.as_u64().unwrap() as u8,
+                })
+                .collect();
+            let profile = calculate(&inventory, &answers).unwrap();
+
+            for domain_score in &profile.domains {
+                let expected = &sheet["expected"][domain_score.domain.code()];
+                assert_eq!(
+                    Some(u64::from(domain_score.raw)),
+                    expected["score"].as_u64(),
+                    "{name}: {:?}",
+                    domain_score.domain
+                );
+                for facet_score in &domain_score.facets {
+                    let expected = &expected["facet"][facet_score.facet.index().to_string()];
+                    assert_eq!(
+                        Some(u64::from(facet_score.raw)),
+                        expected["score"].as_u64(),
+                        "{name}: {:?}",
+                        facet_score.facet
+                    );
+                    assert_eq!(expected["count"].as_u64(), Some(4));
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_calculated_profile_validates() {
+        let inventory = Ipip120::english();
+        let profile = calculate(&inventory, &create_uniform_answers(&inventory, 2)).unwrap();
+        assert!(profile.validate().is_ok());
+
+        let short = crate::Ipip120Subset60::english();
+        let answers: Vec<Answer> = short
+            .questions()
+            .iter()
+            .map(|q| Answer {
+                question_id: q.id.clone(),
+                value: 4,
+            })
+            .collect();
+        assert!(calculate(&short, &answers).unwrap().validate().is_ok());
+    }
+
+    #[test]
+    fn test_validate_rejects_tampered_profiles() {
+        let inventory = Ipip120::english();
+        let profile = calculate(&inventory, &create_uniform_answers(&inventory, 3)).unwrap();
+
+        let mut missing_domain = profile.clone();
+        missing_domain.domains.pop();
+        assert!(matches!(
+            missing_domain.validate(),
+            Err(Error::InvalidProfile(_))
+        ));
+
+        let mut duplicate_facet = profile.clone();
+        duplicate_facet.domains[0].facets[1] = duplicate_facet.domains[0].facets[0].clone();
+        assert!(duplicate_facet.validate().is_err());
+
+        let mut out_of_range = profile.clone();
+        out_of_range.domains[0].facets[0].raw = 21;
+        assert!(out_of_range.validate().is_err());
+
+        // In range, but the domain total no longer adds up
+        let mut wrong_sum = profile.clone();
+        wrong_sum.domains[0].facets[0].raw = 13;
+        assert!(wrong_sum.validate().is_err());
+
+        let mut wrong_level = profile.clone();
+        wrong_level.domains[1].level = ScoreLevel::High;
+        assert!(wrong_level.validate().is_err());
+
+        let mut wrong_validity = profile;
+        wrong_validity.validity.as_mut().unwrap().irv = f32::NAN;
+        assert!(wrong_validity.validate().is_err());
+    }
 }
@@ -0,0 +1,198 @@
+//! Short 60-question subset of the inventory, for a test of about seven minutes.
+//!
+//! The subset keeps two of the four IPIP-NEO-120 questions of every facet: the first
+//! positively and the first negatively keyed one where a facet has both, so agreeing with
+//! everything doesn't inflate scores. Being a subset, it exists in every language the full
+//! inventory does and picks up text overrides applied to it.
+//!
+//! This is not the published IPIP-NEO-60 (Maples-Keller et al., 2019), whose items were
+//! chosen by their psychometric properties. The selection here is mechanical and hasn't
+//! been validated: its reliability is unknown and lower than the full inventory's, and
+//! its levels only divide the score range, so treat its scores as a rough estimate.
+
+use crate::Error;
+use crate::inventory::{Inventory, Ipip120};
+use crate::types::{Domain, Question};
+
+/// Unvalidated 60-question subset of [`Ipip120`], 12 questions per domain and 2 per facet.
+///
+/// Facet scores range 2-10 and domain scores 12-60, so compare profiles by percentage
+/// rather than raw score.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Ipip120Subset60 {
+    questions: Vec<Question>,
+    lang: String,
+    scale_labels: [String; 5],
+}
+
+impl Ipip120Subset60 {
+    /// Instrument id used in [`CommonProfile`](crate::CommonProfile).
+    pub const INSTRUMENT: &'static str = "ipip-120-subset-60";
+
+    /// Number of questions per facet.
+    pub const QUESTIONS_PER_FACET: u8 = 2;
+
+    /// Load the subset for a specific language.
+    ///
+    /// Supports the same languages as [`Ipip120::new`].
+    pub fn new(lang: &str) -> Result<Self, Error> {
+        Ok(Self::from_full(&Ipip120::new(lang)?))
+    }
+
+    /// Subset of a full inventory, keeping its language, wording and scale labels.
+    pub fn from_full(full: &Ipip120) -> Self {
+        let mut kept = Vec::with_capacity(60);
+        for domain in Domain::all() {
+            for facet in 1..=6 {
+                let facet_questions: Vec<&Question> = full
+                    .questions()
+                    .iter()
+                    .filter(|q| q.domain == *domain && q.facet_index == facet)
+                    .collect();
+                let plus = facet_questions.iter().find(|q| !q.reversed);
+                let minus = facet_questions.iter().find(|q| q.reversed);
+                match (plus, minus) {
+                    (Some(plus), Some(minus)) => kept.extend([plus.id.as_str(), minus.id.as_str()]),
+                    _ => kept.extend(facet_questions.iter().take(2).map(|q| q.id.as_str())),
+                }
+            }
+        }
+
+        // Keep the full inventory's order, which alternates domains
+        let questions = full
+            .questions()
+            .iter()
+            .filter(|q| kept.contains(&q.id.as_str()))
+            .cloned()
+            .collect();
+
+        Self {
+            questions,
+            lang: full.lang().to_string(),
+            scale_labels: full.scale_labels().clone(),
+        }
+    }
+
+    /// Load the English subset.
+    #[cfg(feature = "lang-en")]
+    pub fn english() -> Self {
+        Self::from_full(&Ipip120::english())
+    }
+
+    /// Load the Russian subset.
+    #[cfg(feature = "lang-ru")]
+    pub fn russian() -> Self {
+        Self::from_full(&Ipip120::russian())
+    }
+
+    /// Get all questions in the inventory.
+    pub fn questions(&self) -> &[Question] {
+        &self.questions
+    }
+
+    /// Get a question by its ID.
+    pub fn question_by_id(&self, id: &str) -> Option<&Question> {
+        self.questions.iter().find(|q| q.id == id)
+    }
+
+    /// Get the language of this inventory.
+    pub fn lang(&self) -> &str {
+        &self.lang
+    }
+
+    /// Labels of the five answer options, for values 1 to 5.
+    pub fn scale_labels(&self) -> &[String; 5] {
+        &self.scale_labels
+    }
+
+    /// Get the number of questions.
+    pub fn len(&self) -> usize {
+        self.questions.len()
+    }
+
+    /// Check if the inventory is empty.
+    pub fn is_empty(&self) -> bool {
+        self.questions.is_empty()
+    }
+}
+
+impl Inventory for Ipip120Subset60 {
+    fn questions(&self) -> &[Question] {
+        &self.questions
+    }
+
+    fn questions_per_facet(&self) -> usize {
+        Self::QUESTIONS_PER_FACET.into()
+    }
+}
+
+#[cfg(all(test, feature = "lang-en", feature = "lang-ru"))]
+mod tests {
+    use super::*;
+    use crate::{Answer, ScoreLevel, calculate};
+
+    #[test]
+    fn test_question_distribution() {
+        for inventory in [Ipip120Subset60::english(), Ipip120Subset60::russian()] {
+            assert_eq!(inventory.len(), 60);
+            for domain in Domain::all() {
+                for facet in 1..=6 {
+                    let questions: Vec<&Question> = inventory
+                        .questions()
+                        .iter()
+                        .filter(|q| q.domain == *domain && q.facet_index == facet)
+                        .collect();
+                    assert_eq!(questions.len(), 2, "{domain:?} facet {facet}");
+                }
+            }
+        }
+
+        // Same items in every language
+        let ids = |inventory: Ipip120Subset60| -> Vec<String> {
+            inventory.questions().iter().map(|q| q.id.clone()).collect()
+        };
+        assert_eq!(
+            ids(Ipip120Subset60::english()),
+            ids(Ipip120Subset60::russian())
+        );
+    }
+
+    #[test]
+    fn test_short_form_scoring() {
+        let inventory = Ipip120Subset60::english();
+        let answers: Vec<Answer> = inventory
+            .questions()
+            .iter()
+            .map(|q| Answer {
+                question_id: q.id.clone(),
+                value: if q.reversed { 1 } else { 5 },
+            })
+            .collect();
+        let profile = calculate(&inventory, &answers).unwrap();
+
+        for domain_score in &profile.domains {
+            assert_eq!(domain_score.raw, 60);
+            assert_eq!(domain_score.percentage(), 100.0);
+            assert_eq!(domain_score.level, ScoreLevel::High);
+            for facet_score in &domain_score.facets {
+                assert_eq!((facet_score.raw, facet_score.questions), (10, 2));
+                assert_eq!(facet_score.percentage(), 100.0);
+            }
+        }
+
+        // Full-length answers don't fit the short form
+        let full = Ipip120::english();
+        let answers: Vec<Answer> = full
+            .questions()
+            .iter()
+            .map(|q| Answer {
+                question_id: q.id.clone(),
+                value: 3,
+            })
+            .collect();
+        assert!(matches!(
+            calculate(&inventory, &answers),
+            Err(Error::InvalidAnswerCount(120))
+        ));
+    }
+}
@@ -0,0 +1,257 @@
+//! Response times and speed-based validity checks.
+//!
+//! A [`TimedAnswer`] is an [`Answer`] with the time the respondent took for it, when the
+//! test interface measured one. [`SpeedReport`] looks at those times for protocols that
+//! were clicked through faster than the items can be read (a median under two seconds per
+//! item, after Huang et al., 2012) and for single items answered unusually fast or slow
+//! compared with the rest of the protocol.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::Error;
+use crate::inventory::Inventory;
+use crate::scoring::calculate;
+use crate::types::{Answer, PersonalityProfile};
+
+/// Answers faster than this can't have followed reading the item.
+const FAST_ANSWER_MS: u32 = 1_000;
+
+/// Median response time below which a whole protocol counts as too fast.
+const TOO_FAST_MEDIAN_MS: u32 = 2_000;
+
+/// Timed answers needed before a protocol is judged by its median.
+const MIN_TIMED_ANSWERS: usize = 10;
+
+/// Robust z-score (on log times) beyond which an item is an outlier.
+const OUTLIER_Z: f64 = 3.5;
+
+/// An answer with its response time.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TimedAnswer {
+    /// The question ID this answer is for.
+    pub question_id: String,
+    /// The response value (1-5).
+    pub value: u8,
+    /// Milliseconds from showing the question to the answer, if measured.
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub response_ms: Option<u32>,
+}
+
+impl TimedAnswer {
+    /// The answer without its timing.
+    pub fn to_answer(&self) -> Answer {
+        Answer {
+            question_id: self.question_id.clone(),
+            value: self.value,
+        }
+    }
+}
+
+impl From<Answer> for TimedAnswer {
+    fn from(answer: Answer) -> Self {
+        TimedAnswer {
+            question_id: answer.question_id,
+            value: answer.value,
+            response_ms: None,
+        }
+    }
+}
+
+/// Direction of a response time outlier.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum OutlierKind {
+    Fast,
+    Slow,
+}
+
+/// An item answered much faster or slower than the rest of the protocol.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TimingOutlier {
+    pub question_id: String,
+    pub response_ms: u32,
+    pub kind: OutlierKind,
+}
+
+/// Speed indices of one protocol.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct SpeedReport {
+    /// Answers that came with a response time.
+    pub timed: usize,
+    /// Median response time; `None` without timed answers.
+    pub median_ms: Option<u32>,
+    /// Answers given in under a second.
+    pub fast_answers: usize,
+    /// The protocol as a whole was answered implausibly fast.
+    pub too_fast: bool,
+    /// Items far outside the protocol's usual pace, in answer order.
+    pub outliers: Vec<TimingOutlier>,
+}
+
+impl SpeedReport {
+    /// Speed indices of the answers that have a response time.
+    pub fn from_answers(answers: &[TimedAnswer]) -> Self {
+        let timed: Vec<(&str, u32)> = answers
+            .iter()
+            .filter_map(|a| Some((a.question_id.as_str(), a.response_ms?)))
+            .collect();
+
+        let mut sorted: Vec<u32> = timed.iter().map(|&(_, ms)| ms).collect();
+        sorted.sort_unstable();
+        let median_ms = median(&sorted);
+        let fast_answers = sorted.iter().filter(|&&ms| ms < FAST_ANSWER_MS).count();
+        let too_fast =
+            timed.len() >= MIN_TIMED_ANSWERS && median_ms.is_some_and(|ms| ms < TOO_FAST_MEDIAN_MS);
+
+        // Response times are skewed, so compare log times with the median and the median
+        // absolute deviation rather than mean and SD
+        let log = |ms: u32| f64::from(ms.max(1)).ln();
+        let mut outliers = Vec::new();
+        if timed.len() >= MIN_TIMED_ANSWERS
+            && let Some(center) = median_ms.map(log)
+        {
+            let mut deviations: Vec<f64> =
+                sorted.iter().map(|&ms| (log(ms) - center).abs()).collect();
+            deviations.sort_by(f64::total_cmp);
+            let mad = deviations[deviations.len() / 2];
+            if mad > 0.0 {
+                for &(question_id, ms) in &timed {
+                    let z = 0.6745 * (log(ms) - center) / mad;
+                    let kind = if z > OUTLIER_Z {
+                        OutlierKind::Slow
+                    } else if z < -OUTLIER_Z {
+                        OutlierKind::Fast
+                    } else {
+                        continue;
+                    };
+                    outliers.push(TimingOutlier {
+                        question_id: question_id.to_string(),
+                        response_ms: ms,
+                        kind,
+                    });
+                }
+            }
+        }
+
+        SpeedReport {
+            timed: timed.len(),
+            median_ms,
+            fast_answers,
+            too_fast,
+            outliers,
+        }
+    }
+}
+
+/// Median of sorted values (the upper one for an even count).
+fn median(sorted: &[u32]) -> Option<u32> {
+    sorted.get(sorted.len() / 2).copied()
+}
+
+/// Calculate the personality profile from timed answers.
+///
+/// Scores like [`calculate`]; the profile's validity report additionally carries a
+/// [`SpeedReport`] and flags protocols that were answered too fast.
+pub fn calculate_timed<I: Inventory + ?Sized>(
+    inventory: &I,
+    answers: &[TimedAnswer],
+) -> Result<PersonalityProfile, Error> {
+    let plain: Vec<Answer> = answers.iter().map(TimedAnswer::to_answer).collect();
+    let mut profile = calculate(inventory, &plain)?;
+    if let Some(validity) = &mut profile.validity {
+        validity.add_speed(SpeedReport::from_answers(answers));
+    }
+    Ok(profile)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn timed(times: &[u32]) -> Vec<TimedAnswer> {
+        times
+            .iter()
+            .enumerate()
+            .map(|(i, &ms)| TimedAnswer {
+                question_id: format!("q{i}"),
+                value: 3,
+                response_ms: Some(ms),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_clicked_through_protocol_is_too_fast() {
+        let report = SpeedReport::from_answers(&timed(&[600; 20]));
+        assert_eq!(report.timed, 20);
+        assert_eq!(report.median_ms, Some(600));
+        assert_eq!(report.fast_answers, 20);
+        assert!(report.too_fast);
+    }
+
+    #[test]
+    fn test_outliers_in_both_directions() {
+        let mut times: Vec<u32> = (0..30).map(|i| 4_000 + (i % 5) * 400).collect();
+        times[3] = 90_000;
+        times[7] = 150;
+        let report = SpeedReport::from_answers(&timed(&times));
+
+        assert!(!report.too_fast);
+        assert_eq!(
+            report
+                .outliers
+                .iter()
+                .map(|o| (o.question_id.as_str(), o.kind))
+                .collect::<Vec<_>>(),
+            vec![("q3", OutlierKind::Slow), ("q7", OutlierKind::Fast)]
+        );
+    }
+
+    #[test]
+    fn test_untimed_answers_are_ignored() {
+        let mut answers = timed(&[500; 5]);
+        answers.extend((0..20).map(|i| {
+            TimedAnswer::from(Answer {
+                question_id: format!("u{i}"),
+                value: 3,
+            })
+        }));
+        let report = SpeedReport::from_answers(&answers);
+
+        assert_eq!(report.timed, 5);
+        // Too few timed answers to judge the whole protocol
+        assert!(!report.too_fast);
+        assert!(report.outliers.is_empty());
+    }
+
+    #[cfg(feature = "lang-en")]
+    #[test]
+    fn test_calculate_timed_flags_speeding() {
+        use crate::inventory::Ipip120;
+        use crate::validity::ValidityFlag;
+
+        let inventory = Ipip120::english();
+        let answers: Vec<TimedAnswer> = inventory
+            .questions()
+            .iter()
+            .enumerate()
+            .map(|(i, q)| TimedAnswer {
+                question_id: q.id.clone(),
+                value: (i % 5) as u8 + 1,
+                response_ms: Some(700),
+            })
+            .collect();
+        let profile = calculate_timed(&inventory, &answers).unwrap();
+        let validity = profile.validity.unwrap();
+
+        assert!(validity.speed.unwrap().too_fast);
+        assert!(validity.flags.contains(&ValidityFlag::TooFast));
+    }
+}
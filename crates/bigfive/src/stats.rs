@@ -0,0 +1,43 @@
+//! Statistics over a dataset of profiles, e.g. for the structural validation of a new
+//! language version of the inventory.
+//!
+//! ```
+//! # #[cfg(feature = "lang-en")] {
+//! use bigfive::stats::correlation_matrix;
+//! use bigfive::{Answer, Domain, Ipip120, ScaleId, calculate};
+//!
+//! let inventory = Ipip120::english();
+//! let profiles: Vec<_> = (1..=5)
+//!     .map(|value| {
+//!         let answers: Vec<Answer> = inventory
+//!             .questions()
+//!             .iter()
+//!             .map(|q| Answer {
+//!                 question_id: q.id.clone(),
+//!                 value: if q.reversed { 6 - value } else { value },
+//!             })
+//!             .collect();
+//!         calculate(&inventory, &answers).unwrap()
+//!     })
+//!     .collect();
+//!
+//! let matrix = correlation_matrix(&profiles).unwrap();
+//! let n = ScaleId::Domain(Domain::Neuroticism);
+//! assert_eq!(matrix.get(n, n), Some(1.0));
+//! assert!(matrix.to_csv().starts_with(",N,E,"));
+//! # }
+//! ```
+
+pub use crate::psychometrics::CorrelationMatrix as Matrix;
+
+use crate::types::PersonalityProfile;
+
+/// Pearson correlations between the 5 domain and 30 facet scores of a dataset of profiles,
+/// with [`Matrix::to_csv`] for further analysis.
+///
+/// Same as [`CorrelationMatrix::from_profiles`](crate::CorrelationMatrix::from_profiles):
+/// profiles missing a domain or facet are skipped, and there is no matrix (`None`) with
+/// fewer than two profiles left.
+pub fn correlation_matrix(profiles: &[PersonalityProfile]) -> Option<Matrix> {
+    Matrix::from_profiles(profiles)
+}
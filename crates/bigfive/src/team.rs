@@ -0,0 +1,292 @@
+//! Aggregate profile of a team or other group of people who took the test.
+//!
+//! Per domain and facet, [`TeamProfile`] has the mean and spread of the members' scores and
+//! a diversity index over their levels, so a team that splits evenly into low, neutral and
+//! high scorers stands apart from one where everybody is alike. Members far from the rest of
+//! the team on a domain are listed as [`TeamOutlier`]s.
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::types::{Domain, Facet, PersonalityProfile, ScoreLevel};
+
+/// Distance in percentage points from the mean of the other members from which a member
+/// counts as an outlier on a domain.
+pub const OUTLIER_POINTS: f32 = 25.0;
+
+/// Team members needed before outliers are looked for; with two, neither is "the odd one".
+pub const MIN_OUTLIER_TEAM: usize = 3;
+
+/// Members' scores on one domain or facet.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TeamScale {
+    /// Number of members with this score.
+    pub members: usize,
+    /// Mean score as a percentage of the range.
+    pub mean: f32,
+    /// Standard deviation of the scores in percentage points (0 for a single member).
+    pub spread: f32,
+    /// Lowest score as a percentage of the range.
+    pub min: f32,
+    /// Highest score as a percentage of the range.
+    pub max: f32,
+    /// Blau's index over the members' levels: the chance that two members picked at
+    /// random differ in level. 0 when all share one level, at most 2/3 for an even split.
+    pub diversity: f32,
+}
+
+impl TeamScale {
+    fn from_scores(scores: &[(f32, ScoreLevel)]) -> Option<Self> {
+        if scores.is_empty() {
+            return None;
+        }
+        let n = scores.len() as f64;
+        let mean = scores.iter().map(|(s, _)| f64::from(*s)).sum::<f64>() / n;
+        let variance = scores
+            .iter()
+            .map(|(s, _)| (f64::from(*s) - mean).powi(2))
+            .sum::<f64>()
+            / n;
+        let share = |level| scores.iter().filter(|(_, l)| *l == level).count() as f64 / n;
+        let concentration: f64 = [ScoreLevel::Low, ScoreLevel::Neutral, ScoreLevel::High]
+            .into_iter()
+            .map(|level| share(level).powi(2))
+            .sum();
+
+        Some(TeamScale {
+            members: scores.len(),
+            mean: mean as f32,
+            spread: variance.sqrt() as f32,
+            min: scores.iter().map(|(s, _)| *s).fold(f32::INFINITY, f32::min),
+            max: scores
+                .iter()
+                .map(|(s, _)| *s)
+                .fold(f32::NEG_INFINITY, f32::max),
+            diversity: (1.0 - concentration) as f32,
+        })
+    }
+
+    /// Distance between the lowest and highest score in percentage points.
+    pub fn range(&self) -> f32 {
+        self.max - self.min
+    }
+}
+
+/// A member far from the rest of the team on a domain.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TeamOutlier {
+    /// Index of the member in the profiles the team was built from.
+    pub member: usize,
+    pub domain: Domain,
+    /// The member's score as a percentage of the range.
+    pub score: f32,
+    /// Mean score of all other members.
+    pub others_mean: f32,
+}
+
+impl TeamOutlier {
+    /// Whether the member scores above the rest of the team.
+    pub fn is_above(&self) -> bool {
+        self.score > self.others_mean
+    }
+}
+
+/// Mean, spread and diversity of a group's scores, with its outlier members.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct TeamProfile {
+    /// Number of profiles the team was built from.
+    pub members: usize,
+    /// Per domain, in [`Domain::all`] order; domains no member has are left out.
+    pub domains: Vec<(Domain, TeamScale)>,
+    /// Per facet, in domain then facet order; facets no member has are left out.
+    pub facets: Vec<(Facet, TeamScale)>,
+    /// Members at least [`OUTLIER_POINTS`] from the others' mean, by domain then member.
+    pub outliers: Vec<TeamOutlier>,
+}
+
+impl TeamProfile {
+    /// Aggregate the profiles of a team's members. Returns `None` for an empty team.
+    ///
+    /// Scores are compared as percentages of their range, so members may have taken
+    /// different inventories.
+    pub fn from_profiles(profiles: &[PersonalityProfile]) -> Option<Self> {
+        if profiles.is_empty() {
+            return None;
+        }
+
+        let mut domains = Vec::new();
+        let mut facets = Vec::new();
+        let mut outliers = Vec::new();
+        for &domain in Domain::all() {
+            let scores: Vec<(usize, f32, ScoreLevel)> = profiles
+                .iter()
+                .enumerate()
+                .filter_map(|(member, p)| {
+                    let score = p.domain_score(domain)?;
+                    Some((member, score.percentage(), score.level))
+                })
+                .collect();
+            let levels: Vec<(f32, ScoreLevel)> = scores.iter().map(|&(_, s, l)| (s, l)).collect();
+            let Some(scale) = TeamScale::from_scores(&levels) else {
+                continue;
+            };
+            domains.push((domain, scale));
+            outliers.extend(domain_outliers(domain, &scores));
+
+            for &facet in domain.facets() {
+                let levels: Vec<(f32, ScoreLevel)> = profiles
+                    .iter()
+                    .filter_map(|p| p.facet_score(facet))
+                    .map(|s| (s.percentage(), s.level))
+                    .collect();
+                if let Some(scale) = TeamScale::from_scores(&levels) {
+                    facets.push((facet, scale));
+                }
+            }
+        }
+
+        Some(TeamProfile {
+            members: profiles.len(),
+            domains,
+            facets,
+            outliers,
+        })
+    }
+
+    /// Team scores on a domain.
+    pub fn domain(&self, domain: Domain) -> Option<&TeamScale> {
+        self.domains
+            .iter()
+            .find(|(d, _)| *d == domain)
+            .map(|(_, scale)| scale)
+    }
+
+    /// Team scores on a facet.
+    pub fn facet(&self, facet: Facet) -> Option<&TeamScale> {
+        self.facets
+            .iter()
+            .find(|(f, _)| *f == facet)
+            .map(|(_, scale)| scale)
+    }
+
+    /// Indices of members who are an outlier on at least one domain, ascending.
+    pub fn outlier_members(&self) -> Vec<usize> {
+        let mut members: Vec<usize> = self.outliers.iter().map(|o| o.member).collect();
+        members.sort_unstable();
+        members.dedup();
+        members
+    }
+}
+
+/// Members of one domain scoring far from the mean of everyone else.
+fn domain_outliers(domain: Domain, scores: &[(usize, f32, ScoreLevel)]) -> Vec<TeamOutlier> {
+    if scores.len() < MIN_OUTLIER_TEAM {
+        return Vec::new();
+    }
+    let total: f32 = scores.iter().map(|(_, s, _)| s).sum();
+    let others = (scores.len() - 1) as f32;
+    scores
+        .iter()
+        .map(|&(member, score, _)| TeamOutlier {
+            member,
+            domain,
+            score,
+            others_mean: (total - score) / others,
+        })
+        .filter(|o| (o.score - o.others_mean).abs() >= OUTLIER_POINTS)
+        .collect()
+}
+
+#[cfg(all(test, feature = "lang-en"))]
+mod tests {
+    use super::*;
+    use crate::inventory::Ipip120;
+    use crate::scoring::calculate;
+    use crate::types::Answer;
+
+    /// Profile answering every item of each domain with the given score (N, E, O, A, C).
+    fn profile(scores: [u8; 5]) -> PersonalityProfile {
+        let inventory = Ipip120::english();
+        let answers: Vec<Answer> = inventory
+            .questions()
+            .iter()
+            .map(|q| {
+                let index = Domain::all().iter().position(|d| *d == q.domain).unwrap();
+                let score = scores[index];
+                Answer {
+                    question_id: q.id.clone(),
+                    value: if q.reversed { 6 - score } else { score },
+                }
+            })
+            .collect();
+        calculate(&inventory, &answers).unwrap()
+    }
+
+    #[test]
+    fn test_empty_and_single() {
+        assert_eq!(TeamProfile::from_profiles(&[]), None);
+
+        let team = TeamProfile::from_profiles(&[profile([2, 4, 3, 5, 1])]).unwrap();
+        assert_eq!(team.members, 1);
+        assert_eq!(team.domains.len(), 5);
+        assert_eq!(team.facets.len(), 30);
+        let extraversion = team.domain(Domain::Extraversion).unwrap();
+        assert_eq!(extraversion.mean, 75.0);
+        assert_eq!(extraversion.spread, 0.0);
+        assert_eq!(extraversion.diversity, 0.0);
+        assert!(team.outliers.is_empty());
+    }
+
+    #[test]
+    fn test_mean_spread_and_diversity() {
+        let team = TeamProfile::from_profiles(&[
+            profile([1, 3, 3, 3, 3]),
+            profile([3, 3, 3, 3, 3]),
+            profile([5, 3, 3, 3, 3]),
+        ])
+        .unwrap();
+
+        let neuroticism = team.domain(Domain::Neuroticism).unwrap();
+        assert_eq!(neuroticism.mean, 50.0);
+        assert_eq!(neuroticism.range(), 100.0);
+        assert!((neuroticism.spread - 40.82).abs() < 0.01);
+        // One member per level
+        assert!((neuroticism.diversity - 2.0 / 3.0).abs() < 0.001);
+
+        let anxiety = team.facet(Facet::Anxiety).unwrap();
+        assert_eq!(anxiety.members, 3);
+        assert!((anxiety.diversity - 2.0 / 3.0).abs() < 0.001);
+
+        let openness = team.domain(Domain::Openness).unwrap();
+        assert_eq!(openness.spread, 0.0);
+        assert_eq!(openness.diversity, 0.0);
+    }
+
+    #[test]
+    fn test_outliers() {
+        let team = TeamProfile::from_profiles(&[
+            profile([3, 3, 3, 3, 3]),
+            profile([3, 3, 3, 3, 3]),
+            profile([3, 3, 3, 3, 3]),
+            profile([3, 5, 3, 1, 3]),
+        ])
+        .unwrap();
+
+        assert_eq!(team.outlier_members(), [3]);
+        assert_eq!(team.outliers.len(), 2);
+        assert_eq!(team.outliers[0].domain, Domain::Extraversion);
+        assert!(team.outliers[0].is_above());
+        assert_eq!(team.outliers[0].others_mean, 50.0);
+        assert_eq!(team.outliers[1].domain, Domain::Agreeableness);
+        assert!(!team.outliers[1].is_above());
+
+        // Two members are just two different people
+        let pair =
+            TeamProfile::from_profiles(&[profile([1, 1, 1, 1, 1]), profile([5, 5, 5, 5, 5])])
+                .unwrap();
+        assert!(pair.outliers.is_empty());
+    }
+}
@@ -1,5 +1,11 @@
 //! Core types for the Big Five personality test.
 
+use std::ops::RangeInclusive;
+
+use crate::Error;
+use crate::scoring::ScoreRange;
+use crate::validity::ValidityReport;
+
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -301,6 +307,192 @@ pub enum ScoreLevel {
     High,
 }
 
+/// Where the neutral and high levels start, in percent of the score range (0 for all
+/// answers 1, 100 for all answers 5).
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(try_from = "RawLevelCutoffs", into = "RawLevelCutoffs")
+)]
+pub struct LevelCutoffs {
+    neutral_from: f64,
+    high_from: f64,
+}
+
+/// Cutoffs as stored, checked by [`LevelCutoffs::new`] when loaded.
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+struct RawLevelCutoffs {
+    neutral_from: f64,
+    high_from: f64,
+}
+
+#[cfg(feature = "serde")]
+impl TryFrom<RawLevelCutoffs> for LevelCutoffs {
+    type Error = Error;
+
+    fn try_from(raw: RawLevelCutoffs) -> Result<Self, Error> {
+        LevelCutoffs::new(raw.neutral_from, raw.high_from)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl From<LevelCutoffs> for RawLevelCutoffs {
+    fn from(cutoffs: LevelCutoffs) -> Self {
+        RawLevelCutoffs {
+            neutral_from: cutoffs.neutral_from,
+            high_from: cutoffs.high_from,
+        }
+    }
+}
+
+impl LevelCutoffs {
+    /// The score range split into thirds, the default.
+    pub const THIRDS: LevelCutoffs = LevelCutoffs {
+        neutral_from: 100.0 / 3.0,
+        high_from: 200.0 / 3.0,
+    };
+
+    /// Cutoffs at the given percentages; `neutral_from` must be above 0 and below
+    /// `high_from`, which can be at most 100.
+    pub fn new(neutral_from: f64, high_from: f64) -> Result<Self, Error> {
+        if !(neutral_from > 0.0 && neutral_from < high_from && high_from <= 100.0) {
+            return Err(Error::InvalidLevelCutoffs(neutral_from, high_from));
+        }
+        Ok(LevelCutoffs {
+            neutral_from,
+            high_from,
+        })
+    }
+
+    /// Percentage of the score range where the neutral level starts.
+    pub fn neutral_from(&self) -> f64 {
+        self.neutral_from
+    }
+
+    /// Percentage of the score range where the high level starts.
+    pub fn high_from(&self) -> f64 {
+        self.high_from
+    }
+
+    /// Lowest raw score at or above `percent` of the range for `questions` answers.
+    fn raw_start(questions: u8, percent: f64) -> u8 {
+        let span = 4.0 * f64::from(questions);
+        // The tolerance keeps cutoffs like 100/3 from rounding past an exact band edge
+        let offset = (percent / 100.0 * span - 1e-9).ceil().max(0.0);
+        (f64::from(questions) + offset) as u8
+    }
+}
+
+impl Default for LevelCutoffs {
+    fn default() -> Self {
+        LevelCutoffs::THIRDS
+    }
+}
+
+impl ScoreLevel {
+    /// All levels, from low to high.
+    pub const ALL: [ScoreLevel; 3] = [ScoreLevel::Low, ScoreLevel::Neutral, ScoreLevel::High];
+
+    /// Raw scores that fall into this level for a sum of `questions` answers.
+    ///
+    /// The range from `questions` (all 1s) to `5 * questions` (all 5s) is split into
+    /// thirds, rounding band edges up.
+    pub fn range(&self, questions: u8) -> RangeInclusive<u8> {
+        self.range_with(questions, &LevelCutoffs::THIRDS)
+    }
+
+    /// Raw scores that fall into this level for a sum of `questions` answers, banded at
+    /// `cutoffs` with band edges rounded up.
+    ///
+    /// A band can be empty when cutoffs are closer together than one raw point.
+    pub fn range_with(&self, questions: u8, cutoffs: &LevelCutoffs) -> RangeInclusive<u8> {
+        let neutral_start = LevelCutoffs::raw_start(questions, cutoffs.neutral_from);
+        let high_start = LevelCutoffs::raw_start(questions, cutoffs.high_from);
+        match self {
+            ScoreLevel::Low => questions..=neutral_start.saturating_sub(1),
+            ScoreLevel::Neutral => neutral_start..=high_start.saturating_sub(1),
+            ScoreLevel::High => high_start..=questions.saturating_mul(5),
+        }
+    }
+
+    /// Level of a raw score summed from `questions` answers.
+    pub fn for_raw(raw: u8, questions: u8) -> ScoreLevel {
+        ScoreLevel::for_raw_with(raw, questions, &LevelCutoffs::THIRDS)
+    }
+
+    /// Level of a raw score summed from `questions` answers, banded at `cutoffs`.
+    pub fn for_raw_with(raw: u8, questions: u8, cutoffs: &LevelCutoffs) -> ScoreLevel {
+        ScoreLevel::ALL
+            .into_iter()
+            .find(|level| level.range_with(questions, cutoffs).contains(&raw))
+            .unwrap_or(ScoreLevel::Neutral) // Should not happen with valid data
+    }
+
+    /// Raw IPIP-NEO-120 domain scores (24-120) that fall into this level.
+    pub fn domain_range(&self) -> RangeInclusive<u8> {
+        self.range(24)
+    }
+
+    /// Raw IPIP-NEO-120 facet scores (4-20) that fall into this level.
+    pub fn facet_range(&self) -> RangeInclusive<u8> {
+        self.range(4)
+    }
+}
+
+/// Finer score categorization in five bands, for text that needs more nuance than
+/// [`ScoreLevel`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ScoreBand {
+    /// Bottom fifth of the score range
+    VeryLow,
+    /// Second fifth
+    Low,
+    /// Middle fifth
+    Neutral,
+    /// Fourth fifth
+    High,
+    /// Top fifth of the score range
+    VeryHigh,
+}
+
+impl ScoreBand {
+    /// All bands, from very low to very high.
+    pub const ALL: [ScoreBand; 5] = [
+        ScoreBand::VeryLow,
+        ScoreBand::Low,
+        ScoreBand::Neutral,
+        ScoreBand::High,
+        ScoreBand::VeryHigh,
+    ];
+
+    /// Raw scores that fall into this band for a sum of `questions` answers.
+    ///
+    /// The range from `questions` (all 1s) to `5 * questions` (all 5s) is split into
+    /// fifths, rounding band edges up like [`ScoreLevel::range`].
+    pub fn range(&self, questions: u8) -> RangeInclusive<u8> {
+        let q = u16::from(questions);
+        // Band k starts k fifths into the span of 4 * questions
+        let start = |k: u16| (q * (5 + 4 * k)).div_ceil(5) as u8;
+        let k = *self as u16;
+        if *self == ScoreBand::VeryHigh {
+            start(k)..=questions.saturating_mul(5)
+        } else {
+            start(k)..=start(k + 1).saturating_sub(1)
+        }
+    }
+
+    /// Band of a raw score summed from `questions` answers.
+    pub fn for_raw(raw: u8, questions: u8) -> ScoreBand {
+        ScoreBand::ALL
+            .into_iter()
+            .find(|band| band.range(questions).contains(&raw))
+            .unwrap_or(ScoreBand::Neutral) // Should not happen with valid data
+    }
+}
+
 /// A single question in the inventory.
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
@@ -337,8 +529,11 @@ pub struct Answer {
 }
 
 /// Score for a single facet.
+///
+/// Fields may be added in minor releases; build scores with [`FacetScore::new`].
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[non_exhaustive]
 pub struct FacetScore {
     /// The facet being scored.
     pub facet: Facet,
@@ -346,18 +541,34 @@ pub struct FacetScore {
     pub raw: u8,
     /// Categorized level.
     pub level: ScoreLevel,
+    /// Number of questions summed into the raw score.
+    #[cfg_attr(feature = "serde", serde(default = "default_facet_questions"))]
+    pub questions: u8,
 }
 
 impl FacetScore {
-    /// Calculate percentage (0-100) based on min=4, max=20.
+    /// Score of a facet from `questions` answers summing to `raw`.
+    pub fn new(facet: Facet, raw: u8, level: ScoreLevel, questions: u8) -> Self {
+        FacetScore {
+            facet,
+            raw,
+            level,
+            questions,
+        }
+    }
+
+    /// Calculate percentage (0-100) of the raw score range, e.g. 4-20 for 4 questions.
     pub fn percentage(&self) -> f32 {
-        ((self.raw as f32 - 4.0) / 16.0) * 100.0
+        ScoreRange::percentage(self)
     }
 }
 
 /// Score for a domain.
+///
+/// Fields may be added in minor releases; build scores with [`DomainScore::new`].
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[non_exhaustive]
 pub struct DomainScore {
     /// The domain being scored.
     pub domain: Domain,
@@ -367,24 +578,74 @@ pub struct DomainScore {
     pub level: ScoreLevel,
     /// Individual facet scores.
     pub facets: Vec<FacetScore>,
+    /// Number of questions summed into the raw score.
+    #[cfg_attr(feature = "serde", serde(default = "default_domain_questions"))]
+    pub questions: u8,
 }
 
 impl DomainScore {
-    /// Calculate percentage (0-100) based on min=24, max=120.
+    /// Score of a domain from `questions` answers summing to `raw`, with its facet scores.
+    pub fn new(
+        domain: Domain,
+        raw: u8,
+        level: ScoreLevel,
+        facets: Vec<FacetScore>,
+        questions: u8,
+    ) -> Self {
+        DomainScore {
+            domain,
+            raw,
+            level,
+            facets,
+            questions,
+        }
+    }
+
+    /// Calculate percentage (0-100) of the raw score range, e.g. 24-120 for 24 questions.
     pub fn percentage(&self) -> f32 {
-        ((self.raw as f32 - 24.0) / 96.0) * 100.0
+        ScoreRange::percentage(self)
     }
 }
 
+/// Question count of facet scores saved before it was recorded (IPIP-NEO-120).
+#[cfg(feature = "serde")]
+fn default_facet_questions() -> u8 {
+    4
+}
+
+/// Question count of domain scores saved before it was recorded (IPIP-NEO-120).
+#[cfg(feature = "serde")]
+fn default_domain_questions() -> u8 {
+    24
+}
+
 /// Complete personality profile with all domain and facet scores.
+///
+/// Fields may be added in minor releases; build profiles with [`PersonalityProfile::new`].
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[non_exhaustive]
 pub struct PersonalityProfile {
     /// Scores for all five domains.
     pub domains: Vec<DomainScore>,
+    /// Careless-responding indices of the answers, when scored from them (not for profiles
+    /// decoded from a compact string or saved before they were computed).
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub validity: Option<ValidityReport>,
 }
 
 impl PersonalityProfile {
+    /// Profile of the given domain scores, without validity indices.
+    pub fn new(domains: Vec<DomainScore>) -> Self {
+        PersonalityProfile {
+            domains,
+            validity: None,
+        }
+    }
+
     /// Get score for a specific domain.
     pub fn domain_score(&self, domain: Domain) -> Option<&DomainScore> {
         self.domains.iter().find(|d| d.domain == domain)
@@ -396,4 +657,14 @@ impl PersonalityProfile {
         self.domain_score(domain)
             .and_then(|d| d.facets.iter().find(|f| f.facet == facet))
     }
+
+    /// Questions per facet of the inventory the profile was scored on, e.g. 4 for the
+    /// IPIP-NEO-120; `None` for a profile without facet scores.
+    pub fn questions_per_facet(&self) -> Option<u8> {
+        self.domains
+            .iter()
+            .flat_map(|d| &d.facets)
+            .map(|f| f.questions)
+            .next()
+    }
 }
@@ -0,0 +1,145 @@
+//! Answer validation that reports every problem at once.
+//!
+//! [`calculate`](crate::calculate) stops at the first problem it finds; this is meant for
+//! showing the user everything that needs fixing before scoring.
+
+use std::collections::HashSet;
+
+use crate::inventory::Inventory;
+use crate::types::Answer;
+
+/// A problem with the answers for a single question.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AnswerIssue {
+    /// The question has no answer.
+    Missing { question_id: String },
+    /// The answer value is outside 1-5.
+    InvalidValue { question_id: String, value: u8 },
+    /// The question was answered more than once.
+    Duplicate { question_id: String },
+    /// The answer refers to a question that isn't in the inventory.
+    UnknownQuestion { question_id: String },
+}
+
+impl AnswerIssue {
+    /// Id of the question the issue is about.
+    pub fn question_id(&self) -> &str {
+        match self {
+            AnswerIssue::Missing { question_id }
+            | AnswerIssue::InvalidValue { question_id, .. }
+            | AnswerIssue::Duplicate { question_id }
+            | AnswerIssue::UnknownQuestion { question_id } => question_id,
+        }
+    }
+}
+
+/// Check answers against the inventory and collect all issues.
+///
+/// Issues about inventory questions come in inventory order, followed by answers to
+/// unknown questions. An empty result means the answers can be scored.
+pub fn validate_answers<I: Inventory + ?Sized>(
+    inventory: &I,
+    answers: &[Answer],
+) -> Vec<AnswerIssue> {
+    let mut issues = Vec::new();
+
+    for question in inventory.questions() {
+        let mut matching = answers.iter().filter(|a| a.question_id == question.id);
+        let Some(answer) = matching.next() else {
+            issues.push(AnswerIssue::Missing {
+                question_id: question.id.clone(),
+            });
+            continue;
+        };
+        if !(1..=5).contains(&answer.value) {
+            issues.push(AnswerIssue::InvalidValue {
+                question_id: question.id.clone(),
+                value: answer.value,
+            });
+        }
+        if matching.next().is_some() {
+            issues.push(AnswerIssue::Duplicate {
+                question_id: question.id.clone(),
+            });
+        }
+    }
+
+    let known: HashSet<&str> = inventory
+        .questions()
+        .iter()
+        .map(|q| q.id.as_str())
+        .collect();
+    issues.extend(
+        answers
+            .iter()
+            .filter(|a| !known.contains(a.question_id.as_str()))
+            .map(|a| AnswerIssue::UnknownQuestion {
+                question_id: a.question_id.clone(),
+            }),
+    );
+
+    issues
+}
+
+#[cfg(all(test, feature = "lang-en"))]
+mod tests {
+    use super::*;
+    use crate::inventory::Ipip120;
+
+    fn create_uniform_answers(inventory: &Ipip120, value: u8) -> Vec<Answer> {
+        inventory
+            .questions()
+            .iter()
+            .map(|q| Answer {
+                question_id: q.id.clone(),
+                value,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_valid_answers() {
+        let inventory = Ipip120::english();
+        let answers = create_uniform_answers(&inventory, 3);
+        assert!(validate_answers(&inventory, &answers).is_empty());
+    }
+
+    #[test]
+    fn test_collects_all_issues() {
+        let inventory = Ipip120::english();
+        let mut answers = create_uniform_answers(&inventory, 3);
+        let missing = answers.remove(5).question_id;
+        answers[0].value = 0;
+        answers[10].value = 6;
+        let duplicate = answers[20].clone();
+        answers.push(duplicate);
+        answers.push(Answer {
+            question_id: "nope".to_string(),
+            value: 3,
+        });
+
+        let issues = validate_answers(&inventory, &answers);
+        assert_eq!(issues.len(), 5);
+        assert_eq!(
+            issues[0],
+            AnswerIssue::InvalidValue {
+                question_id: answers[0].question_id.clone(),
+                value: 0,
+            }
+        );
+        assert!(issues.contains(&AnswerIssue::Missing {
+            question_id: missing,
+        }));
+        assert!(
+            issues
+                .iter()
+                .any(|i| matches!(i, AnswerIssue::InvalidValue { value: 6, .. }))
+        );
+        assert!(
+            issues
+                .iter()
+                .any(|i| matches!(i, AnswerIssue::Duplicate { .. }))
+        );
+        assert_eq!(issues.last().unwrap().question_id(), "nope");
+    }
+}
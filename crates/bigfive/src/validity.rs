@@ -0,0 +1,258 @@
+//! Indicators of careless responding.
+//!
+//! Straight-lined protocols (the same answer over and over) and random ones still produce
+//! a profile, but not a meaningful one. [`ValidityReport`] computes the usual screening
+//! indices from the raw answers so HR and research users can set such protocols aside:
+//!
+//! - **Longstring**: the longest run of identical consecutive answers.
+//! - **IRV** (intra-individual response variability): the standard deviation of the raw
+//!   answers; close to zero for straight-lining.
+//! - **Infrequency**: the IPIP-NEO has no dedicated infrequency items, so this counts
+//!   facets in which a positively and a negatively keyed item got the same extreme answer
+//!   ("very accurate" to both "I love parties" and "I avoid crowds"), a combination
+//!   attentive respondents rarely produce.
+
+use std::collections::HashSet;
+
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::Error;
+use crate::inventory::Inventory;
+use crate::speed::SpeedReport;
+use crate::types::{Answer, Question};
+
+/// Longest run of identical answers still considered attentive.
+const MAX_LONGSTRING: usize = 9;
+
+/// Lowest response variability still considered attentive.
+const MIN_IRV: f32 = 0.5;
+
+/// Most facets with contradictory extreme answers still considered attentive.
+const MAX_INFREQUENCY: usize = 3;
+
+/// An indicator outside its attentive range.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub enum ValidityFlag {
+    /// A long run of identical answers.
+    Longstring,
+    /// Almost no variation between answers.
+    LowVariability,
+    /// Contradictory extreme answers in several facets.
+    Infrequency,
+    /// Answered faster than the items can be read.
+    TooFast,
+}
+
+/// Careless-responding indices of one protocol.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+pub struct ValidityReport {
+    /// Longest run of identical consecutive answers, in inventory order.
+    pub longstring: usize,
+    /// Standard deviation of the raw answers (0-2).
+    pub irv: f32,
+    /// Facets in which oppositely keyed items got the same extreme answer.
+    pub infrequency: usize,
+    /// Response time indices, for answers scored with [`calculate_timed`](crate::calculate_timed).
+    #[cfg_attr(
+        feature = "serde",
+        serde(default, skip_serializing_if = "Option::is_none")
+    )]
+    pub speed: Option<SpeedReport>,
+    /// Indicators outside their attentive range; empty for an unremarkable protocol.
+    pub flags: Vec<ValidityFlag>,
+}
+
+impl ValidityReport {
+    /// Compute the indices for a complete set of answers.
+    ///
+    /// Fails like [`calculate`](crate::calculate) on missing or out-of-range answers.
+    pub fn from_answers<I: Inventory + ?Sized>(
+        inventory: &I,
+        answers: &[Answer],
+    ) -> Result<Self, Error> {
+        let questions = inventory.questions();
+        if answers.len() != questions.len() {
+            return Err(Error::InvalidAnswerCount(answers.len()));
+        }
+        let values = questions
+            .iter()
+            .map(|q| {
+                let answer = answers
+                    .iter()
+                    .find(|a| a.question_id == q.id)
+                    .ok_or_else(|| Error::MissingAnswer(q.id.clone()))?;
+                if !(1..=5).contains(&answer.value) {
+                    return Err(Error::InvalidAnswerValue(answer.value));
+                }
+                Ok(answer.value)
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self::assess(questions, &values))
+    }
+
+    /// Indices for raw answer `values` (1-5) given in the order of `questions`.
+    pub(crate) fn assess(questions: &[Question], values: &[u8]) -> Self {
+        let longstring = values
+            .chunk_by(|a, b| a == b)
+            .map(<[u8]>::len)
+            .max()
+            .unwrap_or(0);
+
+        let irv = if values.is_empty() {
+            0.0
+        } else {
+            let n = values.len() as f32;
+            let mean = values.iter().map(|&v| f32::from(v)).sum::<f32>() / n;
+            let variance = values
+                .iter()
+                .map(|&v| (f32::from(v) - mean).powi(2))
+                .sum::<f32>()
+                / n;
+            variance.sqrt()
+        };
+
+        let extremes: Vec<(&Question, u8)> = questions
+            .iter()
+            .zip(values.iter().copied())
+            .filter(|&(_, value)| value == 1 || value == 5)
+            .collect();
+        let infrequency = extremes
+            .iter()
+            .filter(|(question, value)| {
+                extremes.iter().any(|(other, other_value)| {
+                    other_value == value
+                        && other.reversed != question.reversed
+                        && other.domain == question.domain
+                        && other.facet_index == question.facet_index
+                })
+            })
+            .map(|(question, _)| (question.domain, question.facet_index))
+            .collect::<HashSet<_>>()
+            .len();
+
+        let mut flags = Vec::new();
+        if longstring > MAX_LONGSTRING {
+            flags.push(ValidityFlag::Longstring);
+        }
+        if irv < MIN_IRV {
+            flags.push(ValidityFlag::LowVariability);
+        }
+        if infrequency > MAX_INFREQUENCY {
+            flags.push(ValidityFlag::Infrequency);
+        }
+
+        ValidityReport {
+            longstring,
+            irv,
+            infrequency,
+            speed: None,
+            flags,
+        }
+    }
+
+    /// Attach response time indices, flagging the protocol if it was too fast.
+    pub fn add_speed(&mut self, speed: SpeedReport) {
+        if speed.too_fast && !self.flags.contains(&ValidityFlag::TooFast) {
+            self.flags.push(ValidityFlag::TooFast);
+        }
+        self.speed = Some(speed);
+    }
+
+    /// Whether no indicator is outside its attentive range.
+    pub fn is_valid(&self) -> bool {
+        self.flags.is_empty()
+    }
+}
+
+#[cfg(all(test, feature = "lang-en"))]
+mod tests {
+    use super::*;
+    use crate::inventory::Ipip120;
+
+    fn answers(inventory: &Ipip120, value: impl Fn(usize, &Question) -> u8) -> Vec<Answer> {
+        inventory
+            .questions()
+            .iter()
+            .enumerate()
+            .map(|(i, q)| Answer {
+                question_id: q.id.clone(),
+                value: value(i, q),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_straight_lined_protocol_is_flagged() {
+        let inventory = Ipip120::english();
+        let report =
+            ValidityReport::from_answers(&inventory, &answers(&inventory, |_, _| 5)).unwrap();
+
+        assert_eq!(report.longstring, 120);
+        assert_eq!(report.irv, 0.0);
+        assert!(report.infrequency > MAX_INFREQUENCY);
+        assert_eq!(
+            report.flags,
+            vec![
+                ValidityFlag::Longstring,
+                ValidityFlag::LowVariability,
+                ValidityFlag::Infrequency
+            ]
+        );
+        assert!(!report.is_valid());
+    }
+
+    #[test]
+    fn test_neutral_straight_line_is_not_infrequent() {
+        let inventory = Ipip120::english();
+        let report =
+            ValidityReport::from_answers(&inventory, &answers(&inventory, |_, _| 3)).unwrap();
+
+        assert_eq!(report.infrequency, 0);
+        assert_eq!(
+            report.flags,
+            vec![ValidityFlag::Longstring, ValidityFlag::LowVariability]
+        );
+    }
+
+    #[test]
+    fn test_consistent_varied_protocol_passes() {
+        let inventory = Ipip120::english();
+        // Agrees with the keyed direction, with the strength varying between questions
+        let report = ValidityReport::from_answers(
+            &inventory,
+            &answers(&inventory, |i, q| {
+                let value = [4, 5, 4, 2][i % 4];
+                if q.reversed { 6 - value } else { value }
+            }),
+        )
+        .unwrap();
+
+        assert!(report.longstring <= MAX_LONGSTRING);
+        assert!(report.irv >= MIN_IRV);
+        assert_eq!(report.infrequency, 0);
+        assert!(report.is_valid());
+    }
+
+    #[test]
+    fn test_missing_answer_is_an_error() {
+        let inventory = Ipip120::english();
+        let mut answers = answers(&inventory, |_, _| 3);
+        answers[0].question_id = "unknown".to_string();
+
+        assert!(matches!(
+            ValidityReport::from_answers(&inventory, &answers),
+            Err(Error::MissingAnswer(_))
+        ));
+    }
+
+    #[test]
+    fn test_calculate_attaches_report() {
+        let inventory = Ipip120::english();
+        let profile = crate::calculate(&inventory, &answers(&inventory, |_, _| 3)).unwrap();
+
+        assert_eq!(profile.validity.unwrap().longstring, 120);
+    }
+}
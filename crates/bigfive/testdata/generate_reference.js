@@ -0,0 +1,80 @@
+// Generates reference_scores.json: answer sheets scored by the Alheimsins
+// reference implementation, for the golden tests in src/scoring.rs.
+//
+// Reference packages (npm), at exact versions:
+//   @alheimsins/b5-calculate-score           scoring
+//   @alheimsins/b5-johnson-120-ipip-neo-pi-r items, keying and choice scores
+//
+// Usage:
+//   npm install --no-save @alheimsins/b5-calculate-score@<version> \
+//     @alheimsins/b5-johnson-120-ipip-neo-pi-r@<version>
+//   node generate_reference.js > reference_scores.json
+//
+// The installed versions are printed to stderr; write them into the install
+// command above whenever the fixture is regenerated.
+//
+// Answers are listed in the question order of ../data/en.json, which the tests
+// zip with the bundled inventory. Only the question ids are taken from there:
+// domain, facet and the score of each answer come from the reference item
+// package, so a keying mistake in ../data would make the tests fail.
+
+const calculateScore = require('@alheimsins/b5-calculate-score')
+const { getItems } = require('@alheimsins/b5-johnson-120-ipip-neo-pi-r')
+
+const questionIds = require('../data/en.json').map(q => q.id)
+const items = new Map(getItems('en').map(item => [item.id, item]))
+
+if (items.size !== questionIds.length) {
+  throw new Error(`reference has ${items.size} items, ../data/en.json ${questionIds.length}`)
+}
+const referenceItems = questionIds.map(id => {
+  const item = items.get(id)
+  if (!item) throw new Error(`question ${id} is not in the reference items`)
+  return item
+})
+
+for (const name of ['@alheimsins/b5-calculate-score', '@alheimsins/b5-johnson-120-ipip-neo-pi-r']) {
+  console.error(`${name}@${require(`${name}/package.json`).version}`)
+}
+
+// Small deterministic PRNG so the corpus is reproducible
+function lcg (seed) {
+  let state = seed
+  return () => {
+    state = (state * 1103515245 + 12345) % 2147483648
+    return state
+  }
+}
+
+const sheets = [
+  { name: 'all ones', answers: referenceItems.map(() => 1) },
+  { name: 'all threes', answers: referenceItems.map(() => 3) },
+  { name: 'all fives', answers: referenceItems.map(() => 5) },
+  { name: 'alternating 1/5', answers: referenceItems.map((_, i) => (i % 2 ? 5 : 1)) },
+  { name: 'cycling 1-5', answers: referenceItems.map((_, i) => (i % 5) + 1) },
+  { name: 'keyed high', answers: referenceItems.map(item => (item.keyed === 'plus' ? 5 : 1)) },
+  { name: 'keyed low', answers: referenceItems.map(item => (item.keyed === 'plus' ? 2 : 4)) }
+]
+for (const seed of [1, 42, 2024, 31337, 99991]) {
+  const next = lcg(seed)
+  sheets.push({ name: `random seed ${seed}`, answers: referenceItems.map(() => (next() % 5) + 1) })
+}
+
+// An answer of 1-5 is the position of the chosen option, from "Very Inaccurate"
+// to "Very Accurate"; the reference lists minus-keyed options with reversed scores
+const corpus = sheets.map(({ name, answers }) => ({
+  name,
+  answers,
+  expected: calculateScore({
+    answers: referenceItems.map((item, i) => ({
+      domain: item.domain,
+      facet: item.facet,
+      score: item.choices[answers[i] - 1].score
+    }))
+  })
+}))
+
+// Keep each answer sheet on one line
+const json = JSON.stringify(corpus, null, 2)
+  .replace(/\[\s+([\d,\s]+?)\s+\]/g, (_, values) => `[${values.split(/,\s*/).join(', ')}]`)
+console.log(json)